@@ -61,6 +61,7 @@ get_rs agent.proto
 get_rs health.proto
 get_rs github.com/kata-containers/agent/pkg/types/types.proto
 get_rs google/protobuf/empty.proto
+get_rs ttrpc.proto
 
 get_rs oci.proto
 # Need change Box<Self> to ::std::boxed::Box<Self> because there is another struct Box
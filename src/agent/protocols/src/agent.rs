@@ -9463,74 +9463,25 @@ impl ::protobuf::reflect::ProtobufValue for ListRoutesRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct OnlineCPUMemRequest {
-    // message fields
-    pub wait: bool,
-    pub nb_cpus: u32,
-    pub cpu_only: bool,
+pub struct GetNetworkEventsRequest {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a OnlineCPUMemRequest {
-    fn default() -> &'a OnlineCPUMemRequest {
-        <OnlineCPUMemRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GetNetworkEventsRequest {
+    fn default() -> &'a GetNetworkEventsRequest {
+        <GetNetworkEventsRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl OnlineCPUMemRequest {
-    pub fn new() -> OnlineCPUMemRequest {
+impl GetNetworkEventsRequest {
+    pub fn new() -> GetNetworkEventsRequest {
         ::std::default::Default::default()
     }
-
-    // bool wait = 1;
-
-
-    pub fn get_wait(&self) -> bool {
-        self.wait
-    }
-    pub fn clear_wait(&mut self) {
-        self.wait = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_wait(&mut self, v: bool) {
-        self.wait = v;
-    }
-
-    // uint32 nb_cpus = 2;
-
-
-    pub fn get_nb_cpus(&self) -> u32 {
-        self.nb_cpus
-    }
-    pub fn clear_nb_cpus(&mut self) {
-        self.nb_cpus = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_nb_cpus(&mut self, v: u32) {
-        self.nb_cpus = v;
-    }
-
-    // bool cpu_only = 3;
-
-
-    pub fn get_cpu_only(&self) -> bool {
-        self.cpu_only
-    }
-    pub fn clear_cpu_only(&mut self) {
-        self.cpu_only = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_cpu_only(&mut self, v: bool) {
-        self.cpu_only = v;
-    }
 }
 
-impl ::protobuf::Message for OnlineCPUMemRequest {
+impl ::protobuf::Message for GetNetworkEventsRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -9539,27 +9490,6 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.wait = tmp;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.nb_cpus = tmp;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.cpu_only = tmp;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -9572,30 +9502,12 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.wait != false {
-            my_size += 2;
-        }
-        if self.nb_cpus != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.nb_cpus, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.cpu_only != false {
-            my_size += 2;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.wait != false {
-            os.write_bool(1, self.wait)?;
-        }
-        if self.nb_cpus != 0 {
-            os.write_uint32(2, self.nb_cpus)?;
-        }
-        if self.cpu_only != false {
-            os.write_bool(3, self.cpu_only)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9626,8 +9538,8 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> OnlineCPUMemRequest {
-        OnlineCPUMemRequest::new()
+    fn new() -> GetNetworkEventsRequest {
+        GetNetworkEventsRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9637,24 +9549,9 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "wait",
-                    |m: &OnlineCPUMemRequest| { &m.wait },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.wait },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "nb_cpus",
-                    |m: &OnlineCPUMemRequest| { &m.nb_cpus },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.nb_cpus },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "cpu_only",
-                    |m: &OnlineCPUMemRequest| { &m.cpu_only },
-                    |m: &mut OnlineCPUMemRequest| { &mut m.cpu_only },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<OnlineCPUMemRequest>(
-                    "OnlineCPUMemRequest",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<GetNetworkEventsRequest>(
+                    "GetNetworkEventsRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9662,87 +9559,150 @@ impl ::protobuf::Message for OnlineCPUMemRequest {
         }
     }
 
-    fn default_instance() -> &'static OnlineCPUMemRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OnlineCPUMemRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static GetNetworkEventsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetNetworkEventsRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OnlineCPUMemRequest,
+            ptr: 0 as *const GetNetworkEventsRequest,
         };
         unsafe {
-            instance.get(OnlineCPUMemRequest::new)
+            instance.get(GetNetworkEventsRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for OnlineCPUMemRequest {
+impl ::protobuf::Clear for GetNetworkEventsRequest {
     fn clear(&mut self) {
-        self.wait = false;
-        self.nb_cpus = 0;
-        self.cpu_only = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for OnlineCPUMemRequest {
+impl ::std::fmt::Debug for GetNetworkEventsRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for OnlineCPUMemRequest {
+impl ::protobuf::reflect::ProtobufValue for GetNetworkEventsRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ReseedRandomDevRequest {
+pub struct TrafficControl {
     // message fields
-    pub data: ::std::vec::Vec<u8>,
+    pub qdiscs: ::protobuf::RepeatedField<super::types::Qdisc>,
+    pub classes: ::protobuf::RepeatedField<super::types::TrafficClass>,
+    pub filters: ::protobuf::RepeatedField<super::types::TrafficFilter>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ReseedRandomDevRequest {
-    fn default() -> &'a ReseedRandomDevRequest {
-        <ReseedRandomDevRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TrafficControl {
+    fn default() -> &'a TrafficControl {
+        <TrafficControl as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ReseedRandomDevRequest {
-    pub fn new() -> ReseedRandomDevRequest {
+impl TrafficControl {
+    pub fn new() -> TrafficControl {
         ::std::default::Default::default()
     }
 
-    // bytes data = 2;
+    // repeated .types.Qdisc qdiscs = 1;
 
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn get_qdiscs(&self) -> &[super::types::Qdisc] {
+        &self.qdiscs
     }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
+    pub fn clear_qdiscs(&mut self) {
+        self.qdiscs.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
+    pub fn set_qdiscs(&mut self, v: ::protobuf::RepeatedField<super::types::Qdisc>) {
+        self.qdiscs = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
+    pub fn mut_qdiscs(&mut self) -> &mut ::protobuf::RepeatedField<super::types::Qdisc> {
+        &mut self.qdiscs
     }
 
     // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    pub fn take_qdiscs(&mut self) -> ::protobuf::RepeatedField<super::types::Qdisc> {
+        ::std::mem::replace(&mut self.qdiscs, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .types.TrafficClass classes = 2;
+
+
+    pub fn get_classes(&self) -> &[super::types::TrafficClass] {
+        &self.classes
+    }
+    pub fn clear_classes(&mut self) {
+        self.classes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_classes(&mut self, v: ::protobuf::RepeatedField<super::types::TrafficClass>) {
+        self.classes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_classes(&mut self) -> &mut ::protobuf::RepeatedField<super::types::TrafficClass> {
+        &mut self.classes
+    }
+
+    // Take field
+    pub fn take_classes(&mut self) -> ::protobuf::RepeatedField<super::types::TrafficClass> {
+        ::std::mem::replace(&mut self.classes, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .types.TrafficFilter filters = 3;
+
+
+    pub fn get_filters(&self) -> &[super::types::TrafficFilter] {
+        &self.filters
+    }
+    pub fn clear_filters(&mut self) {
+        self.filters.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filters(&mut self, v: ::protobuf::RepeatedField<super::types::TrafficFilter>) {
+        self.filters = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filters(&mut self) -> &mut ::protobuf::RepeatedField<super::types::TrafficFilter> {
+        &mut self.filters
+    }
+
+    // Take field
+    pub fn take_filters(&mut self) -> ::protobuf::RepeatedField<super::types::TrafficFilter> {
+        ::std::mem::replace(&mut self.filters, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for ReseedRandomDevRequest {
+impl ::protobuf::Message for TrafficControl {
     fn is_initialized(&self) -> bool {
+        for v in &self.qdiscs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.classes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.filters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -9750,8 +9710,14 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.qdiscs)?;
+                },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.classes)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.filters)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -9765,18 +9731,39 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.data);
-        }
+        for value in &self.qdiscs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.classes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.filters {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.data.is_empty() {
-            os.write_bytes(2, &self.data)?;
-        }
+        for v in &self.qdiscs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.classes {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.filters {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -9807,8 +9794,8 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ReseedRandomDevRequest {
-        ReseedRandomDevRequest::new()
+    fn new() -> TrafficControl {
+        TrafficControl::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -9819,13 +9806,23 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &ReseedRandomDevRequest| { &m.data },
-                    |m: &mut ReseedRandomDevRequest| { &mut m.data },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Qdisc>>(
+                    "qdiscs",
+                    |m: &TrafficControl| { &m.qdiscs },
+                    |m: &mut TrafficControl| { &mut m.qdiscs },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ReseedRandomDevRequest>(
-                    "ReseedRandomDevRequest",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::TrafficClass>>(
+                    "classes",
+                    |m: &TrafficControl| { &m.classes },
+                    |m: &mut TrafficControl| { &mut m.classes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::TrafficFilter>>(
+                    "filters",
+                    |m: &TrafficControl| { &m.filters },
+                    |m: &mut TrafficControl| { &mut m.filters },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TrafficControl>(
+                    "TrafficControl",
                     fields,
                     file_descriptor_proto()
                 )
@@ -9833,169 +9830,99 @@ impl ::protobuf::Message for ReseedRandomDevRequest {
         }
     }
 
-    fn default_instance() -> &'static ReseedRandomDevRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ReseedRandomDevRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static TrafficControl {
+        static mut instance: ::protobuf::lazy::Lazy<TrafficControl> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ReseedRandomDevRequest,
+            ptr: 0 as *const TrafficControl,
         };
         unsafe {
-            instance.get(ReseedRandomDevRequest::new)
+            instance.get(TrafficControl::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ReseedRandomDevRequest {
+impl ::protobuf::Clear for TrafficControl {
     fn clear(&mut self) {
-        self.data.clear();
+        self.qdiscs.clear();
+        self.classes.clear();
+        self.filters.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ReseedRandomDevRequest {
+impl ::std::fmt::Debug for TrafficControl {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ReseedRandomDevRequest {
+impl ::protobuf::reflect::ProtobufValue for TrafficControl {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct AgentDetails {
+pub struct UpdateTrafficControlRequest {
     // message fields
-    pub version: ::std::string::String,
-    pub init_daemon: bool,
-    pub device_handlers: ::protobuf::RepeatedField<::std::string::String>,
-    pub storage_handlers: ::protobuf::RepeatedField<::std::string::String>,
-    pub supports_seccomp: bool,
+    pub traffic_control: ::protobuf::SingularPtrField<TrafficControl>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a AgentDetails {
-    fn default() -> &'a AgentDetails {
-        <AgentDetails as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateTrafficControlRequest {
+    fn default() -> &'a UpdateTrafficControlRequest {
+        <UpdateTrafficControlRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AgentDetails {
-    pub fn new() -> AgentDetails {
+impl UpdateTrafficControlRequest {
+    pub fn new() -> UpdateTrafficControlRequest {
         ::std::default::Default::default()
     }
 
-    // string version = 1;
+    // .grpc.TrafficControl traffic_control = 1;
 
 
-    pub fn get_version(&self) -> &str {
-        &self.version
+    pub fn get_traffic_control(&self) -> &TrafficControl {
+        self.traffic_control.as_ref().unwrap_or_else(|| TrafficControl::default_instance())
     }
-    pub fn clear_version(&mut self) {
-        self.version.clear();
+    pub fn clear_traffic_control(&mut self) {
+        self.traffic_control.clear();
+    }
+
+    pub fn has_traffic_control(&self) -> bool {
+        self.traffic_control.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_version(&mut self, v: ::std::string::String) {
-        self.version = v;
+    pub fn set_traffic_control(&mut self, v: TrafficControl) {
+        self.traffic_control = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_version(&mut self) -> &mut ::std::string::String {
-        &mut self.version
-    }
-
-    // Take field
-    pub fn take_version(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.version, ::std::string::String::new())
-    }
-
-    // bool init_daemon = 2;
-
-
-    pub fn get_init_daemon(&self) -> bool {
-        self.init_daemon
-    }
-    pub fn clear_init_daemon(&mut self) {
-        self.init_daemon = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_init_daemon(&mut self, v: bool) {
-        self.init_daemon = v;
-    }
-
-    // repeated string device_handlers = 3;
-
-
-    pub fn get_device_handlers(&self) -> &[::std::string::String] {
-        &self.device_handlers
-    }
-    pub fn clear_device_handlers(&mut self) {
-        self.device_handlers.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_device_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.device_handlers = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_device_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.device_handlers
-    }
-
-    // Take field
-    pub fn take_device_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.device_handlers, ::protobuf::RepeatedField::new())
-    }
-
-    // repeated string storage_handlers = 4;
-
-
-    pub fn get_storage_handlers(&self) -> &[::std::string::String] {
-        &self.storage_handlers
-    }
-    pub fn clear_storage_handlers(&mut self) {
-        self.storage_handlers.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_storage_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.storage_handlers = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_storage_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.storage_handlers
+    pub fn mut_traffic_control(&mut self) -> &mut TrafficControl {
+        if self.traffic_control.is_none() {
+            self.traffic_control.set_default();
+        }
+        self.traffic_control.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_storage_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.storage_handlers, ::protobuf::RepeatedField::new())
-    }
-
-    // bool supports_seccomp = 5;
-
-
-    pub fn get_supports_seccomp(&self) -> bool {
-        self.supports_seccomp
-    }
-    pub fn clear_supports_seccomp(&mut self) {
-        self.supports_seccomp = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_supports_seccomp(&mut self, v: bool) {
-        self.supports_seccomp = v;
+    pub fn take_traffic_control(&mut self) -> TrafficControl {
+        self.traffic_control.take().unwrap_or_else(|| TrafficControl::new())
     }
 }
 
-impl ::protobuf::Message for AgentDetails {
+impl ::protobuf::Message for UpdateTrafficControlRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.traffic_control {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -10004,27 +9931,7 @@ impl ::protobuf::Message for AgentDetails {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.init_daemon = tmp;
-                },
-                3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.device_handlers)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.storage_handlers)?;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.supports_seccomp = tmp;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.traffic_control)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10038,20 +9945,9 @@ impl ::protobuf::Message for AgentDetails {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.version.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.version);
-        }
-        if self.init_daemon != false {
-            my_size += 2;
-        }
-        for value in &self.device_handlers {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in &self.storage_handlers {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
-        if self.supports_seccomp != false {
-            my_size += 2;
+        if let Some(ref v) = self.traffic_control.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -10059,20 +9955,10 @@ impl ::protobuf::Message for AgentDetails {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.version.is_empty() {
-            os.write_string(1, &self.version)?;
-        }
-        if self.init_daemon != false {
-            os.write_bool(2, self.init_daemon)?;
-        }
-        for v in &self.device_handlers {
-            os.write_string(3, &v)?;
-        };
-        for v in &self.storage_handlers {
-            os.write_string(4, &v)?;
-        };
-        if self.supports_seccomp != false {
-            os.write_bool(5, self.supports_seccomp)?;
+        if let Some(ref v) = self.traffic_control.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10104,8 +9990,8 @@ impl ::protobuf::Message for AgentDetails {
         Self::descriptor_static()
     }
 
-    fn new() -> AgentDetails {
-        AgentDetails::new()
+    fn new() -> UpdateTrafficControlRequest {
+        UpdateTrafficControlRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10116,33 +10002,13 @@ impl ::protobuf::Message for AgentDetails {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "version",
-                    |m: &AgentDetails| { &m.version },
-                    |m: &mut AgentDetails| { &mut m.version },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "init_daemon",
-                    |m: &AgentDetails| { &m.init_daemon },
-                    |m: &mut AgentDetails| { &mut m.init_daemon },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "device_handlers",
-                    |m: &AgentDetails| { &m.device_handlers },
-                    |m: &mut AgentDetails| { &mut m.device_handlers },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TrafficControl>>(
+                    "traffic_control",
+                    |m: &UpdateTrafficControlRequest| { &m.traffic_control },
+                    |m: &mut UpdateTrafficControlRequest| { &mut m.traffic_control },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "storage_handlers",
-                    |m: &AgentDetails| { &m.storage_handlers },
-                    |m: &mut AgentDetails| { &mut m.storage_handlers },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "supports_seccomp",
-                    |m: &AgentDetails| { &m.supports_seccomp },
-                    |m: &mut AgentDetails| { &mut m.supports_seccomp },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<AgentDetails>(
-                    "AgentDetails",
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateTrafficControlRequest>(
+                    "UpdateTrafficControlRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10150,93 +10016,84 @@ impl ::protobuf::Message for AgentDetails {
         }
     }
 
-    fn default_instance() -> &'static AgentDetails {
-        static mut instance: ::protobuf::lazy::Lazy<AgentDetails> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static UpdateTrafficControlRequest {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateTrafficControlRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AgentDetails,
+            ptr: 0 as *const UpdateTrafficControlRequest,
         };
         unsafe {
-            instance.get(AgentDetails::new)
+            instance.get(UpdateTrafficControlRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for AgentDetails {
+impl ::protobuf::Clear for UpdateTrafficControlRequest {
     fn clear(&mut self) {
-        self.version.clear();
-        self.init_daemon = false;
-        self.device_handlers.clear();
-        self.storage_handlers.clear();
-        self.supports_seccomp = false;
+        self.traffic_control.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for AgentDetails {
+impl ::std::fmt::Debug for UpdateTrafficControlRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AgentDetails {
+impl ::protobuf::reflect::ProtobufValue for UpdateTrafficControlRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct GuestDetailsRequest {
+pub struct ListTrafficControlRequest {
     // message fields
-    pub mem_block_size: bool,
-    pub mem_hotplug_probe: bool,
+    pub device: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a GuestDetailsRequest {
-    fn default() -> &'a GuestDetailsRequest {
-        <GuestDetailsRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListTrafficControlRequest {
+    fn default() -> &'a ListTrafficControlRequest {
+        <ListTrafficControlRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GuestDetailsRequest {
-    pub fn new() -> GuestDetailsRequest {
+impl ListTrafficControlRequest {
+    pub fn new() -> ListTrafficControlRequest {
         ::std::default::Default::default()
     }
 
-    // bool mem_block_size = 1;
+    // string device = 1;
 
 
-    pub fn get_mem_block_size(&self) -> bool {
-        self.mem_block_size
+    pub fn get_device(&self) -> &str {
+        &self.device
     }
-    pub fn clear_mem_block_size(&mut self) {
-        self.mem_block_size = false;
+    pub fn clear_device(&mut self) {
+        self.device.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_mem_block_size(&mut self, v: bool) {
-        self.mem_block_size = v;
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
     }
 
-    // bool mem_hotplug_probe = 2;
-
-
-    pub fn get_mem_hotplug_probe(&self) -> bool {
-        self.mem_hotplug_probe
-    }
-    pub fn clear_mem_hotplug_probe(&mut self) {
-        self.mem_hotplug_probe = false;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
     }
 
-    // Param is passed by value, moved
-    pub fn set_mem_hotplug_probe(&mut self, v: bool) {
-        self.mem_hotplug_probe = v;
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for GuestDetailsRequest {
+impl ::protobuf::Message for ListTrafficControlRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -10246,18 +10103,7 @@ impl ::protobuf::Message for GuestDetailsRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.mem_block_size = tmp;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.mem_hotplug_probe = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10271,11 +10117,8 @@ impl ::protobuf::Message for GuestDetailsRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.mem_block_size != false {
-            my_size += 2;
-        }
-        if self.mem_hotplug_probe != false {
-            my_size += 2;
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -10283,11 +10126,8 @@ impl ::protobuf::Message for GuestDetailsRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.mem_block_size != false {
-            os.write_bool(1, self.mem_block_size)?;
-        }
-        if self.mem_hotplug_probe != false {
-            os.write_bool(2, self.mem_hotplug_probe)?;
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10319,8 +10159,8 @@ impl ::protobuf::Message for GuestDetailsRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> GuestDetailsRequest {
-        GuestDetailsRequest::new()
+    fn new() -> ListTrafficControlRequest {
+        ListTrafficControlRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10331,18 +10171,13 @@ impl ::protobuf::Message for GuestDetailsRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "mem_block_size",
-                    |m: &GuestDetailsRequest| { &m.mem_block_size },
-                    |m: &mut GuestDetailsRequest| { &mut m.mem_block_size },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "mem_hotplug_probe",
-                    |m: &GuestDetailsRequest| { &m.mem_hotplug_probe },
-                    |m: &mut GuestDetailsRequest| { &mut m.mem_hotplug_probe },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &ListTrafficControlRequest| { &m.device },
+                    |m: &mut ListTrafficControlRequest| { &mut m.device },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsRequest>(
-                    "GuestDetailsRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ListTrafficControlRequest>(
+                    "ListTrafficControlRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10350,126 +10185,164 @@ impl ::protobuf::Message for GuestDetailsRequest {
         }
     }
 
-    fn default_instance() -> &'static GuestDetailsRequest {
-        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ListTrafficControlRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ListTrafficControlRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GuestDetailsRequest,
+            ptr: 0 as *const ListTrafficControlRequest,
         };
         unsafe {
-            instance.get(GuestDetailsRequest::new)
+            instance.get(ListTrafficControlRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for GuestDetailsRequest {
+impl ::protobuf::Clear for ListTrafficControlRequest {
     fn clear(&mut self) {
-        self.mem_block_size = false;
-        self.mem_hotplug_probe = false;
+        self.device.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for GuestDetailsRequest {
+impl ::std::fmt::Debug for ListTrafficControlRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GuestDetailsRequest {
+impl ::protobuf::reflect::ProtobufValue for ListTrafficControlRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct GuestDetailsResponse {
+pub struct NetworkEvent {
     // message fields
-    pub mem_block_size_bytes: u64,
-    pub agent_details: ::protobuf::SingularPtrField<AgentDetails>,
-    pub support_mem_hotplug_probe: bool,
+    pub kind: NetworkEvent_Kind,
+    pub deleted: bool,
+    pub interface: ::protobuf::SingularPtrField<super::types::Interface>,
+    pub route: ::protobuf::SingularPtrField<super::types::Route>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a GuestDetailsResponse {
-    fn default() -> &'a GuestDetailsResponse {
-        <GuestDetailsResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a NetworkEvent {
+    fn default() -> &'a NetworkEvent {
+        <NetworkEvent as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GuestDetailsResponse {
-    pub fn new() -> GuestDetailsResponse {
+impl NetworkEvent {
+    pub fn new() -> NetworkEvent {
         ::std::default::Default::default()
     }
 
-    // uint64 mem_block_size_bytes = 1;
+    // .grpc.NetworkEvent.Kind kind = 1;
 
 
-    pub fn get_mem_block_size_bytes(&self) -> u64 {
-        self.mem_block_size_bytes
+    pub fn get_kind(&self) -> NetworkEvent_Kind {
+        self.kind
     }
-    pub fn clear_mem_block_size_bytes(&mut self) {
-        self.mem_block_size_bytes = 0;
+    pub fn clear_kind(&mut self) {
+        self.kind = NetworkEvent_Kind::LINK;
     }
 
     // Param is passed by value, moved
-    pub fn set_mem_block_size_bytes(&mut self, v: u64) {
-        self.mem_block_size_bytes = v;
+    pub fn set_kind(&mut self, v: NetworkEvent_Kind) {
+        self.kind = v;
     }
 
-    // .grpc.AgentDetails agent_details = 2;
+    // bool deleted = 2;
 
 
-    pub fn get_agent_details(&self) -> &AgentDetails {
-        self.agent_details.as_ref().unwrap_or_else(|| AgentDetails::default_instance())
+    pub fn get_deleted(&self) -> bool {
+        self.deleted
     }
-    pub fn clear_agent_details(&mut self) {
-        self.agent_details.clear();
+    pub fn clear_deleted(&mut self) {
+        self.deleted = false;
     }
 
-    pub fn has_agent_details(&self) -> bool {
-        self.agent_details.is_some()
+    // Param is passed by value, moved
+    pub fn set_deleted(&mut self, v: bool) {
+        self.deleted = v;
+    }
+
+    // .types.Interface interface = 3;
+
+
+    pub fn get_interface(&self) -> &super::types::Interface {
+        self.interface.as_ref().unwrap_or_else(|| super::types::Interface::default_instance())
+    }
+    pub fn clear_interface(&mut self) {
+        self.interface.clear();
+    }
+
+    pub fn has_interface(&self) -> bool {
+        self.interface.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_agent_details(&mut self, v: AgentDetails) {
-        self.agent_details = ::protobuf::SingularPtrField::some(v);
+    pub fn set_interface(&mut self, v: super::types::Interface) {
+        self.interface = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_agent_details(&mut self) -> &mut AgentDetails {
-        if self.agent_details.is_none() {
-            self.agent_details.set_default();
+    pub fn mut_interface(&mut self) -> &mut super::types::Interface {
+        if self.interface.is_none() {
+            self.interface.set_default();
         }
-        self.agent_details.as_mut().unwrap()
+        self.interface.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_agent_details(&mut self) -> AgentDetails {
-        self.agent_details.take().unwrap_or_else(|| AgentDetails::new())
+    pub fn take_interface(&mut self) -> super::types::Interface {
+        self.interface.take().unwrap_or_else(|| super::types::Interface::new())
     }
 
-    // bool support_mem_hotplug_probe = 3;
+    // .types.Route route = 4;
 
 
-    pub fn get_support_mem_hotplug_probe(&self) -> bool {
-        self.support_mem_hotplug_probe
+    pub fn get_route(&self) -> &super::types::Route {
+        self.route.as_ref().unwrap_or_else(|| super::types::Route::default_instance())
     }
-    pub fn clear_support_mem_hotplug_probe(&mut self) {
-        self.support_mem_hotplug_probe = false;
+    pub fn clear_route(&mut self) {
+        self.route.clear();
+    }
+
+    pub fn has_route(&self) -> bool {
+        self.route.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_support_mem_hotplug_probe(&mut self, v: bool) {
-        self.support_mem_hotplug_probe = v;
+    pub fn set_route(&mut self, v: super::types::Route) {
+        self.route = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_route(&mut self) -> &mut super::types::Route {
+        if self.route.is_none() {
+            self.route.set_default();
+        }
+        self.route.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_route(&mut self) -> super::types::Route {
+        self.route.take().unwrap_or_else(|| super::types::Route::new())
     }
 }
 
-impl ::protobuf::Message for GuestDetailsResponse {
+impl ::protobuf::Message for NetworkEvent {
     fn is_initialized(&self) -> bool {
-        for v in &self.agent_details {
+        for v in &self.interface {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.route {
             if !v.is_initialized() {
                 return false;
             }
@@ -10482,21 +10355,20 @@ impl ::protobuf::Message for GuestDetailsResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.mem_block_size_bytes = tmp;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.agent_details)?;
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.support_mem_hotplug_probe = tmp;
+                    self.deleted = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.interface)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.route)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10510,15 +10382,19 @@ impl ::protobuf::Message for GuestDetailsResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.mem_block_size_bytes != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.mem_block_size_bytes, ::protobuf::wire_format::WireTypeVarint);
+        if self.kind != NetworkEvent_Kind::LINK {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
         }
-        if let Some(ref v) = self.agent_details.as_ref() {
+        if self.deleted != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.interface.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if self.support_mem_hotplug_probe != false {
-            my_size += 2;
+        if let Some(ref v) = self.route.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -10526,16 +10402,21 @@ impl ::protobuf::Message for GuestDetailsResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.mem_block_size_bytes != 0 {
-            os.write_uint64(1, self.mem_block_size_bytes)?;
+        if self.kind != NetworkEvent_Kind::LINK {
+            os.write_enum(1, self.kind.value())?;
         }
-        if let Some(ref v) = self.agent_details.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if self.deleted != false {
+            os.write_bool(2, self.deleted)?;
+        }
+        if let Some(ref v) = self.interface.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        if self.support_mem_hotplug_probe != false {
-            os.write_bool(3, self.support_mem_hotplug_probe)?;
+        if let Some(ref v) = self.route.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10567,8 +10448,8 @@ impl ::protobuf::Message for GuestDetailsResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> GuestDetailsResponse {
-        GuestDetailsResponse::new()
+    fn new() -> NetworkEvent {
+        NetworkEvent::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10579,23 +10460,28 @@ impl ::protobuf::Message for GuestDetailsResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "mem_block_size_bytes",
-                    |m: &GuestDetailsResponse| { &m.mem_block_size_bytes },
-                    |m: &mut GuestDetailsResponse| { &mut m.mem_block_size_bytes },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AgentDetails>>(
-                    "agent_details",
-                    |m: &GuestDetailsResponse| { &m.agent_details },
-                    |m: &mut GuestDetailsResponse| { &mut m.agent_details },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<NetworkEvent_Kind>>(
+                    "kind",
+                    |m: &NetworkEvent| { &m.kind },
+                    |m: &mut NetworkEvent| { &mut m.kind },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "support_mem_hotplug_probe",
-                    |m: &GuestDetailsResponse| { &m.support_mem_hotplug_probe },
-                    |m: &mut GuestDetailsResponse| { &mut m.support_mem_hotplug_probe },
+                    "deleted",
+                    |m: &NetworkEvent| { &m.deleted },
+                    |m: &mut NetworkEvent| { &mut m.deleted },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsResponse>(
-                    "GuestDetailsResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Interface>>(
+                    "interface",
+                    |m: &NetworkEvent| { &m.interface },
+                    |m: &mut NetworkEvent| { &mut m.interface },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::types::Route>>(
+                    "route",
+                    |m: &NetworkEvent| { &m.route },
+                    |m: &mut NetworkEvent| { &mut m.route },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<NetworkEvent>(
+                    "NetworkEvent",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10603,85 +10489,166 @@ impl ::protobuf::Message for GuestDetailsResponse {
         }
     }
 
-    fn default_instance() -> &'static GuestDetailsResponse {
-        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static NetworkEvent {
+        static mut instance: ::protobuf::lazy::Lazy<NetworkEvent> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GuestDetailsResponse,
+            ptr: 0 as *const NetworkEvent,
         };
         unsafe {
-            instance.get(GuestDetailsResponse::new)
+            instance.get(NetworkEvent::new)
         }
     }
 }
 
-impl ::protobuf::Clear for GuestDetailsResponse {
+impl ::protobuf::Clear for NetworkEvent {
     fn clear(&mut self) {
-        self.mem_block_size_bytes = 0;
-        self.agent_details.clear();
-        self.support_mem_hotplug_probe = false;
+        self.kind = NetworkEvent_Kind::LINK;
+        self.deleted = false;
+        self.interface.clear();
+        self.route.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for GuestDetailsResponse {
+impl ::std::fmt::Debug for NetworkEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GuestDetailsResponse {
+impl ::protobuf::reflect::ProtobufValue for NetworkEvent {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NetworkEvent_Kind {
+    LINK = 0,
+    ADDRESS = 1,
+    ROUTE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for NetworkEvent_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NetworkEvent_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(NetworkEvent_Kind::LINK),
+            1 => ::std::option::Option::Some(NetworkEvent_Kind::ADDRESS),
+            2 => ::std::option::Option::Some(NetworkEvent_Kind::ROUTE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NetworkEvent_Kind] = &[
+            NetworkEvent_Kind::LINK,
+            NetworkEvent_Kind::ADDRESS,
+            NetworkEvent_Kind::ROUTE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("NetworkEvent_Kind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for NetworkEvent_Kind {
+}
+
+impl ::std::default::Default for NetworkEvent_Kind {
+    fn default() -> Self {
+        NetworkEvent_Kind::LINK
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NetworkEvent_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
-pub struct MemHotplugByProbeRequest {
+pub struct OnlineCPUMemRequest {
     // message fields
-    pub memHotplugProbeAddr: ::std::vec::Vec<u64>,
+    pub wait: bool,
+    pub nb_cpus: u32,
+    pub cpu_only: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MemHotplugByProbeRequest {
-    fn default() -> &'a MemHotplugByProbeRequest {
-        <MemHotplugByProbeRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a OnlineCPUMemRequest {
+    fn default() -> &'a OnlineCPUMemRequest {
+        <OnlineCPUMemRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MemHotplugByProbeRequest {
-    pub fn new() -> MemHotplugByProbeRequest {
+impl OnlineCPUMemRequest {
+    pub fn new() -> OnlineCPUMemRequest {
         ::std::default::Default::default()
     }
 
-    // repeated uint64 memHotplugProbeAddr = 1;
+    // bool wait = 1;
 
 
-    pub fn get_memHotplugProbeAddr(&self) -> &[u64] {
-        &self.memHotplugProbeAddr
+    pub fn get_wait(&self) -> bool {
+        self.wait
     }
-    pub fn clear_memHotplugProbeAddr(&mut self) {
-        self.memHotplugProbeAddr.clear();
+    pub fn clear_wait(&mut self) {
+        self.wait = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_memHotplugProbeAddr(&mut self, v: ::std::vec::Vec<u64>) {
-        self.memHotplugProbeAddr = v;
+    pub fn set_wait(&mut self, v: bool) {
+        self.wait = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_memHotplugProbeAddr(&mut self) -> &mut ::std::vec::Vec<u64> {
-        &mut self.memHotplugProbeAddr
+    // uint32 nb_cpus = 2;
+
+
+    pub fn get_nb_cpus(&self) -> u32 {
+        self.nb_cpus
+    }
+    pub fn clear_nb_cpus(&mut self) {
+        self.nb_cpus = 0;
     }
 
-    // Take field
-    pub fn take_memHotplugProbeAddr(&mut self) -> ::std::vec::Vec<u64> {
-        ::std::mem::replace(&mut self.memHotplugProbeAddr, ::std::vec::Vec::new())
+    // Param is passed by value, moved
+    pub fn set_nb_cpus(&mut self, v: u32) {
+        self.nb_cpus = v;
+    }
+
+    // bool cpu_only = 3;
+
+
+    pub fn get_cpu_only(&self) -> bool {
+        self.cpu_only
+    }
+    pub fn clear_cpu_only(&mut self) {
+        self.cpu_only = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_only(&mut self, v: bool) {
+        self.cpu_only = v;
     }
 }
 
-impl ::protobuf::Message for MemHotplugByProbeRequest {
+impl ::protobuf::Message for OnlineCPUMemRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -10691,32 +10658,62 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.memHotplugProbeAddr)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.wait = tmp;
                 },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.nb_cpus = tmp;
                 },
-            };
-        }
-        ::std::result::Result::Ok(())
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cpu_only = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.memHotplugProbeAddr {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
+        if self.wait != false {
+            my_size += 2;
+        }
+        if self.nb_cpus != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.nb_cpus, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_only != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.memHotplugProbeAddr {
-            os.write_uint64(1, *v)?;
-        };
+        if self.wait != false {
+            os.write_bool(1, self.wait)?;
+        }
+        if self.nb_cpus != 0 {
+            os.write_uint32(2, self.nb_cpus)?;
+        }
+        if self.cpu_only != false {
+            os.write_bool(3, self.cpu_only)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -10747,8 +10744,8 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> MemHotplugByProbeRequest {
-        MemHotplugByProbeRequest::new()
+    fn new() -> OnlineCPUMemRequest {
+        OnlineCPUMemRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10759,13 +10756,23 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "memHotplugProbeAddr",
-                    |m: &MemHotplugByProbeRequest| { &m.memHotplugProbeAddr },
-                    |m: &mut MemHotplugByProbeRequest| { &mut m.memHotplugProbeAddr },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "wait",
+                    |m: &OnlineCPUMemRequest| { &m.wait },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.wait },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<MemHotplugByProbeRequest>(
-                    "MemHotplugByProbeRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "nb_cpus",
+                    |m: &OnlineCPUMemRequest| { &m.nb_cpus },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.nb_cpus },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "cpu_only",
+                    |m: &OnlineCPUMemRequest| { &m.cpu_only },
+                    |m: &mut OnlineCPUMemRequest| { &mut m.cpu_only },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OnlineCPUMemRequest>(
+                    "OnlineCPUMemRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10773,89 +10780,86 @@ impl ::protobuf::Message for MemHotplugByProbeRequest {
         }
     }
 
-    fn default_instance() -> &'static MemHotplugByProbeRequest {
-        static mut instance: ::protobuf::lazy::Lazy<MemHotplugByProbeRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static OnlineCPUMemRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OnlineCPUMemRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MemHotplugByProbeRequest,
+            ptr: 0 as *const OnlineCPUMemRequest,
         };
         unsafe {
-            instance.get(MemHotplugByProbeRequest::new)
+            instance.get(OnlineCPUMemRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for MemHotplugByProbeRequest {
+impl ::protobuf::Clear for OnlineCPUMemRequest {
     fn clear(&mut self) {
-        self.memHotplugProbeAddr.clear();
+        self.wait = false;
+        self.nb_cpus = 0;
+        self.cpu_only = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MemHotplugByProbeRequest {
+impl ::std::fmt::Debug for OnlineCPUMemRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MemHotplugByProbeRequest {
+impl ::protobuf::reflect::ProtobufValue for OnlineCPUMemRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SetGuestDateTimeRequest {
+pub struct ReseedRandomDevRequest {
     // message fields
-    pub Sec: i64,
-    pub Usec: i64,
+    pub data: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SetGuestDateTimeRequest {
-    fn default() -> &'a SetGuestDateTimeRequest {
-        <SetGuestDateTimeRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ReseedRandomDevRequest {
+    fn default() -> &'a ReseedRandomDevRequest {
+        <ReseedRandomDevRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SetGuestDateTimeRequest {
-    pub fn new() -> SetGuestDateTimeRequest {
+impl ReseedRandomDevRequest {
+    pub fn new() -> ReseedRandomDevRequest {
         ::std::default::Default::default()
     }
 
-    // int64 Sec = 1;
+    // bytes data = 2;
 
 
-    pub fn get_Sec(&self) -> i64 {
-        self.Sec
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
     }
-    pub fn clear_Sec(&mut self) {
-        self.Sec = 0;
+    pub fn clear_data(&mut self) {
+        self.data.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_Sec(&mut self, v: i64) {
-        self.Sec = v;
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
     }
 
-    // int64 Usec = 2;
-
-
-    pub fn get_Usec(&self) -> i64 {
-        self.Usec
-    }
-    pub fn clear_Usec(&mut self) {
-        self.Usec = 0;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
     }
 
-    // Param is passed by value, moved
-    pub fn set_Usec(&mut self, v: i64) {
-        self.Usec = v;
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for SetGuestDateTimeRequest {
+impl ::protobuf::Message for ReseedRandomDevRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -10864,19 +10868,8 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.Sec = tmp;
-                },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.Usec = tmp;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -10890,11 +10883,8 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.Sec != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.Sec, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.Usec != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.Usec, ::protobuf::wire_format::WireTypeVarint);
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.data);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -10902,11 +10892,8 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.Sec != 0 {
-            os.write_int64(1, self.Sec)?;
-        }
-        if self.Usec != 0 {
-            os.write_int64(2, self.Usec)?;
+        if !self.data.is_empty() {
+            os.write_bytes(2, &self.data)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -10938,8 +10925,8 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> SetGuestDateTimeRequest {
-        SetGuestDateTimeRequest::new()
+    fn new() -> ReseedRandomDevRequest {
+        ReseedRandomDevRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -10950,18 +10937,13 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "Sec",
-                    |m: &SetGuestDateTimeRequest| { &m.Sec },
-                    |m: &mut SetGuestDateTimeRequest| { &mut m.Sec },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "Usec",
-                    |m: &SetGuestDateTimeRequest| { &m.Usec },
-                    |m: &mut SetGuestDateTimeRequest| { &mut m.Usec },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &ReseedRandomDevRequest| { &m.data },
+                    |m: &mut ReseedRandomDevRequest| { &mut m.data },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SetGuestDateTimeRequest>(
-                    "SetGuestDateTimeRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ReseedRandomDevRequest>(
+                    "ReseedRandomDevRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -10969,218 +10951,168 @@ impl ::protobuf::Message for SetGuestDateTimeRequest {
         }
     }
 
-    fn default_instance() -> &'static SetGuestDateTimeRequest {
-        static mut instance: ::protobuf::lazy::Lazy<SetGuestDateTimeRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ReseedRandomDevRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ReseedRandomDevRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SetGuestDateTimeRequest,
+            ptr: 0 as *const ReseedRandomDevRequest,
         };
         unsafe {
-            instance.get(SetGuestDateTimeRequest::new)
+            instance.get(ReseedRandomDevRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SetGuestDateTimeRequest {
+impl ::protobuf::Clear for ReseedRandomDevRequest {
     fn clear(&mut self) {
-        self.Sec = 0;
-        self.Usec = 0;
+        self.data.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SetGuestDateTimeRequest {
+impl ::std::fmt::Debug for ReseedRandomDevRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SetGuestDateTimeRequest {
+impl ::protobuf::reflect::ProtobufValue for ReseedRandomDevRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Storage {
+pub struct AgentDetails {
     // message fields
-    pub driver: ::std::string::String,
-    pub driver_options: ::protobuf::RepeatedField<::std::string::String>,
-    pub source: ::std::string::String,
-    pub fstype: ::std::string::String,
-    pub options: ::protobuf::RepeatedField<::std::string::String>,
-    pub mount_point: ::std::string::String,
+    pub version: ::std::string::String,
+    pub init_daemon: bool,
+    pub device_handlers: ::protobuf::RepeatedField<::std::string::String>,
+    pub storage_handlers: ::protobuf::RepeatedField<::std::string::String>,
+    pub supports_seccomp: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Storage {
-    fn default() -> &'a Storage {
-        <Storage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AgentDetails {
+    fn default() -> &'a AgentDetails {
+        <AgentDetails as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Storage {
-    pub fn new() -> Storage {
+impl AgentDetails {
+    pub fn new() -> AgentDetails {
         ::std::default::Default::default()
     }
 
-    // string driver = 1;
+    // string version = 1;
 
 
-    pub fn get_driver(&self) -> &str {
-        &self.driver
+    pub fn get_version(&self) -> &str {
+        &self.version
     }
-    pub fn clear_driver(&mut self) {
-        self.driver.clear();
+    pub fn clear_version(&mut self) {
+        self.version.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_driver(&mut self, v: ::std::string::String) {
-        self.driver = v;
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_driver(&mut self) -> &mut ::std::string::String {
-        &mut self.driver
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
     }
 
     // Take field
-    pub fn take_driver(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.driver, ::std::string::String::new())
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
     }
 
-    // repeated string driver_options = 2;
+    // bool init_daemon = 2;
 
 
-    pub fn get_driver_options(&self) -> &[::std::string::String] {
-        &self.driver_options
+    pub fn get_init_daemon(&self) -> bool {
+        self.init_daemon
     }
-    pub fn clear_driver_options(&mut self) {
-        self.driver_options.clear();
+    pub fn clear_init_daemon(&mut self) {
+        self.init_daemon = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_driver_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.driver_options = v;
+    pub fn set_init_daemon(&mut self, v: bool) {
+        self.init_daemon = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_driver_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.driver_options
-    }
-
-    // Take field
-    pub fn take_driver_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.driver_options, ::protobuf::RepeatedField::new())
-    }
-
-    // string source = 3;
-
-
-    pub fn get_source(&self) -> &str {
-        &self.source
-    }
-    pub fn clear_source(&mut self) {
-        self.source.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_source(&mut self, v: ::std::string::String) {
-        self.source = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_source(&mut self) -> &mut ::std::string::String {
-        &mut self.source
-    }
-
-    // Take field
-    pub fn take_source(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.source, ::std::string::String::new())
-    }
-
-    // string fstype = 4;
+    // repeated string device_handlers = 3;
 
 
-    pub fn get_fstype(&self) -> &str {
-        &self.fstype
+    pub fn get_device_handlers(&self) -> &[::std::string::String] {
+        &self.device_handlers
     }
-    pub fn clear_fstype(&mut self) {
-        self.fstype.clear();
+    pub fn clear_device_handlers(&mut self) {
+        self.device_handlers.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_fstype(&mut self, v: ::std::string::String) {
-        self.fstype = v;
+    pub fn set_device_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.device_handlers = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_fstype(&mut self) -> &mut ::std::string::String {
-        &mut self.fstype
+    pub fn mut_device_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.device_handlers
     }
 
     // Take field
-    pub fn take_fstype(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.fstype, ::std::string::String::new())
+    pub fn take_device_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.device_handlers, ::protobuf::RepeatedField::new())
     }
 
-    // repeated string options = 5;
+    // repeated string storage_handlers = 4;
 
 
-    pub fn get_options(&self) -> &[::std::string::String] {
-        &self.options
+    pub fn get_storage_handlers(&self) -> &[::std::string::String] {
+        &self.storage_handlers
     }
-    pub fn clear_options(&mut self) {
-        self.options.clear();
+    pub fn clear_storage_handlers(&mut self) {
+        self.storage_handlers.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.options = v;
+    pub fn set_storage_handlers(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.storage_handlers = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.options
+    pub fn mut_storage_handlers(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.storage_handlers
     }
 
     // Take field
-    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    pub fn take_storage_handlers(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.storage_handlers, ::protobuf::RepeatedField::new())
     }
 
-    // string mount_point = 6;
+    // bool supports_seccomp = 5;
 
 
-    pub fn get_mount_point(&self) -> &str {
-        &self.mount_point
+    pub fn get_supports_seccomp(&self) -> bool {
+        self.supports_seccomp
     }
-    pub fn clear_mount_point(&mut self) {
-        self.mount_point.clear();
+    pub fn clear_supports_seccomp(&mut self) {
+        self.supports_seccomp = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_mount_point(&mut self, v: ::std::string::String) {
-        self.mount_point = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_mount_point(&mut self) -> &mut ::std::string::String {
-        &mut self.mount_point
-    }
-
-    // Take field
-    pub fn take_mount_point(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.mount_point, ::std::string::String::new())
+    pub fn set_supports_seccomp(&mut self, v: bool) {
+        self.supports_seccomp = v;
     }
 }
 
-impl ::protobuf::Message for Storage {
+impl ::protobuf::Message for AgentDetails {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -11190,22 +11122,27 @@ impl ::protobuf::Message for Storage {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.driver)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.driver_options)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.init_daemon = tmp;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.device_handlers)?;
                 },
                 4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fstype)?;
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.storage_handlers)?;
                 },
                 5 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mount_point)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.supports_seccomp = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11219,23 +11156,20 @@ impl ::protobuf::Message for Storage {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.driver.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.driver);
-        }
-        for value in &self.driver_options {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        if !self.source.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.source);
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
         }
-        if !self.fstype.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.fstype);
+        if self.init_daemon != false {
+            my_size += 2;
         }
-        for value in &self.options {
-            my_size += ::protobuf::rt::string_size(5, &value);
+        for value in &self.device_handlers {
+            my_size += ::protobuf::rt::string_size(3, &value);
         };
-        if !self.mount_point.is_empty() {
-            my_size += ::protobuf::rt::string_size(6, &self.mount_point);
+        for value in &self.storage_handlers {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if self.supports_seccomp != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -11243,23 +11177,20 @@ impl ::protobuf::Message for Storage {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.driver.is_empty() {
-            os.write_string(1, &self.driver)?;
-        }
-        for v in &self.driver_options {
-            os.write_string(2, &v)?;
-        };
-        if !self.source.is_empty() {
-            os.write_string(3, &self.source)?;
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
         }
-        if !self.fstype.is_empty() {
-            os.write_string(4, &self.fstype)?;
+        if self.init_daemon != false {
+            os.write_bool(2, self.init_daemon)?;
         }
-        for v in &self.options {
-            os.write_string(5, &v)?;
+        for v in &self.device_handlers {
+            os.write_string(3, &v)?;
         };
-        if !self.mount_point.is_empty() {
-            os.write_string(6, &self.mount_point)?;
+        for v in &self.storage_handlers {
+            os.write_string(4, &v)?;
+        };
+        if self.supports_seccomp != false {
+            os.write_bool(5, self.supports_seccomp)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -11291,8 +11222,8 @@ impl ::protobuf::Message for Storage {
         Self::descriptor_static()
     }
 
-    fn new() -> Storage {
-        Storage::new()
+    fn new() -> AgentDetails {
+        AgentDetails::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11304,37 +11235,32 @@ impl ::protobuf::Message for Storage {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "driver",
-                    |m: &Storage| { &m.driver },
-                    |m: &mut Storage| { &mut m.driver },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "driver_options",
-                    |m: &Storage| { &m.driver_options },
-                    |m: &mut Storage| { &mut m.driver_options },
+                    "version",
+                    |m: &AgentDetails| { &m.version },
+                    |m: &mut AgentDetails| { &mut m.version },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "source",
-                    |m: &Storage| { &m.source },
-                    |m: &mut Storage| { &mut m.source },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "init_daemon",
+                    |m: &AgentDetails| { &m.init_daemon },
+                    |m: &mut AgentDetails| { &mut m.init_daemon },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "fstype",
-                    |m: &Storage| { &m.fstype },
-                    |m: &mut Storage| { &mut m.fstype },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device_handlers",
+                    |m: &AgentDetails| { &m.device_handlers },
+                    |m: &mut AgentDetails| { &mut m.device_handlers },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "options",
-                    |m: &Storage| { &m.options },
-                    |m: &mut Storage| { &mut m.options },
+                    "storage_handlers",
+                    |m: &AgentDetails| { &m.storage_handlers },
+                    |m: &mut AgentDetails| { &mut m.storage_handlers },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "mount_point",
-                    |m: &Storage| { &m.mount_point },
-                    |m: &mut Storage| { &mut m.mount_point },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "supports_seccomp",
+                    |m: &AgentDetails| { &m.supports_seccomp },
+                    |m: &mut AgentDetails| { &mut m.supports_seccomp },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Storage>(
-                    "Storage",
+                ::protobuf::reflect::MessageDescriptor::new::<AgentDetails>(
+                    "AgentDetails",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11342,196 +11268,93 @@ impl ::protobuf::Message for Storage {
         }
     }
 
-    fn default_instance() -> &'static Storage {
-        static mut instance: ::protobuf::lazy::Lazy<Storage> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static AgentDetails {
+        static mut instance: ::protobuf::lazy::Lazy<AgentDetails> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Storage,
+            ptr: 0 as *const AgentDetails,
         };
         unsafe {
-            instance.get(Storage::new)
+            instance.get(AgentDetails::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Storage {
+impl ::protobuf::Clear for AgentDetails {
     fn clear(&mut self) {
-        self.driver.clear();
-        self.driver_options.clear();
-        self.source.clear();
-        self.fstype.clear();
-        self.options.clear();
-        self.mount_point.clear();
+        self.version.clear();
+        self.init_daemon = false;
+        self.device_handlers.clear();
+        self.storage_handlers.clear();
+        self.supports_seccomp = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Storage {
+impl ::std::fmt::Debug for AgentDetails {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Storage {
+impl ::protobuf::reflect::ProtobufValue for AgentDetails {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Device {
+pub struct GuestDetailsRequest {
     // message fields
-    pub id: ::std::string::String,
-    pub field_type: ::std::string::String,
-    pub vm_path: ::std::string::String,
-    pub container_path: ::std::string::String,
-    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    pub mem_block_size: bool,
+    pub mem_hotplug_probe: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Device {
-    fn default() -> &'a Device {
-        <Device as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GuestDetailsRequest {
+    fn default() -> &'a GuestDetailsRequest {
+        <GuestDetailsRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Device {
-    pub fn new() -> Device {
+impl GuestDetailsRequest {
+    pub fn new() -> GuestDetailsRequest {
         ::std::default::Default::default()
     }
 
-    // string id = 1;
-
-
-    pub fn get_id(&self) -> &str {
-        &self.id
-    }
-    pub fn clear_id(&mut self) {
-        self.id.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        &mut self.id
-    }
-
-    // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.id, ::std::string::String::new())
-    }
-
-    // string type = 2;
-
-
-    pub fn get_field_type(&self) -> &str {
-        &self.field_type
-    }
-    pub fn clear_field_type(&mut self) {
-        self.field_type.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_field_type(&mut self, v: ::std::string::String) {
-        self.field_type = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
-        &mut self.field_type
-    }
-
-    // Take field
-    pub fn take_field_type(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
-    }
-
-    // string vm_path = 3;
-
-
-    pub fn get_vm_path(&self) -> &str {
-        &self.vm_path
-    }
-    pub fn clear_vm_path(&mut self) {
-        self.vm_path.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_vm_path(&mut self, v: ::std::string::String) {
-        self.vm_path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_vm_path(&mut self) -> &mut ::std::string::String {
-        &mut self.vm_path
-    }
-
-    // Take field
-    pub fn take_vm_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.vm_path, ::std::string::String::new())
-    }
-
-    // string container_path = 4;
+    // bool mem_block_size = 1;
 
 
-    pub fn get_container_path(&self) -> &str {
-        &self.container_path
+    pub fn get_mem_block_size(&self) -> bool {
+        self.mem_block_size
     }
-    pub fn clear_container_path(&mut self) {
-        self.container_path.clear();
+    pub fn clear_mem_block_size(&mut self) {
+        self.mem_block_size = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_container_path(&mut self, v: ::std::string::String) {
-        self.container_path = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_container_path(&mut self) -> &mut ::std::string::String {
-        &mut self.container_path
-    }
-
-    // Take field
-    pub fn take_container_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.container_path, ::std::string::String::new())
+    pub fn set_mem_block_size(&mut self, v: bool) {
+        self.mem_block_size = v;
     }
 
-    // repeated string options = 5;
+    // bool mem_hotplug_probe = 2;
 
 
-    pub fn get_options(&self) -> &[::std::string::String] {
-        &self.options
+    pub fn get_mem_hotplug_probe(&self) -> bool {
+        self.mem_hotplug_probe
     }
-    pub fn clear_options(&mut self) {
-        self.options.clear();
+    pub fn clear_mem_hotplug_probe(&mut self) {
+        self.mem_hotplug_probe = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.options = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.options
-    }
-
-    // Take field
-    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    pub fn set_mem_hotplug_probe(&mut self, v: bool) {
+        self.mem_hotplug_probe = v;
     }
 }
 
-impl ::protobuf::Message for Device {
+impl ::protobuf::Message for GuestDetailsRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -11541,19 +11364,18 @@ impl ::protobuf::Message for Device {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.mem_block_size = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.vm_path)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_path)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.mem_hotplug_probe = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11567,42 +11389,24 @@ impl ::protobuf::Message for Device {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.id);
-        }
-        if !self.field_type.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.field_type);
-        }
-        if !self.vm_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.vm_path);
+        if self.mem_block_size != false {
+            my_size += 2;
         }
-        if !self.container_path.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.container_path);
+        if self.mem_hotplug_probe != false {
+            my_size += 2;
         }
-        for value in &self.options {
-            my_size += ::protobuf::rt::string_size(5, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.id.is_empty() {
-            os.write_string(1, &self.id)?;
-        }
-        if !self.field_type.is_empty() {
-            os.write_string(2, &self.field_type)?;
-        }
-        if !self.vm_path.is_empty() {
-            os.write_string(3, &self.vm_path)?;
+        if self.mem_block_size != false {
+            os.write_bool(1, self.mem_block_size)?;
         }
-        if !self.container_path.is_empty() {
-            os.write_string(4, &self.container_path)?;
+        if self.mem_hotplug_probe != false {
+            os.write_bool(2, self.mem_hotplug_probe)?;
         }
-        for v in &self.options {
-            os.write_string(5, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -11633,8 +11437,8 @@ impl ::protobuf::Message for Device {
         Self::descriptor_static()
     }
 
-    fn new() -> Device {
-        Device::new()
+    fn new() -> GuestDetailsRequest {
+        GuestDetailsRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11645,33 +11449,18 @@ impl ::protobuf::Message for Device {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "id",
-                    |m: &Device| { &m.id },
-                    |m: &mut Device| { &mut m.id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "type",
-                    |m: &Device| { &m.field_type },
-                    |m: &mut Device| { &mut m.field_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "vm_path",
-                    |m: &Device| { &m.vm_path },
-                    |m: &mut Device| { &mut m.vm_path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "container_path",
-                    |m: &Device| { &m.container_path },
-                    |m: &mut Device| { &mut m.container_path },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "mem_block_size",
+                    |m: &GuestDetailsRequest| { &m.mem_block_size },
+                    |m: &mut GuestDetailsRequest| { &mut m.mem_block_size },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "options",
-                    |m: &Device| { &m.options },
-                    |m: &mut Device| { &mut m.options },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "mem_hotplug_probe",
+                    |m: &GuestDetailsRequest| { &m.mem_hotplug_probe },
+                    |m: &mut GuestDetailsRequest| { &mut m.mem_hotplug_probe },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Device>(
-                    "Device",
+                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsRequest>(
+                    "GuestDetailsRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11679,142 +11468,130 @@ impl ::protobuf::Message for Device {
         }
     }
 
-    fn default_instance() -> &'static Device {
-        static mut instance: ::protobuf::lazy::Lazy<Device> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static GuestDetailsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Device,
+            ptr: 0 as *const GuestDetailsRequest,
         };
         unsafe {
-            instance.get(Device::new)
+            instance.get(GuestDetailsRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Device {
+impl ::protobuf::Clear for GuestDetailsRequest {
     fn clear(&mut self) {
-        self.id.clear();
-        self.field_type.clear();
-        self.vm_path.clear();
-        self.container_path.clear();
-        self.options.clear();
+        self.mem_block_size = false;
+        self.mem_hotplug_probe = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Device {
+impl ::std::fmt::Debug for GuestDetailsRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Device {
+impl ::protobuf::reflect::ProtobufValue for GuestDetailsRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StringUser {
+pub struct GuestDetailsResponse {
     // message fields
-    pub uid: ::std::string::String,
-    pub gid: ::std::string::String,
-    pub additionalGids: ::protobuf::RepeatedField<::std::string::String>,
+    pub mem_block_size_bytes: u64,
+    pub agent_details: ::protobuf::SingularPtrField<AgentDetails>,
+    pub support_mem_hotplug_probe: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StringUser {
-    fn default() -> &'a StringUser {
-        <StringUser as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a GuestDetailsResponse {
+    fn default() -> &'a GuestDetailsResponse {
+        <GuestDetailsResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StringUser {
-    pub fn new() -> StringUser {
+impl GuestDetailsResponse {
+    pub fn new() -> GuestDetailsResponse {
         ::std::default::Default::default()
     }
 
-    // string uid = 1;
+    // uint64 mem_block_size_bytes = 1;
 
 
-    pub fn get_uid(&self) -> &str {
-        &self.uid
+    pub fn get_mem_block_size_bytes(&self) -> u64 {
+        self.mem_block_size_bytes
     }
-    pub fn clear_uid(&mut self) {
-        self.uid.clear();
+    pub fn clear_mem_block_size_bytes(&mut self) {
+        self.mem_block_size_bytes = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_uid(&mut self, v: ::std::string::String) {
-        self.uid = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_uid(&mut self) -> &mut ::std::string::String {
-        &mut self.uid
-    }
-
-    // Take field
-    pub fn take_uid(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.uid, ::std::string::String::new())
+    pub fn set_mem_block_size_bytes(&mut self, v: u64) {
+        self.mem_block_size_bytes = v;
     }
 
-    // string gid = 2;
+    // .grpc.AgentDetails agent_details = 2;
 
 
-    pub fn get_gid(&self) -> &str {
-        &self.gid
+    pub fn get_agent_details(&self) -> &AgentDetails {
+        self.agent_details.as_ref().unwrap_or_else(|| AgentDetails::default_instance())
     }
-    pub fn clear_gid(&mut self) {
-        self.gid.clear();
+    pub fn clear_agent_details(&mut self) {
+        self.agent_details.clear();
+    }
+
+    pub fn has_agent_details(&self) -> bool {
+        self.agent_details.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_gid(&mut self, v: ::std::string::String) {
-        self.gid = v;
+    pub fn set_agent_details(&mut self, v: AgentDetails) {
+        self.agent_details = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_gid(&mut self) -> &mut ::std::string::String {
-        &mut self.gid
+    pub fn mut_agent_details(&mut self) -> &mut AgentDetails {
+        if self.agent_details.is_none() {
+            self.agent_details.set_default();
+        }
+        self.agent_details.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_gid(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.gid, ::std::string::String::new())
+    pub fn take_agent_details(&mut self) -> AgentDetails {
+        self.agent_details.take().unwrap_or_else(|| AgentDetails::new())
     }
 
-    // repeated string additionalGids = 3;
+    // bool support_mem_hotplug_probe = 3;
 
 
-    pub fn get_additionalGids(&self) -> &[::std::string::String] {
-        &self.additionalGids
+    pub fn get_support_mem_hotplug_probe(&self) -> bool {
+        self.support_mem_hotplug_probe
     }
-    pub fn clear_additionalGids(&mut self) {
-        self.additionalGids.clear();
+    pub fn clear_support_mem_hotplug_probe(&mut self) {
+        self.support_mem_hotplug_probe = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_additionalGids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.additionalGids = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_additionalGids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.additionalGids
-    }
-
-    // Take field
-    pub fn take_additionalGids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.additionalGids, ::protobuf::RepeatedField::new())
+    pub fn set_support_mem_hotplug_probe(&mut self, v: bool) {
+        self.support_mem_hotplug_probe = v;
     }
 }
 
-impl ::protobuf::Message for StringUser {
+impl ::protobuf::Message for GuestDetailsResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.agent_details {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -11823,13 +11600,21 @@ impl ::protobuf::Message for StringUser {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.uid)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.mem_block_size_bytes = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gid)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.agent_details)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.additionalGids)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.support_mem_hotplug_probe = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -11843,30 +11628,33 @@ impl ::protobuf::Message for StringUser {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.uid.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.uid);
+        if self.mem_block_size_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mem_block_size_bytes, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.gid.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.gid);
+        if let Some(ref v) = self.agent_details.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.support_mem_hotplug_probe != false {
+            my_size += 2;
         }
-        for value in &self.additionalGids {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.uid.is_empty() {
-            os.write_string(1, &self.uid)?;
+        if self.mem_block_size_bytes != 0 {
+            os.write_uint64(1, self.mem_block_size_bytes)?;
         }
-        if !self.gid.is_empty() {
-            os.write_string(2, &self.gid)?;
+        if let Some(ref v) = self.agent_details.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.support_mem_hotplug_probe != false {
+            os.write_bool(3, self.support_mem_hotplug_probe)?;
         }
-        for v in &self.additionalGids {
-            os.write_string(3, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -11897,8 +11685,8 @@ impl ::protobuf::Message for StringUser {
         Self::descriptor_static()
     }
 
-    fn new() -> StringUser {
-        StringUser::new()
+    fn new() -> GuestDetailsResponse {
+        GuestDetailsResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -11909,23 +11697,23 @@ impl ::protobuf::Message for StringUser {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "uid",
-                    |m: &StringUser| { &m.uid },
-                    |m: &mut StringUser| { &mut m.uid },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "mem_block_size_bytes",
+                    |m: &GuestDetailsResponse| { &m.mem_block_size_bytes },
+                    |m: &mut GuestDetailsResponse| { &mut m.mem_block_size_bytes },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "gid",
-                    |m: &StringUser| { &m.gid },
-                    |m: &mut StringUser| { &mut m.gid },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AgentDetails>>(
+                    "agent_details",
+                    |m: &GuestDetailsResponse| { &m.agent_details },
+                    |m: &mut GuestDetailsResponse| { &mut m.agent_details },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "additionalGids",
-                    |m: &StringUser| { &m.additionalGids },
-                    |m: &mut StringUser| { &mut m.additionalGids },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "support_mem_hotplug_probe",
+                    |m: &GuestDetailsResponse| { &m.support_mem_hotplug_probe },
+                    |m: &mut GuestDetailsResponse| { &mut m.support_mem_hotplug_probe },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<StringUser>(
-                    "StringUser",
+                ::protobuf::reflect::MessageDescriptor::new::<GuestDetailsResponse>(
+                    "GuestDetailsResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -11933,209 +11721,259 @@ impl ::protobuf::Message for StringUser {
         }
     }
 
-    fn default_instance() -> &'static StringUser {
-        static mut instance: ::protobuf::lazy::Lazy<StringUser> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static GuestDetailsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<GuestDetailsResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const StringUser,
+            ptr: 0 as *const GuestDetailsResponse,
         };
         unsafe {
-            instance.get(StringUser::new)
+            instance.get(GuestDetailsResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for StringUser {
+impl ::protobuf::Clear for GuestDetailsResponse {
     fn clear(&mut self) {
-        self.uid.clear();
-        self.gid.clear();
-        self.additionalGids.clear();
+        self.mem_block_size_bytes = 0;
+        self.agent_details.clear();
+        self.support_mem_hotplug_probe = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StringUser {
+impl ::std::fmt::Debug for GuestDetailsResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StringUser {
+impl ::protobuf::reflect::ProtobufValue for GuestDetailsResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CopyFileRequest {
+pub struct MemHotplugByProbeRequest {
     // message fields
-    pub path: ::std::string::String,
-    pub file_size: i64,
-    pub file_mode: u32,
-    pub dir_mode: u32,
-    pub uid: i32,
-    pub gid: i32,
-    pub offset: i64,
-    pub data: ::std::vec::Vec<u8>,
+    pub memHotplugProbeAddr: ::std::vec::Vec<u64>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CopyFileRequest {
-    fn default() -> &'a CopyFileRequest {
-        <CopyFileRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a MemHotplugByProbeRequest {
+    fn default() -> &'a MemHotplugByProbeRequest {
+        <MemHotplugByProbeRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CopyFileRequest {
-    pub fn new() -> CopyFileRequest {
+impl MemHotplugByProbeRequest {
+    pub fn new() -> MemHotplugByProbeRequest {
         ::std::default::Default::default()
     }
 
-    // string path = 1;
+    // repeated uint64 memHotplugProbeAddr = 1;
 
 
-    pub fn get_path(&self) -> &str {
-        &self.path
+    pub fn get_memHotplugProbeAddr(&self) -> &[u64] {
+        &self.memHotplugProbeAddr
     }
-    pub fn clear_path(&mut self) {
-        self.path.clear();
+    pub fn clear_memHotplugProbeAddr(&mut self) {
+        self.memHotplugProbeAddr.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_path(&mut self, v: ::std::string::String) {
-        self.path = v;
+    pub fn set_memHotplugProbeAddr(&mut self, v: ::std::vec::Vec<u64>) {
+        self.memHotplugProbeAddr = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_path(&mut self) -> &mut ::std::string::String {
-        &mut self.path
+    pub fn mut_memHotplugProbeAddr(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.memHotplugProbeAddr
     }
 
     // Take field
-    pub fn take_path(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    pub fn take_memHotplugProbeAddr(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.memHotplugProbeAddr, ::std::vec::Vec::new())
     }
+}
 
-    // int64 file_size = 2;
-
-
-    pub fn get_file_size(&self) -> i64 {
-        self.file_size
-    }
-    pub fn clear_file_size(&mut self) {
-        self.file_size = 0;
+impl ::protobuf::Message for MemHotplugByProbeRequest {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // Param is passed by value, moved
-    pub fn set_file_size(&mut self, v: i64) {
-        self.file_size = v;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.memHotplugProbeAddr)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // uint32 file_mode = 3;
-
-
-    pub fn get_file_mode(&self) -> u32 {
-        self.file_mode
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.memHotplugProbeAddr {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
-    pub fn clear_file_mode(&mut self) {
-        self.file_mode = 0;
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.memHotplugProbeAddr {
+            os.write_uint64(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_file_mode(&mut self, v: u32) {
-        self.file_mode = v;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // uint32 dir_mode = 4;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn get_dir_mode(&self) -> u32 {
-        self.dir_mode
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-    pub fn clear_dir_mode(&mut self) {
-        self.dir_mode = 0;
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Param is passed by value, moved
-    pub fn set_dir_mode(&mut self, v: u32) {
-        self.dir_mode = v;
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // int32 uid = 5;
+    fn new() -> MemHotplugByProbeRequest {
+        MemHotplugByProbeRequest::new()
+    }
 
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memHotplugProbeAddr",
+                    |m: &MemHotplugByProbeRequest| { &m.memHotplugProbeAddr },
+                    |m: &mut MemHotplugByProbeRequest| { &mut m.memHotplugProbeAddr },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MemHotplugByProbeRequest>(
+                    "MemHotplugByProbeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
 
-    pub fn get_uid(&self) -> i32 {
-        self.uid
+    fn default_instance() -> &'static MemHotplugByProbeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<MemHotplugByProbeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MemHotplugByProbeRequest,
+        };
+        unsafe {
+            instance.get(MemHotplugByProbeRequest::new)
+        }
     }
-    pub fn clear_uid(&mut self) {
-        self.uid = 0;
+}
+
+impl ::protobuf::Clear for MemHotplugByProbeRequest {
+    fn clear(&mut self) {
+        self.memHotplugProbeAddr.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_uid(&mut self, v: i32) {
-        self.uid = v;
+impl ::std::fmt::Debug for MemHotplugByProbeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // int32 gid = 6;
+impl ::protobuf::reflect::ProtobufValue for MemHotplugByProbeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
 
+#[derive(PartialEq,Clone,Default)]
+pub struct SetGuestDateTimeRequest {
+    // message fields
+    pub Sec: i64,
+    pub Usec: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_gid(&self) -> i32 {
-        self.gid
-    }
-    pub fn clear_gid(&mut self) {
-        self.gid = 0;
+impl<'a> ::std::default::Default for &'a SetGuestDateTimeRequest {
+    fn default() -> &'a SetGuestDateTimeRequest {
+        <SetGuestDateTimeRequest as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_gid(&mut self, v: i32) {
-        self.gid = v;
+impl SetGuestDateTimeRequest {
+    pub fn new() -> SetGuestDateTimeRequest {
+        ::std::default::Default::default()
     }
 
-    // int64 offset = 7;
+    // int64 Sec = 1;
 
 
-    pub fn get_offset(&self) -> i64 {
-        self.offset
+    pub fn get_Sec(&self) -> i64 {
+        self.Sec
     }
-    pub fn clear_offset(&mut self) {
-        self.offset = 0;
+    pub fn clear_Sec(&mut self) {
+        self.Sec = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_offset(&mut self, v: i64) {
-        self.offset = v;
+    pub fn set_Sec(&mut self, v: i64) {
+        self.Sec = v;
     }
 
-    // bytes data = 8;
+    // int64 Usec = 2;
 
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn get_Usec(&self) -> i64 {
+        self.Usec
     }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
+    pub fn clear_Usec(&mut self) {
+        self.Usec = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
-    }
-
-    // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    pub fn set_Usec(&mut self, v: i64) {
+        self.Usec = v;
     }
 }
 
-impl ::protobuf::Message for CopyFileRequest {
+impl ::protobuf::Message for SetGuestDateTimeRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -12145,53 +11983,3226 @@ impl ::protobuf::Message for CopyFileRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int64()?;
-                    self.file_size = tmp;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.file_mode = tmp;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.dir_mode = tmp;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.uid = tmp;
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.gid = tmp;
+                    self.Sec = tmp;
                 },
-                7 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int64()?;
-                    self.offset = tmp;
+                    self.Usec = tmp;
                 },
-                8 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.Sec != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.Sec, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.Usec != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.Usec, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.Sec != 0 {
+            os.write_int64(1, self.Sec)?;
+        }
+        if self.Usec != 0 {
+            os.write_int64(2, self.Usec)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetGuestDateTimeRequest {
+        SetGuestDateTimeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "Sec",
+                    |m: &SetGuestDateTimeRequest| { &m.Sec },
+                    |m: &mut SetGuestDateTimeRequest| { &mut m.Sec },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "Usec",
+                    |m: &SetGuestDateTimeRequest| { &m.Usec },
+                    |m: &mut SetGuestDateTimeRequest| { &mut m.Usec },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetGuestDateTimeRequest>(
+                    "SetGuestDateTimeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SetGuestDateTimeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SetGuestDateTimeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SetGuestDateTimeRequest,
+        };
+        unsafe {
+            instance.get(SetGuestDateTimeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SetGuestDateTimeRequest {
+    fn clear(&mut self) {
+        self.Sec = 0;
+        self.Usec = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetGuestDateTimeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetGuestDateTimeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Storage {
+    // message fields
+    pub driver: ::std::string::String,
+    pub driver_options: ::protobuf::RepeatedField<::std::string::String>,
+    pub source: ::std::string::String,
+    pub fstype: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    pub mount_point: ::std::string::String,
+    pub size_limit: u64,
+    pub fs_group: ::protobuf::SingularPtrField<FSGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Storage {
+    fn default() -> &'a Storage {
+        <Storage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Storage {
+    pub fn new() -> Storage {
+        ::std::default::Default::default()
+    }
+
+    // string driver = 1;
+
+
+    pub fn get_driver(&self) -> &str {
+        &self.driver
+    }
+    pub fn clear_driver(&mut self) {
+        self.driver.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_driver(&mut self, v: ::std::string::String) {
+        self.driver = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_driver(&mut self) -> &mut ::std::string::String {
+        &mut self.driver
+    }
+
+    // Take field
+    pub fn take_driver(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.driver, ::std::string::String::new())
+    }
+
+    // repeated string driver_options = 2;
+
+
+    pub fn get_driver_options(&self) -> &[::std::string::String] {
+        &self.driver_options
+    }
+    pub fn clear_driver_options(&mut self) {
+        self.driver_options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_driver_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.driver_options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_driver_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.driver_options
+    }
+
+    // Take field
+    pub fn take_driver_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.driver_options, ::protobuf::RepeatedField::new())
+    }
+
+    // string source = 3;
+
+
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: ::std::string::String) {
+        self.source = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut ::std::string::String {
+        &mut self.source
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source, ::std::string::String::new())
+    }
+
+    // string fstype = 4;
+
+
+    pub fn get_fstype(&self) -> &str {
+        &self.fstype
+    }
+    pub fn clear_fstype(&mut self) {
+        self.fstype.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fstype(&mut self, v: ::std::string::String) {
+        self.fstype = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fstype(&mut self) -> &mut ::std::string::String {
+        &mut self.fstype
+    }
+
+    // Take field
+    pub fn take_fstype(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fstype, ::std::string::String::new())
+    }
+
+    // repeated string options = 5;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+
+    // string mount_point = 6;
+
+
+    pub fn get_mount_point(&self) -> &str {
+        &self.mount_point
+    }
+    pub fn clear_mount_point(&mut self) {
+        self.mount_point.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mount_point(&mut self, v: ::std::string::String) {
+        self.mount_point = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mount_point(&mut self) -> &mut ::std::string::String {
+        &mut self.mount_point
+    }
+
+    // Take field
+    pub fn take_mount_point(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.mount_point, ::std::string::String::new())
+    }
+
+    // uint64 size_limit = 7;
+
+
+    pub fn get_size_limit(&self) -> u64 {
+        self.size_limit
+    }
+    pub fn clear_size_limit(&mut self) {
+        self.size_limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size_limit(&mut self, v: u64) {
+        self.size_limit = v;
+    }
+
+    // .grpc.FSGroup fs_group = 8;
+
+
+    pub fn get_fs_group(&self) -> &FSGroup {
+        self.fs_group.as_ref().unwrap_or_else(|| FSGroup::default_instance())
+    }
+    pub fn clear_fs_group(&mut self) {
+        self.fs_group.clear();
+    }
+
+    pub fn has_fs_group(&self) -> bool {
+        self.fs_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fs_group(&mut self, v: FSGroup) {
+        self.fs_group = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fs_group(&mut self) -> &mut FSGroup {
+        if self.fs_group.is_none() {
+            self.fs_group.set_default();
+        }
+        self.fs_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_fs_group(&mut self) -> FSGroup {
+        self.fs_group.take().unwrap_or_else(|| FSGroup::new())
+    }
+}
+
+impl ::protobuf::Message for Storage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fs_group {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.driver)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.driver_options)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fstype)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.mount_point)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.size_limit = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.fs_group)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.driver.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.driver);
+        }
+        for value in &self.driver_options {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if !self.source.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.source);
+        }
+        if !self.fstype.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.fstype);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if !self.mount_point.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.mount_point);
+        }
+        if self.size_limit != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.size_limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.fs_group.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.driver.is_empty() {
+            os.write_string(1, &self.driver)?;
+        }
+        for v in &self.driver_options {
+            os.write_string(2, &v)?;
+        };
+        if !self.source.is_empty() {
+            os.write_string(3, &self.source)?;
+        }
+        if !self.fstype.is_empty() {
+            os.write_string(4, &self.fstype)?;
+        }
+        for v in &self.options {
+            os.write_string(5, &v)?;
+        };
+        if !self.mount_point.is_empty() {
+            os.write_string(6, &self.mount_point)?;
+        }
+        if self.size_limit != 0 {
+            os.write_uint64(7, self.size_limit)?;
+        }
+        if let Some(ref v) = self.fs_group.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Storage {
+        Storage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "driver",
+                    |m: &Storage| { &m.driver },
+                    |m: &mut Storage| { &mut m.driver },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "driver_options",
+                    |m: &Storage| { &m.driver_options },
+                    |m: &mut Storage| { &mut m.driver_options },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "source",
+                    |m: &Storage| { &m.source },
+                    |m: &mut Storage| { &mut m.source },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "fstype",
+                    |m: &Storage| { &m.fstype },
+                    |m: &mut Storage| { &mut m.fstype },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "options",
+                    |m: &Storage| { &m.options },
+                    |m: &mut Storage| { &mut m.options },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "mount_point",
+                    |m: &Storage| { &m.mount_point },
+                    |m: &mut Storage| { &mut m.mount_point },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "size_limit",
+                    |m: &Storage| { &m.size_limit },
+                    |m: &mut Storage| { &mut m.size_limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FSGroup>>(
+                    "fs_group",
+                    |m: &Storage| { &m.fs_group },
+                    |m: &mut Storage| { &mut m.fs_group },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Storage>(
+                    "Storage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Storage {
+        static mut instance: ::protobuf::lazy::Lazy<Storage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Storage,
+        };
+        unsafe {
+            instance.get(Storage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Storage {
+    fn clear(&mut self) {
+        self.driver.clear();
+        self.driver_options.clear();
+        self.source.clear();
+        self.fstype.clear();
+        self.options.clear();
+        self.mount_point.clear();
+        self.size_limit = 0;
+        self.fs_group.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Storage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FSGroup {
+    // message fields
+    pub group_id: u32,
+    pub group_change_policy: FSGroupChangePolicy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FSGroup {
+    fn default() -> &'a FSGroup {
+        <FSGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FSGroup {
+    pub fn new() -> FSGroup {
+        ::std::default::Default::default()
+    }
+
+    // uint32 group_id = 1;
+
+
+    pub fn get_group_id(&self) -> u32 {
+        self.group_id
+    }
+    pub fn clear_group_id(&mut self) {
+        self.group_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_id(&mut self, v: u32) {
+        self.group_id = v;
+    }
+
+    // .grpc.FSGroupChangePolicy group_change_policy = 2;
+
+
+    pub fn get_group_change_policy(&self) -> FSGroupChangePolicy {
+        self.group_change_policy
+    }
+    pub fn clear_group_change_policy(&mut self) {
+        self.group_change_policy = FSGroupChangePolicy::Always;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group_change_policy(&mut self, v: FSGroupChangePolicy) {
+        self.group_change_policy = v;
+    }
+}
+
+impl ::protobuf::Message for FSGroup {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.group_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.group_change_policy, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.group_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.group_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.group_change_policy != FSGroupChangePolicy::Always {
+            my_size += ::protobuf::rt::enum_size(2, self.group_change_policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.group_id != 0 {
+            os.write_uint32(1, self.group_id)?;
+        }
+        if self.group_change_policy != FSGroupChangePolicy::Always {
+            os.write_enum(2, self.group_change_policy.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FSGroup {
+        FSGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "group_id",
+                    |m: &FSGroup| { &m.group_id },
+                    |m: &mut FSGroup| { &mut m.group_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FSGroupChangePolicy>>(
+                    "group_change_policy",
+                    |m: &FSGroup| { &m.group_change_policy },
+                    |m: &mut FSGroup| { &mut m.group_change_policy },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<FSGroup>(
+                    "FSGroup",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static FSGroup {
+        static mut instance: ::protobuf::lazy::Lazy<FSGroup> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const FSGroup,
+        };
+        unsafe {
+            instance.get(FSGroup::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for FSGroup {
+    fn clear(&mut self) {
+        self.group_id = 0;
+        self.group_change_policy = FSGroupChangePolicy::Always;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FSGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FSGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetVolumeStatsRequest {
+    // message fields
+    pub volume_guest_path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetVolumeStatsRequest {
+    fn default() -> &'a GetVolumeStatsRequest {
+        <GetVolumeStatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetVolumeStatsRequest {
+    pub fn new() -> GetVolumeStatsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string volume_guest_path = 1;
+
+
+    pub fn get_volume_guest_path(&self) -> &str {
+        &self.volume_guest_path
+    }
+    pub fn clear_volume_guest_path(&mut self) {
+        self.volume_guest_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_guest_path(&mut self, v: ::std::string::String) {
+        self.volume_guest_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_guest_path(&mut self) -> &mut ::std::string::String {
+        &mut self.volume_guest_path
+    }
+
+    // Take field
+    pub fn take_volume_guest_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.volume_guest_path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetVolumeStatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_guest_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.volume_guest_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.volume_guest_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.volume_guest_path.is_empty() {
+            os.write_string(1, &self.volume_guest_path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetVolumeStatsRequest {
+        GetVolumeStatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "volume_guest_path",
+                    |m: &GetVolumeStatsRequest| { &m.volume_guest_path },
+                    |m: &mut GetVolumeStatsRequest| { &mut m.volume_guest_path },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GetVolumeStatsRequest>(
+                    "GetVolumeStatsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetVolumeStatsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetVolumeStatsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetVolumeStatsRequest,
+        };
+        unsafe {
+            instance.get(GetVolumeStatsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetVolumeStatsRequest {
+    fn clear(&mut self) {
+        self.volume_guest_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetVolumeStatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetVolumeStatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VolumeStats {
+    // message fields
+    pub volume_guest_path: ::std::string::String,
+    pub capacity_bytes: u64,
+    pub available_bytes: u64,
+    pub used_bytes: u64,
+    pub inodes: u64,
+    pub inodes_free: u64,
+    pub inodes_used: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VolumeStats {
+    fn default() -> &'a VolumeStats {
+        <VolumeStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VolumeStats {
+    pub fn new() -> VolumeStats {
+        ::std::default::Default::default()
+    }
+
+    // string volume_guest_path = 1;
+
+
+    pub fn get_volume_guest_path(&self) -> &str {
+        &self.volume_guest_path
+    }
+    pub fn clear_volume_guest_path(&mut self) {
+        self.volume_guest_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_guest_path(&mut self, v: ::std::string::String) {
+        self.volume_guest_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_guest_path(&mut self) -> &mut ::std::string::String {
+        &mut self.volume_guest_path
+    }
+
+    // Take field
+    pub fn take_volume_guest_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.volume_guest_path, ::std::string::String::new())
+    }
+
+    // uint64 capacity_bytes = 2;
+
+
+    pub fn get_capacity_bytes(&self) -> u64 {
+        self.capacity_bytes
+    }
+    pub fn clear_capacity_bytes(&mut self) {
+        self.capacity_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capacity_bytes(&mut self, v: u64) {
+        self.capacity_bytes = v;
+    }
+
+    // uint64 available_bytes = 3;
+
+
+    pub fn get_available_bytes(&self) -> u64 {
+        self.available_bytes
+    }
+    pub fn clear_available_bytes(&mut self) {
+        self.available_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_available_bytes(&mut self, v: u64) {
+        self.available_bytes = v;
+    }
+
+    // uint64 used_bytes = 4;
+
+
+    pub fn get_used_bytes(&self) -> u64 {
+        self.used_bytes
+    }
+    pub fn clear_used_bytes(&mut self) {
+        self.used_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_used_bytes(&mut self, v: u64) {
+        self.used_bytes = v;
+    }
+
+    // uint64 inodes = 5;
+
+
+    pub fn get_inodes(&self) -> u64 {
+        self.inodes
+    }
+    pub fn clear_inodes(&mut self) {
+        self.inodes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inodes(&mut self, v: u64) {
+        self.inodes = v;
+    }
+
+    // uint64 inodes_free = 6;
+
+
+    pub fn get_inodes_free(&self) -> u64 {
+        self.inodes_free
+    }
+    pub fn clear_inodes_free(&mut self) {
+        self.inodes_free = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inodes_free(&mut self, v: u64) {
+        self.inodes_free = v;
+    }
+
+    // uint64 inodes_used = 7;
+
+
+    pub fn get_inodes_used(&self) -> u64 {
+        self.inodes_used
+    }
+    pub fn clear_inodes_used(&mut self) {
+        self.inodes_used = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inodes_used(&mut self, v: u64) {
+        self.inodes_used = v;
+    }
+}
+
+impl ::protobuf::Message for VolumeStats {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_guest_path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.capacity_bytes = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.available_bytes = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.used_bytes = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.inodes = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.inodes_free = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.inodes_used = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.volume_guest_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.volume_guest_path);
+        }
+        if self.capacity_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.capacity_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.available_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.available_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.used_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.used_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.inodes != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.inodes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.inodes_free != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.inodes_free, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.inodes_used != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.inodes_used, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.volume_guest_path.is_empty() {
+            os.write_string(1, &self.volume_guest_path)?;
+        }
+        if self.capacity_bytes != 0 {
+            os.write_uint64(2, self.capacity_bytes)?;
+        }
+        if self.available_bytes != 0 {
+            os.write_uint64(3, self.available_bytes)?;
+        }
+        if self.used_bytes != 0 {
+            os.write_uint64(4, self.used_bytes)?;
+        }
+        if self.inodes != 0 {
+            os.write_uint64(5, self.inodes)?;
+        }
+        if self.inodes_free != 0 {
+            os.write_uint64(6, self.inodes_free)?;
+        }
+        if self.inodes_used != 0 {
+            os.write_uint64(7, self.inodes_used)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VolumeStats {
+        VolumeStats::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "volume_guest_path",
+                    |m: &VolumeStats| { &m.volume_guest_path },
+                    |m: &mut VolumeStats| { &mut m.volume_guest_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "capacity_bytes",
+                    |m: &VolumeStats| { &m.capacity_bytes },
+                    |m: &mut VolumeStats| { &mut m.capacity_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "available_bytes",
+                    |m: &VolumeStats| { &m.available_bytes },
+                    |m: &mut VolumeStats| { &mut m.available_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "used_bytes",
+                    |m: &VolumeStats| { &m.used_bytes },
+                    |m: &mut VolumeStats| { &mut m.used_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "inodes",
+                    |m: &VolumeStats| { &m.inodes },
+                    |m: &mut VolumeStats| { &mut m.inodes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "inodes_free",
+                    |m: &VolumeStats| { &m.inodes_free },
+                    |m: &mut VolumeStats| { &mut m.inodes_free },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "inodes_used",
+                    |m: &VolumeStats| { &m.inodes_used },
+                    |m: &mut VolumeStats| { &mut m.inodes_used },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<VolumeStats>(
+                    "VolumeStats",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static VolumeStats {
+        static mut instance: ::protobuf::lazy::Lazy<VolumeStats> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const VolumeStats,
+        };
+        unsafe {
+            instance.get(VolumeStats::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for VolumeStats {
+    fn clear(&mut self) {
+        self.volume_guest_path.clear();
+        self.capacity_bytes = 0;
+        self.available_bytes = 0;
+        self.used_bytes = 0;
+        self.inodes = 0;
+        self.inodes_free = 0;
+        self.inodes_used = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VolumeStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VolumeStats {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VolumeStatsResponse {
+    // message fields
+    pub volumes: ::protobuf::RepeatedField<VolumeStats>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VolumeStatsResponse {
+    fn default() -> &'a VolumeStatsResponse {
+        <VolumeStatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VolumeStatsResponse {
+    pub fn new() -> VolumeStatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .grpc.VolumeStats volumes = 1;
+
+
+    pub fn get_volumes(&self) -> &[VolumeStats] {
+        &self.volumes
+    }
+    pub fn clear_volumes(&mut self) {
+        self.volumes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volumes(&mut self, v: ::protobuf::RepeatedField<VolumeStats>) {
+        self.volumes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_volumes(&mut self) -> &mut ::protobuf::RepeatedField<VolumeStats> {
+        &mut self.volumes
+    }
+
+    // Take field
+    pub fn take_volumes(&mut self) -> ::protobuf::RepeatedField<VolumeStats> {
+        ::std::mem::replace(&mut self.volumes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VolumeStatsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.volumes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.volumes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.volumes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.volumes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VolumeStatsResponse {
+        VolumeStatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VolumeStats>>(
+                    "volumes",
+                    |m: &VolumeStatsResponse| { &m.volumes },
+                    |m: &mut VolumeStatsResponse| { &mut m.volumes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<VolumeStatsResponse>(
+                    "VolumeStatsResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static VolumeStatsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<VolumeStatsResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const VolumeStatsResponse,
+        };
+        unsafe {
+            instance.get(VolumeStatsResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for VolumeStatsResponse {
+    fn clear(&mut self) {
+        self.volumes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VolumeStatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VolumeStatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResizeVolumeRequest {
+    // message fields
+    pub volume_guest_path: ::std::string::String,
+    pub size: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ResizeVolumeRequest {
+    fn default() -> &'a ResizeVolumeRequest {
+        <ResizeVolumeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ResizeVolumeRequest {
+    pub fn new() -> ResizeVolumeRequest {
+        ::std::default::Default::default()
+    }
+
+    // string volume_guest_path = 1;
+
+
+    pub fn get_volume_guest_path(&self) -> &str {
+        &self.volume_guest_path
+    }
+    pub fn clear_volume_guest_path(&mut self) {
+        self.volume_guest_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_volume_guest_path(&mut self, v: ::std::string::String) {
+        self.volume_guest_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_volume_guest_path(&mut self) -> &mut ::std::string::String {
+        &mut self.volume_guest_path
+    }
+
+    // Take field
+    pub fn take_volume_guest_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.volume_guest_path, ::std::string::String::new())
+    }
+
+    // uint64 size = 2;
+
+
+    pub fn get_size(&self) -> u64 {
+        self.size
+    }
+    pub fn clear_size(&mut self) {
+        self.size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = v;
+    }
+}
+
+impl ::protobuf::Message for ResizeVolumeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.volume_guest_path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.size = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.volume_guest_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.volume_guest_path);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.volume_guest_path.is_empty() {
+            os.write_string(1, &self.volume_guest_path)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(2, self.size)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResizeVolumeRequest {
+        ResizeVolumeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "volume_guest_path",
+                    |m: &ResizeVolumeRequest| { &m.volume_guest_path },
+                    |m: &mut ResizeVolumeRequest| { &mut m.volume_guest_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "size",
+                    |m: &ResizeVolumeRequest| { &m.size },
+                    |m: &mut ResizeVolumeRequest| { &mut m.size },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResizeVolumeRequest>(
+                    "ResizeVolumeRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ResizeVolumeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ResizeVolumeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResizeVolumeRequest,
+        };
+        unsafe {
+            instance.get(ResizeVolumeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResizeVolumeRequest {
+    fn clear(&mut self) {
+        self.volume_guest_path.clear();
+        self.size = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResizeVolumeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResizeVolumeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Device {
+    // message fields
+    pub id: ::std::string::String,
+    pub field_type: ::std::string::String,
+    pub vm_path: ::std::string::String,
+    pub container_path: ::std::string::String,
+    pub options: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Device {
+    fn default() -> &'a Device {
+        <Device as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Device {
+    pub fn new() -> Device {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string type = 2;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // string vm_path = 3;
+
+
+    pub fn get_vm_path(&self) -> &str {
+        &self.vm_path
+    }
+    pub fn clear_vm_path(&mut self) {
+        self.vm_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vm_path(&mut self, v: ::std::string::String) {
+        self.vm_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vm_path(&mut self) -> &mut ::std::string::String {
+        &mut self.vm_path
+    }
+
+    // Take field
+    pub fn take_vm_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.vm_path, ::std::string::String::new())
+    }
+
+    // string container_path = 4;
+
+
+    pub fn get_container_path(&self) -> &str {
+        &self.container_path
+    }
+    pub fn clear_container_path(&mut self) {
+        self.container_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_container_path(&mut self, v: ::std::string::String) {
+        self.container_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_container_path(&mut self) -> &mut ::std::string::String {
+        &mut self.container_path
+    }
+
+    // Take field
+    pub fn take_container_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.container_path, ::std::string::String::new())
+    }
+
+    // repeated string options = 5;
+
+
+    pub fn get_options(&self) -> &[::std::string::String] {
+        &self.options
+    }
+    pub fn clear_options(&mut self) {
+        self.options.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_options(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.options = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_options(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.options
+    }
+
+    // Take field
+    pub fn take_options(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.options, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Device {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.vm_path)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.container_path)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.options)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.field_type);
+        }
+        if !self.vm_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.vm_path);
+        }
+        if !self.container_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.container_path);
+        }
+        for value in &self.options {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.field_type.is_empty() {
+            os.write_string(2, &self.field_type)?;
+        }
+        if !self.vm_path.is_empty() {
+            os.write_string(3, &self.vm_path)?;
+        }
+        if !self.container_path.is_empty() {
+            os.write_string(4, &self.container_path)?;
+        }
+        for v in &self.options {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Device {
+        Device::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "id",
+                    |m: &Device| { &m.id },
+                    |m: &mut Device| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "type",
+                    |m: &Device| { &m.field_type },
+                    |m: &mut Device| { &mut m.field_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vm_path",
+                    |m: &Device| { &m.vm_path },
+                    |m: &mut Device| { &mut m.vm_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "container_path",
+                    |m: &Device| { &m.container_path },
+                    |m: &mut Device| { &mut m.container_path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "options",
+                    |m: &Device| { &m.options },
+                    |m: &mut Device| { &mut m.options },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Device>(
+                    "Device",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Device {
+        static mut instance: ::protobuf::lazy::Lazy<Device> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Device,
+        };
+        unsafe {
+            instance.get(Device::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Device {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.field_type.clear();
+        self.vm_path.clear();
+        self.container_path.clear();
+        self.options.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Device {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Device {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StringUser {
+    // message fields
+    pub uid: ::std::string::String,
+    pub gid: ::std::string::String,
+    pub additionalGids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StringUser {
+    fn default() -> &'a StringUser {
+        <StringUser as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StringUser {
+    pub fn new() -> StringUser {
+        ::std::default::Default::default()
+    }
+
+    // string uid = 1;
+
+
+    pub fn get_uid(&self) -> &str {
+        &self.uid
+    }
+    pub fn clear_uid(&mut self) {
+        self.uid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uid(&mut self, v: ::std::string::String) {
+        self.uid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uid(&mut self) -> &mut ::std::string::String {
+        &mut self.uid
+    }
+
+    // Take field
+    pub fn take_uid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.uid, ::std::string::String::new())
+    }
+
+    // string gid = 2;
+
+
+    pub fn get_gid(&self) -> &str {
+        &self.gid
+    }
+    pub fn clear_gid(&mut self) {
+        self.gid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gid(&mut self, v: ::std::string::String) {
+        self.gid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gid(&mut self) -> &mut ::std::string::String {
+        &mut self.gid
+    }
+
+    // Take field
+    pub fn take_gid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gid, ::std::string::String::new())
+    }
+
+    // repeated string additionalGids = 3;
+
+
+    pub fn get_additionalGids(&self) -> &[::std::string::String] {
+        &self.additionalGids
+    }
+    pub fn clear_additionalGids(&mut self) {
+        self.additionalGids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_additionalGids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.additionalGids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_additionalGids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.additionalGids
+    }
+
+    // Take field
+    pub fn take_additionalGids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.additionalGids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for StringUser {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.uid)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gid)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.additionalGids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.uid.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.uid);
+        }
+        if !self.gid.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.gid);
+        }
+        for value in &self.additionalGids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.uid.is_empty() {
+            os.write_string(1, &self.uid)?;
+        }
+        if !self.gid.is_empty() {
+            os.write_string(2, &self.gid)?;
+        }
+        for v in &self.additionalGids {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StringUser {
+        StringUser::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "uid",
+                    |m: &StringUser| { &m.uid },
+                    |m: &mut StringUser| { &mut m.uid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "gid",
+                    |m: &StringUser| { &m.gid },
+                    |m: &mut StringUser| { &mut m.gid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "additionalGids",
+                    |m: &StringUser| { &m.additionalGids },
+                    |m: &mut StringUser| { &mut m.additionalGids },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StringUser>(
+                    "StringUser",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StringUser {
+        static mut instance: ::protobuf::lazy::Lazy<StringUser> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StringUser,
+        };
+        unsafe {
+            instance.get(StringUser::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StringUser {
+    fn clear(&mut self) {
+        self.uid.clear();
+        self.gid.clear();
+        self.additionalGids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StringUser {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StringUser {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CopyFileRequest {
+    // message fields
+    pub path: ::std::string::String,
+    pub file_size: i64,
+    pub file_mode: u32,
+    pub dir_mode: u32,
+    pub uid: i32,
+    pub gid: i32,
+    pub offset: i64,
+    pub data: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CopyFileRequest {
+    fn default() -> &'a CopyFileRequest {
+        <CopyFileRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CopyFileRequest {
+    pub fn new() -> CopyFileRequest {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int64 file_size = 2;
+
+
+    pub fn get_file_size(&self) -> i64 {
+        self.file_size
+    }
+    pub fn clear_file_size(&mut self) {
+        self.file_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_size(&mut self, v: i64) {
+        self.file_size = v;
+    }
+
+    // uint32 file_mode = 3;
+
+
+    pub fn get_file_mode(&self) -> u32 {
+        self.file_mode
+    }
+    pub fn clear_file_mode(&mut self) {
+        self.file_mode = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_mode(&mut self, v: u32) {
+        self.file_mode = v;
+    }
+
+    // uint32 dir_mode = 4;
+
+
+    pub fn get_dir_mode(&self) -> u32 {
+        self.dir_mode
+    }
+    pub fn clear_dir_mode(&mut self) {
+        self.dir_mode = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir_mode(&mut self, v: u32) {
+        self.dir_mode = v;
+    }
+
+    // int32 uid = 5;
+
+
+    pub fn get_uid(&self) -> i32 {
+        self.uid
+    }
+    pub fn clear_uid(&mut self) {
+        self.uid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uid(&mut self, v: i32) {
+        self.uid = v;
+    }
+
+    // int32 gid = 6;
+
+
+    pub fn get_gid(&self) -> i32 {
+        self.gid
+    }
+    pub fn clear_gid(&mut self) {
+        self.gid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gid(&mut self, v: i32) {
+        self.gid = v;
+    }
+
+    // int64 offset = 7;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // bytes data = 8;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CopyFileRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.file_size = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.file_mode = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.dir_mode = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.uid = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.gid = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.file_size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.file_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.file_mode != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.file_mode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.dir_mode != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.dir_mode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.uid != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.uid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.gid != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.gid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.data);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.file_size != 0 {
+            os.write_int64(2, self.file_size)?;
+        }
+        if self.file_mode != 0 {
+            os.write_uint32(3, self.file_mode)?;
+        }
+        if self.dir_mode != 0 {
+            os.write_uint32(4, self.dir_mode)?;
+        }
+        if self.uid != 0 {
+            os.write_int32(5, self.uid)?;
+        }
+        if self.gid != 0 {
+            os.write_int32(6, self.gid)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(7, self.offset)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(8, &self.data)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CopyFileRequest {
+        CopyFileRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &CopyFileRequest| { &m.path },
+                    |m: &mut CopyFileRequest| { &mut m.path },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "file_size",
+                    |m: &CopyFileRequest| { &m.file_size },
+                    |m: &mut CopyFileRequest| { &mut m.file_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "file_mode",
+                    |m: &CopyFileRequest| { &m.file_mode },
+                    |m: &mut CopyFileRequest| { &mut m.file_mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "dir_mode",
+                    |m: &CopyFileRequest| { &m.dir_mode },
+                    |m: &mut CopyFileRequest| { &mut m.dir_mode },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "uid",
+                    |m: &CopyFileRequest| { &m.uid },
+                    |m: &mut CopyFileRequest| { &mut m.uid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "gid",
+                    |m: &CopyFileRequest| { &m.gid },
+                    |m: &mut CopyFileRequest| { &mut m.gid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "offset",
+                    |m: &CopyFileRequest| { &m.offset },
+                    |m: &mut CopyFileRequest| { &mut m.offset },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &CopyFileRequest| { &m.data },
+                    |m: &mut CopyFileRequest| { &mut m.data },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CopyFileRequest>(
+                    "CopyFileRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CopyFileRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CopyFileRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CopyFileRequest,
+        };
+        unsafe {
+            instance.get(CopyFileRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CopyFileRequest {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.file_size = 0;
+        self.file_mode = 0;
+        self.dir_mode = 0;
+        self.uid = 0;
+        self.gid = 0;
+        self.offset = 0;
+        self.data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CopyFileRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CopyFileRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StartTracingRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StartTracingRequest {
+    fn default() -> &'a StartTracingRequest {
+        <StartTracingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StartTracingRequest {
+    pub fn new() -> StartTracingRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for StartTracingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StartTracingRequest {
+        StartTracingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<StartTracingRequest>(
+                    "StartTracingRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StartTracingRequest {
+        static mut instance: ::protobuf::lazy::Lazy<StartTracingRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StartTracingRequest,
+        };
+        unsafe {
+            instance.get(StartTracingRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StartTracingRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StartTracingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StartTracingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StopTracingRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StopTracingRequest {
+    fn default() -> &'a StopTracingRequest {
+        <StopTracingRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StopTracingRequest {
+    pub fn new() -> StopTracingRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for StopTracingRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StopTracingRequest {
+        StopTracingRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<StopTracingRequest>(
+                    "StopTracingRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StopTracingRequest {
+        static mut instance: ::protobuf::lazy::Lazy<StopTracingRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StopTracingRequest,
+        };
+        unsafe {
+            instance.get(StopTracingRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StopTracingRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StopTracingRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StopTracingRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetLogLevelRequest {
+    // message fields
+    pub level: ::std::string::String,
+    pub subsystems: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetLogLevelRequest {
+    fn default() -> &'a SetLogLevelRequest {
+        <SetLogLevelRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetLogLevelRequest {
+    pub fn new() -> SetLogLevelRequest {
+        ::std::default::Default::default()
+    }
+
+    // string level = 1;
+
+
+    pub fn get_level(&self) -> &str {
+        &self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: ::std::string::String) {
+        self.level = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_level(&mut self) -> &mut ::std::string::String {
+        &mut self.level
+    }
+
+    // Take field
+    pub fn take_level(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.level, ::std::string::String::new())
+    }
+
+    // repeated .grpc.SetLogLevelRequest.SubsystemsEntry subsystems = 2;
+
+
+    pub fn get_subsystems(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.subsystems
+    }
+    pub fn clear_subsystems(&mut self) {
+        self.subsystems.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subsystems(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.subsystems = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subsystems(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.subsystems
+    }
+
+    // Take field
+    pub fn take_subsystems(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.subsystems, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for SetLogLevelRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.level)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.subsystems)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.level.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.level);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(2, &self.subsystems);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.level.is_empty() {
+            os.write_string(1, &self.level)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(2, &self.subsystems, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetLogLevelRequest {
+        SetLogLevelRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "level",
+                    |m: &SetLogLevelRequest| { &m.level },
+                    |m: &mut SetLogLevelRequest| { &mut m.level },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                    "subsystems",
+                    |m: &SetLogLevelRequest| { &m.subsystems },
+                    |m: &mut SetLogLevelRequest| { &mut m.subsystems },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetLogLevelRequest>(
+                    "SetLogLevelRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SetLogLevelRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SetLogLevelRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SetLogLevelRequest,
+        };
+        unsafe {
+            instance.get(SetLogLevelRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SetLogLevelRequest {
+    fn clear(&mut self) {
+        self.level.clear();
+        self.subsystems.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetLogLevelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetLogLevelRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetMetricsRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetMetricsRequest {
+    fn default() -> &'a GetMetricsRequest {
+        <GetMetricsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetMetricsRequest {
+    pub fn new() -> GetMetricsRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for GetMetricsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -12204,60 +15215,12 @@ impl ::protobuf::Message for CopyFileRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.path.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.path);
-        }
-        if self.file_size != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.file_size, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.file_mode != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.file_mode, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.dir_mode != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.dir_mode, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.uid != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.uid, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.gid != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.gid, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.offset != 0 {
-            my_size += ::protobuf::rt::value_size(7, self.offset, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(8, &self.data);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.path.is_empty() {
-            os.write_string(1, &self.path)?;
-        }
-        if self.file_size != 0 {
-            os.write_int64(2, self.file_size)?;
-        }
-        if self.file_mode != 0 {
-            os.write_uint32(3, self.file_mode)?;
-        }
-        if self.dir_mode != 0 {
-            os.write_uint32(4, self.dir_mode)?;
-        }
-        if self.uid != 0 {
-            os.write_int32(5, self.uid)?;
-        }
-        if self.gid != 0 {
-            os.write_int32(6, self.gid)?;
-        }
-        if self.offset != 0 {
-            os.write_int64(7, self.offset)?;
-        }
-        if !self.data.is_empty() {
-            os.write_bytes(8, &self.data)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -12288,8 +15251,8 @@ impl ::protobuf::Message for CopyFileRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> CopyFileRequest {
-        CopyFileRequest::new()
+    fn new() -> GetMetricsRequest {
+        GetMetricsRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -12299,49 +15262,9 @@ impl ::protobuf::Message for CopyFileRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "path",
-                    |m: &CopyFileRequest| { &m.path },
-                    |m: &mut CopyFileRequest| { &mut m.path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "file_size",
-                    |m: &CopyFileRequest| { &m.file_size },
-                    |m: &mut CopyFileRequest| { &mut m.file_size },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "file_mode",
-                    |m: &CopyFileRequest| { &m.file_mode },
-                    |m: &mut CopyFileRequest| { &mut m.file_mode },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "dir_mode",
-                    |m: &CopyFileRequest| { &m.dir_mode },
-                    |m: &mut CopyFileRequest| { &mut m.dir_mode },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "uid",
-                    |m: &CopyFileRequest| { &m.uid },
-                    |m: &mut CopyFileRequest| { &mut m.uid },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "gid",
-                    |m: &CopyFileRequest| { &m.gid },
-                    |m: &mut CopyFileRequest| { &mut m.gid },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "offset",
-                    |m: &CopyFileRequest| { &m.offset },
-                    |m: &mut CopyFileRequest| { &mut m.offset },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &CopyFileRequest| { &m.data },
-                    |m: &mut CopyFileRequest| { &mut m.data },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CopyFileRequest>(
-                    "CopyFileRequest",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<GetMetricsRequest>(
+                    "GetMetricsRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12349,63 +15272,83 @@ impl ::protobuf::Message for CopyFileRequest {
         }
     }
 
-    fn default_instance() -> &'static CopyFileRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CopyFileRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static GetMetricsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetMetricsRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CopyFileRequest,
+            ptr: 0 as *const GetMetricsRequest,
         };
         unsafe {
-            instance.get(CopyFileRequest::new)
+            instance.get(GetMetricsRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CopyFileRequest {
+impl ::protobuf::Clear for GetMetricsRequest {
     fn clear(&mut self) {
-        self.path.clear();
-        self.file_size = 0;
-        self.file_mode = 0;
-        self.dir_mode = 0;
-        self.uid = 0;
-        self.gid = 0;
-        self.offset = 0;
-        self.data.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CopyFileRequest {
+impl ::std::fmt::Debug for GetMetricsRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CopyFileRequest {
+impl ::protobuf::reflect::ProtobufValue for GetMetricsRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StartTracingRequest {
+pub struct Metrics {
+    // message fields
+    pub metrics: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StartTracingRequest {
-    fn default() -> &'a StartTracingRequest {
-        <StartTracingRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Metrics {
+    fn default() -> &'a Metrics {
+        <Metrics as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StartTracingRequest {
-    pub fn new() -> StartTracingRequest {
+impl Metrics {
+    pub fn new() -> Metrics {
         ::std::default::Default::default()
     }
+
+    // string metrics = 1;
+
+
+    pub fn get_metrics(&self) -> &str {
+        &self.metrics
+    }
+    pub fn clear_metrics(&mut self) {
+        self.metrics.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metrics(&mut self, v: ::std::string::String) {
+        self.metrics = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_metrics(&mut self) -> &mut ::std::string::String {
+        &mut self.metrics
+    }
+
+    // Take field
+    pub fn take_metrics(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.metrics, ::std::string::String::new())
+    }
 }
 
-impl ::protobuf::Message for StartTracingRequest {
+impl ::protobuf::Message for Metrics {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -12414,6 +15357,9 @@ impl ::protobuf::Message for StartTracingRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.metrics)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -12426,12 +15372,18 @@ impl ::protobuf::Message for StartTracingRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if !self.metrics.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.metrics);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.metrics.is_empty() {
+            os.write_string(1, &self.metrics)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -12462,8 +15414,8 @@ impl ::protobuf::Message for StartTracingRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> StartTracingRequest {
-        StartTracingRequest::new()
+    fn new() -> Metrics {
+        Metrics::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -12473,9 +15425,14 @@ impl ::protobuf::Message for StartTracingRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<StartTracingRequest>(
-                    "StartTracingRequest",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "metrics",
+                    |m: &Metrics| { &m.metrics },
+                    |m: &mut Metrics| { &mut m.metrics },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Metrics>(
+                    "Metrics",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12483,55 +15440,84 @@ impl ::protobuf::Message for StartTracingRequest {
         }
     }
 
-    fn default_instance() -> &'static StartTracingRequest {
-        static mut instance: ::protobuf::lazy::Lazy<StartTracingRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Metrics {
+        static mut instance: ::protobuf::lazy::Lazy<Metrics> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const StartTracingRequest,
+            ptr: 0 as *const Metrics,
         };
         unsafe {
-            instance.get(StartTracingRequest::new)
+            instance.get(Metrics::new)
         }
     }
 }
 
-impl ::protobuf::Clear for StartTracingRequest {
+impl ::protobuf::Clear for Metrics {
     fn clear(&mut self) {
+        self.metrics.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StartTracingRequest {
+impl ::std::fmt::Debug for Metrics {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StartTracingRequest {
+impl ::protobuf::reflect::ProtobufValue for Metrics {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StopTracingRequest {
+pub struct SetPolicyRequest {
+    // message fields
+    pub policy: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StopTracingRequest {
-    fn default() -> &'a StopTracingRequest {
-        <StopTracingRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetPolicyRequest {
+    fn default() -> &'a SetPolicyRequest {
+        <SetPolicyRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StopTracingRequest {
-    pub fn new() -> StopTracingRequest {
+impl SetPolicyRequest {
+    pub fn new() -> SetPolicyRequest {
         ::std::default::Default::default()
     }
+
+    // string policy = 1;
+
+
+    pub fn get_policy(&self) -> &str {
+        &self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: ::std::string::String) {
+        self.policy = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_policy(&mut self) -> &mut ::std::string::String {
+        &mut self.policy
+    }
+
+    // Take field
+    pub fn take_policy(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.policy, ::std::string::String::new())
+    }
 }
 
-impl ::protobuf::Message for StopTracingRequest {
+impl ::protobuf::Message for SetPolicyRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -12540,6 +15526,9 @@ impl ::protobuf::Message for StopTracingRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.policy)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -12552,12 +15541,18 @@ impl ::protobuf::Message for StopTracingRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if !self.policy.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.policy);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.policy.is_empty() {
+            os.write_string(1, &self.policy)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -12588,8 +15583,8 @@ impl ::protobuf::Message for StopTracingRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> StopTracingRequest {
-        StopTracingRequest::new()
+    fn new() -> SetPolicyRequest {
+        SetPolicyRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -12599,9 +15594,14 @@ impl ::protobuf::Message for StopTracingRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<StopTracingRequest>(
-                    "StopTracingRequest",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "policy",
+                    |m: &SetPolicyRequest| { &m.policy },
+                    |m: &mut SetPolicyRequest| { &mut m.policy },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SetPolicyRequest>(
+                    "SetPolicyRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -12609,35 +15609,91 @@ impl ::protobuf::Message for StopTracingRequest {
         }
     }
 
-    fn default_instance() -> &'static StopTracingRequest {
-        static mut instance: ::protobuf::lazy::Lazy<StopTracingRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SetPolicyRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SetPolicyRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const StopTracingRequest,
+            ptr: 0 as *const SetPolicyRequest,
         };
         unsafe {
-            instance.get(StopTracingRequest::new)
+            instance.get(SetPolicyRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for StopTracingRequest {
+impl ::protobuf::Clear for SetPolicyRequest {
     fn clear(&mut self) {
+        self.policy.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StopTracingRequest {
+impl ::std::fmt::Debug for SetPolicyRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StopTracingRequest {
+impl ::protobuf::reflect::ProtobufValue for SetPolicyRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FSGroupChangePolicy {
+    Always = 0,
+    OnRootMismatch = 1,
+}
+
+impl ::protobuf::ProtobufEnum for FSGroupChangePolicy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FSGroupChangePolicy> {
+        match value {
+            0 => ::std::option::Option::Some(FSGroupChangePolicy::Always),
+            1 => ::std::option::Option::Some(FSGroupChangePolicy::OnRootMismatch),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FSGroupChangePolicy] = &[
+            FSGroupChangePolicy::Always,
+            FSGroupChangePolicy::OnRootMismatch,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("FSGroupChangePolicy", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for FSGroupChangePolicy {
+}
+
+impl ::std::default::Default for FSGroupChangePolicy {
+    fn default() -> Self {
+        FSGroupChangePolicy::Always
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FSGroupChangePolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0bagent.proto\x12\x04grpc\x1a\toci.proto\x1a6github.com/kata-contain\
     ers/agent/pkg/types/types.proto\x1a\x1bgoogle/protobuf/empty.proto\"\x9d\
@@ -12752,905 +15808,1153 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     uest\x12.\n\tinterface\x18\x01\x20\x01(\x0b2\x10.types.InterfaceR\tinter\
     face\";\n\x13UpdateRoutesRequest\x12$\n\x06routes\x18\x01\x20\x01(\x0b2\
     \x0c.grpc.RoutesR\x06routes\"\x17\n\x15ListInterfacesRequest\"\x13\n\x11\
-    ListRoutesRequest\"]\n\x13OnlineCPUMemRequest\x12\x12\n\x04wait\x18\x01\
-    \x20\x01(\x08R\x04wait\x12\x17\n\x07nb_cpus\x18\x02\x20\x01(\rR\x06nbCpu\
-    s\x12\x19\n\x08cpu_only\x18\x03\x20\x01(\x08R\x07cpuOnly\",\n\x16ReseedR\
-    andomDevRequest\x12\x12\n\x04data\x18\x02\x20\x01(\x0cR\x04data\"\xc8\
-    \x01\n\x0cAgentDetails\x12\x18\n\x07version\x18\x01\x20\x01(\tR\x07versi\
-    on\x12\x1f\n\x0binit_daemon\x18\x02\x20\x01(\x08R\ninitDaemon\x12'\n\x0f\
-    device_handlers\x18\x03\x20\x03(\tR\x0edeviceHandlers\x12)\n\x10storage_\
-    handlers\x18\x04\x20\x03(\tR\x0fstorageHandlers\x12)\n\x10supports_secco\
-    mp\x18\x05\x20\x01(\x08R\x0fsupportsSeccomp\"g\n\x13GuestDetailsRequest\
-    \x12$\n\x0emem_block_size\x18\x01\x20\x01(\x08R\x0cmemBlockSize\x12*\n\
-    \x11mem_hotplug_probe\x18\x02\x20\x01(\x08R\x0fmemHotplugProbe\"\xbb\x01\
-    \n\x14GuestDetailsResponse\x12/\n\x14mem_block_size_bytes\x18\x01\x20\
-    \x01(\x04R\x11memBlockSizeBytes\x127\n\ragent_details\x18\x02\x20\x01(\
-    \x0b2\x12.grpc.AgentDetailsR\x0cagentDetails\x129\n\x19support_mem_hotpl\
-    ug_probe\x18\x03\x20\x01(\x08R\x16supportMemHotplugProbe\"L\n\x18MemHotp\
-    lugByProbeRequest\x120\n\x13memHotplugProbeAddr\x18\x01\x20\x03(\x04R\
-    \x13memHotplugProbeAddr\"?\n\x17SetGuestDateTimeRequest\x12\x10\n\x03Sec\
-    \x18\x01\x20\x01(\x03R\x03Sec\x12\x12\n\x04Usec\x18\x02\x20\x01(\x03R\
-    \x04Usec\"\xb3\x01\n\x07Storage\x12\x16\n\x06driver\x18\x01\x20\x01(\tR\
-    \x06driver\x12%\n\x0edriver_options\x18\x02\x20\x03(\tR\rdriverOptions\
-    \x12\x16\n\x06source\x18\x03\x20\x01(\tR\x06source\x12\x16\n\x06fstype\
-    \x18\x04\x20\x01(\tR\x06fstype\x12\x18\n\x07options\x18\x05\x20\x03(\tR\
-    \x07options\x12\x1f\n\x0bmount_point\x18\x06\x20\x01(\tR\nmountPoint\"\
-    \x86\x01\n\x06Device\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\
-    \x04type\x18\x02\x20\x01(\tR\x04type\x12\x17\n\x07vm_path\x18\x03\x20\
-    \x01(\tR\x06vmPath\x12%\n\x0econtainer_path\x18\x04\x20\x01(\tR\rcontain\
-    erPath\x12\x18\n\x07options\x18\x05\x20\x03(\tR\x07options\"X\n\nStringU\
-    ser\x12\x10\n\x03uid\x18\x01\x20\x01(\tR\x03uid\x12\x10\n\x03gid\x18\x02\
-    \x20\x01(\tR\x03gid\x12&\n\x0eadditionalGids\x18\x03\x20\x03(\tR\x0eaddi\
-    tionalGids\"\xca\x01\n\x0fCopyFileRequest\x12\x12\n\x04path\x18\x01\x20\
-    \x01(\tR\x04path\x12\x1b\n\tfile_size\x18\x02\x20\x01(\x03R\x08fileSize\
-    \x12\x1b\n\tfile_mode\x18\x03\x20\x01(\rR\x08fileMode\x12\x19\n\x08dir_m\
-    ode\x18\x04\x20\x01(\rR\x07dirMode\x12\x10\n\x03uid\x18\x05\x20\x01(\x05\
-    R\x03uid\x12\x10\n\x03gid\x18\x06\x20\x01(\x05R\x03gid\x12\x16\n\x06offs\
-    et\x18\x07\x20\x01(\x03R\x06offset\x12\x12\n\x04data\x18\x08\x20\x01(\
-    \x0cR\x04data\"\x15\n\x13StartTracingRequest\"\x14\n\x12StopTracingReque\
-    st2\x93\x10\n\x0cAgentService\x12G\n\x0fCreateContainer\x12\x1c.grpc.Cre\
-    ateContainerRequest\x1a\x16.google.protobuf.Empty\x12E\n\x0eStartContain\
-    er\x12\x1b.grpc.StartContainerRequest\x1a\x16.google.protobuf.Empty\x12G\
-    \n\x0fRemoveContainer\x12\x1c.grpc.RemoveContainerRequest\x1a\x16.google\
-    .protobuf.Empty\x12?\n\x0bExecProcess\x12\x18.grpc.ExecProcessRequest\
-    \x1a\x16.google.protobuf.Empty\x12C\n\rSignalProcess\x12\x1a.grpc.Signal\
-    ProcessRequest\x1a\x16.google.protobuf.Empty\x12B\n\x0bWaitProcess\x12\
-    \x18.grpc.WaitProcessRequest\x1a\x19.grpc.WaitProcessResponse\x12H\n\rLi\
-    stProcesses\x12\x1a.grpc.ListProcessesRequest\x1a\x1b.grpc.ListProcesses\
-    Response\x12G\n\x0fUpdateContainer\x12\x1c.grpc.UpdateContainerRequest\
-    \x1a\x16.google.protobuf.Empty\x12K\n\x0eStatsContainer\x12\x1b.grpc.Sta\
-    tsContainerRequest\x1a\x1c.grpc.StatsContainerResponse\x12E\n\x0ePauseCo\
-    ntainer\x12\x1b.grpc.PauseContainerRequest\x1a\x16.google.protobuf.Empty\
-    \x12G\n\x0fResumeContainer\x12\x1c.grpc.ResumeContainerRequest\x1a\x16.g\
-    oogle.protobuf.Empty\x12A\n\nWriteStdin\x12\x18.grpc.WriteStreamRequest\
-    \x1a\x19.grpc.WriteStreamResponse\x12?\n\nReadStdout\x12\x17.grpc.ReadSt\
-    reamRequest\x1a\x18.grpc.ReadStreamResponse\x12?\n\nReadStderr\x12\x17.g\
-    rpc.ReadStreamRequest\x1a\x18.grpc.ReadStreamResponse\x12=\n\nCloseStdin\
-    \x12\x17.grpc.CloseStdinRequest\x1a\x16.google.protobuf.Empty\x12A\n\x0c\
-    TtyWinResize\x12\x19.grpc.TtyWinResizeRequest\x1a\x16.google.protobuf.Em\
-    pty\x12A\n\x0fUpdateInterface\x12\x1c.grpc.UpdateInterfaceRequest\x1a\
-    \x10.types.Interface\x127\n\x0cUpdateRoutes\x12\x19.grpc.UpdateRoutesReq\
-    uest\x1a\x0c.grpc.Routes\x12?\n\x0eListInterfaces\x12\x1b.grpc.ListInter\
-    facesRequest\x1a\x10.grpc.Interfaces\x123\n\nListRoutes\x12\x17.grpc.Lis\
-    tRoutesRequest\x1a\x0c.grpc.Routes\x12A\n\x0cStartTracing\x12\x19.grpc.S\
-    tartTracingRequest\x1a\x16.google.protobuf.Empty\x12?\n\x0bStopTracing\
-    \x12\x18.grpc.StopTracingRequest\x1a\x16.google.protobuf.Empty\x12C\n\rC\
-    reateSandbox\x12\x1a.grpc.CreateSandboxRequest\x1a\x16.google.protobuf.E\
-    mpty\x12E\n\x0eDestroySandbox\x12\x1b.grpc.DestroySandboxRequest\x1a\x16\
-    .google.protobuf.Empty\x12A\n\x0cOnlineCPUMem\x12\x19.grpc.OnlineCPUMemR\
-    equest\x1a\x16.google.protobuf.Empty\x12G\n\x0fReseedRandomDev\x12\x1c.g\
-    rpc.ReseedRandomDevRequest\x1a\x16.google.protobuf.Empty\x12H\n\x0fGetGu\
-    estDetails\x12\x19.grpc.GuestDetailsRequest\x1a\x1a.grpc.GuestDetailsRes\
-    ponse\x12K\n\x11MemHotplugByProbe\x12\x1e.grpc.MemHotplugByProbeRequest\
-    \x1a\x16.google.protobuf.Empty\x12I\n\x10SetGuestDateTime\x12\x1d.grpc.S\
-    etGuestDateTimeRequest\x1a\x16.google.protobuf.Empty\x129\n\x08CopyFile\
-    \x12\x15.grpc.CopyFileRequest\x1a\x16.google.protobuf.EmptyJ\xe1\xa4\x01\
-    \n\x07\x12\x05\x06\0\xe5\x03\x01\nO\n\x01\x0c\x12\x03\x06\0\x122E\n\x20C\
-    opyright\x202017\x20HyperHQ\x20Inc.\n\n\x20SPDX-License-Identifier:\x20A\
-    pache-2.0\n\n\n\x08\n\x01\x02\x12\x03\x08\0\r\n\t\n\x02\x03\0\x12\x03\n\
-    \0\x13\n\t\n\x02\x03\x01\x12\x03\x0b\0@\n\t\n\x02\x03\x02\x12\x03\x0c\0%\
-    \n\x16\n\x02\x06\0\x12\x04\x0f\0>\x01\x1a\n\x20unstable\n\n\n\n\x03\x06\
-    \0\x01\x12\x03\x0f\x08\x14\n\x18\n\x04\x06\0\x02\0\x12\x03\x11\x08T\x1a\
-    \x0b\x20execution\n\n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\x11\x0c\x1b\n\
-    \x0c\n\x05\x06\0\x02\0\x02\x12\x03\x11\x1c2\n\x0c\n\x05\x06\0\x02\0\x03\
-    \x12\x03\x11=R\n\x0b\n\x04\x06\0\x02\x01\x12\x03\x12\x08R\n\x0c\n\x05\
-    \x06\0\x02\x01\x01\x12\x03\x12\x0c\x1a\n\x0c\n\x05\x06\0\x02\x01\x02\x12\
-    \x03\x12\x1b0\n\x0c\n\x05\x06\0\x02\x01\x03\x12\x03\x12;P\n\x9c\x03\n\
-    \x04\x06\0\x02\x02\x12\x03\x1a\x08T\x1a\x8e\x03\x20RemoveContainer\x20wi\
-    ll\x20tear\x20down\x20an\x20existing\x20container\x20by\x20forcibly\x20t\
-    erminating\n\x20all\x20processes\x20running\x20inside\x20that\x20contain\
-    er\x20and\x20releasing\x20all\x20internal\n\x20resources\x20associated\
-    \x20with\x20it.\n\x20RemoveContainer\x20will\x20wait\x20for\x20all\x20pr\
-    ocesses\x20termination\x20before\x20returning.\n\x20If\x20any\x20process\
-    \x20can\x20not\x20be\x20killed\x20or\x20if\x20it\x20can\x20not\x20be\x20\
-    killed\x20after\n\x20the\x20RemoveContainerRequest\x20timeout,\x20Remove\
-    Container\x20will\x20return\x20an\x20error.\n\n\x0c\n\x05\x06\0\x02\x02\
-    \x01\x12\x03\x1a\x0c\x1b\n\x0c\n\x05\x06\0\x02\x02\x02\x12\x03\x1a\x1c2\
-    \n\x0c\n\x05\x06\0\x02\x02\x03\x12\x03\x1a=R\n\x0b\n\x04\x06\0\x02\x03\
-    \x12\x03\x1b\x08L\n\x0c\n\x05\x06\0\x02\x03\x01\x12\x03\x1b\x0c\x17\n\
-    \x0c\n\x05\x06\0\x02\x03\x02\x12\x03\x1b\x18*\n\x0c\n\x05\x06\0\x02\x03\
-    \x03\x12\x03\x1b5J\n\x0b\n\x04\x06\0\x02\x04\x12\x03\x1c\x08P\n\x0c\n\
-    \x05\x06\0\x02\x04\x01\x12\x03\x1c\x0c\x19\n\x0c\n\x05\x06\0\x02\x04\x02\
-    \x12\x03\x1c\x1a.\n\x0c\n\x05\x06\0\x02\x04\x03\x12\x03\x1c9N\n*\n\x04\
-    \x06\0\x02\x05\x12\x03\x1d\x08J\"\x1d\x20wait\x20&\x20reap\x20like\x20wa\
-    itpid(2)\n\n\x0c\n\x05\x06\0\x02\x05\x01\x12\x03\x1d\x0c\x17\n\x0c\n\x05\
-    \x06\0\x02\x05\x02\x12\x03\x1d\x18*\n\x0c\n\x05\x06\0\x02\x05\x03\x12\
-    \x03\x1d5H\n\x0b\n\x04\x06\0\x02\x06\x12\x03\x1e\x08P\n\x0c\n\x05\x06\0\
-    \x02\x06\x01\x12\x03\x1e\x0c\x19\n\x0c\n\x05\x06\0\x02\x06\x02\x12\x03\
-    \x1e\x1a.\n\x0c\n\x05\x06\0\x02\x06\x03\x12\x03\x1e9N\n\x0b\n\x04\x06\0\
-    \x02\x07\x12\x03\x1f\x08T\n\x0c\n\x05\x06\0\x02\x07\x01\x12\x03\x1f\x0c\
-    \x1b\n\x0c\n\x05\x06\0\x02\x07\x02\x12\x03\x1f\x1c2\n\x0c\n\x05\x06\0\
-    \x02\x07\x03\x12\x03\x1f=R\n\x0b\n\x04\x06\0\x02\x08\x12\x03\x20\x08S\n\
-    \x0c\n\x05\x06\0\x02\x08\x01\x12\x03\x20\x0c\x1a\n\x0c\n\x05\x06\0\x02\
-    \x08\x02\x12\x03\x20\x1b0\n\x0c\n\x05\x06\0\x02\x08\x03\x12\x03\x20;Q\n\
-    \x0b\n\x04\x06\0\x02\t\x12\x03!\x08R\n\x0c\n\x05\x06\0\x02\t\x01\x12\x03\
-    !\x0c\x1a\n\x0c\n\x05\x06\0\x02\t\x02\x12\x03!\x1b0\n\x0c\n\x05\x06\0\
-    \x02\t\x03\x12\x03!;P\n\x0b\n\x04\x06\0\x02\n\x12\x03\"\x08T\n\x0c\n\x05\
-    \x06\0\x02\n\x01\x12\x03\"\x0c\x1b\n\x0c\n\x05\x06\0\x02\n\x02\x12\x03\"\
-    \x1c2\n\x0c\n\x05\x06\0\x02\n\x03\x12\x03\"=R\n\x14\n\x04\x06\0\x02\x0b\
-    \x12\x03%\x08I\x1a\x07\x20stdio\n\n\x0c\n\x05\x06\0\x02\x0b\x01\x12\x03%\
-    \x0c\x16\n\x0c\n\x05\x06\0\x02\x0b\x02\x12\x03%\x17)\n\x0c\n\x05\x06\0\
-    \x02\x0b\x03\x12\x03%4G\n\x0b\n\x04\x06\0\x02\x0c\x12\x03&\x08G\n\x0c\n\
-    \x05\x06\0\x02\x0c\x01\x12\x03&\x0c\x16\n\x0c\n\x05\x06\0\x02\x0c\x02\
-    \x12\x03&\x17(\n\x0c\n\x05\x06\0\x02\x0c\x03\x12\x03&3E\n\x0b\n\x04\x06\
-    \0\x02\r\x12\x03'\x08G\n\x0c\n\x05\x06\0\x02\r\x01\x12\x03'\x0c\x16\n\
-    \x0c\n\x05\x06\0\x02\r\x02\x12\x03'\x17(\n\x0c\n\x05\x06\0\x02\r\x03\x12\
-    \x03'3E\n\x0b\n\x04\x06\0\x02\x0e\x12\x03(\x08J\n\x0c\n\x05\x06\0\x02\
-    \x0e\x01\x12\x03(\x0c\x16\n\x0c\n\x05\x06\0\x02\x0e\x02\x12\x03(\x17(\n\
-    \x0c\n\x05\x06\0\x02\x0e\x03\x12\x03(3H\n\x0b\n\x04\x06\0\x02\x0f\x12\
-    \x03)\x08N\n\x0c\n\x05\x06\0\x02\x0f\x01\x12\x03)\x0c\x18\n\x0c\n\x05\
-    \x06\0\x02\x0f\x02\x12\x03)\x19,\n\x0c\n\x05\x06\0\x02\x0f\x03\x12\x03)7\
-    L\n\x19\n\x04\x06\0\x02\x10\x12\x03,\x08N\x1a\x0c\x20networking\n\n\x0c\
-    \n\x05\x06\0\x02\x10\x01\x12\x03,\x0c\x1b\n\x0c\n\x05\x06\0\x02\x10\x02\
-    \x12\x03,\x1c2\n\x0c\n\x05\x06\0\x02\x10\x03\x12\x03,=L\n\x0b\n\x04\x06\
-    \0\x02\x11\x12\x03-\x08?\n\x0c\n\x05\x06\0\x02\x11\x01\x12\x03-\x0c\x18\
-    \n\x0c\n\x05\x06\0\x02\x11\x02\x12\x03-\x19,\n\x0c\n\x05\x06\0\x02\x11\
-    \x03\x12\x03-7=\n\x0b\n\x04\x06\0\x02\x12\x12\x03.\x08F\n\x0c\n\x05\x06\
-    \0\x02\x12\x01\x12\x03.\x0c\x1a\n\x0c\n\x05\x06\0\x02\x12\x02\x12\x03.\
-    \x1b0\n\x0c\n\x05\x06\0\x02\x12\x03\x12\x03.:D\n\x0b\n\x04\x06\0\x02\x13\
-    \x12\x03/\x08;\n\x0c\n\x05\x06\0\x02\x13\x01\x12\x03/\x0c\x16\n\x0c\n\
-    \x05\x06\0\x02\x13\x02\x12\x03/\x17(\n\x0c\n\x05\x06\0\x02\x13\x03\x12\
-    \x03/39\n\x16\n\x04\x06\0\x02\x14\x12\x032\x08N\x1a\t\x20tracing\n\n\x0c\
-    \n\x05\x06\0\x02\x14\x01\x12\x032\x0c\x18\n\x0c\n\x05\x06\0\x02\x14\x02\
-    \x12\x032\x19,\n\x0c\n\x05\x06\0\x02\x14\x03\x12\x0327L\n\x0b\n\x04\x06\
-    \0\x02\x15\x12\x033\x08L\n\x0c\n\x05\x06\0\x02\x15\x01\x12\x033\x0c\x17\
-    \n\x0c\n\x05\x06\0\x02\x15\x02\x12\x033\x18*\n\x0c\n\x05\x06\0\x02\x15\
-    \x03\x12\x0335J\nH\n\x04\x06\0\x02\x16\x12\x036\x08P\x1a;\x20misc\x20(TO\
-    DO:\x20some\x20rpcs\x20can\x20be\x20replaced\x20by\x20hyperstart-exec)\n\
-    \n\x0c\n\x05\x06\0\x02\x16\x01\x12\x036\x0c\x19\n\x0c\n\x05\x06\0\x02\
-    \x16\x02\x12\x036\x1a.\n\x0c\n\x05\x06\0\x02\x16\x03\x12\x0369N\n\x0b\n\
-    \x04\x06\0\x02\x17\x12\x037\x08R\n\x0c\n\x05\x06\0\x02\x17\x01\x12\x037\
-    \x0c\x1a\n\x0c\n\x05\x06\0\x02\x17\x02\x12\x037\x1b0\n\x0c\n\x05\x06\0\
-    \x02\x17\x03\x12\x037;P\n\x0b\n\x04\x06\0\x02\x18\x12\x038\x08N\n\x0c\n\
-    \x05\x06\0\x02\x18\x01\x12\x038\x0c\x18\n\x0c\n\x05\x06\0\x02\x18\x02\
-    \x12\x038\x19,\n\x0c\n\x05\x06\0\x02\x18\x03\x12\x0387L\n\x0b\n\x04\x06\
-    \0\x02\x19\x12\x039\x08T\n\x0c\n\x05\x06\0\x02\x19\x01\x12\x039\x0c\x1b\
-    \n\x0c\n\x05\x06\0\x02\x19\x02\x12\x039\x1c2\n\x0c\n\x05\x06\0\x02\x19\
-    \x03\x12\x039=R\n\x0b\n\x04\x06\0\x02\x1a\x12\x03:\x08P\n\x0c\n\x05\x06\
-    \0\x02\x1a\x01\x12\x03:\x0c\x1b\n\x0c\n\x05\x06\0\x02\x1a\x02\x12\x03:\
-    \x1c/\n\x0c\n\x05\x06\0\x02\x1a\x03\x12\x03::N\n\x0b\n\x04\x06\0\x02\x1b\
-    \x12\x03;\x08X\n\x0c\n\x05\x06\0\x02\x1b\x01\x12\x03;\x0c\x1d\n\x0c\n\
-    \x05\x06\0\x02\x1b\x02\x12\x03;\x1e6\n\x0c\n\x05\x06\0\x02\x1b\x03\x12\
-    \x03;AV\n\x0b\n\x04\x06\0\x02\x1c\x12\x03<\x08V\n\x0c\n\x05\x06\0\x02\
-    \x1c\x01\x12\x03<\x0c\x1c\n\x0c\n\x05\x06\0\x02\x1c\x02\x12\x03<\x1d4\n\
-    \x0c\n\x05\x06\0\x02\x1c\x03\x12\x03<?T\n\x0b\n\x04\x06\0\x02\x1d\x12\
-    \x03=\x08F\n\x0c\n\x05\x06\0\x02\x1d\x01\x12\x03=\x0c\x14\n\x0c\n\x05\
-    \x06\0\x02\x1d\x02\x12\x03=\x15$\n\x0c\n\x05\x06\0\x02\x1d\x03\x12\x03=/\
-    D\n\n\n\x02\x04\0\x12\x04@\0N\x01\n\n\n\x03\x04\0\x01\x12\x03@\x08\x1e\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03A\x08\x20\n\r\n\x05\x04\0\x02\0\x04\x12\
-    \x04A\x08@\x20\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03A\x08\x0e\n\x0c\n\x05\
-    \x04\0\x02\0\x01\x12\x03A\x0f\x1b\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03A\
-    \x1e\x1f\n\x0b\n\x04\x04\0\x02\x01\x12\x03B\x08\x1b\n\r\n\x05\x04\0\x02\
-    \x01\x04\x12\x04B\x08A\x20\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03B\x08\
-    \x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03B\x0f\x16\n\x0c\n\x05\x04\0\
-    \x02\x01\x03\x12\x03B\x19\x1a\n\x0b\n\x04\x04\0\x02\x02\x12\x03C\x08#\n\
-    \r\n\x05\x04\0\x02\x02\x04\x12\x04C\x08B\x1b\n\x0c\n\x05\x04\0\x02\x02\
-    \x06\x12\x03C\x08\x12\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03C\x13\x1e\n\
-    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03C!\"\n\x0b\n\x04\x04\0\x02\x03\x12\
-    \x03D\x08$\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03D\x08\x10\n\x0c\n\x05\
-    \x04\0\x02\x03\x06\x12\x03D\x11\x17\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
-    \x03D\x18\x1f\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03D\"#\n\x0b\n\x04\x04\
-    \0\x02\x04\x12\x03E\x08&\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03E\x08\x10\
-    \n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03E\x11\x18\n\x0c\n\x05\x04\0\x02\
-    \x04\x01\x12\x03E\x19!\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03E$%\n\x0b\n\
-    \x04\x04\0\x02\x05\x12\x03F\x08\x15\n\r\n\x05\x04\0\x02\x05\x04\x12\x04F\
-    \x08E&\n\x0c\n\x05\x04\0\x02\x05\x06\x12\x03F\x08\x0c\n\x0c\n\x05\x04\0\
-    \x02\x05\x01\x12\x03F\r\x10\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03F\x13\
-    \x14\n\xba\x02\n\x04\x04\0\x02\x06\x12\x03M\x08\x1f\x1a\xac\x02\x20This\
-    \x20field\x20is\x20used\x20to\x20indicate\x20if\x20the\x20container\x20n\
-    eeds\x20to\x20join\n\x20sandbox\x20shared\x20pid\x20ns\x20or\x20create\
-    \x20a\x20new\x20namespace.\x20This\x20field\x20is\n\x20meant\x20to\x20ov\
-    erride\x20the\x20NEWPID\x20config\x20settings\x20in\x20the\x20OCI\x20spe\
-    c.\n\x20The\x20agent\x20would\x20receive\x20an\x20OCI\x20spec\x20with\
-    \x20PID\x20namespace\x20cleared\n\x20out\x20altogether\x20and\x20not\x20\
-    just\x20the\x20pid\x20ns\x20path.\n\n\r\n\x05\x04\0\x02\x06\x04\x12\x04M\
-    \x08F\x15\n\x0c\n\x05\x04\0\x02\x06\x05\x12\x03M\x08\x0c\n\x0c\n\x05\x04\
-    \0\x02\x06\x01\x12\x03M\r\x1a\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03M\x1d\
-    \x1e\n\n\n\x02\x04\x01\x12\x04P\0R\x01\n\n\n\x03\x04\x01\x01\x12\x03P\
-    \x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03Q\x08\x20\n\r\n\x05\x04\x01\
-    \x02\0\x04\x12\x04Q\x08P\x1f\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03Q\x08\
-    \x0e\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03Q\x0f\x1b\n\x0c\n\x05\x04\x01\
-    \x02\0\x03\x12\x03Q\x1e\x1f\n\n\n\x02\x04\x02\x12\x04T\0]\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\x02\x02\0\x12\x03U\x08\x20\
-    \n\r\n\x05\x04\x02\x02\0\x04\x12\x04U\x08T\x20\n\x0c\n\x05\x04\x02\x02\0\
-    \x05\x12\x03U\x08\x0e\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03U\x0f\x1b\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03U\x1e\x1f\n\xbc\x01\n\x04\x04\x02\
-    \x02\x01\x12\x03\\\x08\x1b\x1a\xae\x01\x20RemoveContainer\x20will\x20ret\
-    urn\x20an\x20error\x20if\n\x20it\x20could\x20not\x20kill\x20some\x20cont\
-    ainer\x20processes\n\x20after\x20timeout\x20seconds.\n\x20Setting\x20tim\
-    eout\x20to\x200\x20means\x20RemoveContainer\x20will\n\x20wait\x20for\x20\
-    ever.\n\n\r\n\x05\x04\x02\x02\x01\x04\x12\x04\\\x08U\x20\n\x0c\n\x05\x04\
-    \x02\x02\x01\x05\x12\x03\\\x08\x0e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\
-    \x03\\\x0f\x16\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\\\x19\x1a\n\n\n\
-    \x02\x04\x03\x12\x04_\0d\x01\n\n\n\x03\x04\x03\x01\x12\x03_\x08\x1a\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03`\x08\x20\n\r\n\x05\x04\x03\x02\0\x04\
-    \x12\x04`\x08_\x1c\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03`\x08\x0e\n\x0c\
-    \n\x05\x04\x03\x02\0\x01\x12\x03`\x0f\x1b\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x03`\x1e\x1f\n\x0b\n\x04\x04\x03\x02\x01\x12\x03a\x08\x1b\n\r\n\x05\
-    \x04\x03\x02\x01\x04\x12\x04a\x08`\x20\n\x0c\n\x05\x04\x03\x02\x01\x05\
-    \x12\x03a\x08\x0e\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03a\x0f\x16\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x03a\x19\x1a\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x03b\x08#\n\r\n\x05\x04\x03\x02\x02\x04\x12\x04b\x08a\x1b\n\x0c\n\
-    \x05\x04\x03\x02\x02\x06\x12\x03b\x08\x12\n\x0c\n\x05\x04\x03\x02\x02\
-    \x01\x12\x03b\x13\x1e\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03b!\"\n\x0b\
-    \n\x04\x04\x03\x02\x03\x12\x03c\x08\x1c\n\r\n\x05\x04\x03\x02\x03\x04\
-    \x12\x04c\x08b#\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x03c\x08\x0f\n\x0c\n\
-    \x05\x04\x03\x02\x03\x01\x12\x03c\x10\x17\n\x0c\n\x05\x04\x03\x02\x03\
-    \x03\x12\x03c\x1a\x1b\n\n\n\x02\x04\x04\x12\x04f\0n\x01\n\n\n\x03\x04\
-    \x04\x01\x12\x03f\x08\x1c\n\x0b\n\x04\x04\x04\x02\0\x12\x03g\x08\x20\n\r\
-    \n\x05\x04\x04\x02\0\x04\x12\x04g\x08f\x1e\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03g\x08\x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03g\x0f\x1b\n\x0c\n\
-    \x05\x04\x04\x02\0\x03\x12\x03g\x1e\x1f\n\xe8\x01\n\x04\x04\x04\x02\x01\
-    \x12\x03l\x08\x1b\x1a\xda\x01\x20Special\x20case\x20for\x20SignalProcess\
-    ():\x20exec_id\x20can\x20be\x20empty(\"\"),\n\x20which\x20means\x20to\
-    \x20send\x20the\x20signal\x20to\x20all\x20the\x20processes\x20including\
-    \x20their\x20descendants.\n\x20Other\x20APIs\x20with\x20exec_id\x20shoul\
-    d\x20treat\x20empty\x20exec_id\x20as\x20an\x20invalid\x20request.\n\n\r\
-    \n\x05\x04\x04\x02\x01\x04\x12\x04l\x08g\x20\n\x0c\n\x05\x04\x04\x02\x01\
-    \x05\x12\x03l\x08\x0e\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03l\x0f\x16\n\
-    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\x04\x02\
-    \x02\x12\x03m\x08\x1a\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04m\x08l\x1b\n\
-    \x0c\n\x05\x04\x04\x02\x02\x05\x12\x03m\x08\x0e\n\x0c\n\x05\x04\x04\x02\
-    \x02\x01\x12\x03m\x0f\x15\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03m\x18\
-    \x19\n\n\n\x02\x04\x05\x12\x04p\0s\x01\n\n\n\x03\x04\x05\x01\x12\x03p\
-    \x08\x1a\n\x0b\n\x04\x04\x05\x02\0\x12\x03q\x08\x20\n\r\n\x05\x04\x05\
-    \x02\0\x04\x12\x04q\x08p\x1c\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03q\x08\
-    \x0e\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03q\x0f\x1b\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03q\x1e\x1f\n\x0b\n\x04\x04\x05\x02\x01\x12\x03r\x08\x1b\
-    \n\r\n\x05\x04\x05\x02\x01\x04\x12\x04r\x08q\x20\n\x0c\n\x05\x04\x05\x02\
-    \x01\x05\x12\x03r\x08\x0e\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03r\x0f\
-    \x16\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03r\x19\x1a\n\n\n\x02\x04\x06\
-    \x12\x04u\0w\x01\n\n\n\x03\x04\x06\x01\x12\x03u\x08\x1b\n\x0b\n\x04\x04\
-    \x06\x02\0\x12\x03v\x08\x19\n\r\n\x05\x04\x06\x02\0\x04\x12\x04v\x08u\
-    \x1d\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03v\x08\r\n\x0c\n\x05\x04\x06\
-    \x02\0\x01\x12\x03v\x0e\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03v\x17\
-    \x18\nk\n\x02\x04\x07\x12\x04z\0~\x01\x1a_\x20ListProcessesRequest\x20co\
-    ntains\x20the\x20options\x20used\x20to\x20list\x20running\x20processes\
-    \x20inside\x20the\x20container\n\n\n\n\x03\x04\x07\x01\x12\x03z\x08\x1c\
-    \n\x0b\n\x04\x04\x07\x02\0\x12\x03{\x08\x20\n\r\n\x05\x04\x07\x02\0\x04\
-    \x12\x04{\x08z\x1e\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03{\x08\x0e\n\x0c\
-    \n\x05\x04\x07\x02\0\x01\x12\x03{\x0f\x1b\n\x0c\n\x05\x04\x07\x02\0\x03\
-    \x12\x03{\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x01\x12\x03|\x08\x1a\n\r\n\x05\
-    \x04\x07\x02\x01\x04\x12\x04|\x08{\x20\n\x0c\n\x05\x04\x07\x02\x01\x05\
-    \x12\x03|\x08\x0e\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03|\x0f\x15\n\x0c\
-    \n\x05\x04\x07\x02\x01\x03\x12\x03|\x18\x19\n\x0b\n\x04\x04\x07\x02\x02\
-    \x12\x03}\x08!\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x03}\x08\x10\n\x0c\n\
-    \x05\x04\x07\x02\x02\x05\x12\x03}\x11\x17\n\x0c\n\x05\x04\x07\x02\x02\
-    \x01\x12\x03}\x18\x1c\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03}\x1f\x20\n\
-    c\n\x02\x04\x08\x12\x06\x81\x01\0\x83\x01\x01\x1aU\x20ListProcessesRespo\
-    nse\x20represents\x20the\x20list\x20of\x20running\x20processes\x20inside\
-    \x20the\x20container\n\n\x0b\n\x03\x04\x08\x01\x12\x04\x81\x01\x08\x1d\n\
-    \x0c\n\x04\x04\x08\x02\0\x12\x04\x82\x01\x08\x1f\n\x0f\n\x05\x04\x08\x02\
-    \0\x04\x12\x06\x82\x01\x08\x81\x01\x1f\n\r\n\x05\x04\x08\x02\0\x05\x12\
-    \x04\x82\x01\x08\r\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x82\x01\x0e\x1a\n\
-    \r\n\x05\x04\x08\x02\0\x03\x12\x04\x82\x01\x1d\x1e\n\x0c\n\x02\x04\t\x12\
-    \x06\x85\x01\0\x88\x01\x01\n\x0b\n\x03\x04\t\x01\x12\x04\x85\x01\x08\x1e\
-    \n\x0c\n\x04\x04\t\x02\0\x12\x04\x86\x01\x08\x20\n\x0f\n\x05\x04\t\x02\0\
-    \x04\x12\x06\x86\x01\x08\x85\x01\x20\n\r\n\x05\x04\t\x02\0\x05\x12\x04\
-    \x86\x01\x08\x0e\n\r\n\x05\x04\t\x02\0\x01\x12\x04\x86\x01\x0f\x1b\n\r\n\
-    \x05\x04\t\x02\0\x03\x12\x04\x86\x01\x1e\x1f\n\x0c\n\x04\x04\t\x02\x01\
-    \x12\x04\x87\x01\x08%\n\x0f\n\x05\x04\t\x02\x01\x04\x12\x06\x87\x01\x08\
-    \x86\x01\x20\n\r\n\x05\x04\t\x02\x01\x06\x12\x04\x87\x01\x08\x16\n\r\n\
-    \x05\x04\t\x02\x01\x01\x12\x04\x87\x01\x17\x20\n\r\n\x05\x04\t\x02\x01\
-    \x03\x12\x04\x87\x01#$\n\x0c\n\x02\x04\n\x12\x06\x8a\x01\0\x8c\x01\x01\n\
-    \x0b\n\x03\x04\n\x01\x12\x04\x8a\x01\x08\x1d\n\x0c\n\x04\x04\n\x02\0\x12\
-    \x04\x8b\x01\x04\x1c\n\x0f\n\x05\x04\n\x02\0\x04\x12\x06\x8b\x01\x04\x8a\
-    \x01\x1f\n\r\n\x05\x04\n\x02\0\x05\x12\x04\x8b\x01\x04\n\n\r\n\x05\x04\n\
-    \x02\0\x01\x12\x04\x8b\x01\x0b\x17\n\r\n\x05\x04\n\x02\0\x03\x12\x04\x8b\
-    \x01\x1a\x1b\n\x0c\n\x02\x04\x0b\x12\x06\x8e\x01\0\x90\x01\x01\n\x0b\n\
-    \x03\x04\x0b\x01\x12\x04\x8e\x01\x08\x1d\n\x0c\n\x04\x04\x0b\x02\0\x12\
-    \x04\x8f\x01\x04\x1c\n\x0f\n\x05\x04\x0b\x02\0\x04\x12\x06\x8f\x01\x04\
-    \x8e\x01\x1f\n\r\n\x05\x04\x0b\x02\0\x05\x12\x04\x8f\x01\x04\n\n\r\n\x05\
-    \x04\x0b\x02\0\x01\x12\x04\x8f\x01\x0b\x17\n\r\n\x05\x04\x0b\x02\0\x03\
-    \x12\x04\x8f\x01\x1a\x1b\n\x0c\n\x02\x04\x0c\x12\x06\x92\x01\0\x94\x01\
-    \x01\n\x0b\n\x03\x04\x0c\x01\x12\x04\x92\x01\x08\x1e\n\x0c\n\x04\x04\x0c\
-    \x02\0\x12\x04\x93\x01\x04\x1c\n\x0f\n\x05\x04\x0c\x02\0\x04\x12\x06\x93\
-    \x01\x04\x92\x01\x20\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\x93\x01\x04\n\n\
-    \r\n\x05\x04\x0c\x02\0\x01\x12\x04\x93\x01\x0b\x17\n\r\n\x05\x04\x0c\x02\
-    \0\x03\x12\x04\x93\x01\x1a\x1b\n\x0c\n\x02\x04\r\x12\x06\x96\x01\0\x9b\
-    \x01\x01\n\x0b\n\x03\x04\r\x01\x12\x04\x96\x01\x08\x10\n\x0c\n\x04\x04\r\
-    \x02\0\x12\x04\x97\x01\x08\x1f\n\x0f\n\x05\x04\r\x02\0\x04\x12\x06\x97\
-    \x01\x08\x96\x01\x12\n\r\n\x05\x04\r\x02\0\x05\x12\x04\x97\x01\x08\x0e\n\
-    \r\n\x05\x04\r\x02\0\x01\x12\x04\x97\x01\x0f\x1a\n\r\n\x05\x04\r\x02\0\
-    \x03\x12\x04\x97\x01\x1d\x1e\n\x0c\n\x04\x04\r\x02\x01\x12\x04\x98\x01\
-    \x08)\n\r\n\x05\x04\r\x02\x01\x04\x12\x04\x98\x01\x08\x10\n\r\n\x05\x04\
-    \r\x02\x01\x05\x12\x04\x98\x01\x11\x17\n\r\n\x05\x04\r\x02\x01\x01\x12\
-    \x04\x98\x01\x18$\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\x98\x01'(\n\x0c\n\
-    \x04\x04\r\x02\x02\x12\x04\x99\x01\x08'\n\x0f\n\x05\x04\r\x02\x02\x04\
-    \x12\x06\x99\x01\x08\x98\x01)\n\r\n\x05\x04\r\x02\x02\x05\x12\x04\x99\
-    \x01\x08\x0e\n\r\n\x05\x04\r\x02\x02\x01\x12\x04\x99\x01\x0f\"\n\r\n\x05\
-    \x04\r\x02\x02\x03\x12\x04\x99\x01%&\n\x0c\n\x04\x04\r\x02\x03\x12\x04\
-    \x9a\x01\x08%\n\x0f\n\x05\x04\r\x02\x03\x04\x12\x06\x9a\x01\x08\x99\x01'\
-    \n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x9a\x01\x08\x0e\n\r\n\x05\x04\r\x02\
-    \x03\x01\x12\x04\x9a\x01\x0f\x20\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\x9a\
-    \x01#$\n\x0c\n\x02\x04\x0e\x12\x06\x9d\x01\0\xa1\x01\x01\n\x0b\n\x03\x04\
-    \x0e\x01\x12\x04\x9d\x01\x08\x16\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\x9e\
-    \x01\x08\x1b\n\x0f\n\x05\x04\x0e\x02\0\x04\x12\x06\x9e\x01\x08\x9d\x01\
-    \x18\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\x9e\x01\x08\x0e\n\r\n\x05\x04\
-    \x0e\x02\0\x01\x12\x04\x9e\x01\x0f\x16\n\r\n\x05\x04\x0e\x02\0\x03\x12\
-    \x04\x9e\x01\x19\x1a\n\x0c\n\x04\x04\x0e\x02\x01\x12\x04\x9f\x01\x08%\n\
-    \x0f\n\x05\x04\x0e\x02\x01\x04\x12\x06\x9f\x01\x08\x9e\x01\x1b\n\r\n\x05\
-    \x04\x0e\x02\x01\x05\x12\x04\x9f\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x01\
-    \x01\x12\x04\x9f\x01\x0f\x20\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\x9f\
-    \x01#$\n\x0c\n\x04\x04\x0e\x02\x02\x12\x04\xa0\x01\x08\"\n\x0f\n\x05\x04\
-    \x0e\x02\x02\x04\x12\x06\xa0\x01\x08\x9f\x01%\n\r\n\x05\x04\x0e\x02\x02\
-    \x05\x12\x04\xa0\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\xa0\
-    \x01\x0f\x1d\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\xa0\x01\x20!\n\x0c\n\
-    \x02\x04\x0f\x12\x06\xa3\x01\0\xa6\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\
-    \x04\xa3\x01\x08\x10\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\xa4\x01\x08\x1f\n\
-    \x0f\n\x05\x04\x0f\x02\0\x04\x12\x06\xa4\x01\x08\xa3\x01\x12\n\r\n\x05\
-    \x04\x0f\x02\0\x06\x12\x04\xa4\x01\x08\x10\n\r\n\x05\x04\x0f\x02\0\x01\
-    \x12\x04\xa4\x01\x11\x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xa4\x01\x1d\
-    \x1e\n\x0c\n\x04\x04\x0f\x02\x01\x12\x04\xa5\x01\x08+\n\x0f\n\x05\x04\
-    \x0f\x02\x01\x04\x12\x06\xa5\x01\x08\xa4\x01\x1f\n\r\n\x05\x04\x0f\x02\
-    \x01\x06\x12\x04\xa5\x01\x08\x16\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\
-    \xa5\x01\x17&\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\xa5\x01)*\n\x0c\n\
-    \x02\x04\x10\x12\x06\xa8\x01\0\xab\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\
-    \x04\xa8\x01\x08\x11\n\x0c\n\x04\x04\x10\x02\0\x12\x04\xa9\x01\x08\x1b\n\
-    \x0f\n\x05\x04\x10\x02\0\x04\x12\x06\xa9\x01\x08\xa8\x01\x13\n\r\n\x05\
-    \x04\x10\x02\0\x05\x12\x04\xa9\x01\x08\x0e\n\r\n\x05\x04\x10\x02\0\x01\
-    \x12\x04\xa9\x01\x0f\x16\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xa9\x01\x19\
-    \x1a\n\x0c\n\x04\x04\x10\x02\x01\x12\x04\xaa\x01\x08\x19\n\x0f\n\x05\x04\
-    \x10\x02\x01\x04\x12\x06\xaa\x01\x08\xa9\x01\x1b\n\r\n\x05\x04\x10\x02\
-    \x01\x05\x12\x04\xaa\x01\x08\x0e\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\
-    \xaa\x01\x0f\x14\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\xaa\x01\x17\x18\n\
-    \x0c\n\x02\x04\x11\x12\x06\xad\x01\0\xb2\x01\x01\n\x0b\n\x03\x04\x11\x01\
-    \x12\x04\xad\x01\x08\x12\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xae\x01\x08\
-    \x19\n\x0f\n\x05\x04\x11\x02\0\x04\x12\x06\xae\x01\x08\xad\x01\x14\n\r\n\
-    \x05\x04\x11\x02\0\x05\x12\x04\xae\x01\x08\x0e\n\r\n\x05\x04\x11\x02\0\
-    \x01\x12\x04\xae\x01\x0f\x14\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xae\x01\
-    \x17\x18\n\x0c\n\x04\x04\x11\x02\x01\x12\x04\xaf\x01\x08\x1d\n\x0f\n\x05\
-    \x04\x11\x02\x01\x04\x12\x06\xaf\x01\x08\xae\x01\x19\n\r\n\x05\x04\x11\
-    \x02\x01\x05\x12\x04\xaf\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x01\x01\x12\
-    \x04\xaf\x01\x0f\x18\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xaf\x01\x1b\
-    \x1c\n\x0c\n\x04\x04\x11\x02\x02\x12\x04\xb0\x01\x08\x1b\n\x0f\n\x05\x04\
-    \x11\x02\x02\x04\x12\x06\xb0\x01\x08\xaf\x01\x1d\n\r\n\x05\x04\x11\x02\
-    \x02\x05\x12\x04\xb0\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x02\x01\x12\x04\
-    \xb0\x01\x0f\x16\n\r\n\x05\x04\x11\x02\x02\x03\x12\x04\xb0\x01\x19\x1a\n\
-    \x0c\n\x04\x04\x11\x02\x03\x12\x04\xb1\x01\x08\x19\n\x0f\n\x05\x04\x11\
-    \x02\x03\x04\x12\x06\xb1\x01\x08\xb0\x01\x1b\n\r\n\x05\x04\x11\x02\x03\
-    \x05\x12\x04\xb1\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x03\x01\x12\x04\xb1\
-    \x01\x0f\x14\n\r\n\x05\x04\x11\x02\x03\x03\x12\x04\xb1\x01\x17\x18\n\x0c\
-    \n\x02\x04\x12\x12\x06\xb4\x01\0\xbb\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\
-    \x04\xb4\x01\x08\x13\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xb5\x01\x08\x19\n\
-    \x0f\n\x05\x04\x12\x02\0\x04\x12\x06\xb5\x01\x08\xb4\x01\x15\n\r\n\x05\
-    \x04\x12\x02\0\x05\x12\x04\xb5\x01\x08\x0e\n\r\n\x05\x04\x12\x02\0\x01\
-    \x12\x04\xb5\x01\x0f\x14\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xb5\x01\x17\
-    \x18\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\xb6\x01\x08\x1d\n\x0f\n\x05\x04\
-    \x12\x02\x01\x04\x12\x06\xb6\x01\x08\xb5\x01\x19\n\r\n\x05\x04\x12\x02\
-    \x01\x06\x12\x04\xb6\x01\x08\x12\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\
-    \xb6\x01\x13\x18\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xb6\x01\x1b\x1c\n\
-    \x0c\n\x04\x04\x12\x02\x02\x12\x04\xb7\x01\x08\"\n\x0f\n\x05\x04\x12\x02\
-    \x02\x04\x12\x06\xb7\x01\x08\xb6\x01\x1d\n\r\n\x05\x04\x12\x02\x02\x06\
-    \x12\x04\xb7\x01\x08\x12\n\r\n\x05\x04\x12\x02\x02\x01\x12\x04\xb7\x01\
-    \x13\x1d\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\xb7\x01\x20!\n\x0c\n\x04\
-    \x04\x12\x02\x03\x12\x04\xb8\x01\x08$\n\x0f\n\x05\x04\x12\x02\x03\x04\
-    \x12\x06\xb8\x01\x08\xb7\x01\"\n\r\n\x05\x04\x12\x02\x03\x06\x12\x04\xb8\
-    \x01\x08\x12\n\r\n\x05\x04\x12\x02\x03\x01\x12\x04\xb8\x01\x13\x1f\n\r\n\
-    \x05\x04\x12\x02\x03\x03\x12\x04\xb8\x01\"#\n\x0c\n\x04\x04\x12\x02\x04\
-    \x12\x04\xb9\x01\x08\x1f\n\x0f\n\x05\x04\x12\x02\x04\x04\x12\x06\xb9\x01\
-    \x08\xb8\x01$\n\r\n\x05\x04\x12\x02\x04\x05\x12\x04\xb9\x01\x08\x0c\n\r\
-    \n\x05\x04\x12\x02\x04\x01\x12\x04\xb9\x01\r\x1a\n\r\n\x05\x04\x12\x02\
-    \x04\x03\x12\x04\xb9\x01\x1d\x1e\n\x0c\n\x04\x04\x12\x02\x05\x12\x04\xba\
-    \x01\x08&\n\x0f\n\x05\x04\x12\x02\x05\x04\x12\x06\xba\x01\x08\xb9\x01\
-    \x1f\n\r\n\x05\x04\x12\x02\x05\x06\x12\x04\xba\x01\x08\x1b\n\r\n\x05\x04\
-    \x12\x02\x05\x01\x12\x04\xba\x01\x1c!\n\r\n\x05\x04\x12\x02\x05\x03\x12\
-    \x04\xba\x01$%\n\x0c\n\x02\x04\x13\x12\x06\xbe\x01\0\xc3\x01\x01\n\x0b\n\
-    \x03\x04\x13\x01\x12\x04\xbe\x01\x08\x17\n\x0c\n\x04\x04\x13\x02\0\x12\
-    \x04\xbf\x01\x08\x19\n\x0f\n\x05\x04\x13\x02\0\x04\x12\x06\xbf\x01\x08\
-    \xbe\x01\x19\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xbf\x01\x08\x0e\n\r\n\
-    \x05\x04\x13\x02\0\x01\x12\x04\xbf\x01\x0f\x14\n\r\n\x05\x04\x13\x02\0\
-    \x03\x12\x04\xbf\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xc0\x01\
-    \x08\x19\n\x0f\n\x05\x04\x13\x02\x01\x04\x12\x06\xc0\x01\x08\xbf\x01\x19\
-    \n\r\n\x05\x04\x13\x02\x01\x05\x12\x04\xc0\x01\x08\x0e\n\r\n\x05\x04\x13\
-    \x02\x01\x01\x12\x04\xc0\x01\x0f\x14\n\r\n\x05\x04\x13\x02\x01\x03\x12\
-    \x04\xc0\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xc1\x01\x08\x16\
-    \n\x0f\n\x05\x04\x13\x02\x02\x04\x12\x06\xc1\x01\x08\xc0\x01\x19\n\r\n\
-    \x05\x04\x13\x02\x02\x05\x12\x04\xc1\x01\x08\x0e\n\r\n\x05\x04\x13\x02\
-    \x02\x01\x12\x04\xc1\x01\x0f\x11\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\
-    \xc1\x01\x14\x15\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\xc2\x01\x08\x19\n\
-    \x0f\n\x05\x04\x13\x02\x03\x04\x12\x06\xc2\x01\x08\xc1\x01\x16\n\r\n\x05\
-    \x04\x13\x02\x03\x05\x12\x04\xc2\x01\x08\x0e\n\r\n\x05\x04\x13\x02\x03\
-    \x01\x12\x04\xc2\x01\x0f\x14\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\xc2\
-    \x01\x17\x18\n\x0c\n\x02\x04\x14\x12\x06\xc5\x01\0\xce\x01\x01\n\x0b\n\
-    \x03\x04\x14\x01\x12\x04\xc5\x01\x08\x12\nH\n\x04\x04\x14\x02\0\x12\x04\
-    \xc6\x01\x08@\":\x20number\x20of\x20bytes\x20transferred\x20to\x20and\
-    \x20from\x20the\x20block\x20device\n\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\
-    \xc6\x01\x08\x10\n\r\n\x05\x04\x14\x02\0\x06\x12\x04\xc6\x01\x11\x20\n\r\
-    \n\x05\x04\x14\x02\0\x01\x12\x04\xc6\x01!;\n\r\n\x05\x04\x14\x02\0\x03\
-    \x12\x04\xc6\x01>?\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\xc7\x01\x08;\n\r\
-    \n\x05\x04\x14\x02\x01\x04\x12\x04\xc7\x01\x08\x10\n\r\n\x05\x04\x14\x02\
-    \x01\x06\x12\x04\xc7\x01\x11\x20\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\
-    \xc7\x01!6\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\xc7\x019:\n\x0c\n\x04\
-    \x04\x14\x02\x02\x12\x04\xc8\x01\x089\n\r\n\x05\x04\x14\x02\x02\x04\x12\
-    \x04\xc8\x01\x08\x10\n\r\n\x05\x04\x14\x02\x02\x06\x12\x04\xc8\x01\x11\
-    \x20\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\xc8\x01!4\n\r\n\x05\x04\x14\
-    \x02\x02\x03\x12\x04\xc8\x0178\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\xc9\
-    \x01\x08?\n\r\n\x05\x04\x14\x02\x03\x04\x12\x04\xc9\x01\x08\x10\n\r\n\
-    \x05\x04\x14\x02\x03\x06\x12\x04\xc9\x01\x11\x20\n\r\n\x05\x04\x14\x02\
-    \x03\x01\x12\x04\xc9\x01!:\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\xc9\x01\
-    =>\n\x0c\n\x04\x04\x14\x02\x04\x12\x04\xca\x01\x08<\n\r\n\x05\x04\x14\
-    \x02\x04\x04\x12\x04\xca\x01\x08\x10\n\r\n\x05\x04\x14\x02\x04\x06\x12\
-    \x04\xca\x01\x11\x20\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\xca\x01!7\n\r\
-    \n\x05\x04\x14\x02\x04\x03\x12\x04\xca\x01:;\n\x0c\n\x04\x04\x14\x02\x05\
-    \x12\x04\xcb\x01\x089\n\r\n\x05\x04\x14\x02\x05\x04\x12\x04\xcb\x01\x08\
-    \x10\n\r\n\x05\x04\x14\x02\x05\x06\x12\x04\xcb\x01\x11\x20\n\r\n\x05\x04\
-    \x14\x02\x05\x01\x12\x04\xcb\x01!4\n\r\n\x05\x04\x14\x02\x05\x03\x12\x04\
-    \xcb\x0178\n\x0c\n\x04\x04\x14\x02\x06\x12\x04\xcc\x01\x087\n\r\n\x05\
-    \x04\x14\x02\x06\x04\x12\x04\xcc\x01\x08\x10\n\r\n\x05\x04\x14\x02\x06\
-    \x06\x12\x04\xcc\x01\x11\x20\n\r\n\x05\x04\x14\x02\x06\x01\x12\x04\xcc\
-    \x01!2\n\r\n\x05\x04\x14\x02\x06\x03\x12\x04\xcc\x0156\n\x0c\n\x04\x04\
-    \x14\x02\x07\x12\x04\xcd\x01\x087\n\r\n\x05\x04\x14\x02\x07\x04\x12\x04\
-    \xcd\x01\x08\x10\n\r\n\x05\x04\x14\x02\x07\x06\x12\x04\xcd\x01\x11\x20\n\
-    \r\n\x05\x04\x14\x02\x07\x01\x12\x04\xcd\x01!2\n\r\n\x05\x04\x14\x02\x07\
-    \x03\x12\x04\xcd\x0156\n\x0c\n\x02\x04\x15\x12\x06\xd0\x01\0\xd4\x01\x01\
-    \n\x0b\n\x03\x04\x15\x01\x12\x04\xd0\x01\x08\x14\n\x0c\n\x04\x04\x15\x02\
-    \0\x12\x04\xd1\x01\x08\x19\n\x0f\n\x05\x04\x15\x02\0\x04\x12\x06\xd1\x01\
-    \x08\xd0\x01\x16\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xd1\x01\x08\x0e\n\r\
-    \n\x05\x04\x15\x02\0\x01\x12\x04\xd1\x01\x0f\x14\n\r\n\x05\x04\x15\x02\0\
-    \x03\x12\x04\xd1\x01\x17\x18\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xd2\x01\
-    \x08\x1d\n\x0f\n\x05\x04\x15\x02\x01\x04\x12\x06\xd2\x01\x08\xd1\x01\x19\
-    \n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xd2\x01\x08\x0e\n\r\n\x05\x04\x15\
-    \x02\x01\x01\x12\x04\xd2\x01\x0f\x18\n\r\n\x05\x04\x15\x02\x01\x03\x12\
-    \x04\xd2\x01\x1b\x1c\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xd3\x01\x08\x1b\
-    \n\x0f\n\x05\x04\x15\x02\x02\x04\x12\x06\xd3\x01\x08\xd2\x01\x1d\n\r\n\
-    \x05\x04\x15\x02\x02\x05\x12\x04\xd3\x01\x08\x0e\n\r\n\x05\x04\x15\x02\
-    \x02\x01\x12\x04\xd3\x01\x0f\x16\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\
-    \xd3\x01\x19\x1a\n\x0c\n\x02\x04\x16\x12\x06\xd6\x01\0\xdd\x01\x01\n\x0b\
-    \n\x03\x04\x16\x01\x12\x04\xd6\x01\x08\x13\n\x0c\n\x04\x04\x16\x02\0\x12\
-    \x04\xd7\x01\x04\x1b\n\x0f\n\x05\x04\x16\x02\0\x04\x12\x06\xd7\x01\x04\
-    \xd6\x01\x15\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\xd7\x01\x04\x0c\n\r\n\
-    \x05\x04\x16\x02\0\x01\x12\x04\xd7\x01\r\x16\n\r\n\x05\x04\x16\x02\0\x03\
-    \x12\x04\xd7\x01\x19\x1a\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xd8\x01\x04\
-    \"\n\x0f\n\x05\x04\x16\x02\x01\x04\x12\x06\xd8\x01\x04\xd7\x01\x1b\n\r\n\
-    \x05\x04\x16\x02\x01\x06\x12\x04\xd8\x01\x04\x0f\n\r\n\x05\x04\x16\x02\
-    \x01\x01\x12\x04\xd8\x01\x10\x1c\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\
-    \xd8\x01\x20!\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xd9\x01\x04\x1d\n\x0f\
-    \n\x05\x04\x16\x02\x02\x04\x12\x06\xd9\x01\x04\xd8\x01\"\n\r\n\x05\x04\
-    \x16\x02\x02\x06\x12\x04\xd9\x01\x04\r\n\r\n\x05\x04\x16\x02\x02\x01\x12\
-    \x04\xd9\x01\x0e\x18\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\xd9\x01\x1b\
-    \x1c\n\x0c\n\x04\x04\x16\x02\x03\x12\x04\xda\x01\x04\x1f\n\x0f\n\x05\x04\
-    \x16\x02\x03\x04\x12\x06\xda\x01\x04\xd9\x01\x1d\n\r\n\x05\x04\x16\x02\
-    \x03\x06\x12\x04\xda\x01\x04\x0e\n\r\n\x05\x04\x16\x02\x03\x01\x12\x04\
-    \xda\x01\x0f\x1a\n\r\n\x05\x04\x16\x02\x03\x03\x12\x04\xda\x01\x1d\x1e\n\
-    R\n\x04\x04\x16\x02\x04\x12\x04\xdb\x01\x040\"D\x20the\x20map\x20is\x20i\
-    n\x20the\x20format\x20\"size\x20of\x20hugepage:\x20stats\x20of\x20the\
-    \x20hugepage\"\n\n\x0f\n\x05\x04\x16\x02\x04\x04\x12\x06\xdb\x01\x04\xda\
-    \x01\x1f\n\r\n\x05\x04\x16\x02\x04\x06\x12\x04\xdb\x01\x04\x1d\n\r\n\x05\
-    \x04\x16\x02\x04\x01\x12\x04\xdb\x01\x1e+\n\r\n\x05\x04\x16\x02\x04\x03\
-    \x12\x04\xdb\x01./\n\x0c\n\x02\x04\x17\x12\x06\xdf\x01\0\xe9\x01\x01\n\
-    \x0b\n\x03\x04\x17\x01\x12\x04\xdf\x01\x08\x14\n\x0c\n\x04\x04\x17\x02\0\
-    \x12\x04\xe0\x01\x08\x18\n\x0f\n\x05\x04\x17\x02\0\x04\x12\x06\xe0\x01\
-    \x08\xdf\x01\x16\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xe0\x01\x08\x0e\n\r\
-    \n\x05\x04\x17\x02\0\x01\x12\x04\xe0\x01\x0f\x13\n\r\n\x05\x04\x17\x02\0\
-    \x03\x12\x04\xe0\x01\x16\x17\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xe1\x01\
-    \x08\x1c\n\x0f\n\x05\x04\x17\x02\x01\x04\x12\x06\xe1\x01\x08\xe0\x01\x18\
-    \n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xe1\x01\x08\x0e\n\r\n\x05\x04\x17\
-    \x02\x01\x01\x12\x04\xe1\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x01\x03\x12\
-    \x04\xe1\x01\x1a\x1b\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xe2\x01\x08\x1e\
-    \n\x0f\n\x05\x04\x17\x02\x02\x04\x12\x06\xe2\x01\x08\xe1\x01\x1c\n\r\n\
-    \x05\x04\x17\x02\x02\x05\x12\x04\xe2\x01\x08\x0e\n\r\n\x05\x04\x17\x02\
-    \x02\x01\x12\x04\xe2\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\
-    \xe2\x01\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x03\x12\x04\xe3\x01\x08\x1e\n\
-    \x0f\n\x05\x04\x17\x02\x03\x04\x12\x06\xe3\x01\x08\xe2\x01\x1e\n\r\n\x05\
-    \x04\x17\x02\x03\x05\x12\x04\xe3\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x03\
-    \x01\x12\x04\xe3\x01\x0f\x18\n\r\n\x05\x04\x17\x02\x03\x03\x12\x04\xe3\
-    \x01\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x04\x12\x04\xe4\x01\x08\x1e\n\x0f\n\
-    \x05\x04\x17\x02\x04\x04\x12\x06\xe4\x01\x08\xe3\x01\x1e\n\r\n\x05\x04\
-    \x17\x02\x04\x05\x12\x04\xe4\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x04\x01\
-    \x12\x04\xe4\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x04\x03\x12\x04\xe4\x01\
-    \x1c\x1d\n\x0c\n\x04\x04\x17\x02\x05\x12\x04\xe5\x01\x08\x1c\n\x0f\n\x05\
-    \x04\x17\x02\x05\x04\x12\x06\xe5\x01\x08\xe4\x01\x1e\n\r\n\x05\x04\x17\
-    \x02\x05\x05\x12\x04\xe5\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x05\x01\x12\
-    \x04\xe5\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x05\x03\x12\x04\xe5\x01\x1a\
-    \x1b\n\x0c\n\x04\x04\x17\x02\x06\x12\x04\xe6\x01\x08\x1e\n\x0f\n\x05\x04\
-    \x17\x02\x06\x04\x12\x06\xe6\x01\x08\xe5\x01\x1c\n\r\n\x05\x04\x17\x02\
-    \x06\x05\x12\x04\xe6\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x06\x01\x12\x04\
-    \xe6\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x06\x03\x12\x04\xe6\x01\x1c\x1d\n\
-    \x0c\n\x04\x04\x17\x02\x07\x12\x04\xe7\x01\x08\x1d\n\x0f\n\x05\x04\x17\
-    \x02\x07\x04\x12\x06\xe7\x01\x08\xe6\x01\x1e\n\r\n\x05\x04\x17\x02\x07\
-    \x05\x12\x04\xe7\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x07\x01\x12\x04\xe7\
-    \x01\x0f\x18\n\r\n\x05\x04\x17\x02\x07\x03\x12\x04\xe7\x01\x1b\x1c\n\x0c\
-    \n\x04\x04\x17\x02\x08\x12\x04\xe8\x01\x08\x1e\n\x0f\n\x05\x04\x17\x02\
-    \x08\x04\x12\x06\xe8\x01\x08\xe7\x01\x1d\n\r\n\x05\x04\x17\x02\x08\x05\
-    \x12\x04\xe8\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x08\x01\x12\x04\xe8\x01\
-    \x0f\x19\n\r\n\x05\x04\x17\x02\x08\x03\x12\x04\xe8\x01\x1c\x1d\n\x0c\n\
-    \x02\x04\x18\x12\x06\xeb\x01\0\xee\x01\x01\n\x0b\n\x03\x04\x18\x01\x12\
-    \x04\xeb\x01\x08\x1e\n\x0c\n\x04\x04\x18\x02\0\x12\x04\xec\x01\x08%\n\
-    \x0f\n\x05\x04\x18\x02\0\x04\x12\x06\xec\x01\x08\xeb\x01\x20\n\r\n\x05\
-    \x04\x18\x02\0\x06\x12\x04\xec\x01\x08\x13\n\r\n\x05\x04\x18\x02\0\x01\
-    \x12\x04\xec\x01\x14\x20\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xec\x01#$\n\
-    \x0c\n\x04\x04\x18\x02\x01\x12\x04\xed\x01\x080\n\r\n\x05\x04\x18\x02\
-    \x01\x04\x12\x04\xed\x01\x08\x10\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\
-    \xed\x01\x11\x1d\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xed\x01\x1e+\n\r\
-    \n\x05\x04\x18\x02\x01\x03\x12\x04\xed\x01./\n\x0c\n\x02\x04\x19\x12\x06\
-    \xf0\x01\0\xf4\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xf0\x01\x08\x1a\n\
-    \x0c\n\x04\x04\x19\x02\0\x12\x04\xf1\x01\x08\x20\n\x0f\n\x05\x04\x19\x02\
-    \0\x04\x12\x06\xf1\x01\x08\xf0\x01\x1c\n\r\n\x05\x04\x19\x02\0\x05\x12\
-    \x04\xf1\x01\x08\x0e\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xf1\x01\x0f\x1b\
-    \n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xf1\x01\x1e\x1f\n\x0c\n\x04\x04\x19\
-    \x02\x01\x12\x04\xf2\x01\x08\x1b\n\x0f\n\x05\x04\x19\x02\x01\x04\x12\x06\
-    \xf2\x01\x08\xf1\x01\x20\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xf2\x01\
-    \x08\x0e\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xf2\x01\x0f\x16\n\r\n\x05\
-    \x04\x19\x02\x01\x03\x12\x04\xf2\x01\x19\x1a\n\x0c\n\x04\x04\x19\x02\x02\
-    \x12\x04\xf3\x01\x08\x17\n\x0f\n\x05\x04\x19\x02\x02\x04\x12\x06\xf3\x01\
-    \x08\xf2\x01\x1b\n\r\n\x05\x04\x19\x02\x02\x05\x12\x04\xf3\x01\x08\r\n\r\
-    \n\x05\x04\x19\x02\x02\x01\x12\x04\xf3\x01\x0e\x12\n\r\n\x05\x04\x19\x02\
-    \x02\x03\x12\x04\xf3\x01\x15\x16\n\x0c\n\x02\x04\x1a\x12\x06\xf6\x01\0\
-    \xf8\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xf6\x01\x08\x1b\n\x0c\n\x04\
-    \x04\x1a\x02\0\x12\x04\xf7\x01\x08\x17\n\x0f\n\x05\x04\x1a\x02\0\x04\x12\
-    \x06\xf7\x01\x08\xf6\x01\x1d\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xf7\x01\
-    \x08\x0e\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xf7\x01\x0f\x12\n\r\n\x05\
-    \x04\x1a\x02\0\x03\x12\x04\xf7\x01\x15\x16\n\x0c\n\x02\x04\x1b\x12\x06\
-    \xfa\x01\0\xfe\x01\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\xfa\x01\x08\x19\n\
-    \x0c\n\x04\x04\x1b\x02\0\x12\x04\xfb\x01\x08\x20\n\x0f\n\x05\x04\x1b\x02\
-    \0\x04\x12\x06\xfb\x01\x08\xfa\x01\x1b\n\r\n\x05\x04\x1b\x02\0\x05\x12\
-    \x04\xfb\x01\x08\x0e\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\xfb\x01\x0f\x1b\
-    \n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xfb\x01\x1e\x1f\n\x0c\n\x04\x04\x1b\
-    \x02\x01\x12\x04\xfc\x01\x08\x1b\n\x0f\n\x05\x04\x1b\x02\x01\x04\x12\x06\
-    \xfc\x01\x08\xfb\x01\x20\n\r\n\x05\x04\x1b\x02\x01\x05\x12\x04\xfc\x01\
-    \x08\x0e\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xfc\x01\x0f\x16\n\r\n\x05\
-    \x04\x1b\x02\x01\x03\x12\x04\xfc\x01\x19\x1a\n\x0c\n\x04\x04\x1b\x02\x02\
-    \x12\x04\xfd\x01\x08\x17\n\x0f\n\x05\x04\x1b\x02\x02\x04\x12\x06\xfd\x01\
-    \x08\xfc\x01\x1b\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\xfd\x01\x08\x0e\n\
-    \r\n\x05\x04\x1b\x02\x02\x01\x12\x04\xfd\x01\x0f\x12\n\r\n\x05\x04\x1b\
-    \x02\x02\x03\x12\x04\xfd\x01\x15\x16\n\x0c\n\x02\x04\x1c\x12\x06\x80\x02\
-    \0\x82\x02\x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\x80\x02\x08\x1a\n\x0c\n\
-    \x04\x04\x1c\x02\0\x12\x04\x81\x02\x08\x17\n\x0f\n\x05\x04\x1c\x02\0\x04\
-    \x12\x06\x81\x02\x08\x80\x02\x1c\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\x81\
-    \x02\x08\r\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\x81\x02\x0e\x12\n\r\n\x05\
-    \x04\x1c\x02\0\x03\x12\x04\x81\x02\x15\x16\n\x0c\n\x02\x04\x1d\x12\x06\
-    \x84\x02\0\x87\x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\x84\x02\x08\x19\n\
-    \x0c\n\x04\x04\x1d\x02\0\x12\x04\x85\x02\x08\x20\n\x0f\n\x05\x04\x1d\x02\
-    \0\x04\x12\x06\x85\x02\x08\x84\x02\x1b\n\r\n\x05\x04\x1d\x02\0\x05\x12\
-    \x04\x85\x02\x08\x0e\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\x85\x02\x0f\x1b\
-    \n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\x85\x02\x1e\x1f\n\x0c\n\x04\x04\x1d\
-    \x02\x01\x12\x04\x86\x02\x08\x1b\n\x0f\n\x05\x04\x1d\x02\x01\x04\x12\x06\
-    \x86\x02\x08\x85\x02\x20\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\x86\x02\
-    \x08\x0e\n\r\n\x05\x04\x1d\x02\x01\x01\x12\x04\x86\x02\x0f\x16\n\r\n\x05\
-    \x04\x1d\x02\x01\x03\x12\x04\x86\x02\x19\x1a\n\x0c\n\x02\x04\x1e\x12\x06\
-    \x89\x02\0\x8e\x02\x01\n\x0b\n\x03\x04\x1e\x01\x12\x04\x89\x02\x08\x1b\n\
-    \x0c\n\x04\x04\x1e\x02\0\x12\x04\x8a\x02\x08\x20\n\x0f\n\x05\x04\x1e\x02\
-    \0\x04\x12\x06\x8a\x02\x08\x89\x02\x1d\n\r\n\x05\x04\x1e\x02\0\x05\x12\
-    \x04\x8a\x02\x08\x0e\n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\x8a\x02\x0f\x1b\
-    \n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\x8a\x02\x1e\x1f\n\x0c\n\x04\x04\x1e\
-    \x02\x01\x12\x04\x8b\x02\x08\x1b\n\x0f\n\x05\x04\x1e\x02\x01\x04\x12\x06\
-    \x8b\x02\x08\x8a\x02\x20\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\x8b\x02\
-    \x08\x0e\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\x8b\x02\x0f\x16\n\r\n\x05\
-    \x04\x1e\x02\x01\x03\x12\x04\x8b\x02\x19\x1a\n\x0c\n\x04\x04\x1e\x02\x02\
-    \x12\x04\x8c\x02\x08\x17\n\x0f\n\x05\x04\x1e\x02\x02\x04\x12\x06\x8c\x02\
-    \x08\x8b\x02\x1b\n\r\n\x05\x04\x1e\x02\x02\x05\x12\x04\x8c\x02\x08\x0e\n\
-    \r\n\x05\x04\x1e\x02\x02\x01\x12\x04\x8c\x02\x0f\x12\n\r\n\x05\x04\x1e\
-    \x02\x02\x03\x12\x04\x8c\x02\x15\x16\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\
-    \x8d\x02\x08\x1a\n\x0f\n\x05\x04\x1e\x02\x03\x04\x12\x06\x8d\x02\x08\x8c\
-    \x02\x17\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\x8d\x02\x08\x0e\n\r\n\x05\
-    \x04\x1e\x02\x03\x01\x12\x04\x8d\x02\x0f\x15\n\r\n\x05\x04\x1e\x02\x03\
-    \x03\x12\x04\x8d\x02\x18\x19\n\x0c\n\x02\x04\x1f\x12\x06\x90\x02\0\xa1\
-    \x02\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\x90\x02\x08\x1c\n\x0c\n\x04\x04\
-    \x1f\x02\0\x12\x04\x91\x02\x08\x1c\n\x0f\n\x05\x04\x1f\x02\0\x04\x12\x06\
-    \x91\x02\x08\x90\x02\x1e\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\x91\x02\x08\
-    \x0e\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\x91\x02\x0f\x17\n\r\n\x05\x04\
-    \x1f\x02\0\x03\x12\x04\x91\x02\x1a\x1b\n\x0c\n\x04\x04\x1f\x02\x01\x12\
-    \x04\x92\x02\x08\x20\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\x92\x02\x08\
-    \x10\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\x92\x02\x11\x17\n\r\n\x05\x04\
-    \x1f\x02\x01\x01\x12\x04\x92\x02\x18\x1b\n\r\n\x05\x04\x1f\x02\x01\x03\
-    \x12\x04\x92\x02\x1e\x1f\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\x93\x02\x08\
-    &\n\r\n\x05\x04\x1f\x02\x02\x04\x12\x04\x93\x02\x08\x10\n\r\n\x05\x04\
-    \x1f\x02\x02\x06\x12\x04\x93\x02\x11\x18\n\r\n\x05\x04\x1f\x02\x02\x01\
-    \x12\x04\x93\x02\x19!\n\r\n\x05\x04\x1f\x02\x02\x03\x12\x04\x93\x02$%\n\
-    \xea\x01\n\x04\x04\x1f\x02\x03\x12\x04\x99\x02\x08\x1f\x1a\xdb\x01\x20Th\
+    ListRoutesRequest\"\x19\n\x17GetNetworkEventsRequest\"\x95\x01\n\x0eTraf\
+    ficControl\x12$\n\x06qdiscs\x18\x01\x20\x03(\x0b2\x0c.types.QdiscR\x06qd\
+    iscs\x12-\n\x07classes\x18\x02\x20\x03(\x0b2\x13.types.TrafficClassR\x07\
+    classes\x12.\n\x07filters\x18\x03\x20\x03(\x0b2\x14.types.TrafficFilterR\
+    \x07filters\"\\\n\x1bUpdateTrafficControlRequest\x12=\n\x0ftraffic_contr\
+    ol\x18\x01\x20\x01(\x0b2\x14.grpc.TrafficControlR\x0etrafficControl\"3\n\
+    \x19ListTrafficControlRequest\x12\x16\n\x06device\x18\x01\x20\x01(\tR\
+    \x06device\"\xd3\x01\n\x0cNetworkEvent\x12+\n\x04kind\x18\x01\x20\x01(\
+    \x0e2\x17.grpc.NetworkEvent.KindR\x04kind\x12\x18\n\x07deleted\x18\x02\
+    \x20\x01(\x08R\x07deleted\x12.\n\tinterface\x18\x03\x20\x01(\x0b2\x10.ty\
+    pes.InterfaceR\tinterface\x12\"\n\x05route\x18\x04\x20\x01(\x0b2\x0c.typ\
+    es.RouteR\x05route\"(\n\x04Kind\x12\x08\n\x04LINK\x10\0\x12\x0b\n\x07ADD\
+    RESS\x10\x01\x12\t\n\x05ROUTE\x10\x02\"]\n\x13OnlineCPUMemRequest\x12\
+    \x12\n\x04wait\x18\x01\x20\x01(\x08R\x04wait\x12\x17\n\x07nb_cpus\x18\
+    \x02\x20\x01(\rR\x06nbCpus\x12\x19\n\x08cpu_only\x18\x03\x20\x01(\x08R\
+    \x07cpuOnly\",\n\x16ReseedRandomDevRequest\x12\x12\n\x04data\x18\x02\x20\
+    \x01(\x0cR\x04data\"\xc8\x01\n\x0cAgentDetails\x12\x18\n\x07version\x18\
+    \x01\x20\x01(\tR\x07version\x12\x1f\n\x0binit_daemon\x18\x02\x20\x01(\
+    \x08R\ninitDaemon\x12'\n\x0fdevice_handlers\x18\x03\x20\x03(\tR\x0edevic\
+    eHandlers\x12)\n\x10storage_handlers\x18\x04\x20\x03(\tR\x0fstorageHandl\
+    ers\x12)\n\x10supports_seccomp\x18\x05\x20\x01(\x08R\x0fsupportsSeccomp\
+    \"g\n\x13GuestDetailsRequest\x12$\n\x0emem_block_size\x18\x01\x20\x01(\
+    \x08R\x0cmemBlockSize\x12*\n\x11mem_hotplug_probe\x18\x02\x20\x01(\x08R\
+    \x0fmemHotplugProbe\"\xbb\x01\n\x14GuestDetailsResponse\x12/\n\x14mem_bl\
+    ock_size_bytes\x18\x01\x20\x01(\x04R\x11memBlockSizeBytes\x127\n\ragent_\
+    details\x18\x02\x20\x01(\x0b2\x12.grpc.AgentDetailsR\x0cagentDetails\x12\
+    9\n\x19support_mem_hotplug_probe\x18\x03\x20\x01(\x08R\x16supportMemHotp\
+    lugProbe\"L\n\x18MemHotplugByProbeRequest\x120\n\x13memHotplugProbeAddr\
+    \x18\x01\x20\x03(\x04R\x13memHotplugProbeAddr\"?\n\x17SetGuestDateTimeRe\
+    quest\x12\x10\n\x03Sec\x18\x01\x20\x01(\x03R\x03Sec\x12\x12\n\x04Usec\
+    \x18\x02\x20\x01(\x03R\x04Usec\"\xfc\x01\n\x07Storage\x12\x16\n\x06drive\
+    r\x18\x01\x20\x01(\tR\x06driver\x12%\n\x0edriver_options\x18\x02\x20\x03\
+    (\tR\rdriverOptions\x12\x16\n\x06source\x18\x03\x20\x01(\tR\x06source\
+    \x12\x16\n\x06fstype\x18\x04\x20\x01(\tR\x06fstype\x12\x18\n\x07options\
+    \x18\x05\x20\x03(\tR\x07options\x12\x1f\n\x0bmount_point\x18\x06\x20\x01\
+    (\tR\nmountPoint\x12\x1d\n\nsize_limit\x18\x07\x20\x01(\x04R\tsizeLimit\
+    \x12(\n\x08fs_group\x18\x08\x20\x01(\x0b2\r.grpc.FSGroupR\x07fsGroup\"o\
+    \n\x07FSGroup\x12\x19\n\x08group_id\x18\x01\x20\x01(\rR\x07groupId\x12I\
+    \n\x13group_change_policy\x18\x02\x20\x01(\x0e2\x19.grpc.FSGroupChangePo\
+    licyR\x11groupChangePolicy\"C\n\x15GetVolumeStatsRequest\x12*\n\x11volum\
+    e_guest_path\x18\x01\x20\x01(\tR\x0fvolumeGuestPath\"\x82\x02\n\x0bVolum\
+    eStats\x12*\n\x11volume_guest_path\x18\x01\x20\x01(\tR\x0fvolumeGuestPat\
+    h\x12%\n\x0ecapacity_bytes\x18\x02\x20\x01(\x04R\rcapacityBytes\x12'\n\
+    \x0favailable_bytes\x18\x03\x20\x01(\x04R\x0eavailableBytes\x12\x1d\n\nu\
+    sed_bytes\x18\x04\x20\x01(\x04R\tusedBytes\x12\x16\n\x06inodes\x18\x05\
+    \x20\x01(\x04R\x06inodes\x12\x1f\n\x0binodes_free\x18\x06\x20\x01(\x04R\
+    \ninodesFree\x12\x1f\n\x0binodes_used\x18\x07\x20\x01(\x04R\ninodesUsed\
+    \"B\n\x13VolumeStatsResponse\x12+\n\x07volumes\x18\x01\x20\x03(\x0b2\x11\
+    .grpc.VolumeStatsR\x07volumes\"U\n\x13ResizeVolumeRequest\x12*\n\x11volu\
+    me_guest_path\x18\x01\x20\x01(\tR\x0fvolumeGuestPath\x12\x12\n\x04size\
+    \x18\x02\x20\x01(\x04R\x04size\"\x86\x01\n\x06Device\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\x12\x12\n\x04type\x18\x02\x20\x01(\tR\x04type\x12\
+    \x17\n\x07vm_path\x18\x03\x20\x01(\tR\x06vmPath\x12%\n\x0econtainer_path\
+    \x18\x04\x20\x01(\tR\rcontainerPath\x12\x18\n\x07options\x18\x05\x20\x03\
+    (\tR\x07options\"X\n\nStringUser\x12\x10\n\x03uid\x18\x01\x20\x01(\tR\
+    \x03uid\x12\x10\n\x03gid\x18\x02\x20\x01(\tR\x03gid\x12&\n\x0eadditional\
+    Gids\x18\x03\x20\x03(\tR\x0eadditionalGids\"\xca\x01\n\x0fCopyFileReques\
+    t\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x12\x1b\n\tfile_size\x18\
+    \x02\x20\x01(\x03R\x08fileSize\x12\x1b\n\tfile_mode\x18\x03\x20\x01(\rR\
+    \x08fileMode\x12\x19\n\x08dir_mode\x18\x04\x20\x01(\rR\x07dirMode\x12\
+    \x10\n\x03uid\x18\x05\x20\x01(\x05R\x03uid\x12\x10\n\x03gid\x18\x06\x20\
+    \x01(\x05R\x03gid\x12\x16\n\x06offset\x18\x07\x20\x01(\x03R\x06offset\
+    \x12\x12\n\x04data\x18\x08\x20\x01(\x0cR\x04data\"\x15\n\x13StartTracing\
+    Request\"\x14\n\x12StopTracingRequest\"\xb3\x01\n\x12SetLogLevelRequest\
+    \x12\x14\n\x05level\x18\x01\x20\x01(\tR\x05level\x12H\n\nsubsystems\x18\
+    \x02\x20\x03(\x0b2(.grpc.SetLogLevelRequest.SubsystemsEntryR\nsubsystems\
+    \x1a=\n\x0fSubsystemsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"\x13\n\x11Ge\
+    tMetricsRequest\"#\n\x07Metrics\x12\x18\n\x07metrics\x18\x01\x20\x01(\tR\
+    \x07metrics\"*\n\x10SetPolicyRequest\x12\x16\n\x06policy\x18\x01\x20\x01\
+    (\tR\x06policy*5\n\x13FSGroupChangePolicy\x12\n\n\x06Always\x10\0\x12\
+    \x12\n\x0eOnRootMismatch\x10\x012\xbb\x14\n\x0cAgentService\x12G\n\x0fCr\
+    eateContainer\x12\x1c.grpc.CreateContainerRequest\x1a\x16.google.protobu\
+    f.Empty\x12E\n\x0eStartContainer\x12\x1b.grpc.StartContainerRequest\x1a\
+    \x16.google.protobuf.Empty\x12G\n\x0fRemoveContainer\x12\x1c.grpc.Remove\
+    ContainerRequest\x1a\x16.google.protobuf.Empty\x12?\n\x0bExecProcess\x12\
+    \x18.grpc.ExecProcessRequest\x1a\x16.google.protobuf.Empty\x12C\n\rSigna\
+    lProcess\x12\x1a.grpc.SignalProcessRequest\x1a\x16.google.protobuf.Empty\
+    \x12B\n\x0bWaitProcess\x12\x18.grpc.WaitProcessRequest\x1a\x19.grpc.Wait\
+    ProcessResponse\x12H\n\rListProcesses\x12\x1a.grpc.ListProcessesRequest\
+    \x1a\x1b.grpc.ListProcessesResponse\x12G\n\x0fUpdateContainer\x12\x1c.gr\
+    pc.UpdateContainerRequest\x1a\x16.google.protobuf.Empty\x12K\n\x0eStatsC\
+    ontainer\x12\x1b.grpc.StatsContainerRequest\x1a\x1c.grpc.StatsContainerR\
+    esponse\x12E\n\x0ePauseContainer\x12\x1b.grpc.PauseContainerRequest\x1a\
+    \x16.google.protobuf.Empty\x12G\n\x0fResumeContainer\x12\x1c.grpc.Resume\
+    ContainerRequest\x1a\x16.google.protobuf.Empty\x12A\n\nWriteStdin\x12\
+    \x18.grpc.WriteStreamRequest\x1a\x19.grpc.WriteStreamResponse\x12?\n\nRe\
+    adStdout\x12\x17.grpc.ReadStreamRequest\x1a\x18.grpc.ReadStreamResponse\
+    \x12?\n\nReadStderr\x12\x17.grpc.ReadStreamRequest\x1a\x18.grpc.ReadStre\
+    amResponse\x12=\n\nCloseStdin\x12\x17.grpc.CloseStdinRequest\x1a\x16.goo\
+    gle.protobuf.Empty\x12A\n\x0cTtyWinResize\x12\x19.grpc.TtyWinResizeReque\
+    st\x1a\x16.google.protobuf.Empty\x12A\n\x0fUpdateInterface\x12\x1c.grpc.\
+    UpdateInterfaceRequest\x1a\x10.types.Interface\x127\n\x0cUpdateRoutes\
+    \x12\x19.grpc.UpdateRoutesRequest\x1a\x0c.grpc.Routes\x12?\n\x0eListInte\
+    rfaces\x12\x1b.grpc.ListInterfacesRequest\x1a\x10.grpc.Interfaces\x123\n\
+    \nListRoutes\x12\x17.grpc.ListRoutesRequest\x1a\x0c.grpc.Routes\x12G\n\
+    \x10GetNetworkEvents\x12\x1d.grpc.GetNetworkEventsRequest\x1a\x12.grpc.N\
+    etworkEvent0\x01\x12O\n\x14UpdateTrafficControl\x12!.grpc.UpdateTrafficC\
+    ontrolRequest\x1a\x14.grpc.TrafficControl\x12K\n\x12ListTrafficControl\
+    \x12\x1f.grpc.ListTrafficControlRequest\x1a\x14.grpc.TrafficControl\x12A\
+    \n\x0cStartTracing\x12\x19.grpc.StartTracingRequest\x1a\x16.google.proto\
+    buf.Empty\x12?\n\x0bStopTracing\x12\x18.grpc.StopTracingRequest\x1a\x16.\
+    google.protobuf.Empty\x12C\n\rCreateSandbox\x12\x1a.grpc.CreateSandboxRe\
+    quest\x1a\x16.google.protobuf.Empty\x12E\n\x0eDestroySandbox\x12\x1b.grp\
+    c.DestroySandboxRequest\x1a\x16.google.protobuf.Empty\x12A\n\x0cOnlineCP\
+    UMem\x12\x19.grpc.OnlineCPUMemRequest\x1a\x16.google.protobuf.Empty\x12G\
+    \n\x0fReseedRandomDev\x12\x1c.grpc.ReseedRandomDevRequest\x1a\x16.google\
+    .protobuf.Empty\x12H\n\x0fGetGuestDetails\x12\x19.grpc.GuestDetailsReque\
+    st\x1a\x1a.grpc.GuestDetailsResponse\x12K\n\x11MemHotplugByProbe\x12\x1e\
+    .grpc.MemHotplugByProbeRequest\x1a\x16.google.protobuf.Empty\x12I\n\x10S\
+    etGuestDateTime\x12\x1d.grpc.SetGuestDateTimeRequest\x1a\x16.google.prot\
+    obuf.Empty\x129\n\x08CopyFile\x12\x15.grpc.CopyFileRequest\x1a\x16.googl\
+    e.protobuf.Empty\x12H\n\x0eGetVolumeStats\x12\x1b.grpc.GetVolumeStatsReq\
+    uest\x1a\x19.grpc.VolumeStatsResponse\x12A\n\x0cResizeVolume\x12\x19.grp\
+    c.ResizeVolumeRequest\x1a\x16.google.protobuf.Empty\x12?\n\x0bSetLogLeve\
+    l\x12\x18.grpc.SetLogLevelRequest\x1a\x16.google.protobuf.Empty\x124\n\n\
+    GetMetrics\x12\x17.grpc.GetMetricsRequest\x1a\r.grpc.Metrics\x12;\n\tSet\
+    Policy\x12\x16.grpc.SetPolicyRequest\x1a\x16.google.protobuf.EmptyJ\xc6\
+    \xcf\x01\n\x07\x12\x05\x07\0\xee\x04\x01\nm\n\x01\x0c\x12\x03\x07\0\x122\
+    c\n\x20Copyright\x202017\x20HyperHQ\x20Inc.\n\x20Copyright\x202019\x20An\
+    t\x20Financial\n\n\x20SPDX-License-Identifier:\x20Apache-2.0\n\n\n\x08\n\
+    \x01\x02\x12\x03\t\0\r\n\t\n\x02\x03\0\x12\x03\x0b\0\x13\n\t\n\x02\x03\
+    \x01\x12\x03\x0c\0@\n\t\n\x02\x03\x02\x12\x03\r\0%\n\x16\n\x02\x06\0\x12\
+    \x04\x10\0U\x01\x1a\n\x20unstable\n\n\n\n\x03\x06\0\x01\x12\x03\x10\x08\
+    \x14\n\x18\n\x04\x06\0\x02\0\x12\x03\x12\x08T\x1a\x0b\x20execution\n\n\
+    \x0c\n\x05\x06\0\x02\0\x01\x12\x03\x12\x0c\x1b\n\x0c\n\x05\x06\0\x02\0\
+    \x02\x12\x03\x12\x1c2\n\x0c\n\x05\x06\0\x02\0\x03\x12\x03\x12=R\n\x0b\n\
+    \x04\x06\0\x02\x01\x12\x03\x13\x08R\n\x0c\n\x05\x06\0\x02\x01\x01\x12\
+    \x03\x13\x0c\x1a\n\x0c\n\x05\x06\0\x02\x01\x02\x12\x03\x13\x1b0\n\x0c\n\
+    \x05\x06\0\x02\x01\x03\x12\x03\x13;P\n\x9c\x03\n\x04\x06\0\x02\x02\x12\
+    \x03\x1b\x08T\x1a\x8e\x03\x20RemoveContainer\x20will\x20tear\x20down\x20\
+    an\x20existing\x20container\x20by\x20forcibly\x20terminating\n\x20all\
+    \x20processes\x20running\x20inside\x20that\x20container\x20and\x20releas\
+    ing\x20all\x20internal\n\x20resources\x20associated\x20with\x20it.\n\x20\
+    RemoveContainer\x20will\x20wait\x20for\x20all\x20processes\x20terminatio\
+    n\x20before\x20returning.\n\x20If\x20any\x20process\x20can\x20not\x20be\
+    \x20killed\x20or\x20if\x20it\x20can\x20not\x20be\x20killed\x20after\n\
+    \x20the\x20RemoveContainerRequest\x20timeout,\x20RemoveContainer\x20will\
+    \x20return\x20an\x20error.\n\n\x0c\n\x05\x06\0\x02\x02\x01\x12\x03\x1b\
+    \x0c\x1b\n\x0c\n\x05\x06\0\x02\x02\x02\x12\x03\x1b\x1c2\n\x0c\n\x05\x06\
+    \0\x02\x02\x03\x12\x03\x1b=R\n\x0b\n\x04\x06\0\x02\x03\x12\x03\x1c\x08L\
+    \n\x0c\n\x05\x06\0\x02\x03\x01\x12\x03\x1c\x0c\x17\n\x0c\n\x05\x06\0\x02\
+    \x03\x02\x12\x03\x1c\x18*\n\x0c\n\x05\x06\0\x02\x03\x03\x12\x03\x1c5J\n\
+    \x0b\n\x04\x06\0\x02\x04\x12\x03\x1d\x08P\n\x0c\n\x05\x06\0\x02\x04\x01\
+    \x12\x03\x1d\x0c\x19\n\x0c\n\x05\x06\0\x02\x04\x02\x12\x03\x1d\x1a.\n\
+    \x0c\n\x05\x06\0\x02\x04\x03\x12\x03\x1d9N\n*\n\x04\x06\0\x02\x05\x12\
+    \x03\x1e\x08J\"\x1d\x20wait\x20&\x20reap\x20like\x20waitpid(2)\n\n\x0c\n\
+    \x05\x06\0\x02\x05\x01\x12\x03\x1e\x0c\x17\n\x0c\n\x05\x06\0\x02\x05\x02\
+    \x12\x03\x1e\x18*\n\x0c\n\x05\x06\0\x02\x05\x03\x12\x03\x1e5H\n\x0b\n\
+    \x04\x06\0\x02\x06\x12\x03\x1f\x08P\n\x0c\n\x05\x06\0\x02\x06\x01\x12\
+    \x03\x1f\x0c\x19\n\x0c\n\x05\x06\0\x02\x06\x02\x12\x03\x1f\x1a.\n\x0c\n\
+    \x05\x06\0\x02\x06\x03\x12\x03\x1f9N\n\x0b\n\x04\x06\0\x02\x07\x12\x03\
+    \x20\x08T\n\x0c\n\x05\x06\0\x02\x07\x01\x12\x03\x20\x0c\x1b\n\x0c\n\x05\
+    \x06\0\x02\x07\x02\x12\x03\x20\x1c2\n\x0c\n\x05\x06\0\x02\x07\x03\x12\
+    \x03\x20=R\n\x0b\n\x04\x06\0\x02\x08\x12\x03!\x08S\n\x0c\n\x05\x06\0\x02\
+    \x08\x01\x12\x03!\x0c\x1a\n\x0c\n\x05\x06\0\x02\x08\x02\x12\x03!\x1b0\n\
+    \x0c\n\x05\x06\0\x02\x08\x03\x12\x03!;Q\n\x0b\n\x04\x06\0\x02\t\x12\x03\
+    \"\x08R\n\x0c\n\x05\x06\0\x02\t\x01\x12\x03\"\x0c\x1a\n\x0c\n\x05\x06\0\
+    \x02\t\x02\x12\x03\"\x1b0\n\x0c\n\x05\x06\0\x02\t\x03\x12\x03\";P\n\x0b\
+    \n\x04\x06\0\x02\n\x12\x03#\x08T\n\x0c\n\x05\x06\0\x02\n\x01\x12\x03#\
+    \x0c\x1b\n\x0c\n\x05\x06\0\x02\n\x02\x12\x03#\x1c2\n\x0c\n\x05\x06\0\x02\
+    \n\x03\x12\x03#=R\n\x14\n\x04\x06\0\x02\x0b\x12\x03&\x08I\x1a\x07\x20std\
+    io\n\n\x0c\n\x05\x06\0\x02\x0b\x01\x12\x03&\x0c\x16\n\x0c\n\x05\x06\0\
+    \x02\x0b\x02\x12\x03&\x17)\n\x0c\n\x05\x06\0\x02\x0b\x03\x12\x03&4G\n\
+    \x0b\n\x04\x06\0\x02\x0c\x12\x03'\x08G\n\x0c\n\x05\x06\0\x02\x0c\x01\x12\
+    \x03'\x0c\x16\n\x0c\n\x05\x06\0\x02\x0c\x02\x12\x03'\x17(\n\x0c\n\x05\
+    \x06\0\x02\x0c\x03\x12\x03'3E\n\x0b\n\x04\x06\0\x02\r\x12\x03(\x08G\n\
+    \x0c\n\x05\x06\0\x02\r\x01\x12\x03(\x0c\x16\n\x0c\n\x05\x06\0\x02\r\x02\
+    \x12\x03(\x17(\n\x0c\n\x05\x06\0\x02\r\x03\x12\x03(3E\n\x0b\n\x04\x06\0\
+    \x02\x0e\x12\x03)\x08J\n\x0c\n\x05\x06\0\x02\x0e\x01\x12\x03)\x0c\x16\n\
+    \x0c\n\x05\x06\0\x02\x0e\x02\x12\x03)\x17(\n\x0c\n\x05\x06\0\x02\x0e\x03\
+    \x12\x03)3H\n\x0b\n\x04\x06\0\x02\x0f\x12\x03*\x08N\n\x0c\n\x05\x06\0\
+    \x02\x0f\x01\x12\x03*\x0c\x18\n\x0c\n\x05\x06\0\x02\x0f\x02\x12\x03*\x19\
+    ,\n\x0c\n\x05\x06\0\x02\x0f\x03\x12\x03*7L\n\x19\n\x04\x06\0\x02\x10\x12\
+    \x03-\x08N\x1a\x0c\x20networking\n\n\x0c\n\x05\x06\0\x02\x10\x01\x12\x03\
+    -\x0c\x1b\n\x0c\n\x05\x06\0\x02\x10\x02\x12\x03-\x1c2\n\x0c\n\x05\x06\0\
+    \x02\x10\x03\x12\x03-=L\n\x0b\n\x04\x06\0\x02\x11\x12\x03.\x08?\n\x0c\n\
+    \x05\x06\0\x02\x11\x01\x12\x03.\x0c\x18\n\x0c\n\x05\x06\0\x02\x11\x02\
+    \x12\x03.\x19,\n\x0c\n\x05\x06\0\x02\x11\x03\x12\x03.7=\n\x0b\n\x04\x06\
+    \0\x02\x12\x12\x03/\x08F\n\x0c\n\x05\x06\0\x02\x12\x01\x12\x03/\x0c\x1a\
+    \n\x0c\n\x05\x06\0\x02\x12\x02\x12\x03/\x1b0\n\x0c\n\x05\x06\0\x02\x12\
+    \x03\x12\x03/:D\n\x0b\n\x04\x06\0\x02\x13\x12\x030\x08;\n\x0c\n\x05\x06\
+    \0\x02\x13\x01\x12\x030\x0c\x16\n\x0c\n\x05\x06\0\x02\x13\x02\x12\x030\
+    \x17(\n\x0c\n\x05\x06\0\x02\x13\x03\x12\x03039\n\x92\x01\n\x04\x06\0\x02\
+    \x14\x12\x034\x08T\x1a\x84\x01\x20GetNetworkEvents\x20streams\x20the\x20\
+    link,\x20address\x20and\x20route\x20changes\x20done\n\x20inside\x20the\
+    \x20guest,\x20whether\x20by\x20the\x20agent\x20or\x20by\x20any\x20other\
+    \x20process.\n\n\x0c\n\x05\x06\0\x02\x14\x01\x12\x034\x0c\x1c\n\x0c\n\
+    \x05\x06\0\x02\x14\x02\x12\x034\x1d4\n\x0c\n\x05\x06\0\x02\x14\x06\x12\
+    \x034?E\n\x0c\n\x05\x06\0\x02\x14\x03\x12\x034FR\n\x8f\x01\n\x04\x06\0\
+    \x02\x15\x12\x038\x08W\x1a\x81\x01\x20UpdateTrafficControl\x20replaces\
+    \x20the\x20qdiscs,\x20classes\x20and\x20filters\x20of\n\x20the\x20interf\
+    aces\x20it\x20refers\x20to,\x20ListTrafficControl\x20returns\x20them.\n\
+    \n\x0c\n\x05\x06\0\x02\x15\x01\x12\x038\x0c\x20\n\x0c\n\x05\x06\0\x02\
+    \x15\x02\x12\x038!<\n\x0c\n\x05\x06\0\x02\x15\x03\x12\x038GU\n\x0b\n\x04\
+    \x06\0\x02\x16\x12\x039\x08S\n\x0c\n\x05\x06\0\x02\x16\x01\x12\x039\x0c\
+    \x1e\n\x0c\n\x05\x06\0\x02\x16\x02\x12\x039\x1f8\n\x0c\n\x05\x06\0\x02\
+    \x16\x03\x12\x039CQ\n\x16\n\x04\x06\0\x02\x17\x12\x03<\x08N\x1a\t\x20tra\
+    cing\n\n\x0c\n\x05\x06\0\x02\x17\x01\x12\x03<\x0c\x18\n\x0c\n\x05\x06\0\
+    \x02\x17\x02\x12\x03<\x19,\n\x0c\n\x05\x06\0\x02\x17\x03\x12\x03<7L\n\
+    \x0b\n\x04\x06\0\x02\x18\x12\x03=\x08L\n\x0c\n\x05\x06\0\x02\x18\x01\x12\
+    \x03=\x0c\x17\n\x0c\n\x05\x06\0\x02\x18\x02\x12\x03=\x18*\n\x0c\n\x05\
+    \x06\0\x02\x18\x03\x12\x03=5J\nH\n\x04\x06\0\x02\x19\x12\x03@\x08P\x1a;\
+    \x20misc\x20(TODO:\x20some\x20rpcs\x20can\x20be\x20replaced\x20by\x20hyp\
+    erstart-exec)\n\n\x0c\n\x05\x06\0\x02\x19\x01\x12\x03@\x0c\x19\n\x0c\n\
+    \x05\x06\0\x02\x19\x02\x12\x03@\x1a.\n\x0c\n\x05\x06\0\x02\x19\x03\x12\
+    \x03@9N\n\x0b\n\x04\x06\0\x02\x1a\x12\x03A\x08R\n\x0c\n\x05\x06\0\x02\
+    \x1a\x01\x12\x03A\x0c\x1a\n\x0c\n\x05\x06\0\x02\x1a\x02\x12\x03A\x1b0\n\
+    \x0c\n\x05\x06\0\x02\x1a\x03\x12\x03A;P\n\x0b\n\x04\x06\0\x02\x1b\x12\
+    \x03B\x08N\n\x0c\n\x05\x06\0\x02\x1b\x01\x12\x03B\x0c\x18\n\x0c\n\x05\
+    \x06\0\x02\x1b\x02\x12\x03B\x19,\n\x0c\n\x05\x06\0\x02\x1b\x03\x12\x03B7\
+    L\n\x0b\n\x04\x06\0\x02\x1c\x12\x03C\x08T\n\x0c\n\x05\x06\0\x02\x1c\x01\
+    \x12\x03C\x0c\x1b\n\x0c\n\x05\x06\0\x02\x1c\x02\x12\x03C\x1c2\n\x0c\n\
+    \x05\x06\0\x02\x1c\x03\x12\x03C=R\n\x0b\n\x04\x06\0\x02\x1d\x12\x03D\x08\
+    P\n\x0c\n\x05\x06\0\x02\x1d\x01\x12\x03D\x0c\x1b\n\x0c\n\x05\x06\0\x02\
+    \x1d\x02\x12\x03D\x1c/\n\x0c\n\x05\x06\0\x02\x1d\x03\x12\x03D:N\n\x0b\n\
+    \x04\x06\0\x02\x1e\x12\x03E\x08X\n\x0c\n\x05\x06\0\x02\x1e\x01\x12\x03E\
+    \x0c\x1d\n\x0c\n\x05\x06\0\x02\x1e\x02\x12\x03E\x1e6\n\x0c\n\x05\x06\0\
+    \x02\x1e\x03\x12\x03EAV\n\x0b\n\x04\x06\0\x02\x1f\x12\x03F\x08V\n\x0c\n\
+    \x05\x06\0\x02\x1f\x01\x12\x03F\x0c\x1c\n\x0c\n\x05\x06\0\x02\x1f\x02\
+    \x12\x03F\x1d4\n\x0c\n\x05\x06\0\x02\x1f\x03\x12\x03F?T\n\x0b\n\x04\x06\
+    \0\x02\x20\x12\x03G\x08F\n\x0c\n\x05\x06\0\x02\x20\x01\x12\x03G\x0c\x14\
+    \n\x0c\n\x05\x06\0\x02\x20\x02\x12\x03G\x15$\n\x0c\n\x05\x06\0\x02\x20\
+    \x03\x12\x03G/D\n\x16\n\x04\x06\0\x02!\x12\x03J\x08P\x1a\t\x20storage\n\
+    \n\x0c\n\x05\x06\0\x02!\x01\x12\x03J\x0c\x1a\n\x0c\n\x05\x06\0\x02!\x02\
+    \x12\x03J\x1b0\n\x0c\n\x05\x06\0\x02!\x03\x12\x03J;N\n\x0b\n\x04\x06\0\
+    \x02\"\x12\x03K\x08N\n\x0c\n\x05\x06\0\x02\"\x01\x12\x03K\x0c\x18\n\x0c\
+    \n\x05\x06\0\x02\"\x02\x12\x03K\x19,\n\x0c\n\x05\x06\0\x02\"\x03\x12\x03\
+    K7L\n\x16\n\x04\x06\0\x02#\x12\x03N\x08L\x1a\t\x20logging\n\n\x0c\n\x05\
+    \x06\0\x02#\x01\x12\x03N\x0c\x17\n\x0c\n\x05\x06\0\x02#\x02\x12\x03N\x18\
+    *\n\x0c\n\x05\x06\0\x02#\x03\x12\x03N5J\n\x16\n\x04\x06\0\x02$\x12\x03Q\
+    \x08<\x1a\t\x20metrics\n\n\x0c\n\x05\x06\0\x02$\x01\x12\x03Q\x0c\x16\n\
+    \x0c\n\x05\x06\0\x02$\x02\x12\x03Q\x17(\n\x0c\n\x05\x06\0\x02$\x03\x12\
+    \x03Q3:\n\x15\n\x04\x06\0\x02%\x12\x03T\x08H\x1a\x08\x20policy\n\n\x0c\n\
+    \x05\x06\0\x02%\x01\x12\x03T\x0c\x15\n\x0c\n\x05\x06\0\x02%\x02\x12\x03T\
+    \x16&\n\x0c\n\x05\x06\0\x02%\x03\x12\x03T1F\n\n\n\x02\x04\0\x12\x04W\0e\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03W\x08\x1e\n\x0b\n\x04\x04\0\x02\0\x12\
+    \x03X\x08\x20\n\r\n\x05\x04\0\x02\0\x04\x12\x04X\x08W\x20\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03X\x08\x0e\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03X\
+    \x0f\x1b\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03X\x1e\x1f\n\x0b\n\x04\x04\0\
+    \x02\x01\x12\x03Y\x08\x1b\n\r\n\x05\x04\0\x02\x01\x04\x12\x04Y\x08X\x20\
+    \n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03Y\x08\x0e\n\x0c\n\x05\x04\0\x02\
+    \x01\x01\x12\x03Y\x0f\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03Y\x19\x1a\
+    \n\x0b\n\x04\x04\0\x02\x02\x12\x03Z\x08#\n\r\n\x05\x04\0\x02\x02\x04\x12\
+    \x04Z\x08Y\x1b\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03Z\x08\x12\n\x0c\n\
+    \x05\x04\0\x02\x02\x01\x12\x03Z\x13\x1e\n\x0c\n\x05\x04\0\x02\x02\x03\
+    \x12\x03Z!\"\n\x0b\n\x04\x04\0\x02\x03\x12\x03[\x08$\n\x0c\n\x05\x04\0\
+    \x02\x03\x04\x12\x03[\x08\x10\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03[\x11\
+    \x17\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03[\x18\x1f\n\x0c\n\x05\x04\0\
+    \x02\x03\x03\x12\x03[\"#\n\x0b\n\x04\x04\0\x02\x04\x12\x03\\\x08&\n\x0c\
+    \n\x05\x04\0\x02\x04\x04\x12\x03\\\x08\x10\n\x0c\n\x05\x04\0\x02\x04\x06\
+    \x12\x03\\\x11\x18\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\\\x19!\n\x0c\n\
+    \x05\x04\0\x02\x04\x03\x12\x03\\$%\n\x0b\n\x04\x04\0\x02\x05\x12\x03]\
+    \x08\x15\n\r\n\x05\x04\0\x02\x05\x04\x12\x04]\x08\\&\n\x0c\n\x05\x04\0\
+    \x02\x05\x06\x12\x03]\x08\x0c\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03]\r\
+    \x10\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03]\x13\x14\n\xba\x02\n\x04\x04\
+    \0\x02\x06\x12\x03d\x08\x1f\x1a\xac\x02\x20This\x20field\x20is\x20used\
+    \x20to\x20indicate\x20if\x20the\x20container\x20needs\x20to\x20join\n\
+    \x20sandbox\x20shared\x20pid\x20ns\x20or\x20create\x20a\x20new\x20namesp\
+    ace.\x20This\x20field\x20is\n\x20meant\x20to\x20override\x20the\x20NEWPI\
+    D\x20config\x20settings\x20in\x20the\x20OCI\x20spec.\n\x20The\x20agent\
+    \x20would\x20receive\x20an\x20OCI\x20spec\x20with\x20PID\x20namespace\
+    \x20cleared\n\x20out\x20altogether\x20and\x20not\x20just\x20the\x20pid\
+    \x20ns\x20path.\n\n\r\n\x05\x04\0\x02\x06\x04\x12\x04d\x08]\x15\n\x0c\n\
+    \x05\x04\0\x02\x06\x05\x12\x03d\x08\x0c\n\x0c\n\x05\x04\0\x02\x06\x01\
+    \x12\x03d\r\x1a\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03d\x1d\x1e\n\n\n\x02\
+    \x04\x01\x12\x04g\0i\x01\n\n\n\x03\x04\x01\x01\x12\x03g\x08\x1d\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03h\x08\x20\n\r\n\x05\x04\x01\x02\0\x04\x12\x04h\
+    \x08g\x1f\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03h\x08\x0e\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03h\x0f\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03h\
+    \x1e\x1f\n\n\n\x02\x04\x02\x12\x04k\0t\x01\n\n\n\x03\x04\x02\x01\x12\x03\
+    k\x08\x1e\n\x0b\n\x04\x04\x02\x02\0\x12\x03l\x08\x20\n\r\n\x05\x04\x02\
+    \x02\0\x04\x12\x04l\x08k\x20\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03l\x08\
+    \x0e\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03l\x0f\x1b\n\x0c\n\x05\x04\x02\
+    \x02\0\x03\x12\x03l\x1e\x1f\n\xbc\x01\n\x04\x04\x02\x02\x01\x12\x03s\x08\
+    \x1b\x1a\xae\x01\x20RemoveContainer\x20will\x20return\x20an\x20error\x20\
+    if\n\x20it\x20could\x20not\x20kill\x20some\x20container\x20processes\n\
+    \x20after\x20timeout\x20seconds.\n\x20Setting\x20timeout\x20to\x200\x20m\
+    eans\x20RemoveContainer\x20will\n\x20wait\x20for\x20ever.\n\n\r\n\x05\
+    \x04\x02\x02\x01\x04\x12\x04s\x08l\x20\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03s\x08\x0e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03s\x0f\x16\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03s\x19\x1a\n\n\n\x02\x04\x03\x12\x04v\0\
+    {\x01\n\n\n\x03\x04\x03\x01\x12\x03v\x08\x1a\n\x0b\n\x04\x04\x03\x02\0\
+    \x12\x03w\x08\x20\n\r\n\x05\x04\x03\x02\0\x04\x12\x04w\x08v\x1c\n\x0c\n\
+    \x05\x04\x03\x02\0\x05\x12\x03w\x08\x0e\n\x0c\n\x05\x04\x03\x02\0\x01\
+    \x12\x03w\x0f\x1b\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03w\x1e\x1f\n\x0b\n\
+    \x04\x04\x03\x02\x01\x12\x03x\x08\x1b\n\r\n\x05\x04\x03\x02\x01\x04\x12\
+    \x04x\x08w\x20\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03x\x08\x0e\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03x\x0f\x16\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03x\x19\x1a\n\x0b\n\x04\x04\x03\x02\x02\x12\x03y\x08#\n\r\n\
+    \x05\x04\x03\x02\x02\x04\x12\x04y\x08x\x1b\n\x0c\n\x05\x04\x03\x02\x02\
+    \x06\x12\x03y\x08\x12\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03y\x13\x1e\n\
+    \x0c\n\x05\x04\x03\x02\x02\x03\x12\x03y!\"\n\x0b\n\x04\x04\x03\x02\x03\
+    \x12\x03z\x08\x1c\n\r\n\x05\x04\x03\x02\x03\x04\x12\x04z\x08y#\n\x0c\n\
+    \x05\x04\x03\x02\x03\x06\x12\x03z\x08\x0f\n\x0c\n\x05\x04\x03\x02\x03\
+    \x01\x12\x03z\x10\x17\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03z\x1a\x1b\n\
+    \x0b\n\x02\x04\x04\x12\x05}\0\x85\x01\x01\n\n\n\x03\x04\x04\x01\x12\x03}\
+    \x08\x1c\n\x0b\n\x04\x04\x04\x02\0\x12\x03~\x08\x20\n\r\n\x05\x04\x04\
+    \x02\0\x04\x12\x04~\x08}\x1e\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03~\x08\
+    \x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03~\x0f\x1b\n\x0c\n\x05\x04\x04\
+    \x02\0\x03\x12\x03~\x1e\x1f\n\xe9\x01\n\x04\x04\x04\x02\x01\x12\x04\x83\
+    \x01\x08\x1b\x1a\xda\x01\x20Special\x20case\x20for\x20SignalProcess():\
+    \x20exec_id\x20can\x20be\x20empty(\"\"),\n\x20which\x20means\x20to\x20se\
+    nd\x20the\x20signal\x20to\x20all\x20the\x20processes\x20including\x20the\
+    ir\x20descendants.\n\x20Other\x20APIs\x20with\x20exec_id\x20should\x20tr\
+    eat\x20empty\x20exec_id\x20as\x20an\x20invalid\x20request.\n\n\x0e\n\x05\
+    \x04\x04\x02\x01\x04\x12\x05\x83\x01\x08~\x20\n\r\n\x05\x04\x04\x02\x01\
+    \x05\x12\x04\x83\x01\x08\x0e\n\r\n\x05\x04\x04\x02\x01\x01\x12\x04\x83\
+    \x01\x0f\x16\n\r\n\x05\x04\x04\x02\x01\x03\x12\x04\x83\x01\x19\x1a\n\x0c\
+    \n\x04\x04\x04\x02\x02\x12\x04\x84\x01\x08\x1a\n\x0f\n\x05\x04\x04\x02\
+    \x02\x04\x12\x06\x84\x01\x08\x83\x01\x1b\n\r\n\x05\x04\x04\x02\x02\x05\
+    \x12\x04\x84\x01\x08\x0e\n\r\n\x05\x04\x04\x02\x02\x01\x12\x04\x84\x01\
+    \x0f\x15\n\r\n\x05\x04\x04\x02\x02\x03\x12\x04\x84\x01\x18\x19\n\x0c\n\
+    \x02\x04\x05\x12\x06\x87\x01\0\x8a\x01\x01\n\x0b\n\x03\x04\x05\x01\x12\
+    \x04\x87\x01\x08\x1a\n\x0c\n\x04\x04\x05\x02\0\x12\x04\x88\x01\x08\x20\n\
+    \x0f\n\x05\x04\x05\x02\0\x04\x12\x06\x88\x01\x08\x87\x01\x1c\n\r\n\x05\
+    \x04\x05\x02\0\x05\x12\x04\x88\x01\x08\x0e\n\r\n\x05\x04\x05\x02\0\x01\
+    \x12\x04\x88\x01\x0f\x1b\n\r\n\x05\x04\x05\x02\0\x03\x12\x04\x88\x01\x1e\
+    \x1f\n\x0c\n\x04\x04\x05\x02\x01\x12\x04\x89\x01\x08\x1b\n\x0f\n\x05\x04\
+    \x05\x02\x01\x04\x12\x06\x89\x01\x08\x88\x01\x20\n\r\n\x05\x04\x05\x02\
+    \x01\x05\x12\x04\x89\x01\x08\x0e\n\r\n\x05\x04\x05\x02\x01\x01\x12\x04\
+    \x89\x01\x0f\x16\n\r\n\x05\x04\x05\x02\x01\x03\x12\x04\x89\x01\x19\x1a\n\
+    \x0c\n\x02\x04\x06\x12\x06\x8c\x01\0\x8e\x01\x01\n\x0b\n\x03\x04\x06\x01\
+    \x12\x04\x8c\x01\x08\x1b\n\x0c\n\x04\x04\x06\x02\0\x12\x04\x8d\x01\x08\
+    \x19\n\x0f\n\x05\x04\x06\x02\0\x04\x12\x06\x8d\x01\x08\x8c\x01\x1d\n\r\n\
+    \x05\x04\x06\x02\0\x05\x12\x04\x8d\x01\x08\r\n\r\n\x05\x04\x06\x02\0\x01\
+    \x12\x04\x8d\x01\x0e\x14\n\r\n\x05\x04\x06\x02\0\x03\x12\x04\x8d\x01\x17\
+    \x18\nm\n\x02\x04\x07\x12\x06\x91\x01\0\x95\x01\x01\x1a_\x20ListProcesse\
+    sRequest\x20contains\x20the\x20options\x20used\x20to\x20list\x20running\
+    \x20processes\x20inside\x20the\x20container\n\n\x0b\n\x03\x04\x07\x01\
+    \x12\x04\x91\x01\x08\x1c\n\x0c\n\x04\x04\x07\x02\0\x12\x04\x92\x01\x08\
+    \x20\n\x0f\n\x05\x04\x07\x02\0\x04\x12\x06\x92\x01\x08\x91\x01\x1e\n\r\n\
+    \x05\x04\x07\x02\0\x05\x12\x04\x92\x01\x08\x0e\n\r\n\x05\x04\x07\x02\0\
+    \x01\x12\x04\x92\x01\x0f\x1b\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\x92\x01\
+    \x1e\x1f\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\x93\x01\x08\x1a\n\x0f\n\x05\
+    \x04\x07\x02\x01\x04\x12\x06\x93\x01\x08\x92\x01\x20\n\r\n\x05\x04\x07\
+    \x02\x01\x05\x12\x04\x93\x01\x08\x0e\n\r\n\x05\x04\x07\x02\x01\x01\x12\
+    \x04\x93\x01\x0f\x15\n\r\n\x05\x04\x07\x02\x01\x03\x12\x04\x93\x01\x18\
+    \x19\n\x0c\n\x04\x04\x07\x02\x02\x12\x04\x94\x01\x08!\n\r\n\x05\x04\x07\
+    \x02\x02\x04\x12\x04\x94\x01\x08\x10\n\r\n\x05\x04\x07\x02\x02\x05\x12\
+    \x04\x94\x01\x11\x17\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\x94\x01\x18\
+    \x1c\n\r\n\x05\x04\x07\x02\x02\x03\x12\x04\x94\x01\x1f\x20\nc\n\x02\x04\
+    \x08\x12\x06\x98\x01\0\x9a\x01\x01\x1aU\x20ListProcessesResponse\x20repr\
+    esents\x20the\x20list\x20of\x20running\x20processes\x20inside\x20the\x20\
+    container\n\n\x0b\n\x03\x04\x08\x01\x12\x04\x98\x01\x08\x1d\n\x0c\n\x04\
+    \x04\x08\x02\0\x12\x04\x99\x01\x08\x1f\n\x0f\n\x05\x04\x08\x02\0\x04\x12\
+    \x06\x99\x01\x08\x98\x01\x1f\n\r\n\x05\x04\x08\x02\0\x05\x12\x04\x99\x01\
+    \x08\r\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\x99\x01\x0e\x1a\n\r\n\x05\x04\
+    \x08\x02\0\x03\x12\x04\x99\x01\x1d\x1e\n\x0c\n\x02\x04\t\x12\x06\x9c\x01\
+    \0\x9f\x01\x01\n\x0b\n\x03\x04\t\x01\x12\x04\x9c\x01\x08\x1e\n\x0c\n\x04\
+    \x04\t\x02\0\x12\x04\x9d\x01\x08\x20\n\x0f\n\x05\x04\t\x02\0\x04\x12\x06\
+    \x9d\x01\x08\x9c\x01\x20\n\r\n\x05\x04\t\x02\0\x05\x12\x04\x9d\x01\x08\
+    \x0e\n\r\n\x05\x04\t\x02\0\x01\x12\x04\x9d\x01\x0f\x1b\n\r\n\x05\x04\t\
+    \x02\0\x03\x12\x04\x9d\x01\x1e\x1f\n\x0c\n\x04\x04\t\x02\x01\x12\x04\x9e\
+    \x01\x08%\n\x0f\n\x05\x04\t\x02\x01\x04\x12\x06\x9e\x01\x08\x9d\x01\x20\
+    \n\r\n\x05\x04\t\x02\x01\x06\x12\x04\x9e\x01\x08\x16\n\r\n\x05\x04\t\x02\
+    \x01\x01\x12\x04\x9e\x01\x17\x20\n\r\n\x05\x04\t\x02\x01\x03\x12\x04\x9e\
+    \x01#$\n\x0c\n\x02\x04\n\x12\x06\xa1\x01\0\xa3\x01\x01\n\x0b\n\x03\x04\n\
+    \x01\x12\x04\xa1\x01\x08\x1d\n\x0c\n\x04\x04\n\x02\0\x12\x04\xa2\x01\x04\
+    \x1c\n\x0f\n\x05\x04\n\x02\0\x04\x12\x06\xa2\x01\x04\xa1\x01\x1f\n\r\n\
+    \x05\x04\n\x02\0\x05\x12\x04\xa2\x01\x04\n\n\r\n\x05\x04\n\x02\0\x01\x12\
+    \x04\xa2\x01\x0b\x17\n\r\n\x05\x04\n\x02\0\x03\x12\x04\xa2\x01\x1a\x1b\n\
+    \x0c\n\x02\x04\x0b\x12\x06\xa5\x01\0\xa7\x01\x01\n\x0b\n\x03\x04\x0b\x01\
+    \x12\x04\xa5\x01\x08\x1d\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xa6\x01\x04\
+    \x1c\n\x0f\n\x05\x04\x0b\x02\0\x04\x12\x06\xa6\x01\x04\xa5\x01\x1f\n\r\n\
+    \x05\x04\x0b\x02\0\x05\x12\x04\xa6\x01\x04\n\n\r\n\x05\x04\x0b\x02\0\x01\
+    \x12\x04\xa6\x01\x0b\x17\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\xa6\x01\x1a\
+    \x1b\n\x0c\n\x02\x04\x0c\x12\x06\xa9\x01\0\xab\x01\x01\n\x0b\n\x03\x04\
+    \x0c\x01\x12\x04\xa9\x01\x08\x1e\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\xaa\
+    \x01\x04\x1c\n\x0f\n\x05\x04\x0c\x02\0\x04\x12\x06\xaa\x01\x04\xa9\x01\
+    \x20\n\r\n\x05\x04\x0c\x02\0\x05\x12\x04\xaa\x01\x04\n\n\r\n\x05\x04\x0c\
+    \x02\0\x01\x12\x04\xaa\x01\x0b\x17\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\
+    \xaa\x01\x1a\x1b\n\x0c\n\x02\x04\r\x12\x06\xad\x01\0\xb2\x01\x01\n\x0b\n\
+    \x03\x04\r\x01\x12\x04\xad\x01\x08\x10\n\x0c\n\x04\x04\r\x02\0\x12\x04\
+    \xae\x01\x08\x1f\n\x0f\n\x05\x04\r\x02\0\x04\x12\x06\xae\x01\x08\xad\x01\
+    \x12\n\r\n\x05\x04\r\x02\0\x05\x12\x04\xae\x01\x08\x0e\n\r\n\x05\x04\r\
+    \x02\0\x01\x12\x04\xae\x01\x0f\x1a\n\r\n\x05\x04\r\x02\0\x03\x12\x04\xae\
+    \x01\x1d\x1e\n\x0c\n\x04\x04\r\x02\x01\x12\x04\xaf\x01\x08)\n\r\n\x05\
+    \x04\r\x02\x01\x04\x12\x04\xaf\x01\x08\x10\n\r\n\x05\x04\r\x02\x01\x05\
+    \x12\x04\xaf\x01\x11\x17\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\xaf\x01\x18\
+    $\n\r\n\x05\x04\r\x02\x01\x03\x12\x04\xaf\x01'(\n\x0c\n\x04\x04\r\x02\
+    \x02\x12\x04\xb0\x01\x08'\n\x0f\n\x05\x04\r\x02\x02\x04\x12\x06\xb0\x01\
+    \x08\xaf\x01)\n\r\n\x05\x04\r\x02\x02\x05\x12\x04\xb0\x01\x08\x0e\n\r\n\
+    \x05\x04\r\x02\x02\x01\x12\x04\xb0\x01\x0f\"\n\r\n\x05\x04\r\x02\x02\x03\
+    \x12\x04\xb0\x01%&\n\x0c\n\x04\x04\r\x02\x03\x12\x04\xb1\x01\x08%\n\x0f\
+    \n\x05\x04\r\x02\x03\x04\x12\x06\xb1\x01\x08\xb0\x01'\n\r\n\x05\x04\r\
+    \x02\x03\x05\x12\x04\xb1\x01\x08\x0e\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\
+    \xb1\x01\x0f\x20\n\r\n\x05\x04\r\x02\x03\x03\x12\x04\xb1\x01#$\n\x0c\n\
+    \x02\x04\x0e\x12\x06\xb4\x01\0\xb8\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\
+    \x04\xb4\x01\x08\x16\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\xb5\x01\x08\x1b\n\
+    \x0f\n\x05\x04\x0e\x02\0\x04\x12\x06\xb5\x01\x08\xb4\x01\x18\n\r\n\x05\
+    \x04\x0e\x02\0\x05\x12\x04\xb5\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\0\x01\
+    \x12\x04\xb5\x01\x0f\x16\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\xb5\x01\x19\
+    \x1a\n\x0c\n\x04\x04\x0e\x02\x01\x12\x04\xb6\x01\x08%\n\x0f\n\x05\x04\
+    \x0e\x02\x01\x04\x12\x06\xb6\x01\x08\xb5\x01\x1b\n\r\n\x05\x04\x0e\x02\
+    \x01\x05\x12\x04\xb6\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x01\x01\x12\x04\
+    \xb6\x01\x0f\x20\n\r\n\x05\x04\x0e\x02\x01\x03\x12\x04\xb6\x01#$\n\x0c\n\
+    \x04\x04\x0e\x02\x02\x12\x04\xb7\x01\x08\"\n\x0f\n\x05\x04\x0e\x02\x02\
+    \x04\x12\x06\xb7\x01\x08\xb6\x01%\n\r\n\x05\x04\x0e\x02\x02\x05\x12\x04\
+    \xb7\x01\x08\x0e\n\r\n\x05\x04\x0e\x02\x02\x01\x12\x04\xb7\x01\x0f\x1d\n\
+    \r\n\x05\x04\x0e\x02\x02\x03\x12\x04\xb7\x01\x20!\n\x0c\n\x02\x04\x0f\
+    \x12\x06\xba\x01\0\xbd\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\xba\x01\
+    \x08\x10\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\xbb\x01\x08\x1f\n\x0f\n\x05\
+    \x04\x0f\x02\0\x04\x12\x06\xbb\x01\x08\xba\x01\x12\n\r\n\x05\x04\x0f\x02\
+    \0\x06\x12\x04\xbb\x01\x08\x10\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\xbb\
+    \x01\x11\x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xbb\x01\x1d\x1e\n\x0c\n\
+    \x04\x04\x0f\x02\x01\x12\x04\xbc\x01\x08+\n\x0f\n\x05\x04\x0f\x02\x01\
+    \x04\x12\x06\xbc\x01\x08\xbb\x01\x1f\n\r\n\x05\x04\x0f\x02\x01\x06\x12\
+    \x04\xbc\x01\x08\x16\n\r\n\x05\x04\x0f\x02\x01\x01\x12\x04\xbc\x01\x17&\
+    \n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\xbc\x01)*\n\x0c\n\x02\x04\x10\x12\
+    \x06\xbf\x01\0\xc2\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\xbf\x01\x08\
+    \x11\n\x0c\n\x04\x04\x10\x02\0\x12\x04\xc0\x01\x08\x1b\n\x0f\n\x05\x04\
+    \x10\x02\0\x04\x12\x06\xc0\x01\x08\xbf\x01\x13\n\r\n\x05\x04\x10\x02\0\
+    \x05\x12\x04\xc0\x01\x08\x0e\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\xc0\x01\
+    \x0f\x16\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xc0\x01\x19\x1a\n\x0c\n\x04\
+    \x04\x10\x02\x01\x12\x04\xc1\x01\x08\x19\n\x0f\n\x05\x04\x10\x02\x01\x04\
+    \x12\x06\xc1\x01\x08\xc0\x01\x1b\n\r\n\x05\x04\x10\x02\x01\x05\x12\x04\
+    \xc1\x01\x08\x0e\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\xc1\x01\x0f\x14\n\
+    \r\n\x05\x04\x10\x02\x01\x03\x12\x04\xc1\x01\x17\x18\n\x0c\n\x02\x04\x11\
+    \x12\x06\xc4\x01\0\xc9\x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xc4\x01\
+    \x08\x12\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xc5\x01\x08\x19\n\x0f\n\x05\
+    \x04\x11\x02\0\x04\x12\x06\xc5\x01\x08\xc4\x01\x14\n\r\n\x05\x04\x11\x02\
+    \0\x05\x12\x04\xc5\x01\x08\x0e\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xc5\
+    \x01\x0f\x14\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xc5\x01\x17\x18\n\x0c\n\
+    \x04\x04\x11\x02\x01\x12\x04\xc6\x01\x08\x1d\n\x0f\n\x05\x04\x11\x02\x01\
+    \x04\x12\x06\xc6\x01\x08\xc5\x01\x19\n\r\n\x05\x04\x11\x02\x01\x05\x12\
+    \x04\xc6\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\xc6\x01\x0f\
+    \x18\n\r\n\x05\x04\x11\x02\x01\x03\x12\x04\xc6\x01\x1b\x1c\n\x0c\n\x04\
+    \x04\x11\x02\x02\x12\x04\xc7\x01\x08\x1b\n\x0f\n\x05\x04\x11\x02\x02\x04\
+    \x12\x06\xc7\x01\x08\xc6\x01\x1d\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\
+    \xc7\x01\x08\x0e\n\r\n\x05\x04\x11\x02\x02\x01\x12\x04\xc7\x01\x0f\x16\n\
+    \r\n\x05\x04\x11\x02\x02\x03\x12\x04\xc7\x01\x19\x1a\n\x0c\n\x04\x04\x11\
+    \x02\x03\x12\x04\xc8\x01\x08\x19\n\x0f\n\x05\x04\x11\x02\x03\x04\x12\x06\
+    \xc8\x01\x08\xc7\x01\x1b\n\r\n\x05\x04\x11\x02\x03\x05\x12\x04\xc8\x01\
+    \x08\x0e\n\r\n\x05\x04\x11\x02\x03\x01\x12\x04\xc8\x01\x0f\x14\n\r\n\x05\
+    \x04\x11\x02\x03\x03\x12\x04\xc8\x01\x17\x18\n\x0c\n\x02\x04\x12\x12\x06\
+    \xcb\x01\0\xd2\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\xcb\x01\x08\x13\n\
+    \x0c\n\x04\x04\x12\x02\0\x12\x04\xcc\x01\x08\x19\n\x0f\n\x05\x04\x12\x02\
+    \0\x04\x12\x06\xcc\x01\x08\xcb\x01\x15\n\r\n\x05\x04\x12\x02\0\x05\x12\
+    \x04\xcc\x01\x08\x0e\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xcc\x01\x0f\x14\
+    \n\r\n\x05\x04\x12\x02\0\x03\x12\x04\xcc\x01\x17\x18\n\x0c\n\x04\x04\x12\
+    \x02\x01\x12\x04\xcd\x01\x08\x1d\n\x0f\n\x05\x04\x12\x02\x01\x04\x12\x06\
+    \xcd\x01\x08\xcc\x01\x19\n\r\n\x05\x04\x12\x02\x01\x06\x12\x04\xcd\x01\
+    \x08\x12\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\xcd\x01\x13\x18\n\r\n\x05\
+    \x04\x12\x02\x01\x03\x12\x04\xcd\x01\x1b\x1c\n\x0c\n\x04\x04\x12\x02\x02\
+    \x12\x04\xce\x01\x08\"\n\x0f\n\x05\x04\x12\x02\x02\x04\x12\x06\xce\x01\
+    \x08\xcd\x01\x1d\n\r\n\x05\x04\x12\x02\x02\x06\x12\x04\xce\x01\x08\x12\n\
+    \r\n\x05\x04\x12\x02\x02\x01\x12\x04\xce\x01\x13\x1d\n\r\n\x05\x04\x12\
+    \x02\x02\x03\x12\x04\xce\x01\x20!\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\
+    \xcf\x01\x08$\n\x0f\n\x05\x04\x12\x02\x03\x04\x12\x06\xcf\x01\x08\xce\
+    \x01\"\n\r\n\x05\x04\x12\x02\x03\x06\x12\x04\xcf\x01\x08\x12\n\r\n\x05\
+    \x04\x12\x02\x03\x01\x12\x04\xcf\x01\x13\x1f\n\r\n\x05\x04\x12\x02\x03\
+    \x03\x12\x04\xcf\x01\"#\n\x0c\n\x04\x04\x12\x02\x04\x12\x04\xd0\x01\x08\
+    \x1f\n\x0f\n\x05\x04\x12\x02\x04\x04\x12\x06\xd0\x01\x08\xcf\x01$\n\r\n\
+    \x05\x04\x12\x02\x04\x05\x12\x04\xd0\x01\x08\x0c\n\r\n\x05\x04\x12\x02\
+    \x04\x01\x12\x04\xd0\x01\r\x1a\n\r\n\x05\x04\x12\x02\x04\x03\x12\x04\xd0\
+    \x01\x1d\x1e\n\x0c\n\x04\x04\x12\x02\x05\x12\x04\xd1\x01\x08&\n\x0f\n\
+    \x05\x04\x12\x02\x05\x04\x12\x06\xd1\x01\x08\xd0\x01\x1f\n\r\n\x05\x04\
+    \x12\x02\x05\x06\x12\x04\xd1\x01\x08\x1b\n\r\n\x05\x04\x12\x02\x05\x01\
+    \x12\x04\xd1\x01\x1c!\n\r\n\x05\x04\x12\x02\x05\x03\x12\x04\xd1\x01$%\n\
+    \x0c\n\x02\x04\x13\x12\x06\xd5\x01\0\xda\x01\x01\n\x0b\n\x03\x04\x13\x01\
+    \x12\x04\xd5\x01\x08\x17\n\x0c\n\x04\x04\x13\x02\0\x12\x04\xd6\x01\x08\
+    \x19\n\x0f\n\x05\x04\x13\x02\0\x04\x12\x06\xd6\x01\x08\xd5\x01\x19\n\r\n\
+    \x05\x04\x13\x02\0\x05\x12\x04\xd6\x01\x08\x0e\n\r\n\x05\x04\x13\x02\0\
+    \x01\x12\x04\xd6\x01\x0f\x14\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\xd6\x01\
+    \x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\xd7\x01\x08\x19\n\x0f\n\x05\
+    \x04\x13\x02\x01\x04\x12\x06\xd7\x01\x08\xd6\x01\x19\n\r\n\x05\x04\x13\
+    \x02\x01\x05\x12\x04\xd7\x01\x08\x0e\n\r\n\x05\x04\x13\x02\x01\x01\x12\
+    \x04\xd7\x01\x0f\x14\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xd7\x01\x17\
+    \x18\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xd8\x01\x08\x16\n\x0f\n\x05\x04\
+    \x13\x02\x02\x04\x12\x06\xd8\x01\x08\xd7\x01\x19\n\r\n\x05\x04\x13\x02\
+    \x02\x05\x12\x04\xd8\x01\x08\x0e\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\
+    \xd8\x01\x0f\x11\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\xd8\x01\x14\x15\n\
+    \x0c\n\x04\x04\x13\x02\x03\x12\x04\xd9\x01\x08\x19\n\x0f\n\x05\x04\x13\
+    \x02\x03\x04\x12\x06\xd9\x01\x08\xd8\x01\x16\n\r\n\x05\x04\x13\x02\x03\
+    \x05\x12\x04\xd9\x01\x08\x0e\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\xd9\
+    \x01\x0f\x14\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\xd9\x01\x17\x18\n\x0c\
+    \n\x02\x04\x14\x12\x06\xdc\x01\0\xe5\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\
+    \x04\xdc\x01\x08\x12\nH\n\x04\x04\x14\x02\0\x12\x04\xdd\x01\x08@\":\x20n\
+    umber\x20of\x20bytes\x20transferred\x20to\x20and\x20from\x20the\x20block\
+    \x20device\n\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\xdd\x01\x08\x10\n\r\n\
+    \x05\x04\x14\x02\0\x06\x12\x04\xdd\x01\x11\x20\n\r\n\x05\x04\x14\x02\0\
+    \x01\x12\x04\xdd\x01!;\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xdd\x01>?\n\
+    \x0c\n\x04\x04\x14\x02\x01\x12\x04\xde\x01\x08;\n\r\n\x05\x04\x14\x02\
+    \x01\x04\x12\x04\xde\x01\x08\x10\n\r\n\x05\x04\x14\x02\x01\x06\x12\x04\
+    \xde\x01\x11\x20\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\xde\x01!6\n\r\n\
+    \x05\x04\x14\x02\x01\x03\x12\x04\xde\x019:\n\x0c\n\x04\x04\x14\x02\x02\
+    \x12\x04\xdf\x01\x089\n\r\n\x05\x04\x14\x02\x02\x04\x12\x04\xdf\x01\x08\
+    \x10\n\r\n\x05\x04\x14\x02\x02\x06\x12\x04\xdf\x01\x11\x20\n\r\n\x05\x04\
+    \x14\x02\x02\x01\x12\x04\xdf\x01!4\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\
+    \xdf\x0178\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\xe0\x01\x08?\n\r\n\x05\
+    \x04\x14\x02\x03\x04\x12\x04\xe0\x01\x08\x10\n\r\n\x05\x04\x14\x02\x03\
+    \x06\x12\x04\xe0\x01\x11\x20\n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\xe0\
+    \x01!:\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\xe0\x01=>\n\x0c\n\x04\x04\
+    \x14\x02\x04\x12\x04\xe1\x01\x08<\n\r\n\x05\x04\x14\x02\x04\x04\x12\x04\
+    \xe1\x01\x08\x10\n\r\n\x05\x04\x14\x02\x04\x06\x12\x04\xe1\x01\x11\x20\n\
+    \r\n\x05\x04\x14\x02\x04\x01\x12\x04\xe1\x01!7\n\r\n\x05\x04\x14\x02\x04\
+    \x03\x12\x04\xe1\x01:;\n\x0c\n\x04\x04\x14\x02\x05\x12\x04\xe2\x01\x089\
+    \n\r\n\x05\x04\x14\x02\x05\x04\x12\x04\xe2\x01\x08\x10\n\r\n\x05\x04\x14\
+    \x02\x05\x06\x12\x04\xe2\x01\x11\x20\n\r\n\x05\x04\x14\x02\x05\x01\x12\
+    \x04\xe2\x01!4\n\r\n\x05\x04\x14\x02\x05\x03\x12\x04\xe2\x0178\n\x0c\n\
+    \x04\x04\x14\x02\x06\x12\x04\xe3\x01\x087\n\r\n\x05\x04\x14\x02\x06\x04\
+    \x12\x04\xe3\x01\x08\x10\n\r\n\x05\x04\x14\x02\x06\x06\x12\x04\xe3\x01\
+    \x11\x20\n\r\n\x05\x04\x14\x02\x06\x01\x12\x04\xe3\x01!2\n\r\n\x05\x04\
+    \x14\x02\x06\x03\x12\x04\xe3\x0156\n\x0c\n\x04\x04\x14\x02\x07\x12\x04\
+    \xe4\x01\x087\n\r\n\x05\x04\x14\x02\x07\x04\x12\x04\xe4\x01\x08\x10\n\r\
+    \n\x05\x04\x14\x02\x07\x06\x12\x04\xe4\x01\x11\x20\n\r\n\x05\x04\x14\x02\
+    \x07\x01\x12\x04\xe4\x01!2\n\r\n\x05\x04\x14\x02\x07\x03\x12\x04\xe4\x01\
+    56\n\x0c\n\x02\x04\x15\x12\x06\xe7\x01\0\xeb\x01\x01\n\x0b\n\x03\x04\x15\
+    \x01\x12\x04\xe7\x01\x08\x14\n\x0c\n\x04\x04\x15\x02\0\x12\x04\xe8\x01\
+    \x08\x19\n\x0f\n\x05\x04\x15\x02\0\x04\x12\x06\xe8\x01\x08\xe7\x01\x16\n\
+    \r\n\x05\x04\x15\x02\0\x05\x12\x04\xe8\x01\x08\x0e\n\r\n\x05\x04\x15\x02\
+    \0\x01\x12\x04\xe8\x01\x0f\x14\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\xe8\
+    \x01\x17\x18\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xe9\x01\x08\x1d\n\x0f\n\
+    \x05\x04\x15\x02\x01\x04\x12\x06\xe9\x01\x08\xe8\x01\x19\n\r\n\x05\x04\
+    \x15\x02\x01\x05\x12\x04\xe9\x01\x08\x0e\n\r\n\x05\x04\x15\x02\x01\x01\
+    \x12\x04\xe9\x01\x0f\x18\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\xe9\x01\
+    \x1b\x1c\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xea\x01\x08\x1b\n\x0f\n\x05\
+    \x04\x15\x02\x02\x04\x12\x06\xea\x01\x08\xe9\x01\x1d\n\r\n\x05\x04\x15\
+    \x02\x02\x05\x12\x04\xea\x01\x08\x0e\n\r\n\x05\x04\x15\x02\x02\x01\x12\
+    \x04\xea\x01\x0f\x16\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\xea\x01\x19\
+    \x1a\n\x0c\n\x02\x04\x16\x12\x06\xed\x01\0\xf4\x01\x01\n\x0b\n\x03\x04\
+    \x16\x01\x12\x04\xed\x01\x08\x13\n\x0c\n\x04\x04\x16\x02\0\x12\x04\xee\
+    \x01\x04\x1b\n\x0f\n\x05\x04\x16\x02\0\x04\x12\x06\xee\x01\x04\xed\x01\
+    \x15\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\xee\x01\x04\x0c\n\r\n\x05\x04\
+    \x16\x02\0\x01\x12\x04\xee\x01\r\x16\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\
+    \xee\x01\x19\x1a\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xef\x01\x04\"\n\x0f\
+    \n\x05\x04\x16\x02\x01\x04\x12\x06\xef\x01\x04\xee\x01\x1b\n\r\n\x05\x04\
+    \x16\x02\x01\x06\x12\x04\xef\x01\x04\x0f\n\r\n\x05\x04\x16\x02\x01\x01\
+    \x12\x04\xef\x01\x10\x1c\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\xef\x01\
+    \x20!\n\x0c\n\x04\x04\x16\x02\x02\x12\x04\xf0\x01\x04\x1d\n\x0f\n\x05\
+    \x04\x16\x02\x02\x04\x12\x06\xf0\x01\x04\xef\x01\"\n\r\n\x05\x04\x16\x02\
+    \x02\x06\x12\x04\xf0\x01\x04\r\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xf0\
+    \x01\x0e\x18\n\r\n\x05\x04\x16\x02\x02\x03\x12\x04\xf0\x01\x1b\x1c\n\x0c\
+    \n\x04\x04\x16\x02\x03\x12\x04\xf1\x01\x04\x1f\n\x0f\n\x05\x04\x16\x02\
+    \x03\x04\x12\x06\xf1\x01\x04\xf0\x01\x1d\n\r\n\x05\x04\x16\x02\x03\x06\
+    \x12\x04\xf1\x01\x04\x0e\n\r\n\x05\x04\x16\x02\x03\x01\x12\x04\xf1\x01\
+    \x0f\x1a\n\r\n\x05\x04\x16\x02\x03\x03\x12\x04\xf1\x01\x1d\x1e\nR\n\x04\
+    \x04\x16\x02\x04\x12\x04\xf2\x01\x040\"D\x20the\x20map\x20is\x20in\x20th\
+    e\x20format\x20\"size\x20of\x20hugepage:\x20stats\x20of\x20the\x20hugepa\
+    ge\"\n\n\x0f\n\x05\x04\x16\x02\x04\x04\x12\x06\xf2\x01\x04\xf1\x01\x1f\n\
+    \r\n\x05\x04\x16\x02\x04\x06\x12\x04\xf2\x01\x04\x1d\n\r\n\x05\x04\x16\
+    \x02\x04\x01\x12\x04\xf2\x01\x1e+\n\r\n\x05\x04\x16\x02\x04\x03\x12\x04\
+    \xf2\x01./\n\x0c\n\x02\x04\x17\x12\x06\xf6\x01\0\x80\x02\x01\n\x0b\n\x03\
+    \x04\x17\x01\x12\x04\xf6\x01\x08\x14\n\x0c\n\x04\x04\x17\x02\0\x12\x04\
+    \xf7\x01\x08\x18\n\x0f\n\x05\x04\x17\x02\0\x04\x12\x06\xf7\x01\x08\xf6\
+    \x01\x16\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xf7\x01\x08\x0e\n\r\n\x05\
+    \x04\x17\x02\0\x01\x12\x04\xf7\x01\x0f\x13\n\r\n\x05\x04\x17\x02\0\x03\
+    \x12\x04\xf7\x01\x16\x17\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xf8\x01\x08\
+    \x1c\n\x0f\n\x05\x04\x17\x02\x01\x04\x12\x06\xf8\x01\x08\xf7\x01\x18\n\r\
+    \n\x05\x04\x17\x02\x01\x05\x12\x04\xf8\x01\x08\x0e\n\r\n\x05\x04\x17\x02\
+    \x01\x01\x12\x04\xf8\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\
+    \xf8\x01\x1a\x1b\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xf9\x01\x08\x1e\n\
+    \x0f\n\x05\x04\x17\x02\x02\x04\x12\x06\xf9\x01\x08\xf8\x01\x1c\n\r\n\x05\
+    \x04\x17\x02\x02\x05\x12\x04\xf9\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x02\
+    \x01\x12\x04\xf9\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\xf9\
+    \x01\x1c\x1d\n\x0c\n\x04\x04\x17\x02\x03\x12\x04\xfa\x01\x08\x1e\n\x0f\n\
+    \x05\x04\x17\x02\x03\x04\x12\x06\xfa\x01\x08\xf9\x01\x1e\n\r\n\x05\x04\
+    \x17\x02\x03\x05\x12\x04\xfa\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x03\x01\
+    \x12\x04\xfa\x01\x0f\x18\n\r\n\x05\x04\x17\x02\x03\x03\x12\x04\xfa\x01\
+    \x1c\x1d\n\x0c\n\x04\x04\x17\x02\x04\x12\x04\xfb\x01\x08\x1e\n\x0f\n\x05\
+    \x04\x17\x02\x04\x04\x12\x06\xfb\x01\x08\xfa\x01\x1e\n\r\n\x05\x04\x17\
+    \x02\x04\x05\x12\x04\xfb\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x04\x01\x12\
+    \x04\xfb\x01\x0f\x19\n\r\n\x05\x04\x17\x02\x04\x03\x12\x04\xfb\x01\x1c\
+    \x1d\n\x0c\n\x04\x04\x17\x02\x05\x12\x04\xfc\x01\x08\x1c\n\x0f\n\x05\x04\
+    \x17\x02\x05\x04\x12\x06\xfc\x01\x08\xfb\x01\x1e\n\r\n\x05\x04\x17\x02\
+    \x05\x05\x12\x04\xfc\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x05\x01\x12\x04\
+    \xfc\x01\x0f\x17\n\r\n\x05\x04\x17\x02\x05\x03\x12\x04\xfc\x01\x1a\x1b\n\
+    \x0c\n\x04\x04\x17\x02\x06\x12\x04\xfd\x01\x08\x1e\n\x0f\n\x05\x04\x17\
+    \x02\x06\x04\x12\x06\xfd\x01\x08\xfc\x01\x1c\n\r\n\x05\x04\x17\x02\x06\
+    \x05\x12\x04\xfd\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x06\x01\x12\x04\xfd\
+    \x01\x0f\x19\n\r\n\x05\x04\x17\x02\x06\x03\x12\x04\xfd\x01\x1c\x1d\n\x0c\
+    \n\x04\x04\x17\x02\x07\x12\x04\xfe\x01\x08\x1d\n\x0f\n\x05\x04\x17\x02\
+    \x07\x04\x12\x06\xfe\x01\x08\xfd\x01\x1e\n\r\n\x05\x04\x17\x02\x07\x05\
+    \x12\x04\xfe\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x07\x01\x12\x04\xfe\x01\
+    \x0f\x18\n\r\n\x05\x04\x17\x02\x07\x03\x12\x04\xfe\x01\x1b\x1c\n\x0c\n\
+    \x04\x04\x17\x02\x08\x12\x04\xff\x01\x08\x1e\n\x0f\n\x05\x04\x17\x02\x08\
+    \x04\x12\x06\xff\x01\x08\xfe\x01\x1d\n\r\n\x05\x04\x17\x02\x08\x05\x12\
+    \x04\xff\x01\x08\x0e\n\r\n\x05\x04\x17\x02\x08\x01\x12\x04\xff\x01\x0f\
+    \x19\n\r\n\x05\x04\x17\x02\x08\x03\x12\x04\xff\x01\x1c\x1d\n\x0c\n\x02\
+    \x04\x18\x12\x06\x82\x02\0\x85\x02\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\
+    \x82\x02\x08\x1e\n\x0c\n\x04\x04\x18\x02\0\x12\x04\x83\x02\x08%\n\x0f\n\
+    \x05\x04\x18\x02\0\x04\x12\x06\x83\x02\x08\x82\x02\x20\n\r\n\x05\x04\x18\
+    \x02\0\x06\x12\x04\x83\x02\x08\x13\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\
+    \x83\x02\x14\x20\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\x83\x02#$\n\x0c\n\
+    \x04\x04\x18\x02\x01\x12\x04\x84\x02\x080\n\r\n\x05\x04\x18\x02\x01\x04\
+    \x12\x04\x84\x02\x08\x10\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\x84\x02\
+    \x11\x1d\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\x84\x02\x1e+\n\r\n\x05\
+    \x04\x18\x02\x01\x03\x12\x04\x84\x02./\n\x0c\n\x02\x04\x19\x12\x06\x87\
+    \x02\0\x8b\x02\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\x87\x02\x08\x1a\n\x0c\
+    \n\x04\x04\x19\x02\0\x12\x04\x88\x02\x08\x20\n\x0f\n\x05\x04\x19\x02\0\
+    \x04\x12\x06\x88\x02\x08\x87\x02\x1c\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\
+    \x88\x02\x08\x0e\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\x88\x02\x0f\x1b\n\r\
+    \n\x05\x04\x19\x02\0\x03\x12\x04\x88\x02\x1e\x1f\n\x0c\n\x04\x04\x19\x02\
+    \x01\x12\x04\x89\x02\x08\x1b\n\x0f\n\x05\x04\x19\x02\x01\x04\x12\x06\x89\
+    \x02\x08\x88\x02\x20\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\x89\x02\x08\
+    \x0e\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\x89\x02\x0f\x16\n\r\n\x05\x04\
+    \x19\x02\x01\x03\x12\x04\x89\x02\x19\x1a\n\x0c\n\x04\x04\x19\x02\x02\x12\
+    \x04\x8a\x02\x08\x17\n\x0f\n\x05\x04\x19\x02\x02\x04\x12\x06\x8a\x02\x08\
+    \x89\x02\x1b\n\r\n\x05\x04\x19\x02\x02\x05\x12\x04\x8a\x02\x08\r\n\r\n\
+    \x05\x04\x19\x02\x02\x01\x12\x04\x8a\x02\x0e\x12\n\r\n\x05\x04\x19\x02\
+    \x02\x03\x12\x04\x8a\x02\x15\x16\n\x0c\n\x02\x04\x1a\x12\x06\x8d\x02\0\
+    \x8f\x02\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\x8d\x02\x08\x1b\n\x0c\n\x04\
+    \x04\x1a\x02\0\x12\x04\x8e\x02\x08\x17\n\x0f\n\x05\x04\x1a\x02\0\x04\x12\
+    \x06\x8e\x02\x08\x8d\x02\x1d\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\x8e\x02\
+    \x08\x0e\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\x8e\x02\x0f\x12\n\r\n\x05\
+    \x04\x1a\x02\0\x03\x12\x04\x8e\x02\x15\x16\n\x0c\n\x02\x04\x1b\x12\x06\
+    \x91\x02\0\x95\x02\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\x91\x02\x08\x19\n\
+    \x0c\n\x04\x04\x1b\x02\0\x12\x04\x92\x02\x08\x20\n\x0f\n\x05\x04\x1b\x02\
+    \0\x04\x12\x06\x92\x02\x08\x91\x02\x1b\n\r\n\x05\x04\x1b\x02\0\x05\x12\
+    \x04\x92\x02\x08\x0e\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\x92\x02\x0f\x1b\
+    \n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\x92\x02\x1e\x1f\n\x0c\n\x04\x04\x1b\
+    \x02\x01\x12\x04\x93\x02\x08\x1b\n\x0f\n\x05\x04\x1b\x02\x01\x04\x12\x06\
+    \x93\x02\x08\x92\x02\x20\n\r\n\x05\x04\x1b\x02\x01\x05\x12\x04\x93\x02\
+    \x08\x0e\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\x93\x02\x0f\x16\n\r\n\x05\
+    \x04\x1b\x02\x01\x03\x12\x04\x93\x02\x19\x1a\n\x0c\n\x04\x04\x1b\x02\x02\
+    \x12\x04\x94\x02\x08\x17\n\x0f\n\x05\x04\x1b\x02\x02\x04\x12\x06\x94\x02\
+    \x08\x93\x02\x1b\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\x94\x02\x08\x0e\n\
+    \r\n\x05\x04\x1b\x02\x02\x01\x12\x04\x94\x02\x0f\x12\n\r\n\x05\x04\x1b\
+    \x02\x02\x03\x12\x04\x94\x02\x15\x16\n\x0c\n\x02\x04\x1c\x12\x06\x97\x02\
+    \0\x99\x02\x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\x97\x02\x08\x1a\n\x0c\n\
+    \x04\x04\x1c\x02\0\x12\x04\x98\x02\x08\x17\n\x0f\n\x05\x04\x1c\x02\0\x04\
+    \x12\x06\x98\x02\x08\x97\x02\x1c\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\x98\
+    \x02\x08\r\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\x98\x02\x0e\x12\n\r\n\x05\
+    \x04\x1c\x02\0\x03\x12\x04\x98\x02\x15\x16\n\x0c\n\x02\x04\x1d\x12\x06\
+    \x9b\x02\0\x9e\x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\x9b\x02\x08\x19\n\
+    \x0c\n\x04\x04\x1d\x02\0\x12\x04\x9c\x02\x08\x20\n\x0f\n\x05\x04\x1d\x02\
+    \0\x04\x12\x06\x9c\x02\x08\x9b\x02\x1b\n\r\n\x05\x04\x1d\x02\0\x05\x12\
+    \x04\x9c\x02\x08\x0e\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\x9c\x02\x0f\x1b\
+    \n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\x9c\x02\x1e\x1f\n\x0c\n\x04\x04\x1d\
+    \x02\x01\x12\x04\x9d\x02\x08\x1b\n\x0f\n\x05\x04\x1d\x02\x01\x04\x12\x06\
+    \x9d\x02\x08\x9c\x02\x20\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\x9d\x02\
+    \x08\x0e\n\r\n\x05\x04\x1d\x02\x01\x01\x12\x04\x9d\x02\x0f\x16\n\r\n\x05\
+    \x04\x1d\x02\x01\x03\x12\x04\x9d\x02\x19\x1a\n\x0c\n\x02\x04\x1e\x12\x06\
+    \xa0\x02\0\xa5\x02\x01\n\x0b\n\x03\x04\x1e\x01\x12\x04\xa0\x02\x08\x1b\n\
+    \x0c\n\x04\x04\x1e\x02\0\x12\x04\xa1\x02\x08\x20\n\x0f\n\x05\x04\x1e\x02\
+    \0\x04\x12\x06\xa1\x02\x08\xa0\x02\x1d\n\r\n\x05\x04\x1e\x02\0\x05\x12\
+    \x04\xa1\x02\x08\x0e\n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\xa1\x02\x0f\x1b\
+    \n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xa1\x02\x1e\x1f\n\x0c\n\x04\x04\x1e\
+    \x02\x01\x12\x04\xa2\x02\x08\x1b\n\x0f\n\x05\x04\x1e\x02\x01\x04\x12\x06\
+    \xa2\x02\x08\xa1\x02\x20\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\xa2\x02\
+    \x08\x0e\n\r\n\x05\x04\x1e\x02\x01\x01\x12\x04\xa2\x02\x0f\x16\n\r\n\x05\
+    \x04\x1e\x02\x01\x03\x12\x04\xa2\x02\x19\x1a\n\x0c\n\x04\x04\x1e\x02\x02\
+    \x12\x04\xa3\x02\x08\x17\n\x0f\n\x05\x04\x1e\x02\x02\x04\x12\x06\xa3\x02\
+    \x08\xa2\x02\x1b\n\r\n\x05\x04\x1e\x02\x02\x05\x12\x04\xa3\x02\x08\x0e\n\
+    \r\n\x05\x04\x1e\x02\x02\x01\x12\x04\xa3\x02\x0f\x12\n\r\n\x05\x04\x1e\
+    \x02\x02\x03\x12\x04\xa3\x02\x15\x16\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\
+    \xa4\x02\x08\x1a\n\x0f\n\x05\x04\x1e\x02\x03\x04\x12\x06\xa4\x02\x08\xa3\
+    \x02\x17\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\xa4\x02\x08\x0e\n\r\n\x05\
+    \x04\x1e\x02\x03\x01\x12\x04\xa4\x02\x0f\x15\n\r\n\x05\x04\x1e\x02\x03\
+    \x03\x12\x04\xa4\x02\x18\x19\n\x0c\n\x02\x04\x1f\x12\x06\xa7\x02\0\xb8\
+    \x02\x01\n\x0b\n\x03\x04\x1f\x01\x12\x04\xa7\x02\x08\x1c\n\x0c\n\x04\x04\
+    \x1f\x02\0\x12\x04\xa8\x02\x08\x1c\n\x0f\n\x05\x04\x1f\x02\0\x04\x12\x06\
+    \xa8\x02\x08\xa7\x02\x1e\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\xa8\x02\x08\
+    \x0e\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xa8\x02\x0f\x17\n\r\n\x05\x04\
+    \x1f\x02\0\x03\x12\x04\xa8\x02\x1a\x1b\n\x0c\n\x04\x04\x1f\x02\x01\x12\
+    \x04\xa9\x02\x08\x20\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\xa9\x02\x08\
+    \x10\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\xa9\x02\x11\x17\n\r\n\x05\x04\
+    \x1f\x02\x01\x01\x12\x04\xa9\x02\x18\x1b\n\r\n\x05\x04\x1f\x02\x01\x03\
+    \x12\x04\xa9\x02\x1e\x1f\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xaa\x02\x08\
+    &\n\r\n\x05\x04\x1f\x02\x02\x04\x12\x04\xaa\x02\x08\x10\n\r\n\x05\x04\
+    \x1f\x02\x02\x06\x12\x04\xaa\x02\x11\x18\n\r\n\x05\x04\x1f\x02\x02\x01\
+    \x12\x04\xaa\x02\x19!\n\r\n\x05\x04\x1f\x02\x02\x03\x12\x04\xaa\x02$%\n\
+    \xea\x01\n\x04\x04\x1f\x02\x03\x12\x04\xb0\x02\x08\x1f\x1a\xdb\x01\x20Th\
     is\x20field\x20means\x20that\x20a\x20pause\x20process\x20needs\x20to\x20\
     be\x20created\x20by\x20the\n\x20agent.\x20This\x20pid\x20namespace\x20of\
     \x20the\x20pause\x20process\x20will\x20be\x20treated\x20as\n\x20a\x20sha\
     red\x20pid\x20namespace.\x20All\x20containers\x20created\x20will\x20join\
     \x20this\x20shared\n\x20pid\x20namespace.\n\n\x0f\n\x05\x04\x1f\x02\x03\
-    \x04\x12\x06\x99\x02\x08\x93\x02&\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\
-    \x99\x02\x08\x0c\n\r\n\x05\x04\x1f\x02\x03\x01\x12\x04\x99\x02\r\x1a\n\r\
-    \n\x05\x04\x1f\x02\x03\x03\x12\x04\x99\x02\x1d\x1e\n\xc5\x01\n\x04\x04\
-    \x1f\x02\x04\x12\x04\x9d\x02\x08\x1e\x1a\xb6\x01\x20SandboxId\x20identif\
+    \x04\x12\x06\xb0\x02\x08\xaa\x02&\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\
+    \xb0\x02\x08\x0c\n\r\n\x05\x04\x1f\x02\x03\x01\x12\x04\xb0\x02\r\x1a\n\r\
+    \n\x05\x04\x1f\x02\x03\x03\x12\x04\xb0\x02\x1d\x1e\n\xc5\x01\n\x04\x04\
+    \x1f\x02\x04\x12\x04\xb4\x02\x08\x1e\x1a\xb6\x01\x20SandboxId\x20identif\
     ies\x20which\x20sandbox\x20is\x20using\x20the\x20agent.\x20We\x20allow\
     \x20only\n\x20one\x20sandbox\x20per\x20agent\x20and\x20implicitly\x20req\
     uire\x20that\x20CreateSandbox\x20is\n\x20called\x20before\x20other\x20sa\
-    ndbox/network\x20calls.\n\n\x0f\n\x05\x04\x1f\x02\x04\x04\x12\x06\x9d\
-    \x02\x08\x99\x02\x1f\n\r\n\x05\x04\x1f\x02\x04\x05\x12\x04\x9d\x02\x08\
-    \x0e\n\r\n\x05\x04\x1f\x02\x04\x01\x12\x04\x9d\x02\x0f\x19\n\r\n\x05\x04\
-    \x1f\x02\x04\x03\x12\x04\x9d\x02\x1c\x1d\n\x98\x01\n\x04\x04\x1f\x02\x05\
-    \x12\x04\xa0\x02\x08#\x1a\x89\x01\x20This\x20field,\x20if\x20non-empty,\
+    ndbox/network\x20calls.\n\n\x0f\n\x05\x04\x1f\x02\x04\x04\x12\x06\xb4\
+    \x02\x08\xb0\x02\x1f\n\r\n\x05\x04\x1f\x02\x04\x05\x12\x04\xb4\x02\x08\
+    \x0e\n\r\n\x05\x04\x1f\x02\x04\x01\x12\x04\xb4\x02\x0f\x19\n\r\n\x05\x04\
+    \x1f\x02\x04\x03\x12\x04\xb4\x02\x1c\x1d\n\x98\x01\n\x04\x04\x1f\x02\x05\
+    \x12\x04\xb7\x02\x08#\x1a\x89\x01\x20This\x20field,\x20if\x20non-empty,\
     \x20designates\x20an\x20absolute\x20path\x20to\x20a\x20directory\n\x20th\
     at\x20the\x20agent\x20will\x20search\x20for\x20OCI\x20hooks\x20to\x20run\
     \x20within\x20the\x20guest.\n\n\x0f\n\x05\x04\x1f\x02\x05\x04\x12\x06\
-    \xa0\x02\x08\x9d\x02\x1e\n\r\n\x05\x04\x1f\x02\x05\x05\x12\x04\xa0\x02\
-    \x08\x0e\n\r\n\x05\x04\x1f\x02\x05\x01\x12\x04\xa0\x02\x0f\x1e\n\r\n\x05\
-    \x04\x1f\x02\x05\x03\x12\x04\xa0\x02!\"\n\x0c\n\x02\x04\x20\x12\x06\xa3\
-    \x02\0\xa4\x02\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xa3\x02\x08\x1d\n\x0c\
-    \n\x02\x04!\x12\x06\xa6\x02\0\xa8\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\
-    \xa6\x02\x08\x12\n\x0c\n\x04\x04!\x02\0\x12\x04\xa7\x02\x080\n\r\n\x05\
-    \x04!\x02\0\x04\x12\x04\xa7\x02\x08\x10\n\r\n\x05\x04!\x02\0\x06\x12\x04\
-    \xa7\x02\x11\x20\n\r\n\x05\x04!\x02\0\x01\x12\x04\xa7\x02!+\n\r\n\x05\
-    \x04!\x02\0\x03\x12\x04\xa7\x02./\n\x0c\n\x02\x04\"\x12\x06\xaa\x02\0\
-    \xac\x02\x01\n\x0b\n\x03\x04\"\x01\x12\x04\xaa\x02\x08\x0e\n\x0c\n\x04\
-    \x04\"\x02\0\x12\x04\xab\x02\x08(\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xab\
-    \x02\x08\x10\n\r\n\x05\x04\"\x02\0\x06\x12\x04\xab\x02\x11\x1c\n\r\n\x05\
-    \x04\"\x02\0\x01\x12\x04\xab\x02\x1d#\n\r\n\x05\x04\"\x02\0\x03\x12\x04\
-    \xab\x02&'\n\x0c\n\x02\x04#\x12\x06\xae\x02\0\xb0\x02\x01\n\x0b\n\x03\
-    \x04#\x01\x12\x04\xae\x02\x08\x1e\n\x0c\n\x04\x04#\x02\0\x12\x04\xaf\x02\
-    \x08&\n\x0f\n\x05\x04#\x02\0\x04\x12\x06\xaf\x02\x08\xae\x02\x20\n\r\n\
-    \x05\x04#\x02\0\x06\x12\x04\xaf\x02\x08\x17\n\r\n\x05\x04#\x02\0\x01\x12\
-    \x04\xaf\x02\x18!\n\r\n\x05\x04#\x02\0\x03\x12\x04\xaf\x02$%\n\x0c\n\x02\
-    \x04$\x12\x06\xb2\x02\0\xb4\x02\x01\n\x0b\n\x03\x04$\x01\x12\x04\xb2\x02\
-    \x08\x1b\n\x0c\n\x04\x04$\x02\0\x12\x04\xb3\x02\x08\x1a\n\x0f\n\x05\x04$\
-    \x02\0\x04\x12\x06\xb3\x02\x08\xb2\x02\x1d\n\r\n\x05\x04$\x02\0\x06\x12\
-    \x04\xb3\x02\x08\x0e\n\r\n\x05\x04$\x02\0\x01\x12\x04\xb3\x02\x0f\x15\n\
-    \r\n\x05\x04$\x02\0\x03\x12\x04\xb3\x02\x18\x19\n\x0c\n\x02\x04%\x12\x06\
-    \xb6\x02\0\xb7\x02\x01\n\x0b\n\x03\x04%\x01\x12\x04\xb6\x02\x08\x1d\n\
-    \x0c\n\x02\x04&\x12\x06\xb9\x02\0\xba\x02\x01\n\x0b\n\x03\x04&\x01\x12\
-    \x04\xb9\x02\x08\x19\n\x0c\n\x02\x04'\x12\x06\xbc\x02\0\xc7\x02\x01\n\
-    \x0b\n\x03\x04'\x01\x12\x04\xbc\x02\x08\x1b\n\xf6\x01\n\x04\x04'\x02\0\
-    \x12\x04\xc0\x02\x08\x16\x1a\xe7\x01\x20Wait\x20specifies\x20if\x20the\
-    \x20caller\x20waits\x20for\x20the\x20agent\x20to\x20online\x20all\x20res\
-    ources.\n\x20If\x20true\x20the\x20agent\x20returns\x20once\x20all\x20res\
-    ources\x20have\x20been\x20connected,\x20otherwise\x20all\n\x20resources\
-    \x20are\x20connected\x20asynchronously\x20and\x20the\x20agent\x20returns\
-    \x20immediately.\n\n\x0f\n\x05\x04'\x02\0\x04\x12\x06\xc0\x02\x08\xbc\
-    \x02\x1d\n\r\n\x05\x04'\x02\0\x05\x12\x04\xc0\x02\x08\x0c\n\r\n\x05\x04'\
-    \x02\0\x01\x12\x04\xc0\x02\r\x11\n\r\n\x05\x04'\x02\0\x03\x12\x04\xc0\
-    \x02\x14\x15\n`\n\x04\x04'\x02\x01\x12\x04\xc3\x02\x08\x1b\x1aR\x20NbCpu\
-    s\x20specifies\x20the\x20number\x20of\x20CPUs\x20that\x20were\x20added\
-    \x20and\x20the\x20agent\x20has\x20to\x20online.\n\n\x0f\n\x05\x04'\x02\
-    \x01\x04\x12\x06\xc3\x02\x08\xc0\x02\x16\n\r\n\x05\x04'\x02\x01\x05\x12\
-    \x04\xc3\x02\x08\x0e\n\r\n\x05\x04'\x02\x01\x01\x12\x04\xc3\x02\x0f\x16\
-    \n\r\n\x05\x04'\x02\x01\x03\x12\x04\xc3\x02\x19\x1a\nA\n\x04\x04'\x02\
-    \x02\x12\x04\xc6\x02\x08\x1a\x1a3\x20CpuOnly\x20specifies\x20whether\x20\
-    only\x20online\x20CPU\x20or\x20not.\n\n\x0f\n\x05\x04'\x02\x02\x04\x12\
-    \x06\xc6\x02\x08\xc3\x02\x1b\n\r\n\x05\x04'\x02\x02\x05\x12\x04\xc6\x02\
-    \x08\x0c\n\r\n\x05\x04'\x02\x02\x01\x12\x04\xc6\x02\r\x15\n\r\n\x05\x04'\
-    \x02\x02\x03\x12\x04\xc6\x02\x18\x19\n\x0c\n\x02\x04(\x12\x06\xc9\x02\0\
-    \xcc\x02\x01\n\x0b\n\x03\x04(\x01\x12\x04\xc9\x02\x08\x1e\nM\n\x04\x04(\
-    \x02\0\x12\x04\xcb\x02\x08\x17\x1a?\x20Data\x20specifies\x20the\x20rando\
-    m\x20data\x20used\x20to\x20reseed\x20the\x20guest\x20crng.\n\n\x0f\n\x05\
-    \x04(\x02\0\x04\x12\x06\xcb\x02\x08\xc9\x02\x20\n\r\n\x05\x04(\x02\0\x05\
-    \x12\x04\xcb\x02\x08\r\n\r\n\x05\x04(\x02\0\x01\x12\x04\xcb\x02\x0e\x12\
-    \n\r\n\x05\x04(\x02\0\x03\x12\x04\xcb\x02\x15\x16\nX\n\x02\x04)\x12\x06\
-    \xcf\x02\0\xdf\x02\x01\x1aJ\x20AgentDetails\x20provides\x20information\
-    \x20to\x20the\x20client\x20about\x20the\x20running\x20agent.\n\n\x0b\n\
-    \x03\x04)\x01\x12\x04\xcf\x02\x08\x14\nC\n\x04\x04)\x02\0\x12\x04\xd1\
-    \x02\x08\x1b\x1a5\x20Semantic\x20version\x20of\x20agent\x20(see\x20https\
-    ://semver.org).\n\n\x0f\n\x05\x04)\x02\0\x04\x12\x06\xd1\x02\x08\xcf\x02\
-    \x16\n\r\n\x05\x04)\x02\0\x05\x12\x04\xd1\x02\x08\x0e\n\r\n\x05\x04)\x02\
-    \0\x01\x12\x04\xd1\x02\x0f\x16\n\r\n\x05\x04)\x02\0\x03\x12\x04\xd1\x02\
-    \x19\x1a\n5\n\x04\x04)\x02\x01\x12\x04\xd4\x02\x08\x1d\x1a'\x20Set\x20if\
-    \x20the\x20agent\x20is\x20running\x20as\x20PID\x201.\n\n\x0f\n\x05\x04)\
-    \x02\x01\x04\x12\x06\xd4\x02\x08\xd1\x02\x1b\n\r\n\x05\x04)\x02\x01\x05\
-    \x12\x04\xd4\x02\x08\x0c\n\r\n\x05\x04)\x02\x01\x01\x12\x04\xd4\x02\r\
-    \x18\n\r\n\x05\x04)\x02\x01\x03\x12\x04\xd4\x02\x1b\x1c\n2\n\x04\x04)\
-    \x02\x02\x12\x04\xd7\x02\x08,\x1a$\x20List\x20of\x20available\x20device\
-    \x20handlers.\n\n\r\n\x05\x04)\x02\x02\x04\x12\x04\xd7\x02\x08\x10\n\r\n\
-    \x05\x04)\x02\x02\x05\x12\x04\xd7\x02\x11\x17\n\r\n\x05\x04)\x02\x02\x01\
-    \x12\x04\xd7\x02\x18'\n\r\n\x05\x04)\x02\x02\x03\x12\x04\xd7\x02*+\n3\n\
-    \x04\x04)\x02\x03\x12\x04\xda\x02\x08-\x1a%\x20List\x20of\x20available\
-    \x20storage\x20handlers.\n\n\r\n\x05\x04)\x02\x03\x04\x12\x04\xda\x02\
-    \x08\x10\n\r\n\x05\x04)\x02\x03\x05\x12\x04\xda\x02\x11\x17\n\r\n\x05\
-    \x04)\x02\x03\x01\x12\x04\xda\x02\x18(\n\r\n\x05\x04)\x02\x03\x03\x12\
-    \x04\xda\x02+,\np\n\x04\x04)\x02\x04\x12\x04\xde\x02\x08\"\x1ab\x20Set\
-    \x20only\x20if\x20the\x20agent\x20is\x20built\x20with\x20seccomp\x20supp\
-    ort\x20and\x20the\x20guest\n\x20environment\x20supports\x20seccomp.\n\n\
-    \x0f\n\x05\x04)\x02\x04\x04\x12\x06\xde\x02\x08\xda\x02-\n\r\n\x05\x04)\
-    \x02\x04\x05\x12\x04\xde\x02\x08\x0c\n\r\n\x05\x04)\x02\x04\x01\x12\x04\
-    \xde\x02\r\x1d\n\r\n\x05\x04)\x02\x04\x03\x12\x04\xde\x02\x20!\n\x0c\n\
-    \x02\x04*\x12\x06\xe1\x02\0\xeb\x02\x01\n\x0b\n\x03\x04*\x01\x12\x04\xe1\
-    \x02\x08\x1b\n\xd5\x01\n\x04\x04*\x02\0\x12\x04\xe5\x02\x08\x20\x1a\xc6\
+    \xb7\x02\x08\xb4\x02\x1e\n\r\n\x05\x04\x1f\x02\x05\x05\x12\x04\xb7\x02\
+    \x08\x0e\n\r\n\x05\x04\x1f\x02\x05\x01\x12\x04\xb7\x02\x0f\x1e\n\r\n\x05\
+    \x04\x1f\x02\x05\x03\x12\x04\xb7\x02!\"\n\x0c\n\x02\x04\x20\x12\x06\xba\
+    \x02\0\xbb\x02\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xba\x02\x08\x1d\n\x0c\
+    \n\x02\x04!\x12\x06\xbd\x02\0\xbf\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\
+    \xbd\x02\x08\x12\n\x0c\n\x04\x04!\x02\0\x12\x04\xbe\x02\x080\n\r\n\x05\
+    \x04!\x02\0\x04\x12\x04\xbe\x02\x08\x10\n\r\n\x05\x04!\x02\0\x06\x12\x04\
+    \xbe\x02\x11\x20\n\r\n\x05\x04!\x02\0\x01\x12\x04\xbe\x02!+\n\r\n\x05\
+    \x04!\x02\0\x03\x12\x04\xbe\x02./\n\x0c\n\x02\x04\"\x12\x06\xc1\x02\0\
+    \xc3\x02\x01\n\x0b\n\x03\x04\"\x01\x12\x04\xc1\x02\x08\x0e\n\x0c\n\x04\
+    \x04\"\x02\0\x12\x04\xc2\x02\x08(\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xc2\
+    \x02\x08\x10\n\r\n\x05\x04\"\x02\0\x06\x12\x04\xc2\x02\x11\x1c\n\r\n\x05\
+    \x04\"\x02\0\x01\x12\x04\xc2\x02\x1d#\n\r\n\x05\x04\"\x02\0\x03\x12\x04\
+    \xc2\x02&'\n\x0c\n\x02\x04#\x12\x06\xc5\x02\0\xc7\x02\x01\n\x0b\n\x03\
+    \x04#\x01\x12\x04\xc5\x02\x08\x1e\n\x0c\n\x04\x04#\x02\0\x12\x04\xc6\x02\
+    \x08&\n\x0f\n\x05\x04#\x02\0\x04\x12\x06\xc6\x02\x08\xc5\x02\x20\n\r\n\
+    \x05\x04#\x02\0\x06\x12\x04\xc6\x02\x08\x17\n\r\n\x05\x04#\x02\0\x01\x12\
+    \x04\xc6\x02\x18!\n\r\n\x05\x04#\x02\0\x03\x12\x04\xc6\x02$%\n\x0c\n\x02\
+    \x04$\x12\x06\xc9\x02\0\xcb\x02\x01\n\x0b\n\x03\x04$\x01\x12\x04\xc9\x02\
+    \x08\x1b\n\x0c\n\x04\x04$\x02\0\x12\x04\xca\x02\x08\x1a\n\x0f\n\x05\x04$\
+    \x02\0\x04\x12\x06\xca\x02\x08\xc9\x02\x1d\n\r\n\x05\x04$\x02\0\x06\x12\
+    \x04\xca\x02\x08\x0e\n\r\n\x05\x04$\x02\0\x01\x12\x04\xca\x02\x0f\x15\n\
+    \r\n\x05\x04$\x02\0\x03\x12\x04\xca\x02\x18\x19\n\x0c\n\x02\x04%\x12\x06\
+    \xcd\x02\0\xce\x02\x01\n\x0b\n\x03\x04%\x01\x12\x04\xcd\x02\x08\x1d\n\
+    \x0c\n\x02\x04&\x12\x06\xd0\x02\0\xd1\x02\x01\n\x0b\n\x03\x04&\x01\x12\
+    \x04\xd0\x02\x08\x19\n\x0c\n\x02\x04'\x12\x06\xd3\x02\0\xd4\x02\x01\n\
+    \x0b\n\x03\x04'\x01\x12\x04\xd3\x02\x08\x1f\n\x0c\n\x02\x04(\x12\x06\xd6\
+    \x02\0\xda\x02\x01\n\x0b\n\x03\x04(\x01\x12\x04\xd6\x02\x08\x16\n\x0c\n\
+    \x04\x04(\x02\0\x12\x04\xd7\x02\x08(\n\r\n\x05\x04(\x02\0\x04\x12\x04\
+    \xd7\x02\x08\x10\n\r\n\x05\x04(\x02\0\x06\x12\x04\xd7\x02\x11\x1c\n\r\n\
+    \x05\x04(\x02\0\x01\x12\x04\xd7\x02\x1d#\n\r\n\x05\x04(\x02\0\x03\x12\
+    \x04\xd7\x02&'\n\x0c\n\x04\x04(\x02\x01\x12\x04\xd8\x02\x080\n\r\n\x05\
+    \x04(\x02\x01\x04\x12\x04\xd8\x02\x08\x10\n\r\n\x05\x04(\x02\x01\x06\x12\
+    \x04\xd8\x02\x11#\n\r\n\x05\x04(\x02\x01\x01\x12\x04\xd8\x02$+\n\r\n\x05\
+    \x04(\x02\x01\x03\x12\x04\xd8\x02./\n\x0c\n\x04\x04(\x02\x02\x12\x04\xd9\
+    \x02\x081\n\r\n\x05\x04(\x02\x02\x04\x12\x04\xd9\x02\x08\x10\n\r\n\x05\
+    \x04(\x02\x02\x06\x12\x04\xd9\x02\x11$\n\r\n\x05\x04(\x02\x02\x01\x12\
+    \x04\xd9\x02%,\n\r\n\x05\x04(\x02\x02\x03\x12\x04\xd9\x02/0\n\x0c\n\x02\
+    \x04)\x12\x06\xdc\x02\0\xde\x02\x01\n\x0b\n\x03\x04)\x01\x12\x04\xdc\x02\
+    \x08#\n\x0c\n\x04\x04)\x02\0\x12\x04\xdd\x02\x08+\n\x0f\n\x05\x04)\x02\0\
+    \x04\x12\x06\xdd\x02\x08\xdc\x02%\n\r\n\x05\x04)\x02\0\x06\x12\x04\xdd\
+    \x02\x08\x16\n\r\n\x05\x04)\x02\0\x01\x12\x04\xdd\x02\x17&\n\r\n\x05\x04\
+    )\x02\0\x03\x12\x04\xdd\x02)*\n\x0c\n\x02\x04*\x12\x06\xe0\x02\0\xe3\x02\
+    \x01\n\x0b\n\x03\x04*\x01\x12\x04\xe0\x02\x08!\n>\n\x04\x04*\x02\0\x12\
+    \x04\xe2\x02\x08\x1a\x1a0\x20device\x20restricts\x20the\x20listing\x20to\
+    \x20one\x20interface.\n\n\x0f\n\x05\x04*\x02\0\x04\x12\x06\xe2\x02\x08\
+    \xe0\x02#\n\r\n\x05\x04*\x02\0\x05\x12\x04\xe2\x02\x08\x0e\n\r\n\x05\x04\
+    *\x02\0\x01\x12\x04\xe2\x02\x0f\x15\n\r\n\x05\x04*\x02\0\x03\x12\x04\xe2\
+    \x02\x18\x19\nI\n\x02\x04+\x12\x06\xe6\x02\0\xf7\x02\x01\x1a;\x20Network\
+    Event\x20describes\x20one\x20link,\x20address\x20or\x20route\x20change.\
+    \n\n\x0b\n\x03\x04+\x01\x12\x04\xe6\x02\x08\x14\n\x0e\n\x04\x04+\x04\0\
+    \x12\x06\xe7\x02\x08\xeb\x02\t\n\r\n\x05\x04+\x04\0\x01\x12\x04\xe7\x02\
+    \r\x11\n\x0e\n\x06\x04+\x04\0\x02\0\x12\x04\xe8\x02\x10\x19\n\x0f\n\x07\
+    \x04+\x04\0\x02\0\x01\x12\x04\xe8\x02\x10\x14\n\x0f\n\x07\x04+\x04\0\x02\
+    \0\x02\x12\x04\xe8\x02\x17\x18\n\x0e\n\x06\x04+\x04\0\x02\x01\x12\x04\
+    \xe9\x02\x10\x1c\n\x0f\n\x07\x04+\x04\0\x02\x01\x01\x12\x04\xe9\x02\x10\
+    \x17\n\x0f\n\x07\x04+\x04\0\x02\x01\x02\x12\x04\xe9\x02\x1a\x1b\n\x0e\n\
+    \x06\x04+\x04\0\x02\x02\x12\x04\xea\x02\x10\x1a\n\x0f\n\x07\x04+\x04\0\
+    \x02\x02\x01\x12\x04\xea\x02\x10\x15\n\x0f\n\x07\x04+\x04\0\x02\x02\x02\
+    \x12\x04\xea\x02\x18\x19\n\x0c\n\x04\x04+\x02\0\x12\x04\xec\x02\x08\x16\
+    \n\x0f\n\x05\x04+\x02\0\x04\x12\x06\xec\x02\x08\xeb\x02\t\n\r\n\x05\x04+\
+    \x02\0\x06\x12\x04\xec\x02\x08\x0c\n\r\n\x05\x04+\x02\0\x01\x12\x04\xec\
+    \x02\r\x11\n\r\n\x05\x04+\x02\0\x03\x12\x04\xec\x02\x14\x15\nP\n\x04\x04\
+    +\x02\x01\x12\x04\xef\x02\x08\x19\x1aB\x20Deleted\x20is\x20set\x20when\
+    \x20the\x20link,\x20address\x20or\x20route\x20has\x20been\x20removed.\n\
+    \n\x0f\n\x05\x04+\x02\x01\x04\x12\x06\xef\x02\x08\xec\x02\x16\n\r\n\x05\
+    \x04+\x02\x01\x05\x12\x04\xef\x02\x08\x0c\n\r\n\x05\x04+\x02\x01\x01\x12\
+    \x04\xef\x02\r\x14\n\r\n\x05\x04+\x02\x01\x03\x12\x04\xef\x02\x17\x18\n\
+    \x84\x01\n\x04\x04+\x02\x02\x12\x04\xf3\x02\x08&\x1av\x20Interface\x20is\
+    \x20set\x20for\x20link\x20and\x20address\x20events.\x20For\x20address\
+    \x20events,\n\x20IPAddresses\x20only\x20holds\x20the\x20address\x20which\
+    \x20changed.\n\n\x0f\n\x05\x04+\x02\x02\x04\x12\x06\xf3\x02\x08\xef\x02\
+    \x19\n\r\n\x05\x04+\x02\x02\x06\x12\x04\xf3\x02\x08\x17\n\r\n\x05\x04+\
+    \x02\x02\x01\x12\x04\xf3\x02\x18!\n\r\n\x05\x04+\x02\x02\x03\x12\x04\xf3\
+    \x02$%\n.\n\x04\x04+\x02\x03\x12\x04\xf6\x02\x08\x1e\x1a\x20\x20Route\
+    \x20is\x20set\x20for\x20route\x20events.\n\n\x0f\n\x05\x04+\x02\x03\x04\
+    \x12\x06\xf6\x02\x08\xf3\x02&\n\r\n\x05\x04+\x02\x03\x06\x12\x04\xf6\x02\
+    \x08\x13\n\r\n\x05\x04+\x02\x03\x01\x12\x04\xf6\x02\x14\x19\n\r\n\x05\
+    \x04+\x02\x03\x03\x12\x04\xf6\x02\x1c\x1d\n\x0c\n\x02\x04,\x12\x06\xf9\
+    \x02\0\x84\x03\x01\n\x0b\n\x03\x04,\x01\x12\x04\xf9\x02\x08\x1b\n\xf6\
+    \x01\n\x04\x04,\x02\0\x12\x04\xfd\x02\x08\x16\x1a\xe7\x01\x20Wait\x20spe\
+    cifies\x20if\x20the\x20caller\x20waits\x20for\x20the\x20agent\x20to\x20o\
+    nline\x20all\x20resources.\n\x20If\x20true\x20the\x20agent\x20returns\
+    \x20once\x20all\x20resources\x20have\x20been\x20connected,\x20otherwise\
+    \x20all\n\x20resources\x20are\x20connected\x20asynchronously\x20and\x20t\
+    he\x20agent\x20returns\x20immediately.\n\n\x0f\n\x05\x04,\x02\0\x04\x12\
+    \x06\xfd\x02\x08\xf9\x02\x1d\n\r\n\x05\x04,\x02\0\x05\x12\x04\xfd\x02\
+    \x08\x0c\n\r\n\x05\x04,\x02\0\x01\x12\x04\xfd\x02\r\x11\n\r\n\x05\x04,\
+    \x02\0\x03\x12\x04\xfd\x02\x14\x15\n`\n\x04\x04,\x02\x01\x12\x04\x80\x03\
+    \x08\x1b\x1aR\x20NbCpus\x20specifies\x20the\x20number\x20of\x20CPUs\x20t\
+    hat\x20were\x20added\x20and\x20the\x20agent\x20has\x20to\x20online.\n\n\
+    \x0f\n\x05\x04,\x02\x01\x04\x12\x06\x80\x03\x08\xfd\x02\x16\n\r\n\x05\
+    \x04,\x02\x01\x05\x12\x04\x80\x03\x08\x0e\n\r\n\x05\x04,\x02\x01\x01\x12\
+    \x04\x80\x03\x0f\x16\n\r\n\x05\x04,\x02\x01\x03\x12\x04\x80\x03\x19\x1a\
+    \nA\n\x04\x04,\x02\x02\x12\x04\x83\x03\x08\x1a\x1a3\x20CpuOnly\x20specif\
+    ies\x20whether\x20only\x20online\x20CPU\x20or\x20not.\n\n\x0f\n\x05\x04,\
+    \x02\x02\x04\x12\x06\x83\x03\x08\x80\x03\x1b\n\r\n\x05\x04,\x02\x02\x05\
+    \x12\x04\x83\x03\x08\x0c\n\r\n\x05\x04,\x02\x02\x01\x12\x04\x83\x03\r\
+    \x15\n\r\n\x05\x04,\x02\x02\x03\x12\x04\x83\x03\x18\x19\n\x0c\n\x02\x04-\
+    \x12\x06\x86\x03\0\x89\x03\x01\n\x0b\n\x03\x04-\x01\x12\x04\x86\x03\x08\
+    \x1e\nM\n\x04\x04-\x02\0\x12\x04\x88\x03\x08\x17\x1a?\x20Data\x20specifi\
+    es\x20the\x20random\x20data\x20used\x20to\x20reseed\x20the\x20guest\x20c\
+    rng.\n\n\x0f\n\x05\x04-\x02\0\x04\x12\x06\x88\x03\x08\x86\x03\x20\n\r\n\
+    \x05\x04-\x02\0\x05\x12\x04\x88\x03\x08\r\n\r\n\x05\x04-\x02\0\x01\x12\
+    \x04\x88\x03\x0e\x12\n\r\n\x05\x04-\x02\0\x03\x12\x04\x88\x03\x15\x16\nX\
+    \n\x02\x04.\x12\x06\x8c\x03\0\x9c\x03\x01\x1aJ\x20AgentDetails\x20provid\
+    es\x20information\x20to\x20the\x20client\x20about\x20the\x20running\x20a\
+    gent.\n\n\x0b\n\x03\x04.\x01\x12\x04\x8c\x03\x08\x14\nC\n\x04\x04.\x02\0\
+    \x12\x04\x8e\x03\x08\x1b\x1a5\x20Semantic\x20version\x20of\x20agent\x20(\
+    see\x20https://semver.org).\n\n\x0f\n\x05\x04.\x02\0\x04\x12\x06\x8e\x03\
+    \x08\x8c\x03\x16\n\r\n\x05\x04.\x02\0\x05\x12\x04\x8e\x03\x08\x0e\n\r\n\
+    \x05\x04.\x02\0\x01\x12\x04\x8e\x03\x0f\x16\n\r\n\x05\x04.\x02\0\x03\x12\
+    \x04\x8e\x03\x19\x1a\n5\n\x04\x04.\x02\x01\x12\x04\x91\x03\x08\x1d\x1a'\
+    \x20Set\x20if\x20the\x20agent\x20is\x20running\x20as\x20PID\x201.\n\n\
+    \x0f\n\x05\x04.\x02\x01\x04\x12\x06\x91\x03\x08\x8e\x03\x1b\n\r\n\x05\
+    \x04.\x02\x01\x05\x12\x04\x91\x03\x08\x0c\n\r\n\x05\x04.\x02\x01\x01\x12\
+    \x04\x91\x03\r\x18\n\r\n\x05\x04.\x02\x01\x03\x12\x04\x91\x03\x1b\x1c\n2\
+    \n\x04\x04.\x02\x02\x12\x04\x94\x03\x08,\x1a$\x20List\x20of\x20available\
+    \x20device\x20handlers.\n\n\r\n\x05\x04.\x02\x02\x04\x12\x04\x94\x03\x08\
+    \x10\n\r\n\x05\x04.\x02\x02\x05\x12\x04\x94\x03\x11\x17\n\r\n\x05\x04.\
+    \x02\x02\x01\x12\x04\x94\x03\x18'\n\r\n\x05\x04.\x02\x02\x03\x12\x04\x94\
+    \x03*+\n3\n\x04\x04.\x02\x03\x12\x04\x97\x03\x08-\x1a%\x20List\x20of\x20\
+    available\x20storage\x20handlers.\n\n\r\n\x05\x04.\x02\x03\x04\x12\x04\
+    \x97\x03\x08\x10\n\r\n\x05\x04.\x02\x03\x05\x12\x04\x97\x03\x11\x17\n\r\
+    \n\x05\x04.\x02\x03\x01\x12\x04\x97\x03\x18(\n\r\n\x05\x04.\x02\x03\x03\
+    \x12\x04\x97\x03+,\np\n\x04\x04.\x02\x04\x12\x04\x9b\x03\x08\"\x1ab\x20S\
+    et\x20only\x20if\x20the\x20agent\x20is\x20built\x20with\x20seccomp\x20su\
+    pport\x20and\x20the\x20guest\n\x20environment\x20supports\x20seccomp.\n\
+    \n\x0f\n\x05\x04.\x02\x04\x04\x12\x06\x9b\x03\x08\x97\x03-\n\r\n\x05\x04\
+    .\x02\x04\x05\x12\x04\x9b\x03\x08\x0c\n\r\n\x05\x04.\x02\x04\x01\x12\x04\
+    \x9b\x03\r\x1d\n\r\n\x05\x04.\x02\x04\x03\x12\x04\x9b\x03\x20!\n\x0c\n\
+    \x02\x04/\x12\x06\x9e\x03\0\xa8\x03\x01\n\x0b\n\x03\x04/\x01\x12\x04\x9e\
+    \x03\x08\x1b\n\xd5\x01\n\x04\x04/\x02\0\x12\x04\xa2\x03\x08\x20\x1a\xc6\
     \x01\x20MemBlockSize\x20asks\x20server\x20to\x20return\x20the\x20system\
     \x20memory\x20block\x20size\x20that\x20can\x20be\x20used\n\x20for\x20mem\
     ory\x20hotplug\x20alignment.\x20Typically\x20the\x20server\x20returns\
     \x20what's\x20in\n\x20/sys/devices/system/memory/block_size_bytes.\n\n\
-    \x0f\n\x05\x04*\x02\0\x04\x12\x06\xe5\x02\x08\xe1\x02\x1d\n\r\n\x05\x04*\
-    \x02\0\x05\x12\x04\xe5\x02\x08\x0c\n\r\n\x05\x04*\x02\0\x01\x12\x04\xe5\
-    \x02\r\x1b\n\r\n\x05\x04*\x02\0\x03\x12\x04\xe5\x02\x1e\x1f\n\xd1\x01\n\
-    \x04\x04*\x02\x01\x12\x04\xea\x02\x08#\x1a\xc2\x01\x20MemoryHotplugProbe\
+    \x0f\n\x05\x04/\x02\0\x04\x12\x06\xa2\x03\x08\x9e\x03\x1d\n\r\n\x05\x04/\
+    \x02\0\x05\x12\x04\xa2\x03\x08\x0c\n\r\n\x05\x04/\x02\0\x01\x12\x04\xa2\
+    \x03\r\x1b\n\r\n\x05\x04/\x02\0\x03\x12\x04\xa2\x03\x1e\x1f\n\xd1\x01\n\
+    \x04\x04/\x02\x01\x12\x04\xa7\x03\x08#\x1a\xc2\x01\x20MemoryHotplugProbe\
     \x20asks\x20server\x20to\x20return\x20whether\x20guest\x20kernel\x20supp\
     orts\x20memory\x20hotplug\n\x20via\x20probeinterface.\x20Typically\x20th\
     e\x20server\x20will\x20check\x20if\x20the\x20path\n\x20/sys/devices/syst\
-    em/memory/probe\x20exists.\n\n\x0f\n\x05\x04*\x02\x01\x04\x12\x06\xea\
-    \x02\x08\xe5\x02\x20\n\r\n\x05\x04*\x02\x01\x05\x12\x04\xea\x02\x08\x0c\
-    \n\r\n\x05\x04*\x02\x01\x01\x12\x04\xea\x02\r\x1e\n\r\n\x05\x04*\x02\x01\
-    \x03\x12\x04\xea\x02!\"\n\x0c\n\x02\x04+\x12\x06\xed\x02\0\xf4\x02\x01\n\
-    \x0b\n\x03\x04+\x01\x12\x04\xed\x02\x08\x1c\nP\n\x04\x04+\x02\0\x12\x04\
-    \xef\x02\x08(\x1aB\x20MemBlockSizeBytes\x20returns\x20the\x20system\x20m\
-    emory\x20block\x20size\x20in\x20bytes.\n\n\x0f\n\x05\x04+\x02\0\x04\x12\
-    \x06\xef\x02\x08\xed\x02\x1e\n\r\n\x05\x04+\x02\0\x05\x12\x04\xef\x02\
-    \x08\x0e\n\r\n\x05\x04+\x02\0\x01\x12\x04\xef\x02\x0f#\n\r\n\x05\x04+\
-    \x02\0\x03\x12\x04\xef\x02&'\n\x0c\n\x04\x04+\x02\x01\x12\x04\xf1\x02\
-    \x08'\n\x0f\n\x05\x04+\x02\x01\x04\x12\x06\xf1\x02\x08\xef\x02(\n\r\n\
-    \x05\x04+\x02\x01\x06\x12\x04\xf1\x02\x08\x14\n\r\n\x05\x04+\x02\x01\x01\
-    \x12\x04\xf1\x02\x15\"\n\r\n\x05\x04+\x02\x01\x03\x12\x04\xf1\x02%&\n\
-    \x0c\n\x04\x04+\x02\x02\x12\x04\xf3\x02\x08+\n\x0f\n\x05\x04+\x02\x02\
-    \x04\x12\x06\xf3\x02\x08\xf1\x02'\n\r\n\x05\x04+\x02\x02\x05\x12\x04\xf3\
-    \x02\x08\x0c\n\r\n\x05\x04+\x02\x02\x01\x12\x04\xf3\x02\r&\n\r\n\x05\x04\
-    +\x02\x02\x03\x12\x04\xf3\x02)*\n\x0c\n\x02\x04,\x12\x06\xf6\x02\0\xfa\
-    \x02\x01\n\x0b\n\x03\x04,\x01\x12\x04\xf6\x02\x08\x20\n\xb2\x01\n\x04\
-    \x04,\x02\0\x12\x04\xf9\x02\x080\x1a\xa3\x01\x20server\x20needs\x20to\
+    em/memory/probe\x20exists.\n\n\x0f\n\x05\x04/\x02\x01\x04\x12\x06\xa7\
+    \x03\x08\xa2\x03\x20\n\r\n\x05\x04/\x02\x01\x05\x12\x04\xa7\x03\x08\x0c\
+    \n\r\n\x05\x04/\x02\x01\x01\x12\x04\xa7\x03\r\x1e\n\r\n\x05\x04/\x02\x01\
+    \x03\x12\x04\xa7\x03!\"\n\x0c\n\x02\x040\x12\x06\xaa\x03\0\xb1\x03\x01\n\
+    \x0b\n\x03\x040\x01\x12\x04\xaa\x03\x08\x1c\nP\n\x04\x040\x02\0\x12\x04\
+    \xac\x03\x08(\x1aB\x20MemBlockSizeBytes\x20returns\x20the\x20system\x20m\
+    emory\x20block\x20size\x20in\x20bytes.\n\n\x0f\n\x05\x040\x02\0\x04\x12\
+    \x06\xac\x03\x08\xaa\x03\x1e\n\r\n\x05\x040\x02\0\x05\x12\x04\xac\x03\
+    \x08\x0e\n\r\n\x05\x040\x02\0\x01\x12\x04\xac\x03\x0f#\n\r\n\x05\x040\
+    \x02\0\x03\x12\x04\xac\x03&'\n\x0c\n\x04\x040\x02\x01\x12\x04\xae\x03\
+    \x08'\n\x0f\n\x05\x040\x02\x01\x04\x12\x06\xae\x03\x08\xac\x03(\n\r\n\
+    \x05\x040\x02\x01\x06\x12\x04\xae\x03\x08\x14\n\r\n\x05\x040\x02\x01\x01\
+    \x12\x04\xae\x03\x15\"\n\r\n\x05\x040\x02\x01\x03\x12\x04\xae\x03%&\n\
+    \x0c\n\x04\x040\x02\x02\x12\x04\xb0\x03\x08+\n\x0f\n\x05\x040\x02\x02\
+    \x04\x12\x06\xb0\x03\x08\xae\x03'\n\r\n\x05\x040\x02\x02\x05\x12\x04\xb0\
+    \x03\x08\x0c\n\r\n\x05\x040\x02\x02\x01\x12\x04\xb0\x03\r&\n\r\n\x05\x04\
+    0\x02\x02\x03\x12\x04\xb0\x03)*\n\x0c\n\x02\x041\x12\x06\xb3\x03\0\xb7\
+    \x03\x01\n\x0b\n\x03\x041\x01\x12\x04\xb3\x03\x08\x20\n\xb2\x01\n\x04\
+    \x041\x02\0\x12\x04\xb6\x03\x080\x1a\xa3\x01\x20server\x20needs\x20to\
     \x20send\x20the\x20value\x20of\x20memHotplugProbeAddr\x20into\x20file\
     \x20/sys/devices/system/memory/probe,\n\x20in\x20order\x20to\x20notify\
     \x20the\x20guest\x20kernel\x20about\x20hot-add\x20memory\x20event\n\n\r\
-    \n\x05\x04,\x02\0\x04\x12\x04\xf9\x02\x08\x10\n\r\n\x05\x04,\x02\0\x05\
-    \x12\x04\xf9\x02\x11\x17\n\r\n\x05\x04,\x02\0\x01\x12\x04\xf9\x02\x18+\n\
-    \r\n\x05\x04,\x02\0\x03\x12\x04\xf9\x02./\n\x0c\n\x02\x04-\x12\x06\xfc\
-    \x02\0\x81\x03\x01\n\x0b\n\x03\x04-\x01\x12\x04\xfc\x02\x08\x1f\n/\n\x04\
-    \x04-\x02\0\x12\x04\xfe\x02\x08\x16\x1a!\x20Sec\x20the\x20second\x20sinc\
-    e\x20the\x20Epoch.\n\n\x0f\n\x05\x04-\x02\0\x04\x12\x06\xfe\x02\x08\xfc\
-    \x02!\n\r\n\x05\x04-\x02\0\x05\x12\x04\xfe\x02\x08\r\n\r\n\x05\x04-\x02\
-    \0\x01\x12\x04\xfe\x02\x0e\x11\n\r\n\x05\x04-\x02\0\x03\x12\x04\xfe\x02\
-    \x14\x15\nF\n\x04\x04-\x02\x01\x12\x04\x80\x03\x08\x17\x1a8\x20Usec\x20t\
+    \n\x05\x041\x02\0\x04\x12\x04\xb6\x03\x08\x10\n\r\n\x05\x041\x02\0\x05\
+    \x12\x04\xb6\x03\x11\x17\n\r\n\x05\x041\x02\0\x01\x12\x04\xb6\x03\x18+\n\
+    \r\n\x05\x041\x02\0\x03\x12\x04\xb6\x03./\n\x0c\n\x02\x042\x12\x06\xb9\
+    \x03\0\xbe\x03\x01\n\x0b\n\x03\x042\x01\x12\x04\xb9\x03\x08\x1f\n/\n\x04\
+    \x042\x02\0\x12\x04\xbb\x03\x08\x16\x1a!\x20Sec\x20the\x20second\x20sinc\
+    e\x20the\x20Epoch.\n\n\x0f\n\x05\x042\x02\0\x04\x12\x06\xbb\x03\x08\xb9\
+    \x03!\n\r\n\x05\x042\x02\0\x05\x12\x04\xbb\x03\x08\r\n\r\n\x05\x042\x02\
+    \0\x01\x12\x04\xbb\x03\x0e\x11\n\r\n\x05\x042\x02\0\x03\x12\x04\xbb\x03\
+    \x14\x15\nF\n\x04\x042\x02\x01\x12\x04\xbd\x03\x08\x17\x1a8\x20Usec\x20t\
     he\x20microseconds\x20portion\x20of\x20time\x20since\x20the\x20Epoch.\n\
-    \n\x0f\n\x05\x04-\x02\x01\x04\x12\x06\x80\x03\x08\xfe\x02\x16\n\r\n\x05\
-    \x04-\x02\x01\x05\x12\x04\x80\x03\x08\r\n\r\n\x05\x04-\x02\x01\x01\x12\
-    \x04\x80\x03\x0e\x12\n\r\n\x05\x04-\x02\x01\x03\x12\x04\x80\x03\x15\x16\
-    \n\xa3\x01\n\x02\x04.\x12\x06\x85\x03\0\x9f\x03\x01\x1a\x94\x01\x20Stora\
+    \n\x0f\n\x05\x042\x02\x01\x04\x12\x06\xbd\x03\x08\xbb\x03\x16\n\r\n\x05\
+    \x042\x02\x01\x05\x12\x04\xbd\x03\x08\r\n\r\n\x05\x042\x02\x01\x01\x12\
+    \x04\xbd\x03\x0e\x12\n\r\n\x05\x042\x02\x01\x03\x12\x04\xbd\x03\x15\x16\
+    \n\xa3\x01\n\x02\x043\x12\x06\xc2\x03\0\xe3\x03\x01\x1a\x94\x01\x20Stora\
     ge\x20represents\x20both\x20the\x20rootfs\x20of\x20the\x20container,\x20\
     and\x20any\x20volume\x20that\n\x20could\x20have\x20been\x20defined\x20th\
     rough\x20the\x20Mount\x20list\x20of\x20the\x20OCI\x20specification.\n\n\
-    \x0b\n\x03\x04.\x01\x12\x04\x85\x03\x08\x0f\n\x8b\x02\n\x04\x04.\x02\0\
-    \x12\x04\x8a\x03\x08\x1a\x1a\xfc\x01\x20Driver\x20is\x20used\x20to\x20de\
+    \x0b\n\x03\x043\x01\x12\x04\xc2\x03\x08\x0f\n\x8b\x02\n\x04\x043\x02\0\
+    \x12\x04\xc7\x03\x08\x1a\x1a\xfc\x01\x20Driver\x20is\x20used\x20to\x20de\
     fine\x20the\x20way\x20the\x20storage\x20is\x20passed\x20through\x20the\n\
     \x20virtual\x20machine.\x20It\x20can\x20be\x20\"9p\",\x20\"blk\",\x20or\
     \x20something\x20else,\x20but\x20for\n\x20all\x20cases,\x20this\x20will\
     \x20define\x20if\x20some\x20extra\x20steps\x20are\x20required\x20before\
     \n\x20this\x20storage\x20gets\x20mounted\x20into\x20the\x20container.\n\
-    \n\x0f\n\x05\x04.\x02\0\x04\x12\x06\x8a\x03\x08\x85\x03\x11\n\r\n\x05\
-    \x04.\x02\0\x05\x12\x04\x8a\x03\x08\x0e\n\r\n\x05\x04.\x02\0\x01\x12\x04\
-    \x8a\x03\x0f\x15\n\r\n\x05\x04.\x02\0\x03\x12\x04\x8a\x03\x18\x19\n\xd0\
-    \x01\n\x04\x04.\x02\x01\x12\x04\x8e\x03\x08+\x1a\xc1\x01\x20DriverOption\
+    \n\x0f\n\x05\x043\x02\0\x04\x12\x06\xc7\x03\x08\xc2\x03\x11\n\r\n\x05\
+    \x043\x02\0\x05\x12\x04\xc7\x03\x08\x0e\n\r\n\x05\x043\x02\0\x01\x12\x04\
+    \xc7\x03\x0f\x15\n\r\n\x05\x043\x02\0\x03\x12\x04\xc7\x03\x18\x19\n\xd0\
+    \x01\n\x04\x043\x02\x01\x12\x04\xcb\x03\x08+\x1a\xc1\x01\x20DriverOption\
     s\x20allows\x20the\x20caller\x20to\x20define\x20a\x20list\x20of\x20optio\
     ns\x20such\n\x20as\x20block\x20sizes,\x20numbers\x20of\x20luns,\x20...\
     \x20which\x20are\x20very\x20specific\x20to\n\x20every\x20device\x20and\
     \x20cannot\x20be\x20generalized\x20through\x20extra\x20fields.\n\n\r\n\
-    \x05\x04.\x02\x01\x04\x12\x04\x8e\x03\x08\x10\n\r\n\x05\x04.\x02\x01\x05\
-    \x12\x04\x8e\x03\x11\x17\n\r\n\x05\x04.\x02\x01\x01\x12\x04\x8e\x03\x18&\
-    \n\r\n\x05\x04.\x02\x01\x03\x12\x04\x8e\x03)*\n\xce\x02\n\x04\x04.\x02\
-    \x02\x12\x04\x94\x03\x08\x1a\x1a\xbf\x02\x20Source\x20can\x20be\x20anyth\
+    \x05\x043\x02\x01\x04\x12\x04\xcb\x03\x08\x10\n\r\n\x05\x043\x02\x01\x05\
+    \x12\x04\xcb\x03\x11\x17\n\r\n\x05\x043\x02\x01\x01\x12\x04\xcb\x03\x18&\
+    \n\r\n\x05\x043\x02\x01\x03\x12\x04\xcb\x03)*\n\xce\x02\n\x04\x043\x02\
+    \x02\x12\x04\xd1\x03\x08\x1a\x1a\xbf\x02\x20Source\x20can\x20be\x20anyth\
     ing\x20representing\x20the\x20source\x20of\x20the\x20storage.\x20This\n\
     \x20will\x20be\x20handled\x20by\x20the\x20proper\x20handler\x20based\x20\
     on\x20the\x20Driver\x20used.\n\x20For\x20instance,\x20it\x20can\x20be\
     \x20a\x20very\x20simple\x20path\x20if\x20the\x20caller\x20knows\x20the\n\
     \x20name\x20of\x20device\x20inside\x20the\x20VM,\x20or\x20it\x20can\x20b\
     e\x20some\x20sort\x20of\x20identifier\n\x20to\x20let\x20the\x20agent\x20\
-    find\x20the\x20device\x20inside\x20the\x20VM.\n\n\x0f\n\x05\x04.\x02\x02\
-    \x04\x12\x06\x94\x03\x08\x8e\x03+\n\r\n\x05\x04.\x02\x02\x05\x12\x04\x94\
-    \x03\x08\x0e\n\r\n\x05\x04.\x02\x02\x01\x12\x04\x94\x03\x0f\x15\n\r\n\
-    \x05\x04.\x02\x02\x03\x12\x04\x94\x03\x18\x19\n\xdb\x01\n\x04\x04.\x02\
-    \x03\x12\x04\x98\x03\x08\x1a\x1a\xcc\x01\x20Fstype\x20represents\x20the\
+    find\x20the\x20device\x20inside\x20the\x20VM.\n\n\x0f\n\x05\x043\x02\x02\
+    \x04\x12\x06\xd1\x03\x08\xcb\x03+\n\r\n\x05\x043\x02\x02\x05\x12\x04\xd1\
+    \x03\x08\x0e\n\r\n\x05\x043\x02\x02\x01\x12\x04\xd1\x03\x0f\x15\n\r\n\
+    \x05\x043\x02\x02\x03\x12\x04\xd1\x03\x18\x19\n\xdb\x01\n\x04\x043\x02\
+    \x03\x12\x04\xd5\x03\x08\x1a\x1a\xcc\x01\x20Fstype\x20represents\x20the\
     \x20filesystem\x20that\x20needs\x20to\x20be\x20used\x20to\x20mount\x20th\
     e\n\x20storage\x20inside\x20the\x20VM.\x20For\x20instance,\x20it\x20coul\
     d\x20be\x20\"xfs\"\x20for\x20block\n\x20device,\x20\"9p\"\x20for\x20shar\
     ed\x20filesystem,\x20or\x20\"tmpfs\"\x20for\x20shared\x20/dev/shm.\n\n\
-    \x0f\n\x05\x04.\x02\x03\x04\x12\x06\x98\x03\x08\x94\x03\x1a\n\r\n\x05\
-    \x04.\x02\x03\x05\x12\x04\x98\x03\x08\x0e\n\r\n\x05\x04.\x02\x03\x01\x12\
-    \x04\x98\x03\x0f\x15\n\r\n\x05\x04.\x02\x03\x03\x12\x04\x98\x03\x18\x19\
-    \nw\n\x04\x04.\x02\x04\x12\x04\x9b\x03\x08$\x1ai\x20Options\x20describes\
+    \x0f\n\x05\x043\x02\x03\x04\x12\x06\xd5\x03\x08\xd1\x03\x1a\n\r\n\x05\
+    \x043\x02\x03\x05\x12\x04\xd5\x03\x08\x0e\n\r\n\x05\x043\x02\x03\x01\x12\
+    \x04\xd5\x03\x0f\x15\n\r\n\x05\x043\x02\x03\x03\x12\x04\xd5\x03\x18\x19\
+    \nw\n\x04\x043\x02\x04\x12\x04\xd8\x03\x08$\x1ai\x20Options\x20describes\
     \x20the\x20additional\x20options\x20that\x20might\x20be\x20needed\x20to\
-    \n\x20mount\x20properly\x20the\x20storage\x20filesytem.\n\n\r\n\x05\x04.\
-    \x02\x04\x04\x12\x04\x9b\x03\x08\x10\n\r\n\x05\x04.\x02\x04\x05\x12\x04\
-    \x9b\x03\x11\x17\n\r\n\x05\x04.\x02\x04\x01\x12\x04\x9b\x03\x18\x1f\n\r\
-    \n\x05\x04.\x02\x04\x03\x12\x04\x9b\x03\"#\na\n\x04\x04.\x02\x05\x12\x04\
-    \x9e\x03\x08\x1f\x1aS\x20MountPoint\x20refers\x20to\x20the\x20path\x20wh\
+    \n\x20mount\x20properly\x20the\x20storage\x20filesytem.\n\n\r\n\x05\x043\
+    \x02\x04\x04\x12\x04\xd8\x03\x08\x10\n\r\n\x05\x043\x02\x04\x05\x12\x04\
+    \xd8\x03\x11\x17\n\r\n\x05\x043\x02\x04\x01\x12\x04\xd8\x03\x18\x1f\n\r\
+    \n\x05\x043\x02\x04\x03\x12\x04\xd8\x03\"#\na\n\x04\x043\x02\x05\x12\x04\
+    \xdb\x03\x08\x1f\x1aS\x20MountPoint\x20refers\x20to\x20the\x20path\x20wh\
     ere\x20the\x20storage\x20should\x20be\x20mounted\n\x20inside\x20the\x20V\
-    M.\n\n\x0f\n\x05\x04.\x02\x05\x04\x12\x06\x9e\x03\x08\x9b\x03$\n\r\n\x05\
-    \x04.\x02\x05\x05\x12\x04\x9e\x03\x08\x0e\n\r\n\x05\x04.\x02\x05\x01\x12\
-    \x04\x9e\x03\x0f\x1a\n\r\n\x05\x04.\x02\x05\x03\x12\x04\x9e\x03\x1d\x1e\
-    \n\x88\x01\n\x02\x04/\x12\x06\xa3\x03\0\xc3\x03\x01\x1az\x20Device\x20re\
-    presents\x20only\x20the\x20devices\x20that\x20could\x20have\x20been\x20d\
-    efined\x20through\x20the\n\x20Linux\x20Device\x20list\x20of\x20the\x20OC\
-    I\x20specification.\n\n\x0b\n\x03\x04/\x01\x12\x04\xa3\x03\x08\x0e\n\xb0\
-    \x01\n\x04\x04/\x02\0\x12\x04\xa7\x03\x08\x16\x1a\xa1\x01\x20Id\x20can\
-    \x20be\x20used\x20to\x20identify\x20the\x20device\x20inside\x20the\x20VM\
-    .\x20Some\x20devices\n\x20might\x20not\x20need\x20it\x20to\x20be\x20iden\
-    tified\x20on\x20the\x20VM,\x20and\x20will\x20rely\x20on\x20the\n\x20prov\
-    ided\x20VmPath\x20instead.\n\n\x0f\n\x05\x04/\x02\0\x04\x12\x06\xa7\x03\
-    \x08\xa3\x03\x10\n\r\n\x05\x04/\x02\0\x05\x12\x04\xa7\x03\x08\x0e\n\r\n\
-    \x05\x04/\x02\0\x01\x12\x04\xa7\x03\x0f\x11\n\r\n\x05\x04/\x02\0\x03\x12\
-    \x04\xa7\x03\x14\x15\n\xbd\x01\n\x04\x04/\x02\x01\x12\x04\xac\x03\x08\
-    \x18\x1a\xae\x01\x20Type\x20defines\x20the\x20type\x20of\x20device\x20de\
-    scribed.\x20This\x20can\x20be\x20\"blk\",\n\x20\"scsi\",\x20\"vfio\",\
-    \x20...\n\x20Particularly,\x20this\x20should\x20be\x20used\x20to\x20trig\
-    ger\x20the\x20use\x20of\x20the\n\x20appropriate\x20device\x20handler.\n\
-    \n\x0f\n\x05\x04/\x02\x01\x04\x12\x06\xac\x03\x08\xa7\x03\x16\n\r\n\x05\
-    \x04/\x02\x01\x05\x12\x04\xac\x03\x08\x0e\n\r\n\x05\x04/\x02\x01\x01\x12\
-    \x04\xac\x03\x0f\x13\n\r\n\x05\x04/\x02\x01\x03\x12\x04\xac\x03\x16\x17\
-    \n\xab\x02\n\x04\x04/\x02\x02\x12\x04\xb2\x03\x08\x1b\x1a\x9c\x02\x20VmP\
-    ath\x20can\x20be\x20used\x20by\x20the\x20caller\x20to\x20provide\x20dire\
-    ctly\x20the\x20path\x20of\n\x20the\x20device\x20as\x20it\x20will\x20appe\
-    ar\x20inside\x20the\x20VM.\x20For\x20some\x20devices,\x20the\n\x20device\
-    \x20id\x20or\x20the\x20list\x20of\x20options\x20passed\x20might\x20not\
-    \x20be\x20enough\x20to\x20find\n\x20the\x20device.\x20In\x20those\x20cas\
-    es,\x20the\x20caller\x20should\x20predict\x20and\x20provide\n\x20this\
-    \x20vm_path.\n\n\x0f\n\x05\x04/\x02\x02\x04\x12\x06\xb2\x03\x08\xac\x03\
-    \x18\n\r\n\x05\x04/\x02\x02\x05\x12\x04\xb2\x03\x08\x0e\n\r\n\x05\x04/\
-    \x02\x02\x01\x12\x04\xb2\x03\x0f\x16\n\r\n\x05\x04/\x02\x02\x03\x12\x04\
-    \xb2\x03\x19\x1a\n\xd4\x05\n\x04\x04/\x02\x03\x12\x04\xbe\x03\x08\"\x1a\
-    \xc5\x05\x20ContainerPath\x20defines\x20the\x20path\x20where\x20the\x20d\
-    evice\x20should\x20be\x20found\x20inside\n\x20the\x20container.\x20This\
-    \x20path\x20should\x20match\x20the\x20path\x20of\x20the\x20device\x20fro\
-    m\n\x20the\x20device\x20list\x20listed\x20inside\x20the\x20OCI\x20spec.\
-    \x20This\x20is\x20used\x20in\x20order\n\x20to\x20identify\x20the\x20righ\
-    t\x20device\x20in\x20the\x20spec\x20and\x20update\x20it\x20with\x20the\n\
-    \x20right\x20options\x20such\x20as\x20major/minor\x20numbers\x20as\x20th\
-    ey\x20appear\x20inside\n\x20the\x20VM\x20for\x20instance.\x20Note\x20tha\
-    t\x20an\x20empty\x20ctr_path\x20should\x20be\x20used\n\x20to\x20make\x20\
-    sure\x20the\x20device\x20handler\x20inside\x20the\x20agent\x20is\x20call\
-    ed,\x20but\n\x20no\x20spec\x20update\x20needs\x20to\x20be\x20performed.\
-    \x20This\x20has\x20to\x20happen\x20for\x20the\n\x20case\x20of\x20rootfs,\
-    \x20when\x20a\x20device\x20has\x20to\x20be\x20waited\x20for\x20after\x20\
-    it\x20has\n\x20been\x20hotplugged.\x20An\x20equivalent\x20Storage\x20ent\
-    ry\x20should\x20be\x20defined\x20if\n\x20any\x20mount\x20needs\x20to\x20\
-    be\x20performed\x20afterwards.\n\n\x0f\n\x05\x04/\x02\x03\x04\x12\x06\
-    \xbe\x03\x08\xb2\x03\x1b\n\r\n\x05\x04/\x02\x03\x05\x12\x04\xbe\x03\x08\
-    \x0e\n\r\n\x05\x04/\x02\x03\x01\x12\x04\xbe\x03\x0f\x1d\n\r\n\x05\x04/\
-    \x02\x03\x03\x12\x04\xbe\x03\x20!\n\xca\x01\n\x04\x04/\x02\x04\x12\x04\
-    \xc2\x03\x08$\x1a\xbb\x01\x20Options\x20allows\x20the\x20caller\x20to\
-    \x20define\x20a\x20list\x20of\x20options\x20such\x20as\x20block\n\x20siz\
-    es,\x20numbers\x20of\x20luns,\x20...\x20which\x20are\x20very\x20specific\
-    \x20to\x20every\x20device\n\x20and\x20cannot\x20be\x20generalized\x20thr\
-    ough\x20extra\x20fields.\n\n\r\n\x05\x04/\x02\x04\x04\x12\x04\xc2\x03\
-    \x08\x10\n\r\n\x05\x04/\x02\x04\x05\x12\x04\xc2\x03\x11\x17\n\r\n\x05\
-    \x04/\x02\x04\x01\x12\x04\xc2\x03\x18\x1f\n\r\n\x05\x04/\x02\x04\x03\x12\
-    \x04\xc2\x03\"#\n\x0c\n\x02\x040\x12\x06\xc5\x03\0\xc9\x03\x01\n\x0b\n\
-    \x03\x040\x01\x12\x04\xc5\x03\x08\x12\n\x0c\n\x04\x040\x02\0\x12\x04\xc6\
-    \x03\x08\x17\n\x0f\n\x05\x040\x02\0\x04\x12\x06\xc6\x03\x08\xc5\x03\x14\
-    \n\r\n\x05\x040\x02\0\x05\x12\x04\xc6\x03\x08\x0e\n\r\n\x05\x040\x02\0\
-    \x01\x12\x04\xc6\x03\x0f\x12\n\r\n\x05\x040\x02\0\x03\x12\x04\xc6\x03\
-    \x15\x16\n\x0c\n\x04\x040\x02\x01\x12\x04\xc7\x03\x08\x17\n\x0f\n\x05\
-    \x040\x02\x01\x04\x12\x06\xc7\x03\x08\xc6\x03\x17\n\r\n\x05\x040\x02\x01\
-    \x05\x12\x04\xc7\x03\x08\x0e\n\r\n\x05\x040\x02\x01\x01\x12\x04\xc7\x03\
-    \x0f\x12\n\r\n\x05\x040\x02\x01\x03\x12\x04\xc7\x03\x15\x16\n\x0c\n\x04\
-    \x040\x02\x02\x12\x04\xc8\x03\x08+\n\r\n\x05\x040\x02\x02\x04\x12\x04\
-    \xc8\x03\x08\x10\n\r\n\x05\x040\x02\x02\x05\x12\x04\xc8\x03\x11\x17\n\r\
-    \n\x05\x040\x02\x02\x01\x12\x04\xc8\x03\x18&\n\r\n\x05\x040\x02\x02\x03\
-    \x12\x04\xc8\x03)*\n\x0c\n\x02\x041\x12\x06\xcb\x03\0\xdf\x03\x01\n\x0b\
-    \n\x03\x041\x01\x12\x04\xcb\x03\x08\x17\nj\n\x04\x041\x02\0\x12\x04\xce\
-    \x03\x08\x18\x1a\\\x20Path\x20is\x20the\x20destination\x20file\x20in\x20\
-    the\x20guest.\x20It\x20must\x20be\x20absolute,\n\x20canonical\x20and\x20\
-    below\x20/run.\n\n\x0f\n\x05\x041\x02\0\x04\x12\x06\xce\x03\x08\xcb\x03\
-    \x19\n\r\n\x05\x041\x02\0\x05\x12\x04\xce\x03\x08\x0e\n\r\n\x05\x041\x02\
-    \0\x01\x12\x04\xce\x03\x0f\x13\n\r\n\x05\x041\x02\0\x03\x12\x04\xce\x03\
-    \x16\x17\n\xbd\x01\n\x04\x041\x02\x01\x12\x04\xd2\x03\x08\x1c\x1a\xae\
-    \x01\x20FileSize\x20is\x20the\x20expected\x20file\x20size,\x20for\x20sec\
-    urity\x20reasons\x20write\x20operations\n\x20are\x20made\x20in\x20a\x20t\
-    emporary\x20file,\x20once\x20it\x20has\x20the\x20expected\x20size,\x20it\
-    's\x20moved\n\x20to\x20the\x20destination\x20path.\n\n\x0f\n\x05\x041\
-    \x02\x01\x04\x12\x06\xd2\x03\x08\xce\x03\x18\n\r\n\x05\x041\x02\x01\x05\
-    \x12\x04\xd2\x03\x08\r\n\r\n\x05\x041\x02\x01\x01\x12\x04\xd2\x03\x0e\
-    \x17\n\r\n\x05\x041\x02\x01\x03\x12\x04\xd2\x03\x1a\x1b\n*\n\x04\x041\
-    \x02\x02\x12\x04\xd4\x03\x08\x1d\x1a\x1c\x20FileMode\x20is\x20the\x20fil\
-    e\x20mode.\n\n\x0f\n\x05\x041\x02\x02\x04\x12\x06\xd4\x03\x08\xd2\x03\
-    \x1c\n\r\n\x05\x041\x02\x02\x05\x12\x04\xd4\x03\x08\x0e\n\r\n\x05\x041\
-    \x02\x02\x01\x12\x04\xd4\x03\x0f\x18\n\r\n\x05\x041\x02\x02\x03\x12\x04\
-    \xd4\x03\x1b\x1c\nS\n\x04\x041\x02\x03\x12\x04\xd6\x03\x08\x1c\x1aE\x20D\
-    irMode\x20is\x20the\x20mode\x20for\x20the\x20parent\x20directories\x20of\
-    \x20destination\x20path.\n\n\x0f\n\x05\x041\x02\x03\x04\x12\x06\xd6\x03\
-    \x08\xd4\x03\x1d\n\r\n\x05\x041\x02\x03\x05\x12\x04\xd6\x03\x08\x0e\n\r\
-    \n\x05\x041\x02\x03\x01\x12\x04\xd6\x03\x0f\x17\n\r\n\x05\x041\x02\x03\
-    \x03\x12\x04\xd6\x03\x1a\x1b\n+\n\x04\x041\x02\x04\x12\x04\xd8\x03\x08\
-    \x16\x1a\x1d\x20Uid\x20is\x20the\x20numeric\x20user\x20id.\n\n\x0f\n\x05\
-    \x041\x02\x04\x04\x12\x06\xd8\x03\x08\xd6\x03\x1c\n\r\n\x05\x041\x02\x04\
-    \x05\x12\x04\xd8\x03\x08\r\n\r\n\x05\x041\x02\x04\x01\x12\x04\xd8\x03\
-    \x0e\x11\n\r\n\x05\x041\x02\x04\x03\x12\x04\xd8\x03\x14\x15\n,\n\x04\x04\
-    1\x02\x05\x12\x04\xda\x03\x08\x16\x1a\x1e\x20Gid\x20is\x20the\x20numeric\
-    \x20group\x20id.\n\n\x0f\n\x05\x041\x02\x05\x04\x12\x06\xda\x03\x08\xd8\
-    \x03\x16\n\r\n\x05\x041\x02\x05\x05\x12\x04\xda\x03\x08\r\n\r\n\x05\x041\
-    \x02\x05\x01\x12\x04\xda\x03\x0e\x11\n\r\n\x05\x041\x02\x05\x03\x12\x04\
-    \xda\x03\x14\x15\n4\n\x04\x041\x02\x06\x12\x04\xdc\x03\x08\x19\x1a&\x20O\
-    ffset\x20for\x20the\x20next\x20write\x20operation.\n\n\x0f\n\x05\x041\
-    \x02\x06\x04\x12\x06\xdc\x03\x08\xda\x03\x16\n\r\n\x05\x041\x02\x06\x05\
-    \x12\x04\xdc\x03\x08\r\n\r\n\x05\x041\x02\x06\x01\x12\x04\xdc\x03\x0e\
-    \x14\n\r\n\x05\x041\x02\x06\x03\x12\x04\xdc\x03\x17\x18\n6\n\x04\x041\
-    \x02\x07\x12\x04\xde\x03\x08\x17\x1a(\x20Data\x20to\x20write\x20in\x20th\
-    e\x20destination\x20file.\n\n\x0f\n\x05\x041\x02\x07\x04\x12\x06\xde\x03\
-    \x08\xdc\x03\x19\n\r\n\x05\x041\x02\x07\x05\x12\x04\xde\x03\x08\r\n\r\n\
-    \x05\x041\x02\x07\x01\x12\x04\xde\x03\x0e\x12\n\r\n\x05\x041\x02\x07\x03\
-    \x12\x04\xde\x03\x15\x16\n\x0c\n\x02\x042\x12\x06\xe1\x03\0\xe2\x03\x01\
-    \n\x0b\n\x03\x042\x01\x12\x04\xe1\x03\x08\x1b\n\x0c\n\x02\x043\x12\x06\
-    \xe4\x03\0\xe5\x03\x01\n\x0b\n\x03\x043\x01\x12\x04\xe4\x03\x08\x1ab\x06\
-    proto3\
+    M.\n\n\x0f\n\x05\x043\x02\x05\x04\x12\x06\xdb\x03\x08\xd8\x03$\n\r\n\x05\
+    \x043\x02\x05\x05\x12\x04\xdb\x03\x08\x0e\n\r\n\x05\x043\x02\x05\x01\x12\
+    \x04\xdb\x03\x0f\x1a\n\r\n\x05\x043\x02\x05\x03\x12\x04\xdb\x03\x1d\x1e\
+    \n\xc6\x01\n\x04\x043\x02\x06\x12\x04\xdf\x03\x08\x1e\x1a\xb7\x01\x20Siz\
+    eLimit\x20is\x20the\x20maximum\x20size\x20in\x20bytes\x20of\x20an\x20eph\
+    emeral\x20or\x20local\n\x20storage,\x20the\x20size\x20of\x20the\x20tmpfs\
+    \x20of\x20the\x20former\x20and\x20a\x20project\x20quota\n\x20on\x20the\
+    \x20directory\x20of\x20the\x20latter.\x200\x20means\x20unlimited.\n\n\
+    \x0f\n\x05\x043\x02\x06\x04\x12\x06\xdf\x03\x08\xdb\x03\x1f\n\r\n\x05\
+    \x043\x02\x06\x05\x12\x04\xdf\x03\x08\x0e\n\r\n\x05\x043\x02\x06\x01\x12\
+    \x04\xdf\x03\x0f\x19\n\r\n\x05\x043\x02\x06\x03\x12\x04\xdf\x03\x1c\x1d\
+    \n\x91\x01\n\x04\x043\x02\x07\x12\x04\xe2\x03\x08\x1d\x1a\x82\x01\x20FSG\
+    roup,\x20if\x20set,\x20is\x20the\x20group\x20the\x20files\x20of\x20the\
+    \x20storage\x20are\x20given\n\x20to\x20once\x20it\x20is\x20mounted,\x20a\
+    s\x20for\x20the\x20fsGroup\x20of\x20a\x20Kubernetes\x20pod.\n\n\x0f\n\
+    \x05\x043\x02\x07\x04\x12\x06\xe2\x03\x08\xdf\x03\x1e\n\r\n\x05\x043\x02\
+    \x07\x06\x12\x04\xe2\x03\x08\x0f\n\r\n\x05\x043\x02\x07\x01\x12\x04\xe2\
+    \x03\x10\x18\n\r\n\x05\x043\x02\x07\x03\x12\x04\xe2\x03\x1b\x1c\nU\n\x02\
+    \x05\0\x12\x06\xe6\x03\0\xec\x03\x01\x1aG\x20FSGroupChangePolicy\x20tell\
+    s\x20when\x20the\x20ownership\x20of\x20a\x20storage\x20is\x20applied.\n\
+    \n\x0b\n\x03\x05\0\x01\x12\x04\xe6\x03\x05\x18\n/\n\x04\x05\0\x02\0\x12\
+    \x04\xe8\x03\x08\x13\x1a!\x20Always\x20walks\x20the\x20whole\x20storage.\
+    \n\n\r\n\x05\x05\0\x02\0\x01\x12\x04\xe8\x03\x08\x0e\n\r\n\x05\x05\0\x02\
+    \0\x02\x12\x04\xe8\x03\x11\x12\nh\n\x04\x05\0\x02\x01\x12\x04\xeb\x03\
+    \x08\x1b\x1aZ\x20OnRootMismatch\x20only\x20walks\x20it\x20when\x20its\
+    \x20root\x20does\x20not\x20have\x20the\n\x20group\x20and\x20permissions\
+    \x20yet.\n\n\r\n\x05\x05\0\x02\x01\x01\x12\x04\xeb\x03\x08\x16\n\r\n\x05\
+    \x05\0\x02\x01\x02\x12\x04\xeb\x03\x19\x1a\n\x0c\n\x02\x044\x12\x06\xee\
+    \x03\0\xf1\x03\x01\n\x0b\n\x03\x044\x01\x12\x04\xee\x03\x08\x0f\n\x0c\n\
+    \x04\x044\x02\0\x12\x04\xef\x03\x08\x1c\n\x0f\n\x05\x044\x02\0\x04\x12\
+    \x06\xef\x03\x08\xee\x03\x11\n\r\n\x05\x044\x02\0\x05\x12\x04\xef\x03\
+    \x08\x0e\n\r\n\x05\x044\x02\0\x01\x12\x04\xef\x03\x0f\x17\n\r\n\x05\x044\
+    \x02\0\x03\x12\x04\xef\x03\x1a\x1b\n\x0c\n\x04\x044\x02\x01\x12\x04\xf0\
+    \x03\x084\n\x0f\n\x05\x044\x02\x01\x04\x12\x06\xf0\x03\x08\xef\x03\x1c\n\
+    \r\n\x05\x044\x02\x01\x06\x12\x04\xf0\x03\x08\x1b\n\r\n\x05\x044\x02\x01\
+    \x01\x12\x04\xf0\x03\x1c/\n\r\n\x05\x044\x02\x01\x03\x12\x04\xf0\x0323\n\
+    \x0c\n\x02\x045\x12\x06\xf3\x03\0\xf7\x03\x01\n\x0b\n\x03\x045\x01\x12\
+    \x04\xf3\x03\x08\x1d\n\x90\x01\n\x04\x045\x02\0\x12\x04\xf6\x03\x08%\x1a\
+    \x81\x01\x20VolumeGuestPath\x20restricts\x20the\x20statistics\x20to\x20t\
+    he\x20storage\x20mounted\n\x20there.\x20All\x20the\x20storages\x20of\x20\
+    the\x20sandbox\x20are\x20reported\x20otherwise.\n\n\x0f\n\x05\x045\x02\0\
+    \x04\x12\x06\xf6\x03\x08\xf3\x03\x1f\n\r\n\x05\x045\x02\0\x05\x12\x04\
+    \xf6\x03\x08\x0e\n\r\n\x05\x045\x02\0\x01\x12\x04\xf6\x03\x0f\x20\n\r\n\
+    \x05\x045\x02\0\x03\x12\x04\xf6\x03#$\nK\n\x02\x046\x12\x06\xfa\x03\0\
+    \x82\x04\x01\x1a=\x20VolumeStats\x20is\x20the\x20usage\x20of\x20a\x20sto\
+    rage,\x20in\x20bytes\x20and\x20inodes.\n\n\x0b\n\x03\x046\x01\x12\x04\
+    \xfa\x03\x08\x13\n\x0c\n\x04\x046\x02\0\x12\x04\xfb\x03\x08%\n\x0f\n\x05\
+    \x046\x02\0\x04\x12\x06\xfb\x03\x08\xfa\x03\x15\n\r\n\x05\x046\x02\0\x05\
+    \x12\x04\xfb\x03\x08\x0e\n\r\n\x05\x046\x02\0\x01\x12\x04\xfb\x03\x0f\
+    \x20\n\r\n\x05\x046\x02\0\x03\x12\x04\xfb\x03#$\n\x0c\n\x04\x046\x02\x01\
+    \x12\x04\xfc\x03\x08\"\n\x0f\n\x05\x046\x02\x01\x04\x12\x06\xfc\x03\x08\
+    \xfb\x03%\n\r\n\x05\x046\x02\x01\x05\x12\x04\xfc\x03\x08\x0e\n\r\n\x05\
+    \x046\x02\x01\x01\x12\x04\xfc\x03\x0f\x1d\n\r\n\x05\x046\x02\x01\x03\x12\
+    \x04\xfc\x03\x20!\n\x0c\n\x04\x046\x02\x02\x12\x04\xfd\x03\x08#\n\x0f\n\
+    \x05\x046\x02\x02\x04\x12\x06\xfd\x03\x08\xfc\x03\"\n\r\n\x05\x046\x02\
+    \x02\x05\x12\x04\xfd\x03\x08\x0e\n\r\n\x05\x046\x02\x02\x01\x12\x04\xfd\
+    \x03\x0f\x1e\n\r\n\x05\x046\x02\x02\x03\x12\x04\xfd\x03!\"\n\x0c\n\x04\
+    \x046\x02\x03\x12\x04\xfe\x03\x08\x1e\n\x0f\n\x05\x046\x02\x03\x04\x12\
+    \x06\xfe\x03\x08\xfd\x03#\n\r\n\x05\x046\x02\x03\x05\x12\x04\xfe\x03\x08\
+    \x0e\n\r\n\x05\x046\x02\x03\x01\x12\x04\xfe\x03\x0f\x19\n\r\n\x05\x046\
+    \x02\x03\x03\x12\x04\xfe\x03\x1c\x1d\n\x0c\n\x04\x046\x02\x04\x12\x04\
+    \xff\x03\x08\x1a\n\x0f\n\x05\x046\x02\x04\x04\x12\x06\xff\x03\x08\xfe\
+    \x03\x1e\n\r\n\x05\x046\x02\x04\x05\x12\x04\xff\x03\x08\x0e\n\r\n\x05\
+    \x046\x02\x04\x01\x12\x04\xff\x03\x0f\x15\n\r\n\x05\x046\x02\x04\x03\x12\
+    \x04\xff\x03\x18\x19\n\x0c\n\x04\x046\x02\x05\x12\x04\x80\x04\x08\x1f\n\
+    \x0f\n\x05\x046\x02\x05\x04\x12\x06\x80\x04\x08\xff\x03\x1a\n\r\n\x05\
+    \x046\x02\x05\x05\x12\x04\x80\x04\x08\x0e\n\r\n\x05\x046\x02\x05\x01\x12\
+    \x04\x80\x04\x0f\x1a\n\r\n\x05\x046\x02\x05\x03\x12\x04\x80\x04\x1d\x1e\
+    \n\x0c\n\x04\x046\x02\x06\x12\x04\x81\x04\x08\x1f\n\x0f\n\x05\x046\x02\
+    \x06\x04\x12\x06\x81\x04\x08\x80\x04\x1f\n\r\n\x05\x046\x02\x06\x05\x12\
+    \x04\x81\x04\x08\x0e\n\r\n\x05\x046\x02\x06\x01\x12\x04\x81\x04\x0f\x1a\
+    \n\r\n\x05\x046\x02\x06\x03\x12\x04\x81\x04\x1d\x1e\n\x0c\n\x02\x047\x12\
+    \x06\x84\x04\0\x86\x04\x01\n\x0b\n\x03\x047\x01\x12\x04\x84\x04\x08\x1b\
+    \n\x0c\n\x04\x047\x02\0\x12\x04\x85\x04\x08)\n\r\n\x05\x047\x02\0\x04\
+    \x12\x04\x85\x04\x08\x10\n\r\n\x05\x047\x02\0\x06\x12\x04\x85\x04\x11\
+    \x1c\n\r\n\x05\x047\x02\0\x01\x12\x04\x85\x04\x1d$\n\r\n\x05\x047\x02\0\
+    \x03\x12\x04\x85\x04'(\n\x0c\n\x02\x048\x12\x06\x88\x04\0\x8e\x04\x01\n\
+    \x0b\n\x03\x048\x01\x12\x04\x88\x04\x08\x1b\nP\n\x04\x048\x02\0\x12\x04\
+    \x8a\x04\x08%\x1aB\x20VolumeGuestPath\x20is\x20the\x20mount\x20point\x20\
+    of\x20the\x20block\x20storage\x20to\x20grow.\n\n\x0f\n\x05\x048\x02\0\
+    \x04\x12\x06\x8a\x04\x08\x88\x04\x1d\n\r\n\x05\x048\x02\0\x05\x12\x04\
+    \x8a\x04\x08\x0e\n\r\n\x05\x048\x02\0\x01\x12\x04\x8a\x04\x0f\x20\n\r\n\
+    \x05\x048\x02\0\x03\x12\x04\x8a\x04#$\ng\n\x04\x048\x02\x01\x12\x04\x8d\
+    \x04\x08\x18\x1aY\x20Size\x20is\x20the\x20new\x20size\x20in\x20bytes\x20\
+    of\x20its\x20filesystem,\x200\x20for\x20the\x20whole\n\x20device\x20once\
+    \x20expanded.\n\n\x0f\n\x05\x048\x02\x01\x04\x12\x06\x8d\x04\x08\x8a\x04\
+    %\n\r\n\x05\x048\x02\x01\x05\x12\x04\x8d\x04\x08\x0e\n\r\n\x05\x048\x02\
+    \x01\x01\x12\x04\x8d\x04\x0f\x13\n\r\n\x05\x048\x02\x01\x03\x12\x04\x8d\
+    \x04\x16\x17\n\x88\x01\n\x02\x049\x12\x06\x92\x04\0\xb2\x04\x01\x1az\x20\
+    Device\x20represents\x20only\x20the\x20devices\x20that\x20could\x20have\
+    \x20been\x20defined\x20through\x20the\n\x20Linux\x20Device\x20list\x20of\
+    \x20the\x20OCI\x20specification.\n\n\x0b\n\x03\x049\x01\x12\x04\x92\x04\
+    \x08\x0e\n\xb0\x01\n\x04\x049\x02\0\x12\x04\x96\x04\x08\x16\x1a\xa1\x01\
+    \x20Id\x20can\x20be\x20used\x20to\x20identify\x20the\x20device\x20inside\
+    \x20the\x20VM.\x20Some\x20devices\n\x20might\x20not\x20need\x20it\x20to\
+    \x20be\x20identified\x20on\x20the\x20VM,\x20and\x20will\x20rely\x20on\
+    \x20the\n\x20provided\x20VmPath\x20instead.\n\n\x0f\n\x05\x049\x02\0\x04\
+    \x12\x06\x96\x04\x08\x92\x04\x10\n\r\n\x05\x049\x02\0\x05\x12\x04\x96\
+    \x04\x08\x0e\n\r\n\x05\x049\x02\0\x01\x12\x04\x96\x04\x0f\x11\n\r\n\x05\
+    \x049\x02\0\x03\x12\x04\x96\x04\x14\x15\n\xbd\x01\n\x04\x049\x02\x01\x12\
+    \x04\x9b\x04\x08\x18\x1a\xae\x01\x20Type\x20defines\x20the\x20type\x20of\
+    \x20device\x20described.\x20This\x20can\x20be\x20\"blk\",\n\x20\"scsi\",\
+    \x20\"vfio\",\x20...\n\x20Particularly,\x20this\x20should\x20be\x20used\
+    \x20to\x20trigger\x20the\x20use\x20of\x20the\n\x20appropriate\x20device\
+    \x20handler.\n\n\x0f\n\x05\x049\x02\x01\x04\x12\x06\x9b\x04\x08\x96\x04\
+    \x16\n\r\n\x05\x049\x02\x01\x05\x12\x04\x9b\x04\x08\x0e\n\r\n\x05\x049\
+    \x02\x01\x01\x12\x04\x9b\x04\x0f\x13\n\r\n\x05\x049\x02\x01\x03\x12\x04\
+    \x9b\x04\x16\x17\n\xab\x02\n\x04\x049\x02\x02\x12\x04\xa1\x04\x08\x1b\
+    \x1a\x9c\x02\x20VmPath\x20can\x20be\x20used\x20by\x20the\x20caller\x20to\
+    \x20provide\x20directly\x20the\x20path\x20of\n\x20the\x20device\x20as\
+    \x20it\x20will\x20appear\x20inside\x20the\x20VM.\x20For\x20some\x20devic\
+    es,\x20the\n\x20device\x20id\x20or\x20the\x20list\x20of\x20options\x20pa\
+    ssed\x20might\x20not\x20be\x20enough\x20to\x20find\n\x20the\x20device.\
+    \x20In\x20those\x20cases,\x20the\x20caller\x20should\x20predict\x20and\
+    \x20provide\n\x20this\x20vm_path.\n\n\x0f\n\x05\x049\x02\x02\x04\x12\x06\
+    \xa1\x04\x08\x9b\x04\x18\n\r\n\x05\x049\x02\x02\x05\x12\x04\xa1\x04\x08\
+    \x0e\n\r\n\x05\x049\x02\x02\x01\x12\x04\xa1\x04\x0f\x16\n\r\n\x05\x049\
+    \x02\x02\x03\x12\x04\xa1\x04\x19\x1a\n\xd4\x05\n\x04\x049\x02\x03\x12\
+    \x04\xad\x04\x08\"\x1a\xc5\x05\x20ContainerPath\x20defines\x20the\x20pat\
+    h\x20where\x20the\x20device\x20should\x20be\x20found\x20inside\n\x20the\
+    \x20container.\x20This\x20path\x20should\x20match\x20the\x20path\x20of\
+    \x20the\x20device\x20from\n\x20the\x20device\x20list\x20listed\x20inside\
+    \x20the\x20OCI\x20spec.\x20This\x20is\x20used\x20in\x20order\n\x20to\x20\
+    identify\x20the\x20right\x20device\x20in\x20the\x20spec\x20and\x20update\
+    \x20it\x20with\x20the\n\x20right\x20options\x20such\x20as\x20major/minor\
+    \x20numbers\x20as\x20they\x20appear\x20inside\n\x20the\x20VM\x20for\x20i\
+    nstance.\x20Note\x20that\x20an\x20empty\x20ctr_path\x20should\x20be\x20u\
+    sed\n\x20to\x20make\x20sure\x20the\x20device\x20handler\x20inside\x20the\
+    \x20agent\x20is\x20called,\x20but\n\x20no\x20spec\x20update\x20needs\x20\
+    to\x20be\x20performed.\x20This\x20has\x20to\x20happen\x20for\x20the\n\
+    \x20case\x20of\x20rootfs,\x20when\x20a\x20device\x20has\x20to\x20be\x20w\
+    aited\x20for\x20after\x20it\x20has\n\x20been\x20hotplugged.\x20An\x20equ\
+    ivalent\x20Storage\x20entry\x20should\x20be\x20defined\x20if\n\x20any\
+    \x20mount\x20needs\x20to\x20be\x20performed\x20afterwards.\n\n\x0f\n\x05\
+    \x049\x02\x03\x04\x12\x06\xad\x04\x08\xa1\x04\x1b\n\r\n\x05\x049\x02\x03\
+    \x05\x12\x04\xad\x04\x08\x0e\n\r\n\x05\x049\x02\x03\x01\x12\x04\xad\x04\
+    \x0f\x1d\n\r\n\x05\x049\x02\x03\x03\x12\x04\xad\x04\x20!\n\xca\x01\n\x04\
+    \x049\x02\x04\x12\x04\xb1\x04\x08$\x1a\xbb\x01\x20Options\x20allows\x20t\
+    he\x20caller\x20to\x20define\x20a\x20list\x20of\x20options\x20such\x20as\
+    \x20block\n\x20sizes,\x20numbers\x20of\x20luns,\x20...\x20which\x20are\
+    \x20very\x20specific\x20to\x20every\x20device\n\x20and\x20cannot\x20be\
+    \x20generalized\x20through\x20extra\x20fields.\n\n\r\n\x05\x049\x02\x04\
+    \x04\x12\x04\xb1\x04\x08\x10\n\r\n\x05\x049\x02\x04\x05\x12\x04\xb1\x04\
+    \x11\x17\n\r\n\x05\x049\x02\x04\x01\x12\x04\xb1\x04\x18\x1f\n\r\n\x05\
+    \x049\x02\x04\x03\x12\x04\xb1\x04\"#\n\x0c\n\x02\x04:\x12\x06\xb4\x04\0\
+    \xb8\x04\x01\n\x0b\n\x03\x04:\x01\x12\x04\xb4\x04\x08\x12\n\x0c\n\x04\
+    \x04:\x02\0\x12\x04\xb5\x04\x08\x17\n\x0f\n\x05\x04:\x02\0\x04\x12\x06\
+    \xb5\x04\x08\xb4\x04\x14\n\r\n\x05\x04:\x02\0\x05\x12\x04\xb5\x04\x08\
+    \x0e\n\r\n\x05\x04:\x02\0\x01\x12\x04\xb5\x04\x0f\x12\n\r\n\x05\x04:\x02\
+    \0\x03\x12\x04\xb5\x04\x15\x16\n\x0c\n\x04\x04:\x02\x01\x12\x04\xb6\x04\
+    \x08\x17\n\x0f\n\x05\x04:\x02\x01\x04\x12\x06\xb6\x04\x08\xb5\x04\x17\n\
+    \r\n\x05\x04:\x02\x01\x05\x12\x04\xb6\x04\x08\x0e\n\r\n\x05\x04:\x02\x01\
+    \x01\x12\x04\xb6\x04\x0f\x12\n\r\n\x05\x04:\x02\x01\x03\x12\x04\xb6\x04\
+    \x15\x16\n\x0c\n\x04\x04:\x02\x02\x12\x04\xb7\x04\x08+\n\r\n\x05\x04:\
+    \x02\x02\x04\x12\x04\xb7\x04\x08\x10\n\r\n\x05\x04:\x02\x02\x05\x12\x04\
+    \xb7\x04\x11\x17\n\r\n\x05\x04:\x02\x02\x01\x12\x04\xb7\x04\x18&\n\r\n\
+    \x05\x04:\x02\x02\x03\x12\x04\xb7\x04)*\n\x0c\n\x02\x04;\x12\x06\xba\x04\
+    \0\xcf\x04\x01\n\x0b\n\x03\x04;\x01\x12\x04\xba\x04\x08\x17\n\xcc\x01\n\
+    \x04\x04;\x02\0\x12\x04\xbe\x04\x08\x18\x1a\xbd\x01\x20Path\x20is\x20the\
+    \x20destination\x20file\x20in\x20the\x20guest.\x20It\x20must\x20be\x20ab\
+    solute,\n\x20canonical\x20and\x20below\x20/run.\x20Files\x20below\x20the\
+    \x20source\x20of\x20a\x20\"watchable\"\n\x20storage\x20are\x20published\
+    \x20at\x20its\x20mount\x20point\x20once\x20complete.\n\n\x0f\n\x05\x04;\
+    \x02\0\x04\x12\x06\xbe\x04\x08\xba\x04\x19\n\r\n\x05\x04;\x02\0\x05\x12\
+    \x04\xbe\x04\x08\x0e\n\r\n\x05\x04;\x02\0\x01\x12\x04\xbe\x04\x0f\x13\n\
+    \r\n\x05\x04;\x02\0\x03\x12\x04\xbe\x04\x16\x17\n\xbd\x01\n\x04\x04;\x02\
+    \x01\x12\x04\xc2\x04\x08\x1c\x1a\xae\x01\x20FileSize\x20is\x20the\x20exp\
+    ected\x20file\x20size,\x20for\x20security\x20reasons\x20write\x20operati\
+    ons\n\x20are\x20made\x20in\x20a\x20temporary\x20file,\x20once\x20it\x20h\
+    as\x20the\x20expected\x20size,\x20it's\x20moved\n\x20to\x20the\x20destin\
+    ation\x20path.\n\n\x0f\n\x05\x04;\x02\x01\x04\x12\x06\xc2\x04\x08\xbe\
+    \x04\x18\n\r\n\x05\x04;\x02\x01\x05\x12\x04\xc2\x04\x08\r\n\r\n\x05\x04;\
+    \x02\x01\x01\x12\x04\xc2\x04\x0e\x17\n\r\n\x05\x04;\x02\x01\x03\x12\x04\
+    \xc2\x04\x1a\x1b\n*\n\x04\x04;\x02\x02\x12\x04\xc4\x04\x08\x1d\x1a\x1c\
+    \x20FileMode\x20is\x20the\x20file\x20mode.\n\n\x0f\n\x05\x04;\x02\x02\
+    \x04\x12\x06\xc4\x04\x08\xc2\x04\x1c\n\r\n\x05\x04;\x02\x02\x05\x12\x04\
+    \xc4\x04\x08\x0e\n\r\n\x05\x04;\x02\x02\x01\x12\x04\xc4\x04\x0f\x18\n\r\
+    \n\x05\x04;\x02\x02\x03\x12\x04\xc4\x04\x1b\x1c\nS\n\x04\x04;\x02\x03\
+    \x12\x04\xc6\x04\x08\x1c\x1aE\x20DirMode\x20is\x20the\x20mode\x20for\x20\
+    the\x20parent\x20directories\x20of\x20destination\x20path.\n\n\x0f\n\x05\
+    \x04;\x02\x03\x04\x12\x06\xc6\x04\x08\xc4\x04\x1d\n\r\n\x05\x04;\x02\x03\
+    \x05\x12\x04\xc6\x04\x08\x0e\n\r\n\x05\x04;\x02\x03\x01\x12\x04\xc6\x04\
+    \x0f\x17\n\r\n\x05\x04;\x02\x03\x03\x12\x04\xc6\x04\x1a\x1b\n+\n\x04\x04\
+    ;\x02\x04\x12\x04\xc8\x04\x08\x16\x1a\x1d\x20Uid\x20is\x20the\x20numeric\
+    \x20user\x20id.\n\n\x0f\n\x05\x04;\x02\x04\x04\x12\x06\xc8\x04\x08\xc6\
+    \x04\x1c\n\r\n\x05\x04;\x02\x04\x05\x12\x04\xc8\x04\x08\r\n\r\n\x05\x04;\
+    \x02\x04\x01\x12\x04\xc8\x04\x0e\x11\n\r\n\x05\x04;\x02\x04\x03\x12\x04\
+    \xc8\x04\x14\x15\n,\n\x04\x04;\x02\x05\x12\x04\xca\x04\x08\x16\x1a\x1e\
+    \x20Gid\x20is\x20the\x20numeric\x20group\x20id.\n\n\x0f\n\x05\x04;\x02\
+    \x05\x04\x12\x06\xca\x04\x08\xc8\x04\x16\n\r\n\x05\x04;\x02\x05\x05\x12\
+    \x04\xca\x04\x08\r\n\r\n\x05\x04;\x02\x05\x01\x12\x04\xca\x04\x0e\x11\n\
+    \r\n\x05\x04;\x02\x05\x03\x12\x04\xca\x04\x14\x15\n4\n\x04\x04;\x02\x06\
+    \x12\x04\xcc\x04\x08\x19\x1a&\x20Offset\x20for\x20the\x20next\x20write\
+    \x20operation.\n\n\x0f\n\x05\x04;\x02\x06\x04\x12\x06\xcc\x04\x08\xca\
+    \x04\x16\n\r\n\x05\x04;\x02\x06\x05\x12\x04\xcc\x04\x08\r\n\r\n\x05\x04;\
+    \x02\x06\x01\x12\x04\xcc\x04\x0e\x14\n\r\n\x05\x04;\x02\x06\x03\x12\x04\
+    \xcc\x04\x17\x18\n6\n\x04\x04;\x02\x07\x12\x04\xce\x04\x08\x17\x1a(\x20D\
+    ata\x20to\x20write\x20in\x20the\x20destination\x20file.\n\n\x0f\n\x05\
+    \x04;\x02\x07\x04\x12\x06\xce\x04\x08\xcc\x04\x19\n\r\n\x05\x04;\x02\x07\
+    \x05\x12\x04\xce\x04\x08\r\n\r\n\x05\x04;\x02\x07\x01\x12\x04\xce\x04\
+    \x0e\x12\n\r\n\x05\x04;\x02\x07\x03\x12\x04\xce\x04\x15\x16\n\x0c\n\x02\
+    \x04<\x12\x06\xd1\x04\0\xd2\x04\x01\n\x0b\n\x03\x04<\x01\x12\x04\xd1\x04\
+    \x08\x1b\n\x0c\n\x02\x04=\x12\x06\xd4\x04\0\xd5\x04\x01\n\x0b\n\x03\x04=\
+    \x01\x12\x04\xd4\x04\x08\x1a\n\x0c\n\x02\x04>\x12\x06\xd7\x04\0\xdf\x04\
+    \x01\n\x0b\n\x03\x04>\x01\x12\x04\xd7\x04\x08\x1a\nn\n\x04\x04>\x02\0\
+    \x12\x04\xda\x04\x08\x19\x1a`\x20Level\x20is\x20the\x20log\x20level\x20o\
+    f\x20the\x20agent,\x20by\x20its\x20logrus\x20name,\x20e.g.\n\x20\"debug\
+    \",\x20left\x20as\x20is\x20when\x20empty.\n\n\x0f\n\x05\x04>\x02\0\x04\
+    \x12\x06\xda\x04\x08\xd7\x04\x1c\n\r\n\x05\x04>\x02\0\x05\x12\x04\xda\
+    \x04\x08\x0e\n\r\n\x05\x04>\x02\0\x01\x12\x04\xda\x04\x0f\x14\n\r\n\x05\
+    \x04>\x02\0\x03\x12\x04\xda\x04\x17\x18\n\xd0\x01\n\x04\x04>\x02\x01\x12\
+    \x04\xde\x04\x08+\x1a\xc1\x01\x20Subsystems\x20are\x20the\x20log\x20leve\
+    ls\x20of\x20the\x20subsystems\x20logging\x20at\x20another\n\x20level\x20\
+    than\x20the\x20agent,\x20e.g.\x20{\"netlink\":\x20\"debug\"},\x20by\x20t\
+    he\x20\"subsystem\"\n\x20field\x20of\x20their\x20records.\x20They\x20rep\
+    lace\x20those\x20set\x20before.\n\n\x0f\n\x05\x04>\x02\x01\x04\x12\x06\
+    \xde\x04\x08\xda\x04\x19\n\r\n\x05\x04>\x02\x01\x06\x12\x04\xde\x04\x08\
+    \x1b\n\r\n\x05\x04>\x02\x01\x01\x12\x04\xde\x04\x1c&\n\r\n\x05\x04>\x02\
+    \x01\x03\x12\x04\xde\x04)*\n\x0c\n\x02\x04?\x12\x06\xe1\x04\0\xe2\x04\
+    \x01\n\x0b\n\x03\x04?\x01\x12\x04\xe1\x04\x08\x19\nq\n\x02\x04@\x12\x06\
+    \xe6\x04\0\xe8\x04\x01\x1ac\x20Metrics\x20are\x20the\x20metrics\x20of\
+    \x20the\x20agent\x20and\x20of\x20the\x20guest,\x20in\x20the\x20Prometheu\
+    s\n\x20text\x20exposition\x20format.\n\n\x0b\n\x03\x04@\x01\x12\x04\xe6\
+    \x04\x08\x0f\n\x0c\n\x04\x04@\x02\0\x12\x04\xe7\x04\x08\x1b\n\x0f\n\x05\
+    \x04@\x02\0\x04\x12\x06\xe7\x04\x08\xe6\x04\x11\n\r\n\x05\x04@\x02\0\x05\
+    \x12\x04\xe7\x04\x08\x0e\n\r\n\x05\x04@\x02\0\x01\x12\x04\xe7\x04\x0f\
+    \x16\n\r\n\x05\x04@\x02\0\x03\x12\x04\xe7\x04\x19\x1a\n\x0c\n\x02\x04A\
+    \x12\x06\xea\x04\0\xee\x04\x01\n\x0b\n\x03\x04A\x01\x12\x04\xea\x04\x08\
+    \x18\n\x89\x01\n\x04\x04A\x02\0\x12\x04\xed\x04\x08\x1a\x1a{\x20Policy\
+    \x20is\x20the\x20authorization\x20policy\x20of\x20the\x20RPCs,\x20as\x20\
+    a\x20JSON\x20document.\n\x20It\x20is\x20set\x20once,\x20unless\x20the\
+    \x20agent\x20loaded\x20one\x20at\x20boot.\n\n\x0f\n\x05\x04A\x02\0\x04\
+    \x12\x06\xed\x04\x08\xea\x04\x1a\n\r\n\x05\x04A\x02\0\x05\x12\x04\xed\
+    \x04\x08\x0e\n\r\n\x05\x04A\x02\0\x01\x12\x04\xed\x04\x0f\x15\n\r\n\x05\
+    \x04A\x02\0\x03\x12\x04\xed\x04\x18\x19b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -158,6 +158,27 @@ const METHOD_AGENT_SERVICE_LIST_ROUTES: ::grpcio::Method<super::agent::ListRoute
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_GET_NETWORK_EVENTS: ::grpcio::Method<super::agent::GetNetworkEventsRequest, super::agent::NetworkEvent> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/grpc.AgentService/GetNetworkEvents",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_UPDATE_TRAFFIC_CONTROL: ::grpcio::Method<super::agent::UpdateTrafficControlRequest, super::agent::TrafficControl> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/UpdateTrafficControl",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_LIST_TRAFFIC_CONTROL: ::grpcio::Method<super::agent::ListTrafficControlRequest, super::agent::TrafficControl> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/ListTrafficControl",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_START_TRACING: ::grpcio::Method<super::agent::StartTracingRequest, super::empty::Empty> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/StartTracing",
@@ -228,6 +249,41 @@ const METHOD_AGENT_SERVICE_COPY_FILE: ::grpcio::Method<super::agent::CopyFileReq
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_GET_VOLUME_STATS: ::grpcio::Method<super::agent::GetVolumeStatsRequest, super::agent::VolumeStatsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/GetVolumeStats",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_RESIZE_VOLUME: ::grpcio::Method<super::agent::ResizeVolumeRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/ResizeVolume",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_SET_LOG_LEVEL: ::grpcio::Method<super::agent::SetLogLevelRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/SetLogLevel",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_GET_METRICS: ::grpcio::Method<super::agent::GetMetricsRequest, super::agent::Metrics> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/GetMetrics",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_SET_POLICY: ::grpcio::Method<super::agent::SetPolicyRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/SetPolicy",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct AgentServiceClient {
     client: ::grpcio::Client,
@@ -560,6 +616,46 @@ impl AgentServiceClient {
         self.list_routes_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn get_network_events_opt(&self, req: &super::agent::GetNetworkEventsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::NetworkEvent>> {
+        self.client.server_streaming(&METHOD_AGENT_SERVICE_GET_NETWORK_EVENTS, req, opt)
+    }
+
+    pub fn get_network_events(&self, req: &super::agent::GetNetworkEventsRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::NetworkEvent>> {
+        self.get_network_events_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_traffic_control_opt(&self, req: &super::agent::UpdateTrafficControlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::TrafficControl> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_TRAFFIC_CONTROL, req, opt)
+    }
+
+    pub fn update_traffic_control(&self, req: &super::agent::UpdateTrafficControlRequest) -> ::grpcio::Result<super::agent::TrafficControl> {
+        self.update_traffic_control_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_traffic_control_async_opt(&self, req: &super::agent::UpdateTrafficControlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::TrafficControl>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_UPDATE_TRAFFIC_CONTROL, req, opt)
+    }
+
+    pub fn update_traffic_control_async(&self, req: &super::agent::UpdateTrafficControlRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::TrafficControl>> {
+        self.update_traffic_control_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_traffic_control_opt(&self, req: &super::agent::ListTrafficControlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::TrafficControl> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_LIST_TRAFFIC_CONTROL, req, opt)
+    }
+
+    pub fn list_traffic_control(&self, req: &super::agent::ListTrafficControlRequest) -> ::grpcio::Result<super::agent::TrafficControl> {
+        self.list_traffic_control_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_traffic_control_async_opt(&self, req: &super::agent::ListTrafficControlRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::TrafficControl>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_LIST_TRAFFIC_CONTROL, req, opt)
+    }
+
+    pub fn list_traffic_control_async(&self, req: &super::agent::ListTrafficControlRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::TrafficControl>> {
+        self.list_traffic_control_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn start_tracing_opt(&self, req: &super::agent::StartTracingRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_START_TRACING, req, opt)
     }
@@ -719,6 +815,86 @@ impl AgentServiceClient {
     pub fn copy_file_async(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
         self.copy_file_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn get_volume_stats_opt(&self, req: &super::agent::GetVolumeStatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::VolumeStatsResponse> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_GET_VOLUME_STATS, req, opt)
+    }
+
+    pub fn get_volume_stats(&self, req: &super::agent::GetVolumeStatsRequest) -> ::grpcio::Result<super::agent::VolumeStatsResponse> {
+        self.get_volume_stats_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_volume_stats_async_opt(&self, req: &super::agent::GetVolumeStatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::VolumeStatsResponse>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_GET_VOLUME_STATS, req, opt)
+    }
+
+    pub fn get_volume_stats_async(&self, req: &super::agent::GetVolumeStatsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::VolumeStatsResponse>> {
+        self.get_volume_stats_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn resize_volume_opt(&self, req: &super::agent::ResizeVolumeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_RESIZE_VOLUME, req, opt)
+    }
+
+    pub fn resize_volume(&self, req: &super::agent::ResizeVolumeRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.resize_volume_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn resize_volume_async_opt(&self, req: &super::agent::ResizeVolumeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_RESIZE_VOLUME, req, opt)
+    }
+
+    pub fn resize_volume_async(&self, req: &super::agent::ResizeVolumeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.resize_volume_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_log_level_opt(&self, req: &super::agent::SetLogLevelRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_SET_LOG_LEVEL, req, opt)
+    }
+
+    pub fn set_log_level(&self, req: &super::agent::SetLogLevelRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.set_log_level_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_log_level_async_opt(&self, req: &super::agent::SetLogLevelRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_SET_LOG_LEVEL, req, opt)
+    }
+
+    pub fn set_log_level_async(&self, req: &super::agent::SetLogLevelRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.set_log_level_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_metrics_opt(&self, req: &super::agent::GetMetricsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::Metrics> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_GET_METRICS, req, opt)
+    }
+
+    pub fn get_metrics(&self, req: &super::agent::GetMetricsRequest) -> ::grpcio::Result<super::agent::Metrics> {
+        self.get_metrics_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_metrics_async_opt(&self, req: &super::agent::GetMetricsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::Metrics>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_GET_METRICS, req, opt)
+    }
+
+    pub fn get_metrics_async(&self, req: &super::agent::GetMetricsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::Metrics>> {
+        self.get_metrics_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_policy_opt(&self, req: &super::agent::SetPolicyRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_SET_POLICY, req, opt)
+    }
+
+    pub fn set_policy(&self, req: &super::agent::SetPolicyRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.set_policy_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn set_policy_async_opt(&self, req: &super::agent::SetPolicyRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_SET_POLICY, req, opt)
+    }
+
+    pub fn set_policy_async(&self, req: &super::agent::SetPolicyRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.set_policy_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -745,6 +921,9 @@ pub trait AgentService {
     fn update_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
     fn list_interfaces(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListInterfacesRequest, sink: ::grpcio::UnarySink<super::agent::Interfaces>);
     fn list_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
+    fn get_network_events(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetNetworkEventsRequest, sink: ::grpcio::ServerStreamingSink<super::agent::NetworkEvent>);
+    fn update_traffic_control(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateTrafficControlRequest, sink: ::grpcio::UnarySink<super::agent::TrafficControl>);
+    fn list_traffic_control(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListTrafficControlRequest, sink: ::grpcio::UnarySink<super::agent::TrafficControl>);
     fn start_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StartTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn stop_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StopTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn create_sandbox(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CreateSandboxRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
@@ -755,6 +934,11 @@ pub trait AgentService {
     fn mem_hotplug_by_probe(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::MemHotplugByProbeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn set_guest_date_time(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SetGuestDateTimeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn copy_file(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CopyFileRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn get_volume_stats(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetVolumeStatsRequest, sink: ::grpcio::UnarySink<super::agent::VolumeStatsResponse>);
+    fn resize_volume(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ResizeVolumeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn set_log_level(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SetLogLevelRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn get_metrics(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetMetricsRequest, sink: ::grpcio::UnarySink<super::agent::Metrics>);
+    fn set_policy(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SetPolicyRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
 }
 
 pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -840,6 +1024,18 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.list_routes(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_AGENT_SERVICE_GET_NETWORK_EVENTS, move |ctx, req, resp| {
+        instance.get_network_events(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_TRAFFIC_CONTROL, move |ctx, req, resp| {
+        instance.update_traffic_control(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_LIST_TRAFFIC_CONTROL, move |ctx, req, resp| {
+        instance.list_traffic_control(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_START_TRACING, move |ctx, req, resp| {
         instance.start_tracing(ctx, req, resp)
     });
@@ -879,5 +1075,25 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_COPY_FILE, move |ctx, req, resp| {
         instance.copy_file(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_GET_VOLUME_STATS, move |ctx, req, resp| {
+        instance.get_volume_stats(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_RESIZE_VOLUME, move |ctx, req, resp| {
+        instance.resize_volume(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_SET_LOG_LEVEL, move |ctx, req, resp| {
+        instance.set_log_level(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_GET_METRICS, move |ctx, req, resp| {
+        instance.get_metrics(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_SET_POLICY, move |ctx, req, resp| {
+        instance.set_policy(ctx, req, resp)
+    });
     builder.build()
 }
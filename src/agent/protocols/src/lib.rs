@@ -7,6 +7,7 @@ pub mod health_grpc;
 pub mod oci;
 pub mod types;
 pub mod empty;
+pub mod ttrpc;
 
 #[cfg(test)]
 mod tests {
@@ -274,6 +274,14 @@ pub struct Interface {
     pub pciAddr: ::std::string::String,
     pub field_type: ::std::string::String,
     pub raw_flags: u32,
+    pub index: i32,
+    pub oper_state: ::std::string::String,
+    pub carrier: bool,
+    pub txqlen: u32,
+    pub master_index: i32,
+    pub master: ::std::string::String,
+    pub driver: ::std::string::String,
+    pub statistics: ::protobuf::SingularPtrField<InterfaceStatistics>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -474,6 +482,177 @@ impl Interface {
     pub fn set_raw_flags(&mut self, v: u32) {
         self.raw_flags = v;
     }
+
+    // int32 index = 9;
+
+
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i32) {
+        self.index = v;
+    }
+
+    // string oper_state = 10;
+
+
+    pub fn get_oper_state(&self) -> &str {
+        &self.oper_state
+    }
+    pub fn clear_oper_state(&mut self) {
+        self.oper_state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_oper_state(&mut self, v: ::std::string::String) {
+        self.oper_state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_oper_state(&mut self) -> &mut ::std::string::String {
+        &mut self.oper_state
+    }
+
+    // Take field
+    pub fn take_oper_state(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.oper_state, ::std::string::String::new())
+    }
+
+    // bool carrier = 11;
+
+
+    pub fn get_carrier(&self) -> bool {
+        self.carrier
+    }
+    pub fn clear_carrier(&mut self) {
+        self.carrier = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_carrier(&mut self, v: bool) {
+        self.carrier = v;
+    }
+
+    // uint32 txqlen = 12;
+
+
+    pub fn get_txqlen(&self) -> u32 {
+        self.txqlen
+    }
+    pub fn clear_txqlen(&mut self) {
+        self.txqlen = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_txqlen(&mut self, v: u32) {
+        self.txqlen = v;
+    }
+
+    // int32 master_index = 13;
+
+
+    pub fn get_master_index(&self) -> i32 {
+        self.master_index
+    }
+    pub fn clear_master_index(&mut self) {
+        self.master_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_master_index(&mut self, v: i32) {
+        self.master_index = v;
+    }
+
+    // string master = 14;
+
+
+    pub fn get_master(&self) -> &str {
+        &self.master
+    }
+    pub fn clear_master(&mut self) {
+        self.master.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_master(&mut self, v: ::std::string::String) {
+        self.master = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_master(&mut self) -> &mut ::std::string::String {
+        &mut self.master
+    }
+
+    // Take field
+    pub fn take_master(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.master, ::std::string::String::new())
+    }
+
+    // string driver = 15;
+
+
+    pub fn get_driver(&self) -> &str {
+        &self.driver
+    }
+    pub fn clear_driver(&mut self) {
+        self.driver.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_driver(&mut self, v: ::std::string::String) {
+        self.driver = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_driver(&mut self) -> &mut ::std::string::String {
+        &mut self.driver
+    }
+
+    // Take field
+    pub fn take_driver(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.driver, ::std::string::String::new())
+    }
+
+    // .types.InterfaceStatistics statistics = 16;
+
+
+    pub fn get_statistics(&self) -> &InterfaceStatistics {
+        self.statistics.as_ref().unwrap_or_else(|| InterfaceStatistics::default_instance())
+    }
+    pub fn clear_statistics(&mut self) {
+        self.statistics.clear();
+    }
+
+    pub fn has_statistics(&self) -> bool {
+        self.statistics.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_statistics(&mut self, v: InterfaceStatistics) {
+        self.statistics = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_statistics(&mut self) -> &mut InterfaceStatistics {
+        if self.statistics.is_none() {
+            self.statistics.set_default();
+        }
+        self.statistics.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_statistics(&mut self) -> InterfaceStatistics {
+        self.statistics.take().unwrap_or_else(|| InterfaceStatistics::new())
+    }
 }
 
 impl ::protobuf::Message for Interface {
@@ -483,6 +662,11 @@ impl ::protobuf::Message for Interface {
                 return false;
             }
         };
+        for v in &self.statistics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -522,6 +706,46 @@ impl ::protobuf::Message for Interface {
                     let tmp = is.read_uint32()?;
                     self.raw_flags = tmp;
                 },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.index = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.oper_state)?;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.carrier = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.txqlen = tmp;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.master_index = tmp;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.master)?;
+                },
+                15 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.driver)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.statistics)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -559,6 +783,31 @@ impl ::protobuf::Message for Interface {
         if self.raw_flags != 0 {
             my_size += ::protobuf::rt::value_size(8, self.raw_flags, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.oper_state.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.oper_state);
+        }
+        if self.carrier != false {
+            my_size += 2;
+        }
+        if self.txqlen != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.txqlen, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.master_index != 0 {
+            my_size += ::protobuf::rt::value_size(13, self.master_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.master.is_empty() {
+            my_size += ::protobuf::rt::string_size(14, &self.master);
+        }
+        if !self.driver.is_empty() {
+            my_size += ::protobuf::rt::string_size(15, &self.driver);
+        }
+        if let Some(ref v) = self.statistics.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -591,6 +840,32 @@ impl ::protobuf::Message for Interface {
         if self.raw_flags != 0 {
             os.write_uint32(8, self.raw_flags)?;
         }
+        if self.index != 0 {
+            os.write_int32(9, self.index)?;
+        }
+        if !self.oper_state.is_empty() {
+            os.write_string(10, &self.oper_state)?;
+        }
+        if self.carrier != false {
+            os.write_bool(11, self.carrier)?;
+        }
+        if self.txqlen != 0 {
+            os.write_uint32(12, self.txqlen)?;
+        }
+        if self.master_index != 0 {
+            os.write_int32(13, self.master_index)?;
+        }
+        if !self.master.is_empty() {
+            os.write_string(14, &self.master)?;
+        }
+        if !self.driver.is_empty() {
+            os.write_string(15, &self.driver)?;
+        }
+        if let Some(ref v) = self.statistics.as_ref() {
+            os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -673,6 +948,46 @@ impl ::protobuf::Message for Interface {
                     |m: &Interface| { &m.raw_flags },
                     |m: &mut Interface| { &mut m.raw_flags },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "index",
+                    |m: &Interface| { &m.index },
+                    |m: &mut Interface| { &mut m.index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "oper_state",
+                    |m: &Interface| { &m.oper_state },
+                    |m: &mut Interface| { &mut m.oper_state },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "carrier",
+                    |m: &Interface| { &m.carrier },
+                    |m: &mut Interface| { &mut m.carrier },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "txqlen",
+                    |m: &Interface| { &m.txqlen },
+                    |m: &mut Interface| { &mut m.txqlen },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "master_index",
+                    |m: &Interface| { &m.master_index },
+                    |m: &mut Interface| { &mut m.master_index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "master",
+                    |m: &Interface| { &m.master },
+                    |m: &mut Interface| { &mut m.master },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "driver",
+                    |m: &Interface| { &m.driver },
+                    |m: &mut Interface| { &mut m.driver },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<InterfaceStatistics>>(
+                    "statistics",
+                    |m: &Interface| { &m.statistics },
+                    |m: &mut Interface| { &mut m.statistics },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Interface>(
                     "Interface",
                     fields,
@@ -703,6 +1018,14 @@ impl ::protobuf::Clear for Interface {
         self.pciAddr.clear();
         self.field_type.clear();
         self.raw_flags = 0;
+        self.index = 0;
+        self.oper_state.clear();
+        self.carrier = false;
+        self.txqlen = 0;
+        self.master_index = 0;
+        self.master.clear();
+        self.driver.clear();
+        self.statistics.clear();
         self.unknown_fields.clear();
     }
 }
@@ -720,150 +1043,186 @@ impl ::protobuf::reflect::ProtobufValue for Interface {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Route {
+pub struct InterfaceStatistics {
     // message fields
-    pub dest: ::std::string::String,
-    pub gateway: ::std::string::String,
-    pub device: ::std::string::String,
-    pub source: ::std::string::String,
-    pub scope: u32,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+    pub collisions: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Route {
-    fn default() -> &'a Route {
-        <Route as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a InterfaceStatistics {
+    fn default() -> &'a InterfaceStatistics {
+        <InterfaceStatistics as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Route {
-    pub fn new() -> Route {
+impl InterfaceStatistics {
+    pub fn new() -> InterfaceStatistics {
         ::std::default::Default::default()
     }
 
-    // string dest = 1;
+    // uint64 rx_packets = 1;
 
 
-    pub fn get_dest(&self) -> &str {
-        &self.dest
+    pub fn get_rx_packets(&self) -> u64 {
+        self.rx_packets
     }
-    pub fn clear_dest(&mut self) {
-        self.dest.clear();
+    pub fn clear_rx_packets(&mut self) {
+        self.rx_packets = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_dest(&mut self, v: ::std::string::String) {
-        self.dest = v;
+    pub fn set_rx_packets(&mut self, v: u64) {
+        self.rx_packets = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_dest(&mut self) -> &mut ::std::string::String {
-        &mut self.dest
+    // uint64 tx_packets = 2;
+
+
+    pub fn get_tx_packets(&self) -> u64 {
+        self.tx_packets
+    }
+    pub fn clear_tx_packets(&mut self) {
+        self.tx_packets = 0;
     }
 
-    // Take field
-    pub fn take_dest(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.dest, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_tx_packets(&mut self, v: u64) {
+        self.tx_packets = v;
     }
 
-    // string gateway = 2;
+    // uint64 rx_bytes = 3;
 
 
-    pub fn get_gateway(&self) -> &str {
-        &self.gateway
+    pub fn get_rx_bytes(&self) -> u64 {
+        self.rx_bytes
     }
-    pub fn clear_gateway(&mut self) {
-        self.gateway.clear();
+    pub fn clear_rx_bytes(&mut self) {
+        self.rx_bytes = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_gateway(&mut self, v: ::std::string::String) {
-        self.gateway = v;
+    pub fn set_rx_bytes(&mut self, v: u64) {
+        self.rx_bytes = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_gateway(&mut self) -> &mut ::std::string::String {
-        &mut self.gateway
+    // uint64 tx_bytes = 4;
+
+
+    pub fn get_tx_bytes(&self) -> u64 {
+        self.tx_bytes
+    }
+    pub fn clear_tx_bytes(&mut self) {
+        self.tx_bytes = 0;
     }
 
-    // Take field
-    pub fn take_gateway(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.gateway, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_tx_bytes(&mut self, v: u64) {
+        self.tx_bytes = v;
     }
 
-    // string device = 3;
+    // uint64 rx_errors = 5;
 
 
-    pub fn get_device(&self) -> &str {
-        &self.device
+    pub fn get_rx_errors(&self) -> u64 {
+        self.rx_errors
     }
-    pub fn clear_device(&mut self) {
-        self.device.clear();
+    pub fn clear_rx_errors(&mut self) {
+        self.rx_errors = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_device(&mut self, v: ::std::string::String) {
-        self.device = v;
+    pub fn set_rx_errors(&mut self, v: u64) {
+        self.rx_errors = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_device(&mut self) -> &mut ::std::string::String {
-        &mut self.device
+    // uint64 tx_errors = 6;
+
+
+    pub fn get_tx_errors(&self) -> u64 {
+        self.tx_errors
+    }
+    pub fn clear_tx_errors(&mut self) {
+        self.tx_errors = 0;
     }
 
-    // Take field
-    pub fn take_device(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_tx_errors(&mut self, v: u64) {
+        self.tx_errors = v;
     }
 
-    // string source = 4;
+    // uint64 rx_dropped = 7;
 
 
-    pub fn get_source(&self) -> &str {
-        &self.source
+    pub fn get_rx_dropped(&self) -> u64 {
+        self.rx_dropped
     }
-    pub fn clear_source(&mut self) {
-        self.source.clear();
+    pub fn clear_rx_dropped(&mut self) {
+        self.rx_dropped = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_source(&mut self, v: ::std::string::String) {
-        self.source = v;
+    pub fn set_rx_dropped(&mut self, v: u64) {
+        self.rx_dropped = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_source(&mut self) -> &mut ::std::string::String {
-        &mut self.source
+    // uint64 tx_dropped = 8;
+
+
+    pub fn get_tx_dropped(&self) -> u64 {
+        self.tx_dropped
+    }
+    pub fn clear_tx_dropped(&mut self) {
+        self.tx_dropped = 0;
     }
 
-    // Take field
-    pub fn take_source(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.source, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_tx_dropped(&mut self, v: u64) {
+        self.tx_dropped = v;
     }
 
-    // uint32 scope = 5;
+    // uint64 multicast = 9;
 
 
-    pub fn get_scope(&self) -> u32 {
-        self.scope
+    pub fn get_multicast(&self) -> u64 {
+        self.multicast
     }
-    pub fn clear_scope(&mut self) {
-        self.scope = 0;
+    pub fn clear_multicast(&mut self) {
+        self.multicast = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_scope(&mut self, v: u32) {
-        self.scope = v;
+    pub fn set_multicast(&mut self, v: u64) {
+        self.multicast = v;
+    }
+
+    // uint64 collisions = 10;
+
+
+    pub fn get_collisions(&self) -> u64 {
+        self.collisions
+    }
+    pub fn clear_collisions(&mut self) {
+        self.collisions = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_collisions(&mut self, v: u64) {
+        self.collisions = v;
     }
 }
 
-impl ::protobuf::Message for Route {
+impl ::protobuf::Message for InterfaceStatistics {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -873,23 +1232,2620 @@ impl ::protobuf::Message for Route {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dest)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rx_packets = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gateway)?;
-                },
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.tx_packets = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rx_bytes = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.tx_bytes = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rx_errors = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.tx_errors = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rx_dropped = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.tx_dropped = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.multicast = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.collisions = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rx_packets != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rx_packets, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.tx_packets != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.tx_packets, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rx_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.rx_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.tx_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.tx_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rx_errors != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rx_errors, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.tx_errors != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.tx_errors, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rx_dropped != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.rx_dropped, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.tx_dropped != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.tx_dropped, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.multicast != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.multicast, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.collisions != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.collisions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.rx_packets != 0 {
+            os.write_uint64(1, self.rx_packets)?;
+        }
+        if self.tx_packets != 0 {
+            os.write_uint64(2, self.tx_packets)?;
+        }
+        if self.rx_bytes != 0 {
+            os.write_uint64(3, self.rx_bytes)?;
+        }
+        if self.tx_bytes != 0 {
+            os.write_uint64(4, self.tx_bytes)?;
+        }
+        if self.rx_errors != 0 {
+            os.write_uint64(5, self.rx_errors)?;
+        }
+        if self.tx_errors != 0 {
+            os.write_uint64(6, self.tx_errors)?;
+        }
+        if self.rx_dropped != 0 {
+            os.write_uint64(7, self.rx_dropped)?;
+        }
+        if self.tx_dropped != 0 {
+            os.write_uint64(8, self.tx_dropped)?;
+        }
+        if self.multicast != 0 {
+            os.write_uint64(9, self.multicast)?;
+        }
+        if self.collisions != 0 {
+            os.write_uint64(10, self.collisions)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> InterfaceStatistics {
+        InterfaceStatistics::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rx_packets",
+                    |m: &InterfaceStatistics| { &m.rx_packets },
+                    |m: &mut InterfaceStatistics| { &mut m.rx_packets },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "tx_packets",
+                    |m: &InterfaceStatistics| { &m.tx_packets },
+                    |m: &mut InterfaceStatistics| { &mut m.tx_packets },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rx_bytes",
+                    |m: &InterfaceStatistics| { &m.rx_bytes },
+                    |m: &mut InterfaceStatistics| { &mut m.rx_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "tx_bytes",
+                    |m: &InterfaceStatistics| { &m.tx_bytes },
+                    |m: &mut InterfaceStatistics| { &mut m.tx_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rx_errors",
+                    |m: &InterfaceStatistics| { &m.rx_errors },
+                    |m: &mut InterfaceStatistics| { &mut m.rx_errors },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "tx_errors",
+                    |m: &InterfaceStatistics| { &m.tx_errors },
+                    |m: &mut InterfaceStatistics| { &mut m.tx_errors },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rx_dropped",
+                    |m: &InterfaceStatistics| { &m.rx_dropped },
+                    |m: &mut InterfaceStatistics| { &mut m.rx_dropped },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "tx_dropped",
+                    |m: &InterfaceStatistics| { &m.tx_dropped },
+                    |m: &mut InterfaceStatistics| { &mut m.tx_dropped },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "multicast",
+                    |m: &InterfaceStatistics| { &m.multicast },
+                    |m: &mut InterfaceStatistics| { &mut m.multicast },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "collisions",
+                    |m: &InterfaceStatistics| { &m.collisions },
+                    |m: &mut InterfaceStatistics| { &mut m.collisions },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<InterfaceStatistics>(
+                    "InterfaceStatistics",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static InterfaceStatistics {
+        static mut instance: ::protobuf::lazy::Lazy<InterfaceStatistics> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const InterfaceStatistics,
+        };
+        unsafe {
+            instance.get(InterfaceStatistics::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for InterfaceStatistics {
+    fn clear(&mut self) {
+        self.rx_packets = 0;
+        self.tx_packets = 0;
+        self.rx_bytes = 0;
+        self.tx_bytes = 0;
+        self.rx_errors = 0;
+        self.tx_errors = 0;
+        self.rx_dropped = 0;
+        self.tx_dropped = 0;
+        self.multicast = 0;
+        self.collisions = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InterfaceStatistics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InterfaceStatistics {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Route {
+    // message fields
+    pub dest: ::std::string::String,
+    pub gateway: ::std::string::String,
+    pub device: ::std::string::String,
+    pub source: ::std::string::String,
+    pub scope: u32,
+    pub metric: u32,
+    pub mtu: u32,
+    pub onlink: bool,
+    pub protocol: u32,
+    pub multipath: ::protobuf::RepeatedField<Nexthop>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Route {
+    fn default() -> &'a Route {
+        <Route as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Route {
+    pub fn new() -> Route {
+        ::std::default::Default::default()
+    }
+
+    // string dest = 1;
+
+
+    pub fn get_dest(&self) -> &str {
+        &self.dest
+    }
+    pub fn clear_dest(&mut self) {
+        self.dest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dest(&mut self, v: ::std::string::String) {
+        self.dest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dest(&mut self) -> &mut ::std::string::String {
+        &mut self.dest
+    }
+
+    // Take field
+    pub fn take_dest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.dest, ::std::string::String::new())
+    }
+
+    // string gateway = 2;
+
+
+    pub fn get_gateway(&self) -> &str {
+        &self.gateway
+    }
+    pub fn clear_gateway(&mut self) {
+        self.gateway.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gateway(&mut self, v: ::std::string::String) {
+        self.gateway = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gateway(&mut self) -> &mut ::std::string::String {
+        &mut self.gateway
+    }
+
+    // Take field
+    pub fn take_gateway(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gateway, ::std::string::String::new())
+    }
+
+    // string device = 3;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // string source = 4;
+
+
+    pub fn get_source(&self) -> &str {
+        &self.source
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: ::std::string::String) {
+        self.source = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut ::std::string::String {
+        &mut self.source
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source, ::std::string::String::new())
+    }
+
+    // uint32 scope = 5;
+
+
+    pub fn get_scope(&self) -> u32 {
+        self.scope
+    }
+    pub fn clear_scope(&mut self) {
+        self.scope = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scope(&mut self, v: u32) {
+        self.scope = v;
+    }
+
+    // uint32 metric = 6;
+
+
+    pub fn get_metric(&self) -> u32 {
+        self.metric
+    }
+    pub fn clear_metric(&mut self) {
+        self.metric = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metric(&mut self, v: u32) {
+        self.metric = v;
+    }
+
+    // uint32 mtu = 7;
+
+
+    pub fn get_mtu(&self) -> u32 {
+        self.mtu
+    }
+    pub fn clear_mtu(&mut self) {
+        self.mtu = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mtu(&mut self, v: u32) {
+        self.mtu = v;
+    }
+
+    // bool onlink = 8;
+
+
+    pub fn get_onlink(&self) -> bool {
+        self.onlink
+    }
+    pub fn clear_onlink(&mut self) {
+        self.onlink = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_onlink(&mut self, v: bool) {
+        self.onlink = v;
+    }
+
+    // uint32 protocol = 9;
+
+
+    pub fn get_protocol(&self) -> u32 {
+        self.protocol
+    }
+    pub fn clear_protocol(&mut self) {
+        self.protocol = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol(&mut self, v: u32) {
+        self.protocol = v;
+    }
+
+    // repeated .types.Nexthop multipath = 10;
+
+
+    pub fn get_multipath(&self) -> &[Nexthop] {
+        &self.multipath
+    }
+    pub fn clear_multipath(&mut self) {
+        self.multipath.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_multipath(&mut self, v: ::protobuf::RepeatedField<Nexthop>) {
+        self.multipath = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_multipath(&mut self) -> &mut ::protobuf::RepeatedField<Nexthop> {
+        &mut self.multipath
+    }
+
+    // Take field
+    pub fn take_multipath(&mut self) -> ::protobuf::RepeatedField<Nexthop> {
+        ::std::mem::replace(&mut self.multipath, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Route {
+    fn is_initialized(&self) -> bool {
+        for v in &self.multipath {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.dest)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gateway)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.scope = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.metric = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mtu = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.onlink = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.protocol = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.multipath)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.dest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.dest);
+        }
+        if !self.gateway.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.gateway);
+        }
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.device);
+        }
+        if !self.source.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.source);
+        }
+        if self.scope != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.scope, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.metric != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.metric, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mtu != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.mtu, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.onlink != false {
+            my_size += 2;
+        }
+        if self.protocol != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.protocol, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.multipath {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.dest.is_empty() {
+            os.write_string(1, &self.dest)?;
+        }
+        if !self.gateway.is_empty() {
+            os.write_string(2, &self.gateway)?;
+        }
+        if !self.device.is_empty() {
+            os.write_string(3, &self.device)?;
+        }
+        if !self.source.is_empty() {
+            os.write_string(4, &self.source)?;
+        }
+        if self.scope != 0 {
+            os.write_uint32(5, self.scope)?;
+        }
+        if self.metric != 0 {
+            os.write_uint32(6, self.metric)?;
+        }
+        if self.mtu != 0 {
+            os.write_uint32(7, self.mtu)?;
+        }
+        if self.onlink != false {
+            os.write_bool(8, self.onlink)?;
+        }
+        if self.protocol != 0 {
+            os.write_uint32(9, self.protocol)?;
+        }
+        for v in &self.multipath {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Route {
+        Route::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "dest",
+                    |m: &Route| { &m.dest },
+                    |m: &mut Route| { &mut m.dest },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "gateway",
+                    |m: &Route| { &m.gateway },
+                    |m: &mut Route| { &mut m.gateway },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &Route| { &m.device },
+                    |m: &mut Route| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "source",
+                    |m: &Route| { &m.source },
+                    |m: &mut Route| { &mut m.source },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "scope",
+                    |m: &Route| { &m.scope },
+                    |m: &mut Route| { &mut m.scope },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "metric",
+                    |m: &Route| { &m.metric },
+                    |m: &mut Route| { &mut m.metric },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "mtu",
+                    |m: &Route| { &m.mtu },
+                    |m: &mut Route| { &mut m.mtu },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "onlink",
+                    |m: &Route| { &m.onlink },
+                    |m: &mut Route| { &mut m.onlink },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol",
+                    |m: &Route| { &m.protocol },
+                    |m: &mut Route| { &mut m.protocol },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Nexthop>>(
+                    "multipath",
+                    |m: &Route| { &m.multipath },
+                    |m: &mut Route| { &mut m.multipath },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Route>(
+                    "Route",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Route {
+        static mut instance: ::protobuf::lazy::Lazy<Route> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Route,
+        };
+        unsafe {
+            instance.get(Route::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Route {
+    fn clear(&mut self) {
+        self.dest.clear();
+        self.gateway.clear();
+        self.device.clear();
+        self.source.clear();
+        self.scope = 0;
+        self.metric = 0;
+        self.mtu = 0;
+        self.onlink = false;
+        self.protocol = 0;
+        self.multipath.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Route {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Route {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Nexthop {
+    // message fields
+    pub gateway: ::std::string::String,
+    pub device: ::std::string::String,
+    pub weight: u32,
+    pub onlink: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Nexthop {
+    fn default() -> &'a Nexthop {
+        <Nexthop as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Nexthop {
+    pub fn new() -> Nexthop {
+        ::std::default::Default::default()
+    }
+
+    // string gateway = 1;
+
+
+    pub fn get_gateway(&self) -> &str {
+        &self.gateway
+    }
+    pub fn clear_gateway(&mut self) {
+        self.gateway.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gateway(&mut self, v: ::std::string::String) {
+        self.gateway = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gateway(&mut self) -> &mut ::std::string::String {
+        &mut self.gateway
+    }
+
+    // Take field
+    pub fn take_gateway(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gateway, ::std::string::String::new())
+    }
+
+    // string device = 2;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // uint32 weight = 3;
+
+
+    pub fn get_weight(&self) -> u32 {
+        self.weight
+    }
+    pub fn clear_weight(&mut self) {
+        self.weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_weight(&mut self, v: u32) {
+        self.weight = v;
+    }
+
+    // bool onlink = 4;
+
+
+    pub fn get_onlink(&self) -> bool {
+        self.onlink
+    }
+    pub fn clear_onlink(&mut self) {
+        self.onlink = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_onlink(&mut self, v: bool) {
+        self.onlink = v;
+    }
+}
+
+impl ::protobuf::Message for Nexthop {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gateway)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.weight = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.onlink = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.gateway.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.gateway);
+        }
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.device);
+        }
+        if self.weight != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.weight, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.onlink != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.gateway.is_empty() {
+            os.write_string(1, &self.gateway)?;
+        }
+        if !self.device.is_empty() {
+            os.write_string(2, &self.device)?;
+        }
+        if self.weight != 0 {
+            os.write_uint32(3, self.weight)?;
+        }
+        if self.onlink != false {
+            os.write_bool(4, self.onlink)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Nexthop {
+        Nexthop::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "gateway",
+                    |m: &Nexthop| { &m.gateway },
+                    |m: &mut Nexthop| { &mut m.gateway },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &Nexthop| { &m.device },
+                    |m: &mut Nexthop| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "weight",
+                    |m: &Nexthop| { &m.weight },
+                    |m: &mut Nexthop| { &mut m.weight },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "onlink",
+                    |m: &Nexthop| { &m.onlink },
+                    |m: &mut Nexthop| { &mut m.onlink },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Nexthop>(
+                    "Nexthop",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Nexthop {
+        static mut instance: ::protobuf::lazy::Lazy<Nexthop> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Nexthop,
+        };
+        unsafe {
+            instance.get(Nexthop::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Nexthop {
+    fn clear(&mut self) {
+        self.gateway.clear();
+        self.device.clear();
+        self.weight = 0;
+        self.onlink = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Nexthop {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Nexthop {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Qdisc {
+    // message fields
+    pub device: ::std::string::String,
+    pub parent: ::std::string::String,
+    pub handle: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub rate: u64,
+    pub burst: u32,
+    pub limit: u32,
+    pub default_class: u32,
+    pub flows: u32,
+    pub target: u32,
+    pub interval: u32,
+    pub ecn: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Qdisc {
+    fn default() -> &'a Qdisc {
+        <Qdisc as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Qdisc {
+    pub fn new() -> Qdisc {
+        ::std::default::Default::default()
+    }
+
+    // string device = 1;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // string parent = 2;
+
+
+    pub fn get_parent(&self) -> &str {
+        &self.parent
+    }
+    pub fn clear_parent(&mut self) {
+        self.parent.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent(&mut self, v: ::std::string::String) {
+        self.parent = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent(&mut self) -> &mut ::std::string::String {
+        &mut self.parent
+    }
+
+    // Take field
+    pub fn take_parent(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent, ::std::string::String::new())
+    }
+
+    // string handle = 3;
+
+
+    pub fn get_handle(&self) -> &str {
+        &self.handle
+    }
+    pub fn clear_handle(&mut self) {
+        self.handle.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_handle(&mut self, v: ::std::string::String) {
+        self.handle = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_handle(&mut self) -> &mut ::std::string::String {
+        &mut self.handle
+    }
+
+    // Take field
+    pub fn take_handle(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.handle, ::std::string::String::new())
+    }
+
+    // string kind = 4;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // uint64 rate = 5;
+
+
+    pub fn get_rate(&self) -> u64 {
+        self.rate
+    }
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u64) {
+        self.rate = v;
+    }
+
+    // uint32 burst = 6;
+
+
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+    pub fn clear_burst(&mut self) {
+        self.burst = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_burst(&mut self, v: u32) {
+        self.burst = v;
+    }
+
+    // uint32 limit = 7;
+
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u32) {
+        self.limit = v;
+    }
+
+    // uint32 default_class = 8;
+
+
+    pub fn get_default_class(&self) -> u32 {
+        self.default_class
+    }
+    pub fn clear_default_class(&mut self) {
+        self.default_class = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_class(&mut self, v: u32) {
+        self.default_class = v;
+    }
+
+    // uint32 flows = 9;
+
+
+    pub fn get_flows(&self) -> u32 {
+        self.flows
+    }
+    pub fn clear_flows(&mut self) {
+        self.flows = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_flows(&mut self, v: u32) {
+        self.flows = v;
+    }
+
+    // uint32 target = 10;
+
+
+    pub fn get_target(&self) -> u32 {
+        self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: u32) {
+        self.target = v;
+    }
+
+    // uint32 interval = 11;
+
+
+    pub fn get_interval(&self) -> u32 {
+        self.interval
+    }
+    pub fn clear_interval(&mut self) {
+        self.interval = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_interval(&mut self, v: u32) {
+        self.interval = v;
+    }
+
+    // bool ecn = 12;
+
+
+    pub fn get_ecn(&self) -> bool {
+        self.ecn
+    }
+    pub fn clear_ecn(&mut self) {
+        self.ecn = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ecn(&mut self, v: bool) {
+        self.ecn = v;
+    }
+}
+
+impl ::protobuf::Message for Qdisc {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.handle)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rate = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.burst = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.limit = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.default_class = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.flows = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.interval = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ecn = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
+        }
+        if !self.parent.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.parent);
+        }
+        if !self.handle.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.handle);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.kind);
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.burst != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.burst, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.default_class != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.default_class, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.flows != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.flows, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.target != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.target, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.interval != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.interval, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ecn != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
+        }
+        if !self.parent.is_empty() {
+            os.write_string(2, &self.parent)?;
+        }
+        if !self.handle.is_empty() {
+            os.write_string(3, &self.handle)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(4, &self.kind)?;
+        }
+        if self.rate != 0 {
+            os.write_uint64(5, self.rate)?;
+        }
+        if self.burst != 0 {
+            os.write_uint32(6, self.burst)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(7, self.limit)?;
+        }
+        if self.default_class != 0 {
+            os.write_uint32(8, self.default_class)?;
+        }
+        if self.flows != 0 {
+            os.write_uint32(9, self.flows)?;
+        }
+        if self.target != 0 {
+            os.write_uint32(10, self.target)?;
+        }
+        if self.interval != 0 {
+            os.write_uint32(11, self.interval)?;
+        }
+        if self.ecn != false {
+            os.write_bool(12, self.ecn)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Qdisc {
+        Qdisc::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &Qdisc| { &m.device },
+                    |m: &mut Qdisc| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "parent",
+                    |m: &Qdisc| { &m.parent },
+                    |m: &mut Qdisc| { &mut m.parent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "handle",
+                    |m: &Qdisc| { &m.handle },
+                    |m: &mut Qdisc| { &mut m.handle },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "kind",
+                    |m: &Qdisc| { &m.kind },
+                    |m: &mut Qdisc| { &mut m.kind },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rate",
+                    |m: &Qdisc| { &m.rate },
+                    |m: &mut Qdisc| { &mut m.rate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "burst",
+                    |m: &Qdisc| { &m.burst },
+                    |m: &mut Qdisc| { &mut m.burst },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "limit",
+                    |m: &Qdisc| { &m.limit },
+                    |m: &mut Qdisc| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "default_class",
+                    |m: &Qdisc| { &m.default_class },
+                    |m: &mut Qdisc| { &mut m.default_class },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "flows",
+                    |m: &Qdisc| { &m.flows },
+                    |m: &mut Qdisc| { &mut m.flows },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "target",
+                    |m: &Qdisc| { &m.target },
+                    |m: &mut Qdisc| { &mut m.target },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "interval",
+                    |m: &Qdisc| { &m.interval },
+                    |m: &mut Qdisc| { &mut m.interval },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ecn",
+                    |m: &Qdisc| { &m.ecn },
+                    |m: &mut Qdisc| { &mut m.ecn },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Qdisc>(
+                    "Qdisc",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Qdisc {
+        static mut instance: ::protobuf::lazy::Lazy<Qdisc> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Qdisc,
+        };
+        unsafe {
+            instance.get(Qdisc::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Qdisc {
+    fn clear(&mut self) {
+        self.device.clear();
+        self.parent.clear();
+        self.handle.clear();
+        self.kind.clear();
+        self.rate = 0;
+        self.burst = 0;
+        self.limit = 0;
+        self.default_class = 0;
+        self.flows = 0;
+        self.target = 0;
+        self.interval = 0;
+        self.ecn = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Qdisc {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Qdisc {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TrafficClass {
+    // message fields
+    pub device: ::std::string::String,
+    pub parent: ::std::string::String,
+    pub classid: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub rate: u64,
+    pub ceil: u64,
+    pub burst: u32,
+    pub cburst: u32,
+    pub prio: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TrafficClass {
+    fn default() -> &'a TrafficClass {
+        <TrafficClass as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TrafficClass {
+    pub fn new() -> TrafficClass {
+        ::std::default::Default::default()
+    }
+
+    // string device = 1;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // string parent = 2;
+
+
+    pub fn get_parent(&self) -> &str {
+        &self.parent
+    }
+    pub fn clear_parent(&mut self) {
+        self.parent.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent(&mut self, v: ::std::string::String) {
+        self.parent = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent(&mut self) -> &mut ::std::string::String {
+        &mut self.parent
+    }
+
+    // Take field
+    pub fn take_parent(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent, ::std::string::String::new())
+    }
+
+    // string classid = 3;
+
+
+    pub fn get_classid(&self) -> &str {
+        &self.classid
+    }
+    pub fn clear_classid(&mut self) {
+        self.classid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_classid(&mut self, v: ::std::string::String) {
+        self.classid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_classid(&mut self) -> &mut ::std::string::String {
+        &mut self.classid
+    }
+
+    // Take field
+    pub fn take_classid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.classid, ::std::string::String::new())
+    }
+
+    // string kind = 4;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // uint64 rate = 5;
+
+
+    pub fn get_rate(&self) -> u64 {
+        self.rate
+    }
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u64) {
+        self.rate = v;
+    }
+
+    // uint64 ceil = 6;
+
+
+    pub fn get_ceil(&self) -> u64 {
+        self.ceil
+    }
+    pub fn clear_ceil(&mut self) {
+        self.ceil = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ceil(&mut self, v: u64) {
+        self.ceil = v;
+    }
+
+    // uint32 burst = 7;
+
+
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+    pub fn clear_burst(&mut self) {
+        self.burst = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_burst(&mut self, v: u32) {
+        self.burst = v;
+    }
+
+    // uint32 cburst = 8;
+
+
+    pub fn get_cburst(&self) -> u32 {
+        self.cburst
+    }
+    pub fn clear_cburst(&mut self) {
+        self.cburst = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cburst(&mut self, v: u32) {
+        self.cburst = v;
+    }
+
+    // uint32 prio = 9;
+
+
+    pub fn get_prio(&self) -> u32 {
+        self.prio
+    }
+    pub fn clear_prio(&mut self) {
+        self.prio = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prio(&mut self, v: u32) {
+        self.prio = v;
+    }
+}
+
+impl ::protobuf::Message for TrafficClass {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.classid)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.rate = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ceil = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.burst = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cburst = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.prio = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
+        }
+        if !self.parent.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.parent);
+        }
+        if !self.classid.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.classid);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.kind);
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ceil != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.ceil, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.burst != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.burst, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cburst != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.cburst, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.prio != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.prio, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
+        }
+        if !self.parent.is_empty() {
+            os.write_string(2, &self.parent)?;
+        }
+        if !self.classid.is_empty() {
+            os.write_string(3, &self.classid)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(4, &self.kind)?;
+        }
+        if self.rate != 0 {
+            os.write_uint64(5, self.rate)?;
+        }
+        if self.ceil != 0 {
+            os.write_uint64(6, self.ceil)?;
+        }
+        if self.burst != 0 {
+            os.write_uint32(7, self.burst)?;
+        }
+        if self.cburst != 0 {
+            os.write_uint32(8, self.cburst)?;
+        }
+        if self.prio != 0 {
+            os.write_uint32(9, self.prio)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TrafficClass {
+        TrafficClass::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &TrafficClass| { &m.device },
+                    |m: &mut TrafficClass| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "parent",
+                    |m: &TrafficClass| { &m.parent },
+                    |m: &mut TrafficClass| { &mut m.parent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "classid",
+                    |m: &TrafficClass| { &m.classid },
+                    |m: &mut TrafficClass| { &mut m.classid },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "kind",
+                    |m: &TrafficClass| { &m.kind },
+                    |m: &mut TrafficClass| { &mut m.kind },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "rate",
+                    |m: &TrafficClass| { &m.rate },
+                    |m: &mut TrafficClass| { &mut m.rate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ceil",
+                    |m: &TrafficClass| { &m.ceil },
+                    |m: &mut TrafficClass| { &mut m.ceil },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "burst",
+                    |m: &TrafficClass| { &m.burst },
+                    |m: &mut TrafficClass| { &mut m.burst },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "cburst",
+                    |m: &TrafficClass| { &m.cburst },
+                    |m: &mut TrafficClass| { &mut m.cburst },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "prio",
+                    |m: &TrafficClass| { &m.prio },
+                    |m: &mut TrafficClass| { &mut m.prio },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TrafficClass>(
+                    "TrafficClass",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TrafficClass {
+        static mut instance: ::protobuf::lazy::Lazy<TrafficClass> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TrafficClass,
+        };
+        unsafe {
+            instance.get(TrafficClass::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TrafficClass {
+    fn clear(&mut self) {
+        self.device.clear();
+        self.parent.clear();
+        self.classid.clear();
+        self.kind.clear();
+        self.rate = 0;
+        self.ceil = 0;
+        self.burst = 0;
+        self.cburst = 0;
+        self.prio = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TrafficClass {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TrafficClass {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MirredAction {
+    // message fields
+    pub device: ::std::string::String,
+    pub mirror: bool,
+    pub ingress: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MirredAction {
+    fn default() -> &'a MirredAction {
+        <MirredAction as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MirredAction {
+    pub fn new() -> MirredAction {
+        ::std::default::Default::default()
+    }
+
+    // string device = 1;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // bool mirror = 2;
+
+
+    pub fn get_mirror(&self) -> bool {
+        self.mirror
+    }
+    pub fn clear_mirror(&mut self) {
+        self.mirror = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mirror(&mut self, v: bool) {
+        self.mirror = v;
+    }
+
+    // bool ingress = 3;
+
+
+    pub fn get_ingress(&self) -> bool {
+        self.ingress
+    }
+    pub fn clear_ingress(&mut self) {
+        self.ingress = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ingress(&mut self, v: bool) {
+        self.ingress = v;
+    }
+}
+
+impl ::protobuf::Message for MirredAction {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.mirror = tmp;
+                },
                 3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ingress = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
+        }
+        if self.mirror != false {
+            my_size += 2;
+        }
+        if self.ingress != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
+        }
+        if self.mirror != false {
+            os.write_bool(2, self.mirror)?;
+        }
+        if self.ingress != false {
+            os.write_bool(3, self.ingress)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MirredAction {
+        MirredAction::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "device",
+                    |m: &MirredAction| { &m.device },
+                    |m: &mut MirredAction| { &mut m.device },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "mirror",
+                    |m: &MirredAction| { &m.mirror },
+                    |m: &mut MirredAction| { &mut m.mirror },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "ingress",
+                    |m: &MirredAction| { &m.ingress },
+                    |m: &mut MirredAction| { &mut m.ingress },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MirredAction>(
+                    "MirredAction",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MirredAction {
+        static mut instance: ::protobuf::lazy::Lazy<MirredAction> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MirredAction,
+        };
+        unsafe {
+            instance.get(MirredAction::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MirredAction {
+    fn clear(&mut self) {
+        self.device.clear();
+        self.mirror = false;
+        self.ingress = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MirredAction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MirredAction {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TrafficFilter {
+    // message fields
+    pub device: ::std::string::String,
+    pub parent: ::std::string::String,
+    pub priority: u32,
+    pub protocol: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub classid: ::std::string::String,
+    pub mirred: ::protobuf::SingularPtrField<MirredAction>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TrafficFilter {
+    fn default() -> &'a TrafficFilter {
+        <TrafficFilter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TrafficFilter {
+    pub fn new() -> TrafficFilter {
+        ::std::default::Default::default()
+    }
+
+    // string device = 1;
+
+
+    pub fn get_device(&self) -> &str {
+        &self.device
+    }
+    pub fn clear_device(&mut self) {
+        self.device.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_device(&mut self, v: ::std::string::String) {
+        self.device = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_device(&mut self) -> &mut ::std::string::String {
+        &mut self.device
+    }
+
+    // Take field
+    pub fn take_device(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.device, ::std::string::String::new())
+    }
+
+    // string parent = 2;
+
+
+    pub fn get_parent(&self) -> &str {
+        &self.parent
+    }
+    pub fn clear_parent(&mut self) {
+        self.parent.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent(&mut self, v: ::std::string::String) {
+        self.parent = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent(&mut self) -> &mut ::std::string::String {
+        &mut self.parent
+    }
+
+    // Take field
+    pub fn take_parent(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent, ::std::string::String::new())
+    }
+
+    // uint32 priority = 3;
+
+
+    pub fn get_priority(&self) -> u32 {
+        self.priority
+    }
+    pub fn clear_priority(&mut self) {
+        self.priority = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: u32) {
+        self.priority = v;
+    }
+
+    // string protocol = 4;
+
+
+    pub fn get_protocol(&self) -> &str {
+        &self.protocol
+    }
+    pub fn clear_protocol(&mut self) {
+        self.protocol.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol(&mut self, v: ::std::string::String) {
+        self.protocol = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_protocol(&mut self) -> &mut ::std::string::String {
+        &mut self.protocol
+    }
+
+    // Take field
+    pub fn take_protocol(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.protocol, ::std::string::String::new())
+    }
+
+    // string kind = 5;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string classid = 6;
+
+
+    pub fn get_classid(&self) -> &str {
+        &self.classid
+    }
+    pub fn clear_classid(&mut self) {
+        self.classid.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_classid(&mut self, v: ::std::string::String) {
+        self.classid = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_classid(&mut self) -> &mut ::std::string::String {
+        &mut self.classid
+    }
+
+    // Take field
+    pub fn take_classid(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.classid, ::std::string::String::new())
+    }
+
+    // .types.MirredAction mirred = 7;
+
+
+    pub fn get_mirred(&self) -> &MirredAction {
+        self.mirred.as_ref().unwrap_or_else(|| MirredAction::default_instance())
+    }
+    pub fn clear_mirred(&mut self) {
+        self.mirred.clear();
+    }
+
+    pub fn has_mirred(&self) -> bool {
+        self.mirred.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mirred(&mut self, v: MirredAction) {
+        self.mirred = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mirred(&mut self) -> &mut MirredAction {
+        if self.mirred.is_none() {
+            self.mirred.set_default();
+        }
+        self.mirred.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_mirred(&mut self) -> MirredAction {
+        self.mirred.take().unwrap_or_else(|| MirredAction::new())
+    }
+}
+
+impl ::protobuf::Message for TrafficFilter {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mirred {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.device)?;
                 },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source)?;
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent)?;
                 },
-                5 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.scope = tmp;
+                    self.priority = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.protocol)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.classid)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.mirred)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -903,20 +3859,27 @@ impl ::protobuf::Message for Route {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.dest.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.dest);
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.device);
         }
-        if !self.gateway.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.gateway);
+        if !self.parent.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.parent);
         }
-        if !self.device.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.device);
+        if self.priority != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.priority, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.source.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.source);
+        if !self.protocol.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.protocol);
         }
-        if self.scope != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.scope, ::protobuf::wire_format::WireTypeVarint);
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.kind);
+        }
+        if !self.classid.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.classid);
+        }
+        if let Some(ref v) = self.mirred.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -924,20 +3887,28 @@ impl ::protobuf::Message for Route {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.dest.is_empty() {
-            os.write_string(1, &self.dest)?;
+        if !self.device.is_empty() {
+            os.write_string(1, &self.device)?;
         }
-        if !self.gateway.is_empty() {
-            os.write_string(2, &self.gateway)?;
+        if !self.parent.is_empty() {
+            os.write_string(2, &self.parent)?;
         }
-        if !self.device.is_empty() {
-            os.write_string(3, &self.device)?;
+        if self.priority != 0 {
+            os.write_uint32(3, self.priority)?;
         }
-        if !self.source.is_empty() {
-            os.write_string(4, &self.source)?;
+        if !self.protocol.is_empty() {
+            os.write_string(4, &self.protocol)?;
         }
-        if self.scope != 0 {
-            os.write_uint32(5, self.scope)?;
+        if !self.kind.is_empty() {
+            os.write_string(5, &self.kind)?;
+        }
+        if !self.classid.is_empty() {
+            os.write_string(6, &self.classid)?;
+        }
+        if let Some(ref v) = self.mirred.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -969,8 +3940,8 @@ impl ::protobuf::Message for Route {
         Self::descriptor_static()
     }
 
-    fn new() -> Route {
-        Route::new()
+    fn new() -> TrafficFilter {
+        TrafficFilter::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -982,32 +3953,42 @@ impl ::protobuf::Message for Route {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "dest",
-                    |m: &Route| { &m.dest },
-                    |m: &mut Route| { &mut m.dest },
+                    "device",
+                    |m: &TrafficFilter| { &m.device },
+                    |m: &mut TrafficFilter| { &mut m.device },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "gateway",
-                    |m: &Route| { &m.gateway },
-                    |m: &mut Route| { &mut m.gateway },
+                    "parent",
+                    |m: &TrafficFilter| { &m.parent },
+                    |m: &mut TrafficFilter| { &mut m.parent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "priority",
+                    |m: &TrafficFilter| { &m.priority },
+                    |m: &mut TrafficFilter| { &mut m.priority },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "device",
-                    |m: &Route| { &m.device },
-                    |m: &mut Route| { &mut m.device },
+                    "protocol",
+                    |m: &TrafficFilter| { &m.protocol },
+                    |m: &mut TrafficFilter| { &mut m.protocol },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "source",
-                    |m: &Route| { &m.source },
-                    |m: &mut Route| { &mut m.source },
+                    "kind",
+                    |m: &TrafficFilter| { &m.kind },
+                    |m: &mut TrafficFilter| { &mut m.kind },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "scope",
-                    |m: &Route| { &m.scope },
-                    |m: &mut Route| { &mut m.scope },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "classid",
+                    |m: &TrafficFilter| { &m.classid },
+                    |m: &mut TrafficFilter| { &mut m.classid },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Route>(
-                    "Route",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MirredAction>>(
+                    "mirred",
+                    |m: &TrafficFilter| { &m.mirred },
+                    |m: &mut TrafficFilter| { &mut m.mirred },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TrafficFilter>(
+                    "TrafficFilter",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1015,35 +3996,37 @@ impl ::protobuf::Message for Route {
         }
     }
 
-    fn default_instance() -> &'static Route {
-        static mut instance: ::protobuf::lazy::Lazy<Route> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static TrafficFilter {
+        static mut instance: ::protobuf::lazy::Lazy<TrafficFilter> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Route,
+            ptr: 0 as *const TrafficFilter,
         };
         unsafe {
-            instance.get(Route::new)
+            instance.get(TrafficFilter::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Route {
+impl ::protobuf::Clear for TrafficFilter {
     fn clear(&mut self) {
-        self.dest.clear();
-        self.gateway.clear();
         self.device.clear();
-        self.source.clear();
-        self.scope = 0;
+        self.parent.clear();
+        self.priority = 0;
+        self.protocol.clear();
+        self.kind.clear();
+        self.classid.clear();
+        self.mirred.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Route {
+impl ::std::fmt::Debug for TrafficFilter {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Route {
+impl ::protobuf::reflect::ProtobufValue for TrafficFilter {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -1108,95 +4091,409 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n6github.com/kata-containers/agent/pkg/types/types.proto\x12\x05types\"\
     b\n\tIPAddress\x12'\n\x06family\x18\x01\x20\x01(\x0e2\x0f.types.IPFamily\
     R\x06family\x12\x18\n\x07address\x18\x02\x20\x01(\tR\x07address\x12\x12\
-    \n\x04mask\x18\x03\x20\x01(\tR\x04mask\"\xe0\x01\n\tInterface\x12\x16\n\
+    \n\x04mask\x18\x03\x20\x01(\tR\x04mask\"\xd6\x03\n\tInterface\x12\x16\n\
     \x06device\x18\x01\x20\x01(\tR\x06device\x12\x12\n\x04name\x18\x02\x20\
     \x01(\tR\x04name\x122\n\x0bIPAddresses\x18\x03\x20\x03(\x0b2\x10.types.I\
     PAddressR\x0bIPAddresses\x12\x10\n\x03mtu\x18\x04\x20\x01(\x04R\x03mtu\
     \x12\x16\n\x06hwAddr\x18\x05\x20\x01(\tR\x06hwAddr\x12\x18\n\x07pciAddr\
     \x18\x06\x20\x01(\tR\x07pciAddr\x12\x12\n\x04type\x18\x07\x20\x01(\tR\
-    \x04type\x12\x1b\n\traw_flags\x18\x08\x20\x01(\rR\x08rawFlags\"{\n\x05Ro\
-    ute\x12\x12\n\x04dest\x18\x01\x20\x01(\tR\x04dest\x12\x18\n\x07gateway\
-    \x18\x02\x20\x01(\tR\x07gateway\x12\x16\n\x06device\x18\x03\x20\x01(\tR\
-    \x06device\x12\x16\n\x06source\x18\x04\x20\x01(\tR\x06source\x12\x14\n\
-    \x05scope\x18\x05\x20\x01(\rR\x05scope*\x1a\n\x08IPFamily\x12\x06\n\x02v\
-    4\x10\0\x12\x06\n\x02v6\x10\x01J\xea\x0e\n\x06\x12\x04\x06\0/\x01\nU\n\
-    \x01\x0c\x12\x03\x06\0\x122K\n\x20Copyright\x202018\x20Intel\x20Corporat\
-    ion.\n\n\x20SPDX-License-Identifier:\x20Apache-2.0\n\n\n\x08\n\x01\x02\
-    \x12\x03\x08\0\x0e\n\n\n\x02\x05\0\x12\x04\n\0\r\x01\n\n\n\x03\x05\0\x01\
-    \x12\x03\n\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x0b\x08\x0f\n\x0c\n\x05\
-    \x05\0\x02\0\x01\x12\x03\x0b\x08\n\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
-    \x0b\r\x0e\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x0c\x08\x0f\n\x0c\n\x05\x05\
-    \0\x02\x01\x01\x12\x03\x0c\x08\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x0c\r\x0e\n\n\n\x02\x04\0\x12\x04\x0f\0\x13\x01\n\n\n\x03\x04\0\x01\x12\
-    \x03\x0f\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x10\x08\x1c\n\r\n\x05\
-    \x04\0\x02\0\x04\x12\x04\x10\x08\x0f\x13\n\x0c\n\x05\x04\0\x02\0\x06\x12\
-    \x03\x10\x08\x10\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x10\x11\x17\n\x0c\n\
-    \x05\x04\0\x02\0\x03\x12\x03\x10\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\
-    \x03\x11\x08\x1b\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x11\x08\x10\x1c\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x11\x08\x0e\n\x0c\n\x05\x04\0\x02\
-    \x01\x01\x12\x03\x11\x0f\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x11\
-    \x19\x1a\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x12\x08\x18\n\r\n\x05\x04\0\
-    \x02\x02\x04\x12\x04\x12\x08\x11\x1b\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
-    \x03\x12\x08\x0e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x12\x0f\x13\n\x0c\
-    \n\x05\x04\0\x02\x02\x03\x12\x03\x12\x16\x17\n\n\n\x02\x04\x01\x12\x04\
-    \x15\0'\x01\n\n\n\x03\x04\x01\x01\x12\x03\x15\x08\x11\n\x0b\n\x04\x04\
-    \x01\x02\0\x12\x03\x16\x08\x1a\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\x16\
-    \x08\x15\x13\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x16\x08\x0e\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\x16\x0f\x15\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\x16\x18\x19\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x17\x08\x18\n\r\
-    \n\x05\x04\x01\x02\x01\x04\x12\x04\x17\x08\x16\x1a\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\x17\x08\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
-    \x17\x0f\x13\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x17\x16\x17\n\x0b\n\
-    \x04\x04\x01\x02\x02\x12\x03\x18\x08+\n\x0c\n\x05\x04\x01\x02\x02\x04\
-    \x12\x03\x18\x08\x10\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03\x18\x11\x1a\
-    \n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x18\x1b&\n\x0c\n\x05\x04\x01\
-    \x02\x02\x03\x12\x03\x18)*\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x19\x08\
-    \x17\n\r\n\x05\x04\x01\x02\x03\x04\x12\x04\x19\x08\x18+\n\x0c\n\x05\x04\
-    \x01\x02\x03\x05\x12\x03\x19\x08\x0e\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
-    \x03\x19\x0f\x12\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x19\x15\x16\n\
-    \x0b\n\x04\x04\x01\x02\x04\x12\x03\x1a\x08\x1a\n\r\n\x05\x04\x01\x02\x04\
-    \x04\x12\x04\x1a\x08\x19\x17\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\x1a\
-    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x1a\x0f\x15\n\x0c\n\x05\
-    \x04\x01\x02\x04\x03\x12\x03\x1a\x18\x19\n\xfc\x01\n\x04\x04\x01\x02\x05\
-    \x12\x03\x1f\x08\x1b\x1a\xee\x01\x20pciAddr\x20is\x20the\x20PCI\x20addre\
+    \x04type\x12\x1b\n\traw_flags\x18\x08\x20\x01(\rR\x08rawFlags\x12\x14\n\
+    \x05index\x18\t\x20\x01(\x05R\x05index\x12\x1d\n\noper_state\x18\n\x20\
+    \x01(\tR\toperState\x12\x18\n\x07carrier\x18\x0b\x20\x01(\x08R\x07carrie\
+    r\x12\x16\n\x06txqlen\x18\x0c\x20\x01(\rR\x06txqlen\x12!\n\x0cmaster_ind\
+    ex\x18\r\x20\x01(\x05R\x0bmasterIndex\x12\x16\n\x06master\x18\x0e\x20\
+    \x01(\tR\x06master\x12\x16\n\x06driver\x18\x0f\x20\x01(\tR\x06driver\x12\
+    :\n\nstatistics\x18\x10\x20\x01(\x0b2\x1a.types.InterfaceStatisticsR\nst\
+    atistics\"\xbf\x02\n\x13InterfaceStatistics\x12\x1d\n\nrx_packets\x18\
+    \x01\x20\x01(\x04R\trxPackets\x12\x1d\n\ntx_packets\x18\x02\x20\x01(\x04\
+    R\ttxPackets\x12\x19\n\x08rx_bytes\x18\x03\x20\x01(\x04R\x07rxBytes\x12\
+    \x19\n\x08tx_bytes\x18\x04\x20\x01(\x04R\x07txBytes\x12\x1b\n\trx_errors\
+    \x18\x05\x20\x01(\x04R\x08rxErrors\x12\x1b\n\ttx_errors\x18\x06\x20\x01(\
+    \x04R\x08txErrors\x12\x1d\n\nrx_dropped\x18\x07\x20\x01(\x04R\trxDropped\
+    \x12\x1d\n\ntx_dropped\x18\x08\x20\x01(\x04R\ttxDropped\x12\x1c\n\tmulti\
+    cast\x18\t\x20\x01(\x04R\tmulticast\x12\x1e\n\ncollisions\x18\n\x20\x01(\
+    \x04R\ncollisions\"\x87\x02\n\x05Route\x12\x12\n\x04dest\x18\x01\x20\x01\
+    (\tR\x04dest\x12\x18\n\x07gateway\x18\x02\x20\x01(\tR\x07gateway\x12\x16\
+    \n\x06device\x18\x03\x20\x01(\tR\x06device\x12\x16\n\x06source\x18\x04\
+    \x20\x01(\tR\x06source\x12\x14\n\x05scope\x18\x05\x20\x01(\rR\x05scope\
+    \x12\x16\n\x06metric\x18\x06\x20\x01(\rR\x06metric\x12\x10\n\x03mtu\x18\
+    \x07\x20\x01(\rR\x03mtu\x12\x16\n\x06onlink\x18\x08\x20\x01(\x08R\x06onl\
+    ink\x12\x1a\n\x08protocol\x18\t\x20\x01(\rR\x08protocol\x12,\n\tmultipat\
+    h\x18\n\x20\x03(\x0b2\x0e.types.NexthopR\tmultipath\"k\n\x07Nexthop\x12\
+    \x18\n\x07gateway\x18\x01\x20\x01(\tR\x07gateway\x12\x16\n\x06device\x18\
+    \x02\x20\x01(\tR\x06device\x12\x16\n\x06weight\x18\x03\x20\x01(\rR\x06we\
+    ight\x12\x16\n\x06onlink\x18\x04\x20\x01(\x08R\x06onlink\"\xa4\x02\n\x05\
+    Qdisc\x12\x16\n\x06device\x18\x01\x20\x01(\tR\x06device\x12\x16\n\x06par\
+    ent\x18\x02\x20\x01(\tR\x06parent\x12\x16\n\x06handle\x18\x03\x20\x01(\t\
+    R\x06handle\x12\x12\n\x04kind\x18\x04\x20\x01(\tR\x04kind\x12\x12\n\x04r\
+    ate\x18\x05\x20\x01(\x04R\x04rate\x12\x14\n\x05burst\x18\x06\x20\x01(\rR\
+    \x05burst\x12\x14\n\x05limit\x18\x07\x20\x01(\rR\x05limit\x12#\n\rdefaul\
+    t_class\x18\x08\x20\x01(\rR\x0cdefaultClass\x12\x14\n\x05flows\x18\t\x20\
+    \x01(\rR\x05flows\x12\x16\n\x06target\x18\n\x20\x01(\rR\x06target\x12\
+    \x1a\n\x08interval\x18\x0b\x20\x01(\rR\x08interval\x12\x10\n\x03ecn\x18\
+    \x0c\x20\x01(\x08R\x03ecn\"\xd6\x01\n\x0cTrafficClass\x12\x16\n\x06devic\
+    e\x18\x01\x20\x01(\tR\x06device\x12\x16\n\x06parent\x18\x02\x20\x01(\tR\
+    \x06parent\x12\x18\n\x07classid\x18\x03\x20\x01(\tR\x07classid\x12\x12\n\
+    \x04kind\x18\x04\x20\x01(\tR\x04kind\x12\x12\n\x04rate\x18\x05\x20\x01(\
+    \x04R\x04rate\x12\x12\n\x04ceil\x18\x06\x20\x01(\x04R\x04ceil\x12\x14\n\
+    \x05burst\x18\x07\x20\x01(\rR\x05burst\x12\x16\n\x06cburst\x18\x08\x20\
+    \x01(\rR\x06cburst\x12\x12\n\x04prio\x18\t\x20\x01(\rR\x04prio\"X\n\x0cM\
+    irredAction\x12\x16\n\x06device\x18\x01\x20\x01(\tR\x06device\x12\x16\n\
+    \x06mirror\x18\x02\x20\x01(\x08R\x06mirror\x12\x18\n\x07ingress\x18\x03\
+    \x20\x01(\x08R\x07ingress\"\xd2\x01\n\rTrafficFilter\x12\x16\n\x06device\
+    \x18\x01\x20\x01(\tR\x06device\x12\x16\n\x06parent\x18\x02\x20\x01(\tR\
+    \x06parent\x12\x1a\n\x08priority\x18\x03\x20\x01(\rR\x08priority\x12\x1a\
+    \n\x08protocol\x18\x04\x20\x01(\tR\x08protocol\x12\x12\n\x04kind\x18\x05\
+    \x20\x01(\tR\x04kind\x12\x18\n\x07classid\x18\x06\x20\x01(\tR\x07classid\
+    \x12+\n\x06mirred\x18\x07\x20\x01(\x0b2\x13.types.MirredActionR\x06mirre\
+    d*\x1a\n\x08IPFamily\x12\x06\n\x02v4\x10\0\x12\x06\n\x02v6\x10\x01J\x9cD\
+    \n\x07\x12\x05\x07\0\xbc\x01\x01\nw\n\x01\x0c\x12\x03\x07\0\x122m\n\x20C\
+    opyright\x202018\x20Intel\x20Corporation.\n\x20Copyright\x20(c)\x202019\
+    \x20Ant\x20Financial\n\n\x20SPDX-License-Identifier:\x20Apache-2.0\n\n\n\
+    \x08\n\x01\x02\x12\x03\t\0\x0e\n\n\n\x02\x05\0\x12\x04\x0b\0\x0e\x01\n\n\
+    \n\x03\x05\0\x01\x12\x03\x0b\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x0c\
+    \x08\x0f\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0c\x08\n\n\x0c\n\x05\x05\0\
+    \x02\0\x02\x12\x03\x0c\r\x0e\n\x0b\n\x04\x05\0\x02\x01\x12\x03\r\x08\x0f\
+    \n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\r\x08\n\n\x0c\n\x05\x05\0\x02\x01\
+    \x02\x12\x03\r\r\x0e\n\n\n\x02\x04\0\x12\x04\x10\0\x14\x01\n\n\n\x03\x04\
+    \0\x01\x12\x03\x10\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x11\x08\x1c\n\
+    \r\n\x05\x04\0\x02\0\x04\x12\x04\x11\x08\x10\x13\n\x0c\n\x05\x04\0\x02\0\
+    \x06\x12\x03\x11\x08\x10\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x11\x11\x17\
+    \n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x11\x1a\x1b\n\x0b\n\x04\x04\0\x02\
+    \x01\x12\x03\x12\x08\x1b\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x12\x08\x11\
+    \x1c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x12\x08\x0e\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x03\x12\x0f\x16\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x12\x19\x1a\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x13\x08\x18\n\r\n\x05\x04\
+    \0\x02\x02\x04\x12\x04\x13\x08\x12\x1b\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
+    \x03\x13\x08\x0e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x13\x0f\x13\n\x0c\
+    \n\x05\x04\0\x02\x02\x03\x12\x03\x13\x16\x17\n\n\n\x02\x04\x01\x12\x04\
+    \x16\0=\x01\n\n\n\x03\x04\x01\x01\x12\x03\x16\x08\x11\n\x0b\n\x04\x04\
+    \x01\x02\0\x12\x03\x17\x08\x1a\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\x17\
+    \x08\x16\x13\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x17\x08\x0e\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x17\x0f\x15\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x17\x18\x19\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x18\x08\x18\n\r\
+    \n\x05\x04\x01\x02\x01\x04\x12\x04\x18\x08\x17\x1a\n\x0c\n\x05\x04\x01\
+    \x02\x01\x05\x12\x03\x18\x08\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
+    \x18\x0f\x13\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x18\x16\x17\n\x0b\n\
+    \x04\x04\x01\x02\x02\x12\x03\x19\x08+\n\x0c\n\x05\x04\x01\x02\x02\x04\
+    \x12\x03\x19\x08\x10\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03\x19\x11\x1a\
+    \n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x19\x1b&\n\x0c\n\x05\x04\x01\
+    \x02\x02\x03\x12\x03\x19)*\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x1a\x08\
+    \x17\n\r\n\x05\x04\x01\x02\x03\x04\x12\x04\x1a\x08\x19+\n\x0c\n\x05\x04\
+    \x01\x02\x03\x05\x12\x03\x1a\x08\x0e\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
+    \x03\x1a\x0f\x12\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x1a\x15\x16\n\
+    \x0b\n\x04\x04\x01\x02\x04\x12\x03\x1b\x08\x1a\n\r\n\x05\x04\x01\x02\x04\
+    \x04\x12\x04\x1b\x08\x1a\x17\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\x1b\
+    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x1b\x0f\x15\n\x0c\n\x05\
+    \x04\x01\x02\x04\x03\x12\x03\x1b\x18\x19\n\xfc\x01\n\x04\x04\x01\x02\x05\
+    \x12\x03\x20\x08\x1b\x1a\xee\x01\x20pciAddr\x20is\x20the\x20PCI\x20addre\
     ss\x20in\x20the\x20format\x20\x20\"bridgeAddr/deviceAddr\".\n\x20Here,\
     \x20bridgeAddr\x20is\x20the\x20address\x20at\x20which\x20the\x20bridge\
     \x20is\x20attached\x20on\x20the\x20root\x20bus,\n\x20while\x20deviceAddr\
     \x20is\x20the\x20address\x20at\x20which\x20the\x20network\x20device\x20i\
     s\x20attached\x20on\x20the\x20bridge.\n\n\r\n\x05\x04\x01\x02\x05\x04\
-    \x12\x04\x1f\x08\x1a\x1a\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x1f\x08\
-    \x0e\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\x1f\x0f\x16\n\x0c\n\x05\x04\
-    \x01\x02\x05\x03\x12\x03\x1f\x19\x1a\n\x88\x02\n\x04\x04\x01\x02\x06\x12\
-    \x03%\x08\x18\x1a\xfa\x01\x20Type\x20defines\x20the\x20type\x20of\x20int\
+    \x12\x04\x20\x08\x1b\x1a\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x20\x08\
+    \x0e\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\x20\x0f\x16\n\x0c\n\x05\x04\
+    \x01\x02\x05\x03\x12\x03\x20\x19\x1a\n\x88\x02\n\x04\x04\x01\x02\x06\x12\
+    \x03&\x08\x18\x1a\xfa\x01\x20Type\x20defines\x20the\x20type\x20of\x20int\
     erface\x20described\x20by\x20this\x20structure.\n\x20The\x20expected\x20\
     values\x20are\x20the\x20one\x20that\x20are\x20defined\x20by\x20the\x20ne\
     tlink\n\x20library,\x20regarding\x20each\x20type\x20of\x20link.\x20Here\
     \x20is\x20a\x20non\x20exhaustive\n\x20list:\x20\"veth\",\x20\"macvtap\",\
     \x20\"vlan\",\x20\"macvlan\",\x20\"tap\",\x20...\n\n\r\n\x05\x04\x01\x02\
-    \x06\x04\x12\x04%\x08\x1f\x1b\n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03%\
-    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x06\x01\x12\x03%\x0f\x13\n\x0c\n\x05\
-    \x04\x01\x02\x06\x03\x12\x03%\x16\x17\n\x0b\n\x04\x04\x01\x02\x07\x12\
-    \x03&\x08\x1d\n\r\n\x05\x04\x01\x02\x07\x04\x12\x04&\x08%\x18\n\x0c\n\
-    \x05\x04\x01\x02\x07\x05\x12\x03&\x08\x0e\n\x0c\n\x05\x04\x01\x02\x07\
-    \x01\x12\x03&\x0f\x18\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03&\x1b\x1c\n\
-    \n\n\x02\x04\x02\x12\x04)\0/\x01\n\n\n\x03\x04\x02\x01\x12\x03)\x08\r\n\
-    \x0b\n\x04\x04\x02\x02\0\x12\x03*\x08\x18\n\r\n\x05\x04\x02\x02\0\x04\
-    \x12\x04*\x08)\x0f\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03*\x08\x0e\n\x0c\
-    \n\x05\x04\x02\x02\0\x01\x12\x03*\x0f\x13\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x03*\x16\x17\n\x0b\n\x04\x04\x02\x02\x01\x12\x03+\x08\x1b\n\r\n\x05\
-    \x04\x02\x02\x01\x04\x12\x04+\x08*\x18\n\x0c\n\x05\x04\x02\x02\x01\x05\
-    \x12\x03+\x08\x0e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03+\x0f\x16\n\x0c\
-    \n\x05\x04\x02\x02\x01\x03\x12\x03+\x19\x1a\n\x0b\n\x04\x04\x02\x02\x02\
-    \x12\x03,\x08\x1a\n\r\n\x05\x04\x02\x02\x02\x04\x12\x04,\x08+\x1b\n\x0c\
-    \n\x05\x04\x02\x02\x02\x05\x12\x03,\x08\x0e\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03,\x0f\x15\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03,\x18\x19\n\
-    \x0b\n\x04\x04\x02\x02\x03\x12\x03-\x08\x1a\n\r\n\x05\x04\x02\x02\x03\
-    \x04\x12\x04-\x08,\x1a\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03-\x08\x0e\
-    \n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03-\x0f\x15\n\x0c\n\x05\x04\x02\
-    \x02\x03\x03\x12\x03-\x18\x19\n\x0b\n\x04\x04\x02\x02\x04\x12\x03.\x08\
-    \x19\n\r\n\x05\x04\x02\x02\x04\x04\x12\x04.\x08-\x1a\n\x0c\n\x05\x04\x02\
-    \x02\x04\x05\x12\x03.\x08\x0e\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03.\
-    \x0f\x14\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03.\x17\x18b\x06proto3\
+    \x06\x04\x12\x04&\x08\x20\x1b\n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03&\
+    \x08\x0e\n\x0c\n\x05\x04\x01\x02\x06\x01\x12\x03&\x0f\x13\n\x0c\n\x05\
+    \x04\x01\x02\x06\x03\x12\x03&\x16\x17\n\x0b\n\x04\x04\x01\x02\x07\x12\
+    \x03'\x08\x1d\n\r\n\x05\x04\x01\x02\x07\x04\x12\x04'\x08&\x18\n\x0c\n\
+    \x05\x04\x01\x02\x07\x05\x12\x03'\x08\x0e\n\x0c\n\x05\x04\x01\x02\x07\
+    \x01\x12\x03'\x0f\x18\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03'\x1b\x1c\n\
+    \x9e\x01\n\x04\x04\x01\x02\x08\x12\x03-\x08\x18\x1a,\x20index\x20is\x20t\
+    he\x20interface\x20index\x20in\x20the\x20guest.\n2c\x20The\x20fields\x20\
+    below\x20are\x20only\x20filled\x20by\x20ListInterfaces,\x20they\x20are\n\
+    \x20ignored\x20when\x20updating\x20an\x20interface.\n\n\r\n\x05\x04\x01\
+    \x02\x08\x04\x12\x04-\x08'\x1d\n\x0c\n\x05\x04\x01\x02\x08\x05\x12\x03-\
+    \x08\r\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03-\x0e\x13\n\x0c\n\x05\x04\
+    \x01\x02\x08\x03\x12\x03-\x16\x17\n\x8f\x01\n\x04\x04\x01\x02\t\x12\x031\
+    \x08\x1f\x1a\x81\x01\x20oper_state\x20is\x20the\x20RFC\x202863\x20operat\
+    ional\x20state:\x20\"up\",\x20\"down\",\n\x20\"lowerlayerdown\",\x20\"do\
+    rmant\",\x20\"testing\",\x20\"notpresent\"\x20or\x20\"unknown\".\n\n\r\n\
+    \x05\x04\x01\x02\t\x04\x12\x041\x08-\x18\n\x0c\n\x05\x04\x01\x02\t\x05\
+    \x12\x031\x08\x0e\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x031\x0f\x19\n\x0c\n\
+    \x05\x04\x01\x02\t\x03\x12\x031\x1c\x1e\n\x0b\n\x04\x04\x01\x02\n\x12\
+    \x032\x08\x1a\n\r\n\x05\x04\x01\x02\n\x04\x12\x042\x081\x1f\n\x0c\n\x05\
+    \x04\x01\x02\n\x05\x12\x032\x08\x0c\n\x0c\n\x05\x04\x01\x02\n\x01\x12\
+    \x032\r\x14\n\x0c\n\x05\x04\x01\x02\n\x03\x12\x032\x17\x19\n\x0b\n\x04\
+    \x04\x01\x02\x0b\x12\x033\x08\x1b\n\r\n\x05\x04\x01\x02\x0b\x04\x12\x043\
+    \x082\x1a\n\x0c\n\x05\x04\x01\x02\x0b\x05\x12\x033\x08\x0e\n\x0c\n\x05\
+    \x04\x01\x02\x0b\x01\x12\x033\x0f\x15\n\x0c\n\x05\x04\x01\x02\x0b\x03\
+    \x12\x033\x18\x1a\nQ\n\x04\x04\x01\x02\x0c\x12\x036\x08\x20\x1aD\x20mast\
+    er\x20is\x20the\x20bridge\x20or\x20bond\x20the\x20interface\x20is\x20ens\
+    laved\x20to,\x20if\x20any.\n\n\r\n\x05\x04\x01\x02\x0c\x04\x12\x046\x083\
+    \x1b\n\x0c\n\x05\x04\x01\x02\x0c\x05\x12\x036\x08\r\n\x0c\n\x05\x04\x01\
+    \x02\x0c\x01\x12\x036\x0e\x1a\n\x0c\n\x05\x04\x01\x02\x0c\x03\x12\x036\
+    \x1d\x1f\n\x0b\n\x04\x04\x01\x02\r\x12\x037\x08\x1b\n\r\n\x05\x04\x01\
+    \x02\r\x04\x12\x047\x086\x20\n\x0c\n\x05\x04\x01\x02\r\x05\x12\x037\x08\
+    \x0e\n\x0c\n\x05\x04\x01\x02\r\x01\x12\x037\x0f\x15\n\x0c\n\x05\x04\x01\
+    \x02\r\x03\x12\x037\x18\x1a\n\x93\x01\n\x04\x04\x01\x02\x0e\x12\x03;\x08\
+    \x1b\x1a\x85\x01\x20driver\x20is\x20the\x20kernel\x20driver\x20of\x20the\
+    \x20device\x20behind\x20the\x20interface,\n\x20e.g.\x20\"virtio_net\".\
+    \x20It\x20is\x20empty\x20for\x20virtual\x20interfaces,\x20as\x20pciAddr.\
+    \n\n\r\n\x05\x04\x01\x02\x0e\x04\x12\x04;\x087\x1b\n\x0c\n\x05\x04\x01\
+    \x02\x0e\x05\x12\x03;\x08\x0e\n\x0c\n\x05\x04\x01\x02\x0e\x01\x12\x03;\
+    \x0f\x15\n\x0c\n\x05\x04\x01\x02\x0e\x03\x12\x03;\x18\x1a\n\x0b\n\x04\
+    \x04\x01\x02\x0f\x12\x03<\x08,\n\r\n\x05\x04\x01\x02\x0f\x04\x12\x04<\
+    \x08;\x1b\n\x0c\n\x05\x04\x01\x02\x0f\x06\x12\x03<\x08\x1b\n\x0c\n\x05\
+    \x04\x01\x02\x0f\x01\x12\x03<\x1c&\n\x0c\n\x05\x04\x01\x02\x0f\x03\x12\
+    \x03<)+\nK\n\x02\x04\x02\x12\x04@\0K\x01\x1a?\x20InterfaceStatistics\x20\
+    are\x20the\x2064\x20bits\x20counters\x20of\x20an\x20interface.\n\n\n\n\
+    \x03\x04\x02\x01\x12\x03@\x08\x1b\n\x0b\n\x04\x04\x02\x02\0\x12\x03A\x08\
+    \x1e\n\r\n\x05\x04\x02\x02\0\x04\x12\x04A\x08@\x1d\n\x0c\n\x05\x04\x02\
+    \x02\0\x05\x12\x03A\x08\x0e\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03A\x0f\
+    \x19\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03A\x1c\x1d\n\x0b\n\x04\x04\x02\
+    \x02\x01\x12\x03B\x08\x1e\n\r\n\x05\x04\x02\x02\x01\x04\x12\x04B\x08A\
+    \x1e\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03B\x08\x0e\n\x0c\n\x05\x04\
+    \x02\x02\x01\x01\x12\x03B\x0f\x19\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
+    \x03B\x1c\x1d\n\x0b\n\x04\x04\x02\x02\x02\x12\x03C\x08\x1c\n\r\n\x05\x04\
+    \x02\x02\x02\x04\x12\x04C\x08B\x1e\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
+    \x03C\x08\x0e\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03C\x0f\x17\n\x0c\n\
+    \x05\x04\x02\x02\x02\x03\x12\x03C\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x03\
+    \x12\x03D\x08\x1c\n\r\n\x05\x04\x02\x02\x03\x04\x12\x04D\x08C\x1c\n\x0c\
+    \n\x05\x04\x02\x02\x03\x05\x12\x03D\x08\x0e\n\x0c\n\x05\x04\x02\x02\x03\
+    \x01\x12\x03D\x0f\x17\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03D\x1a\x1b\n\
+    \x0b\n\x04\x04\x02\x02\x04\x12\x03E\x08\x1d\n\r\n\x05\x04\x02\x02\x04\
+    \x04\x12\x04E\x08D\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03E\x08\x0e\
+    \n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03E\x0f\x18\n\x0c\n\x05\x04\x02\
+    \x02\x04\x03\x12\x03E\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x05\x12\x03F\x08\
+    \x1d\n\r\n\x05\x04\x02\x02\x05\x04\x12\x04F\x08E\x1d\n\x0c\n\x05\x04\x02\
+    \x02\x05\x05\x12\x03F\x08\x0e\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03F\
+    \x0f\x18\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03F\x1b\x1c\n\x0b\n\x04\
+    \x04\x02\x02\x06\x12\x03G\x08\x1e\n\r\n\x05\x04\x02\x02\x06\x04\x12\x04G\
+    \x08F\x1d\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x03G\x08\x0e\n\x0c\n\x05\
+    \x04\x02\x02\x06\x01\x12\x03G\x0f\x19\n\x0c\n\x05\x04\x02\x02\x06\x03\
+    \x12\x03G\x1c\x1d\n\x0b\n\x04\x04\x02\x02\x07\x12\x03H\x08\x1e\n\r\n\x05\
+    \x04\x02\x02\x07\x04\x12\x04H\x08G\x1e\n\x0c\n\x05\x04\x02\x02\x07\x05\
+    \x12\x03H\x08\x0e\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03H\x0f\x19\n\x0c\
+    \n\x05\x04\x02\x02\x07\x03\x12\x03H\x1c\x1d\n\x0b\n\x04\x04\x02\x02\x08\
+    \x12\x03I\x08\x1d\n\r\n\x05\x04\x02\x02\x08\x04\x12\x04I\x08H\x1e\n\x0c\
+    \n\x05\x04\x02\x02\x08\x05\x12\x03I\x08\x0e\n\x0c\n\x05\x04\x02\x02\x08\
+    \x01\x12\x03I\x0f\x18\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03I\x1b\x1c\n\
+    \x0b\n\x04\x04\x02\x02\t\x12\x03J\x08\x1f\n\r\n\x05\x04\x02\x02\t\x04\
+    \x12\x04J\x08I\x1d\n\x0c\n\x05\x04\x02\x02\t\x05\x12\x03J\x08\x0e\n\x0c\
+    \n\x05\x04\x02\x02\t\x01\x12\x03J\x0f\x19\n\x0c\n\x05\x04\x02\x02\t\x03\
+    \x12\x03J\x1c\x1e\n\n\n\x02\x04\x03\x12\x04M\0e\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03M\x08\r\n\x0b\n\x04\x04\x03\x02\0\x12\x03N\x08\x18\n\r\n\x05\
+    \x04\x03\x02\0\x04\x12\x04N\x08M\x0f\n\x0c\n\x05\x04\x03\x02\0\x05\x12\
+    \x03N\x08\x0e\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03N\x0f\x13\n\x0c\n\x05\
+    \x04\x03\x02\0\x03\x12\x03N\x16\x17\n\x0b\n\x04\x04\x03\x02\x01\x12\x03O\
+    \x08\x1b\n\r\n\x05\x04\x03\x02\x01\x04\x12\x04O\x08N\x18\n\x0c\n\x05\x04\
+    \x03\x02\x01\x05\x12\x03O\x08\x0e\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03O\x0f\x16\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03O\x19\x1a\n\x0b\n\
+    \x04\x04\x03\x02\x02\x12\x03P\x08\x1a\n\r\n\x05\x04\x03\x02\x02\x04\x12\
+    \x04P\x08O\x1b\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03P\x08\x0e\n\x0c\n\
+    \x05\x04\x03\x02\x02\x01\x12\x03P\x0f\x15\n\x0c\n\x05\x04\x03\x02\x02\
+    \x03\x12\x03P\x18\x19\n\x0b\n\x04\x04\x03\x02\x03\x12\x03Q\x08\x1a\n\r\n\
+    \x05\x04\x03\x02\x03\x04\x12\x04Q\x08P\x1a\n\x0c\n\x05\x04\x03\x02\x03\
+    \x05\x12\x03Q\x08\x0e\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03Q\x0f\x15\n\
+    \x0c\n\x05\x04\x03\x02\x03\x03\x12\x03Q\x18\x19\n\x0b\n\x04\x04\x03\x02\
+    \x04\x12\x03R\x08\x19\n\r\n\x05\x04\x03\x02\x04\x04\x12\x04R\x08Q\x1a\n\
+    \x0c\n\x05\x04\x03\x02\x04\x05\x12\x03R\x08\x0e\n\x0c\n\x05\x04\x03\x02\
+    \x04\x01\x12\x03R\x0f\x14\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03R\x17\
+    \x18\nW\n\x04\x04\x03\x02\x05\x12\x03U\x08\x1a\x1aJ\x20metric\x20is\x20t\
+    he\x20route\x20priority\x20(RTA_PRIORITY),\x20lower\x20values\x20are\x20\
+    preferred.\n\n\r\n\x05\x04\x03\x02\x05\x04\x12\x04U\x08R\x19\n\x0c\n\x05\
+    \x04\x03\x02\x05\x05\x12\x03U\x08\x0e\n\x0c\n\x05\x04\x03\x02\x05\x01\
+    \x12\x03U\x0f\x15\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x03U\x18\x19\nI\n\
+    \x04\x04\x03\x02\x06\x12\x03X\x08\x17\x1a<\x20mtu\x20is\x20the\x20per-ro\
+    ute\x20MTU\x20(RTAX_MTU).\x20Zero\x20leaves\x20it\x20unset.\n\n\r\n\x05\
+    \x04\x03\x02\x06\x04\x12\x04X\x08U\x1a\n\x0c\n\x05\x04\x03\x02\x06\x05\
+    \x12\x03X\x08\x0e\n\x0c\n\x05\x04\x03\x02\x06\x01\x12\x03X\x0f\x12\n\x0c\
+    \n\x05\x04\x03\x02\x06\x03\x12\x03X\x15\x16\n\x86\x01\n\x04\x04\x03\x02\
+    \x07\x12\x03\\\x08\x18\x1ay\x20onlink\x20tells\x20the\x20kernel\x20the\
+    \x20gateway\x20is\x20directly\x20reachable\x20on\x20the\n\x20device\x20e\
+    ven\x20if\x20it\x20is\x20outside\x20of\x20the\x20interface\x20subnet.\n\
+    \n\r\n\x05\x04\x03\x02\x07\x04\x12\x04\\\x08X\x17\n\x0c\n\x05\x04\x03\
+    \x02\x07\x05\x12\x03\\\x08\x0c\n\x0c\n\x05\x04\x03\x02\x07\x01\x12\x03\\\
+    \r\x13\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03\\\x16\x17\n\x88\x01\n\x04\
+    \x04\x03\x02\x08\x12\x03`\x08\x1c\x1a{\x20protocol\x20is\x20the\x20route\
+    \x20origin\x20(rtm_protocol),\x20e.g.\x203\x20for\x20\"boot\"\x20or\n\
+    \x204\x20for\x20\"static\".\x20Zero\x20means\x20the\x20agent\x20default\
+    \x20(\"boot\").\n\n\r\n\x05\x04\x03\x02\x08\x04\x12\x04`\x08\\\x18\n\x0c\
+    \n\x05\x04\x03\x02\x08\x05\x12\x03`\x08\x0e\n\x0c\n\x05\x04\x03\x02\x08\
+    \x01\x12\x03`\x0f\x17\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03`\x1a\x1b\n\
+    u\n\x04\x04\x03\x02\t\x12\x03d\x08(\x1ah\x20multipath\x20holds\x20the\
+    \x20ECMP\x20nexthops\x20of\x20the\x20route.\x20When\x20it\x20is\x20not\n\
+    \x20empty,\x20gateway\x20and\x20device\x20are\x20ignored.\n\n\x0c\n\x05\
+    \x04\x03\x02\t\x04\x12\x03d\x08\x10\n\x0c\n\x05\x04\x03\x02\t\x06\x12\
+    \x03d\x11\x18\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03d\x19\"\n\x0c\n\x05\
+    \x04\x03\x02\t\x03\x12\x03d%'\n\n\n\x02\x04\x04\x12\x04g\0n\x01\n\n\n\
+    \x03\x04\x04\x01\x12\x03g\x08\x0f\n\x0b\n\x04\x04\x04\x02\0\x12\x03h\x08\
+    \x1b\n\r\n\x05\x04\x04\x02\0\x04\x12\x04h\x08g\x11\n\x0c\n\x05\x04\x04\
+    \x02\0\x05\x12\x03h\x08\x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03h\x0f\
+    \x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03h\x19\x1a\n\x0b\n\x04\x04\x04\
+    \x02\x01\x12\x03i\x08\x1a\n\r\n\x05\x04\x04\x02\x01\x04\x12\x04i\x08h\
+    \x1b\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03i\x08\x0e\n\x0c\n\x05\x04\
+    \x04\x02\x01\x01\x12\x03i\x0f\x15\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\
+    \x03i\x18\x19\nS\n\x04\x04\x04\x02\x02\x12\x03l\x08\x1a\x1aF\x20weight\
+    \x20is\x20the\x20relative\x20weight\x20of\x20this\x20nexthop.\x20Zero\
+    \x20is\x20treated\x20as\x201.\n\n\r\n\x05\x04\x04\x02\x02\x04\x12\x04l\
+    \x08i\x1a\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03l\x08\x0e\n\x0c\n\x05\
+    \x04\x04\x02\x02\x01\x12\x03l\x0f\x15\n\x0c\n\x05\x04\x04\x02\x02\x03\
+    \x12\x03l\x18\x19\n\x0b\n\x04\x04\x04\x02\x03\x12\x03m\x08\x18\n\r\n\x05\
+    \x04\x04\x02\x03\x04\x12\x04m\x08l\x1a\n\x0c\n\x05\x04\x04\x02\x03\x05\
+    \x12\x03m\x08\x0c\n\x0c\n\x05\x04\x04\x02\x03\x01\x12\x03m\r\x13\n\x0c\n\
+    \x05\x04\x04\x02\x03\x03\x12\x03m\x16\x17\n\xbb\x01\n\x02\x04\x05\x12\
+    \x05s\0\x8e\x01\x01\x1a\xad\x01\x20Qdisc\x20is\x20a\x20queueing\x20disci\
+    pline\x20attached\x20to\x20an\x20interface.\x20Handles\x20are\n\x20writt\
+    en\x20as\x20tc(8)\x20does,\x20\"major:minor\"\x20in\x20hexadecimal,\x20\
+    \"root\"\x20and\n\x20\"ingress\"\x20being\x20accepted\x20as\x20parents.\
+    \n\n\n\n\x03\x04\x05\x01\x12\x03s\x08\r\n\x0b\n\x04\x04\x05\x02\0\x12\
+    \x03t\x08\x1a\n\r\n\x05\x04\x05\x02\0\x04\x12\x04t\x08s\x0f\n\x0c\n\x05\
+    \x04\x05\x02\0\x05\x12\x03t\x08\x0e\n\x0c\n\x05\x04\x05\x02\0\x01\x12\
+    \x03t\x0f\x15\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03t\x18\x19\n\x0b\n\x04\
+    \x04\x05\x02\x01\x12\x03u\x08\x1a\n\r\n\x05\x04\x05\x02\x01\x04\x12\x04u\
+    \x08t\x1a\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03u\x08\x0e\n\x0c\n\x05\
+    \x04\x05\x02\x01\x01\x12\x03u\x0f\x15\n\x0c\n\x05\x04\x05\x02\x01\x03\
+    \x12\x03u\x18\x19\nN\n\x04\x04\x05\x02\x02\x12\x03x\x08\x1a\x1aA\x20hand\
+    le\x20of\x20the\x20qdisc,\x20e.g.\x20\"1:\".\x20Empty\x20lets\x20the\x20\
+    kernel\x20pick\x20one.\n\n\r\n\x05\x04\x05\x02\x02\x04\x12\x04x\x08u\x1a\
+    \n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03x\x08\x0e\n\x0c\n\x05\x04\x05\
+    \x02\x02\x01\x12\x03x\x0f\x15\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03x\
+    \x18\x19\nD\n\x04\x04\x05\x02\x03\x12\x03{\x08\x18\x1a7\x20kind\x20is\
+    \x20one\x20of\x20\"ingress\",\x20\"tbf\",\x20\"htb\"\x20or\x20\"fq_codel\
+    \".\n\n\r\n\x05\x04\x05\x02\x03\x04\x12\x04{\x08x\x1a\n\x0c\n\x05\x04\
+    \x05\x02\x03\x05\x12\x03{\x08\x0e\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\
+    \x03{\x0f\x13\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03{\x16\x17\n@\n\x04\
+    \x04\x05\x02\x04\x12\x03~\x08\x18\x1a3\x20tbf:\x20rate\x20in\x20bytes\
+    \x20per\x20second\x20and\x20burst\x20in\x20bytes.\n\n\r\n\x05\x04\x05\
+    \x02\x04\x04\x12\x04~\x08{\x18\n\x0c\n\x05\x04\x05\x02\x04\x05\x12\x03~\
+    \x08\x0e\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03~\x0f\x13\n\x0c\n\x05\
+    \x04\x05\x02\x04\x03\x12\x03~\x16\x17\n\x0b\n\x04\x04\x05\x02\x05\x12\
+    \x03\x7f\x08\x19\n\r\n\x05\x04\x05\x02\x05\x04\x12\x04\x7f\x08~\x18\n\
+    \x0c\n\x05\x04\x05\x02\x05\x05\x12\x03\x7f\x08\x0e\n\x0c\n\x05\x04\x05\
+    \x02\x05\x01\x12\x03\x7f\x0f\x14\n\x0c\n\x05\x04\x05\x02\x05\x03\x12\x03\
+    \x7f\x17\x18\n}\n\x04\x04\x05\x02\x06\x12\x04\x83\x01\x08\x19\x1ao\x20li\
+    mit\x20is\x20the\x20queue\x20length,\x20in\x20bytes\x20for\x20tbf\x20whe\
+    re\x20zero\x20allows\n\x2025ms\x20of\x20queueing,\x20and\x20in\x20packet\
+    s\x20for\x20fq_codel.\n\n\x0e\n\x05\x04\x05\x02\x06\x04\x12\x05\x83\x01\
+    \x08\x7f\x19\n\r\n\x05\x04\x05\x02\x06\x05\x12\x04\x83\x01\x08\x0e\n\r\n\
+    \x05\x04\x05\x02\x06\x01\x12\x04\x83\x01\x0f\x14\n\r\n\x05\x04\x05\x02\
+    \x06\x03\x12\x04\x83\x01\x17\x18\nL\n\x04\x04\x05\x02\x07\x12\x04\x86\
+    \x01\x08!\x1a>\x20htb:\x20minor\x20number\x20of\x20the\x20class\x20uncla\
+    ssified\x20traffic\x20goes\x20to.\n\n\x0f\n\x05\x04\x05\x02\x07\x04\x12\
+    \x06\x86\x01\x08\x83\x01\x19\n\r\n\x05\x04\x05\x02\x07\x05\x12\x04\x86\
+    \x01\x08\x0e\n\r\n\x05\x04\x05\x02\x07\x01\x12\x04\x86\x01\x0f\x1c\n\r\n\
+    \x05\x04\x05\x02\x07\x03\x12\x04\x86\x01\x1f\x20\nq\n\x04\x04\x05\x02\
+    \x08\x12\x04\x8a\x01\x08\x19\x1ac\x20fq_codel:\x20number\x20of\x20flows,\
+    \x20target\x20and\x20interval\x20in\x20microseconds.\n\x20Zero\x20leaves\
+    \x20the\x20kernel\x20defaults.\n\n\x0f\n\x05\x04\x05\x02\x08\x04\x12\x06\
+    \x8a\x01\x08\x86\x01!\n\r\n\x05\x04\x05\x02\x08\x05\x12\x04\x8a\x01\x08\
+    \x0e\n\r\n\x05\x04\x05\x02\x08\x01\x12\x04\x8a\x01\x0f\x14\n\r\n\x05\x04\
+    \x05\x02\x08\x03\x12\x04\x8a\x01\x17\x18\n\x0c\n\x04\x04\x05\x02\t\x12\
+    \x04\x8b\x01\x08\x1b\n\x0f\n\x05\x04\x05\x02\t\x04\x12\x06\x8b\x01\x08\
+    \x8a\x01\x19\n\r\n\x05\x04\x05\x02\t\x05\x12\x04\x8b\x01\x08\x0e\n\r\n\
+    \x05\x04\x05\x02\t\x01\x12\x04\x8b\x01\x0f\x15\n\r\n\x05\x04\x05\x02\t\
+    \x03\x12\x04\x8b\x01\x18\x1a\n\x0c\n\x04\x04\x05\x02\n\x12\x04\x8c\x01\
+    \x08\x1d\n\x0f\n\x05\x04\x05\x02\n\x04\x12\x06\x8c\x01\x08\x8b\x01\x1b\n\
+    \r\n\x05\x04\x05\x02\n\x05\x12\x04\x8c\x01\x08\x0e\n\r\n\x05\x04\x05\x02\
+    \n\x01\x12\x04\x8c\x01\x0f\x17\n\r\n\x05\x04\x05\x02\n\x03\x12\x04\x8c\
+    \x01\x1a\x1c\n\x0c\n\x04\x04\x05\x02\x0b\x12\x04\x8d\x01\x08\x16\n\x0f\n\
+    \x05\x04\x05\x02\x0b\x04\x12\x06\x8d\x01\x08\x8c\x01\x1d\n\r\n\x05\x04\
+    \x05\x02\x0b\x05\x12\x04\x8d\x01\x08\x0c\n\r\n\x05\x04\x05\x02\x0b\x01\
+    \x12\x04\x8d\x01\r\x10\n\r\n\x05\x04\x05\x02\x0b\x03\x12\x04\x8d\x01\x13\
+    \x15\nU\n\x02\x04\x06\x12\x06\x91\x01\0\x9f\x01\x01\x1aG\x20TrafficClass\
+    \x20is\x20a\x20class\x20of\x20a\x20classful\x20qdisc,\x20only\x20\"htb\"\
+    \x20is\x20supported.\n\n\x0b\n\x03\x04\x06\x01\x12\x04\x91\x01\x08\x14\n\
+    \x0c\n\x04\x04\x06\x02\0\x12\x04\x92\x01\x08\x1a\n\x0f\n\x05\x04\x06\x02\
+    \0\x04\x12\x06\x92\x01\x08\x91\x01\x16\n\r\n\x05\x04\x06\x02\0\x05\x12\
+    \x04\x92\x01\x08\x0e\n\r\n\x05\x04\x06\x02\0\x01\x12\x04\x92\x01\x0f\x15\
+    \n\r\n\x05\x04\x06\x02\0\x03\x12\x04\x92\x01\x18\x19\n\x0c\n\x04\x04\x06\
+    \x02\x01\x12\x04\x93\x01\x08\x1a\n\x0f\n\x05\x04\x06\x02\x01\x04\x12\x06\
+    \x93\x01\x08\x92\x01\x1a\n\r\n\x05\x04\x06\x02\x01\x05\x12\x04\x93\x01\
+    \x08\x0e\n\r\n\x05\x04\x06\x02\x01\x01\x12\x04\x93\x01\x0f\x15\n\r\n\x05\
+    \x04\x06\x02\x01\x03\x12\x04\x93\x01\x18\x19\n\x0c\n\x04\x04\x06\x02\x02\
+    \x12\x04\x94\x01\x08\x1b\n\x0f\n\x05\x04\x06\x02\x02\x04\x12\x06\x94\x01\
+    \x08\x93\x01\x1a\n\r\n\x05\x04\x06\x02\x02\x05\x12\x04\x94\x01\x08\x0e\n\
+    \r\n\x05\x04\x06\x02\x02\x01\x12\x04\x94\x01\x0f\x16\n\r\n\x05\x04\x06\
+    \x02\x02\x03\x12\x04\x94\x01\x19\x1a\n\x0c\n\x04\x04\x06\x02\x03\x12\x04\
+    \x95\x01\x08\x18\n\x0f\n\x05\x04\x06\x02\x03\x04\x12\x06\x95\x01\x08\x94\
+    \x01\x1b\n\r\n\x05\x04\x06\x02\x03\x05\x12\x04\x95\x01\x08\x0e\n\r\n\x05\
+    \x04\x06\x02\x03\x01\x12\x04\x95\x01\x0f\x13\n\r\n\x05\x04\x06\x02\x03\
+    \x03\x12\x04\x95\x01\x16\x17\nK\n\x04\x04\x06\x02\x04\x12\x04\x98\x01\
+    \x08\x18\x1a=\x20rate\x20and\x20ceil\x20in\x20bytes\x20per\x20second,\
+    \x20a\x20zero\x20ceil\x20is\x20the\x20rate.\n\n\x0f\n\x05\x04\x06\x02\
+    \x04\x04\x12\x06\x98\x01\x08\x95\x01\x18\n\r\n\x05\x04\x06\x02\x04\x05\
+    \x12\x04\x98\x01\x08\x0e\n\r\n\x05\x04\x06\x02\x04\x01\x12\x04\x98\x01\
+    \x0f\x13\n\r\n\x05\x04\x06\x02\x04\x03\x12\x04\x98\x01\x16\x17\n\x0c\n\
+    \x04\x04\x06\x02\x05\x12\x04\x99\x01\x08\x18\n\x0f\n\x05\x04\x06\x02\x05\
+    \x04\x12\x06\x99\x01\x08\x98\x01\x18\n\r\n\x05\x04\x06\x02\x05\x05\x12\
+    \x04\x99\x01\x08\x0e\n\r\n\x05\x04\x06\x02\x05\x01\x12\x04\x99\x01\x0f\
+    \x13\n\r\n\x05\x04\x06\x02\x05\x03\x12\x04\x99\x01\x16\x17\nL\n\x04\x04\
+    \x06\x02\x06\x12\x04\x9c\x01\x08\x19\x1a>\x20burst\x20and\x20cburst\x20i\
+    n\x20bytes,\x20zero\x20lets\x20the\x20agent\x20compute\x20them.\n\n\x0f\
+    \n\x05\x04\x06\x02\x06\x04\x12\x06\x9c\x01\x08\x99\x01\x18\n\r\n\x05\x04\
+    \x06\x02\x06\x05\x12\x04\x9c\x01\x08\x0e\n\r\n\x05\x04\x06\x02\x06\x01\
+    \x12\x04\x9c\x01\x0f\x14\n\r\n\x05\x04\x06\x02\x06\x03\x12\x04\x9c\x01\
+    \x17\x18\n\x0c\n\x04\x04\x06\x02\x07\x12\x04\x9d\x01\x08\x1a\n\x0f\n\x05\
+    \x04\x06\x02\x07\x04\x12\x06\x9d\x01\x08\x9c\x01\x19\n\r\n\x05\x04\x06\
+    \x02\x07\x05\x12\x04\x9d\x01\x08\x0e\n\r\n\x05\x04\x06\x02\x07\x01\x12\
+    \x04\x9d\x01\x0f\x15\n\r\n\x05\x04\x06\x02\x07\x03\x12\x04\x9d\x01\x18\
+    \x19\n\x0c\n\x04\x04\x06\x02\x08\x12\x04\x9e\x01\x08\x18\n\x0f\n\x05\x04\
+    \x06\x02\x08\x04\x12\x06\x9e\x01\x08\x9d\x01\x1a\n\r\n\x05\x04\x06\x02\
+    \x08\x05\x12\x04\x9e\x01\x08\x0e\n\r\n\x05\x04\x06\x02\x08\x01\x12\x04\
+    \x9e\x01\x0f\x13\n\r\n\x05\x04\x06\x02\x08\x03\x12\x04\x9e\x01\x16\x17\n\
+    S\n\x02\x04\x07\x12\x06\xa2\x01\0\xa9\x01\x01\x1aE\x20MirredAction\x20re\
+    directs\x20or\x20mirrors\x20the\x20packets\x20to\x20another\x20interface\
+    .\n\n\x0b\n\x03\x04\x07\x01\x12\x04\xa2\x01\x08\x14\n\x0c\n\x04\x04\x07\
+    \x02\0\x12\x04\xa3\x01\x08\x1a\n\x0f\n\x05\x04\x07\x02\0\x04\x12\x06\xa3\
+    \x01\x08\xa2\x01\x16\n\r\n\x05\x04\x07\x02\0\x05\x12\x04\xa3\x01\x08\x0e\
+    \n\r\n\x05\x04\x07\x02\0\x01\x12\x04\xa3\x01\x0f\x15\n\r\n\x05\x04\x07\
+    \x02\0\x03\x12\x04\xa3\x01\x18\x19\n\x0c\n\x04\x04\x07\x02\x01\x12\x04\
+    \xa4\x01\x08\x18\n\x0f\n\x05\x04\x07\x02\x01\x04\x12\x06\xa4\x01\x08\xa3\
+    \x01\x1a\n\r\n\x05\x04\x07\x02\x01\x05\x12\x04\xa4\x01\x08\x0c\n\r\n\x05\
+    \x04\x07\x02\x01\x01\x12\x04\xa4\x01\r\x13\n\r\n\x05\x04\x07\x02\x01\x03\
+    \x12\x04\xa4\x01\x16\x17\nZ\n\x04\x04\x07\x02\x02\x12\x04\xa8\x01\x08\
+    \x19\x1aL\x20ingress\x20sends\x20the\x20packets\x20to\x20the\x20ingress\
+    \x20of\x20device\x20instead\x20of\n\x20its\x20egress.\n\n\x0f\n\x05\x04\
+    \x07\x02\x02\x04\x12\x06\xa8\x01\x08\xa4\x01\x18\n\r\n\x05\x04\x07\x02\
+    \x02\x05\x12\x04\xa8\x01\x08\x0c\n\r\n\x05\x04\x07\x02\x02\x01\x12\x04\
+    \xa8\x01\r\x14\n\r\n\x05\x04\x07\x02\x02\x03\x12\x04\xa8\x01\x17\x18\n\
+    \x85\x01\n\x02\x04\x08\x12\x06\xad\x01\0\xbc\x01\x01\x1aw\x20TrafficFilt\
+    er\x20classifies\x20the\x20packets\x20of\x20a\x20qdisc.\x20Both\x20the\
+    \x20\"u32\"\x20and\n\x20\"matchall\"\x20kinds\x20match\x20every\x20packe\
+    t\x20of\x20protocol.\n\n\x0b\n\x03\x04\x08\x01\x12\x04\xad\x01\x08\x15\n\
+    \x0c\n\x04\x04\x08\x02\0\x12\x04\xae\x01\x08\x1a\n\x0f\n\x05\x04\x08\x02\
+    \0\x04\x12\x06\xae\x01\x08\xad\x01\x17\n\r\n\x05\x04\x08\x02\0\x05\x12\
+    \x04\xae\x01\x08\x0e\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\xae\x01\x0f\x15\
+    \n\r\n\x05\x04\x08\x02\0\x03\x12\x04\xae\x01\x18\x19\n\x0c\n\x04\x04\x08\
+    \x02\x01\x12\x04\xaf\x01\x08\x1a\n\x0f\n\x05\x04\x08\x02\x01\x04\x12\x06\
+    \xaf\x01\x08\xae\x01\x1a\n\r\n\x05\x04\x08\x02\x01\x05\x12\x04\xaf\x01\
+    \x08\x0e\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\xaf\x01\x0f\x15\n\r\n\x05\
+    \x04\x08\x02\x01\x03\x12\x04\xaf\x01\x18\x19\nF\n\x04\x04\x08\x02\x02\
+    \x12\x04\xb2\x01\x08\x1c\x1a8\x20priority\x20of\x20the\x20filter,\x20zer\
+    o\x20lets\x20the\x20kernel\x20pick\x20one.\n\n\x0f\n\x05\x04\x08\x02\x02\
+    \x04\x12\x06\xb2\x01\x08\xaf\x01\x1a\n\r\n\x05\x04\x08\x02\x02\x05\x12\
+    \x04\xb2\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x02\x01\x12\x04\xb2\x01\x0f\
+    \x17\n\r\n\x05\x04\x08\x02\x02\x03\x12\x04\xb2\x01\x1a\x1b\nv\n\x04\x04\
+    \x08\x02\x03\x12\x04\xb6\x01\x08\x1c\x1ah\x20protocol\x20is\x20\"all\",\
+    \x20\"ip\",\x20\"ipv6\",\x20\"arp\",\x20\"802.1q\"\x20or\x20an\x20ethert\
+    ype\n\x20such\x20as\x20\"0x88cc\".\x20Empty\x20means\x20\"all\".\n\n\x0f\
+    \n\x05\x04\x08\x02\x03\x04\x12\x06\xb6\x01\x08\xb2\x01\x1c\n\r\n\x05\x04\
+    \x08\x02\x03\x05\x12\x04\xb6\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x03\x01\
+    \x12\x04\xb6\x01\x0f\x17\n\r\n\x05\x04\x08\x02\x03\x03\x12\x04\xb6\x01\
+    \x1a\x1b\n\x0c\n\x04\x04\x08\x02\x04\x12\x04\xb7\x01\x08\x18\n\x0f\n\x05\
+    \x04\x08\x02\x04\x04\x12\x06\xb7\x01\x08\xb6\x01\x1c\n\r\n\x05\x04\x08\
+    \x02\x04\x05\x12\x04\xb7\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x04\x01\x12\
+    \x04\xb7\x01\x0f\x13\n\r\n\x05\x04\x08\x02\x04\x03\x12\x04\xb7\x01\x16\
+    \x17\n?\n\x04\x04\x08\x02\x05\x12\x04\xba\x01\x08\x1b\x1a1\x20classid\
+    \x20is\x20the\x20class\x20the\x20packets\x20go\x20to,\x20if\x20any.\n\n\
+    \x0f\n\x05\x04\x08\x02\x05\x04\x12\x06\xba\x01\x08\xb7\x01\x18\n\r\n\x05\
+    \x04\x08\x02\x05\x05\x12\x04\xba\x01\x08\x0e\n\r\n\x05\x04\x08\x02\x05\
+    \x01\x12\x04\xba\x01\x0f\x16\n\r\n\x05\x04\x08\x02\x05\x03\x12\x04\xba\
+    \x01\x19\x1a\n\x0c\n\x04\x04\x08\x02\x06\x12\x04\xbb\x01\x08\x20\n\x0f\n\
+    \x05\x04\x08\x02\x06\x04\x12\x06\xbb\x01\x08\xba\x01\x1b\n\r\n\x05\x04\
+    \x08\x02\x06\x06\x12\x04\xbb\x01\x08\x14\n\r\n\x05\x04\x08\x02\x06\x01\
+    \x12\x04\xbb\x01\x15\x1b\n\r\n\x05\x04\x08\x02\x06\x03\x12\x04\xbb\x01\
+    \x1e\x1fb\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
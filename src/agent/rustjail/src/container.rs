@@ -28,7 +28,7 @@ use crate::specconv::CreateOpts;
 // use crate::stats::Stats;
 use crate::capabilities::{self, CAPSMAP};
 use crate::cgroups::fs::{self as fscgroup, Manager as FsManager};
-use crate::{mount, validator};
+use crate::{mount, trace, validator};
 
 use protocols::agent::StatsContainerResponse;
 
@@ -821,6 +821,7 @@ fn join_namespaces(
     parent: &mut u32,
 ) -> Result<(Pid, RawFd)> {
     let logger = logger.new(o!("action" => "join-namespaces"));
+    let _span = trace::span("join_namespaces", &[]);
 
     // let ccond = Cond::new().chain_err(|| "create cond failed")?;
     // let pcond = Cond::new().chain_err(|| "create cond failed")?;
@@ -1442,6 +1443,7 @@ use std::time::Duration;
 
 fn execute_hook(logger: &Logger, h: &Hook, st: &OCIState) -> Result<()> {
     let logger = logger.new(o!("action" => "execute-hook"));
+    let _span = trace::span("execute_hook", &[("path", h.Path.as_str())]);
 
     let binary = PathBuf::from(h.Path.as_str());
     let path = binary.canonicalize()?;
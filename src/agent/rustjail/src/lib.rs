@@ -47,7 +47,9 @@ pub mod container;
 pub mod errors;
 pub mod mount;
 pub mod process;
+pub mod securejoin;
 pub mod specconv;
+pub mod trace;
 // pub mod sync;
 pub mod capabilities;
 pub mod validator;
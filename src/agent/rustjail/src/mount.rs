@@ -23,6 +23,7 @@ use std::io::{BufRead, BufReader};
 
 use crate::container::DEFAULT_DEVICES;
 use crate::errors::*;
+use crate::securejoin::{open_in_root, secure_join, secure_join_nofollow};
 use lazy_static;
 use std::string::ToString;
 
@@ -53,6 +54,11 @@ lazy_static! {
         m.insert("shared", MsFlags::MS_SHARED | MsFlags::MS_REC);
         m.insert("private", MsFlags::MS_PRIVATE | MsFlags::MS_REC);
         m.insert("slave", MsFlags::MS_SLAVE | MsFlags::MS_REC);
+        m.insert("unbindable", MsFlags::MS_UNBINDABLE | MsFlags::MS_REC);
+        m.insert("rshared", MsFlags::MS_SHARED | MsFlags::MS_REC);
+        m.insert("rprivate", MsFlags::MS_PRIVATE | MsFlags::MS_REC);
+        m.insert("rslave", MsFlags::MS_SLAVE | MsFlags::MS_REC);
+        m.insert("runbindable", MsFlags::MS_UNBINDABLE | MsFlags::MS_REC);
         m
     };
     static ref OPTIONS: HashMap<&'static str, (bool, MsFlags)> = {
@@ -147,9 +153,9 @@ pub fn init_rootfs(
     let olddir = unistd::getcwd()?;
     unistd::chdir(rootfs)?;
 
-    default_symlinks()?;
-    create_devices(&linux.Devices, bind_device)?;
-    ensure_ptmx()?;
+    default_symlinks(rootfs)?;
+    create_devices(rootfs, &linux.Devices, bind_device)?;
+    ensure_ptmx(rootfs)?;
 
     unistd::chdir(&olddir)?;
 
@@ -205,7 +211,8 @@ fn mount_cgroups(
             // already mounted, xxx,yyy style cgroup
             if key != base {
                 let src = format!("{}/{}", m.destination.as_str(), key);
-                unix::fs::symlink(destination.as_str(), &src[1..])?;
+                let src = secure_join_nofollow(Path::new(rootfs), Path::new(&src))?;
+                unix::fs::symlink(destination.as_str(), &src)?;
             }
 
             continue;
@@ -234,7 +241,8 @@ fn mount_cgroups(
 
         if key != base {
             let src = format!("{}/{}", m.destination.as_str(), key);
-            match unix::fs::symlink(destination.as_str(), &src[1..]) {
+            let src = secure_join_nofollow(Path::new(rootfs), Path::new(&src))?;
+            match unix::fs::symlink(destination.as_str(), &src) {
                 Err(e) => {
                     info!(
                         logger,
@@ -253,10 +261,11 @@ fn mount_cgroups(
     unistd::chdir(&olddir)?;
 
     if flags.contains(MsFlags::MS_RDONLY) {
-        let dest = format!("{}{}", rootfs, m.destination.as_str());
+        let dest = secure_join(Path::new(rootfs), Path::new(&m.destination))?;
+        let dest = dest.to_str().unwrap();
         mount::mount(
-            Some(dest.as_str()),
-            dest.as_str(),
+            Some(dest),
+            dest,
             None::<&str>,
             flags | MsFlags::MS_BIND | MsFlags::MS_REMOUNT,
             None::<&str>,
@@ -427,8 +436,24 @@ fn parse_mount(m: &Mount) -> (MsFlags, String) {
 }
 
 fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str) -> Result<()> {
+    // mount(2) would only change the propagation of the destination,
+    // which is set apart once mounted
+    let propagation = flags
+        & (MsFlags::MS_SHARED | MsFlags::MS_PRIVATE | MsFlags::MS_SLAVE | MsFlags::MS_UNBINDABLE);
+    let flags = flags & !propagation;
+
     let d = String::from(data);
-    let dest = format!("{}{}", rootfs, &m.destination);
+    let dest = secure_join(Path::new(rootfs), Path::new(&m.destination))?;
+    let dest = match dest.to_str() {
+        Some(dest) => dest.to_string(),
+        None => {
+            return Err(ErrorKind::ErrorCode(format!(
+                "invalid mount destination {}",
+                dest.display()
+            ))
+            .into())
+        }
+    };
 
     let src = if m.field_type.as_str() == "bind" {
         let src = fs::canonicalize(m.source.as_str())?;
@@ -442,12 +467,7 @@ fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str)
         match fs::create_dir_all(&dir) {
             Ok(_) => {}
             Err(e) => {
-                info!(
-                    sl!(),
-                    "creat dir {}: {}",
-                    dir.to_str().unwrap(),
-                    e.to_string()
-                );
+                info!(sl!(), "creat dir {}: {}", dir.display(), e.to_string());
             }
         }
 
@@ -461,7 +481,7 @@ fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str)
         PathBuf::from(&m.source)
     };
 
-    info!(sl!(), "{}, {}", src.to_str().unwrap(), dest.as_str());
+    info!(sl!(), "{}, {}", src.display(), dest.as_str());
 
     // ignore this check since some mount's src didn't been a directory
     // such as tmpfs.
@@ -481,9 +501,15 @@ fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str)
         }
     }
 
+    // mount through a descriptor of the destination, should it have been
+    // replaced by a symlink since it was resolved
+    let fd = open_in_root(Path::new(rootfs), Path::new(&m.destination), OFlag::O_PATH)?;
+    defer!(unistd::close(fd).unwrap_or(()));
+    let target = format!("/proc/self/fd/{}", fd);
+
     match mount::mount(
-        Some(src.to_str().unwrap()),
-        dest.as_str(),
+        Some(src.as_path()),
+        target.as_str(),
         Some(m.field_type.as_str()),
         flags,
         Some(d.as_str()),
@@ -505,9 +531,15 @@ fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str)
                 | MsFlags::MS_SLAVE),
         )
     {
+        // the descriptor above is for what is under the new mount,
+        // open it again to reach the mount itself
+        let fd = open_in_root(Path::new(rootfs), Path::new(&m.destination), OFlag::O_PATH)?;
+        defer!(unistd::close(fd).unwrap_or(()));
+        let target = format!("/proc/self/fd/{}", fd);
+
         match mount::mount(
-            Some(dest.as_str()),
-            dest.as_str(),
+            Some(target.as_str()),
+            target.as_str(),
             None::<&str>,
             flags | MsFlags::MS_REMOUNT,
             None::<&str>,
@@ -524,6 +556,30 @@ fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str)
             Ok(_) => {}
         }
     }
+
+    if !propagation.is_empty() {
+        let fd = open_in_root(Path::new(rootfs), Path::new(&m.destination), OFlag::O_PATH)?;
+        defer!(unistd::close(fd).unwrap_or(()));
+        let target = format!("/proc/self/fd/{}", fd);
+
+        // MS_REC makes the propagation recursive as well
+        if let Err(e) = mount::mount(
+            None::<&str>,
+            target.as_str(),
+            None::<&str>,
+            propagation | (flags & MsFlags::MS_REC),
+            None::<&str>,
+        ) {
+            info!(
+                sl!(),
+                "propagation of {}: {}",
+                dest.as_str(),
+                e.as_errno().unwrap().desc()
+            );
+            return Err(e.into());
+        }
+    }
+
     Ok(())
 }
 
@@ -534,35 +590,41 @@ static SYMLINKS: &'static [(&'static str, &'static str)] = &[
     ("/proc/self/fd/2", "dev/stderr"),
 ];
 
-fn default_symlinks() -> Result<()> {
+fn default_symlinks(rootfs: &str) -> Result<()> {
+    let root = Path::new(rootfs);
     if Path::new("/proc/kcore").exists() {
-        unix::fs::symlink("/proc/kcore", "dev/kcore")?;
+        unix::fs::symlink(
+            "/proc/kcore",
+            secure_join_nofollow(root, Path::new("dev/kcore"))?,
+        )?;
     }
     for &(src, dst) in SYMLINKS {
-        unix::fs::symlink(src, dst)?;
+        unix::fs::symlink(src, secure_join_nofollow(root, Path::new(dst))?)?;
     }
     Ok(())
 }
-fn create_devices(devices: &[LinuxDevice], bind: bool) -> Result<()> {
-    let op: fn(&LinuxDevice) -> Result<()> = if bind { bind_dev } else { mknod_dev };
+fn create_devices(rootfs: &str, devices: &[LinuxDevice], bind: bool) -> Result<()> {
+    let op: fn(&Path, &LinuxDevice) -> Result<()> = if bind { bind_dev } else { mknod_dev };
+    let root = Path::new(rootfs);
     let old = stat::umask(Mode::from_bits_truncate(0o000));
     for dev in DEFAULT_DEVICES.iter() {
-        op(dev)?;
+        op(&secure_join(root, Path::new(&dev.Path))?, dev)?;
     }
     for dev in devices {
         if !dev.Path.starts_with("/dev") || dev.Path.contains("..") {
             let msg = format!("{} is not a valid device path", dev.Path);
             bail!(ErrorKind::ErrorCode(msg));
         }
-        op(dev)?;
+        op(&secure_join(root, Path::new(&dev.Path))?, dev)?;
     }
     stat::umask(old);
     Ok(())
 }
 
-fn ensure_ptmx() -> Result<()> {
-    let _ = fs::remove_file("dev/ptmx");
-    unix::fs::symlink("pts/ptmx", "dev/ptmx")?;
+fn ensure_ptmx(rootfs: &str) -> Result<()> {
+    let ptmx = secure_join_nofollow(Path::new(rootfs), Path::new("dev/ptmx"))?;
+    let _ = fs::remove_file(&ptmx);
+    unix::fs::symlink("pts/ptmx", &ptmx)?;
     Ok(())
 }
 
@@ -580,21 +642,21 @@ lazy_static! {
     };
 }
 
-fn mknod_dev(dev: &LinuxDevice) -> Result<()> {
+fn mknod_dev(path: &Path, dev: &LinuxDevice) -> Result<()> {
     let f = match LINUXDEVICETYPE.get(dev.Type.as_str()) {
         Some(v) => v,
         None => return Err(ErrorKind::ErrorCode("invalid spec".to_string()).into()),
     };
 
     stat::mknod(
-        &dev.Path[1..],
+        path,
         *f,
         Mode::from_bits_truncate(dev.FileMode),
         makedev(dev.Major as u64, dev.Minor as u64),
     )?;
 
     unistd::chown(
-        &dev.Path[1..],
+        path,
         Some(Uid::from_raw(dev.UID as uid_t)),
         Some(Gid::from_raw(dev.GID as uid_t)),
     )?;
@@ -602,10 +664,10 @@ fn mknod_dev(dev: &LinuxDevice) -> Result<()> {
     Ok(())
 }
 
-fn bind_dev(dev: &LinuxDevice) -> Result<()> {
+fn bind_dev(path: &Path, dev: &LinuxDevice) -> Result<()> {
     let fd = fcntl::open(
-        &dev.Path[1..],
-        OFlag::O_RDWR | OFlag::O_CREAT,
+        path,
+        OFlag::O_RDWR | OFlag::O_CREAT | OFlag::O_NOFOLLOW,
         Mode::from_bits_truncate(0o644),
     )?;
 
@@ -613,7 +675,7 @@ fn bind_dev(dev: &LinuxDevice) -> Result<()> {
 
     mount::mount(
         Some(&*dev.Path),
-        &dev.Path[1..],
+        path,
         None::<&str>,
         MsFlags::MS_BIND,
         None::<&str>,
@@ -628,12 +690,15 @@ pub fn finish_rootfs(spec: &Spec) -> Result<()> {
     if spec.Linux.is_some() {
         let linux = spec.Linux.as_ref().unwrap();
 
+        // the container rootfs, pivoted to by now
+        let root = Path::new("/");
+
         for path in linux.MaskedPaths.iter() {
-            mask_path(path)?;
+            mask_path(root, path)?;
         }
 
         for path in linux.ReadonlyPaths.iter() {
-            readonly_path(path)?;
+            readonly_path(root, path)?;
         }
     }
 
@@ -663,72 +728,68 @@ pub fn finish_rootfs(spec: &Spec) -> Result<()> {
     Ok(())
 }
 
-fn mask_path(path: &str) -> Result<()> {
+// mask_path hides path, in the container rootfs root, under /dev/null.
+fn mask_path(root: &Path, path: &str) -> Result<()> {
     if !path.starts_with("/") || path.contains("..") {
         return Err(nix::Error::Sys(Errno::EINVAL).into());
     }
 
-    //info!("{}", path);
+    // mount through a descriptor opened in root, for neither symlinks nor
+    // /proc magic links to lead out of it
+    let fd = match open_in_root(root, Path::new(path), OFlag::O_PATH) {
+        Ok(fd) => fd,
+        Err(Error(ErrorKind::Nix(nix::Error::Sys(e)), _))
+            if e == Errno::ENOENT || e == Errno::ENOTDIR =>
+        {
+            return Ok(())
+        }
+        Err(e) => return Err(e),
+    };
+    defer!(unistd::close(fd).unwrap_or(()));
+    let target = format!("/proc/self/fd/{}", fd);
 
-    match mount::mount(
+    mount::mount(
         Some("/dev/null"),
-        path,
+        target.as_str(),
         None::<&str>,
         MsFlags::MS_BIND,
         None::<&str>,
-    ) {
-        Err(nix::Error::Sys(e)) => {
-            if e != Errno::ENOENT && e != Errno::ENOTDIR {
-                //info!("{}: {}", path, e.desc());
-                return Err(nix::Error::Sys(e).into());
-            }
-        }
-
-        Err(e) => {
-            //info!("{}: {}", path, e.as_errno().unwrap().desc());
-            return Err(e.into());
-        }
-
-        Ok(_) => {}
-    }
+    )?;
 
     Ok(())
 }
 
-fn readonly_path(path: &str) -> Result<()> {
+// readonly_path makes path, in the container rootfs root, read-only.
+fn readonly_path(root: &Path, path: &str) -> Result<()> {
     if !path.starts_with("/") || path.contains("..") {
         return Err(nix::Error::Sys(Errno::EINVAL).into());
     }
 
-    //info!("{}", path);
+    let fd = match open_in_root(root, Path::new(path), OFlag::O_PATH) {
+        Ok(fd) => fd,
+        Err(Error(ErrorKind::Nix(nix::Error::Sys(Errno::ENOENT)), _)) => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    defer!(unistd::close(fd).unwrap_or(()));
+    let target = format!("/proc/self/fd/{}", fd);
 
-    match mount::mount(
-        Some(&path[1..]),
-        path,
+    mount::mount(
+        Some(target.as_str()),
+        target.as_str(),
         None::<&str>,
         MsFlags::MS_BIND | MsFlags::MS_REC,
         None::<&str>,
-    ) {
-        Err(nix::Error::Sys(e)) => {
-            if e == Errno::ENOENT {
-                return Ok(());
-            } else {
-                //info!("{}: {}", path, e.desc());
-                return Err(nix::Error::Sys(e).into());
-            }
-        }
-
-        Err(e) => {
-            //info!("{}: {}", path, e.as_errno().unwrap().desc());
-            return Err(e.into());
-        }
+    )?;
 
-        Ok(_) => {}
-    }
+    // the descriptor above is for what is under the new mount, open it
+    // again to reach the mount itself
+    let fd = open_in_root(root, Path::new(path), OFlag::O_PATH)?;
+    defer!(unistd::close(fd).unwrap_or(()));
+    let target = format!("/proc/self/fd/{}", fd);
 
     mount::mount(
-        Some(&path[1..]),
-        &path[1..],
+        Some(target.as_str()),
+        target.as_str(),
         None::<&str>,
         MsFlags::MS_BIND | MsFlags::MS_REC | MsFlags::MS_RDONLY | MsFlags::MS_REMOUNT,
         None::<&str>,
@@ -12,7 +12,7 @@ use std::os::unix::io::RawFd;
 // use crate::cgroups::Manager as CgroupManager;
 // use crate::intelrdt::Manager as RdtManager;
 
-use nix::fcntl::OFlag;
+use nix::fcntl::{self, FcntlArg, OFlag};
 use nix::sys::signal::{self, Signal};
 use nix::sys::socket::{self, AddressFamily, SockFlag, SockType};
 use nix::sys::wait::{self, WaitStatus};
@@ -138,4 +138,14 @@ impl Process {
 
         Ok(p)
     }
+
+    // set_pipe_size sets the capacity of the stdio pipes, in bytes, which
+    // the kernel rounds up to a power of two pages.
+    pub fn set_pipe_size(&self, size: i32) -> Result<()> {
+        for fd in [self.stdin, self.stdout, self.stderr].iter().flatten() {
+            fcntl::fcntl(*fd, FcntlArg::F_SETPIPE_SZ(size))?;
+        }
+
+        Ok(())
+    }
 }
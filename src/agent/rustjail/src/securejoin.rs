@@ -0,0 +1,261 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Resolve paths inside a container rootfs the way the container itself
+// would, so that symlinks planted in an untrusted image cannot send a
+// mount, a device node or a symlink out of the rootfs.
+
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::Mode;
+use nix::unistd;
+use std::collections::VecDeque;
+use std::ffi::{CString, OsString};
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::RawFd;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::errors::*;
+
+// the kernel gives up after as many symlinks
+const MAX_SYMLINKS: u32 = 40;
+
+const SYS_OPENAT2: libc::c_long = 437;
+const RESOLVE_NO_MAGICLINKS: u64 = 0x02;
+const RESOLVE_IN_ROOT: u64 = 0x10;
+
+#[repr(C)]
+struct open_how {
+    flags: u64,
+    mode: u64,
+    resolve: u64,
+}
+
+// set once openat2 turned out to be missing, kernels before 5.6
+static NO_OPENAT2: AtomicBool = AtomicBool::new(false);
+
+// secure_join returns the path unsafe_path refers to inside root. The
+// symlinks met on the way are resolved relative to root, absolute ones
+// included, and ".." stops at root, so the result cannot be out of root.
+// What does not exist yet is appended as is, to be created by the caller.
+pub fn secure_join(root: &Path, unsafe_path: &Path) -> Result<PathBuf> {
+    let mut pending: VecDeque<OsString> = components(unsafe_path).into_iter().collect();
+    let mut resolved: Vec<OsString> = Vec::new();
+    let mut links = 0;
+
+    while let Some(c) = pending.pop_front() {
+        if c == ".." {
+            resolved.pop();
+            continue;
+        }
+
+        let path = join(root, &resolved).join(&c);
+        let meta = match fs::symlink_metadata(&path) {
+            Ok(meta) => meta,
+            Err(ref e) if not_found(e) => {
+                resolved.push(c);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if !meta.file_type().is_symlink() {
+            resolved.push(c);
+            continue;
+        }
+
+        links += 1;
+        if links > MAX_SYMLINKS {
+            return Err(ErrorKind::Nix(nix::Error::Sys(Errno::ELOOP)).into());
+        }
+
+        let target = fs::read_link(&path)?;
+        if target.is_absolute() {
+            resolved.clear();
+        }
+
+        for t in components(&target).into_iter().rev() {
+            pending.push_front(t);
+        }
+    }
+
+    Ok(join(root, &resolved))
+}
+
+// secure_join_nofollow is secure_join, except that the last component
+// is not resolved. It is meant for creating or removing symlinks.
+pub fn secure_join_nofollow(root: &Path, unsafe_path: &Path) -> Result<PathBuf> {
+    let mut parts = components(unsafe_path);
+
+    match parts.pop() {
+        Some(ref last) if last != ".." => {
+            let parent: PathBuf = parts.iter().collect();
+            Ok(secure_join(root, &parent)?.join(last))
+        }
+        _ => secure_join(root, unsafe_path),
+    }
+}
+
+// open_in_root opens unsafe_path inside root. The kernel resolves it by
+// itself through openat2(RESOLVE_IN_ROOT) where it can, which leaves no
+// window for the path to change in between, secure_join does otherwise.
+pub fn open_in_root(root: &Path, unsafe_path: &Path, flags: OFlag) -> Result<RawFd> {
+    let flags = flags | OFlag::O_CLOEXEC;
+
+    if !NO_OPENAT2.load(Ordering::Relaxed) {
+        let dirfd = fcntl::open(
+            root,
+            OFlag::O_PATH | OFlag::O_DIRECTORY | OFlag::O_CLOEXEC,
+            Mode::empty(),
+        )?;
+        let res = openat2(dirfd, unsafe_path, flags);
+        let _ = unistd::close(dirfd);
+
+        match res {
+            Err(nix::Error::Sys(Errno::ENOSYS)) => NO_OPENAT2.store(true, Ordering::Relaxed),
+            res => return Ok(res?),
+        }
+    }
+
+    let path = secure_join(root, unsafe_path)?;
+    Ok(fcntl::open(
+        &path,
+        flags | OFlag::O_NOFOLLOW,
+        Mode::empty(),
+    )?)
+}
+
+fn openat2(dirfd: RawFd, path: &Path, flags: OFlag) -> nix::Result<RawFd> {
+    let path =
+        CString::new(path.as_os_str().as_bytes()).map_err(|_| nix::Error::Sys(Errno::EINVAL))?;
+    let how = open_how {
+        flags: flags.bits() as u64,
+        mode: 0,
+        resolve: RESOLVE_IN_ROOT | RESOLVE_NO_MAGICLINKS,
+    };
+
+    let res = unsafe {
+        libc::syscall(
+            SYS_OPENAT2,
+            dirfd,
+            path.as_ptr(),
+            &how as *const open_how,
+            mem::size_of::<open_how>(),
+        )
+    };
+
+    Errno::result(res).map(|fd| fd as RawFd)
+}
+
+// components splits path into names and "..", dropping the root and ".".
+fn components(path: &Path) -> Vec<OsString> {
+    path.components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_os_string()),
+            Component::ParentDir => Some(OsString::from("..")),
+            _ => None,
+        })
+        .collect()
+}
+
+fn join(root: &Path, parts: &[OsString]) -> PathBuf {
+    let mut path = root.to_path_buf();
+    path.extend(parts);
+    path
+}
+
+fn not_found(e: &io::Error) -> bool {
+    e.kind() == io::ErrorKind::NotFound || e.raw_os_error() == Some(libc::ENOTDIR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_secure_join() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        fs::create_dir_all(root.join("etc/ssl")).unwrap();
+        fs::write(root.join("etc/passwd"), "").unwrap();
+        symlink("/etc", root.join("abs")).unwrap();
+        symlink("../../../../etc", root.join("etc/ssl/up")).unwrap();
+        symlink("ssl", root.join("etc/rel")).unwrap();
+        symlink("/proc/self/root/etc", root.join("magic")).unwrap();
+        symlink("loop", root.join("loop")).unwrap();
+
+        let cases = [
+            ("/", ""),
+            ("/etc/passwd", "etc/passwd"),
+            ("etc/./ssl//", "etc/ssl"),
+            ("/../../etc", "etc"),
+            ("/abs/passwd", "etc/passwd"),
+            ("/etc/ssl/up/passwd", "etc/passwd"),
+            ("/etc/rel/../passwd", "etc/passwd"),
+            ("/abs/new/dir", "etc/new/dir"),
+            ("/new/../etc", "etc"),
+            ("/etc/passwd/file", "etc/passwd/file"),
+            ("/magic", "proc/self/root/etc"),
+        ];
+
+        for (path, expected) in cases.iter() {
+            assert_eq!(
+                secure_join(root, Path::new(path)).unwrap(),
+                root.join(expected),
+                "{}",
+                path
+            );
+        }
+
+        let err = secure_join(root, Path::new("/loop")).unwrap_err();
+        match err.kind() {
+            ErrorKind::Nix(nix::Error::Sys(Errno::ELOOP)) => {}
+            _ => panic!("unexpected error {:?}", err),
+        }
+
+        assert_eq!(
+            secure_join_nofollow(root, Path::new("/abs/rel")).unwrap(),
+            root.join("etc/rel")
+        );
+        assert_eq!(
+            secure_join_nofollow(root, Path::new("/abs")).unwrap(),
+            root.join("abs")
+        );
+        assert_eq!(
+            secure_join_nofollow(root, Path::new("/etc/..")).unwrap(),
+            root.to_path_buf()
+        );
+    }
+
+    #[test]
+    fn test_open_in_root() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        fs::create_dir(root.join("etc")).unwrap();
+        fs::write(root.join("etc/hostname"), "inside").unwrap();
+        symlink("/etc/hostname", root.join("hostname")).unwrap();
+
+        // both the openat2 path and the fallback must stay in root
+        for fallback in [false, true].iter() {
+            NO_OPENAT2.store(*fallback, Ordering::Relaxed);
+
+            let fd = open_in_root(root, Path::new("/hostname"), OFlag::O_RDONLY).unwrap();
+            let mut buf = [0u8; 16];
+            let n = unistd::read(fd, &mut buf).unwrap();
+            unistd::close(fd).unwrap();
+            assert_eq!(&buf[..n], b"inside");
+
+            assert!(open_in_root(root, Path::new("/missing"), OFlag::O_RDONLY).is_err());
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Spans of the steps taken here, for the agent to trace them: it sets the
+// hook starting a span, which lasts until the guard returned is dropped.
+
+use std::any::Any;
+use std::sync::RwLock;
+
+pub type SpanHook = fn(&str, &[(&str, &str)]) -> Box<dyn Any>;
+
+lazy_static! {
+    static ref SPAN_HOOK: RwLock<Option<SpanHook>> = RwLock::new(None);
+}
+
+pub fn set_span_hook(hook: SpanHook) {
+    *SPAN_HOOK.write().unwrap() = Some(hook);
+}
+
+// span starts the span name with attributes, when there is a hook.
+pub fn span(name: &str, attributes: &[(&str, &str)]) -> Option<Box<dyn Any>> {
+    SPAN_HOOK.read().unwrap().map(|hook| hook(name, attributes))
+}
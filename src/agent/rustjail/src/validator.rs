@@ -216,6 +216,51 @@ fn sysctl(oci: &Spec) -> Result<()> {
     Ok(())
 }
 
+static PROPAGATIONS: &'static [&'static str] = &[
+    "shared",
+    "rshared",
+    "private",
+    "rprivate",
+    "slave",
+    "rslave",
+    "unbindable",
+    "runbindable",
+];
+
+// mount_propagation checks that each mount has at most one propagation
+// and that the rootfs propagation, which applies to the mounts of the
+// sandbox below them, allows it: mounts can only propagate back to the
+// sandbox if the rootfs is shared, and receive from it unless private.
+fn mount_propagation(oci: &Spec) -> Result<()> {
+    let rootfs = oci.Linux.as_ref().unwrap().RootfsPropagation.as_str();
+    let rootfs_shared = rootfs == "shared" || rootfs == "rshared";
+    // the rootfs is made a slave when its propagation is not set
+    let rootfs_private = !rootfs_shared && rootfs != "" && rootfs != "slave" && rootfs != "rslave";
+
+    for m in oci.Mounts.iter() {
+        let propagation: Vec<&str> = m
+            .options
+            .iter()
+            .map(String::as_str)
+            .filter(|o| PROPAGATIONS.contains(o))
+            .collect();
+
+        let ok = match propagation.as_slice() {
+            [] => true,
+            ["shared"] | ["rshared"] => rootfs_shared,
+            ["slave"] | ["rslave"] => !rootfs_private,
+            [_] => true,
+            _ => false,
+        };
+
+        if !ok {
+            return Err(ErrorKind::Nix(Error::from_errno(Errno::EINVAL)).into());
+        }
+    }
+
+    Ok(())
+}
+
 fn rootless_euid_mapping(oci: &Spec) -> Result<()> {
     let linux = oci.Linux.as_ref().unwrap();
     if !contain_namespace(&linux.Namespaces, "user") {
@@ -295,6 +340,7 @@ pub fn validate(conf: &Config) -> Result<()> {
     usernamespace(oci)?;
     cgroupnamespace(oci)?;
     sysctl(&oci)?;
+    mount_propagation(oci)?;
 
     if conf.rootless_euid {
         rootless_euid(oci)?;
@@ -302,3 +348,38 @@ pub fn validate(conf: &Config) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protocols::oci::{Linux, Mount};
+
+    #[test]
+    fn test_mount_propagation() {
+        let spec = |rootfs: &str, options: &[&str]| {
+            let mut linux = Linux::new();
+            linux.RootfsPropagation = rootfs.to_string();
+
+            let mut m = Mount::new();
+            m.destination = "/data".to_string();
+            m.field_type = "bind".to_string();
+            m.options = RepeatedField::from_vec(options.iter().map(|o| o.to_string()).collect());
+
+            let mut spec = Spec::new();
+            spec.set_Linux(linux);
+            spec.Mounts = RepeatedField::from_vec(vec![m]);
+            spec
+        };
+
+        assert!(mount_propagation(&spec("", &["rbind", "ro"])).is_ok());
+        assert!(mount_propagation(&spec("rshared", &["rbind", "rshared"])).is_ok());
+        assert!(mount_propagation(&spec("shared", &["bind", "shared"])).is_ok());
+        assert!(mount_propagation(&spec("", &["rbind", "rslave"])).is_ok());
+        assert!(mount_propagation(&spec("rprivate", &["rbind", "rprivate"])).is_ok());
+
+        assert!(mount_propagation(&spec("", &["rbind", "rshared"])).is_err());
+        assert!(mount_propagation(&spec("rslave", &["rbind", "rshared"])).is_err());
+        assert!(mount_propagation(&spec("private", &["rbind", "rslave"])).is_err());
+        assert!(mount_propagation(&spec("rshared", &["rshared", "rslave"])).is_err());
+    }
+}
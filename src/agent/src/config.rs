@@ -3,27 +3,162 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 use rustjail::errors::*;
+use std::collections::HashSet;
 use std::fs;
+use std::path::Path;
 use std::time;
 
 const DEBUG_CONSOLE_FLAG: &'static str = "agent.debug_console";
 const DEV_MODE_FLAG: &'static str = "agent.devmode";
 const LOG_LEVEL_FLAG: &'static str = "agent.log";
+const LOG_VPORT_FLAG: &'static str = "agent.log_vport";
 const HOTPLUG_TIMOUT_FLAG: &'static str = "agent.hotplug_timeout";
+const SERVER_TYPE_FLAG: &'static str = "agent.server_type";
+const SERVER_ADDR_FLAG: &'static str = "agent.server_addr";
+const CONFIG_FILE_FLAG: &'static str = "agent.config_file";
+const TRACE_MODE_FLAG: &'static str = "agent.trace";
+const TRACE_ADDR_FLAG: &'static str = "agent.trace_addr";
+const POLICY_FILE_FLAG: &'static str = "agent.policy_file";
+const TLS_DIR_FLAG: &'static str = "agent.tls_dir";
+const TLS_STORAGE_FLAG: &'static str = "agent.tls_storage";
+
+// where the guest image may bring its configuration
+const DEFAULT_CONFIG_FILE: &'static str = "/etc/kata-containers/agent.toml";
 
 const DEFAULT_LOG_LEVEL: slog::Level = slog::Level::Info;
 const DEFAULT_HOTPLUG_TIMEOUT: time::Duration = time::Duration::from_secs(3);
 
-// FIXME: unused
-const TRACE_MODE_FLAG: &'static str = "agent.trace";
-const USE_VSOCK_FLAG: &'static str = "agent.use_vsock";
+// any CID, port 1024
+const DEFAULT_SERVER_ADDR: ServerAddr = ServerAddr::Vsock(libc::VMADDR_CID_ANY, 1024);
+
+// the host, port 10240
+const DEFAULT_TRACE_ADDR: ServerAddr = ServerAddr::Vsock(libc::VMADDR_CID_HOST, 10240);
+
+const VSOCK_SCHEME: &'static str = "vsock://";
+const UNIX_SCHEME: &'static str = "unix://";
+const VIRTIO_PORTS_DIR: &'static str = "/dev/virtio-ports/";
+
+// ServerType is the protocol the agent serves the runtime with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ServerType {
+    Grpc,
+    // lighter than gRPC, as containerd shims use, without streams: it
+    // serves all the RPCs but GetNetworkEvents
+    Ttrpc,
+}
+
+// ServerAddr is where the agent serves the runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerAddr {
+    // CID and port
+    Vsock(u32, u16),
+    Unix(String),
+    // a virtio-serial port, for hypervisors without vsock
+    Serial(String),
+}
+
+// TlsStorage is a storage which brings the TLS certificates at boot, by its
+// filesystem type and source, e.g. "ext4:/dev/vdb" or "virtiofs:certs".
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsStorage {
+    pub fstype: String,
+    pub source: String,
+}
+
+// the methods of the agent service, which allowed_rpcs names
+pub const AGENT_RPCS: &[&str] = &[
+    "CreateContainer",
+    "StartContainer",
+    "RemoveContainer",
+    "ExecProcess",
+    "SignalProcess",
+    "WaitProcess",
+    "ListProcesses",
+    "UpdateContainer",
+    "StatsContainer",
+    "PauseContainer",
+    "ResumeContainer",
+    "WriteStdin",
+    "ReadStdout",
+    "ReadStderr",
+    "CloseStdin",
+    "TtyWinResize",
+    "UpdateInterface",
+    "UpdateRoutes",
+    "ListInterfaces",
+    "ListRoutes",
+    "GetNetworkEvents",
+    "UpdateTrafficControl",
+    "ListTrafficControl",
+    "StartTracing",
+    "StopTracing",
+    "CreateSandbox",
+    "DestroySandbox",
+    "OnlineCPUMem",
+    "ReseedRandomDev",
+    "GetGuestDetails",
+    "MemHotplugByProbe",
+    "SetGuestDateTime",
+    "CopyFile",
+    "GetVolumeStats",
+    "ResizeVolume",
+    "SetLogLevel",
+    "GetMetrics",
+    "SetPolicy",
+];
+
+// ConfigFile is the TOML configuration file of the agent, all of whose
+// settings are optional, e.g.
+//
+//   log_level = "debug"
+//   server_addr = "vsock://-1:1024"
+//   allowed_rpcs = ["CreateContainer", "StartContainer", "WaitProcess"]
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    log_level: Option<String>,
+    log_vport: Option<u32>,
+    // in seconds
+    hotplug_timeout: Option<u64>,
+    server_type: Option<String>,
+    server_addr: Option<String>,
+    debug_console: Option<bool>,
+    dev_mode: Option<bool>,
+    // only "cgroupfs", as rustjail manages cgroups by their filesystem
+    cgroup_driver: Option<String>,
+    // in bytes
+    container_pipe_size: Option<u32>,
+    allowed_rpcs: Option<Vec<String>>,
+    tracing: Option<bool>,
+    trace_addr: Option<String>,
+    policy_file: Option<String>,
+    tls_dir: Option<String>,
+    tls_storage: Option<String>,
+}
 
 #[derive(Debug)]
 pub struct agentConfig {
     pub debug_console: bool,
     pub dev_mode: bool,
     pub log_level: slog::Level,
+    // the vsock port the logs are served on, 0 for the console
+    pub log_vport: u32,
     pub hotplug_timeout: time::Duration,
+    pub server_type: ServerType,
+    pub server_addr: ServerAddr,
+    // of the stdio pipes of the container processes, 0 for the default
+    pub container_pipe_size: u32,
+    // the methods of the agent service served, None for all of them
+    pub allowed_rpcs: Option<HashSet<String>>,
+    pub tracing: bool,
+    // where the trace collector of the host listens
+    pub trace_addr: ServerAddr,
+    // the authorization policy of the RPCs to load at boot
+    pub policy_file: Option<String>,
+    // where the certificates of the gRPC server with mutual TLS are, which
+    // the storage is mounted on if any
+    pub tls_dir: Option<String>,
+    pub tls_storage: Option<TlsStorage>,
 }
 
 impl agentConfig {
@@ -32,20 +167,72 @@ impl agentConfig {
             debug_console: false,
             dev_mode: false,
             log_level: DEFAULT_LOG_LEVEL,
+            log_vport: 0,
             hotplug_timeout: DEFAULT_HOTPLUG_TIMEOUT,
+            server_type: ServerType::Grpc,
+            server_addr: DEFAULT_SERVER_ADDR,
+            container_pipe_size: 0,
+            allowed_rpcs: None,
+            tracing: false,
+            trace_addr: DEFAULT_TRACE_ADDR,
+            policy_file: None,
+            tls_dir: None,
+            tls_storage: None,
         }
     }
 
+    // parse_cmdline reads the kernel command line in file, and the
+    // configuration file first, which the command line overrides.
     pub fn parse_cmdline(&mut self, file: &str) -> Result<()> {
         let cmdline = fs::read_to_string(file)?;
         let params: Vec<&str> = cmdline.split_ascii_whitespace().collect();
+
+        let mut config_file = None;
+        for param in params.iter() {
+            if param.starts_with(CONFIG_FILE_FLAG) {
+                config_file = Some(get_config_file(param)?);
+            }
+        }
+
+        match config_file {
+            Some(path) => self.parse_config_file(&path)?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                self.parse_config_file(DEFAULT_CONFIG_FILE)?
+            }
+            None => {}
+        }
+
         for param in params.iter() {
             if param.starts_with(DEBUG_CONSOLE_FLAG) {
-                self.debug_console = true;
+                self.debug_console = get_bool_value(param, DEBUG_CONSOLE_FLAG)?;
             }
 
             if param.starts_with(DEV_MODE_FLAG) {
-                self.dev_mode = true;
+                self.dev_mode = get_bool_value(param, DEV_MODE_FLAG)?;
+            }
+
+            if param.starts_with(TLS_DIR_FLAG) {
+                self.tls_dir = Some(get_tls_dir(param)?);
+            }
+
+            if param.starts_with(TLS_STORAGE_FLAG) {
+                self.tls_storage = Some(get_tls_storage(param)?);
+            }
+
+            if param.starts_with(POLICY_FILE_FLAG) {
+                self.policy_file = Some(get_policy_file(param)?);
+            }
+
+            if param.starts_with(TRACE_ADDR_FLAG) {
+                self.trace_addr = get_trace_addr(param)?;
+                // not the trace mode, which shares the prefix
+                continue;
+            }
+
+            if param.starts_with(LOG_VPORT_FLAG) {
+                self.log_vport = get_log_vport(param)?;
+                // not the log level, which shares the prefix
+                continue;
             }
 
             if param.starts_with(LOG_LEVEL_FLAG) {
@@ -60,10 +247,123 @@ impl agentConfig {
                     self.hotplug_timeout = hotplugTimeout;
                 }
             }
+
+            if param.starts_with(SERVER_TYPE_FLAG) {
+                self.server_type = get_server_type(param)?;
+            }
+
+            if param.starts_with(SERVER_ADDR_FLAG) {
+                self.server_addr = get_server_addr(param)?;
+            }
+
+            if param.starts_with(TRACE_MODE_FLAG) {
+                self.tracing = get_bool_value(param, TRACE_MODE_FLAG)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn parse_config_file(&mut self, path: &str) -> Result<()> {
+        fs::read_to_string(path)
+            .map_err(|e| e.into())
+            .and_then(|contents| self.parse_config(&contents))
+            .map_err(|e| {
+                ErrorKind::ErrorCode(format!("invalid configuration file {}: {}", path, e)).into()
+            })
+    }
+
+    fn parse_config(&mut self, contents: &str) -> Result<()> {
+        let file: ConfigFile =
+            toml::from_str(contents).map_err(|e| ErrorKind::ErrorCode(format!("{}", e)))?;
+
+        if let Some(level) = file.log_level {
+            self.log_level = logrus_to_slog_level(&level)?;
+        }
+
+        if let Some(port) = file.log_vport {
+            self.log_vport = port;
+        }
+
+        if let Some(timeout) = file.hotplug_timeout {
+            if timeout == 0 {
+                return Err(ErrorKind::ErrorCode(String::from("invalid hotplug timeout")).into());
+            }
+            self.hotplug_timeout = time::Duration::from_secs(timeout);
+        }
+
+        if let Some(server_type) = file.server_type {
+            self.server_type = parse_server_type(&server_type)?;
+        }
+
+        if let Some(addr) = file.server_addr {
+            self.server_addr = parse_server_addr(&addr)?;
+        }
+
+        if let Some(debug_console) = file.debug_console {
+            self.debug_console = debug_console;
+        }
+
+        if let Some(dev_mode) = file.dev_mode {
+            self.dev_mode = dev_mode;
+        }
+
+        if let Some(driver) = file.cgroup_driver {
+            if driver != "cgroupfs" {
+                return Err(ErrorKind::ErrorCode(format!(
+                    "cgroup driver {} not supported",
+                    driver
+                ))
+                .into());
+            }
+        }
+
+        if let Some(size) = file.container_pipe_size {
+            if size > i32::max_value() as u32 {
+                return Err(
+                    ErrorKind::ErrorCode(format!("invalid container pipe size {}", size)).into(),
+                );
+            }
+            self.container_pipe_size = size;
+        }
+
+        if let Some(rpcs) = file.allowed_rpcs {
+            if let Some(rpc) = rpcs.iter().find(|r| !AGENT_RPCS.contains(&r.as_str())) {
+                return Err(ErrorKind::ErrorCode(format!("unknown rpc {}", rpc)).into());
+            }
+            self.allowed_rpcs = Some(rpcs.into_iter().collect());
+        }
+
+        if let Some(tracing) = file.tracing {
+            self.tracing = tracing;
+        }
+
+        if let Some(addr) = file.trace_addr {
+            self.trace_addr = parse_trace_addr(&addr)?;
+        }
+
+        if let Some(path) = file.policy_file {
+            self.policy_file = Some(path);
+        }
+
+        if let Some(dir) = file.tls_dir {
+            self.tls_dir = Some(dir);
+        }
+
+        if let Some(storage) = file.tls_storage {
+            self.tls_storage = Some(parse_tls_storage(&storage)?);
         }
 
         Ok(())
     }
+
+    // is_allowed tells whether method of the agent service is served.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        match &self.allowed_rpcs {
+            Some(rpcs) => rpcs.contains(method),
+            None => true,
+        }
+    }
 }
 
 // Map logrus (https://godoc.org/github.com/sirupsen/logrus)
@@ -71,7 +371,7 @@ impl agentConfig {
 //
 // Note: Logrus names are used for compatability with the previous
 // golang-based agent.
-fn logrus_to_slog_level(logrus_level: &str) -> Result<slog::Level> {
+pub fn logrus_to_slog_level(logrus_level: &str) -> Result<slog::Level> {
     let level = match logrus_level {
         // Note: different semantics to logrus: log, but don't panic.
         "fatal" | "panic" => slog::Level::Critical,
@@ -93,6 +393,16 @@ fn logrus_to_slog_level(logrus_level: &str) -> Result<slog::Level> {
     Ok(level)
 }
 
+// get_bool_value returns the value of the boolean flag of param, which is
+// true unless set to false.
+fn get_bool_value(param: &str, flag: &str) -> Result<bool> {
+    match &param[flag.len()..] {
+        "" | "=true" => Ok(true),
+        "=false" => Ok(false),
+        _ => Err(ErrorKind::ErrorCode(format!("invalid {} parameter", flag)).into()),
+    }
+}
+
 fn get_log_level(param: &str) -> Result<slog::Level> {
     let fields: Vec<&str> = param.split("=").collect();
 
@@ -113,6 +423,18 @@ fn get_log_level(param: &str) -> Result<slog::Level> {
     Ok(level)
 }
 
+fn get_log_vport(param: &str) -> Result<u32> {
+    let fields: Vec<&str> = param.split("=").collect();
+
+    if fields.len() != 2 || fields[0] != LOG_VPORT_FLAG {
+        return Err(ErrorKind::ErrorCode(String::from("invalid log vport parameter")).into());
+    }
+
+    fields[1]
+        .parse::<u32>()
+        .map_err(|_| ErrorKind::ErrorCode(String::from("unable to parse log vport")).into())
+}
+
 fn get_hotplug_timeout(param: &str) -> Result<time::Duration> {
     let fields: Vec<&str> = param.split("=").collect();
 
@@ -133,6 +455,147 @@ fn get_hotplug_timeout(param: &str) -> Result<time::Duration> {
     Ok(time::Duration::from_secs(value.unwrap()))
 }
 
+fn get_server_type(param: &str) -> Result<ServerType> {
+    let fields: Vec<&str> = param.split("=").collect();
+
+    if fields.len() != 2 || fields[0] != SERVER_TYPE_FLAG {
+        return Err(ErrorKind::ErrorCode(String::from("invalid server type parameter")).into());
+    }
+
+    parse_server_type(fields[1])
+}
+
+fn parse_server_type(server_type: &str) -> Result<ServerType> {
+    match server_type {
+        "grpc" => Ok(ServerType::Grpc),
+        "ttrpc" => Ok(ServerType::Ttrpc),
+        _ => Err(ErrorKind::ErrorCode(String::from("invalid server type")).into()),
+    }
+}
+
+fn get_trace_addr(param: &str) -> Result<ServerAddr> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != TRACE_ADDR_FLAG {
+        return Err(ErrorKind::ErrorCode(String::from("invalid trace address parameter")).into());
+    }
+
+    parse_trace_addr(fields[1])
+}
+
+// parse_trace_addr parses the address of a trace collector, on vsock or a
+// unix socket.
+fn parse_trace_addr(addr: &str) -> Result<ServerAddr> {
+    match parse_server_addr(addr)? {
+        ServerAddr::Serial(_) => {
+            Err(ErrorKind::ErrorCode(format!("invalid trace address {}", addr)).into())
+        }
+        addr => Ok(addr),
+    }
+}
+
+fn get_config_file(param: &str) -> Result<String> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != CONFIG_FILE_FLAG || fields[1].is_empty() {
+        return Err(ErrorKind::ErrorCode(String::from("invalid config file parameter")).into());
+    }
+
+    Ok(fields[1].to_string())
+}
+
+fn get_policy_file(param: &str) -> Result<String> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != POLICY_FILE_FLAG || fields[1].is_empty() {
+        return Err(ErrorKind::ErrorCode(String::from("invalid policy file parameter")).into());
+    }
+
+    Ok(fields[1].to_string())
+}
+
+fn get_tls_dir(param: &str) -> Result<String> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != TLS_DIR_FLAG || fields[1].is_empty() {
+        return Err(ErrorKind::ErrorCode(String::from("invalid TLS directory parameter")).into());
+    }
+
+    Ok(fields[1].to_string())
+}
+
+fn get_tls_storage(param: &str) -> Result<TlsStorage> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != TLS_STORAGE_FLAG {
+        return Err(ErrorKind::ErrorCode(String::from("invalid TLS storage parameter")).into());
+    }
+
+    parse_tls_storage(fields[1])
+}
+
+// parse_tls_storage parses a storage of the TLS certificates as
+// <fstype>:<source>.
+fn parse_tls_storage(storage: &str) -> Result<TlsStorage> {
+    let fields: Vec<&str> = storage.splitn(2, ":").collect();
+
+    if fields.len() != 2 || fields[0].is_empty() || fields[1].is_empty() {
+        return Err(ErrorKind::ErrorCode(format!("invalid TLS storage {}", storage)).into());
+    }
+
+    Ok(TlsStorage {
+        fstype: fields[0].to_string(),
+        source: fields[1].to_string(),
+    })
+}
+
+fn get_server_addr(param: &str) -> Result<ServerAddr> {
+    let fields: Vec<&str> = param.splitn(2, "=").collect();
+
+    if fields.len() != 2 || fields[0] != SERVER_ADDR_FLAG {
+        return Err(ErrorKind::ErrorCode(String::from("invalid server address parameter")).into());
+    }
+
+    parse_server_addr(fields[1])
+}
+
+// parse_server_addr parses vsock://CID:PORT, with -1 for any CID,
+// unix:///path and /dev/virtio-ports/name.
+pub fn parse_server_addr(addr: &str) -> Result<ServerAddr> {
+    let invalid = || ErrorKind::ErrorCode(format!("invalid server address {}", addr));
+
+    if addr.starts_with(VSOCK_SCHEME) {
+        let fields: Vec<&str> = addr[VSOCK_SCHEME.len()..].split(':').collect();
+        if fields.len() != 2 {
+            return Err(invalid().into());
+        }
+
+        let cid = match fields[0].parse::<i64>() {
+            Ok(-1) => libc::VMADDR_CID_ANY,
+            Ok(cid) if cid >= 0 && cid < libc::VMADDR_CID_ANY as i64 => cid as u32,
+            _ => return Err(invalid().into()),
+        };
+        let port = fields[1].parse::<u16>().map_err(|_| invalid())?;
+
+        return Ok(ServerAddr::Vsock(cid, port));
+    }
+
+    if addr.starts_with(UNIX_SCHEME) {
+        let path = &addr[UNIX_SCHEME.len()..];
+        if !path.starts_with('/') {
+            return Err(invalid().into());
+        }
+
+        return Ok(ServerAddr::Unix(path.to_string()));
+    }
+
+    if addr.starts_with(VIRTIO_PORTS_DIR) && addr.len() > VIRTIO_PORTS_DIR.len() {
+        return Ok(ServerAddr::Serial(addr.to_string()));
+    }
+
+    Err(invalid().into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,6 +647,8 @@ mod tests {
         assert_eq!(config.dev_mode, false);
         assert_eq!(config.log_level, DEFAULT_LOG_LEVEL);
         assert_eq!(config.hotplug_timeout, DEFAULT_HOTPLUG_TIMEOUT);
+        assert_eq!(config.server_type, ServerType::Grpc);
+        assert_eq!(config.server_addr, DEFAULT_SERVER_ADDR);
     }
 
     #[test]
@@ -335,6 +800,12 @@ mod tests {
                 dev_mode: true,
                 hotplug_timeout: time::Duration::from_secs(3),
             },
+            TestData {
+                contents: "agent.devmode=true agent.debug_console=false",
+                debug_console: false,
+                dev_mode: true,
+                hotplug_timeout: time::Duration::from_secs(3),
+            },
         ];
 
         let dir = tempdir().expect("failed to create tmpdir");
@@ -613,4 +1084,285 @@ mod tests {
             assert_result!(d.result, result, format!("{}", msg));
         }
     }
+
+    #[test]
+    fn test_get_trace_addr() {
+        assert_eq!(
+            get_trace_addr("agent.trace_addr=vsock://2:10240").unwrap(),
+            ServerAddr::Vsock(libc::VMADDR_CID_HOST, 10240)
+        );
+        assert_eq!(
+            get_trace_addr("agent.trace_addr=unix:///run/collector.sock").unwrap(),
+            ServerAddr::Unix("/run/collector.sock".to_string())
+        );
+        assert!(get_trace_addr("agent.trace_addr=/dev/virtio-ports/trace").is_err());
+        assert!(get_trace_addr("agent.trace_addr=").is_err());
+
+        // apart from the trace mode, which shares the prefix
+        let dir = tempdir().unwrap();
+        let cmdline = dir.path().join("cmdline");
+        fs::write(&cmdline, "agent.trace_addr=vsock://2:4000 agent.trace").unwrap();
+
+        let mut config = agentConfig::new();
+        config.parse_cmdline(cmdline.to_str().unwrap()).unwrap();
+        assert_eq!(config.trace_addr, ServerAddr::Vsock(2, 4000));
+        assert!(config.tracing);
+    }
+
+    #[test]
+    fn test_get_policy_file() {
+        assert_eq!(
+            get_policy_file("agent.policy_file=/etc/policy.json").unwrap(),
+            "/etc/policy.json"
+        );
+        assert!(get_policy_file("agent.policy_file=").is_err());
+        assert!(get_policy_file("agent.policy_files=/etc/policy.json").is_err());
+    }
+
+    #[test]
+    fn test_get_tls() {
+        let dir = tempdir().unwrap();
+        let cmdline = dir.path().join("cmdline");
+        fs::write(
+            &cmdline,
+            "agent.tls_dir=/run/tls agent.tls_storage=virtiofs:certs",
+        )
+        .unwrap();
+
+        let mut config = agentConfig::new();
+        config.parse_cmdline(cmdline.to_str().unwrap()).unwrap();
+        assert_eq!(config.tls_dir, Some("/run/tls".to_string()));
+        assert_eq!(
+            config.tls_storage,
+            Some(TlsStorage {
+                fstype: "virtiofs".to_string(),
+                source: "certs".to_string(),
+            })
+        );
+
+        assert_eq!(
+            parse_tls_storage("ext4:/dev/vdb").unwrap(),
+            TlsStorage {
+                fstype: "ext4".to_string(),
+                source: "/dev/vdb".to_string(),
+            }
+        );
+        assert!(get_tls_dir("agent.tls_dir=").is_err());
+        assert!(get_tls_storage("agent.tls_storage=/dev/vdb").is_err());
+        assert!(get_tls_storage("agent.tls_storage=ext4:").is_err());
+        assert!(get_tls_storage("agent.tls_storage=:/dev/vdb").is_err());
+    }
+
+    #[test]
+    fn test_get_log_vport() {
+        assert_eq!(get_log_vport("agent.log_vport=1025").unwrap(), 1025);
+        assert!(get_log_vport("agent.log_vport=").is_err());
+        assert!(get_log_vport("agent.log_vport=-1").is_err());
+        assert!(get_log_vport("agent.log_vports=1025").is_err());
+
+        // apart from the log level, which shares the prefix
+        let dir = tempdir().unwrap();
+        let cmdline = dir.path().join("cmdline");
+        fs::write(&cmdline, "agent.log_vport=1025 agent.log=debug").unwrap();
+
+        let mut config = agentConfig::new();
+        config.parse_cmdline(cmdline.to_str().unwrap()).unwrap();
+        assert_eq!(config.log_vport, 1025);
+        assert_eq!(config.log_level, slog::Level::Debug);
+    }
+
+    #[test]
+    fn test_get_server_type() {
+        assert_eq!(
+            get_server_type("agent.server_type=grpc").unwrap(),
+            ServerType::Grpc
+        );
+        assert_eq!(
+            get_server_type("agent.server_type=ttrpc").unwrap(),
+            ServerType::Ttrpc
+        );
+        assert!(get_server_type("agent.server_type=").is_err());
+        assert!(get_server_type("agent.server_type=http").is_err());
+        assert!(get_server_type("agent.server_typo=grpc").is_err());
+    }
+
+    #[test]
+    fn test_get_server_addr() {
+        let tests = &[
+            (
+                "agent.server_addr=vsock://-1:1024",
+                Some(ServerAddr::Vsock(libc::VMADDR_CID_ANY, 1024)),
+            ),
+            (
+                "agent.server_addr=vsock://3:2048",
+                Some(ServerAddr::Vsock(3, 2048)),
+            ),
+            (
+                "agent.server_addr=unix:///run/agent.sock",
+                Some(ServerAddr::Unix("/run/agent.sock".to_string())),
+            ),
+            (
+                "agent.server_addr=/dev/virtio-ports/agent.channel.0",
+                Some(ServerAddr::Serial(
+                    "/dev/virtio-ports/agent.channel.0".to_string(),
+                )),
+            ),
+            ("agent.server_addr=vsock://-1", None),
+            ("agent.server_addr=vsock://-2:1024", None),
+            ("agent.server_addr=vsock://3:port", None),
+            ("agent.server_addr=vsock://3:65536", None),
+            ("agent.server_addr=unix://agent.sock", None),
+            ("agent.server_addr=/dev/virtio-ports/", None),
+            ("agent.server_addr=/dev/ttyS0", None),
+            ("agent.server_addr=", None),
+            ("agent.server_address=vsock://3:2048", None),
+        ];
+
+        for (param, addr) in tests.iter() {
+            match addr {
+                Some(addr) => assert_eq!(get_server_addr(param).unwrap(), *addr, "{}", param),
+                None => assert!(get_server_addr(param).is_err(), "{}", param),
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_bool_value() {
+        assert!(get_bool_value("agent.trace", TRACE_MODE_FLAG).unwrap());
+        assert!(get_bool_value("agent.trace=true", TRACE_MODE_FLAG).unwrap());
+        assert!(!get_bool_value("agent.trace=false", TRACE_MODE_FLAG).unwrap());
+        assert!(get_bool_value("agent.trace=0", TRACE_MODE_FLAG).is_err());
+        assert!(get_bool_value("agent.tracer", TRACE_MODE_FLAG).is_err());
+    }
+
+    #[test]
+    fn test_agent_rpcs() {
+        let proto = include_str!("../protocols/protos/agent.proto");
+        let rpcs: Vec<&str> = proto
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| l.starts_with("rpc "))
+            .map(|l| l[4..].split('(').next().unwrap().trim())
+            .collect();
+        assert_eq!(rpcs, AGENT_RPCS);
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let mut config = agentConfig::new();
+        config
+            .parse_config(
+                r#"
+log_level = "debug"
+log_vport = 1025
+hotplug_timeout = 10
+server_type = "ttrpc"
+server_addr = "unix:///run/agent.sock"
+debug_console = true
+dev_mode = true
+cgroup_driver = "cgroupfs"
+container_pipe_size = 1048576
+allowed_rpcs = ["CreateContainer", "StartContainer"]
+tracing = true
+policy_file = "/etc/kata-containers/policy.json"
+tls_dir = "/etc/kata-containers/tls"
+tls_storage = "ext4:/dev/vdb"
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(config.log_level, slog::Level::Debug);
+        assert_eq!(config.log_vport, 1025);
+        assert_eq!(config.hotplug_timeout, time::Duration::from_secs(10));
+        assert_eq!(config.server_type, ServerType::Ttrpc);
+        assert_eq!(
+            config.server_addr,
+            ServerAddr::Unix("/run/agent.sock".to_string())
+        );
+        assert!(config.debug_console);
+        assert!(config.dev_mode);
+        assert_eq!(config.container_pipe_size, 1048576);
+        assert!(config.is_allowed("StartContainer"));
+        assert!(!config.is_allowed("ExecProcess"));
+        assert!(config.tracing);
+        assert_eq!(
+            config.policy_file,
+            Some("/etc/kata-containers/policy.json".to_string())
+        );
+        assert_eq!(config.tls_dir, Some("/etc/kata-containers/tls".to_string()));
+        assert_eq!(
+            config.tls_storage,
+            Some(TlsStorage {
+                fstype: "ext4".to_string(),
+                source: "/dev/vdb".to_string(),
+            })
+        );
+
+        // all is allowed unless configured
+        let mut config = agentConfig::new();
+        config.parse_config("").unwrap();
+        assert!(config.is_allowed("ExecProcess"));
+
+        let invalid = &[
+            "log_level = \"verbose\"",
+            "hotplug_timeout = 0",
+            "hotplug_timeout = \"3s\"",
+            "server_type = \"http\"",
+            "server_addr = \"vsock://3\"",
+            "cgroup_driver = \"systemd\"",
+            "cgroup_driver = \"cgroupv3\"",
+            "container_pipe_size = -1",
+            "container_pipe_size = 4294967295",
+            "allowed_rpcs = \"ExecProcess\"",
+            "allowed_rpcs = [\"Exec\"]",
+            "tls_storage = \"/dev/vdb\"",
+            "debug = true",
+            "log_level = ",
+        ];
+
+        for contents in invalid.iter() {
+            let mut config = agentConfig::new();
+            assert!(config.parse_config(contents).is_err(), "{}", contents);
+        }
+    }
+
+    #[test]
+    fn test_parse_cmdline_config_file() {
+        let dir = tempdir().unwrap();
+        let config_file = dir.path().join("agent.toml");
+        fs::write(
+            &config_file,
+            "log_level = \"debug\"\nhotplug_timeout = 10\nserver_type = \"ttrpc\"\n",
+        )
+        .unwrap();
+
+        // the command line overrides the file
+        let cmdline = dir.path().join("cmdline");
+        fs::write(
+            &cmdline,
+            format!(
+                "agent.hotplug_timeout=5 agent.config_file={}",
+                config_file.display()
+            ),
+        )
+        .unwrap();
+
+        let mut config = agentConfig::new();
+        config.parse_cmdline(cmdline.to_str().unwrap()).unwrap();
+        assert_eq!(config.log_level, slog::Level::Debug);
+        assert_eq!(config.hotplug_timeout, time::Duration::from_secs(5));
+        assert_eq!(config.server_type, ServerType::Ttrpc);
+
+        fs::write(&config_file, "log_level = \"verbose\"\n").unwrap();
+        let mut config = agentConfig::new();
+        assert!(config.parse_cmdline(cmdline.to_str().unwrap()).is_err());
+
+        fs::write(&cmdline, "agent.config_file=/nonexistent/agent.toml").unwrap();
+        let mut config = agentConfig::new();
+        assert!(config.parse_cmdline(cmdline.to_str().unwrap()).is_err());
+
+        fs::write(&cmdline, "agent.config_file=").unwrap();
+        let mut config = agentConfig::new();
+        assert!(config.parse_cmdline(cmdline.to_str().unwrap()).is_err());
+    }
 }
@@ -15,6 +15,7 @@ use std::sync::{Arc, Mutex};
 
 use crate::mount::{DRIVERBLKTYPE, DRIVERMMIOBLKTYPE, DRIVERNVDIMMTYPE, DRIVERSCSITYPE};
 use crate::sandbox::Sandbox;
+use crate::tracer;
 use crate::{AGENT_CONFIG, GLOBAL_DEVICE_WATCHER};
 use protocols::agent::Device;
 use protocols::oci::Spec;
@@ -54,6 +55,15 @@ pub const SCSI_BLOCK_SUFFIX: &'static str = "block";
 const SCSI_DISK_SUFFIX: &'static str = "/device/block";
 const SCSI_HOST_PATH: &'static str = "/sys/class/scsi_host";
 
+// NVDIMM const
+
+// pmem devices are named after the namespaces the kernel finds on the
+// nvdimm regions, and live on the nvdimm bus rather than on PCI, e.g.
+// /devices/LNXSYSTM:00/LNXSYBUS:00/ACPI0012:00/ndbus0/region0/namespace0.0/block/pmem0
+pub const PMEM_DEV_PREFIX: &'static str = "pmem";
+pub const NVDIMM_BUS: &'static str = "/ndbus";
+const SYS_BLOCK_PREFIX: &'static str = "/sys/class/block";
+
 // DeviceHandler is the type of callback to be defined to handle every
 // type of device driver.
 type DeviceHandler = fn(&Device, &mut Spec, Arc<Mutex<Sandbox>>) -> Result<()>;
@@ -147,11 +157,11 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str) -> Result<S
         let watcher = GLOBAL_DEVICE_WATCHER.clone();
         let mut w = watcher.lock().unwrap();
 
-        let s = sandbox.clone();
-        let sb = s.lock().unwrap();
+        let pci_device_map = sandbox.lock().unwrap().pci_device_map.clone();
+        let pci_device_map = pci_device_map.lock().unwrap();
 
-        for (key, value) in &(sb.pci_device_map) {
-            if key.contains(dev_addr) {
+        for (key, value) in pci_device_map.iter() {
+            if device_matches(key, dev_addr) {
                 dev_name = value.to_string();
                 info!(sl!(), "Device {} found in pci device map", dev_addr);
                 break;
@@ -174,6 +184,9 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str) -> Result<S
         let agent_config = AGENT_CONFIG.clone();
         let config = agent_config.read().unwrap();
 
+        let mut span = tracer::span("wait_device");
+        span.set_attribute("dev_addr", dev_addr);
+
         match rx.recv_timeout(config.hotplug_timeout) {
             Ok(name) => dev_name = name,
             Err(_) => {
@@ -193,6 +206,20 @@ pub fn get_device_name(sandbox: Arc<Mutex<Sandbox>>, dev_addr: &str) -> Result<S
     Ok(format!("{}/{}", SYSTEM_DEV_PATH, &dev_name))
 }
 
+// device_matches tells whether devpath, the sysfs path of a block device
+// as in uevents, is the device known as dev_addr by get_device_name.
+pub fn device_matches(devpath: &str, dev_addr: &str) -> bool {
+    // nvdimm namespace
+    if dev_addr.starts_with(PMEM_DEV_PREFIX) {
+        return devpath.contains(NVDIMM_BUS) && devpath.ends_with(&format!("/{}", dev_addr));
+    }
+
+    // blk block device
+    devpath.starts_with(&format!("{}/{}", ROOT_BUS_PATH, dev_addr)) ||
+        // scsi block device
+        (dev_addr.ends_with(SCSI_BLOCK_SUFFIX) && devpath.contains(dev_addr))
+}
+
 pub fn get_scsi_device_name(sandbox: Arc<Mutex<Sandbox>>, scsi_addr: &str) -> Result<String> {
     scan_scsi_bus(scsi_addr)?;
 
@@ -209,6 +236,46 @@ pub fn get_pci_device_name(sandbox: Arc<Mutex<Sandbox>>, pci_id: &str) -> Result
     get_device_name(sandbox, pci_addr.as_str())
 }
 
+// get_pmem_device_name waits for the pmem device node dev, e.g. /dev/pmem0
+// or /dev/pmem0p1. NVDIMMs are usually there at boot, before the uevent
+// listener, so sysfs is looked at first.
+pub fn get_pmem_device_name(sandbox: Arc<Mutex<Sandbox>>, dev: &str) -> Result<String> {
+    let name = dev
+        .trim_start_matches(SYSTEM_DEV_PATH)
+        .trim_start_matches('/');
+    if !name.starts_with(PMEM_DEV_PREFIX) || name.contains('/') {
+        return Err(ErrorKind::ErrorCode(format!("Invalid pmem device {}", dev)).into());
+    }
+
+    if let Ok(path) = fs::canonicalize(format!("{}/{}", SYS_BLOCK_PREFIX, name)) {
+        let path = path.to_string_lossy();
+        if path.contains(NVDIMM_BUS) {
+            info!(sl!(), "Device {} found in sysfs", name; "path" => path.as_ref());
+            return Ok(format!("{}/{}", SYSTEM_DEV_PATH, name));
+        }
+    }
+
+    get_device_name(sandbox, name)
+}
+
+// pmem_supports_dax tells whether the pmem device dev, a partition or
+// not, can be mounted with DAX.
+pub fn pmem_supports_dax(dev: &str) -> bool {
+    let name = dev
+        .trim_start_matches(SYSTEM_DEV_PATH)
+        .trim_start_matches('/');
+    let block = format!("{}/{}", SYS_BLOCK_PREFIX, name);
+
+    [
+        format!("{}/queue/dax", block),
+        format!("{}/../queue/dax", block),
+    ]
+    .iter()
+    .filter_map(|p| fs::read_to_string(p).ok())
+    .next()
+    .map_or(false, |v| v.trim() == "1")
+}
+
 // scan_scsi_bus scans SCSI bus for the given SCSI address(SCSI-Id and LUN)
 pub fn scan_scsi_bus(scsi_addr: &str) -> Result<()> {
     let tokens: Vec<&str> = scsi_addr.split(":").collect();
@@ -421,3 +488,35 @@ fn add_device(device: &Device, spec: &mut Spec, sandbox: Arc<Mutex<Sandbox>>) ->
 
     dev_handler(device, spec, sandbox)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_matches() {
+        let blk = format!(
+            "{}/0000:00:02.0/0000:01:01.0/virtio4/block/vda",
+            ROOT_BUS_PATH
+        );
+        let scsi = format!(
+            "{}/0000:00:05.0/virtio2/host0/target0:0:0/0:0:0:1/block/sdb",
+            ROOT_BUS_PATH
+        );
+        let pmem =
+            "/devices/LNXSYSTM:00/LNXSYBUS:00/ACPI0012:00/ndbus0/region1/namespace1.0/block/pmem1";
+        let part = format!("{}/pmem1p1", pmem);
+
+        assert!(device_matches(&blk, "0000:00:02.0/0000:01:01.0"));
+        assert!(!device_matches(&blk, "0000:00:02.0/0000:01:02.0"));
+        assert!(device_matches(&scsi, "0:0:0:1/block"));
+        assert!(!device_matches(&scsi, "0:0:0:2/block"));
+
+        assert!(device_matches(pmem, "pmem1"));
+        assert!(!device_matches(pmem, "pmem10"));
+        assert!(!device_matches(&part, "pmem1"));
+        assert!(device_matches(&part, "pmem1p1"));
+        assert!(!device_matches(&blk, "pmem1"));
+        assert!(!device_matches("/devices/virtual/block/pmem1", "pmem1"));
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Minimal device-mapper client talking to the kernel through the ioctls
+// of /dev/mapper/control, as there is no libdevmapper nor udev in the
+// guest.
+
+use libc;
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::{self, Mode, SFlag};
+use nix::unistd;
+use rustjail::errors::*;
+use std::fs;
+use std::mem;
+use std::path::Path;
+
+pub const DM_DIR: &'static str = "/dev/mapper";
+const DM_CONTROL: &'static str = "/dev/mapper/control";
+
+const DM_VERSION_MAJOR: u32 = 4;
+const DM_NAME_LEN: usize = 128;
+const DM_UUID_LEN: usize = 129;
+const DM_MAX_TYPE_NAME: usize = 16;
+
+// enough for a handful of targets and their parameters
+const DM_BUFFER_SIZE: usize = 16 * 1024;
+
+const DM_READONLY_FLAG: u32 = 1 << 0;
+const DM_SECURE_DATA_FLAG: u32 = 1 << 15;
+
+// _IOWR(0xfd, cmd, struct dm_ioctl)
+const DM_IOCTL_BASE: u64 = 0xc138_fd00;
+const DM_DEV_CREATE_CMD: u64 = 3;
+const DM_DEV_REMOVE_CMD: u64 = 4;
+const DM_DEV_SUSPEND_CMD: u64 = 6;
+const DM_TABLE_LOAD_CMD: u64 = 9;
+
+// Handle the differing ioctl(2) request types for different targets
+#[cfg(target_env = "musl")]
+type IoctlRequestType = libc::c_int;
+#[cfg(target_env = "gnu")]
+type IoctlRequestType = libc::c_ulong;
+
+#[repr(C)]
+struct dm_ioctl {
+    version: [u32; 3],
+    data_size: u32,
+    data_start: u32,
+    target_count: u32,
+    open_count: i32,
+    flags: u32,
+    event_nr: u32,
+    padding: u32,
+    dev: u64,
+    name: [u8; DM_NAME_LEN],
+    uuid: [u8; DM_UUID_LEN],
+    data: [u8; 7],
+}
+
+#[repr(C)]
+struct dm_target_spec {
+    sector_start: u64,
+    length: u64,
+    status: i32,
+    next: u32,
+    target_type: [u8; DM_MAX_TYPE_NAME],
+}
+
+// Target is one line of a device-mapper table, in 512 bytes sectors.
+#[derive(Debug, Clone)]
+pub struct Target {
+    pub start: u64,
+    pub length: u64,
+    pub target_type: String,
+    pub params: String,
+}
+
+// create_device creates the device-mapper device name out of targets,
+// activates it and returns its node. The tables may hold keys, which
+// the kernel is asked to wipe once they are no longer needed.
+pub fn create_device(
+    name: &str,
+    uuid: &str,
+    targets: &[Target],
+    read_only: bool,
+) -> Result<String> {
+    let fd = fcntl::open(DM_CONTROL, OFlag::O_RDWR | OFlag::O_CLOEXEC, Mode::empty())?;
+
+    let res = (|| {
+        dm_call(fd, DM_DEV_CREATE_CMD, name, uuid, 0, &[])?;
+
+        let mut flags = DM_SECURE_DATA_FLAG;
+        if read_only {
+            flags |= DM_READONLY_FLAG;
+        }
+
+        let dev = dm_call(fd, DM_TABLE_LOAD_CMD, name, "", flags, targets)
+            // suspending a device without the suspend flag resumes it
+            .and_then(|_| dm_call(fd, DM_DEV_SUSPEND_CMD, name, "", 0, &[]));
+
+        if dev.is_err() {
+            let _ = dm_call(fd, DM_DEV_REMOVE_CMD, name, "", 0, &[]);
+        }
+        dev
+    })();
+    let _ = unistd::close(fd);
+
+    let path = format!("{}/{}", DM_DIR, name);
+    let dev = res?;
+    if !Path::new(&path).exists() {
+        let (major, minor) = decode_dev(dev);
+        stat::mknod(
+            path.as_str(),
+            SFlag::S_IFBLK,
+            Mode::from_bits_truncate(0o600),
+            libc::makedev(major, minor) as libc::dev_t,
+        )?;
+    }
+
+    Ok(path)
+}
+
+// remove_device deactivates the device-mapper device name and removes
+// its node.
+pub fn remove_device(name: &str) -> Result<()> {
+    let fd = fcntl::open(DM_CONTROL, OFlag::O_RDWR | OFlag::O_CLOEXEC, Mode::empty())?;
+    let res = dm_call(fd, DM_DEV_REMOVE_CMD, name, "", 0, &[]);
+    let _ = unistd::close(fd);
+    res?;
+
+    let path = format!("{}/{}", DM_DIR, name);
+    if Path::new(&path).exists() {
+        fs::remove_file(&path)?;
+    }
+
+    Ok(())
+}
+
+// dm_call issues the device-mapper command cmd on the device name and
+// returns the device number the kernel replied with.
+fn dm_call(
+    fd: i32,
+    cmd: u64,
+    name: &str,
+    uuid: &str,
+    flags: u32,
+    targets: &[Target],
+) -> Result<u64> {
+    let mut buf = dm_buffer(name, uuid, flags, targets)?;
+
+    let ret = unsafe {
+        libc::ioctl(
+            fd,
+            (DM_IOCTL_BASE | cmd) as IoctlRequestType,
+            buf.as_mut_ptr(),
+        )
+    };
+    Errno::result(ret).chain_err(|| format!("device-mapper command {} on {}", cmd, name))?;
+
+    let dev = unsafe { (*(buf.as_ptr() as *const dm_ioctl)).dev };
+
+    // the parameters may hold keys
+    for w in buf.iter_mut() {
+        *w = 0;
+    }
+
+    Ok(dev)
+}
+
+// dm_buffer lays out a struct dm_ioctl followed by the target specs,
+// each with its parameters, in a buffer aligned for the kernel.
+fn dm_buffer(name: &str, uuid: &str, flags: u32, targets: &[Target]) -> Result<Vec<u64>> {
+    if name.len() >= DM_NAME_LEN || uuid.len() >= DM_UUID_LEN {
+        return Err(ErrorKind::ErrorCode(format!("device-mapper name {} too long", name)).into());
+    }
+
+    let mut data: Vec<u8> = Vec::new();
+    for t in targets.iter() {
+        if t.target_type.len() >= DM_MAX_TYPE_NAME {
+            return Err(
+                ErrorKind::ErrorCode(format!("unknown target type {}", t.target_type)).into(),
+            );
+        }
+
+        // the parameters are NUL terminated and the next spec 8 bytes aligned
+        let spec_size = mem::size_of::<dm_target_spec>();
+        let len = align8(spec_size + t.params.len() + 1);
+
+        let mut spec = dm_target_spec {
+            sector_start: t.start,
+            length: t.length,
+            status: 0,
+            next: len as u32,
+            target_type: [0; DM_MAX_TYPE_NAME],
+        };
+        spec.target_type[..t.target_type.len()].copy_from_slice(t.target_type.as_bytes());
+
+        let start = data.len();
+        data.resize(start + len, 0);
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &spec as *const dm_target_spec as *const u8,
+                data[start..].as_mut_ptr(),
+                spec_size,
+            );
+        }
+        data[start + spec_size..start + spec_size + t.params.len()]
+            .copy_from_slice(t.params.as_bytes());
+    }
+
+    let hdr_size = mem::size_of::<dm_ioctl>();
+    let size = DM_BUFFER_SIZE.max(align8(hdr_size + data.len()));
+    let mut buf = vec![0u64; size / 8];
+
+    let mut hdr = dm_ioctl {
+        version: [DM_VERSION_MAJOR, 0, 0],
+        data_size: size as u32,
+        data_start: hdr_size as u32,
+        target_count: targets.len() as u32,
+        open_count: 0,
+        flags: flags,
+        event_nr: 0,
+        padding: 0,
+        dev: 0,
+        name: [0; DM_NAME_LEN],
+        uuid: [0; DM_UUID_LEN],
+        data: [0; 7],
+    };
+    hdr.name[..name.len()].copy_from_slice(name.as_bytes());
+    hdr.uuid[..uuid.len()].copy_from_slice(uuid.as_bytes());
+
+    unsafe {
+        let p = buf.as_mut_ptr() as *mut u8;
+        std::ptr::copy_nonoverlapping(&hdr as *const dm_ioctl as *const u8, p, hdr_size);
+        std::ptr::copy_nonoverlapping(data.as_ptr(), p.add(hdr_size), data.len());
+    }
+
+    // the parameters may hold keys
+    for b in data.iter_mut() {
+        *b = 0;
+    }
+
+    Ok(buf)
+}
+
+// decode_dev splits the device number device-mapper returns, in the
+// kernel encoding, into major and minor.
+fn decode_dev(dev: u64) -> (u32, u32) {
+    let major = (dev & 0xfff00) >> 8;
+    let minor = (dev & 0xff) | ((dev >> 12) & 0xfff00);
+    (major as u32, minor as u32)
+}
+
+fn align8(n: usize) -> usize {
+    (n + 7) & !7
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dm_buffer() {
+        assert_eq!(mem::size_of::<dm_ioctl>(), 312);
+        assert_eq!(mem::size_of::<dm_target_spec>(), 40);
+
+        let targets = vec![
+            Target {
+                start: 0,
+                length: 2048,
+                target_type: "linear".to_string(),
+                params: "/dev/vda 0".to_string(),
+            },
+            Target {
+                start: 2048,
+                length: 8,
+                target_type: "zero".to_string(),
+                params: "".to_string(),
+            },
+        ];
+
+        let buf = dm_buffer("vda-crypt", "CRYPT-1", DM_READONLY_FLAG, &targets).unwrap();
+        let bytes = unsafe { std::slice::from_raw_parts(buf.as_ptr() as *const u8, buf.len() * 8) };
+        let hdr = unsafe { &*(buf.as_ptr() as *const dm_ioctl) };
+
+        assert_eq!(hdr.version, [4, 0, 0]);
+        assert_eq!(hdr.data_size as usize, DM_BUFFER_SIZE);
+        assert_eq!(hdr.data_start, 312);
+        assert_eq!(hdr.target_count, 2);
+        assert_eq!(hdr.flags, DM_READONLY_FLAG);
+        assert_eq!(&hdr.name[..10], b"vda-crypt\0");
+        assert_eq!(&hdr.uuid[..8], b"CRYPT-1\0");
+
+        let spec = unsafe { &*(bytes[312..].as_ptr() as *const dm_target_spec) };
+        assert_eq!(spec.length, 2048);
+        assert_eq!(spec.next, 56);
+        assert_eq!(&spec.target_type[..7], b"linear\0");
+        assert_eq!(&bytes[352..363], b"/dev/vda 0\0");
+
+        let spec = unsafe { &*(bytes[368..].as_ptr() as *const dm_target_spec) };
+        assert_eq!(spec.sector_start, 2048);
+        assert_eq!(spec.next, 48);
+        assert_eq!(&spec.target_type[..5], b"zero\0");
+
+        assert!(dm_buffer(&"x".repeat(DM_NAME_LEN), "", 0, &[]).is_err());
+    }
+
+    #[test]
+    fn test_decode_dev() {
+        assert_eq!(decode_dev(0xfd00), (253, 0));
+        assert_eq!(decode_dev(0xfd01), (253, 1));
+        assert_eq!(decode_dev(0x10_fd34), (253, 0x134));
+    }
+}
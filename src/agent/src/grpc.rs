@@ -4,8 +4,9 @@
 //
 
 use futures::*;
-use grpcio::{EnvBuilder, Server, ServerBuilder};
-use grpcio::{RpcStatus, RpcStatusCode};
+use grpcio::{EnvBuilder, Server, ServerBuilder, WriteFlags};
+use grpcio::{RpcContext, RpcStatus, RpcStatusCode};
+use std::collections::{BTreeSet, HashMap};
 use std::sync::{Arc, Mutex};
 
 use protobuf::{RepeatedField, SingularPtrField};
@@ -29,13 +30,23 @@ use nix::sys::stat;
 use nix::unistd::{self, Pid};
 use rustjail::process::ProcessOperations;
 
+use crate::config::{logrus_to_slog_level, ServerAddr, ServerType};
 use crate::device::{add_devices, rescan_pci_bus};
-use crate::mount::{add_storages, remove_mounts, STORAGEHANDLERLIST};
+use crate::metrics;
+use crate::mount::{add_storages, get_volume_stats, STORAGEHANDLERLIST};
 use crate::namespace::{NSTYPEIPC, NSTYPEPID, NSTYPEUTS};
 use crate::netlink::{RtnlHandle, NETLINK_ROUTE};
+use crate::policy;
 use crate::random;
-use crate::sandbox::Sandbox;
+use crate::resize::resize_filesystem;
+use crate::sandbox::{self, Sandbox};
+use crate::serial;
+use crate::tls::TlsFiles;
+use crate::tracer;
+use crate::ttrpc;
 use crate::version::{AGENT_VERSION, API_VERSION};
+use crate::watchable::WatchableStorage;
+use crate::{AGENT_CONFIG, LOG_LEVELS};
 
 use libc::{self, c_ushort, pid_t, winsize, TIOCSWINSZ};
 use serde_json;
@@ -49,13 +60,21 @@ use std::time::Duration;
 use nix::unistd::{Gid, Uid};
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader};
-use std::os::unix::fs::FileExt;
-use std::path::PathBuf;
+use std::os::unix::fs::{FileExt, PermissionsExt};
+use std::path::{Path, PathBuf};
 
 const SYSFS_MEMORY_BLOCK_SIZE_PATH: &'static str = "/sys/devices/system/memory/block_size_bytes";
 const SYSFS_MEMORY_HOTPLUG_PROBE_PATH: &'static str = "/sys/devices/system/memory/probe";
 pub const SYSFS_MEMORY_ONLINE_PATH: &'static str = "/sys/devices/system/memory";
-const CONTAINER_BASE: &'static str = "/run/agent";
+pub const CONTAINER_BASE: &'static str = "/run/agent";
+
+// The services, as named in their protos, ttRPC requests are sent to.
+const AGENT_SERVICE_NAME: &'static str = "grpc.AgentService";
+const HEALTH_SERVICE_NAME: &'static str = "grpc.Health";
+
+// where the server listens for the streams relayed from a virtio-serial port,
+// out of /run/agent, which sandboxes are created anew in
+const SERIAL_BACKEND_SOCKET: &'static str = "/run/kata-containers/agent-serial.sock";
 
 // Convenience macro to obtain the scope logger
 macro_rules! sl {
@@ -64,6 +83,24 @@ macro_rules! sl {
     };
 }
 
+// rpc_start! denies the gRPC call of ctx, unless the configuration and the
+// policy allow its method, and otherwise starts its span and timer, see
+// rpc_start. The method is named by ctx, not by the handler, so that no
+// handler goes unchecked for a misspelt name.
+macro_rules! rpc_start {
+    ($ctx:expr, $sink:expr) => {{
+        let method = rpc_method($ctx.method());
+        if !is_allowed(&method) {
+            let f = $sink
+                .fail(not_allowed(&method))
+                .map_err(move |_e| error!(sl!(), "fail to deny {}", method));
+            $ctx.spawn(f);
+            return;
+        }
+        rpc_start(&$ctx, &method)
+    }};
+}
+
 #[derive(Clone)]
 struct agentService {
     sandbox: Arc<Mutex<Sandbox>>,
@@ -73,13 +110,45 @@ struct agentService {
 impl agentService {
     fn do_create_container(&mut self, req: protocols::agent::CreateContainerRequest) -> Result<()> {
         let cid = req.container_id.clone();
+
+        // The container is reserved while it is created with nothing locked,
+        // so that it is created once, and DestroySandbox waits for it.
+        let reservation = self.sandbox.lock().unwrap().reserve_container(&cid)?;
+        let _creating = reservation.lock().unwrap();
+
+        let res = self.new_container(req);
+
+        let mut s = self.sandbox.lock().unwrap();
+        s.creating.remove(&cid);
+        match res {
+            Ok(ctr) => {
+                s.add_container(&cid, Arc::new(Mutex::new(ctr)));
+                info!(sl!(), "created container!");
+                Ok(())
+            }
+            Err(e) => {
+                let storages = s.storages.clone();
+                drop(s);
+
+                if let Err(err) = storages.lock().unwrap().remove_container_mounts(&cid) {
+                    warn!(sl!(), "failed to release the storages of {}: {}", cid, err);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    // new_container creates and starts the container of req, which is only
+    // added by the caller once started.
+    fn new_container(
+        &mut self,
+        req: protocols::agent::CreateContainerRequest,
+    ) -> Result<LinuxContainer> {
+        let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
 
         let mut oci_spec = req.OCI.clone();
 
-        let sandbox;
-        let mut s;
-
         let oci = oci_spec.as_mut().unwrap();
 
         info!(sl!(), "receive createcontainer {}", &cid);
@@ -105,12 +174,17 @@ impl agentService {
         // list) to bind mount all of them inside the container.
         let m = add_storages(sl!(), req.storages.to_vec(), self.sandbox.clone())?;
         {
-            sandbox = self.sandbox.clone();
-            s = sandbox.lock().unwrap();
-            s.container_mounts.insert(cid.clone(), m);
+            let storages = self.sandbox.lock().unwrap().storages.clone();
+            let mut storages = storages.lock().unwrap();
+            storages.container_mounts.insert(cid.clone(), m);
         }
 
-        update_container_namespaces(&s, oci)?;
+        let no_pivot_root = {
+            let s = self.sandbox.lock().unwrap();
+            let _span = tracer::span("update_container_namespaces");
+            update_container_namespaces(&s, oci)?;
+            s.no_pivot_root
+        };
 
         // write spec to bundle path, hooks might
         // read ocispec
@@ -119,7 +193,7 @@ impl agentService {
         let opts = CreateOpts {
             cgroup_name: "".to_string(),
             use_systemd_cgroup: false,
-            no_pivot_root: s.no_pivot_root,
+            no_pivot_root: no_pivot_root,
             no_new_keyring: false,
             spec: Some(oci.clone()),
             rootless_euid: false,
@@ -130,84 +204,58 @@ impl agentService {
             LinuxContainer::new(cid.as_str(), CONTAINER_BASE, opts, &sl!())?;
 
         let p = if oci.Process.is_some() {
-            let tp = Process::new(&sl!(), oci.get_Process(), eid.as_str(), true)?;
+            let tp = new_process(oci.get_Process(), eid.as_str(), true)?;
             tp
         } else {
             info!(sl!(), "no process configurations!");
             return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into());
         };
 
-        ctr.start(p)?;
-
-        s.add_container(ctr);
-        info!(sl!(), "created container!");
+        // The container starts with nothing locked. Its init process cannot
+        // exit unnoticed meanwhile, as it waits for StartContainer to run the
+        // container process.
+        {
+            let _span = tracer::span("create_container_process");
+            ctr.start(p)?;
+        }
 
-        Ok(())
+        Ok(ctr)
     }
 
     fn do_start_container(&mut self, req: protocols::agent::StartContainerRequest) -> Result<()> {
         let cid = req.container_id.clone();
 
-        let sandbox = self.sandbox.clone();
-        let mut s = sandbox.lock().unwrap();
-
-        let ctr: &mut LinuxContainer = match s.get_container(cid.as_str()) {
+        let ctr = match self.sandbox.lock().unwrap().get_container(cid.as_str()) {
             Some(cr) => cr,
             None => {
                 return Err(ErrorKind::Nix(nix::Error::from_errno(Errno::EINVAL)).into());
             }
         };
+        let mut ctr = ctr.lock().unwrap();
 
-        ctr.exec()?;
+        {
+            let _span = tracer::span("exec_container_process");
+            ctr.exec()?;
+        }
 
         Ok(())
     }
 
     fn do_remove_container(&mut self, req: protocols::agent::RemoveContainerRequest) -> Result<()> {
         let cid = req.container_id.clone();
-        let mut cmounts: Vec<String> = vec![];
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
 
         if req.timeout == 0 {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
-            let ctr = sandbox.get_container(cid.as_str()).unwrap();
-
-            ctr.destroy()?;
+            ctr.lock().unwrap().destroy()?;
 
-            // Find the sandbox storage used by this container
-            let mounts = sandbox.container_mounts.get(&cid);
-            if mounts.is_some() {
-                let mounts = mounts.unwrap();
-
-                remove_mounts(&mounts)?;
-
-                for m in mounts.iter() {
-                    if sandbox.storages.get(m).is_some() {
-                        cmounts.push(m.to_string());
-                    }
-                }
-            }
-
-            for m in cmounts.iter() {
-                sandbox.unset_and_remove_sandbox_storage(m)?;
-            }
-
-            sandbox.container_mounts.remove(cid.as_str());
-            sandbox.containers.remove(cid.as_str());
-
-            return Ok(());
+            return self.remove_container(cid.as_str());
         }
 
         // timeout != 0
-        let s = Arc::clone(&self.sandbox);
-        let cid2 = cid.clone();
         let (tx, rx) = mpsc::channel();
 
         let handle = thread::spawn(move || {
-            let mut sandbox = s.lock().unwrap();
-            let ctr = sandbox.get_container(cid2.as_str()).unwrap();
-
-            ctr.destroy().unwrap();
+            ctr.lock().unwrap().destroy().unwrap();
             tx.send(1).unwrap();
         });
 
@@ -221,29 +269,17 @@ impl agentService {
             );
         }
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        // Find the sandbox storage used by this container
-        let mounts = sandbox.container_mounts.get(&cid);
-        if mounts.is_some() {
-            let mounts = mounts.unwrap();
-
-            remove_mounts(&mounts)?;
-
-            for m in mounts.iter() {
-                if sandbox.storages.get(m).is_some() {
-                    cmounts.push(m.to_string());
-                }
-            }
-        }
+        self.remove_container(cid.as_str())
+    }
 
-        for m in cmounts.iter() {
-            sandbox.unset_and_remove_sandbox_storage(m)?;
-        }
+    // remove_container releases the storages of the destroyed container cid,
+    // and removes it.
+    fn remove_container(&self, cid: &str) -> Result<()> {
+        let storages = self.sandbox.lock().unwrap().storages.clone();
 
-        sandbox.container_mounts.remove(&cid);
-        sandbox.containers.remove(cid.as_str());
+        // Release the sandbox storage used by this container
+        storages.lock().unwrap().remove_container_mounts(cid)?;
+        self.sandbox.lock().unwrap().containers.remove(cid);
 
         Ok(())
     }
@@ -254,9 +290,6 @@ impl agentService {
 
         info!(sl!(), "cid: {} eid: {}", cid.clone(), exec_id.clone());
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
         // ignore string_user, not sure what it is
         let ocip = if req.process.is_some() {
             req.process.as_ref().unwrap()
@@ -264,9 +297,9 @@ impl agentService {
             return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into());
         };
 
-        let p = Process::new(&sl!(), ocip, exec_id.as_str(), false)?;
+        let p = new_process(ocip, exec_id.as_str(), false)?;
 
-        let ctr = match sandbox.get_container(cid.as_str()) {
+        let ctr = match self.sandbox.lock().unwrap().get_container(cid.as_str()) {
             Some(v) => v,
             None => {
                 return Err(
@@ -274,8 +307,12 @@ impl agentService {
                 );
             }
         };
+        let mut ctr = ctr.lock().unwrap();
 
-        ctr.run(p)?;
+        {
+            let _span = tracer::span("run_process");
+            ctr.run(p)?;
+        }
 
         Ok(())
     }
@@ -283,8 +320,6 @@ impl agentService {
     fn do_signal_process(&mut self, req: protocols::agent::SignalProcessRequest) -> Result<()> {
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
 
         info!(
             sl!(),
@@ -292,7 +327,9 @@ impl agentService {
             "container-id" => cid.clone(),
             "exec-id" => eid.clone()
         );
-        let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), true)?;
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut ctr = ctr.lock().unwrap();
+        let p = find_process(&mut ctr, eid.as_str(), true)?;
 
         let mut signal = Signal::from_c_int(req.signal as i32).unwrap();
 
@@ -314,7 +351,6 @@ impl agentService {
     ) -> Result<protocols::agent::WaitProcessResponse> {
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
         let mut resp = WaitProcessResponse::new();
         let pid: pid_t;
         let mut exit_pipe_r: RawFd = -1;
@@ -327,10 +363,11 @@ impl agentService {
             "exec-id" => eid.clone()
         );
 
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
         {
-            let mut sandbox = s.lock().unwrap();
+            let mut ctr = ctr.lock().unwrap();
 
-            let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+            let p = find_process(&mut ctr, eid.as_str(), false)?;
 
             if p.exit_pipe_r.is_some() {
                 exit_pipe_r = p.exit_pipe_r.unwrap();
@@ -344,10 +381,15 @@ impl agentService {
             let _ = unistd::read(exit_pipe_r, buf.as_mut_slice());
         }
 
-        let mut sandbox = s.lock().unwrap();
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
+        let mut ctr = ctr.lock().unwrap();
         // need to close all fds
-        let mut p = ctr.processes.get_mut(&pid).unwrap();
+        let p = match ctr.processes.get_mut(&pid) {
+            Some(p) => p,
+            // removed while its exit was waited for
+            None => {
+                return Err(ErrorKind::ErrorCode(format!("process {} is gone", pid)).into());
+            }
+        };
 
         if p.parent_stdin.is_some() {
             let _ = unistd::close(p.parent_stdin.unwrap());
@@ -395,9 +437,9 @@ impl agentService {
             "exec-id" => eid.clone()
         );
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-        let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut ctr = ctr.lock().unwrap();
+        let p = find_process(&mut ctr, eid.as_str(), false)?;
 
         // use ptmx io
         let fd = if p.term_master.is_some() {
@@ -450,10 +492,10 @@ impl agentService {
         let mut fd: RawFd = -1;
         info!(sl!(), "read stdout for {}/{}", cid.clone(), eid.clone());
         {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
+            let ctr = get_container(&self.sandbox, cid.as_str())?;
+            let mut ctr = ctr.lock().unwrap();
 
-            let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+            let p = find_process(&mut ctr, eid.as_str(), false)?;
 
             if p.term_master.is_some() {
                 fd = p.term_master.unwrap();
@@ -477,183 +519,132 @@ impl agentService {
 
         Ok(resp)
     }
-}
 
-impl protocols::agent_grpc::AgentService for agentService {
-    fn create_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::CreateContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        if let Err(_) = self.do_create_container(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("fail to create container".to_string()),
-                ))
-                .map_err(|_e| error!(sl!(), "container create fail"));
-            ctx.spawn(f);
-            return;
-        } else {
-            let resp = Empty::new();
-            let f = sink
-                .success(resp)
-                .map_err(move |_e| error!(sl!(), "fail to create container"));
-            ctx.spawn(f);
+    // volume_paths returns the mount points of the storages of the sandbox.
+    fn volume_paths(&self) -> BTreeSet<String> {
+        let mut paths = BTreeSet::new();
+
+        let storages = self.sandbox.lock().unwrap().storages.clone();
+        let storages = storages.lock().unwrap();
+
+        paths.extend(storages.storages.keys().cloned());
+        paths.extend(storages.mounts.iter().cloned());
+        for mounts in storages.container_mounts.values() {
+            paths.extend(mounts.iter().cloned());
         }
+
+        paths
     }
 
-    fn start_container(
+    fn do_get_volume_stats(
         &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::StartContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        if let Err(_) = self.do_start_container(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("fail to find container".to_string()),
+        req: protocols::agent::GetVolumeStatsRequest,
+    ) -> Result<protocols::agent::VolumeStatsResponse> {
+        let mut paths = self.volume_paths();
+
+        if !req.volume_guest_path.is_empty() {
+            if !paths.contains(&req.volume_guest_path) {
+                return Err(ErrorKind::ErrorCode(format!(
+                    "no storage at {}",
+                    req.volume_guest_path
                 ))
-                .map_err(move |_e| error!(sl!(), "get container fail"));
-            ctx.spawn(f);
-            return;
+                .into());
+            }
+            paths = vec![req.volume_guest_path].into_iter().collect();
         }
 
-        info!(sl!(), "exec process!\n");
+        let mut volumes = Vec::new();
+        for p in paths.iter() {
+            volumes.push(get_volume_stats(p)?);
+        }
 
-        let resp = Empty::new();
-        let f = sink
-            .success(resp)
-            .map_err(move |_e| error!(sl!(), "fail to create container"));
-        ctx.spawn(f);
+        let mut resp = protocols::agent::VolumeStatsResponse::new();
+        resp.set_volumes(RepeatedField::from_vec(volumes));
+
+        Ok(resp)
     }
 
-    fn remove_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::RemoveContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        if let Err(_) = self.do_remove_container(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to remove container")),
-                ))
-                .map_err(move |_e| error!(sl!(), "remove container failed"));
-            ctx.spawn(f);
-        } else {
-            let resp = Empty::new();
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "cannot destroy container"));
-            ctx.spawn(f);
+    fn do_resize_volume(&mut self, req: protocols::agent::ResizeVolumeRequest) -> Result<()> {
+        if !self.volume_paths().contains(&req.volume_guest_path) {
+            return Err(
+                ErrorKind::ErrorCode(format!("no storage at {}", req.volume_guest_path)).into(),
+            );
         }
+
+        info!(sl!(), "resizing volume";
+            "volume-guest-path" => req.volume_guest_path.as_str(),
+            "size" => req.size);
+
+        resize_filesystem(&req.volume_guest_path, req.size)
     }
-    fn exec_process(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::ExecProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        if let Err(_) = self.do_exec_process(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to exec process!")),
-                ))
-                .map_err(|_e| error!(sl!(), "fail to exec process!"));
-            ctx.spawn(f);
-        } else {
-            let resp = Empty::new();
-            let f = sink
-                .success(resp)
-                .map_err(move |_e| error!(sl!(), "cannot exec process"));
-            ctx.spawn(f);
+
+    fn do_set_log_level(&mut self, req: protocols::agent::SetLogLevelRequest) -> Result<()> {
+        // all is checked before anything changes
+        let level = match req.level.as_str() {
+            "" => None,
+            level => Some(logrus_to_slog_level(level)?),
+        };
+
+        let mut subsystems = HashMap::new();
+        for (subsystem, level) in req.subsystems.iter() {
+            if subsystem.is_empty() {
+                return Err(ErrorKind::ErrorCode("empty subsystem".to_string()).into());
+            }
+            let level = logrus_to_slog_level(level)
+                .chain_err(|| format!("invalid log level of {}", subsystem))?;
+            subsystems.insert(subsystem.clone(), level);
         }
-    }
-    fn signal_process(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::SignalProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
-        if let Err(_) = self.do_signal_process(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to signal process!")),
-                ))
-                .map_err(|_e| error!(sl!(), "fail to signal process!"));
-            ctx.spawn(f);
-        } else {
-            let resp = Empty::new();
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "cannot signal process"));
-            ctx.spawn(f);
+
+        info!(sl!(), "setting log levels";
+            "level" => req.level.as_str(),
+            "subsystems" => format!("{:?}", req.subsystems));
+
+        if let Some(level) = level {
+            LOG_LEVELS.set_level(level);
         }
+        LOG_LEVELS.set_subsystem_levels(subsystems);
+
+        Ok(())
     }
-    fn wait_process(
+
+    fn do_set_policy(&mut self, req: protocols::agent::SetPolicyRequest) -> Result<()> {
+        policy::set(&req.policy)
+    }
+
+    fn do_get_metrics(
         &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::WaitProcessRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::WaitProcessResponse>,
-    ) {
-        if let Ok(resp) = self.do_wait_process(req) {
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "cannot wait process"));
-            ctx.spawn(f);
-        } else {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to wait process!")),
-                ))
-                .map_err(|_e| error!(sl!(), "fail to wait process!"));
-            ctx.spawn(f);
-        }
+        _req: protocols::agent::GetMetricsRequest,
+    ) -> Result<protocols::agent::Metrics> {
+        let mut metrics = protocols::agent::Metrics::new();
+        metrics.set_metrics(metrics::gather());
+
+        Ok(metrics)
     }
-    fn list_processes(
+
+    fn do_list_processes(
         &mut self,
-        ctx: ::grpcio::RpcContext,
         req: protocols::agent::ListProcessesRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::ListProcessesResponse>,
-    ) {
+    ) -> Result<ListProcessesResponse> {
         let cid = req.container_id.clone();
         let format = req.format.clone();
         let mut args = req.args.clone().into_vec();
         let mut resp = ListProcessesResponse::new();
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
-        let pids = ctr.processes().unwrap();
+        let ctr = match self.sandbox.lock().unwrap().get_container(cid.as_str()) {
+            Some(ctr) => ctr,
+            None => {
+                return Err(ErrorKind::ErrorCode(format!("no container {}", cid)).into());
+            }
+        };
+        let pids = ctr.lock().unwrap().processes()?;
 
         match format.as_str() {
             "table" => {}
             "json" => {
-                resp.process_list = serde_json::to_vec(&pids).unwrap();
-                let f = sink
-                    .success(resp)
-                    .map_err(|_e| error!(sl!(), "cannot handle json resp"));
-                ctx.spawn(f);
-                return;
-            }
-            _ => {
-                let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(String::from("invalid format")),
-                    ))
-                    .map_err(|_e| error!(sl!(), "invalid format!"));
-                ctx.spawn(f);
-                return;
+                resp.process_list = serde_json::to_vec(&pids)?;
+                return Ok(resp);
             }
+            _ => return Err(ErrorKind::ErrorCode(String::from("invalid format")).into()),
         }
 
         // format "table"
@@ -666,9 +657,9 @@ impl protocols::agent_grpc::AgentService for agentService {
             .args(args.as_slice())
             .stdout(Stdio::piped())
             .output()
-            .expect("ps failed");
+            .chain_err(|| "ps failed")?;
 
-        let out: String = String::from_utf8(output.stdout).unwrap();
+        let out: String = String::from_utf8(output.stdout)?;
         let mut lines: Vec<String> = out.split('\n').map(|v| v.to_string()).collect();
 
         let predicate = |v| {
@@ -679,7 +670,10 @@ impl protocols::agent_grpc::AgentService for agentService {
             }
         };
 
-        let pid_index = lines[0].split_whitespace().position(predicate).unwrap();
+        let pid_index = match lines[0].split_whitespace().position(predicate) {
+            Some(index) => index,
+            None => return Err(ErrorKind::ErrorCode(String::from("no PID from ps")).into()),
+        };
 
         let mut result = String::new();
         result.push_str(lines[0].as_str());
@@ -696,7 +690,7 @@ impl protocols::agent_grpc::AgentService for agentService {
                 warn!(sl!(), "corrupted output?");
                 continue;
             }
-            let pid = fields[pid_index].trim().parse::<i32>().unwrap();
+            let pid = fields[pid_index].trim().parse::<i32>()?;
 
             for p in &pids {
                 if pid == *p {
@@ -707,81 +701,584 @@ impl protocols::agent_grpc::AgentService for agentService {
 
         resp.process_list = Vec::from(result);
 
-        let f = sink
-            .success(resp)
-            .map_err(|_e| error!(sl!(), "list processes failed"));
-        ctx.spawn(f);
+        Ok(resp)
     }
-    fn update_container(
-        &mut self,
-        ctx: ::grpcio::RpcContext,
-        req: protocols::agent::UpdateContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
-    ) {
+
+    fn do_update_container(&mut self, req: protocols::agent::UpdateContainerRequest) -> Result<()> {
         let cid = req.container_id.clone();
         let res = req.resources.clone();
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
-
-        let resp = Empty::new();
+        let ctr = match self.sandbox.lock().unwrap().get_container(cid.as_str()) {
+            Some(ctr) => ctr,
+            None => {
+                return Err(ErrorKind::ErrorCode(format!("no container {}", cid)).into());
+            }
+        };
 
         if res.is_some() {
-            match ctr.set(res.unwrap()) {
-                Err(_e) => {
-                    let f = sink
-                        .fail(RpcStatus::new(
-                            RpcStatusCode::Internal,
-                            Some("internal error".to_string()),
-                        ))
-                        .map_err(|_e| error!(sl!(), "internal error!"));
-                    ctx.spawn(f);
-                    return;
-                }
-
-                Ok(()) => {}
-            }
+            ctr.lock().unwrap().set(res.unwrap())?;
         }
 
-        let f = sink
-            .success(resp)
-            .map_err(|_e| error!(sl!(), "update container failed!"));
-
-        ctx.spawn(f);
+        Ok(())
     }
-    fn stats_container(
+
+    fn do_stats_container(
         &mut self,
-        ctx: ::grpcio::RpcContext,
         req: protocols::agent::StatsContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::agent::StatsContainerResponse>,
+    ) -> Result<protocols::agent::StatsContainerResponse> {
+        let cid = req.container_id.clone();
+        let ctr = self.sandbox.lock().unwrap().get_container(cid.as_str());
+
+        match ctr {
+            Some(ctr) => ctr.lock().unwrap().stats(),
+            None => Err(ErrorKind::ErrorCode(format!("no container {}", cid)).into()),
+        }
+    }
+
+    fn do_close_stdin(&mut self, req: protocols::agent::CloseStdinRequest) -> Result<()> {
+        let cid = req.container_id.clone();
+        let eid = req.exec_id.clone();
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut ctr = ctr.lock().unwrap();
+
+        let p = find_process(&mut ctr, eid.as_str(), false)?;
+
+        if p.term_master.is_some() {
+            let _ = unistd::close(p.term_master.unwrap());
+            p.term_master = None;
+        }
+
+        if p.parent_stdin.is_some() {
+            let _ = unistd::close(p.parent_stdin.unwrap());
+            p.parent_stdin = None;
+        }
+
+        Ok(())
+    }
+
+    fn do_tty_win_resize(&mut self, req: protocols::agent::TtyWinResizeRequest) -> Result<()> {
+        let cid = req.container_id.clone();
+        let eid = req.exec_id.clone();
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut ctr = ctr.lock().unwrap();
+        let p = find_process(&mut ctr, eid.as_str(), false)?;
+
+        if p.term_master.is_none() {
+            return Err(ErrorKind::ErrorCode("no tty".to_string()).into());
+        }
+
+        let fd = p.term_master.unwrap();
+        unsafe {
+            let win = winsize {
+                ws_row: req.row as c_ushort,
+                ws_col: req.column as c_ushort,
+                ws_xpixel: 0,
+                ws_ypixel: 0,
+            };
+
+            let err = libc::ioctl(fd, TIOCSWINSZ, &win);
+            Errno::result(err).map(drop)?;
+        }
+
+        Ok(())
+    }
+
+    // rtnl runs f with the netlink handle of the sandbox, opened if need be.
+    fn rtnl<T>(&self, f: impl FnOnce(&mut RtnlHandle) -> Result<T>) -> Result<T> {
+        let rtnl = self.sandbox.lock().unwrap().rtnl.clone();
+        let mut rtnl = rtnl.lock().unwrap();
+
+        if rtnl.is_none() {
+            *rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0)?);
+        }
+
+        f(rtnl.as_mut().unwrap())
+    }
+
+    fn do_update_interface(
+        &mut self,
+        req: protocols::agent::UpdateInterfaceRequest,
+    ) -> Result<protocols::types::Interface> {
+        let interface = match req.interface.as_ref() {
+            Some(interface) => interface,
+            None => return Err(ErrorKind::ErrorCode("no interface".to_string()).into()),
+        };
+
+        self.rtnl(|rtnl| rtnl.update_interface(interface))
+    }
+
+    fn do_update_routes(
+        &mut self,
+        req: protocols::agent::UpdateRoutesRequest,
+    ) -> Result<protocols::agent::Routes> {
+        let mut routes = protocols::agent::Routes::new();
+        let rs = req.routes.clone().unwrap_or_default().Routes.into_vec();
+
+        let v = self.rtnl(|rtnl| rtnl.update_routes(rs.as_ref()))?;
+
+        routes.set_Routes(RepeatedField::from_vec(v));
+
+        Ok(routes)
+    }
+
+    fn do_list_interfaces(
+        &mut self,
+        _req: protocols::agent::ListInterfacesRequest,
+    ) -> Result<protocols::agent::Interfaces> {
+        let mut interface = protocols::agent::Interfaces::new();
+        let v = self.rtnl(|rtnl| rtnl.list_interfaces())?;
+
+        interface.set_Interfaces(RepeatedField::from_vec(v));
+
+        Ok(interface)
+    }
+
+    fn do_list_routes(
+        &mut self,
+        _req: protocols::agent::ListRoutesRequest,
+    ) -> Result<protocols::agent::Routes> {
+        let mut routes = protocols::agent::Routes::new();
+        let v = self.rtnl(|rtnl| rtnl.list_routes())?;
+
+        routes.set_Routes(RepeatedField::from_vec(v));
+
+        Ok(routes)
+    }
+
+    fn do_update_traffic_control(
+        &mut self,
+        req: protocols::agent::UpdateTrafficControlRequest,
+    ) -> Result<protocols::agent::TrafficControl> {
+        self.rtnl(|rtnl| rtnl.update_traffic_control(req.get_traffic_control()))
+    }
+
+    fn do_list_traffic_control(
+        &mut self,
+        req: protocols::agent::ListTrafficControlRequest,
+    ) -> Result<protocols::agent::TrafficControl> {
+        self.rtnl(|rtnl| rtnl.list_traffic_control(req.get_device()))
+    }
+
+    fn do_start_tracing(&mut self, _req: protocols::agent::StartTracingRequest) -> Result<()> {
+        tracer::start(&AGENT_CONFIG.read().unwrap().trace_addr)
+    }
+
+    fn do_stop_tracing(&mut self, _req: protocols::agent::StopTracingRequest) -> Result<()> {
+        tracer::stop();
+        Ok(())
+    }
+
+    fn do_create_sandbox(&mut self, req: protocols::agent::CreateSandboxRequest) -> Result<()> {
+        let _ = fs::remove_dir_all(CONTAINER_BASE);
+        let _ = fs::create_dir_all(CONTAINER_BASE);
+
+        {
+            let mut s = self.sandbox.lock().unwrap();
+
+            s.hostname = req.hostname.clone();
+            s.running = true;
+
+            if req.sandbox_id.len() > 0 {
+                s.id = req.sandbox_id.clone();
+            }
+        }
+
+        {
+            let _span = tracer::span("setup_shared_namespaces");
+            sandbox::setup_shared_namespaces(&self.sandbox)?;
+        }
+
+        let m = add_storages(sl!(), req.storages.to_vec(), self.sandbox.clone())?;
+
+        let storages = self.sandbox.lock().unwrap().storages.clone();
+        storages.lock().unwrap().mounts = m;
+
+        Ok(())
+    }
+
+    fn do_destroy_sandbox(&mut self, _req: protocols::agent::DestroySandboxRequest) -> Result<()> {
+        // destroy all containers, clean up, notify agent to exit
+        // etc.
+        sandbox::destroy(&self.sandbox)?;
+
+        if let Some(sender) = self.sandbox.lock().unwrap().sender.take() {
+            let _ = sender.send(1);
+        }
+
+        Ok(())
+    }
+
+    fn do_get_guest_details(
+        &mut self,
+        req: protocols::agent::GuestDetailsRequest,
+    ) -> Result<GuestDetailsResponse> {
+        info!(sl!(), "get guest details!");
+        let mut resp = GuestDetailsResponse::new();
+        // to get memory block size
+        let (u, v) = get_memory_info(req.mem_block_size, req.mem_hotplug_probe)?;
+        resp.mem_block_size_bytes = u;
+        resp.support_mem_hotplug_probe = v;
+
+        // to get agent details
+        let detail = get_agent_details();
+        resp.agent_details = SingularPtrField::some(detail);
+
+        Ok(resp)
+    }
+
+    // handle_ttrpc serves method of AgentService to the ttRPC server, with
+    // the handlers of the gRPC one.
+    fn handle_ttrpc(
+        &mut self,
+        method: &str,
+        payload: &[u8],
+    ) -> std::result::Result<Vec<u8>, ttrpc::Status> {
+        if !is_allowed(method) {
+            return Err(ttrpc::status(
+                ttrpc::CODE_PERMISSION_DENIED,
+                &format!("{} is not allowed", method),
+            ));
+        }
+
+        match method {
+            "CreateContainer" => {
+                let req = ttrpc_request(payload)?;
+                policy::check_create_container(&req).map_err(|e| {
+                    ttrpc::status(
+                        ttrpc::CODE_PERMISSION_DENIED,
+                        &format!("CreateContainer is not allowed: {}", e),
+                    )
+                })?;
+                ttrpc_reply(self.do_create_container(req).map(|_| Empty::new()))
+            }
+            "StartContainer" => ttrpc_call(payload, |req| {
+                self.do_start_container(req).map(|_| Empty::new())
+            }),
+            "RemoveContainer" => ttrpc_call(payload, |req| {
+                self.do_remove_container(req).map(|_| Empty::new())
+            }),
+            "ExecProcess" => ttrpc_call(payload, |req| {
+                self.do_exec_process(req).map(|_| Empty::new())
+            }),
+            "SignalProcess" => ttrpc_call(payload, |req| {
+                self.do_signal_process(req).map(|_| Empty::new())
+            }),
+            "WaitProcess" => ttrpc_call(payload, |req| self.do_wait_process(req)),
+            "ListProcesses" => ttrpc_call(payload, |req| self.do_list_processes(req)),
+            "UpdateContainer" => ttrpc_call(payload, |req| {
+                self.do_update_container(req).map(|_| Empty::new())
+            }),
+            "StatsContainer" => ttrpc_call(payload, |req| self.do_stats_container(req)),
+            "WriteStdin" => ttrpc_call(payload, |req| self.do_write_stream(req)),
+            "ReadStdout" => ttrpc_call(payload, |req| self.do_read_stream(req, true)),
+            "ReadStderr" => ttrpc_call(payload, |req| self.do_read_stream(req, false)),
+            "CloseStdin" => ttrpc_call(payload, |req| {
+                self.do_close_stdin(req).map(|_| Empty::new())
+            }),
+            "TtyWinResize" => ttrpc_call(payload, |req| {
+                self.do_tty_win_resize(req).map(|_| Empty::new())
+            }),
+            "UpdateInterface" => ttrpc_call(payload, |req| self.do_update_interface(req)),
+            "UpdateRoutes" => ttrpc_call(payload, |req| self.do_update_routes(req)),
+            "ListInterfaces" => ttrpc_call(payload, |req| self.do_list_interfaces(req)),
+            "ListRoutes" => ttrpc_call(payload, |req| self.do_list_routes(req)),
+            "UpdateTrafficControl" => {
+                ttrpc_call(payload, |req| self.do_update_traffic_control(req))
+            }
+            "ListTrafficControl" => ttrpc_call(payload, |req| self.do_list_traffic_control(req)),
+            "StartTracing" => ttrpc_call(payload, |req| {
+                self.do_start_tracing(req).map(|_| Empty::new())
+            }),
+            "StopTracing" => ttrpc_call(payload, |req| {
+                self.do_stop_tracing(req).map(|_| Empty::new())
+            }),
+            "CreateSandbox" => ttrpc_call(payload, |req| {
+                self.do_create_sandbox(req).map(|_| Empty::new())
+            }),
+            "DestroySandbox" => ttrpc_call(payload, |req| {
+                self.do_destroy_sandbox(req).map(|_| Empty::new())
+            }),
+            "OnlineCPUMem" => ttrpc_call(payload, |req: protocols::agent::OnlineCPUMemRequest| {
+                sandbox::online_cpu_memory(&self.sandbox, &req).map(|_| Empty::new())
+            }),
+            "ReseedRandomDev" => {
+                ttrpc_call(payload, |req: protocols::agent::ReseedRandomDevRequest| {
+                    random::reseed_rng(req.data.as_slice()).map(|_| Empty::new())
+                })
+            }
+            "GetGuestDetails" => ttrpc_call(payload, |req| self.do_get_guest_details(req)),
+            "MemHotplugByProbe" => ttrpc_call(
+                payload,
+                |req: protocols::agent::MemHotplugByProbeRequest| {
+                    do_mem_hotplug_by_probe(&req.memHotplugProbeAddr).map(|_| Empty::new())
+                },
+            ),
+            "SetGuestDateTime" => {
+                ttrpc_call(payload, |req: protocols::agent::SetGuestDateTimeRequest| {
+                    do_set_guest_date_time(req.Sec, req.Usec).map(|_| Empty::new())
+                })
+            }
+            "CopyFile" => ttrpc_call(payload, |req| {
+                do_copy_file(&req, self.sandbox.clone()).map(|_| Empty::new())
+            }),
+            "GetVolumeStats" => ttrpc_call(payload, |req| self.do_get_volume_stats(req)),
+            "ResizeVolume" => ttrpc_call(payload, |req| {
+                self.do_resize_volume(req).map(|_| Empty::new())
+            }),
+            "SetLogLevel" => ttrpc_call(payload, |req| {
+                self.do_set_log_level(req).map(|_| Empty::new())
+            }),
+            "GetMetrics" => ttrpc_call(payload, |req| self.do_get_metrics(req)),
+            "SetPolicy" => ttrpc_call(payload, |req| self.do_set_policy(req).map(|_| Empty::new())),
+            // GetNetworkEvents streams, which ttRPC does not, and pausing is
+            // not supported by either server
+            _ => Err(ttrpc::status(
+                ttrpc::CODE_UNIMPLEMENTED,
+                &format!("method {} not implemented", method),
+            )),
+        }
+    }
+}
+
+impl protocols::agent_grpc::AgentService for agentService {
+    fn create_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::CreateContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = policy::check_create_container(&req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::PermissionDenied,
+                    Some(format!("CreateContainer is not allowed: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "fail to deny CreateContainer"));
+            ctx.spawn(f);
+            return;
+        }
+
+        if let Err(_) = self.do_create_container(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some("fail to create container".to_string()),
+                ))
+                .map_err(|_e| error!(sl!(), "container create fail"));
+            ctx.spawn(f);
+            return;
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(move |_e| error!(sl!(), "fail to create container"));
+            ctx.spawn(f);
+        }
+    }
+
+    fn start_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StartContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(_) = self.do_start_container(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some("fail to find container".to_string()),
+                ))
+                .map_err(move |_e| error!(sl!(), "get container fail"));
+            ctx.spawn(f);
+            return;
+        }
+
+        info!(sl!(), "exec process!\n");
+
+        let resp = Empty::new();
+        let f = sink
+            .success(resp)
+            .map_err(move |_e| error!(sl!(), "fail to create container"));
+        ctx.spawn(f);
+    }
+
+    fn remove_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::RemoveContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(_) = self.do_remove_container(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(String::from("fail to remove container")),
+                ))
+                .map_err(move |_e| error!(sl!(), "remove container failed"));
+            ctx.spawn(f);
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot destroy container"));
+            ctx.spawn(f);
+        }
+    }
+    fn exec_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ExecProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(_) = self.do_exec_process(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(String::from("fail to exec process!")),
+                ))
+                .map_err(|_e| error!(sl!(), "fail to exec process!"));
+            ctx.spawn(f);
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(move |_e| error!(sl!(), "cannot exec process"));
+            ctx.spawn(f);
+        }
+    }
+    fn signal_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::SignalProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(_) = self.do_signal_process(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(String::from("fail to signal process!")),
+                ))
+                .map_err(|_e| error!(sl!(), "fail to signal process!"));
+            ctx.spawn(f);
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot signal process"));
+            ctx.spawn(f);
+        }
+    }
+    fn wait_process(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::WaitProcessRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::WaitProcessResponse>,
     ) {
-        let cid = req.container_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
+        let _rpc = rpc_start!(ctx, sink);
 
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
+        if let Ok(resp) = self.do_wait_process(req) {
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot wait process"));
+            ctx.spawn(f);
+        } else {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(String::from("fail to wait process!")),
+                ))
+                .map_err(|_e| error!(sl!(), "fail to wait process!"));
+            ctx.spawn(f);
+        }
+    }
+    fn list_processes(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ListProcessesRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::ListProcessesResponse>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
 
-        let resp = match ctr.stats() {
-            Err(_e) => {
+        let resp = match self.do_list_processes(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("internal error!".to_string()),
+                        RpcStatusCode::InvalidArgument,
+                        Some(format!("list processes: {}", e)),
                     ))
-                    .map_err(|_e| error!(sl!(), "internal error!"));
+                    .map_err(|_e| error!(sl!(), "list processes failed"));
                 ctx.spawn(f);
                 return;
             }
+        };
+
+        let f = sink
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "list processes failed"));
+        ctx.spawn(f)
+    }
+    fn update_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::UpdateContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = self.do_update_container(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(format!("update container: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "update container failed!"));
+            ctx.spawn(f);
+            return;
+        }
+
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(|_e| error!(sl!(), "update container failed!"));
+        ctx.spawn(f)
+    }
+    fn stats_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StatsContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::StatsContainerResponse>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
 
-            Ok(r) => r,
+        let resp = match self.do_stats_container(req) {
+            Ok(resp) => resp,
+            Err(e) => {
+                let f = sink
+                    .fail(RpcStatus::new(
+                        RpcStatusCode::Internal,
+                        Some(format!("stats container: {}", e)),
+                    ))
+                    .map_err(|_e| error!(sl!(), "stats containers failed!"));
+                ctx.spawn(f);
+                return;
+            }
         };
 
         let f = sink
             .success(resp)
             .map_err(|_e| error!(sl!(), "stats containers failed!"));
-        ctx.spawn(f);
+        ctx.spawn(f)
     }
     fn pause_container(
         &mut self,
@@ -803,6 +1300,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::WriteStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::WriteStreamResponse>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         if let Ok(resp) = self.do_write_stream(req) {
             let f = sink
                 .success(resp)
@@ -825,6 +1324,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         if let Ok(resp) = self.do_read_stream(req, true) {
             let f = sink
                 .success(resp)
@@ -847,6 +1348,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         if let Ok(resp) = self.do_read_stream(req, false) {
             let f = sink
                 .success(resp)
@@ -869,41 +1372,24 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CloseStdinRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let cid = req.container_id.clone();
-        let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        let p = match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
-            Ok(v) => v,
-            Err(_) => {
-                let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(String::from("invalid argument")),
-                    ))
-                    .map_err(|_e| error!(sl!(), "invalid argument"));
-                ctx.spawn(f);
-                return;
-            }
-        };
+        let _rpc = rpc_start!(ctx, sink);
 
-        if p.term_master.is_some() {
-            let _ = unistd::close(p.term_master.unwrap());
-            p.term_master = None;
-        }
-
-        if p.parent_stdin.is_some() {
-            let _ = unistd::close(p.parent_stdin.unwrap());
-            p.parent_stdin = None;
+        if let Err(e) = self.do_close_stdin(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::InvalidArgument,
+                    Some(format!("close stdin: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "close stdin failed"));
+            ctx.spawn(f);
+            return;
         }
 
-        let resp = Empty::new();
-
+        let empty = protocols::empty::Empty::new();
         let f = sink
-            .success(resp)
+            .success(empty)
             .map_err(|_e| error!(sl!(), "close stdin failed"));
-        ctx.spawn(f);
+        ctx.spawn(f)
     }
 
     fn tty_win_resize(
@@ -912,49 +1398,23 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::TtyWinResizeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let cid = req.container_id.clone();
-        let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-        let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false).unwrap();
+        let _rpc = rpc_start!(ctx, sink);
 
-        if p.term_master.is_none() {
+        if let Err(e) = self.do_tty_win_resize(req) {
             let f = sink
                 .fail(RpcStatus::new(
-                    RpcStatusCode::Unavailable,
-                    Some("no tty".to_string()),
+                    RpcStatusCode::Internal,
+                    Some(format!("tty win resize: {}", e)),
                 ))
                 .map_err(|_e| error!(sl!(), "tty resize"));
             ctx.spawn(f);
             return;
         }
 
-        let fd = p.term_master.unwrap();
-        unsafe {
-            let win = winsize {
-                ws_row: req.row as c_ushort,
-                ws_col: req.column as c_ushort,
-                ws_xpixel: 0,
-                ws_ypixel: 0,
-            };
-
-            let err = libc::ioctl(fd, TIOCSWINSZ, &win);
-            if let Err(_) = Errno::result(err).map(drop) {
-                let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("ioctl error".to_string()),
-                    ))
-                    .map_err(|_e| error!(sl!(), "ioctl error!"));
-                ctx.spawn(f);
-                return;
-            }
-        }
-
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .map_err(|_e| error!(sl!(), "tty resize"));
         ctx.spawn(f)
     }
     fn update_interface(
@@ -963,23 +1423,15 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateInterfaceRequest,
         sink: ::grpcio::UnarySink<protocols::types::Interface>,
     ) {
-        let interface = req.interface.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
-
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
+        let _rpc = rpc_start!(ctx, sink);
 
-        let iface = match rtnl.update_interface(interface.as_ref().unwrap()) {
-            Ok(v) => v,
-            Err(_) => {
+        let resp = match self.do_update_interface(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
                         RpcStatusCode::Internal,
-                        Some("update interface".to_string()),
+                        Some(format!("update interface: {}", e)),
                     ))
                     .map_err(|_e| error!(sl!(), "update interface"));
                 ctx.spawn(f);
@@ -988,8 +1440,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         };
 
         let f = sink
-            .success(iface)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "update interface"));
         ctx.spawn(f)
     }
     fn update_routes(
@@ -998,42 +1450,25 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
-        let mut routes = protocols::agent::Routes::new();
-        let rs = req.routes.clone().unwrap().Routes.into_vec();
-
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
+        let _rpc = rpc_start!(ctx, sink);
 
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-        // get current routes to return when error out
-        let crs = match rtnl.list_routes() {
-            Ok(routes) => routes,
-            Err(_) => {
+        let resp = match self.do_update_routes(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
                         RpcStatusCode::Internal,
-                        Some("update routes".to_string()),
+                        Some(format!("update routes: {}", e)),
                     ))
                     .map_err(|_e| error!(sl!(), "update routes"));
                 ctx.spawn(f);
                 return;
             }
         };
-        let v = match rtnl.update_routes(rs.as_ref()) {
-            Ok(value) => value,
-            Err(_) => crs,
-        };
-
-        routes.set_Routes(RepeatedField::from_vec(v));
 
         let f = sink
-            .success(routes)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "update routes"));
         ctx.spawn(f)
     }
     fn list_interfaces(
@@ -1042,22 +1477,15 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListInterfacesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Interfaces>,
     ) {
-        let mut interface = protocols::agent::Interfaces::new();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
+        let _rpc = rpc_start!(ctx, sink);
 
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-        let v = match rtnl.list_interfaces() {
-            Ok(value) => value,
-            Err(_) => {
+        let resp = match self.do_list_interfaces(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
                         RpcStatusCode::Internal,
-                        Some("list interface".to_string()),
+                        Some(format!("list interfaces: {}", e)),
                     ))
                     .map_err(|_e| error!(sl!(), "list interface"));
                 ctx.spawn(f);
@@ -1065,11 +1493,9 @@ impl protocols::agent_grpc::AgentService for agentService {
             }
         };
 
-        interface.set_Interfaces(RepeatedField::from_vec(v));
-
         let f = sink
-            .success(interface)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "list interface"));
         ctx.spawn(f)
     }
     fn list_routes(
@@ -1078,23 +1504,15 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
-        let mut routes = protocols::agent::Routes::new();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
+        let _rpc = rpc_start!(ctx, sink);
 
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-
-        let v = match rtnl.list_routes() {
-            Ok(value) => value,
-            Err(_) => {
+        let resp = match self.do_list_routes(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
                         RpcStatusCode::Internal,
-                        Some("list routes".to_string()),
+                        Some(format!("list routes: {}", e)),
                     ))
                     .map_err(|_e| error!(sl!(), "list routes"));
                 ctx.spawn(f);
@@ -1102,91 +1520,148 @@ impl protocols::agent_grpc::AgentService for agentService {
             }
         };
 
-        routes.set_Routes(RepeatedField::from_vec(v));
-
         let f = sink
-            .success(routes)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "list routes"));
         ctx.spawn(f)
     }
-    fn start_tracing(
+    fn get_network_events(
         &mut self,
         ctx: ::grpcio::RpcContext,
-        req: protocols::agent::StartTracingRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        _req: protocols::agent::GetNetworkEventsRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::NetworkEvent>,
     ) {
-        info!(sl!(), "start_tracing {:?} self.test={}", req, self.test);
-        self.test = 2;
-        let empty = protocols::empty::Empty::new();
+        let _rpc = rpc_start!(ctx, sink);
+
+        let rx = {
+            let s = Arc::clone(&self.sandbox);
+            let mut sandbox = s.lock().unwrap();
+            sandbox.network.subscribe()
+        };
+
+        // the stream ends when the client goes away, then the
+        // receiver is dropped and the subscription removed, or when
+        // it falls too far behind the events
+        let events = rx
+            .map(|e| (e, WriteFlags::default()))
+            .map_err(|_| grpcio::Error::RemoteStopped);
+
         let f = sink
-            .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .send_all(events)
+            .map(|_| ())
+            .map_err(|e| info!(sl!(), "network events stream closed: {:?}", e));
         ctx.spawn(f)
     }
-    fn stop_tracing(
+    fn update_traffic_control(
         &mut self,
         ctx: ::grpcio::RpcContext,
-        req: protocols::agent::StopTracingRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        req: protocols::agent::UpdateTrafficControlRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::TrafficControl>,
     ) {
-        let empty = protocols::empty::Empty::new();
+        let _rpc = rpc_start!(ctx, sink);
+
+        let resp = match self.do_update_traffic_control(req) {
+            Ok(resp) => resp,
+            Err(e) => {
+                let f = sink
+                    .fail(RpcStatus::new(
+                        RpcStatusCode::Internal,
+                        Some(format!("update traffic control: {}", e)),
+                    ))
+                    .map_err(|_e| error!(sl!(), "update traffic control"));
+                ctx.spawn(f);
+                return;
+            }
+        };
+
         let f = sink
-            .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "update traffic control"));
         ctx.spawn(f)
     }
-    fn create_sandbox(
+    fn list_traffic_control(
         &mut self,
         ctx: ::grpcio::RpcContext,
-        req: protocols::agent::CreateSandboxRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        req: protocols::agent::ListTrafficControlRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::TrafficControl>,
     ) {
-        let mut err = "".to_string();
-
-        {
-            let sandbox = self.sandbox.clone();
-            let mut s = sandbox.lock().unwrap();
-
-            let _ = fs::remove_dir_all(CONTAINER_BASE);
-            let _ = fs::create_dir_all(CONTAINER_BASE);
-
-            s.hostname = req.hostname.clone();
-            s.running = true;
-
-            if req.sandbox_id.len() > 0 {
-                s.id = req.sandbox_id.clone();
-            }
+        let _rpc = rpc_start!(ctx, sink);
 
-            match s.setup_shared_namespaces() {
-                Ok(_) => (),
-                Err(e) => err = e.to_string(),
-            }
-            if err.len() != 0 {
-                let rpc_status =
-                    grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
+        let resp = match self.do_list_traffic_control(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
-                    .fail(rpc_status)
-                    .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+                    .fail(RpcStatus::new(
+                        RpcStatusCode::Internal,
+                        Some(format!("list traffic control: {}", e)),
+                    ))
+                    .map_err(|_e| error!(sl!(), "list traffic control"));
                 ctx.spawn(f);
                 return;
             }
+        };
+
+        let f = sink
+            .success(resp)
+            .map_err(|_e| error!(sl!(), "list traffic control"));
+        ctx.spawn(f)
+    }
+    fn start_tracing(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StartTracingRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = self.do_start_tracing(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(format!("start tracing: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "start tracing"));
+            ctx.spawn(f);
+            return;
         }
 
-        match add_storages(sl!(), req.storages.to_vec(), self.sandbox.clone()) {
-            Ok(m) => {
-                let sandbox = self.sandbox.clone();
-                let mut s = sandbox.lock().unwrap();
-                s.mounts = m
-            }
-            Err(e) => err = e.to_string(),
-        };
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(|_e| error!(sl!(), "start tracing"));
+        ctx.spawn(f)
+    }
+    fn stop_tracing(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::StopTracingRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        let _ = self.do_stop_tracing(req.clone());
+
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(f)
+    }
+    fn create_sandbox(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::CreateSandboxRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
 
-        if err.len() != 0 {
-            let rpc_status =
-                grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
+        if let Err(e) = self.do_create_sandbox(req) {
             let f = sink
-                .fail(rpc_status)
-                .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+                .fail(RpcStatus::new(
+                    RpcStatusCode::FailedPrecondition,
+                    Some(format!("create sandbox: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "create sandbox"));
             ctx.spawn(f);
             return;
         }
@@ -1194,7 +1669,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .map_err(|_e| error!(sl!(), "create sandbox"));
         ctx.spawn(f)
     }
     fn destroy_sandbox(
@@ -1203,19 +1678,23 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::DestroySandboxRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-        // destroy all containers, clean up, notify agent to exit
-        // etc.
-        sandbox.destroy().unwrap();
+        let _rpc = rpc_start!(ctx, sink);
 
-        sandbox.sender.as_ref().unwrap().send(1).unwrap();
-        sandbox.sender = None;
+        if let Err(e) = self.do_destroy_sandbox(req) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(format!("destroy sandbox: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "destroy sandbox"));
+            ctx.spawn(f);
+            return;
+        }
 
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            .map_err(|_e| error!(sl!(), "destroy sandbox"));
         ctx.spawn(f)
     }
     fn online_cpu_mem(
@@ -1224,13 +1703,13 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::OnlineCPUMemRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         // sleep 5 seconds for debug
         // thread::sleep(Duration::new(5, 0));
-        let s = Arc::clone(&self.sandbox);
-        let sandbox = s.lock().unwrap();
         let empty = protocols::empty::Empty::new();
 
-        if let Err(_) = sandbox.online_cpu_memory(&req) {
+        if let Err(_) = sandbox::online_cpu_memory(&self.sandbox, &req) {
             let f = sink
                 .fail(RpcStatus::new(
                     RpcStatusCode::Internal,
@@ -1253,6 +1732,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReseedRandomDevRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         let empty = protocols::empty::Empty::new();
         if let Err(_) = random::reseed_rng(req.data.as_slice()) {
             let f = sink
@@ -1276,36 +1757,26 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::GuestDetailsRequest,
         sink: ::grpcio::UnarySink<protocols::agent::GuestDetailsResponse>,
     ) {
-        info!(sl!(), "get guest details!");
-        let mut resp = GuestDetailsResponse::new();
-        // to get memory block size
-        match get_memory_info(req.mem_block_size, req.mem_hotplug_probe) {
-            Ok((u, v)) => {
-                resp.mem_block_size_bytes = u;
-                resp.support_mem_hotplug_probe = v;
-            }
+        let _rpc = rpc_start!(ctx, sink);
 
-            Err(_) => {
-                info!(sl!(), "fail to get memory info!");
+        let resp = match self.do_get_guest_details(req) {
+            Ok(resp) => resp,
+            Err(e) => {
                 let f = sink
                     .fail(RpcStatus::new(
                         RpcStatusCode::Internal,
-                        Some(String::from("internal error")),
+                        Some(format!("get guest details: {}", e)),
                     ))
-                    .map_err(|_e| error!(sl!(), "cannot get memory info!"));
+                    .map_err(|_e| error!(sl!(), "cannot get guest detail"));
                 ctx.spawn(f);
                 return;
             }
-        }
-
-        // to get agent details
-        let detail = get_agent_details();
-        resp.agent_details = SingularPtrField::some(detail);
+        };
 
         let f = sink
             .success(resp)
             .map_err(|_e| error!(sl!(), "cannot get guest detail"));
-        ctx.spawn(f);
+        ctx.spawn(f)
     }
     fn mem_hotplug_by_probe(
         &mut self,
@@ -1313,6 +1784,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::MemHotplugByProbeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         let empty = protocols::empty::Empty::new();
 
         if let Err(_) = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr) {
@@ -1337,6 +1810,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::SetGuestDateTimeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         let empty = protocols::empty::Empty::new();
         if let Err(_) = do_set_guest_date_time(req.Sec, req.Usec) {
             let f = sink
@@ -1360,8 +1835,10 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CopyFileRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let _rpc = rpc_start!(ctx, sink);
+
         let empty = protocols::empty::Empty::new();
-        if let Err(_) = do_copy_file(&req) {
+        if let Err(_) = do_copy_file(&req, self.sandbox.clone()) {
             let f = sink
                 .fail(RpcStatus::new(
                     RpcStatusCode::Internal,
@@ -1377,6 +1854,140 @@ impl protocols::agent_grpc::AgentService for agentService {
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
         ctx.spawn(f)
     }
+
+    fn get_volume_stats(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::GetVolumeStatsRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::VolumeStatsResponse>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        let resp = match self.do_get_volume_stats(req.clone()) {
+            Ok(resp) => resp,
+            Err(e) => {
+                let f = sink
+                    .fail(RpcStatus::new(
+                        RpcStatusCode::Internal,
+                        Some(format!("get volume stats: {}", e)),
+                    ))
+                    .map_err(|_e| error!(sl!(), "get volume stats"));
+                ctx.spawn(f);
+                return;
+            }
+        };
+
+        let f = sink
+            .success(resp)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(f)
+    }
+
+    fn resize_volume(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ResizeVolumeRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = self.do_resize_volume(req.clone()) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::Internal,
+                    Some(format!("resize volume: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "resize volume"));
+            ctx.spawn(f);
+            return;
+        }
+
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(f)
+    }
+
+    fn set_log_level(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::SetLogLevelRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = self.do_set_log_level(req.clone()) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::InvalidArgument,
+                    Some(format!("set log level: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "set log level"));
+            ctx.spawn(f);
+            return;
+        }
+
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(f)
+    }
+
+    fn get_metrics(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::GetMetricsRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::Metrics>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        let resp = match self.do_get_metrics(req.clone()) {
+            Ok(resp) => resp,
+            Err(e) => {
+                let f = sink
+                    .fail(RpcStatus::new(
+                        RpcStatusCode::Internal,
+                        Some(format!("get metrics: {}", e)),
+                    ))
+                    .map_err(|_e| error!(sl!(), "get metrics"));
+                ctx.spawn(f);
+                return;
+            }
+        };
+
+        let f = sink
+            .success(resp)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(f)
+    }
+
+    fn set_policy(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::SetPolicyRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let _rpc = rpc_start!(ctx, sink);
+
+        if let Err(e) = self.do_set_policy(req.clone()) {
+            let f = sink
+                .fail(RpcStatus::new(
+                    RpcStatusCode::InvalidArgument,
+                    Some(format!("set policy: {}", e)),
+                ))
+                .map_err(|_e| error!(sl!(), "set policy"));
+            ctx.spawn(f);
+            return;
+        }
+
+        let empty = protocols::empty::Empty::new();
+        let f = sink
+            .success(empty)
+            .map_err(move |_e| error!(sl!(), "failed to reply to set policy"));
+        ctx.spawn(f)
+    }
 }
 
 #[derive(Clone)]
@@ -1506,17 +2117,20 @@ fn read_stream(fd: RawFd, l: usize) -> Result<Vec<u8>> {
     Ok(v)
 }
 
+// get_container returns the container cid, to lock once the sandbox is
+// unlocked.
+fn get_container(sandbox: &Arc<Mutex<Sandbox>>, cid: &str) -> Result<Arc<Mutex<LinuxContainer>>> {
+    match sandbox.lock().unwrap().get_container(cid) {
+        Some(v) => Ok(v),
+        None => Err(ErrorKind::ErrorCode(String::from("Invalid container id")).into()),
+    }
+}
+
 fn find_process<'a>(
-    sandbox: &'a mut Sandbox,
-    cid: &'a str,
+    ctr: &'a mut LinuxContainer,
     eid: &'a str,
     init: bool,
 ) -> Result<&'a mut Process> {
-    let ctr = match sandbox.get_container(cid) {
-        Some(v) => v,
-        None => return Err(ErrorKind::ErrorCode(String::from("Invalid container id")).into()),
-    };
-
     if init && eid == "" {
         let p = match ctr.processes.get_mut(&ctr.init_process_pid) {
             Some(v) => v,
@@ -1536,7 +2150,13 @@ fn find_process<'a>(
     Ok(p)
 }
 
-pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16) -> Server {
+// start starts the gRPC server, with mutual TLS when there are TLS files.
+pub fn start<S: Into<String>>(
+    sandbox: Arc<Mutex<Sandbox>>,
+    host: S,
+    port: u16,
+    tls: Option<&TlsFiles>,
+) -> Server {
     let env = Arc::new(
         EnvBuilder::new()
             .cq_count(1)
@@ -1551,15 +2171,17 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
     };
     let service = protocols::agent_grpc::create_agent_service(worker);
     let hservice = protocols::health_grpc::create_health(healthService);
-    let mut server = ServerBuilder::new(env)
+    let builder = ServerBuilder::new(env)
         .register_service(service)
         .register_service(hservice)
-        .requests_slot_per_cq(1024)
-        .bind(host, port)
-        .build()
-        .unwrap();
+        .requests_slot_per_cq(1024);
+    let builder = match tls {
+        Some(files) => builder.bind_secure(host, port, files.credentials()),
+        None => builder.bind(host, port),
+    };
+    let mut server = builder.build().unwrap();
     server.start();
-    info!(sl!(), "gRPC server started");
+    info!(sl!(), "gRPC server started"; "tls" => tls.is_some());
     for &(ref host, port) in server.bind_addrs() {
         info!(sl!(), "listening"; "host" => host,
         "port" => port);
@@ -1568,6 +2190,174 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
     server
 }
 
+// start_ttrpc starts the ttRPC server of the same services as the gRPC
+// one, for a fraction of its memory.
+pub fn start_ttrpc(sandbox: Arc<Mutex<Sandbox>>, host: &str, port: u16) -> Result<ttrpc::Server> {
+    let worker = agentService {
+        sandbox: sandbox,
+        test: 1,
+    };
+    let service: ttrpc::MethodHandler = Arc::new(move |req| {
+        let _timer = metrics::rpc_timer(req.get_method());
+        let _span = tracer::rpc_span(req.get_method(), || {
+            req.get_metadata()
+                .iter()
+                .map(|kv| (kv.key.clone(), kv.value.clone()))
+                .collect()
+        });
+        worker
+            .clone()
+            .handle_ttrpc(req.get_method(), req.get_payload())
+    });
+    let hservice: ttrpc::MethodHandler = Arc::new(|req| match req.get_method() {
+        "Check" => ttrpc_call(req.get_payload(), |_: protocols::health::CheckRequest| {
+            let mut resp = HealthCheckResponse::new();
+            resp.set_status(HealthCheckResponse_ServingStatus::SERVING);
+            Ok(resp)
+        }),
+        "Version" => ttrpc_call(req.get_payload(), |_: protocols::health::CheckRequest| {
+            let mut rep = protocols::health::VersionCheckResponse::new();
+            rep.agent_version = AGENT_VERSION.to_string();
+            rep.grpc_version = API_VERSION.to_string();
+            Ok(rep)
+        }),
+        _ => Err(ttrpc::status(
+            ttrpc::CODE_UNIMPLEMENTED,
+            &format!("method {} not implemented", req.get_method()),
+        )),
+    });
+
+    let mut server = ttrpc::Server::new()
+        .register_service(AGENT_SERVICE_NAME, service)
+        .register_service(HEALTH_SERVICE_NAME, hservice)
+        .bind(host, port)?;
+    server.start()?;
+    info!(sl!(), "ttRPC server started"; "host" => host, "port" => port);
+
+    Ok(server)
+}
+
+// new_process creates the process of ocip, with the stdio pipes configured.
+fn new_process(ocip: &protocols::oci::Process, id: &str, init: bool) -> Result<Process> {
+    let p = Process::new(&sl!(), ocip, id, init)?;
+
+    let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
+    if pipe_size > 0 {
+        p.set_pipe_size(pipe_size as i32)?;
+    }
+
+    Ok(p)
+}
+
+// rpc_start starts the span of a gRPC call of method, and times it.
+fn rpc_start(ctx: &RpcContext, method: &str) -> (tracer::Span, metrics::RpcTimer) {
+    let span = tracer::rpc_span(method, || {
+        ctx.request_headers()
+            .iter()
+            .map(|(k, v)| (k.to_string(), String::from_utf8_lossy(v).to_string()))
+            .collect()
+    });
+    (span, metrics::rpc_timer(method))
+}
+
+// is_allowed tells whether the configuration serves method.
+fn is_allowed(method: &str) -> bool {
+    AGENT_CONFIG.read().unwrap().is_allowed(method) && policy::allows_rpc(method)
+}
+
+fn not_allowed(method: &str) -> RpcStatus {
+    RpcStatus::new(
+        RpcStatusCode::PermissionDenied,
+        Some(format!("{} is not allowed", method)),
+    )
+}
+
+// rpc_method returns the method of the gRPC call of path, e.g.
+// CreateContainer of /grpc.AgentService/CreateContainer.
+fn rpc_method(path: &[u8]) -> String {
+    let path = String::from_utf8_lossy(path);
+    path.rsplit('/').next().unwrap_or_default().to_string()
+}
+
+// ttrpc_call decodes the request of a ttRPC call, runs f on it and
+// encodes its response.
+fn ttrpc_call<Req, Resp, F>(payload: &[u8], f: F) -> std::result::Result<Vec<u8>, ttrpc::Status>
+where
+    Req: protobuf::Message,
+    Resp: protobuf::Message,
+    F: FnOnce(Req) -> Result<Resp>,
+{
+    ttrpc_reply(f(ttrpc_request(payload)?))
+}
+
+fn ttrpc_request<Req: protobuf::Message>(
+    payload: &[u8],
+) -> std::result::Result<Req, ttrpc::Status> {
+    protobuf::parse_from_bytes::<Req>(payload)
+        .map_err(|e| ttrpc::status(ttrpc::CODE_INVALID_ARGUMENT, &format!("{}", e)))
+}
+
+fn ttrpc_reply<Resp: protobuf::Message>(
+    resp: Result<Resp>,
+) -> std::result::Result<Vec<u8>, ttrpc::Status> {
+    let resp = resp.map_err(|e| ttrpc::status(ttrpc::CODE_INTERNAL, &format!("{}", e)))?;
+
+    resp.write_to_bytes()
+        .map_err(|e| ttrpc::status(ttrpc::CODE_INTERNAL, &format!("{}", e)))
+}
+
+// AgentServer is the server of the agent, of either protocol.
+pub enum AgentServer {
+    Grpc(Server),
+    Ttrpc(ttrpc::Server),
+}
+
+impl AgentServer {
+    pub fn shutdown(&mut self) {
+        match self {
+            AgentServer::Grpc(server) => {
+                let _ = server.shutdown().wait();
+            }
+            AgentServer::Ttrpc(server) => server.shutdown(),
+        }
+    }
+}
+
+// start_server serves the runtime with server_type at addr. A virtio-serial
+// port is relayed to the server listening on a unix socket of the agent.
+pub fn start_server(
+    sandbox: Arc<Mutex<Sandbox>>,
+    server_type: ServerType,
+    addr: &ServerAddr,
+    tls: Option<&TlsFiles>,
+) -> Result<AgentServer> {
+    if let (ServerType::Ttrpc, Some(_)) = (&server_type, tls) {
+        return Err(ErrorKind::ErrorCode(String::from("TLS not supported by ttRPC")).into());
+    }
+
+    let (host, port) = match addr {
+        ServerAddr::Vsock(cid, port) => (format!("vsock://{}", *cid as i32), *port),
+        ServerAddr::Unix(path) => (format!("unix://{}", path), 0),
+        ServerAddr::Serial(_) => (format!("unix://{}", SERIAL_BACKEND_SOCKET), 0),
+    };
+
+    if let ServerAddr::Serial(_) = addr {
+        fs::create_dir_all(Path::new(SERIAL_BACKEND_SOCKET).parent().unwrap())?;
+        let _ = fs::remove_file(SERIAL_BACKEND_SOCKET);
+    }
+
+    let server = match server_type {
+        ServerType::Grpc => AgentServer::Grpc(start(sandbox, host, port, tls)),
+        ServerType::Ttrpc => AgentServer::Ttrpc(start_ttrpc(sandbox, &host, port)?),
+    };
+
+    if let ServerAddr::Serial(port) = addr {
+        serial::serve(port, SERIAL_BACKEND_SOCKET);
+    }
+
+    Ok(server)
+}
+
 // This function updates the container namespaces configuration based on the
 // sandbox information. When the sandbox is created, it can be setup in a way
 // that all containers will share some specific namespaces. This is the agent
@@ -1684,13 +2474,27 @@ fn do_set_guest_date_time(sec: i64, usec: i64) -> Result<()> {
     Ok(())
 }
 
-fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
-    let path = fs::canonicalize(req.path.as_str())?;
+fn do_copy_file(req: &CopyFileRequest, sandbox: Arc<Mutex<Sandbox>>) -> Result<()> {
+    let path = canonicalize_path(Path::new(req.path.as_str()))?;
 
     if !path.starts_with(CONTAINER_BASE) {
         return Err(nix::Error::Sys(Errno::EINVAL).into());
     }
 
+    // the files of a watchable storage are published once complete, and
+    // checked against its limits before, and again then
+    let storages = sandbox.lock().unwrap().storages.clone();
+    let watchable = {
+        let sb = storages.lock().unwrap();
+        sb.watchable_storages
+            .values()
+            .find(|w| path.starts_with(&w.source))
+            .cloned()
+    };
+    if let Some(w) = watchable.as_ref() {
+        w.admit(&path, req.file_size)?;
+    }
+
     let parent = path.parent();
 
     let dir = if parent.is_some() {
@@ -1705,17 +2509,16 @@ fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
         }
     }
 
-    let ret = unsafe {
-        libc::chmod(
-            dir.to_str().unwrap().as_ptr() as *const libc::c_char,
-            req.dir_mode,
-        )
-    };
-
-    let _ = Errno::result(ret).map(drop)?;
+    fs::set_permissions(&dir, fs::Permissions::from_mode(req.dir_mode))?;
 
-    let mut tmpfile = path.clone();
-    tmpfile.set_extension("tmp");
+    let tmpfile = match watchable.as_ref() {
+        Some(_) => WatchableStorage::staging_path(&path),
+        None => {
+            let mut tmpfile = path.clone();
+            tmpfile.set_extension("tmp");
+            tmpfile
+        }
+    };
 
     let file = OpenOptions::new()
         .write(true)
@@ -1730,25 +2533,56 @@ fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
         return Ok(());
     }
 
-    let ret = unsafe {
-        libc::chmod(
-            tmpfile.to_str().unwrap().as_ptr() as *const libc::c_char,
-            req.file_mode,
-        )
-    };
-
-    let _ = Errno::result(ret).map(drop)?;
+    fs::set_permissions(&tmpfile, fs::Permissions::from_mode(req.file_mode))?;
     unistd::chown(
         tmpfile.to_str().unwrap(),
         Some(Uid::from_raw(req.uid as u32)),
         Some(Gid::from_raw(req.gid as u32)),
     )?;
 
-    fs::rename(tmpfile, path)?;
+    match watchable {
+        Some(w) => {
+            // under the storages lock, not to race with other copies
+            let _sb = storages.lock().unwrap();
+            if let Err(e) = w.admit(&path, req.file_size) {
+                let _ = fs::remove_file(&tmpfile);
+                return Err(e);
+            }
+            fs::rename(tmpfile, path)?;
+            w.sync()?;
+        }
+        None => fs::rename(tmpfile, path)?,
+    }
 
     Ok(())
 }
 
+// canonicalize_path resolves path as fs::canonicalize does, except that
+// its last components, e.g. a file to be created and its new parent
+// directories, may not exist yet.
+fn canonicalize_path(path: &Path) -> Result<PathBuf> {
+    if !path.is_absolute() {
+        return Err(nix::Error::Sys(Errno::EINVAL).into());
+    }
+
+    let mut existing = path.to_path_buf();
+    let mut missing = Vec::new();
+    while fs::symlink_metadata(&existing).is_err() {
+        match existing.file_name() {
+            Some(name) => missing.push(name.to_os_string()),
+            None => break,
+        }
+        existing.pop();
+    }
+
+    let mut path = fs::canonicalize(&existing)?;
+    for name in missing.iter().rev() {
+        path.push(name);
+    }
+
+    Ok(path)
+}
+
 fn setup_bundle(gspec: &Spec) -> Result<()> {
     if gspec.Root.is_none() {
         return Err(nix::Error::Sys(Errno::EINVAL).into());
@@ -1772,3 +2606,122 @@ fn setup_bundle(gspec: &Spec) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skip_if_not_root;
+    use protocols::oci::{Linux, Root};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_rpc_method() {
+        assert_eq!(
+            rpc_method(b"/grpc.AgentService/CreateContainer"),
+            "CreateContainer"
+        );
+        assert_eq!(rpc_method(b"CreateContainer"), "CreateContainer");
+        assert_eq!(rpc_method(b"/grpc.AgentService/"), "");
+    }
+
+    #[test]
+    fn test_create_container_reserved() {
+        let sandbox = Arc::new(Mutex::new(Sandbox::new(&sl!()).unwrap()));
+        let reservation = sandbox.lock().unwrap().reserve_container("a").unwrap();
+        let creating = reservation.lock().unwrap();
+
+        // created once
+        let mut service = agentService {
+            sandbox: sandbox.clone(),
+            test: 1,
+        };
+        let mut req = protocols::agent::CreateContainerRequest::new();
+        req.set_container_id("a".to_string());
+        assert!(service.do_create_container(req).is_err());
+        assert!(sandbox.lock().unwrap().reserve_container("a").is_err());
+
+        // destroyed once created
+        let (tx, rx) = mpsc::channel();
+        let s = sandbox.clone();
+        thread::spawn(move || tx.send(sandbox::destroy(&s).is_ok()).unwrap());
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+        sandbox.lock().unwrap().creating.remove("a");
+        drop(creating);
+        assert!(rx.recv_timeout(Duration::from_secs(5)).unwrap());
+        assert!(sandbox.lock().unwrap().reserve_container("a").is_ok());
+    }
+
+    fn new_container(id: &str, base: &Path, rootfs: &Path) -> LinuxContainer {
+        let mut root = Root::new();
+        root.set_Path(rootfs.to_str().unwrap().to_string());
+        let mut spec = Spec::new();
+        spec.set_Root(root);
+        spec.set_Linux(Linux::new());
+
+        let opts = CreateOpts {
+            cgroup_name: "".to_string(),
+            use_systemd_cgroup: false,
+            no_pivot_root: false,
+            no_new_keyring: false,
+            spec: Some(spec),
+            rootless_euid: false,
+            rootless_cgroup: false,
+        };
+
+        LinuxContainer::new(id, base.to_str().unwrap(), opts, &sl!()).unwrap()
+    }
+
+    #[test]
+    fn test_container_locks_apart() {
+        skip_if_not_root!();
+
+        let dir = tempdir().unwrap();
+        let rootfs = dir.path().join("rootfs");
+        fs::create_dir(&rootfs).unwrap();
+        let sandbox = Arc::new(Mutex::new(Sandbox::new(&sl!()).unwrap()));
+
+        // a container with output to read
+        let mut ctr = new_container("a", dir.path(), &rootfs);
+        let mut p = Process::new(&sl!(), &protocols::oci::Process::new(), "a", true).unwrap();
+        let (r, w) = unistd::pipe().unwrap();
+        p.parent_stdout = Some(r);
+        ctr.processes.insert(p.pid, p);
+        unistd::write(w, b"hello").unwrap();
+        sandbox
+            .lock()
+            .unwrap()
+            .add_container("a", Arc::new(Mutex::new(ctr)));
+
+        // another, busy, while the storage of a third one mounts
+        let busy = Arc::new(Mutex::new(new_container("b", dir.path(), &rootfs)));
+        sandbox.lock().unwrap().add_container("b", busy.clone());
+        let _busy = busy.lock().unwrap();
+        let storages = sandbox.lock().unwrap().storages.clone();
+        let mount_lock = storages.lock().unwrap().mount_lock("/run/c/rootfs");
+        let _mounting = mount_lock.lock().unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let s = sandbox.clone();
+        thread::spawn(move || {
+            let mut service = agentService {
+                sandbox: s,
+                test: 1,
+            };
+            let mut req = protocols::agent::ReadStreamRequest::new();
+            req.set_container_id("a".to_string());
+            req.set_exec_id("a".to_string());
+            req.set_len(5);
+            tx.send(service.do_read_stream(req, true).map(|r| r.data))
+                .unwrap();
+        });
+
+        let data = rx
+            .recv_timeout(Duration::from_secs(5))
+            .expect("ReadStdout blocked by another container")
+            .unwrap();
+        assert_eq!(data, b"hello");
+
+        let _ = unistd::close(w);
+    }
+}
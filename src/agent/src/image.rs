@@ -0,0 +1,949 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Unpacking of OCI images into a rootfs inside the guest, from an OCI
+// image layout delivered locally, so that the host never needs to see
+// the image contents. The manifest and config are checked against their
+// digests, and each layer against its digest and, once decompressed,
+// against its diff ID, before any of its entries is applied. Layers are
+// applied in order, whiteouts and opaque directories included.
+
+use flate2::read::MultiGzDecoder;
+use libc;
+use nix::sys::stat::{self, Mode, SFlag, UtimensatFlags};
+use nix::sys::time::{TimeSpec, TimeValLike};
+use nix::unistd::{self, FchownatFlags, Gid, Uid};
+use rustjail::errors::*;
+use rustjail::securejoin::secure_join_nofollow;
+use serde_json::{self, Value};
+use std::collections::{HashMap, HashSet};
+use std::ffi::CString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{symlink, OpenOptionsExt, PermissionsExt};
+use std::path::{Component, Path, PathBuf};
+
+use crate::sha256::{hex_digest, Sha256};
+
+const OCI_LAYOUT_FILE: &'static str = "oci-layout";
+const OCI_INDEX_FILE: &'static str = "index.json";
+const OCI_BLOBS_DIR: &'static str = "blobs";
+const REF_NAME_ANNOTATION: &'static str = "org.opencontainers.image.ref.name";
+const DIGEST_ALGORITHM: &'static str = "sha256";
+
+const MEDIA_TYPE_INDEX: &'static str = "application/vnd.oci.image.index.v1+json";
+const MEDIA_TYPE_DOCKER_LIST: &'static str =
+    "application/vnd.docker.distribution.manifest.list.v2+json";
+
+const WHITEOUT_PREFIX: &'static str = ".wh.";
+const WHITEOUT_OPAQUE: &'static str = ".wh..wh..opq";
+
+const TAR_BLOCK_SIZE: u64 = 512;
+const PAX_XATTR_PREFIX: &'static str = "SCHILY.xattr.";
+
+// unpack_image unpacks to rootfs the image of the layout at dir named
+// reference, by its ref name annotation or its manifest digest. The
+// reference may be empty when the layout holds a single image.
+pub fn unpack_image(dir: &Path, reference: &str, rootfs: &Path) -> Result<()> {
+    if !dir.join(OCI_LAYOUT_FILE).exists() {
+        return Err(
+            ErrorKind::ErrorCode(format!("{} is not an OCI image layout", dir.display())).into(),
+        );
+    }
+
+    let index: Value = serde_json::from_slice(&fs::read(dir.join(OCI_INDEX_FILE))?)?;
+    let desc = select_manifest(dir, &index, reference)?;
+    let manifest = read_json_blob(dir, &desc)?;
+    let config = read_json_blob(dir, &manifest["config"])?;
+
+    let layers = manifest["layers"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    let diff_ids = config["rootfs"]["diff_ids"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    if layers.len() != diff_ids.len() {
+        return Err(ErrorKind::ErrorCode(format!(
+            "image has {} layers but {} diff IDs",
+            layers.len(),
+            diff_ids.len()
+        ))
+        .into());
+    }
+
+    for (layer, diff_id) in layers.iter().zip(diff_ids.iter()) {
+        apply_layer(dir, layer, diff_id.as_str().unwrap_or(""), rootfs)?;
+    }
+
+    Ok(())
+}
+
+// select_manifest returns the descriptor of the manifest named reference
+// in index, for the platform of the guest when it is an image index.
+fn select_manifest(dir: &Path, index: &Value, reference: &str) -> Result<Value> {
+    let manifests = index["manifests"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+
+    let found: Vec<&Value> = manifests
+        .iter()
+        .filter(|m| {
+            reference.is_empty()
+                || m["digest"] == reference
+                || m["annotations"][REF_NAME_ANNOTATION] == reference
+        })
+        .collect();
+    if found.len() != 1 {
+        return Err(ErrorKind::ErrorCode(format!(
+            "{} images named {:?} in the layout",
+            found.len(),
+            reference
+        ))
+        .into());
+    }
+
+    let desc = found[0];
+    match desc["mediaType"].as_str() {
+        Some(MEDIA_TYPE_INDEX) | Some(MEDIA_TYPE_DOCKER_LIST) => {}
+        _ => return Ok(desc.clone()),
+    }
+
+    let arch = platform_architecture();
+    let index = read_json_blob(dir, desc)?;
+    index["manifests"]
+        .as_array()
+        .and_then(|m| {
+            m.iter()
+                .find(|m| m["platform"]["os"] == "linux" && m["platform"]["architecture"] == arch)
+        })
+        .cloned()
+        .ok_or_else(|| ErrorKind::ErrorCode(format!("no image for linux/{}", arch)).into())
+}
+
+// platform_architecture returns the architecture of the guest, as named
+// by OCI image platforms.
+fn platform_architecture() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        "powerpc64" => "ppc64le",
+        arch => arch,
+    }
+}
+
+// blob_path returns the path of the blob with digest in the layout.
+fn blob_path(dir: &Path, digest: &str) -> Result<PathBuf> {
+    let mut parts = digest.splitn(2, ':');
+    let (algorithm, hex) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    if algorithm != DIGEST_ALGORITHM
+        || hex.len() != 64
+        || !hex
+            .bytes()
+            .all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(&c))
+    {
+        return Err(ErrorKind::ErrorCode(format!("unsupported digest {:?}", digest)).into());
+    }
+
+    Ok(dir.join(OCI_BLOBS_DIR).join(algorithm).join(hex))
+}
+
+// read_json_blob reads the JSON blob desc describes, once checked.
+fn read_json_blob(dir: &Path, desc: &Value) -> Result<Value> {
+    let digest = desc["digest"].as_str().unwrap_or("");
+    let data = fs::read(blob_path(dir, digest)?)?;
+
+    let mut hash = Sha256::new();
+    hash.update(&data);
+    check_digest(digest, &hash.finish())?;
+
+    Ok(serde_json::from_slice(&data)?)
+}
+
+fn check_digest(expected: &str, digest: &[u8; 32]) -> Result<()> {
+    let actual = format!("{}:{}", DIGEST_ALGORITHM, hex_digest(digest));
+    if actual != expected {
+        return Err(ErrorKind::ErrorCode(format!(
+            "digest mismatch, {} instead of {}",
+            actual, expected
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+// DigestReader hashes what is read through it.
+struct DigestReader<R> {
+    inner: R,
+    hash: Sha256,
+}
+
+impl<R: Read> DigestReader<R> {
+    fn new(inner: R) -> Self {
+        DigestReader {
+            inner,
+            hash: Sha256::new(),
+        }
+    }
+
+    fn digest(self) -> [u8; 32] {
+        self.hash.finish()
+    }
+}
+
+impl<R: Read> Read for DigestReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hash.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Compression {
+    Gzip,
+    Zstd,
+}
+
+// compression returns the compression of layers of media_type, None for
+// those left uncompressed.
+fn compression(media_type: &str) -> Result<Option<Compression>> {
+    if media_type.ends_with("+gzip") || media_type.ends_with(".tar.gzip") {
+        Ok(Some(Compression::Gzip))
+    } else if media_type.ends_with("+zstd") {
+        Ok(Some(Compression::Zstd))
+    } else if media_type.ends_with(".tar") {
+        Ok(None)
+    } else {
+        Err(ErrorKind::ErrorCode(format!("unsupported layer type {}", media_type)).into())
+    }
+}
+
+// apply_layer unpacks the layer desc describes onto rootfs. Its blob is
+// copied aside first, for it not to change once checked, and no entry is
+// applied before both its digest and diff ID are.
+fn apply_layer(dir: &Path, desc: &Value, diff_id: &str, rootfs: &Path) -> Result<()> {
+    let digest = desc["digest"].as_str().unwrap_or("");
+    let media_type = desc["mediaType"].as_str().unwrap_or("");
+    let compression = compression(media_type)?;
+
+    let mut blob = DigestReader::new(File::open(blob_path(dir, digest)?)?);
+    let mut copy = scratch_file(rootfs)?;
+    io::copy(&mut blob, &mut copy)?;
+    let blob_digest = blob.digest();
+    check_digest(digest, &blob_digest)?;
+
+    let compression = match compression {
+        None => {
+            check_digest(diff_id, &blob_digest)?;
+            copy.seek(SeekFrom::Start(0))?;
+            return unpack_tar(&mut copy, rootfs);
+        }
+        Some(compression) => compression,
+    };
+
+    let tar_digest = decompress(compression, &mut copy, |tar| {
+        let mut tar = DigestReader::new(tar);
+        io::copy(&mut tar, &mut io::sink())?;
+        Ok(tar.digest())
+    })
+    .chain_err(|| format!("layer {}", digest))?;
+    check_digest(diff_id, &tar_digest)?;
+
+    decompress(compression, &mut copy, |mut tar| {
+        unpack_tar(&mut tar, rootfs)
+    })
+    .chain_err(|| format!("layer {}", digest))
+}
+
+// scratch_file returns a file next to rootfs, unlinked already, for no one
+// but the agent to reach it.
+fn scratch_file(rootfs: &Path) -> Result<File> {
+    let name = rootfs.file_name().unwrap_or_default().to_string_lossy();
+    let path = rootfs
+        .parent()
+        .unwrap_or(Path::new("/"))
+        .join(format!(".{}.layer", name));
+
+    let _ = fs::remove_file(&path);
+    let f = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)?;
+    fs::remove_file(&path)?;
+
+    Ok(f)
+}
+
+// decompress runs f on what file decompresses to, from its start.
+fn decompress<T>(
+    compression: Compression,
+    file: &mut File,
+    f: impl FnOnce(&mut dyn Read) -> Result<T>,
+) -> Result<T> {
+    file.seek(SeekFrom::Start(0))?;
+    match compression {
+        // as gzip -d, of all the members
+        Compression::Gzip => f(&mut MultiGzDecoder::new(file)),
+        Compression::Zstd => f(&mut zstd::stream::read::Decoder::new(file)?),
+    }
+}
+
+// Entry is a tar entry, once extended headers are applied.
+#[derive(Debug, Default)]
+struct Entry {
+    path: PathBuf,
+    kind: u8,
+    mode: u32,
+    uid: u32,
+    gid: u32,
+    size: u64,
+    mtime: i64,
+    link: PathBuf,
+    devmajor: u32,
+    devminor: u32,
+    xattrs: Vec<(String, Vec<u8>)>,
+}
+
+// unpack_tar applies the tar stream of a layer onto rootfs.
+fn unpack_tar<R: Read>(r: &mut R, rootfs: &Path) -> Result<()> {
+    // what this layer added, which its opaque directories keep
+    let mut added = HashSet::new();
+    let mut pax: HashMap<String, Vec<u8>> = HashMap::new();
+    let mut long_name: Option<Vec<u8>> = None;
+    let mut long_link: Option<Vec<u8>> = None;
+
+    loop {
+        let mut hdr = [0u8; TAR_BLOCK_SIZE as usize];
+        if read_block(r, &mut hdr)? == 0 || hdr.iter().all(|b| *b == 0) {
+            break;
+        }
+
+        let checksum: u32 = hdr
+            .iter()
+            .enumerate()
+            .map(|(i, b)| if (148..156).contains(&i) { b' ' } else { *b } as u32)
+            .sum();
+        if parse_number(&hdr[148..156])? != checksum as u64 {
+            return Err(ErrorKind::ErrorCode("bad tar header checksum".to_string()).into());
+        }
+
+        let kind = hdr[156];
+        let size = parse_number(&hdr[124..136])?;
+
+        match kind {
+            b'x' | b'g' | b'L' | b'K' => {
+                let data = read_data(r, size)?;
+                match kind {
+                    b'x' => pax = parse_pax(&data)?,
+                    b'L' => long_name = Some(trim_nul(&data).to_vec()),
+                    b'K' => long_link = Some(trim_nul(&data).to_vec()),
+                    // global headers hold nothing of use here
+                    _ => {}
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut name = trim_nul(&hdr[0..100]).to_vec();
+        // POSIX ustar, unlike GNU tar, splits long names with a prefix
+        if &hdr[257..263] == b"ustar\0" && hdr[345] != 0 {
+            let mut prefixed = trim_nul(&hdr[345..500]).to_vec();
+            prefixed.push(b'/');
+            prefixed.extend_from_slice(&name);
+            name = prefixed;
+        }
+
+        let mut entry = Entry {
+            kind,
+            mode: parse_number(&hdr[100..108])? as u32,
+            uid: parse_number(&hdr[108..116])? as u32,
+            gid: parse_number(&hdr[116..124])? as u32,
+            size,
+            mtime: parse_number(&hdr[136..148])? as i64,
+            devmajor: parse_number(&hdr[329..337])? as u32,
+            devminor: parse_number(&hdr[337..345])? as u32,
+            ..Default::default()
+        };
+        let mut link = trim_nul(&hdr[157..257]).to_vec();
+
+        if let Some(n) = long_name.take() {
+            name = n;
+        }
+        if let Some(l) = long_link.take() {
+            link = l;
+        }
+        for (k, v) in pax.drain() {
+            let num = || -> Result<u64> {
+                let s = String::from_utf8_lossy(&v);
+                // times may have a fraction
+                Ok(s.split('.').next().unwrap_or("").parse::<u64>()?)
+            };
+            match k.as_str() {
+                "path" => name = v,
+                "linkpath" => link = v,
+                "size" => entry.size = num()?,
+                "uid" => entry.uid = num()? as u32,
+                "gid" => entry.gid = num()? as u32,
+                "mtime" => entry.mtime = num()? as i64,
+                _ if k.starts_with(PAX_XATTR_PREFIX) => entry
+                    .xattrs
+                    .push((k[PAX_XATTR_PREFIX.len()..].to_string(), v)),
+                _ => {}
+            }
+        }
+
+        entry.path = clean_path(&name)?;
+        entry.link = PathBuf::from(std::ffi::OsStr::from_bytes(&link));
+
+        let consumed = apply_entry(r, &entry, rootfs, &mut added)?;
+        skip_data(r, entry.size - consumed, entry.size)?;
+    }
+
+    Ok(())
+}
+
+// read_block reads a whole tar block, unless the stream ends first.
+fn read_block<R: Read>(r: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut n = 0;
+    while n < buf.len() {
+        match r.read(&mut buf[n..])? {
+            0 if n == 0 => return Ok(0),
+            0 => return Err(ErrorKind::ErrorCode("truncated tar header".to_string()).into()),
+            m => n += m,
+        }
+    }
+
+    Ok(n)
+}
+
+// read_data reads the size bytes of data of an entry and its padding.
+fn read_data<R: Read>(r: &mut R, size: u64) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    r.take(size).read_to_end(&mut data)?;
+    if (data.len() as u64) < size {
+        return Err(ErrorKind::ErrorCode("truncated tar entry".to_string()).into());
+    }
+    skip_data(r, 0, size)?;
+
+    Ok(data)
+}
+
+// skip_data skips the left bytes of data of an entry of size bytes, and
+// the padding up to the next block.
+fn skip_data<R: Read>(r: &mut R, left: u64, size: u64) -> Result<()> {
+    let padding = (TAR_BLOCK_SIZE - size % TAR_BLOCK_SIZE) % TAR_BLOCK_SIZE;
+    let n = left + padding;
+    if io::copy(&mut r.take(n), &mut io::sink())? < n {
+        return Err(ErrorKind::ErrorCode("truncated tar entry".to_string()).into());
+    }
+
+    Ok(())
+}
+
+// parse_number parses a numeric header field, in octal or, for large
+// values, in base-256.
+fn parse_number(field: &[u8]) -> Result<u64> {
+    if field[0] & 0x80 != 0 {
+        return Ok(field[1..]
+            .iter()
+            .fold((field[0] & 0x7f) as u64, |n, b| n << 8 | *b as u64));
+    }
+
+    let s = String::from_utf8_lossy(trim_nul(field));
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(0);
+    }
+
+    Ok(u64::from_str_radix(s, 8)?)
+}
+
+// parse_pax parses the "<length> <key>=<value>\n" records of a pax
+// extended header.
+fn parse_pax(data: &[u8]) -> Result<HashMap<String, Vec<u8>>> {
+    let mut records = HashMap::new();
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        let bad = || ErrorKind::ErrorCode("bad pax header".to_string());
+
+        let sp = rest.iter().position(|b| *b == b' ').ok_or_else(bad)?;
+        let len: usize = String::from_utf8_lossy(&rest[..sp]).parse()?;
+        if len <= sp + 1 || len > rest.len() || rest[len - 1] != b'\n' {
+            return Err(bad().into());
+        }
+
+        let record = &rest[sp + 1..len - 1];
+        let eq = record.iter().position(|b| *b == b'=').ok_or_else(bad)?;
+        records.insert(
+            String::from_utf8_lossy(&record[..eq]).to_string(),
+            record[eq + 1..].to_vec(),
+        );
+        rest = &rest[len..];
+    }
+
+    Ok(records)
+}
+
+fn trim_nul(b: &[u8]) -> &[u8] {
+    let end = b.iter().position(|c| *c == 0).unwrap_or(b.len());
+    &b[..end]
+}
+
+// clean_path returns name relative to the rootfs, refusing to go out.
+fn clean_path(name: &[u8]) -> Result<PathBuf> {
+    let mut path = PathBuf::new();
+    for c in Path::new(std::ffi::OsStr::from_bytes(name)).components() {
+        match c {
+            Component::Normal(c) => path.push(c),
+            Component::RootDir | Component::CurDir => {}
+            _ => {
+                return Err(ErrorKind::ErrorCode(format!(
+                    "bad tar entry name {}",
+                    String::from_utf8_lossy(name)
+                ))
+                .into())
+            }
+        }
+    }
+
+    Ok(path)
+}
+
+// apply_entry applies entry onto rootfs, and returns how much of its
+// data it read from r.
+fn apply_entry<R: Read>(
+    r: &mut R,
+    entry: &Entry,
+    rootfs: &Path,
+    added: &mut HashSet<PathBuf>,
+) -> Result<u64> {
+    let name = match entry.path.file_name() {
+        Some(name) => name.to_string_lossy().to_string(),
+        // the root, which the layers do not own
+        None => return Ok(0),
+    };
+
+    let target = secure_join_nofollow(rootfs, &entry.path)?;
+    let parent = target.parent().unwrap();
+    if !parent.exists() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if name == WHITEOUT_OPAQUE {
+        let dir = entry.path.parent().unwrap();
+        for e in fs::read_dir(parent)? {
+            let e = e?;
+            if !added.contains(&dir.join(e.file_name())) {
+                remove_all(&e.path())?;
+            }
+        }
+        return Ok(0);
+    }
+
+    if name.starts_with(WHITEOUT_PREFIX) {
+        let whiteout = &name[WHITEOUT_PREFIX.len()..];
+        if whiteout.is_empty() || whiteout == "." || whiteout == ".." || whiteout.contains('/') {
+            return Err(
+                ErrorKind::ErrorCode(format!("bad whiteout {}", entry.path.display())).into(),
+            );
+        }
+        remove_all(&secure_join_nofollow(
+            rootfs,
+            &entry.path.with_file_name(whiteout),
+        )?)?;
+        return Ok(0);
+    }
+
+    // what lower layers left there is replaced, unless both are directories
+    if let Ok(md) = fs::symlink_metadata(&target) {
+        if !md.is_dir() || entry.kind != b'5' {
+            remove_all(&target)?;
+        }
+    }
+    // with the parents the layer holds without listing them
+    for p in entry.path.ancestors() {
+        added.insert(p.to_path_buf());
+    }
+
+    let mut consumed = 0;
+    match entry.kind {
+        b'0' | b'\0' | b'7' => {
+            let mut f = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .mode(0o600)
+                .open(&target)?;
+            consumed = io::copy(&mut r.take(entry.size), &mut f)?;
+            if consumed < entry.size {
+                return Err(ErrorKind::ErrorCode("truncated tar entry".to_string()).into());
+            }
+        }
+        b'5' => {
+            if !target.exists() {
+                fs::create_dir(&target)?;
+            }
+        }
+        b'2' => symlink(&entry.link, &target)?,
+        b'1' => {
+            let link = clean_path(entry.link.as_os_str().as_bytes())?;
+            fs::hard_link(secure_join_nofollow(rootfs, &link)?, &target)?;
+            // the file is owned already
+            return Ok(0);
+        }
+        b'3' | b'4' | b'6' => {
+            let kind = match entry.kind {
+                b'3' => SFlag::S_IFCHR,
+                b'4' => SFlag::S_IFBLK,
+                _ => SFlag::S_IFIFO,
+            };
+            stat::mknod(
+                &target,
+                kind,
+                Mode::from_bits_truncate(entry.mode),
+                unsafe { libc::makedev(entry.devmajor, entry.devminor) },
+            )?;
+        }
+        k => {
+            return Err(ErrorKind::ErrorCode(format!(
+                "unsupported tar entry type {:?} for {}",
+                k as char,
+                entry.path.display()
+            ))
+            .into())
+        }
+    }
+
+    set_attributes(&target, entry)?;
+
+    Ok(consumed)
+}
+
+// set_attributes sets the owner, mode, extended attributes and time of
+// modification of what entry made at path.
+fn set_attributes(path: &Path, entry: &Entry) -> Result<()> {
+    unistd::fchownat(
+        None,
+        path,
+        Some(Uid::from_raw(entry.uid)),
+        Some(Gid::from_raw(entry.gid)),
+        FchownatFlags::NoFollowSymlink,
+    )?;
+
+    let symlink = entry.kind == b'2';
+    if !symlink {
+        // after chown(2), which clears the setuid and setgid bits
+        fs::set_permissions(path, fs::Permissions::from_mode(entry.mode & 0o7777))?;
+    }
+
+    let cpath = CString::new(path.as_os_str().as_bytes())?;
+    for (k, v) in entry.xattrs.iter() {
+        let ck = CString::new(k.as_str())?;
+        let ret = unsafe {
+            libc::lsetxattr(
+                cpath.as_ptr(),
+                ck.as_ptr(),
+                v.as_ptr() as *const libc::c_void,
+                v.len(),
+                0,
+            )
+        };
+        nix::errno::Errno::result(ret).chain_err(|| format!("set {} of {}", k, path.display()))?;
+    }
+
+    // directories keep changing as their content gets unpacked
+    if entry.kind != b'5' {
+        let mtime = TimeSpec::seconds(entry.mtime);
+        stat::utimensat(None, path, &mtime, &mtime, UtimensatFlags::NoFollowSymlink)?;
+    }
+
+    Ok(())
+}
+
+// remove_all removes path, whatever it is, if it is there.
+fn remove_all(path: &Path) -> Result<()> {
+    let res = match fs::symlink_metadata(path) {
+        Ok(ref md) if md.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(e) => Err(e),
+    };
+
+    match res {
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        res => Ok(res?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+    use std::os::unix::fs::MetadataExt;
+    use tempfile::tempdir;
+
+    fn tar_entry(name: &str, kind: u8, data: &[u8], link: &str) -> Vec<u8> {
+        let mut hdr = [0u8; 512];
+        hdr[..name.len()].copy_from_slice(name.as_bytes());
+        let mode = if kind == b'5' { 0o755 } else { 0o644 };
+        let uid = unistd::getuid().as_raw();
+        let gid = unistd::getgid().as_raw();
+        for (off, len, val) in [
+            (100, 8, mode as u64),
+            (108, 8, uid as u64),
+            (116, 8, gid as u64),
+            (124, 12, data.len() as u64),
+            (136, 12, 1_500_000_000),
+        ]
+        .iter()
+        {
+            let s = format!("{:0width$o}\0", val, width = len - 1);
+            hdr[*off..off + len].copy_from_slice(s.as_bytes());
+        }
+        hdr[156] = kind;
+        hdr[157..157 + link.len()].copy_from_slice(link.as_bytes());
+        hdr[257..263].copy_from_slice(b"ustar\0");
+        hdr[263..265].copy_from_slice(b"00");
+
+        let sum: u32 = hdr
+            .iter()
+            .enumerate()
+            .map(|(i, b)| if i >= 148 && i < 156 { b' ' } else { *b } as u32)
+            .sum();
+        hdr[148..156].copy_from_slice(format!("{:06o}\0 ", sum).as_bytes());
+
+        let mut out = hdr.to_vec();
+        out.extend_from_slice(data);
+        out.resize(out.len() + (512 - data.len() % 512) % 512, 0);
+        out
+    }
+
+    fn digest(data: &[u8]) -> String {
+        let mut h = Sha256::new();
+        h.update(data);
+        format!("sha256:{}", hex_digest(&h.finish()))
+    }
+
+    // write_blob adds data to the layout at dir, and returns its descriptor.
+    fn write_blob(dir: &Path, media_type: &str, data: &[u8]) -> Value {
+        let d = digest(data);
+        fs::write(blob_path(dir, &d).unwrap(), data).unwrap();
+        serde_json::json!({"mediaType": media_type, "digest": d, "size": data.len()})
+    }
+
+    fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
+        match compression {
+            Compression::Gzip => {
+                let mut enc = GzEncoder::new(Vec::new(), flate2::Compression::default());
+                enc.write_all(data).unwrap();
+                enc.finish().unwrap()
+            }
+            Compression::Zstd => zstd::stream::encode_all(data, 0).unwrap(),
+        }
+    }
+
+    // write_layout writes a layout of a single image made of layers, with
+    // the given compression and their own diff IDs unless told otherwise.
+    fn write_layout(
+        dir: &Path,
+        layers: &[(Vec<u8>, Option<Compression>)],
+        diff_ids: Option<Vec<String>>,
+    ) {
+        fs::create_dir_all(dir.join("blobs/sha256")).unwrap();
+        fs::write(
+            dir.join(OCI_LAYOUT_FILE),
+            r#"{"imageLayoutVersion":"1.0.0"}"#,
+        )
+        .unwrap();
+
+        let mut descs = Vec::new();
+        for (tar, compression) in layers.iter() {
+            descs.push(match compression {
+                Some(Compression::Gzip) => write_blob(
+                    dir,
+                    "application/vnd.oci.image.layer.v1.tar+gzip",
+                    &compress(tar, Compression::Gzip),
+                ),
+                Some(Compression::Zstd) => write_blob(
+                    dir,
+                    "application/vnd.oci.image.layer.v1.tar+zstd",
+                    &compress(tar, Compression::Zstd),
+                ),
+                None => write_blob(dir, "application/vnd.oci.image.layer.v1.tar", tar),
+            });
+        }
+
+        let diff_ids =
+            diff_ids.unwrap_or_else(|| layers.iter().map(|(tar, _)| digest(tar)).collect());
+        let config = serde_json::json!({"rootfs": {"type": "layers", "diff_ids": diff_ids}});
+        let config = write_blob(
+            dir,
+            "application/vnd.oci.image.config.v1+json",
+            config.to_string().as_bytes(),
+        );
+        let manifest = serde_json::json!({
+            "schemaVersion": 2, "config": config, "layers": descs,
+        });
+        let mut manifest = write_blob(
+            dir,
+            "application/vnd.oci.image.manifest.v1+json",
+            manifest.to_string().as_bytes(),
+        );
+        manifest["annotations"] = serde_json::json!({REF_NAME_ANNOTATION: "app:v1"});
+
+        let index = serde_json::json!({"schemaVersion": 2, "manifests": [manifest]});
+        fs::write(dir.join(OCI_INDEX_FILE), index.to_string()).unwrap();
+    }
+
+    fn layers() -> Vec<(Vec<u8>, Option<Compression>)> {
+        let mut lower = Vec::new();
+        lower.extend(tar_entry("etc/", b'5', b"", ""));
+        lower.extend(tar_entry("etc/app.conf", b'0', b"v1", ""));
+        lower.extend(tar_entry("etc/old", b'0', b"old", ""));
+        lower.extend(tar_entry("data/", b'5', b"", ""));
+        lower.extend(tar_entry("data/a", b'0', b"a", ""));
+        lower.extend(tar_entry("bin/busybox", b'0', b"elf", ""));
+        lower.extend(tar_entry("bin/sh", b'2', b"", "busybox"));
+        lower.extend(tar_entry("bin/ls", b'1', b"", "bin/busybox"));
+        lower.extend(vec![0u8; 1024]);
+
+        let mut upper = Vec::new();
+        upper.extend(tar_entry("etc/.wh.old", b'0', b"", ""));
+        upper.extend(tar_entry("data/b", b'0', b"b", ""));
+        upper.extend(tar_entry("data/sub/c", b'0', b"c", ""));
+        upper.extend(tar_entry("data/.wh..wh..opq", b'0', b"", ""));
+        upper.extend(tar_entry("./etc/app.conf", b'0', b"v2", ""));
+        upper.extend(vec![0u8; 1024]);
+
+        vec![(lower, None), (upper, Some(Compression::Gzip))]
+    }
+
+    #[test]
+    fn test_unpack_image() {
+        for compression in [Compression::Gzip, Compression::Zstd].iter() {
+            let dir = tempdir().unwrap();
+            let layout = dir.path().join("layout");
+            let rootfs = dir.path().join("rootfs");
+            fs::create_dir(&rootfs).unwrap();
+
+            let mut layers = layers();
+            layers[1].1 = Some(*compression);
+            write_layout(&layout, &layers, None);
+            unpack_image(&layout, "app:v1", &rootfs).unwrap();
+            check_rootfs(&rootfs);
+
+            assert!(unpack_image(&layout, "app:v2", &rootfs).is_err());
+        }
+    }
+
+    // check_rootfs checks rootfs holds the image of layers().
+    fn check_rootfs(rootfs: &Path) {
+        assert_eq!(
+            fs::read_to_string(rootfs.join("etc/app.conf")).unwrap(),
+            "v2"
+        );
+        assert!(!rootfs.join("etc/old").exists());
+        assert!(!rootfs.join("data/a").exists());
+        assert_eq!(fs::read_to_string(rootfs.join("data/b")).unwrap(), "b");
+        assert_eq!(fs::read_to_string(rootfs.join("data/sub/c")).unwrap(), "c");
+        assert_eq!(
+            fs::read_link(rootfs.join("bin/sh")).unwrap(),
+            Path::new("busybox")
+        );
+        let md = fs::metadata(rootfs.join("bin/ls")).unwrap();
+        assert_eq!(
+            md.ino(),
+            fs::metadata(rootfs.join("bin/busybox")).unwrap().ino()
+        );
+        assert_eq!(md.mode() & 0o777, 0o644);
+        assert_eq!(md.mtime(), 1_500_000_000);
+    }
+
+    #[test]
+    fn test_unpack_image_checks() {
+        let dir = tempdir().unwrap();
+
+        // a diff ID not matching the layer
+        let layout = dir.path().join("diff-id");
+        let rootfs = dir.path().join("rootfs1");
+        fs::create_dir(&rootfs).unwrap();
+        let bad = vec![digest(b"lower"), digest(b"upper")];
+        write_layout(&layout, &layers(), Some(bad));
+        assert!(unpack_image(&layout, "", &rootfs).is_err());
+        assert_eq!(fs::read_dir(&rootfs).unwrap().count(), 0);
+
+        // a tampered blob
+        let layout = dir.path().join("tampered");
+        let rootfs = dir.path().join("rootfs2");
+        fs::create_dir(&rootfs).unwrap();
+        let layers = layers();
+        write_layout(&layout, &layers, None);
+        let blob = blob_path(&layout, &digest(&layers[0].0)).unwrap();
+        let mut tar = fs::read(&blob).unwrap();
+        tar[512] = b'x';
+        fs::write(&blob, tar).unwrap();
+        assert!(unpack_image(&layout, "", &rootfs).is_err());
+        assert_eq!(fs::read_dir(&rootfs).unwrap().count(), 0);
+
+        // a path out of the rootfs
+        let mut tar = tar_entry("../escaped", b'0', b"x", "");
+        tar.extend(vec![0u8; 1024]);
+        let layout = dir.path().join("evil");
+        let rootfs = dir.path().join("rootfs3");
+        fs::create_dir(&rootfs).unwrap();
+        write_layout(&layout, &[(tar, None)], None);
+        assert!(unpack_image(&layout, "", &rootfs).is_err());
+        assert!(!dir.path().join("escaped").exists());
+    }
+
+    #[test]
+    fn test_unpack_image_bad_whiteouts() {
+        let dir = tempdir().unwrap();
+
+        for (i, whiteout) in ["data/sub/.wh..", "data/sub/.wh...", "data/.wh."]
+            .iter()
+            .enumerate()
+        {
+            let mut lower = Vec::new();
+            lower.extend(tar_entry("data/", b'5', b"", ""));
+            lower.extend(tar_entry("data/keep", b'0', b"k", ""));
+            lower.extend(tar_entry("data/sub/", b'5', b"", ""));
+            lower.extend(tar_entry("data/sub/keep", b'0', b"k", ""));
+            lower.extend(vec![0u8; 1024]);
+            let mut upper = tar_entry(whiteout, b'0', b"", "");
+            upper.extend(vec![0u8; 1024]);
+
+            let layout = dir.path().join(format!("layout{}", i));
+            let rootfs = dir.path().join(format!("rootfs{}", i));
+            fs::create_dir(&rootfs).unwrap();
+            write_layout(&layout, &[(lower, None), (upper, None)], None);
+
+            assert!(unpack_image(&layout, "", &rootfs).is_err());
+            assert!(rootfs.join("data/keep").exists());
+            assert!(rootfs.join("data/sub/keep").exists());
+        }
+    }
+
+    #[test]
+    fn test_parse_number_and_pax() {
+        assert_eq!(parse_number(b"0000644\0").unwrap(), 0o644);
+        assert_eq!(parse_number(b"     12 ").unwrap(), 0o12);
+        assert_eq!(parse_number(&[0x80, 0, 0, 0, 1, 0, 0, 0]).unwrap(), 1 << 24);
+
+        let pax = parse_pax(b"28 path=very/long/file/name\n20 SCHILY.xattr.a=b\n").unwrap();
+        assert_eq!(pax["path"], b"very/long/file/name");
+        assert_eq!(pax["SCHILY.xattr.a"], b"b");
+        assert!(parse_pax(b"99 path=x\n").is_err());
+    }
+}
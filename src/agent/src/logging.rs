@@ -3,16 +3,23 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use nix::sys::socket::{self, SockFlag};
 use slog::{BorrowedKV, Drain, Key, OwnedKV, OwnedKVList, Record, KV};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
 use std::io;
 use std::io::Write;
+use std::os::unix::io::{FromRawFd, RawFd};
 use std::process;
 use std::result;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread;
+
+// how much of the logs is kept while the host is not reading them
+const VSOCK_LOG_BUFFER_SIZE: usize = 1 << 20;
 
 // XXX: 'writer' param used to make testing possible.
-pub fn create_logger<W>(name: &str, source: &str, level: slog::Level, writer: W) -> slog::Logger
+pub fn create_logger<W>(name: &str, source: &str, levels: LogLevels, writer: W) -> slog::Logger
 where
     W: Write + Send + Sync + 'static,
 {
@@ -24,15 +31,16 @@ where
     // Ensure only a unique set of key/value fields is logged
     let unique_drain = UniqueDrain::new(json_drain).fuse();
 
-    // Allow runtime filtering of records by log level
-    let filter_drain = RuntimeLevelFilter::new(unique_drain, level).fuse();
-
     // Ensure the logger is thread-safe
-    let async_drain = slog_async::Async::new(filter_drain).build().fuse();
+    let async_drain = slog_async::Async::new(unique_drain).build().fuse();
+
+    // Allow runtime filtering of records by log level, as they are logged
+    // rather than as they are written out, once the levels may have changed
+    let filter_drain = RuntimeLevelFilter::new(async_drain, levels).fuse();
 
     // Add some "standard" fields
     slog::Logger::root(
-        async_drain.fuse(),
+        filter_drain,
         o!("version" => env!("CARGO_PKG_VERSION"),
             "subsystem" => "root",
             "pid" => process::id().to_string(),
@@ -132,27 +140,88 @@ where
     }
 }
 
+// LogLevels are the log levels of a logger, which may change at runtime: the
+// level of all records but those of the subsystems with levels of their own,
+// by the "subsystem" field of the records.
+#[derive(Clone)]
+pub struct LogLevels {
+    levels: Arc<RwLock<Levels>>,
+}
+
+struct Levels {
+    level: slog::Level,
+    subsystems: HashMap<String, slog::Level>,
+}
+
+impl LogLevels {
+    pub fn new(level: slog::Level) -> Self {
+        LogLevels {
+            levels: Arc::new(RwLock::new(Levels {
+                level: level,
+                subsystems: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn set_level(&self, level: slog::Level) {
+        self.levels.write().unwrap().level = level;
+    }
+
+    // set_subsystem_levels replaces the levels of the subsystems.
+    pub fn set_subsystem_levels(&self, subsystems: HashMap<String, slog::Level>) {
+        self.levels.write().unwrap().subsystems = subsystems;
+    }
+
+    pub fn level(&self) -> slog::Level {
+        self.levels.read().unwrap().level
+    }
+}
+
+// Used to find the subsystem of a record, the one of the record first, then
+// the one of the newest logger.
+struct SubsystemSerializer {
+    subsystem: Option<String>,
+}
+
+impl slog::Serializer for SubsystemSerializer {
+    fn emit_arguments(&mut self, key: Key, value: &std::fmt::Arguments) -> slog::Result {
+        if self.subsystem.is_none() && key == "subsystem" {
+            self.subsystem = Some(format!("{}", value));
+        }
+        Ok(())
+    }
+}
+
 // A RuntimeLevelFilter will discard all log records whose log level is less than the level
-// specified in the struct.
+// specified in the struct, for their subsystem.
 struct RuntimeLevelFilter<D> {
     drain: D,
-    level: Arc<Mutex<slog::Level>>,
+    levels: LogLevels,
 }
 
 impl<D> RuntimeLevelFilter<D> {
-    fn new(drain: D, level: slog::Level) -> Self {
+    fn new(drain: D, levels: LogLevels) -> Self {
         RuntimeLevelFilter {
             drain: drain,
-            level: Arc::new(Mutex::new(level)),
+            levels: levels,
         }
     }
 
-    fn set_level(&self, level: slog::Level) {
-        let level_ref = self.level.clone();
+    fn level(&self, record: &slog::Record, values: &slog::OwnedKVList) -> slog::Level {
+        let levels = self.levels.levels.read().unwrap();
+
+        if levels.subsystems.is_empty() {
+            return levels.level;
+        }
 
-        let mut log_level = level_ref.lock().unwrap();
+        let mut serializer = SubsystemSerializer { subsystem: None };
+        let _ = record.kv().serialize(record, &mut serializer);
+        let _ = values.serialize(record, &mut serializer);
 
-        *log_level = level;
+        serializer
+            .subsystem
+            .and_then(|s| levels.subsystems.get(&s).cloned())
+            .unwrap_or(levels.level)
     }
 }
 
@@ -168,11 +237,7 @@ where
         record: &slog::Record,
         values: &slog::OwnedKVList,
     ) -> result::Result<Self::Ok, Self::Err> {
-        let level_ref = self.level.clone();
-
-        let log_level = level_ref.lock().unwrap();
-
-        if record.level().is_at_least(*log_level) {
+        if record.level().is_at_least(self.level(record, values)) {
             self.drain.log(record, values)?;
         }
 
@@ -180,6 +245,120 @@ where
     }
 }
 
+// A VsockWriter serves the logs on a vsock port, for the host to read them
+// when the console is not. The records are buffered while the host is not
+// connected, the oldest ones dropped past the size of the buffer, and the
+// host may connect again whenever it lost the connection.
+//
+// Nothing is logged of the connections, which would be logged here again.
+#[derive(Clone)]
+pub struct VsockWriter {
+    buffer: Arc<(Mutex<LogBuffer>, Condvar)>,
+}
+
+struct LogBuffer {
+    // the complete records, by line
+    lines: VecDeque<Vec<u8>>,
+    size: usize,
+    max_size: usize,
+    // the record being written
+    partial: Vec<u8>,
+}
+
+impl LogBuffer {
+    fn push(&mut self, line: Vec<u8>) {
+        self.size += line.len();
+        self.lines.push_back(line);
+
+        while self.size > self.max_size {
+            match self.lines.pop_front() {
+                Some(l) => self.size -= l.len(),
+                None => break,
+            }
+        }
+    }
+}
+
+impl VsockWriter {
+    pub fn new(port: u32) -> rustjail::errors::Result<Self> {
+        let listener = crate::ttrpc::listen_vsock(libc::VMADDR_CID_ANY, port)?;
+        Ok(Self::serve(listener, VSOCK_LOG_BUFFER_SIZE))
+    }
+
+    // serve sends the logs to the connections accepted on listener, one
+    // at a time.
+    fn serve(listener: RawFd, max_size: usize) -> Self {
+        let writer = VsockWriter {
+            buffer: Arc::new((
+                Mutex::new(LogBuffer {
+                    lines: VecDeque::new(),
+                    size: 0,
+                    max_size: max_size,
+                    partial: Vec::new(),
+                }),
+                Condvar::new(),
+            )),
+        };
+
+        let buffer = writer.buffer.clone();
+        thread::spawn(move || loop {
+            let fd = match socket::accept4(listener, SockFlag::SOCK_CLOEXEC) {
+                Ok(fd) => fd,
+                Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => continue,
+                Err(_) => break,
+            };
+
+            let mut conn = unsafe { File::from_raw_fd(fd) };
+            let (lock, cond) = &*buffer;
+
+            loop {
+                let line = {
+                    let mut buf = lock.lock().unwrap();
+                    while buf.lines.is_empty() {
+                        buf = cond.wait(buf).unwrap();
+                    }
+                    let line = buf.lines.pop_front().unwrap();
+                    buf.size -= line.len();
+                    line
+                };
+
+                if conn.write_all(&line).is_err() {
+                    // for the next connection
+                    let mut buf = lock.lock().unwrap();
+                    buf.size += line.len();
+                    buf.lines.push_front(line);
+                    break;
+                }
+            }
+        });
+
+        writer
+    }
+}
+
+impl Write for VsockWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let (lock, cond) = &*self.buffer;
+        let mut buf = lock.lock().unwrap();
+
+        let mut rest = data;
+        while let Some(n) = rest.iter().position(|b| *b == b'\n') {
+            let mut line = std::mem::replace(&mut buf.partial, Vec::new());
+            line.extend_from_slice(&rest[..n + 1]);
+            buf.push(line);
+            rest = &rest[n + 1..];
+        }
+        buf.partial.extend_from_slice(rest);
+
+        cond.notify_one();
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,7 +382,7 @@ mod tests {
         let record_key = "record-key-1";
         let record_value = "record-key-2";
 
-        let logger = create_logger(name, source, level, writer);
+        let logger = create_logger(name, source, LogLevels::new(level), writer);
 
         let msg = "foo, bar, baz";
 
@@ -257,4 +436,99 @@ mod tests {
             .expect("failed to find record key field");
         assert_eq!(field_record_value, record_value);
     }
+
+    #[test]
+    fn test_runtime_level_filter() {
+        let writer = NamedTempFile::new().expect("failed to create tempfile");
+        let mut writer_ref = writer.reopen().expect("failed to clone tempfile");
+
+        let levels = LogLevels::new(slog::Level::Info);
+        let logger = create_logger("name", "source", levels.clone(), writer);
+        let netlink = logger.new(o!("subsystem" => "netlink"));
+
+        debug!(logger, "root-debug-1");
+        debug!(netlink, "netlink-debug-1");
+
+        let mut subsystems = HashMap::new();
+        subsystems.insert("netlink".to_string(), slog::Level::Debug);
+        subsystems.insert("grpc".to_string(), slog::Level::Error);
+        levels.set_subsystem_levels(subsystems);
+
+        debug!(logger, "root-debug-2");
+        info!(logger, "root-info-2");
+        debug!(netlink, "netlink-debug-2");
+        // the subsystem of the record over the one of the logger
+        info!(netlink, "grpc-info-2"; "subsystem" => "grpc");
+
+        levels.set_level(slog::Level::Debug);
+        levels.set_subsystem_levels(HashMap::new());
+
+        debug!(logger, "root-debug-3");
+
+        drop(netlink);
+        drop(logger);
+
+        let mut contents = String::new();
+        writer_ref
+            .read_to_string(&mut contents)
+            .expect("failed to read tempfile contents");
+
+        let msgs: Vec<String> = contents
+            .lines()
+            .map(|l| {
+                let fields: Value = serde_json::from_str(l).expect("failed to convert to json");
+                fields["msg"].as_str().unwrap().to_string()
+            })
+            .collect();
+
+        assert_eq!(
+            msgs,
+            vec!["root-info-2", "netlink-debug-2", "root-debug-3"],
+            "{}",
+            contents
+        );
+    }
+
+    #[test]
+    fn test_vsock_writer() {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::io::IntoRawFd;
+        use std::os::unix::net::{UnixListener, UnixStream};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.sock");
+        let listener = UnixListener::bind(&path).unwrap().into_raw_fd();
+
+        // room for three records of 9 bytes
+        let mut writer = VsockWriter::serve(listener, 30);
+
+        // the oldest records are dropped until the host connects
+        for i in 0..5 {
+            writer
+                .write_all(format!("record-{}", i).as_bytes())
+                .unwrap();
+            writer.write_all(b"\n").unwrap();
+        }
+
+        let conn = UnixStream::connect(&path).unwrap();
+        let mut reader = BufReader::new(conn);
+        let mut line = String::new();
+        for i in 2..5 {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            assert_eq!(line, format!("record-{}\n", i));
+        }
+
+        // and the next one is read, whole, when the host connects again
+        drop(reader);
+        writer.write_all(b"record-5\nrecord-6\n").unwrap();
+
+        let conn = UnixStream::connect(&path).unwrap();
+        let mut reader = BufReader::new(conn);
+        for i in 5..7 {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            assert_eq!(line, format!("record-{}\n", i));
+        }
+    }
 }
@@ -0,0 +1,839 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// LUKS1 volumes, formatted with a single keyslot and opened through
+// dm-crypt. The guest has no cryptsetup: the header and the PBKDF2 and
+// anti-forensic key derivation are handled here, while every use of the
+// cipher, on the keyslots as on the payload, is left to dm-crypt, so
+// that the cipher implementation is the kernel's.
+
+use rustjail::errors::*;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crate::dm::{self, Target};
+use crate::sha256::Sha256;
+
+const LUKS_MAGIC: &'static [u8] = b"LUKS\xba\xbe";
+const LUKS_VERSION: u16 = 1;
+const LUKS_NUMKEYS: usize = 8;
+const LUKS_KEY_ENABLED: u32 = 0x00ac_71f3;
+const LUKS_KEY_DISABLED: u32 = 0x0000_dead;
+const LUKS_STRIPES: u32 = 4000;
+const LUKS_DIGESTSIZE: usize = 20;
+const LUKS_SALTSIZE: usize = 32;
+const LUKS_HDR_SIZE: usize = 592;
+const LUKS_KEYSLOT_SIZE: usize = 48;
+const LUKS_UUID_LEN: usize = 40;
+
+const SECTOR_SIZE: u64 = 512;
+// keyslots start on 4KiB boundaries and the payload on a 2MiB one
+const KEYSLOT_ALIGN: u64 = 8;
+const PAYLOAD_ALIGN: u64 = 4096;
+
+const CIPHER_NAME: &'static str = "aes";
+const CIPHER_MODE: &'static str = "xts-plain64";
+const HASH_SPEC: &'static str = "sha256";
+const KEY_BYTES: usize = 64;
+
+// what the headers of the volumes opened may hold, the header being as
+// untrusted as the rest of the disk
+const CIPHER_NAMES: [&'static str; 1] = [CIPHER_NAME];
+const CIPHER_MODES: [&'static str; 3] = [CIPHER_MODE, "cbc-essiv:sha256", "cbc-plain64"];
+const KEY_SIZES: [u32; 3] = [16, 32, 64];
+const KEYSLOT_ITERATIONS: u32 = 100_000;
+const MK_DIGEST_ITERATIONS: u32 = 1000;
+// well over what cryptsetup benchmarks to, so that a header cannot keep
+// the opening of a volume busy for hours
+const KEYSLOT_ITERATIONS_MAX: u32 = 10_000_000;
+const MK_DIGEST_ITERATIONS_MAX: u32 = 1_000_000;
+
+const RANDOM_DEV: &'static str = "/dev/urandom";
+
+#[derive(Debug, Clone, PartialEq)]
+struct KeySlot {
+    active: bool,
+    iterations: u32,
+    salt: Vec<u8>,
+    key_material_offset: u32,
+    stripes: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LuksHeader {
+    cipher_name: String,
+    cipher_mode: String,
+    hash_spec: String,
+    payload_offset: u32,
+    key_bytes: u32,
+    mk_digest: Vec<u8>,
+    mk_digest_salt: Vec<u8>,
+    mk_digest_iterations: u32,
+    uuid: String,
+    key_slots: Vec<KeySlot>,
+}
+
+fn invalid(what: String) -> Error {
+    ErrorKind::ErrorCode(format!("invalid LUKS header: {}", what)).into()
+}
+
+impl LuksHeader {
+    // parse parses the header of a device of sectors, and checks that it
+    // holds nothing the opening of the volume cannot handle.
+    fn parse(buf: &[u8], sectors: u64) -> Result<Self> {
+        if buf.len() < LUKS_HDR_SIZE || &buf[..6] != LUKS_MAGIC {
+            return Err(ErrorKind::ErrorCode("not a LUKS header".to_string()).into());
+        }
+
+        let version = u16::from_be_bytes([buf[6], buf[7]]);
+        if version != LUKS_VERSION {
+            return Err(
+                ErrorKind::ErrorCode(format!("unsupported LUKS version {}", version)).into(),
+            );
+        }
+
+        let mut key_slots = Vec::new();
+        for i in 0..LUKS_NUMKEYS {
+            let s = &buf[208 + i * LUKS_KEYSLOT_SIZE..];
+            key_slots.push(KeySlot {
+                active: be32(&s[0..]) == LUKS_KEY_ENABLED,
+                iterations: be32(&s[4..]),
+                salt: s[8..8 + LUKS_SALTSIZE].to_vec(),
+                key_material_offset: be32(&s[40..]),
+                stripes: be32(&s[44..]),
+            });
+        }
+
+        let hdr = LuksHeader {
+            cipher_name: cstr(&buf[8..40]),
+            cipher_mode: cstr(&buf[40..72]),
+            hash_spec: cstr(&buf[72..104]),
+            payload_offset: be32(&buf[104..]),
+            key_bytes: be32(&buf[108..]),
+            mk_digest: buf[112..112 + LUKS_DIGESTSIZE].to_vec(),
+            mk_digest_salt: buf[132..132 + LUKS_SALTSIZE].to_vec(),
+            mk_digest_iterations: be32(&buf[164..]),
+            uuid: cstr(&buf[168..168 + LUKS_UUID_LEN]),
+            key_slots: key_slots,
+        };
+        hdr.check(sectors)?;
+
+        Ok(hdr)
+    }
+
+    fn check(&self, sectors: u64) -> Result<()> {
+        if !CIPHER_NAMES.contains(&self.cipher_name.as_str())
+            || !CIPHER_MODES.contains(&self.cipher_mode.as_str())
+        {
+            return Err(invalid(format!("cipher {:?}", self.cipher())));
+        }
+        if self.hash_spec != HASH_SPEC {
+            return Err(invalid(format!("hash {:?}", self.hash_spec)));
+        }
+        if !KEY_SIZES.contains(&self.key_bytes) {
+            return Err(invalid(format!("key of {} bytes", self.key_bytes)));
+        }
+        if self.payload_offset as u64 >= sectors {
+            return Err(invalid(format!(
+                "payload at sector {} of {}",
+                self.payload_offset, sectors
+            )));
+        }
+        if self.mk_digest_iterations == 0 || self.mk_digest_iterations > MK_DIGEST_ITERATIONS_MAX {
+            return Err(invalid(format!(
+                "{} digest iterations",
+                self.mk_digest_iterations
+            )));
+        }
+        if !self
+            .uuid
+            .bytes()
+            .all(|c| c.is_ascii_hexdigit() || c == b'-')
+        {
+            return Err(invalid(format!("UUID {:?}", self.uuid)));
+        }
+
+        for (i, slot) in self.key_slots.iter().enumerate().filter(|(_, s)| s.active) {
+            if slot.iterations == 0 || slot.iterations > KEYSLOT_ITERATIONS_MAX {
+                return Err(invalid(format!(
+                    "{} iterations in keyslot {}",
+                    slot.iterations, i
+                )));
+            }
+            if slot.stripes != LUKS_STRIPES {
+                return Err(invalid(format!(
+                    "{} stripes in keyslot {}",
+                    slot.stripes, i
+                )));
+            }
+            let end = slot.key_material_offset as u64
+                + keyslot_sectors(self.key_bytes as usize, slot.stripes);
+            if end > self.payload_offset as u64 {
+                return Err(invalid(format!("keyslot {} over the payload", i)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![0u8; LUKS_HDR_SIZE];
+
+        buf[..6].copy_from_slice(LUKS_MAGIC);
+        buf[6..8].copy_from_slice(&LUKS_VERSION.to_be_bytes());
+        put_cstr(&mut buf[8..40], &self.cipher_name);
+        put_cstr(&mut buf[40..72], &self.cipher_mode);
+        put_cstr(&mut buf[72..104], &self.hash_spec);
+        buf[104..108].copy_from_slice(&self.payload_offset.to_be_bytes());
+        buf[108..112].copy_from_slice(&self.key_bytes.to_be_bytes());
+        buf[112..112 + LUKS_DIGESTSIZE].copy_from_slice(&self.mk_digest);
+        buf[132..132 + LUKS_SALTSIZE].copy_from_slice(&self.mk_digest_salt);
+        buf[164..168].copy_from_slice(&self.mk_digest_iterations.to_be_bytes());
+        put_cstr(&mut buf[168..168 + LUKS_UUID_LEN], &self.uuid);
+
+        for (i, ks) in self.key_slots.iter().enumerate() {
+            let s = &mut buf[208 + i * LUKS_KEYSLOT_SIZE..208 + (i + 1) * LUKS_KEYSLOT_SIZE];
+            let active = if ks.active {
+                LUKS_KEY_ENABLED
+            } else {
+                LUKS_KEY_DISABLED
+            };
+
+            s[0..4].copy_from_slice(&active.to_be_bytes());
+            s[4..8].copy_from_slice(&ks.iterations.to_be_bytes());
+            s[8..8 + LUKS_SALTSIZE].copy_from_slice(&ks.salt);
+            s[40..44].copy_from_slice(&ks.key_material_offset.to_be_bytes());
+            s[44..48].copy_from_slice(&ks.stripes.to_be_bytes());
+        }
+
+        buf
+    }
+
+    fn cipher(&self) -> String {
+        format!("{}-{}", self.cipher_name, self.cipher_mode)
+    }
+
+    // the digest is the first LUKS_DIGESTSIZE bytes of PBKDF2 of the key
+    fn check_key(&self, key: &[u8]) -> bool {
+        let digest = pbkdf2_sha256(
+            key,
+            &self.mk_digest_salt,
+            self.mk_digest_iterations,
+            LUKS_DIGESTSIZE,
+        );
+        digest == self.mk_digest
+    }
+}
+
+// is_luks tells whether dev has a LUKS header.
+pub fn is_luks(dev: &str) -> Result<bool> {
+    let mut magic = [0u8; 6];
+    File::open(dev)?.read_exact(&mut magic)?;
+    Ok(&magic[..] == LUKS_MAGIC)
+}
+
+// is_blank tells whether dev looks unused, that is whether where its
+// LUKS header would be is zeroed. Formatting anything else could destroy
+// data, e.g. an encrypted volume whose header got lost.
+pub fn is_blank(dev: &str) -> Result<bool> {
+    let mut buf = vec![0u8; (KEYSLOT_ALIGN * SECTOR_SIZE) as usize];
+    File::open(dev)?.read_exact(&mut buf)?;
+    Ok(buf.iter().all(|b| *b == 0))
+}
+
+// format writes a LUKS header to dev, with a random volume key and a
+// keyslot opened by passphrase. The payload is not wiped.
+pub fn format(dev: &str, name: &str, passphrase: &[u8]) -> Result<()> {
+    let sectors = device_sectors(dev)?;
+
+    let material_sectors = keyslot_sectors(KEY_BYTES, LUKS_STRIPES);
+    let slot_sectors = align(material_sectors, KEYSLOT_ALIGN);
+    let payload_offset = align(
+        KEYSLOT_ALIGN + LUKS_NUMKEYS as u64 * slot_sectors,
+        PAYLOAD_ALIGN,
+    );
+    if sectors <= payload_offset {
+        return Err(ErrorKind::ErrorCode(format!("device {} too small for LUKS", dev)).into());
+    }
+
+    let mk = random_bytes(KEY_BYTES)?;
+    let mk_digest_salt = random_bytes(LUKS_SALTSIZE)?;
+
+    let mut key_slots = Vec::new();
+    for i in 0..LUKS_NUMKEYS as u64 {
+        key_slots.push(KeySlot {
+            active: i == 0,
+            iterations: if i == 0 { KEYSLOT_ITERATIONS } else { 0 },
+            salt: if i == 0 {
+                random_bytes(LUKS_SALTSIZE)?
+            } else {
+                vec![0; LUKS_SALTSIZE]
+            },
+            key_material_offset: (KEYSLOT_ALIGN + i * slot_sectors) as u32,
+            stripes: LUKS_STRIPES,
+        });
+    }
+
+    let hdr = LuksHeader {
+        cipher_name: CIPHER_NAME.to_string(),
+        cipher_mode: CIPHER_MODE.to_string(),
+        hash_spec: HASH_SPEC.to_string(),
+        payload_offset: payload_offset as u32,
+        key_bytes: KEY_BYTES as u32,
+        mk_digest: pbkdf2_sha256(&mk, &mk_digest_salt, MK_DIGEST_ITERATIONS, LUKS_DIGESTSIZE),
+        mk_digest_salt: mk_digest_salt,
+        mk_digest_iterations: MK_DIGEST_ITERATIONS,
+        uuid: random_uuid()?,
+        key_slots: key_slots,
+    };
+
+    // wipe the header area first, so that an interrupted format does
+    // not leave a header behind
+    let mut f = OpenOptions::new().write(true).open(dev)?;
+    f.write_all(&vec![0u8; (payload_offset * SECTOR_SIZE) as usize])?;
+    f.sync_all()?;
+
+    let slot = &hdr.key_slots[0];
+    let key = pbkdf2_sha256(passphrase, &slot.salt, slot.iterations, KEY_BYTES);
+    let material = af_split(&mk, LUKS_STRIPES as usize)?;
+    with_keyslot(dev, name, &hdr, slot, &key, false, |path| {
+        let mut f = OpenOptions::new().write(true).open(path)?;
+        f.write_all(&material)?;
+        f.sync_all()?;
+        Ok(())
+    })?;
+
+    f.seek(SeekFrom::Start(0))?;
+    f.write_all(&hdr.to_bytes())?;
+    f.sync_all()?;
+
+    Ok(())
+}
+
+// open maps the payload of the LUKS volume dev to /dev/mapper/name,
+// unlocked by passphrase, and returns the path of the mapping.
+pub fn open(dev: &str, name: &str, passphrase: &[u8], read_only: bool) -> Result<String> {
+    let sectors = device_sectors(dev)?;
+    let hdr = read_header(dev, sectors)?;
+
+    let mut mk = None;
+    for slot in hdr.key_slots.iter().filter(|s| s.active) {
+        let key = pbkdf2_sha256(
+            passphrase,
+            &slot.salt,
+            slot.iterations,
+            hdr.key_bytes as usize,
+        );
+
+        let len = hdr.key_bytes as usize * slot.stripes as usize;
+        let material = with_keyslot(dev, name, &hdr, slot, &key, true, |path| {
+            let mut material = vec![0u8; len];
+            File::open(path)?.read_exact(&mut material)?;
+            Ok(material)
+        })?;
+
+        let key = af_merge(&material, hdr.key_bytes as usize, slot.stripes as usize)?;
+        if hdr.check_key(&key) {
+            mk = Some(key);
+            break;
+        }
+    }
+
+    let mk = match mk {
+        Some(mk) => mk,
+        None => {
+            return Err(
+                ErrorKind::ErrorCode(format!("no LUKS keyslot of {} matches the key", dev)).into(),
+            )
+        }
+    };
+
+    let target = Target {
+        start: 0,
+        length: sectors - hdr.payload_offset as u64,
+        target_type: "crypt".to_string(),
+        params: format!(
+            "{} {} 0 {} {}",
+            hdr.cipher(),
+            hex(&mk),
+            dev,
+            hdr.payload_offset
+        ),
+    };
+    let uuid = format!("CRYPT-LUKS1-{}-{}", hdr.uuid.replace("-", ""), name);
+
+    dm::create_device(name, &uuid, &[target], read_only)
+}
+
+// close removes the mapping open created.
+pub fn close(name: &str) -> Result<()> {
+    dm::remove_device(name)
+}
+
+fn read_header(dev: &str, sectors: u64) -> Result<LuksHeader> {
+    let mut buf = vec![0u8; LUKS_HDR_SIZE];
+    File::open(dev)?.read_exact(&mut buf)?;
+    LuksHeader::parse(&buf, sectors)
+}
+
+// with_keyslot maps the key material of slot through dm-crypt, keyed
+// by key, for f to read or write it in clear.
+fn with_keyslot<T, F>(
+    dev: &str,
+    name: &str,
+    hdr: &LuksHeader,
+    slot: &KeySlot,
+    key: &[u8],
+    read_only: bool,
+    f: F,
+) -> Result<T>
+where
+    F: FnOnce(&str) -> Result<T>,
+{
+    let target = Target {
+        start: 0,
+        length: keyslot_sectors(hdr.key_bytes as usize, slot.stripes),
+        target_type: "crypt".to_string(),
+        params: format!(
+            "{} {} 0 {} {}",
+            hdr.cipher(),
+            hex(key),
+            dev,
+            slot.key_material_offset
+        ),
+    };
+    let tmp = format!("temporary-{}-keyslot", name);
+
+    let path = dm::create_device(&tmp, "", &[target], read_only)?;
+    let res = f(&path);
+    dm::remove_device(&tmp)?;
+
+    res
+}
+
+fn keyslot_sectors(key_bytes: usize, stripes: u32) -> u64 {
+    align(key_bytes as u64 * stripes as u64, SECTOR_SIZE) / SECTOR_SIZE
+}
+
+fn device_sectors(dev: &str) -> Result<u64> {
+    let size = File::open(dev)?.seek(SeekFrom::End(0))?;
+    Ok(size / SECTOR_SIZE)
+}
+
+fn random_bytes(len: usize) -> Result<Vec<u8>> {
+    let mut buf = vec![0u8; len];
+    File::open(RANDOM_DEV)?.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn random_uuid() -> Result<String> {
+    let mut b = random_bytes(16)?;
+    b[6] = (b[6] & 0x0f) | 0x40;
+    b[8] = (b[8] & 0x3f) | 0x80;
+    let h = hex(&b);
+
+    Ok(format!(
+        "{}-{}-{}-{}-{}",
+        &h[0..8],
+        &h[8..12],
+        &h[12..16],
+        &h[16..20],
+        &h[20..32]
+    ))
+}
+
+// af_split spreads key over stripes, the last one being the key xored
+// with the diffusion of all the previous, random, ones. Losing any of
+// the stripes loses the key.
+fn af_split(key: &[u8], stripes: usize) -> Result<Vec<u8>> {
+    let n = key.len();
+    let mut material = random_bytes(n * (stripes - 1))?;
+    let mut d = vec![0u8; n];
+
+    for s in material.chunks(n) {
+        xor(&mut d, s);
+        d = diffuse(&d);
+    }
+
+    xor(&mut d, key);
+    material.extend_from_slice(&d);
+
+    Ok(material)
+}
+
+fn af_merge(material: &[u8], key_bytes: usize, stripes: usize) -> Result<Vec<u8>> {
+    if key_bytes == 0 || stripes == 0 || material.len() < key_bytes * stripes {
+        return Err(ErrorKind::ErrorCode(format!(
+            "{} bytes of key material for {} stripes of {}",
+            material.len(),
+            stripes,
+            key_bytes
+        ))
+        .into());
+    }
+
+    let mut d = vec![0u8; key_bytes];
+
+    for s in material.chunks(key_bytes).take(stripes - 1) {
+        xor(&mut d, s);
+        d = diffuse(&d);
+    }
+
+    xor(
+        &mut d,
+        &material[(stripes - 1) * key_bytes..stripes * key_bytes],
+    );
+    Ok(d)
+}
+
+// diffuse hashes buf by digest sized blocks, each prefixed with its
+// big endian index.
+fn diffuse(buf: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(buf.len());
+
+    for (i, block) in buf.chunks(32).enumerate() {
+        let mut h = Sha256::new();
+        h.update(&(i as u32).to_be_bytes());
+        h.update(block);
+        out.extend_from_slice(&h.finish()[..block.len()]);
+    }
+
+    out
+}
+
+fn xor(dst: &mut [u8], src: &[u8]) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        *d ^= s;
+    }
+}
+
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32, len: usize) -> Vec<u8> {
+    let mac = HmacSha256::new(password);
+    let mut out = Vec::with_capacity(len);
+
+    let mut block = 1u32;
+    while out.len() < len {
+        let mut msg = salt.to_vec();
+        msg.extend_from_slice(&block.to_be_bytes());
+
+        let mut u = mac.mac(&msg);
+        let mut t = u;
+        for _ in 1..iterations {
+            u = mac.mac(&u);
+            xor(&mut t, &u);
+        }
+
+        let n = (len - out.len()).min(t.len());
+        out.extend_from_slice(&t[..n]);
+        block += 1;
+    }
+
+    out
+}
+
+// HmacSha256 keeps the hash states after the padded key, so that each
+// of the many MACs of PBKDF2 only costs the message blocks.
+struct HmacSha256 {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl HmacSha256 {
+    fn new(key: &[u8]) -> Self {
+        let mut k = [0u8; 64];
+        if key.len() > 64 {
+            let mut h = Sha256::new();
+            h.update(key);
+            k[..32].copy_from_slice(&h.finish());
+        } else {
+            k[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha256::new();
+        let mut outer = Sha256::new();
+        inner.update(&k.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+        outer.update(&k.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+
+        HmacSha256 { inner, outer }
+    }
+
+    fn mac(&self, msg: &[u8]) -> [u8; 32] {
+        let mut inner = self.inner.clone();
+        inner.update(msg);
+
+        let mut outer = self.outer.clone();
+        outer.update(&inner.finish());
+        outer.finish()
+    }
+}
+
+fn be32(b: &[u8]) -> u32 {
+    u32::from_be_bytes([b[0], b[1], b[2], b[3]])
+}
+
+fn cstr(b: &[u8]) -> String {
+    let end = b.iter().position(|c| *c == 0).unwrap_or(b.len());
+    String::from_utf8_lossy(&b[..end]).to_string()
+}
+
+fn put_cstr(dst: &mut [u8], s: &str) {
+    let n = s.len().min(dst.len() - 1);
+    dst[..n].copy_from_slice(&s.as_bytes()[..n]);
+}
+
+fn hex(b: &[u8]) -> String {
+    b.iter().map(|c| format!("{:02x}", c)).collect()
+}
+
+fn align(n: u64, to: u64) -> u64 {
+    (n + to - 1) / to * to
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skip_if_not_root;
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    // losetup attaches file to a loop device, whose path it returns.
+    fn losetup(file: &Path) -> Option<String> {
+        let out = Command::new("losetup")
+            .args(&["--find", "--show"])
+            .arg(file)
+            .output()
+            .ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+    }
+
+    #[test]
+    fn test_sha256_hmac_pbkdf2() {
+        let sha = |data: &[u8]| {
+            let mut h = Sha256::new();
+            h.update(data);
+            hex(&h.finish())
+        };
+
+        assert_eq!(
+            sha(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+
+        // the same, fed in pieces straddling the blocks
+        let mut h = Sha256::new();
+        for _ in 0..1000 {
+            h.update(&[b'a'; 1000]);
+        }
+        assert_eq!(
+            hex(&h.finish()),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+
+        assert_eq!(
+            hex(&HmacSha256::new(b"Jefe").mac(b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let cases = [
+            (
+                1,
+                "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+            ),
+            (
+                2,
+                "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43",
+            ),
+            (
+                4096,
+                "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+            ),
+        ];
+        for (iterations, expected) in cases.iter() {
+            assert_eq!(
+                hex(&pbkdf2_sha256(b"password", b"salt", *iterations, 32)),
+                *expected
+            );
+        }
+
+        // longer than a digest
+        assert_eq!(
+            hex(&pbkdf2_sha256(
+                b"passwordPASSWORDpassword",
+                b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+                4096,
+                40
+            )),
+            "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9"
+        );
+    }
+
+    #[test]
+    fn test_af_split_merge() {
+        let key: Vec<u8> = (0..KEY_BYTES as u8).collect();
+
+        let material = af_split(&key, LUKS_STRIPES as usize).unwrap();
+        assert_eq!(material.len(), KEY_BYTES * LUKS_STRIPES as usize);
+        assert_eq!(
+            af_merge(&material, KEY_BYTES, LUKS_STRIPES as usize).unwrap(),
+            key
+        );
+
+        let mut damaged = material.clone();
+        damaged[KEY_BYTES * 10] ^= 1;
+        assert_ne!(
+            af_merge(&damaged, KEY_BYTES, LUKS_STRIPES as usize).unwrap(),
+            key
+        );
+
+        assert!(af_merge(&material, 0, LUKS_STRIPES as usize).is_err());
+        assert!(af_merge(&material, KEY_BYTES, 0).is_err());
+        assert!(af_merge(&material, KEY_BYTES, LUKS_STRIPES as usize + 1).is_err());
+    }
+
+    #[test]
+    fn test_luks_header() {
+        let hdr = LuksHeader {
+            cipher_name: CIPHER_NAME.to_string(),
+            cipher_mode: CIPHER_MODE.to_string(),
+            hash_spec: HASH_SPEC.to_string(),
+            payload_offset: 4096,
+            key_bytes: KEY_BYTES as u32,
+            mk_digest: pbkdf2_sha256(b"key", &[1; LUKS_SALTSIZE], 2, LUKS_DIGESTSIZE),
+            mk_digest_salt: vec![1; LUKS_SALTSIZE],
+            mk_digest_iterations: 2,
+            uuid: random_uuid().unwrap(),
+            key_slots: (0..LUKS_NUMKEYS as u32)
+                .map(|i| KeySlot {
+                    active: i == 1,
+                    iterations: i * 10,
+                    salt: vec![i as u8; LUKS_SALTSIZE],
+                    key_material_offset: 8 + i * 504,
+                    stripes: LUKS_STRIPES,
+                })
+                .collect(),
+        };
+
+        let buf = hdr.to_bytes();
+        assert_eq!(&buf[..8], b"LUKS\xba\xbe\x00\x01");
+        assert_eq!(&buf[104..112], &[0, 0, 0x10, 0, 0, 0, 0, 64]);
+        assert_eq!(&buf[208..212], &LUKS_KEY_DISABLED.to_be_bytes());
+        assert_eq!(&buf[256..260], &LUKS_KEY_ENABLED.to_be_bytes());
+        assert_eq!(buf[168 + 14], b'4');
+
+        let sectors = 1 << 20;
+        let parsed = LuksHeader::parse(&buf, sectors).unwrap();
+        assert_eq!(parsed, hdr);
+        assert_eq!(parsed.cipher(), "aes-xts-plain64");
+        assert!(parsed.check_key(b"key"));
+        assert!(!parsed.check_key(b"kex"));
+
+        let mut bad = buf.clone();
+        bad[7] = 2;
+        assert!(LuksHeader::parse(&bad, sectors).is_err());
+        assert!(LuksHeader::parse(&buf[..100], sectors).is_err());
+
+        // the payload past the end of the device
+        assert!(LuksHeader::parse(&buf, 4096).is_err());
+
+        let tampered = |f: &dyn Fn(&mut LuksHeader)| {
+            let mut bad = hdr.clone();
+            f(&mut bad);
+            LuksHeader::parse(&bad.to_bytes(), sectors)
+        };
+        assert!(tampered(&|h| h.key_slots[1].stripes = 0).is_err());
+        assert!(tampered(&|h| h.key_slots[1].stripes = u32::max_value()).is_err());
+        assert!(tampered(&|h| h.key_slots[1].key_material_offset = 4000).is_err());
+        assert!(tampered(&|h| h.key_bytes = 0).is_err());
+        assert!(tampered(&|h| h.key_bytes = 1 << 30).is_err());
+        assert!(tampered(&|h| h.cipher_mode = "xts-plain64 0 /dev/vda".to_string()).is_err());
+        assert!(tampered(&|h| h.cipher_name = "null".to_string()).is_err());
+        assert!(tampered(&|h| h.hash_spec = "sha1".to_string()).is_err());
+        assert!(tampered(&|h| h.key_slots[1].iterations = 0).is_err());
+        assert!(tampered(&|h| h.key_slots[1].iterations = u32::max_value()).is_err());
+        assert!(tampered(&|h| h.mk_digest_iterations = 0).is_err());
+        assert!(tampered(&|h| h.mk_digest_iterations = u32::max_value()).is_err());
+        // an inactive keyslot is left alone
+        assert!(tampered(&|h| h.key_slots[2].stripes = 0).is_ok());
+        assert!(tampered(&|h| h.key_slots[2].iterations = u32::max_value()).is_ok());
+
+        assert_eq!(keyslot_sectors(KEY_BYTES, LUKS_STRIPES), 500);
+    }
+
+    #[test]
+    fn test_format_open_close() {
+        skip_if_not_root!();
+
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("disk");
+        File::create(&file).unwrap().set_len(8 << 20).unwrap();
+        let dev = match losetup(&file) {
+            Some(dev) => dev,
+            None => {
+                println!(
+                    "INFO: skipping {} which needs a loop device",
+                    module_path!()
+                );
+                return;
+            }
+        };
+        if !Path::new("/dev/mapper/control").exists() {
+            let _ = Command::new("losetup").args(&["-d", &dev]).status();
+            println!(
+                "INFO: skipping {} which needs device-mapper",
+                module_path!()
+            );
+            return;
+        }
+
+        let name = format!("luks-test-{}", std::process::id());
+        let res = (|| -> Result<()> {
+            assert!(is_blank(&dev)?);
+            format(&dev, &name, b"secret")?;
+            assert!(is_luks(&dev)?);
+            assert!(!is_blank(&dev)?);
+
+            let path = open(&dev, &name, b"secret", false)?;
+            OpenOptions::new()
+                .write(true)
+                .open(&path)?
+                .write_all(b"hello")?;
+            close(&name)?;
+
+            assert!(open(&dev, &name, b"wrong", true).is_err());
+
+            let path = open(&dev, &name, b"secret", true)?;
+            let mut data = [0u8; 5];
+            File::open(&path)?.read_exact(&mut data)?;
+            close(&name)?;
+            assert_eq!(&data, b"hello");
+
+            // encrypted on the disk
+            let hdr = read_header(&dev, device_sectors(&dev)?)?;
+            let mut raw = [0u8; 5];
+            let mut f = File::open(&dev)?;
+            f.seek(SeekFrom::Start(hdr.payload_offset as u64 * SECTOR_SIZE))?;
+            f.read_exact(&mut raw)?;
+            assert_ne!(&raw, b"hello");
+
+            Ok(())
+        })();
+
+        let _ = close(&name);
+        let _ = Command::new("losetup").args(&["-d", &dev]).status();
+        res.unwrap();
+    }
+}
@@ -14,6 +14,8 @@ extern crate prctl;
 extern crate protocols;
 extern crate regex;
 extern crate rustjail;
+#[macro_use]
+extern crate serde_derive;
 extern crate serde_json;
 extern crate signal_hook;
 #[macro_use]
@@ -44,19 +46,38 @@ use unistd::Pid;
 
 mod config;
 mod device;
+mod dm;
+mod image;
 mod logging;
+mod luks;
+mod metrics;
 mod mount;
 mod namespace;
 pub mod netlink;
+mod netmon;
 mod network;
+mod nlmsg;
+mod policy;
+mod quota;
 pub mod random;
+mod resize;
 mod sandbox;
+mod serial;
+mod sha256;
+mod tc;
 #[cfg(test)]
 mod test_utils;
+mod tls;
+mod tracer;
+mod ttrpc;
 mod uevent;
 mod version;
+mod watchable;
 
+use config::ServerAddr;
+use logging::{LogLevels, VsockWriter};
 use mount::{cgroups_mount, general_mount};
+use netmon::watch_network_events;
 use sandbox::Sandbox;
 use slog::Logger;
 use uevent::watch_uevents;
@@ -64,8 +85,6 @@ use uevent::watch_uevents;
 mod grpc;
 
 const NAME: &'static str = "kata-agent";
-const VSOCK_ADDR: &'static str = "vsock://-1";
-const VSOCK_PORT: u16 = 1024;
 const KERNEL_CMDLINE_FILE: &'static str = "/proc/cmdline";
 const CONSOLE_PATH: &'static str = "/dev/console";
 
@@ -74,6 +93,8 @@ lazy_static! {
         Arc::new(Mutex::new(HashMap::new()));
     static ref AGENT_CONFIG: Arc<RwLock<agentConfig>> =
         Arc::new(RwLock::new(config::agentConfig::new()));
+    // the log levels of the agent logger, which SetLogLevel changes
+    static ref LOG_LEVELS: LogLevels = LogLevels::new(slog::Level::Info);
 }
 
 use std::mem::MaybeUninit;
@@ -110,7 +131,8 @@ fn main() -> Result<()> {
         // since before do the base mount, it wouldn't access "/proc/cmdline"
         // to get the customzied debug level.
         let writer = io::stdout();
-        let logger = logging::create_logger(NAME, "agent", slog::Level::Debug, writer);
+        let levels = LogLevels::new(slog::Level::Debug);
+        let logger = logging::create_logger(NAME, "agent", levels, writer);
         init_agent_as_init(&logger)?;
     }
 
@@ -119,13 +141,32 @@ fn main() -> Result<()> {
     // it.
     {
         let mut config = agentConfig.write().unwrap();
-        config.parse_cmdline(KERNEL_CMDLINE_FILE)?;
+        if let Err(e) = config.parse_cmdline(KERNEL_CMDLINE_FILE) {
+            // the configured logger is yet to be, report on the console
+            let levels = LogLevels::new(slog::Level::Info);
+            let logger = logging::create_logger(NAME, "agent", levels, io::stdout());
+            error!(logger, "invalid agent configuration"; "error" => format!("{}", e));
+            return Err(e);
+        }
     }
 
     let config = agentConfig.read().unwrap();
-    let writer = io::stdout();
+    let (writer, vsock_err): (Box<dyn io::Write + Send + Sync>, _) = match config.log_vport {
+        0 => (Box::new(io::stdout()), None),
+        port => match VsockWriter::new(port) {
+            Ok(w) => (Box::new(w), None),
+            Err(e) => (Box::new(io::stdout()), Some(e)),
+        },
+    };
     // Recreate a logger with the log level get from "/proc/cmdline".
-    let logger = logging::create_logger(NAME, "agent", config.log_level, writer);
+    LOG_LEVELS.set_level(config.log_level);
+    let logger = logging::create_logger(NAME, "agent", LOG_LEVELS.clone(), writer);
+
+    if let Some(e) = vsock_err {
+        warn!(logger, "failed to serve logs on vsock, logging to the console";
+            "port" => config.log_vport,
+            "error" => format!("{}", e));
+    }
 
     announce(&logger);
 
@@ -140,6 +181,19 @@ fn main() -> Result<()> {
     // which is required to satisfy the the lifetime constraints of the auto-generated gRPC code.
     let _guard = slog_scope::set_global_logger(logger.new(o!("subsystem" => "grpc")));
 
+    // the RPCs are not served unrestricted when the policy fails to load
+    policy::init(config.policy_file.as_ref().map(|p| p.as_str())).map_err(|e| {
+        error!(logger, "failed to load the policy"; "error" => format!("{}", e));
+        e
+    })?;
+
+    if config.tracing {
+        if let Err(e) = tracer::start(&config.trace_addr) {
+            // Report error, but don't fail
+            warn!(logger, "failed to start tracing"; "error" => format!("{}", e));
+        }
+    }
+
     let shells = SHELLS.clone();
 
     let shell_handle = if config.debug_console {
@@ -168,18 +222,22 @@ fn main() -> Result<()> {
 
     setup_signal_handler(&logger, sandbox.clone()).unwrap();
     watch_uevents(sandbox.clone());
+    watch_network_events(sandbox.clone());
 
     let (tx, rx) = mpsc::channel::<i32>();
     sandbox.lock().unwrap().sender = Some(tx);
 
-    //vsock:///dev/vsock, port
-    let mut server = grpc::start(sandbox.clone(), VSOCK_ADDR, VSOCK_PORT);
+    let tls_files = tls::init(&config).map_err(|e| {
+        error!(logger, "failed to load the TLS files"; "error" => format!("{}", e));
+        e
+    })?;
 
-    /*
-        let _ = fs::remove_file("/tmp/testagent");
-        let _ = fs::remove_dir_all("/run/agent");
-        let mut server = grpc::start(sandbox.clone(), "unix:///tmp/testagent", 1);
-    */
+    let mut server = grpc::start_server(
+        sandbox.clone(),
+        config.server_type,
+        &config.server_addr,
+        tls_files.as_ref(),
+    )?;
 
     let handle = thread::spawn(move || {
         // info!("Press ENTER to exit...");
@@ -199,8 +257,10 @@ fn main() -> Result<()> {
         shell_handle.join().unwrap();
     }
 
-    let _ = server.shutdown().wait();
-    let _ = fs::remove_file("/tmp/testagent");
+    server.shutdown();
+    if let ServerAddr::Unix(path) = &config.server_addr {
+        let _ = fs::remove_file(path);
+    }
 
     Ok(())
 }
@@ -256,14 +316,19 @@ fn setup_signal_handler(logger: &Logger, sandbox: Arc<Mutex<Sandbox>>) -> Result
 
                     let logger = logger.new(o!("child-pid" => child_pid));
 
-                    let mut sandbox = s.lock().unwrap();
-                    let process = sandbox.find_process(raw_pid);
-                    if process.is_none() {
-                        info!(logger, "child exited unexpectedly");
-                        continue 'inner;
-                    }
+                    let ctr = match sandbox::find_process_container(&s, raw_pid) {
+                        Some(ctr) => ctr,
+                        None => {
+                            info!(logger, "child exited unexpectedly");
+                            continue 'inner;
+                        }
+                    };
 
-                    let mut p = process.unwrap();
+                    let mut ctr = ctr.lock().unwrap();
+                    let p = match ctr.processes.get_mut(&raw_pid) {
+                        Some(p) => p,
+                        None => continue 'inner,
+                    };
 
                     if p.exit_pipe_w.is_none() {
                         error!(logger, "the process's exit_pipe_w isn't set");
@@ -0,0 +1,573 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Metrics of the agent process, of the RPCs it serves and of the guest, in
+// the Prometheus text exposition format, gathered when they are requested.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::sync::Mutex;
+use std::time::Instant;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "metrics"))
+    };
+}
+
+const PROC_SELF_STATUS: &'static str = "/proc/self/status";
+const PROC_SELF_STAT: &'static str = "/proc/self/stat";
+const PROC_SELF_FD: &'static str = "/proc/self/fd";
+const PROC_MEMINFO: &'static str = "/proc/meminfo";
+const PROC_VMSTAT: &'static str = "/proc/vmstat";
+const PROC_LOADAVG: &'static str = "/proc/loadavg";
+const PROC_STAT: &'static str = "/proc/stat";
+const PROC_DISKSTATS: &'static str = "/proc/diskstats";
+const PROC_NET_DEV: &'static str = "/proc/net/dev";
+
+// upper bounds of the buckets of the RPC latencies, in seconds
+const LATENCY_BUCKETS: [f64; 14] = [
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+// the statistics of /proc/stat per CPU, in their order
+const CPU_MODES: [&'static str; 10] = [
+    "user",
+    "nice",
+    "system",
+    "idle",
+    "iowait",
+    "irq",
+    "softirq",
+    "steal",
+    "guest",
+    "guest_nice",
+];
+
+// the statistics of /proc/diskstats per disk, in their order
+const DISK_ITEMS: [&'static str; 17] = [
+    "reads_completed",
+    "reads_merged",
+    "sectors_read",
+    "read_time_ms",
+    "writes_completed",
+    "writes_merged",
+    "sectors_written",
+    "write_time_ms",
+    "ios_in_progress",
+    "io_time_ms",
+    "weighted_io_time_ms",
+    "discards_completed",
+    "discards_merged",
+    "sectors_discarded",
+    "discard_time_ms",
+    "flushes_completed",
+    "flush_time_ms",
+];
+
+// the statistics of /proc/net/dev per interface, in their order
+const NETDEV_ITEMS: [&'static str; 16] = [
+    "recv_bytes",
+    "recv_packets",
+    "recv_errs",
+    "recv_drop",
+    "recv_fifo",
+    "recv_frame",
+    "recv_compressed",
+    "recv_multicast",
+    "sent_bytes",
+    "sent_packets",
+    "sent_errs",
+    "sent_drop",
+    "sent_fifo",
+    "sent_colls",
+    "sent_carrier",
+    "sent_compressed",
+];
+
+#[derive(Default)]
+struct RpcStats {
+    // requests per bucket of LATENCY_BUCKETS, not cumulated
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+lazy_static! {
+    static ref RPC_STATS: Mutex<BTreeMap<String, RpcStats>> = Mutex::new(BTreeMap::new());
+}
+
+// RpcTimer accounts the latency of an RPC when it is dropped.
+pub struct RpcTimer {
+    method: String,
+    start: Instant,
+}
+
+impl Drop for RpcTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let secs = elapsed.as_secs() as f64 + f64::from(elapsed.subsec_nanos()) / 1e9;
+        observe_rpc(&self.method, secs);
+    }
+}
+
+pub fn rpc_timer(method: &str) -> RpcTimer {
+    RpcTimer {
+        method: method.to_string(),
+        start: Instant::now(),
+    }
+}
+
+fn observe_rpc(method: &str, secs: f64) {
+    let mut stats = RPC_STATS.lock().unwrap();
+    let stat = stats.entry(method.to_string()).or_default();
+
+    if let Some(i) = LATENCY_BUCKETS.iter().position(|le| secs <= *le) {
+        stat.buckets[i] += 1;
+    }
+    stat.count += 1;
+    stat.sum += secs;
+}
+
+struct Encoder {
+    out: String,
+}
+
+impl Encoder {
+    fn new() -> Self {
+        Encoder { out: String::new() }
+    }
+
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP {} {}", name, help);
+        let _ = writeln!(self.out, "# TYPE {} {}", name, kind);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|(k, v)| format!("{}=\"{}\"", k, escape(v)))
+                .collect();
+            let _ = write!(self.out, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(self.out, " {}", value);
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn clock_ticks() -> f64 {
+    match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        n if n > 0 => n as f64,
+        _ => 100.0,
+    }
+}
+
+// kb_value parses the value of a line of /proc/meminfo or of a status file,
+// in bytes when it is in kB.
+fn kb_value(value: &str) -> Option<f64> {
+    let mut fields = value.split_whitespace();
+    let n: f64 = fields.next()?.parse().ok()?;
+    match fields.next() {
+        Some("kB") => Some(n * 1024.0),
+        _ => Some(n),
+    }
+}
+
+fn encode_process(enc: &mut Encoder, status: &str, stat: &str, fds: usize, ticks: f64) {
+    for line in status.lines() {
+        let (key, name, help) = match line.split(':').next() {
+            Some("VmRSS") => (
+                "VmRSS",
+                "kata_agent_process_resident_memory_bytes",
+                "Resident memory size of the agent in bytes.",
+            ),
+            Some("VmSize") => (
+                "VmSize",
+                "kata_agent_process_virtual_memory_bytes",
+                "Virtual memory size of the agent in bytes.",
+            ),
+            Some("Threads") => (
+                "Threads",
+                "kata_agent_process_threads",
+                "Number of threads of the agent.",
+            ),
+            _ => continue,
+        };
+        if let Some(value) = kb_value(&line[key.len() + 1..]) {
+            enc.family(name, "gauge", help);
+            enc.sample(name, &[], value);
+        }
+    }
+
+    // the fields after the command, which may hold spaces, start at state,
+    // the third one: utime and stime are the 14th and 15th
+    if let Some(pos) = stat.rfind(')') {
+        let fields: Vec<&str> = stat[pos + 1..].split_whitespace().collect();
+        let utime = fields.get(11).and_then(|f| f.parse::<f64>().ok());
+        let stime = fields.get(12).and_then(|f| f.parse::<f64>().ok());
+        if let (Some(utime), Some(stime)) = (utime, stime) {
+            let name = "kata_agent_process_cpu_seconds_total";
+            enc.family(name, "counter", "CPU time of the agent in seconds.");
+            enc.sample(name, &[], (utime + stime) / ticks);
+        }
+    }
+
+    let name = "kata_agent_process_open_fds";
+    enc.family(
+        name,
+        "gauge",
+        "Number of file descriptors opened by the agent.",
+    );
+    enc.sample(name, &[], fds as f64);
+}
+
+fn encode_rpcs(enc: &mut Encoder) {
+    let stats = RPC_STATS.lock().unwrap();
+    let name = "kata_agent_rpc_duration_seconds";
+
+    enc.family(
+        name,
+        "histogram",
+        "Latency of the RPCs served by the agent in seconds, per method.",
+    );
+    for (method, stat) in stats.iter() {
+        let bucket = format!("{}_bucket", name);
+        let mut count = 0;
+        for (le, n) in LATENCY_BUCKETS.iter().zip(stat.buckets.iter()) {
+            count += n;
+            let le = le.to_string();
+            enc.sample(
+                &bucket,
+                &[("method", method.as_str()), ("le", le.as_str())],
+                count as f64,
+            );
+        }
+        enc.sample(
+            &bucket,
+            &[("method", method.as_str()), ("le", "+Inf")],
+            stat.count as f64,
+        );
+        enc.sample(
+            &format!("{}_sum", name),
+            &[("method", method.as_str())],
+            stat.sum,
+        );
+        enc.sample(
+            &format!("{}_count", name),
+            &[("method", method.as_str())],
+            stat.count as f64,
+        );
+    }
+}
+
+fn encode_meminfo(enc: &mut Encoder, meminfo: &str) {
+    let name = "kata_guest_meminfo";
+    enc.family(
+        name,
+        "gauge",
+        "Memory statistics of the guest from /proc/meminfo, in bytes but the counts of huge pages.",
+    );
+    for line in meminfo.lines() {
+        let mut parts = line.splitn(2, ':');
+        let (item, value) = match (parts.next(), parts.next()) {
+            (Some(item), Some(value)) => (item.trim(), value),
+            _ => continue,
+        };
+        if let Some(value) = kb_value(value) {
+            enc.sample(name, &[("item", item)], value);
+        }
+    }
+}
+
+fn encode_vmstat(enc: &mut Encoder, vmstat: &str) {
+    let name = "kata_guest_vmstat";
+    enc.family(
+        name,
+        "untyped",
+        "Virtual memory statistics of the guest from /proc/vmstat.",
+    );
+    for line in vmstat.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 2 {
+            continue;
+        }
+        if let Ok(value) = fields[1].parse::<f64>() {
+            enc.sample(name, &[("item", fields[0])], value);
+        }
+    }
+}
+
+fn encode_loadavg(enc: &mut Encoder, loadavg: &str) {
+    let name = "kata_guest_load";
+    enc.family(
+        name,
+        "gauge",
+        "Load average of the guest from /proc/loadavg.",
+    );
+    let periods = ["1m", "5m", "15m"];
+    for (period, value) in periods.iter().zip(loadavg.split_whitespace()) {
+        if let Ok(value) = value.parse::<f64>() {
+            enc.sample(name, &[("period", period)], value);
+        }
+    }
+}
+
+fn encode_cpu_time(enc: &mut Encoder, stat: &str, ticks: f64) {
+    let name = "kata_guest_cpu_time_seconds_total";
+    enc.family(
+        name,
+        "counter",
+        "Time spent by each CPU of the guest in each mode, from /proc/stat, in seconds.",
+    );
+    for line in stat.lines() {
+        let mut fields = line.split_whitespace();
+        // the total of all the CPUs is "cpu", left to the queries to sum
+        let cpu = match fields.next() {
+            Some(f) if f.starts_with("cpu") && f.len() > 3 => &f[3..],
+            _ => continue,
+        };
+        for (mode, value) in CPU_MODES.iter().zip(fields) {
+            if let Ok(value) = value.parse::<f64>() {
+                enc.sample(name, &[("cpu", cpu), ("mode", mode)], value / ticks);
+            }
+        }
+    }
+}
+
+fn encode_diskstats(enc: &mut Encoder, diskstats: &str) {
+    let name = "kata_guest_diskstat";
+    enc.family(
+        name,
+        "untyped",
+        "I/O statistics of the disks of the guest from /proc/diskstats.",
+    );
+    for line in diskstats.lines() {
+        // major, minor and name precede the statistics
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 4 {
+            continue;
+        }
+        for (item, value) in DISK_ITEMS.iter().zip(&fields[3..]) {
+            if let Ok(value) = value.parse::<f64>() {
+                enc.sample(name, &[("disk", fields[2]), ("item", item)], value);
+            }
+        }
+    }
+}
+
+fn encode_netdev(enc: &mut Encoder, netdev: &str) {
+    let name = "kata_guest_netdev_stat";
+    enc.family(
+        name,
+        "untyped",
+        "Statistics of the network interfaces of the guest from /proc/net/dev.",
+    );
+    // the two first lines are headers
+    for line in netdev.lines().skip(2) {
+        let mut parts = line.splitn(2, ':');
+        let (interface, values) = match (parts.next(), parts.next()) {
+            (Some(interface), Some(values)) => (interface.trim(), values),
+            _ => continue,
+        };
+        for (item, value) in NETDEV_ITEMS.iter().zip(values.split_whitespace()) {
+            if let Ok(value) = value.parse::<f64>() {
+                enc.sample(name, &[("interface", interface), ("item", item)], value);
+            }
+        }
+    }
+}
+
+// read_proc reads a file of /proc, whose metrics are skipped when it cannot.
+fn read_proc(path: &str) -> Option<String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) => {
+            warn!(sl!(), "failed to read {}", path; "error" => format!("{}", e));
+            None
+        }
+    }
+}
+
+// gather returns the metrics of the agent and of the guest.
+pub fn gather() -> String {
+    let mut enc = Encoder::new();
+    let ticks = clock_ticks();
+
+    let status = read_proc(PROC_SELF_STATUS).unwrap_or_default();
+    let stat = read_proc(PROC_SELF_STAT).unwrap_or_default();
+    let fds = fs::read_dir(PROC_SELF_FD).map(|d| d.count()).unwrap_or(0);
+    encode_process(&mut enc, &status, &stat, fds, ticks);
+    encode_rpcs(&mut enc);
+
+    if let Some(meminfo) = read_proc(PROC_MEMINFO) {
+        encode_meminfo(&mut enc, &meminfo);
+    }
+    if let Some(vmstat) = read_proc(PROC_VMSTAT) {
+        encode_vmstat(&mut enc, &vmstat);
+    }
+    if let Some(loadavg) = read_proc(PROC_LOADAVG) {
+        encode_loadavg(&mut enc, &loadavg);
+    }
+    if let Some(stat) = read_proc(PROC_STAT) {
+        encode_cpu_time(&mut enc, &stat, ticks);
+    }
+    if let Some(diskstats) = read_proc(PROC_DISKSTATS) {
+        encode_diskstats(&mut enc, &diskstats);
+    }
+    if let Some(netdev) = read_proc(PROC_NET_DEV) {
+        encode_netdev(&mut enc, &netdev);
+    }
+
+    enc.out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_proc() {
+        let mut enc = Encoder::new();
+
+        encode_process(
+            &mut enc,
+            "Name:\tkata-agent\nVmSize:\t  10240 kB\nVmRSS:\t    2048 kB\nThreads:\t5\n",
+            "42 (kata agent) S 1 42 42 0 -1 4194560 100 0 0 0 150 50 0 0 20 0 5 0",
+            7,
+            100.0,
+        );
+        encode_meminfo(
+            &mut enc,
+            "MemTotal:        2048000 kB\nHugePages_Total:       2\n",
+        );
+        encode_vmstat(&mut enc, "nr_free_pages 1234\npgfault 99\n");
+        encode_loadavg(&mut enc, "0.50 0.25 0.10 1/80 1234\n");
+        encode_cpu_time(
+            &mut enc,
+            "cpu  300 0 100 1000 0 0 0 0 0 0\ncpu0 200 0 50 500 0 0 0 0 0 0\nintr 1 2\n",
+            100.0,
+        );
+        encode_diskstats(&mut enc, " 254       0 vda 10 0 80 5 2 0 16 1 0 6 6\n");
+        encode_netdev(
+            &mut enc,
+            "Inter-|   Receive |  Transmit\n face |bytes packets|bytes packets\n  eth0: 1500 3 0 0 0 0 0 0 600 2 0 0 0 0 0 0\n",
+        );
+
+        let expected = [
+            "# TYPE kata_agent_process_resident_memory_bytes gauge",
+            "kata_agent_process_resident_memory_bytes 2097152",
+            "kata_agent_process_virtual_memory_bytes 10485760",
+            "kata_agent_process_threads 5",
+            "kata_agent_process_cpu_seconds_total 2",
+            "kata_agent_process_open_fds 7",
+            "kata_guest_meminfo{item=\"MemTotal\"} 2097152000",
+            "kata_guest_meminfo{item=\"HugePages_Total\"} 2",
+            "kata_guest_vmstat{item=\"pgfault\"} 99",
+            "kata_guest_load{period=\"5m\"} 0.25",
+            "kata_guest_cpu_time_seconds_total{cpu=\"0\",mode=\"user\"} 2",
+            "kata_guest_cpu_time_seconds_total{cpu=\"0\",mode=\"idle\"} 5",
+            "kata_guest_diskstat{disk=\"vda\",item=\"sectors_read\"} 80",
+            "kata_guest_diskstat{disk=\"vda\",item=\"weighted_io_time_ms\"} 6",
+            "kata_guest_netdev_stat{interface=\"eth0\",item=\"recv_bytes\"} 1500",
+            "kata_guest_netdev_stat{interface=\"eth0\",item=\"sent_packets\"} 2",
+        ];
+        for line in expected.iter() {
+            assert!(
+                enc.out.lines().any(|l| l == *line),
+                "{} missing from:\n{}",
+                line,
+                enc.out
+            );
+        }
+
+        // the total of all the CPUs and the lines of other statistics
+        assert!(!enc.out.contains("cpu=\"\""));
+        assert!(!enc.out.contains("intr"));
+        // discards and flushes are not known to older kernels
+        assert!(!enc.out.contains("discards_completed"));
+    }
+
+    #[test]
+    fn test_rpc_duration() {
+        let method = "test_rpc_duration";
+
+        observe_rpc(method, 0.003);
+        observe_rpc(method, 0.2);
+        observe_rpc(method, 60.0);
+        drop(rpc_timer(method));
+
+        let mut enc = Encoder::new();
+        encode_rpcs(&mut enc);
+
+        let label = format!("method=\"{}\"", method);
+        let expected = [
+            format!(
+                "kata_agent_rpc_duration_seconds_bucket{{{},le=\"0.0025\"}} 1",
+                label
+            ),
+            format!(
+                "kata_agent_rpc_duration_seconds_bucket{{{},le=\"0.005\"}} 2",
+                label
+            ),
+            format!(
+                "kata_agent_rpc_duration_seconds_bucket{{{},le=\"0.25\"}} 3",
+                label
+            ),
+            format!(
+                "kata_agent_rpc_duration_seconds_bucket{{{},le=\"10\"}} 3",
+                label
+            ),
+            format!(
+                "kata_agent_rpc_duration_seconds_bucket{{{},le=\"+Inf\"}} 4",
+                label
+            ),
+            format!("kata_agent_rpc_duration_seconds_count{{{}}} 4", label),
+        ];
+        for line in expected.iter() {
+            assert!(
+                enc.out.lines().any(|l| l == line),
+                "{} missing from:\n{}",
+                line,
+                enc.out
+            );
+        }
+    }
+
+    #[test]
+    fn test_gather() {
+        let metrics = gather();
+
+        for name in [
+            "kata_agent_process_resident_memory_bytes",
+            "kata_agent_process_open_fds",
+            "kata_guest_meminfo",
+            "kata_guest_cpu_time_seconds_total",
+        ]
+        .iter()
+        {
+            assert!(
+                metrics.lines().any(|l| l.starts_with(&format!("{} ", name))
+                    || l.starts_with(&format!("{}{{", name))),
+                "{} missing",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
@@ -4,27 +4,43 @@
 //
 
 use rustjail::errors::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::CString;
 use std::fs;
 use std::io;
 use std::iter::FromIterator;
-use std::os::unix::fs::PermissionsExt;
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
 
 use std::path::Path;
+use std::process::Command;
 use std::ptr::null;
 use std::sync::{Arc, Mutex};
 
 use libc::{c_void, mount};
 use nix::mount::{self, MsFlags};
+use nix::sys::{statfs, statvfs};
+use nix::unistd::{self, FchownatFlags, Gid};
+use protobuf::RepeatedField;
 
 use regex::Regex;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-use crate::device::{get_pci_device_name, get_scsi_device_name, online_device};
-use crate::protocols::agent::Storage;
+use crate::device::{
+    get_pci_device_name, get_pmem_device_name, get_scsi_device_name, online_device,
+    pmem_supports_dax,
+};
+use crate::grpc::CONTAINER_BASE;
+use crate::image;
+use crate::luks;
+use crate::protocols::agent::{FSGroupChangePolicy, Storage, VolumeStats};
+use crate::quota;
+use crate::resize;
+use crate::sandbox::SandboxStorages;
+use crate::tracer;
+use crate::watchable::WatchableStorage;
 use crate::Sandbox;
+use rustjail::securejoin::secure_join;
 use slog::Logger;
 
 const DRIVER9PTYPE: &'static str = "9p";
@@ -35,6 +51,40 @@ pub const DRIVERSCSITYPE: &'static str = "scsi";
 pub const DRIVERNVDIMMTYPE: &'static str = "nvdimm";
 const DRIVEREPHEMERALTYPE: &'static str = "ephemeral";
 const DRIVERLOCALTYPE: &'static str = "local";
+const DRIVEROVERLAYFSTYPE: &'static str = "overlayfs";
+const DRIVERWATCHABLETYPE: &'static str = "watchable";
+const DRIVERIMAGETYPE: &'static str = "image";
+
+// filesystems that can be mounted with the dax option, which maps the
+// files of a pmem device straight into the page tables of the guest
+const DAX_FSTYPES: [&'static str; 2] = ["ext4", "xfs"];
+const DAX_OPTION: &'static str = "dax";
+
+// Storage.driver_options opening a block device through dm-crypt. The
+// key, LUKS passphrase, is either given inline or as a file under the
+// sandbox storage directory, and a blank device is only formatted when
+// asked to.
+const ENCRYPTION_OPTION: &'static str = "encryption";
+const ENCRYPTION_KEY_OPTION: &'static str = "encryption_key";
+const ENCRYPTION_KEY_FILE_OPTION: &'static str = "encryption_key_file";
+const ENCRYPTION_FORMAT_OPTION: &'static str = "encryption_format";
+const ENCRYPTION_LUKS: &'static str = "luks";
+// group permissions given along with the fsGroup of a storage
+const FSGROUP_RW_MASK: u32 = 0o660;
+const FSGROUP_RO_MASK: u32 = 0o440;
+const FSGROUP_EXEC_MASK: u32 = 0o110;
+
+// driver options of block storages, to check their filesystem before
+// mounting it and grow it to the size of the device once mounted
+const FSCK_OPTION: &'static str = "fsck=true";
+const RESIZE_OPTION: &'static str = "resize=true";
+
+// The image of an image storage, by its ref name or manifest digest.
+const IMAGE_REF_OPTION: &'static str = "image_ref";
+// Where block devices holding image layouts are mounted while unpacked.
+const IMAGE_LAYOUT_DIR: &'static str = "/run/kata-containers/sandbox/image-layouts";
+
+pub const SANDBOX_STORAGE_DIR: &'static str = "/run/kata-containers/sandbox/storage";
 
 pub const TYPEROOTFS: &'static str = "rootfs";
 
@@ -150,6 +200,14 @@ lazy_static! {
         m.insert(DRIVERLOCALTYPE, local);
     let scsi: StorageHandler = virtio_scsi_storage_handler;
         m.insert(DRIVERSCSITYPE, scsi);
+    let nvdimm: StorageHandler = nvdimm_storage_handler;
+        m.insert(DRIVERNVDIMMTYPE, nvdimm);
+    let overlayfs: StorageHandler = overlayfs_storage_handler;
+        m.insert(DRIVEROVERLAYFSTYPE, overlayfs);
+    let watchable: StorageHandler = watchable_storage_handler;
+        m.insert(DRIVERWATCHABLETYPE, watchable);
+    let image: StorageHandler = image_storage_handler;
+        m.insert(DRIVERIMAGETYPE, image);
         m
     };
 }
@@ -222,15 +280,25 @@ impl<'a> BareMount<'a> {
             options = cstr_options.as_ptr() as *const c_void;
         }
 
+        // Given propagation flags, mount(2) only changes the propagation
+        // of what is mounted already, so they are applied once mounted.
+        let propagation = self.flags
+            & (MsFlags::MS_SHARED
+                | MsFlags::MS_PRIVATE
+                | MsFlags::MS_SLAVE
+                | MsFlags::MS_UNBINDABLE);
+        let flags = self.flags & !propagation;
+
         info!(
             self.logger,
-            "mount source={:?}, dest={:?}, fs_type={:?}, options={:?}",
+            "mount source={:?}, dest={:?}, fs_type={:?}, options={:?}, propagation={:?}",
             self.source,
             self.destination,
             self.fs_type,
-            self.options
+            self.options,
+            propagation
         );
-        let rc = unsafe { mount(source, dest, fs_type, self.flags.bits(), options) };
+        let rc = unsafe { mount(source, dest, fs_type, flags.bits(), options) };
 
         if rc < 0 {
             return Err(ErrorKind::ErrorCode(format!(
@@ -241,43 +309,111 @@ impl<'a> BareMount<'a> {
             ))
             .into());
         }
+
+        if !propagation.is_empty() {
+            // MS_REC makes the propagation recursive as well
+            if let Err(e) = mount::mount(
+                None::<&str>,
+                self.destination,
+                None::<&str>,
+                propagation | (self.flags & MsFlags::MS_REC),
+                None::<&str>,
+            ) {
+                let _ = mount::umount(self.destination);
+                return Err(ErrorKind::ErrorCode(format!(
+                    "failed to set the propagation of {:?}, with error: {}",
+                    self.destination, e
+                ))
+                .into());
+            }
+        }
+
         Ok(())
     }
 }
 
+// mount_once runs mount for the storage of mount_point, unless it is set up
+// already, in which case its reference count is only taken, and returns what
+// record makes of the result, with the storages locked. It runs with the
+// storages unlocked and holding the lock of mount_point, which is released
+// only once the storage is recorded, or unset should mount fail.
+fn mount_once<T, U>(
+    sandbox: &Arc<Mutex<Sandbox>>,
+    mount_point: &str,
+    mount: impl FnOnce() -> Result<T>,
+    record: impl FnOnce(&mut SandboxStorages, T) -> U,
+) -> Result<Option<U>> {
+    let storages = sandbox.lock().unwrap().storages.clone();
+    let lock = storages.lock().unwrap().mount_lock(mount_point);
+    let _mounting = lock.lock().unwrap();
+
+    if !storages.lock().unwrap().set_sandbox_storage(mount_point) {
+        return Ok(None);
+    }
+
+    match mount() {
+        Ok(v) => Ok(Some(record(&mut storages.lock().unwrap(), v))),
+        Err(e) => {
+            storages.lock().unwrap().unset_sandbox_storage(mount_point);
+            Err(e)
+        }
+    }
+}
+
 fn ephemeral_storage_handler(
     logger: &Logger,
     storage: &Storage,
     sandbox: Arc<Mutex<Sandbox>>,
 ) -> Result<String> {
-    let s = sandbox.clone();
-    let mut sb = s.lock().unwrap();
-    let new_storage = sb.set_sandbox_storage(&storage.mount_point);
+    let mount_point = mount_once(
+        &sandbox,
+        &storage.mount_point,
+        || {
+            fs::create_dir_all(Path::new(&storage.mount_point))?;
+            common_storage_handler(logger, &with_size_limit(storage))
+        },
+        |_, mount_point| mount_point,
+    )?;
+
+    Ok(mount_point.unwrap_or_default())
+}
 
-    if !new_storage {
-        return Ok("".to_string());
+// with_size_limit returns storage with its size limit, if any, as the
+// size of its tmpfs.
+fn with_size_limit(storage: &Storage) -> Storage {
+    let mut storage = storage.clone();
+    if storage.size_limit == 0 || storage.fstype != "tmpfs" {
+        return storage;
     }
 
-    if let Err(err) = fs::create_dir_all(Path::new(&storage.mount_point)) {
-        return Err(err.into());
-    }
+    let mut options: Vec<String> = storage
+        .options
+        .iter()
+        .filter(|o| !o.starts_with("size="))
+        .cloned()
+        .collect();
+    options.push(format!("size={}", storage.size_limit));
+    storage.set_options(RepeatedField::from_vec(options));
 
-    common_storage_handler(logger, storage)
+    storage
 }
 
 fn local_storage_handler(
-    _logger: &Logger,
+    logger: &Logger,
     storage: &Storage,
     sandbox: Arc<Mutex<Sandbox>>,
 ) -> Result<String> {
-    let s = sandbox.clone();
-    let mut sb = s.lock().unwrap();
-    let new_storage = sb.set_sandbox_storage(&storage.mount_point);
+    mount_once(
+        &sandbox,
+        &storage.mount_point,
+        || set_up_local_storage(logger, storage),
+        |_, _| (),
+    )?;
 
-    if !new_storage {
-        return Ok("".to_string());
-    }
+    Ok("".to_string())
+}
 
+fn set_up_local_storage(logger: &Logger, storage: &Storage) -> Result<()> {
     fs::create_dir_all(&storage.mount_point)?;
 
     let opts_vec: Vec<String> = storage.options.to_vec();
@@ -294,7 +430,21 @@ fn local_storage_handler(
         fs::set_permissions(&storage.mount_point, permission)?;
     }
 
-    Ok("".to_string())
+    // Without a quota, which the filesystem may not support, the usage
+    // is still reported by get_volume_stats for the runtime to enforce.
+    if storage.size_limit > 0 {
+        match quota::set_project_quota(&storage.mount_point, storage.size_limit) {
+            Ok(id) => info!(logger, "project quota set";
+                "mount-point" => storage.mount_point.as_str(),
+                "project-id" => id,
+                "size-limit" => storage.size_limit),
+            Err(e) => warn!(logger, "failed to set project quota";
+                "mount-point" => storage.mount_point.as_str(),
+                "error" => format!("{}", e)),
+        }
+    }
+
+    Ok(())
 }
 
 fn virtio9p_storage_handler(
@@ -341,13 +491,156 @@ fn virtio_blk_storage_handler(
             return Err(ErrorKind::ErrorCode(format!("Invalid device {}", &storage.source)).into());
         }
     } else {
-        let dev_path = get_pci_device_name(sandbox, &storage.source)?;
+        let dev_path = get_pci_device_name(sandbox.clone(), &storage.source)?;
         storage.source = dev_path;
     }
 
+    if let Some(encryption) = parse_encryption_options(&storage)? {
+        return encrypted_storage_handler(logger, &storage, &encryption, sandbox);
+    }
+
     common_storage_handler(logger, &storage)
 }
 
+#[derive(Debug, PartialEq)]
+struct EncryptionOptions {
+    key: Option<String>,
+    key_file: Option<String>,
+    format: bool,
+}
+
+// parse_encryption_options returns the encryption asked for by the
+// driver options of storage, if any.
+fn parse_encryption_options(storage: &Storage) -> Result<Option<EncryptionOptions>> {
+    let mut encryption = None;
+    let mut opts = EncryptionOptions {
+        key: None,
+        key_file: None,
+        format: false,
+    };
+
+    for opt in storage.driver_options.iter() {
+        // keys may well hold '='
+        let mut kv = opt.splitn(2, '=');
+        let (k, v) = (kv.next().unwrap_or(""), kv.next().unwrap_or(""));
+
+        match k {
+            ENCRYPTION_OPTION => encryption = Some(v.to_string()),
+            ENCRYPTION_KEY_OPTION => opts.key = Some(v.to_string()),
+            ENCRYPTION_KEY_FILE_OPTION => opts.key_file = Some(v.to_string()),
+            ENCRYPTION_FORMAT_OPTION => opts.format = v == "true",
+            _ => {}
+        }
+    }
+
+    match encryption.as_ref().map(String::as_str) {
+        None => return Ok(None),
+        Some(ENCRYPTION_LUKS) => {}
+        Some(e) => {
+            return Err(ErrorKind::ErrorCode(format!("unsupported encryption {}", e)).into())
+        }
+    }
+
+    if opts.key.is_some() == opts.key_file.is_some() {
+        return Err(ErrorKind::ErrorCode(format!(
+            "encrypted storage {} needs exactly one of {} and {}",
+            storage.mount_point, ENCRYPTION_KEY_OPTION, ENCRYPTION_KEY_FILE_OPTION
+        ))
+        .into());
+    }
+
+    Ok(Some(opts))
+}
+
+// encrypted_storage_handler mounts the block device of storage through
+// dm-crypt. The mapping is reference counted with the storage, and torn
+// down along with it by unset_and_remove_sandbox_storage.
+fn encrypted_storage_handler(
+    logger: &Logger,
+    storage: &Storage,
+    encryption: &EncryptionOptions,
+    sandbox: Arc<Mutex<Sandbox>>,
+) -> Result<String> {
+    let mount_point = mount_once(
+        &sandbox,
+        &storage.mount_point,
+        || open_encrypted_storage(logger, storage, encryption),
+        |sb, (name, mount_point)| {
+            sb.crypt_devices.insert(mount_point.clone(), name);
+            mount_point
+        },
+    )?;
+
+    Ok(mount_point.unwrap_or_else(|| storage.mount_point.clone()))
+}
+
+fn open_encrypted_storage(
+    logger: &Logger,
+    storage: &Storage,
+    encryption: &EncryptionOptions,
+) -> Result<(String, String)> {
+    let key = match (&encryption.key, &encryption.key_file) {
+        (Some(key), _) => key.as_bytes().to_vec(),
+        (None, Some(file)) => {
+            let path = secure_join(Path::new(SANDBOX_STORAGE_DIR), Path::new(file))?;
+            fs::read(&path).chain_err(|| format!("read key file {}", file))?
+        }
+        (None, None) => vec![],
+    };
+
+    let dev = storage.source.as_str();
+    let name = format!(
+        "{}-crypt",
+        Path::new(dev).file_name().unwrap().to_string_lossy()
+    );
+
+    let mut formatted = false;
+    if !luks::is_luks(dev)? {
+        if !encryption.format || !luks::is_blank(dev)? {
+            return Err(ErrorKind::ErrorCode(format!("{} is not a LUKS volume", dev)).into());
+        }
+
+        info!(logger, "formatting encrypted storage"; "device" => dev);
+        luks::format(dev, &name, &key)?;
+        formatted = true;
+    }
+
+    let read_only = storage.options.iter().any(|o| o == "ro");
+    let mut storage = storage.clone();
+    storage.source = luks::open(dev, &name, &key, read_only)?;
+
+    let res = if formatted {
+        make_filesystem(&storage.fstype, &storage.source)
+    } else {
+        Ok(())
+    }
+    .and_then(|_| common_storage_handler(logger, &storage));
+
+    match res {
+        Ok(mount_point) => Ok((name, mount_point)),
+        Err(e) => {
+            let _ = luks::close(&name);
+            Err(e)
+        }
+    }
+}
+
+fn make_filesystem(fstype: &str, dev: &str) -> Result<()> {
+    let output = Command::new(format!("mkfs.{}", fstype)).arg(dev).output()?;
+
+    if !output.status.success() {
+        return Err(ErrorKind::ErrorCode(format!(
+            "mkfs.{} {} failed: {}",
+            fstype,
+            dev,
+            String::from_utf8_lossy(&output.stderr)
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
 // virtio_scsi_storage_handler handles the storage for scsi driver.
 fn virtio_scsi_storage_handler(
     logger: &Logger,
@@ -363,11 +656,370 @@ fn virtio_scsi_storage_handler(
     common_storage_handler(logger, &storage)
 }
 
+// nvdimm_storage_handler handles the storage for nvdimm driver, a pmem
+// device usually backing a rootfs image.
+fn nvdimm_storage_handler(
+    logger: &Logger,
+    storage: &Storage,
+    sandbox: Arc<Mutex<Sandbox>>,
+) -> Result<String> {
+    let mut storage = storage.clone();
+
+    // Wait for the pmem device node, e.g. /dev/pmem0.
+    storage.source = get_pmem_device_name(sandbox, &storage.source)?;
+
+    let dax = match with_dax(&storage) {
+        Some(dax) if pmem_supports_dax(&storage.source) => dax,
+        _ => return common_storage_handler(logger, &storage),
+    };
+
+    // The page size, the filesystem or the namespace mode may still rule
+    // DAX out, in which case the device is used through the page cache.
+    match common_storage_handler(logger, &dax) {
+        Ok(m) => Ok(m),
+        Err(e) => {
+            warn!(logger, "dax mount failed, mounting without dax";
+                "source" => storage.source.as_str(),
+                "error" => format!("{}", e));
+            common_storage_handler(logger, &storage)
+        }
+    }
+}
+
+// with_dax returns storage with the dax option added, if its filesystem
+// supports it and DAX was not asked for or against already.
+fn with_dax(storage: &Storage) -> Option<Storage> {
+    if !DAX_FSTYPES.contains(&storage.fstype.as_str())
+        || storage.options.iter().any(|o| o.starts_with(DAX_OPTION))
+    {
+        return None;
+    }
+
+    let mut storage = storage.clone();
+    storage.options.push(DAX_OPTION.to_string());
+    Some(storage)
+}
+
+// overlayfs_storage_handler composes an overlay out of storages added
+// before. The lowerdir option lists their mount points, topmost first,
+// and upperdir and workdir, if any, sit on a writable storage such as an
+// ephemeral or a block one. The layers are reference counted in the
+// sandbox, so that they can be shared by the overlays of many containers.
+fn overlayfs_storage_handler(
+    logger: &Logger,
+    storage: &Storage,
+    sandbox: Arc<Mutex<Sandbox>>,
+) -> Result<String> {
+    let (layers, dirs) = parse_overlay_options(storage)?;
+
+    for d in dirs.iter() {
+        fs::create_dir_all(d)?;
+    }
+
+    // the layers are held before the overlay mounts, for them to stay
+    let storages = sandbox.lock().unwrap().storages.clone();
+    {
+        let mut sb = storages.lock().unwrap();
+        if let Some(l) = layers.iter().find(|l| !sb.storages.contains_key(*l)) {
+            return Err(
+                ErrorKind::ErrorCode(format!("overlay layer {} is not a storage", l)).into(),
+            );
+        }
+        for l in layers.iter() {
+            sb.set_sandbox_storage(l);
+        }
+    }
+
+    let mount_point = match common_storage_handler(logger, storage) {
+        Ok(mount_point) => mount_point,
+        Err(e) => {
+            let mut sb = storages.lock().unwrap();
+            for l in layers.iter() {
+                sb.unset_sandbox_storage(l);
+            }
+            return Err(e);
+        }
+    };
+
+    let mut sb = storages.lock().unwrap();
+    sb.overlay_layers.insert(mount_point.clone(), layers);
+
+    Ok(mount_point)
+}
+
+// parse_overlay_options returns the lower directories of the overlay
+// described by storage, and its upper and work directories if it is
+// writable.
+fn parse_overlay_options(storage: &Storage) -> Result<(Vec<String>, Vec<String>)> {
+    let opts = parse_options(storage.options.to_vec());
+
+    let layers: Vec<String> = match opts.get("lowerdir") {
+        Some(lower) => lower
+            .split(':')
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect(),
+        None => vec![],
+    };
+    if layers.is_empty() {
+        return Err(ErrorKind::ErrorCode(format!(
+            "overlay {} has no lowerdir",
+            storage.mount_point
+        ))
+        .into());
+    }
+
+    let dirs = match (opts.get("upperdir"), opts.get("workdir")) {
+        (Some(upper), Some(work)) => vec![upper.to_string(), work.to_string()],
+        (None, None) => vec![],
+        _ => {
+            return Err(ErrorKind::ErrorCode(format!(
+                "overlay {} needs both upperdir and workdir",
+                storage.mount_point
+            ))
+            .into())
+        }
+    };
+
+    Ok((layers, dirs))
+}
+
+// watchable_storage_handler publishes at the mount point the files the
+// host copied to the staging directory storage.source, and the updates
+// it copies afterwards, see do_copy_file.
+fn watchable_storage_handler(
+    logger: &Logger,
+    storage: &Storage,
+    sandbox: Arc<Mutex<Sandbox>>,
+) -> Result<String> {
+    mount_once(
+        &sandbox,
+        &storage.mount_point,
+        || {
+            let w = new_watchable_storage(storage)?;
+            w.sync()?;
+            info!(logger, "watchable storage published";
+                "source" => format!("{}", w.source.display()),
+                "mount-point" => storage.mount_point.as_str());
+            Ok(w)
+        },
+        |sb, w| {
+            sb.watchable_storages.insert(storage.mount_point.clone(), w);
+        },
+    )?;
+
+    Ok(storage.mount_point.clone())
+}
+
+// new_watchable_storage creates the staging directory and the mount point
+// of storage, which must be apart and both below CONTAINER_BASE, where
+// CopyFile can write.
+fn new_watchable_storage(storage: &Storage) -> Result<WatchableStorage> {
+    let mut dirs = Vec::new();
+    for d in [&storage.source, &storage.mount_point].iter() {
+        let not_below = || {
+            ErrorKind::ErrorCode(format!(
+                "watchable storage {} is not below {}",
+                d, CONTAINER_BASE
+            ))
+        };
+
+        if !Path::new(d).starts_with(CONTAINER_BASE) {
+            return Err(not_below().into());
+        }
+        fs::create_dir_all(d)?;
+
+        let dir = fs::canonicalize(d)?;
+        if !dir.starts_with(CONTAINER_BASE) || dir == Path::new(CONTAINER_BASE) {
+            return Err(not_below().into());
+        }
+        dirs.push(dir);
+    }
+
+    if dirs[0].starts_with(&dirs[1]) || dirs[1].starts_with(&dirs[0]) {
+        return Err(ErrorKind::ErrorCode(format!(
+            "watchable storage {} overlaps its staging directory {}",
+            storage.mount_point, storage.source
+        ))
+        .into());
+    }
+
+    Ok(WatchableStorage::new(&dirs[0], &dirs[1]))
+}
+
+// image_storage_handler unpacks the image named by the image_ref driver
+// option into a tmpfs mounted at the mount point. The OCI image layout
+// holding it is either a directory shared with the guest or, when the
+// source is a device or a PCI address, the root of the storage.fstype
+// filesystem of a block device.
+fn image_storage_handler(
+    logger: &Logger,
+    storage: &Storage,
+    sandbox: Arc<Mutex<Sandbox>>,
+) -> Result<String> {
+    let mut storage = storage.clone();
+    let block = !Path::new(&storage.source).is_absolute() || storage.source.starts_with("/dev");
+    if storage.source.starts_with("/dev") {
+        let metadata = fs::metadata(&storage.source)?;
+
+        let mode = metadata.permissions().mode();
+        if mode & libc::S_IFMT != libc::S_IFBLK {
+            return Err(ErrorKind::ErrorCode(format!("Invalid device {}", &storage.source)).into());
+        }
+    } else if block {
+        storage.source = get_pci_device_name(sandbox.clone(), &storage.source)?;
+    }
+
+    let mut reference = String::new();
+    for opt in storage.driver_options.iter() {
+        let mut kv = opt.splitn(2, '=');
+        if kv.next() == Some(IMAGE_REF_OPTION) {
+            reference = kv.next().unwrap_or("").to_string();
+        }
+    }
+
+    mount_once(
+        &sandbox,
+        &storage.mount_point,
+        || {
+            unpack_image_storage(logger, &storage, block, &reference)?;
+            info!(logger, "image unpacked";
+                "image" => reference.as_str(),
+                "source" => storage.source.as_str(),
+                "mount-point" => storage.mount_point.as_str());
+            Ok(())
+        },
+        |_, _| (),
+    )?;
+
+    Ok(storage.mount_point.clone())
+}
+
+fn unpack_image_storage(
+    logger: &Logger,
+    storage: &Storage,
+    block: bool,
+    reference: &str,
+) -> Result<()> {
+    // the rootfs is made read-only, if asked, once unpacked
+    let read_only = storage.options.iter().any(|o| o == "ro");
+    let mut rootfs = storage.clone();
+    rootfs.source = "tmpfs".to_string();
+    rootfs.fstype = "tmpfs".to_string();
+    rootfs.set_options(
+        storage
+            .options
+            .iter()
+            .filter(|o| *o != "ro")
+            .cloned()
+            .collect(),
+    );
+    let rootfs = with_size_limit(&rootfs);
+
+    fs::create_dir_all(&storage.mount_point)?;
+    mount_storage(logger, &rootfs)?;
+
+    let mount_point = Path::new(&storage.mount_point);
+    let res = if block {
+        unpack_image_device(logger, storage, reference)
+    } else {
+        image::unpack_image(Path::new(&storage.source), reference, mount_point)
+    }
+    .and_then(|_| {
+        if read_only {
+            let flags = MsFlags::MS_REMOUNT | MsFlags::MS_RDONLY;
+            mount::mount::<str, Path, str, str>(None, mount_point, None, flags, None)?;
+        }
+        Ok(())
+    });
+
+    if res.is_err() {
+        let _ = mount::umount(mount_point);
+    }
+
+    res.chain_err(|| format!("unpack image {:?} from {}", reference, storage.source))
+}
+
+// unpack_image_device unpacks the image from the layout on the block
+// device of storage, mounted read-only meanwhile.
+fn unpack_image_device(logger: &Logger, storage: &Storage, reference: &str) -> Result<()> {
+    let name = Path::new(&storage.source).file_name().unwrap();
+    let layout = Path::new(IMAGE_LAYOUT_DIR).join(name);
+    fs::create_dir_all(&layout)?;
+
+    let layout_dir = layout.to_string_lossy();
+    let bare_mount = BareMount::new(
+        storage.source.as_str(),
+        &layout_dir,
+        storage.fstype.as_str(),
+        MsFlags::MS_RDONLY | MsFlags::MS_NODEV | MsFlags::MS_NOSUID | MsFlags::MS_NOEXEC,
+        "",
+        logger,
+    );
+    bare_mount.mount()?;
+
+    let res = image::unpack_image(&layout, reference, Path::new(&storage.mount_point));
+
+    mount::umount(&layout)?;
+    fs::remove_dir(&layout)?;
+
+    res
+}
+
 fn common_storage_handler(logger: &Logger, storage: &Storage) -> Result<String> {
+    let has_option = |o| storage.driver_options.iter().any(|d| d == o);
+
+    if has_option(FSCK_OPTION) {
+        check_filesystem(logger, &storage.fstype, &storage.source)?;
+    }
+
     // Mount the storage device.
     let mount_point = storage.mount_point.to_string();
+    mount_storage(logger, storage)?;
+
+    // The device may have been expanded while no one was using it.
+    if has_option(RESIZE_OPTION) {
+        if let Err(e) = resize::resize_filesystem(&mount_point, 0) {
+            warn!(logger, "failed to resize filesystem";
+                "mount-point" => mount_point.as_str(),
+                "error" => format!("{}", e));
+        }
+    }
 
-    mount_storage(logger, storage).and(Ok(mount_point))
+    Ok(mount_point)
+}
+
+// check_filesystem checks the filesystem on dev, and repairs what can be
+// without asking.
+fn check_filesystem(logger: &Logger, fstype: &str, dev: &str) -> Result<()> {
+    match fstype {
+        "ext2" | "ext3" | "ext4" => {}
+        // xfs checks itself, replaying its log, when mounted
+        "xfs" => return Ok(()),
+        _ => {
+            return Err(ErrorKind::ErrorCode(format!("no filesystem check for {}", fstype)).into())
+        }
+    }
+
+    let output = Command::new("e2fsck").arg("-p").arg(dev).output()?;
+
+    // 1 and 2 tell about errors corrected
+    match output.status.code() {
+        Some(0) => Ok(()),
+        Some(1) | Some(2) => {
+            warn!(logger, "filesystem errors corrected";
+                "device" => dev,
+                "output" => String::from_utf8_lossy(&output.stdout).into_owned());
+            Ok(())
+        }
+        _ => Err(ErrorKind::ErrorCode(format!(
+            "e2fsck {} failed: {}{}",
+            dev,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ))
+        .into()),
+    }
 }
 
 // mount_storage performs the mount described by the storage structure.
@@ -461,12 +1113,18 @@ pub fn add_storages(
             Some(f) => f,
         };
 
+        let mut span = tracer::span("mount_storage");
+        span.set_attribute("driver", &storage.driver);
+        span.set_attribute("mount_point", &storage.mount_point);
+
         let mount_point = match handler(&logger, &storage, sandbox.clone()) {
             // Todo need to rollback the mounted storage if err met.
             Err(e) => return Err(e),
             Ok(m) => m,
         };
 
+        set_ownership(&logger, &storage)?;
+
         if mount_point.len() > 0 {
             mount_list.push(mount_point);
         }
@@ -475,6 +1133,96 @@ pub fn add_storages(
     Ok(mount_list)
 }
 
+// set_ownership gives the files of storage to its fsGroup, if any, for
+// the group to read them, and to write them unless the storage is read
+// only. Directories are made setgid, for new files to get the group too.
+fn set_ownership(logger: &Logger, storage: &Storage) -> Result<()> {
+    if !storage.has_fs_group() {
+        return Ok(());
+    }
+
+    let fs_group = storage.get_fs_group();
+    let gid = fs_group.group_id;
+    let mask = if storage.options.iter().any(|o| o == "ro") {
+        FSGROUP_RO_MASK
+    } else {
+        FSGROUP_RW_MASK
+    };
+    let root = Path::new(&storage.mount_point);
+
+    if fs_group.group_change_policy == FSGroupChangePolicy::OnRootMismatch
+        && ownership_matches(root, gid, mask)?
+    {
+        info!(logger, "storage ownership already set";
+            "mount-point" => storage.mount_point.as_str(), "gid" => gid);
+        return Ok(());
+    }
+
+    if statvfs::statvfs(root)?
+        .flags()
+        .contains(statvfs::FsFlags::ST_RDONLY)
+    {
+        warn!(logger, "cannot set ownership of read-only filesystem";
+            "mount-point" => storage.mount_point.as_str(), "gid" => gid);
+        return Ok(());
+    }
+
+    info!(logger, "setting storage ownership";
+        "mount-point" => storage.mount_point.as_str(), "gid" => gid);
+
+    let dev = fs::symlink_metadata(root)?.dev();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(p) = pending.pop() {
+        let meta = fs::symlink_metadata(&p)?;
+        if meta.dev() != dev {
+            continue;
+        }
+
+        if meta.gid() != gid {
+            unistd::fchownat(
+                None,
+                &p,
+                None,
+                Some(Gid::from_raw(gid)),
+                FchownatFlags::NoFollowSymlink,
+            )?;
+        }
+
+        // the permissions of a symlink are those of its target
+        if meta.file_type().is_symlink() {
+            continue;
+        }
+
+        let mut mode = meta.mode() & 0o7777 | mask;
+        if meta.is_dir() {
+            mode |= libc::S_ISGID | FSGROUP_EXEC_MASK;
+        }
+        if mode != meta.mode() & 0o7777 {
+            fs::set_permissions(&p, fs::Permissions::from_mode(mode))?;
+        }
+
+        if meta.is_dir() {
+            for entry in fs::read_dir(&p)? {
+                pending.push(entry?.path());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn ownership_matches(path: &Path, gid: u32, mask: u32) -> Result<bool> {
+    let meta = fs::metadata(path)?;
+
+    let mut mask = mask;
+    if meta.is_dir() {
+        mask |= libc::S_ISGID | FSGROUP_EXEC_MASK;
+    }
+
+    Ok(meta.gid() == gid && meta.mode() & mask == mask)
+}
+
 fn mount_to_rootfs(logger: &Logger, m: &INIT_MOUNT) -> Result<()> {
     let options_vec: Vec<&str> = m.options.clone();
 
@@ -635,6 +1383,80 @@ pub fn cgroups_mount(logger: &Logger) -> Result<()> {
     Ok(())
 }
 
+// get_volume_stats returns the usage of the storage at path. A storage
+// that is a mere directory shares the statistics of its filesystem but
+// for the usage, which is then its own: that its project quota accounts,
+// or that of its tree when it has none.
+pub fn get_volume_stats(path: &str) -> Result<VolumeStats> {
+    let mut st: libc::statfs = unsafe { std::mem::zeroed() };
+    statfs::statfs(path, &mut st)?;
+
+    let bsize = st.f_bsize as u64;
+    let mut stats = VolumeStats::new();
+    stats.set_volume_guest_path(path.to_string());
+    stats.set_capacity_bytes(st.f_blocks as u64 * bsize);
+    stats.set_available_bytes(st.f_bavail as u64 * bsize);
+    stats.set_used_bytes((st.f_blocks - st.f_bfree) as u64 * bsize);
+    stats.set_inodes(st.f_files as u64);
+    stats.set_inodes_free(st.f_ffree as u64);
+    stats.set_inodes_used((st.f_files - st.f_ffree) as u64);
+
+    if !is_mount_point(path)? {
+        let (used, inodes) = match quota::project_usage(path) {
+            Ok(Some(usage)) => usage,
+            // no quota, or not one accounted
+            _ => dir_usage(Path::new(path))?,
+        };
+        stats.set_used_bytes(used);
+        stats.set_inodes_used(inodes);
+    }
+
+    Ok(stats)
+}
+
+fn is_mount_point(path: &str) -> Result<bool> {
+    let p = Path::new(path);
+    let parent = match p.parent() {
+        Some(parent) => parent,
+        None => return Ok(true),
+    };
+
+    Ok(fs::metadata(p)?.dev() != fs::metadata(parent)?.dev())
+}
+
+// dir_usage returns the space and inodes used by the tree at dir, hard
+// links counted once, without crossing filesystems.
+fn dir_usage(dir: &Path) -> Result<(u64, u64)> {
+    let dev = fs::symlink_metadata(dir)?.dev();
+    let mut seen = HashSet::new();
+    let mut pending = vec![dir.to_path_buf()];
+    let (mut used, mut inodes) = (0, 0);
+
+    while let Some(p) = pending.pop() {
+        let meta = match fs::symlink_metadata(&p) {
+            Ok(meta) => meta,
+            // gone since listed
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        if meta.dev() != dev || !seen.insert(meta.ino()) {
+            continue;
+        }
+
+        used += meta.blocks() * 512;
+        inodes += 1;
+
+        if meta.is_dir() {
+            for entry in fs::read_dir(&p)? {
+                pending.push(entry?.path());
+            }
+        }
+    }
+
+    Ok((used, inodes))
+}
+
 pub fn remove_mounts(mounts: &Vec<String>) -> Result<()> {
     for m in mounts.iter() {
         mount::umount(m.as_str())?;
@@ -694,6 +1516,9 @@ mod tests {
     use std::fs::OpenOptions;
     use std::io::Write;
     use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
     use tempfile::tempdir;
 
     #[derive(Debug, PartialEq)]
@@ -1241,4 +2066,323 @@ mod tests {
             assert!(mounts[1].eq(&cg_devices_mount), msg);
         }
     }
+
+    #[test]
+    fn test_with_dax() {
+        let storage = |fstype: &str, options: &[&str]| {
+            let mut s = Storage::new();
+            s.fstype = fstype.to_string();
+            for o in options {
+                s.options.push(o.to_string());
+            }
+            s
+        };
+
+        let dax = with_dax(&storage("ext4", &["ro"])).unwrap();
+        assert_eq!(dax.options.to_vec(), vec!["ro", "dax"]);
+        assert!(with_dax(&storage("xfs", &[])).is_some());
+
+        assert!(with_dax(&storage("ext4", &["dax=never"])).is_none());
+        assert!(with_dax(&storage("xfs", &["dax"])).is_none());
+        assert!(with_dax(&storage("btrfs", &[])).is_none());
+        assert!(with_dax(&storage("9p", &[])).is_none());
+    }
+
+    #[test]
+    fn test_parse_overlay_options() {
+        let storage = |options: &[&str]| {
+            let mut s = Storage::new();
+            s.mount_point = "/run/kata-containers/c1/rootfs".to_string();
+            for o in options {
+                s.options.push(o.to_string());
+            }
+            s
+        };
+
+        let (layers, dirs) = parse_overlay_options(&storage(&[
+            "lowerdir=/run/layers/b:/run/layers/a",
+            "upperdir=/run/eph/c1/upper",
+            "workdir=/run/eph/c1/work",
+            "index=off",
+        ]))
+        .unwrap();
+        assert_eq!(layers, vec!["/run/layers/b", "/run/layers/a"]);
+        assert_eq!(dirs, vec!["/run/eph/c1/upper", "/run/eph/c1/work"]);
+
+        let (layers, dirs) = parse_overlay_options(&storage(&["lowerdir=/run/layers/a"])).unwrap();
+        assert_eq!(layers, vec!["/run/layers/a"]);
+        assert!(dirs.is_empty());
+
+        assert!(parse_overlay_options(&storage(&["upperdir=/u", "workdir=/w"])).is_err());
+        assert!(parse_overlay_options(&storage(&["lowerdir="])).is_err());
+        assert!(parse_overlay_options(&storage(&["lowerdir=/l", "upperdir=/u"])).is_err());
+    }
+
+    #[test]
+    fn test_overlayfs_storage_unknown_layer() {
+        let logger = slog_scope::logger();
+        let sandbox = Arc::new(Mutex::new(Sandbox::new(&logger).unwrap()));
+        let storages = sandbox.lock().unwrap().storages.clone();
+        storages
+            .lock()
+            .unwrap()
+            .set_sandbox_storage("/run/layers/a");
+
+        let mut storage = Storage::new();
+        storage.driver = DRIVEROVERLAYFSTYPE.to_string();
+        storage.mount_point = "/run/kata-containers/c1/rootfs".to_string();
+        storage
+            .options
+            .push("lowerdir=/run/layers/b:/run/layers/a".to_string());
+
+        // a lowerdir which is not a storage is rejected, and none is held
+        assert!(overlayfs_storage_handler(&logger, &storage, sandbox.clone()).is_err());
+        let sb = storages.lock().unwrap();
+        assert_eq!(sb.storages.get("/run/layers/a"), Some(&1));
+        assert!(sb.storages.get("/run/layers/b").is_none());
+    }
+
+    #[test]
+    fn test_parse_encryption_options() {
+        let storage = |options: &[&str]| {
+            let mut s = Storage::new();
+            s.mount_point = "/run/kata-containers/sandbox/storage/vol".to_string();
+            for o in options {
+                s.driver_options.push(o.to_string());
+            }
+            s
+        };
+
+        assert_eq!(parse_encryption_options(&storage(&[])).unwrap(), None);
+        assert_eq!(
+            parse_encryption_options(&storage(&["encryption=luks", "encryption_key=c2VjcmV0=="]))
+                .unwrap(),
+            Some(EncryptionOptions {
+                key: Some("c2VjcmV0==".to_string()),
+                key_file: None,
+                format: false,
+            })
+        );
+        assert_eq!(
+            parse_encryption_options(&storage(&[
+                "encryption=luks",
+                "encryption_key_file=keys/vol",
+                "encryption_format=true",
+            ]))
+            .unwrap(),
+            Some(EncryptionOptions {
+                key: None,
+                key_file: Some("keys/vol".to_string()),
+                format: true,
+            })
+        );
+
+        let invalid = [
+            vec!["encryption=plain", "encryption_key=k"],
+            vec!["encryption=luks"],
+            vec![
+                "encryption=luks",
+                "encryption_key=k",
+                "encryption_key_file=f",
+            ],
+        ];
+        for options in invalid.iter() {
+            assert!(parse_encryption_options(&storage(options)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_with_size_limit() {
+        let mut storage = Storage::new();
+        storage.fstype = "tmpfs".to_string();
+        storage.options.push("size=64m".to_string());
+        storage.options.push("nodev".to_string());
+
+        assert_eq!(
+            with_size_limit(&storage).options.to_vec(),
+            vec!["size=64m", "nodev"]
+        );
+
+        storage.size_limit = 1 << 20;
+        assert_eq!(
+            with_size_limit(&storage).options.to_vec(),
+            vec!["nodev", "size=1048576"]
+        );
+
+        storage.fstype = "ext4".to_string();
+        assert_eq!(
+            with_size_limit(&storage).options.to_vec(),
+            vec!["size=64m", "nodev"]
+        );
+    }
+
+    #[test]
+    fn test_get_volume_stats() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("vol");
+        fs::create_dir_all(path.join("sub")).unwrap();
+        fs::write(path.join("sub/file"), vec![1u8; 64 * 1024]).unwrap();
+        fs::hard_link(path.join("sub/file"), path.join("link")).unwrap();
+
+        let stats = get_volume_stats(path.to_str().unwrap()).unwrap();
+        assert_eq!(stats.volume_guest_path, path.to_str().unwrap());
+        assert!(stats.capacity_bytes > 0);
+
+        // the directory, not its filesystem: vol, sub and file
+        if quota::project_id(path.to_str().unwrap()).unwrap_or(0) == 0 {
+            assert_eq!(stats.inodes_used, 3);
+            assert!(stats.used_bytes >= 64 * 1024);
+            assert!(stats.used_bytes < 1024 * 1024);
+        }
+    }
+
+    #[test]
+    fn test_set_ownership() {
+        skip_if_not_root!();
+
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("vol");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("sub/file"), "").unwrap();
+        std::os::unix::fs::symlink("/etc/passwd", root.join("link")).unwrap();
+        for p in ["", "sub", "sub/file"].iter() {
+            fs::set_permissions(root.join(p), fs::Permissions::from_mode(0o700)).unwrap();
+        }
+
+        let mode = |p: &str| fs::metadata(root.join(p)).unwrap().mode() & 0o7777;
+        let gid = |p: &str| fs::symlink_metadata(root.join(p)).unwrap().gid();
+
+        let mut storage = Storage::new();
+        storage.mount_point = root.to_str().unwrap().to_string();
+        set_ownership(&logger, &storage).unwrap();
+        assert_eq!(mode("sub/file"), 0o700);
+
+        storage.mut_fs_group().group_id = 1234;
+        set_ownership(&logger, &storage).unwrap();
+        for p in ["", "sub", "sub/file", "link"].iter() {
+            assert_eq!(gid(p), 1234, "{}", p);
+        }
+        assert_eq!(mode(""), 0o2770);
+        assert_eq!(mode("sub"), 0o2770);
+        assert_eq!(mode("sub/file"), 0o760);
+        assert_eq!(fs::metadata("/etc/passwd").unwrap().mode() & 0o777, 0o644);
+
+        // the root matches, the rest is left alone
+        storage.mut_fs_group().group_change_policy = FSGroupChangePolicy::OnRootMismatch;
+        unistd::chown(&root.join("sub/file"), None, Some(Gid::from_raw(0))).unwrap();
+        set_ownership(&logger, &storage).unwrap();
+        assert_eq!(gid("sub/file"), 0);
+
+        // read-only storages only get read permissions
+        storage.mut_fs_group().group_id = 4321;
+        storage.options.push("ro".to_string());
+        fs::set_permissions(root.join("sub/file"), fs::Permissions::from_mode(0o600)).unwrap();
+        set_ownership(&logger, &storage).unwrap();
+        assert_eq!(gid("sub/file"), 4321);
+        assert_eq!(mode("sub/file"), 0o640);
+    }
+
+    #[test]
+    fn test_check_filesystem() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let dir = tempdir().expect("failed to create tmpdir");
+        let dev = dir.path().join("missing");
+        let dev = dev.to_str().unwrap();
+
+        assert!(check_filesystem(&logger, "xfs", dev).is_ok());
+        assert!(check_filesystem(&logger, "vfat", dev).is_err());
+        assert!(check_filesystem(&logger, "ext4", dev).is_err());
+    }
+
+    #[test]
+    fn test_mount_propagation() {
+        skip_if_not_root!();
+
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let dir = tempdir().expect("failed to create tmpdir");
+        let dest = dir.path().to_str().unwrap();
+
+        let (flags, options) = parse_mount_flags_and_options(vec!["nodev", "shared"]);
+        let mount = BareMount::new("tmpfs", dest, "tmpfs", flags, &options, &logger);
+        mount.mount().unwrap();
+
+        let mountinfo = fs::read_to_string("/proc/self/mountinfo").unwrap();
+        let _ = mount::umount(dest);
+
+        let line = mountinfo
+            .lines()
+            .find(|l| l.split(' ').nth(4) == Some(dest))
+            .expect("no mount");
+        assert!(line.contains(" shared:"), "{}", line);
+    }
+
+    #[test]
+    fn test_mount_once() {
+        let sandbox = Arc::new(Mutex::new(Sandbox::new(&slog_scope::logger()).unwrap()));
+
+        // a storage taking long to mount
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let s = sandbox.clone();
+        let slow = thread::spawn(move || {
+            mount_once(
+                &s,
+                "/slow",
+                || {
+                    started_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                    Ok(())
+                },
+                |_, _| (),
+            )
+            .unwrap()
+        });
+        started_rx.recv().unwrap();
+
+        // does not hold the storages of the other mount points
+        assert_eq!(
+            mount_once(&sandbox, "/fast", || Ok(1), |_, v| v).unwrap(),
+            Some(1)
+        );
+
+        // a mount failing lets the next one mount
+        let res: Result<Option<()>> = mount_once(
+            &sandbox,
+            "/failing",
+            || -> Result<()> { Err(ErrorKind::ErrorCode("failed".to_string()).into()) },
+            |_, _| (),
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            mount_once(&sandbox, "/failing", || Ok(()), |_, _| ()).unwrap(),
+            Some(())
+        );
+
+        // while those of its mount point wait for it, to only take a
+        // reference on it
+        let (done_tx, done_rx) = mpsc::channel();
+        let s = sandbox.clone();
+        let waiter = thread::spawn(move || {
+            let res = mount_once(
+                &s,
+                "/slow",
+                || -> Result<()> { panic!("mounted twice") },
+                |_, _| (),
+            );
+            done_tx.send(()).unwrap();
+            res.unwrap()
+        });
+        assert!(done_rx.recv_timeout(Duration::from_millis(200)).is_err());
+
+        release_tx.send(()).unwrap();
+        assert_eq!(slow.join().unwrap(), Some(()));
+        assert_eq!(waiter.join().unwrap(), None);
+
+        let storages = sandbox.lock().unwrap().storages.clone();
+        let sb = storages.lock().unwrap();
+        assert_eq!(sb.storages.get("/slow"), Some(&2));
+        assert_eq!(sb.storages.get("/fast"), Some(&1));
+        assert_eq!(sb.storages.get("/failing"), Some(&1));
+    }
 }
@@ -7,16 +7,21 @@
 #![allow(non_upper_case_globals)]
 #![allow(dead_code)]
 
+use crate::nlmsg::{
+    nl_align, parse_messages, read_struct, NlAttrs, NlError, NlMsg, NlMsgBuilder, NlStruct,
+};
 use libc;
 use nix::errno::Errno;
-use protobuf::RepeatedField;
-use protocols::types::{IPAddress, IPFamily, Interface, Route};
+use protocols::agent::{NetworkEvent, NetworkEvent_Kind};
+use protocols::types::{IPAddress, IPFamily, Interface, InterfaceStatistics, Nexthop, Route};
 use rustjail::errors::*;
 use std::clone::Clone;
 use std::default::Default;
 use std::fmt;
+use std::fs;
 use std::mem;
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
 use std::str::FromStr;
 
 // Convenience macro to obtain the scope logger
@@ -26,6 +31,9 @@ macro_rules! sl {
     };
 }
 
+// where the network interfaces show up in sysfs
+const SYSFS_NET_PATH: &str = "/sys/class/net";
+
 // define the struct, const, etc needed by
 // netlink operations
 
@@ -318,7 +326,9 @@ macro_rules! RTNH_ALIGN {
 #[macro_export]
 macro_rules! RTNH_OK {
     ($rtnh: expr, $len: expr) => {
-        $rtnh.rtnh_len >= mem::size_of::<rtnexthop>() && $rtnh.rtnh_len <= $len
+        ($len as u32 >= mem::size_of::<rtnexthop>() as u32)
+            && ((*$rtnh).rtnh_len as u32 >= mem::size_of::<rtnexthop>() as u32)
+            && ((*$rtnh).rtnh_len as u32 <= $len as u32)
     };
 }
 
@@ -327,7 +337,7 @@ macro_rules! RTNH_NEXT {
     ($rtnh: expr) => {
         unsafe {
             let mut p = $rtnh as *mut rtnexthop as i64;
-            p += RTNH_ALIGN!($rtnh.rtnh_len);
+            p += RTNH_ALIGN!((*$rtnh).rtnh_len) as i64;
             p as *mut rtnexthop
         }
     };
@@ -352,7 +362,7 @@ macro_rules! RTNH_DATA {
     ($rtnh: expr) => {
         unsafe {
             let mut p = $rtnh as *mut rtnexthop as i64;
-            p += RTNH_LENGTH!(0);
+            p += RTNH_LENGTH!(0) as i64;
             p as *mut rtattr
         }
     };
@@ -1051,6 +1061,22 @@ pub const IFLA_MAX_MTU: __u16 = 51;
 pub const __IFLA_MAX: __u16 = 52;
 pub const IFLA_MAX: __u16 = __IFLA_MAX - 1;
 
+pub const IFLA_INFO_UNSPEC: __u16 = 0;
+pub const IFLA_INFO_KIND: __u16 = 1;
+pub const IFLA_INFO_DATA: __u16 = 2;
+pub const IFLA_INFO_XSTATS: __u16 = 3;
+pub const IFLA_INFO_SLAVE_KIND: __u16 = 4;
+pub const IFLA_INFO_SLAVE_DATA: __u16 = 5;
+
+// RFC 2863 operational states, as in IFLA_OPERSTATE
+pub const IF_OPER_UNKNOWN: __u8 = 0;
+pub const IF_OPER_NOTPRESENT: __u8 = 1;
+pub const IF_OPER_DOWN: __u8 = 2;
+pub const IF_OPER_LOWERLAYERDOWN: __u8 = 3;
+pub const IF_OPER_TESTING: __u8 = 4;
+pub const IF_OPER_DORMANT: __u8 = 5;
+pub const IF_OPER_UP: __u8 = 6;
+
 pub const IFA_UNSPEC: __u16 = 0;
 pub const IFA_ADDRESS: __u16 = 1;
 pub const IFA_LOCAL: __u16 = 2;
@@ -1098,6 +1124,14 @@ impl Default for nlmsgerr {
     }
 }
 
+// the headers built and parsed with NlMsgBuilder and NlMsg, none of
+// them has padding.
+unsafe impl NlStruct for ifinfomsg {}
+unsafe impl NlStruct for ifaddrmsg {}
+unsafe impl NlStruct for rtmsg {}
+unsafe impl NlStruct for rtnexthop {}
+unsafe impl NlStruct for rtnl_link_stats64 {}
+
 // #[derive(Copy)]
 pub struct RtnlHandle {
     pub fd: libc::c_int,
@@ -1134,6 +1168,19 @@ pub const NETLINK_ROUTE: libc::c_int = 0;
 pub const NETLINK_EXT_ACK: libc::c_int = 11;
 pub const NETLINK_UEVENT: libc::c_int = 15;
 
+// NETLINK_ROUTE multicast groups, to be passed to RtnlHandle::new()
+pub const RTMGRP_LINK: u32 = 0x1;
+pub const RTMGRP_NOTIFY: u32 = 0x2;
+pub const RTMGRP_NEIGH: u32 = 0x4;
+pub const RTMGRP_TC: u32 = 0x8;
+pub const RTMGRP_IPV4_IFADDR: u32 = 0x10;
+pub const RTMGRP_IPV4_MROUTE: u32 = 0x20;
+pub const RTMGRP_IPV4_ROUTE: u32 = 0x40;
+pub const RTMGRP_IPV4_RULE: u32 = 0x80;
+pub const RTMGRP_IPV6_IFADDR: u32 = 0x100;
+pub const RTMGRP_IPV6_MROUTE: u32 = 0x200;
+pub const RTMGRP_IPV6_ROUTE: u32 = 0x400;
+
 impl RtnlHandle {
     pub fn new(protocal: libc::c_int, group: u32) -> Result<Self> {
         // open netlink_route socket
@@ -1231,16 +1278,15 @@ impl RtnlHandle {
     }
 
     // implement update{interface,routes}, list{interface, routes}
-    fn send_message(&self, data: &mut [u8]) -> Result<()> {
+    fn send_message(&self, data: &[u8]) -> Result<()> {
         let mut sa: libc::sockaddr_nl = unsafe { mem::zeroed::<libc::sockaddr_nl>() };
 
         sa.nl_family = libc::AF_NETLINK as u16;
 
         unsafe {
-            let nh = data.as_mut_ptr() as *mut nlmsghdr;
             let mut iov: libc::iovec = libc::iovec {
-                iov_base: nh as *mut libc::c_void,
-                iov_len: (*nh).nlmsg_len as libc::size_t,
+                iov_base: data.as_ptr() as *mut libc::c_void,
+                iov_len: data.len() as libc::size_t,
             };
 
             let mut h = mem::zeroed::<libc::msghdr>();
@@ -1313,292 +1359,140 @@ impl RtnlHandle {
         }
     }
 
-    unsafe fn recv_dump_message(&self) -> Result<(Vec<Vec<u8>>, Vec<*const nlmsghdr>)> {
-        let mut slv: Vec<Vec<u8>> = Vec::new();
-        let mut lv: Vec<*const nlmsghdr> = Vec::new();
+    // dump sends a NLM_F_DUMP request and collects the replies until
+    // NLMSG_DONE, they may span several reads.
+    pub fn dump(&mut self, mut req: NlMsgBuilder) -> Result<Vec<NlMsg>> {
+        self.seq += 1;
+        self.dump = self.seq;
+        req.set_seq(self.seq);
+
+        self.send_message(req.finish()?.as_slice())?;
+
+        let mut msgs: Vec<NlMsg> = Vec::new();
+        let mut dump_intr = false;
 
         loop {
             let buf = self.recv_message()?;
 
-            let mut msglen = buf.len() as u32;
-            let mut nlh = buf.as_ptr() as *const nlmsghdr;
-            let mut dump_intr = 0;
-            let mut done = 0;
+            for m in parse_messages(buf.as_slice()) {
+                let m = m?;
 
-            while NLMSG_OK!(nlh, msglen) {
-                if (*nlh).nlmsg_pid != self.local.nl_pid || (*nlh).nlmsg_seq != self.dump {
-                    nlh = NLMSG_NEXT!(nlh, msglen);
+                if m.pid != self.local.nl_pid || m.seq != self.dump {
                     continue;
                 }
 
-                // got one nlmsg
-                if (*nlh).nlmsg_flags & NLM_F_DUMP_INTR > 0 {
-                    dump_intr = 1;
+                if m.flags & NLM_F_DUMP_INTR != 0 {
+                    dump_intr = true;
                 }
 
-                if (*nlh).nlmsg_type == NLMSG_DONE {
-                    done = 1;
-                }
-
-                if (*nlh).nlmsg_type == NLMSG_ERROR {
-                    // error message, better to return
-                    // error code in error messages
+                match m.msg_type {
+                    NLMSG_DONE => {
+                        if dump_intr {
+                            info!(sl!(), "dump interuppted, maybe incomplete");
+                        }
 
-                    if (*nlh).nlmsg_len < NLMSG_LENGTH!(mem::size_of::<nlmsgerr>()) {
-                        // truncated
-                        return Err(ErrorKind::ErrorCode("truncated message".to_string()).into());
+                        return Ok(msgs);
                     }
-
-                    let el: *const nlmsgerr = NLMSG_DATA!(nlh) as *const nlmsgerr;
-                    return Err(
-                        ErrorKind::Nix(nix::Error::Sys(Errno::from_i32(-(*el).error))).into(),
-                    );
+                    NLMSG_ERROR => check_ack(&m)?,
+                    _ => msgs.push(m),
                 }
-
-                lv.push(nlh);
-
-                if done == 1 {
-                    break;
-                }
-
-                nlh = NLMSG_NEXT!(nlh, msglen);
-            }
-
-            slv.push(buf);
-
-            if done == 1 {
-                if dump_intr == 1 {
-                    info!(sl!(), "dump interuppted, maybe incomplete");
-                }
-
-                break;
-            }
-
-            // still remain some bytes?
-
-            if msglen != 0 {
-                return Err(ErrorKind::Nix(nix::Error::Sys(Errno::EINVAL)).into());
             }
         }
-        Ok((slv, lv))
     }
 
     pub fn list_interfaces(&mut self) -> Result<Vec<Interface>> {
         let mut ifaces: Vec<Interface> = Vec::new();
 
-        unsafe {
-            // get link info
-            let (_slv, lv) = self.dump_all_links()?;
-
-            // get addrinfo
-            let (_sav, av) = self.dump_all_addresses(0)?;
-
-            // got all the link message and address message
-            // into lv and av repectively, parse attributes
-            for link in &lv {
-                let nlh: *const nlmsghdr = *link;
-                let ifi: *const ifinfomsg = NLMSG_DATA!(nlh) as *const ifinfomsg;
-
-                if (*nlh).nlmsg_type != RTM_NEWLINK && (*nlh).nlmsg_type != RTM_DELLINK {
-                    continue;
-                }
+        // get link info
+        let links = self.dump_all_links()?;
 
-                if (*nlh).nlmsg_len < NLMSG_SPACE!(mem::size_of::<ifinfomsg>()) {
-                    info!(
-                        sl!(),
-                        "invalid nlmsg! nlmsg_len: {}, nlmsg_space: {}",
-                        (*nlh).nlmsg_len,
-                        NLMSG_SPACE!(mem::size_of::<ifinfomsg>())
-                    );
-                    break;
-                }
+        // get addrinfo
+        let addrs = self.dump_all_addresses(0)?;
 
-                let rta: *mut rtattr = IFLA_RTA!(ifi) as *mut rtattr;
-                let rtalen = IFLA_PAYLOAD!(nlh) as u32;
+        // got all the link message and address message
+        // into links and addrs repectively, parse attributes
+        for link in &links {
+            if link.msg_type != RTM_NEWLINK && link.msg_type != RTM_DELLINK {
+                continue;
+            }
 
-                let attrs = parse_attrs(rta, rtalen, (IFLA_MAX + 1) as usize)?;
+            let ifi: ifinfomsg = link.header()?;
 
-                // fill out some fields of Interface,
-                let mut iface: Interface = Interface::default();
+            // fill out some fields of Interface,
+            let mut iface: Interface = parse_link_msg(link)?;
 
-                if attrs[IFLA_IFNAME as usize] as i64 != 0 {
-                    let t = attrs[IFLA_IFNAME as usize];
-                    iface.name = String::from_utf8(getattr_var(t as *const rtattr))?;
+            // get ip address info from addrs
+            for address in &addrs {
+                if address.msg_type != RTM_NEWADDR {
+                    continue;
                 }
 
-                if attrs[IFLA_MTU as usize] as i64 != 0 {
-                    let t = attrs[IFLA_MTU as usize];
-                    iface.mtu = getattr32(t) as u64;
-                }
+                let ifa: ifaddrmsg = address.header()?;
 
-                if attrs[IFLA_ADDRESS as usize] as i64 != 0 {
-                    let alen = RTA_PAYLOAD!(attrs[IFLA_ADDRESS as usize]);
-                    let a: *const u8 = RTA_DATA!(attrs[IFLA_ADDRESS as usize]) as *const u8;
-                    iface.hwAddr = format_address(a, alen as u32)?;
+                if ifa.ifa_index == ifi.ifi_index as u32 {
+                    // found target addresses
+                    // fill address field of Interface
+                    iface.IPAddresses.push(parse_addr_msg(address)?);
                 }
+            }
 
-                // get ip address info from av
-                let mut ads: Vec<IPAddress> = Vec::new();
-
-                for address in &av {
-                    let alh: *const nlmsghdr = *address;
-                    let ifa: *const ifaddrmsg = NLMSG_DATA!(alh) as *const ifaddrmsg;
-                    let arta: *mut rtattr = IFA_RTA!(ifa) as *mut rtattr;
-
-                    if (*alh).nlmsg_type != RTM_NEWADDR {
-                        continue;
-                    }
-
-                    let tlen = NLMSG_SPACE!(mem::size_of::<ifaddrmsg>());
-                    if (*alh).nlmsg_len < tlen {
-                        info!(
-                            sl!(),
-                            "invalid nlmsg! nlmsg_len: {}, nlmsg_space: {}",
-                            (*alh).nlmsg_len,
-                            tlen
-                        );
-                        break;
-                    }
-
-                    let artalen = IFA_PAYLOAD!(alh) as u32;
-
-                    if (*ifa).ifa_index as u32 == (*ifi).ifi_index as u32 {
-                        // found target addresses
-                        // parse attributes and fill out Interface
-                        let addrs = parse_attrs(arta, artalen, (IFA_MAX + 1) as usize)?;
-                        // fill address field of Interface
-                        let mut one: IPAddress = IPAddress::default();
-                        let mut tattr: *const rtattr = addrs[IFA_LOCAL as usize];
-                        if addrs[IFA_ADDRESS as usize] as i64 != 0 {
-                            tattr = addrs[IFA_ADDRESS as usize];
-                        }
-
-                        one.mask = format!("{}", (*ifa).ifa_prefixlen);
-                        let a: *const u8 = RTA_DATA!(tattr) as *const u8;
-                        let alen = RTA_PAYLOAD!(tattr);
-                        one.family = IPFamily::v4;
-
-                        if (*ifa).ifa_family == libc::AF_INET6 as u8 {
-                            one.family = IPFamily::v6;
-                        }
-
-                        // only handle IPv4 for now
-                        // if (*ifa).ifa_family == libc::AF_INET as u8{
-                        one.address = format_address(a, alen as u32)?;
-                        //}
+            let (driver, pci_addr) = get_link_device(SYSFS_NET_PATH, iface.name.as_str());
+            iface.driver = driver;
+            iface.pciAddr = pci_addr;
 
-                        ads.push(one);
-                    }
-                }
+            ifaces.push(iface);
+        }
 
-                iface.IPAddresses = RepeatedField::from_vec(ads);
-                ifaces.push(iface);
+        // masters are links as well, name them from the same dump
+        let names: Vec<(i32, String)> = ifaces.iter().map(|i| (i.index, i.name.clone())).collect();
+        for iface in ifaces.iter_mut().filter(|i| i.master_index != 0) {
+            if let Some((_, name)) = names.iter().find(|(index, _)| *index == iface.master_index) {
+                iface.master = name.clone();
             }
         }
 
         Ok(ifaces)
     }
 
-    unsafe fn dump_all_links(&mut self) -> Result<(Vec<Vec<u8>>, Vec<*const nlmsghdr>)> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        let p = v.as_mut_ptr() as *mut libc::c_char;
-        let nlh: *mut nlmsghdr = p as *mut nlmsghdr;
-        let ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-        (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>() as i32) as __u32;
-        (*nlh).nlmsg_type = RTM_GETLINK;
-        (*nlh).nlmsg_flags = (NLM_F_DUMP | NLM_F_REQUEST) as __u16;
-
-        self.seq += 1;
-        self.dump = self.seq;
-        (*nlh).nlmsg_seq = self.seq;
+    fn dump_all_links(&mut self) -> Result<Vec<NlMsg>> {
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_family = libc::AF_UNSPEC as u8;
 
-        (*ifi).ifi_family = libc::AF_UNSPEC as u8;
+        let mut req = NlMsgBuilder::new(RTM_GETLINK, NLM_F_DUMP | NLM_F_REQUEST);
+        req.push(&ifi);
+        req.attr_u32(IFLA_EXT_MASK, RTEXT_FILTER_VF);
 
-        addattr32(nlh, IFLA_EXT_MASK, RTEXT_FILTER_VF);
-
-        self.send_message(v.as_mut_slice())?;
-        self.recv_dump_message()
+        self.dump(req)
     }
 
-    unsafe fn dump_all_addresses(
-        &mut self,
-        ifindex: __u32,
-    ) -> Result<(Vec<Vec<u8>>, Vec<*const nlmsghdr>)> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        let p = v.as_mut_ptr() as *mut libc::c_char;
-        let nlh: *mut nlmsghdr = p as *mut nlmsghdr;
-        let ifa: *mut ifaddrmsg = NLMSG_DATA!(nlh) as *mut ifaddrmsg;
-
-        (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifaddrmsg>());
-        (*nlh).nlmsg_type = RTM_GETADDR;
-        (*nlh).nlmsg_flags = NLM_F_DUMP | NLM_F_REQUEST;
+    fn dump_all_addresses(&mut self, ifindex: __u32) -> Result<Vec<NlMsg>> {
+        let mut ifa = ifaddrmsg::default();
+        ifa.ifa_family = libc::AF_UNSPEC as u8;
+        ifa.ifa_index = ifindex;
 
-        self.seq += 1;
-        self.dump = self.seq;
-        (*nlh).nlmsg_seq = self.seq;
+        let mut req = NlMsgBuilder::new(RTM_GETADDR, NLM_F_DUMP | NLM_F_REQUEST);
+        req.push(&ifa);
 
-        (*ifa).ifa_family = libc::AF_UNSPEC as u8;
-        (*ifa).ifa_index = ifindex;
-
-        self.send_message(v.as_mut_slice())?;
-
-        self.recv_dump_message()
+        self.dump(req)
     }
 
     fn find_link_by_hwaddr(&mut self, hwaddr: &str) -> Result<ifinfomsg> {
-        let mut hw: Vec<u8> = vec![0; 6];
-        unsafe {
-            //parse out hwaddr in request
-            let p = hw.as_mut_ptr() as *mut u8;
-            let (hw0, hw1, hw2, hw3, hw4, hw5) = scan_fmt!(hwaddr, "{x}:{x}:{x}:{x}:{x}:{x}", 
-				[hex u8], [hex u8], [hex u8], [hex u8], [hex u8],
-				[hex u8])?;
-
-            hw[0] = hw0;
-            hw[1] = hw1;
-            hw[2] = hw2;
-            hw[3] = hw3;
-            hw[4] = hw4;
-            hw[5] = hw5;
-
-            // dump out all links
-            let (_slv, lv) = self.dump_all_links()?;
-
-            for link in &lv {
-                let nlh: *const nlmsghdr = *link;
-                let ifi: *const ifinfomsg = NLMSG_DATA!(nlh) as *const ifinfomsg;
-
-                if (*nlh).nlmsg_type != RTM_NEWLINK && (*nlh).nlmsg_type != RTM_DELLINK {
-                    continue;
-                }
-
-                if (*nlh).nlmsg_len < NLMSG_SPACE!(mem::size_of::<ifinfomsg>()) {
-                    info!(
-                        sl!(),
-                        "invalid nlmsg! nlmsg_len: {}, nlmsg_space: {}",
-                        (*nlh).nlmsg_len,
-                        NLMSG_SPACE!(mem::size_of::<ifinfomsg>())
-                    );
-                    break;
-                }
+        //parse out hwaddr in request
+        let (hw0, hw1, hw2, hw3, hw4, hw5) = scan_fmt!(hwaddr, "{x}:{x}:{x}:{x}:{x}:{x}", 
+			[hex u8], [hex u8], [hex u8], [hex u8], [hex u8],
+			[hex u8])?;
+        let hw: Vec<u8> = vec![hw0, hw1, hw2, hw3, hw4, hw5];
+
+        // dump out all links
+        for link in &self.dump_all_links()? {
+            if link.msg_type != RTM_NEWLINK && link.msg_type != RTM_DELLINK {
+                continue;
+            }
 
-                let rta: *mut rtattr = IFLA_RTA!(ifi) as *mut rtattr;
-                let rtalen = IFLA_PAYLOAD!(nlh) as u32;
-
-                let attrs = parse_attrs(rta, rtalen, (IFLA_MAX + 1) as usize)?;
-
-                // find the target ifinfomsg
-                if attrs[IFLA_ADDRESS as usize] as i64 != 0 {
-                    let a = RTA_DATA!(attrs[IFLA_ADDRESS as usize]) as *const libc::c_void;
-                    if libc::memcmp(
-                        p as *const libc::c_void,
-                        a,
-                        RTA_PAYLOAD!(attrs[IFLA_ADDRESS as usize]) as libc::size_t,
-                    ) == 0
-                    {
-                        return Ok(ifinfomsg { ..*ifi });
-                    }
+            // find the target ifinfomsg
+            if let Some(a) = link.attrs::<ifinfomsg>()?.get(IFLA_ADDRESS) {
+                if a.data == hw.as_slice() {
+                    return Ok(link.header()?);
                 }
             }
         }
@@ -1607,173 +1501,88 @@ impl RtnlHandle {
     }
 
     fn find_link_by_name(&mut self, name: &str) -> Result<ifinfomsg> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-            let mut ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>()) as __u32;
-            (*nlh).nlmsg_type = RTM_GETLINK;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*ifi).ifi_family = libc::AF_UNSPEC as u8;
-
-            addattr_var(
-                nlh,
-                IFLA_IFNAME,
-                name.as_ptr() as *const u8,
-                (name.len() + 1),
-            );
-
-            addattr32(
-                nlh,
-                IFLA_EXT_MASK,
-                RTEXT_FILTER_VF | RTEXT_FILTER_SKIP_STATS,
-            );
-
-            let mut retv = self.rtnl_talk(v.as_mut_slice(), true)?;
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_family = libc::AF_UNSPEC as u8;
 
-            nlh = retv.as_mut_ptr() as *mut nlmsghdr;
-            ifi = NLMSG_DATA!(nlh) as *mut ifinfomsg;
+        let mut req = NlMsgBuilder::new(RTM_GETLINK, NLM_F_REQUEST);
+        req.push(&ifi);
+        req.attr_str(IFLA_IFNAME, name);
+        req.attr_u32(IFLA_EXT_MASK, RTEXT_FILTER_VF | RTEXT_FILTER_SKIP_STATS);
 
-            return Ok(ifinfomsg { ..*ifi });
+        match self.rtnl_talk(req, true)? {
+            Some(m) => Ok(m.header()?),
+            None => Err(ErrorKind::Nix(nix::Error::Sys(Errno::ENODEV)).into()),
         }
     }
 
-    fn rtnl_talk(&mut self, data: &mut [u8], answer: bool) -> Result<Vec<u8>> {
-        unsafe {
-            let nlh: *mut nlmsghdr = data.as_mut_ptr() as *mut nlmsghdr;
-            if !answer {
-                (*nlh).nlmsg_flags |= NLM_F_ACK;
-            }
+    // rtnl_talk sends a request and waits for its answer, or for its ack
+    // when no answer is expected.
+    pub fn rtnl_talk(&mut self, mut req: NlMsgBuilder, answer: bool) -> Result<Option<NlMsg>> {
+        if !answer {
+            req.add_flags(NLM_F_ACK);
         }
 
-        self.send_message(data)?;
-        unsafe {
-            loop {
-                let buf = self.recv_message()?;
-                let mut msglen = buf.len() as u32;
-                let mut nlh = buf.as_ptr() as *const nlmsghdr;
-
-                while NLMSG_OK!(nlh, msglen) {
-                    // not for us
-
-                    if (*nlh).nlmsg_pid != self.local.nl_pid {
-                        nlh = NLMSG_NEXT!(nlh, msglen);
-                        continue;
-                    }
-
-                    if (*nlh).nlmsg_type == NLMSG_ERROR {
-                        // error message, better to return
-                        // error code in error messages
-
-                        if (*nlh).nlmsg_len < NLMSG_LENGTH!(mem::size_of::<nlmsgerr>()) {
-                            // truncated
-                            return Err(
-                                ErrorKind::ErrorCode("truncated message".to_string()).into()
-                            );
-                        }
+        self.seq += 1;
+        let seq = self.seq;
+        req.set_seq(seq);
 
-                        let el: *const nlmsgerr = NLMSG_DATA!(nlh) as *const nlmsgerr;
+        self.send_message(req.finish()?.as_slice())?;
 
-                        // this is ack. -_-
-                        if (*el).error == 0 {
-                            return Ok(Vec::new());
-                        }
+        loop {
+            let buf = self.recv_message()?;
 
-                        return Err(
-                            ErrorKind::Nix(nix::Error::Sys(Errno::from_i32(-(*el).error))).into(),
-                        );
-                    }
+            for m in parse_messages(buf.as_slice()) {
+                let m = m?;
 
-                    // goog message
-                    if answer {
-                        // need to copy out data
-
-                        let mut d: Vec<u8> = vec![0; (*nlh).nlmsg_len as usize];
-                        let dp: *mut libc::c_void = d.as_mut_ptr() as *mut libc::c_void;
-                        libc::memcpy(
-                            dp,
-                            nlh as *const libc::c_void,
-                            (*nlh).nlmsg_len as libc::size_t,
-                        );
-                        return Ok(d);
-                    } else {
-                        return Ok(Vec::new());
-                    }
+                // not for us
+                if m.pid != self.local.nl_pid || m.seq != seq {
+                    continue;
                 }
 
-                if !(NLMSG_OK!(nlh, msglen)) {
-                    return Err(ErrorKind::Nix(nix::Error::Sys(Errno::EINVAL)).into());
+                if m.msg_type == NLMSG_ERROR {
+                    check_ack(&m)?;
+                    return Ok(None);
                 }
+
+                return Ok(if answer { Some(m) } else { None });
             }
         }
     }
 
     fn set_link_status(&mut self, ifinfo: &ifinfomsg, up: bool) -> Result<()> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let p: *mut u8 = v.as_mut_ptr() as *mut u8;
-            let mut nlh: *mut nlmsghdr = p as *mut nlmsghdr;
-            let mut ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>() as u32) as __u32;
-            (*nlh).nlmsg_type = RTM_NEWLINK;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_family = ifinfo.ifi_family;
+        ifi.ifi_type = ifinfo.ifi_type;
+        ifi.ifi_index = ifinfo.ifi_index;
 
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
+        ifi.ifi_change |= libc::IFF_UP as u32;
 
-            (*ifi).ifi_family = ifinfo.ifi_family;
-            (*ifi).ifi_type = ifinfo.ifi_type;
-            (*ifi).ifi_index = ifinfo.ifi_index;
-
-            (*ifi).ifi_change |= libc::IFF_UP as u32;
-
-            if up {
-                (*ifi).ifi_flags |= libc::IFF_UP as u32;
-            } else {
-                (*ifi).ifi_flags &= !libc::IFF_UP as u32;
-            }
+        if up {
+            ifi.ifi_flags |= libc::IFF_UP as u32;
+        } else {
+            ifi.ifi_flags &= !libc::IFF_UP as u32;
         }
 
-        self.rtnl_talk(v.as_mut_slice(), false)?;
+        let mut req = NlMsgBuilder::new(RTM_NEWLINK, NLM_F_REQUEST);
+        req.push(&ifi);
+
+        self.rtnl_talk(req, false)?;
 
         Ok(())
     }
 
     fn delete_one_addr(&mut self, ifinfo: &ifinfomsg, addr: &RtIPAddr) -> Result<()> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let p: *mut u8 = v.as_mut_ptr() as *mut u8;
-
-            let mut nlh: *mut nlmsghdr = p as *mut nlmsghdr;
-            let mut ifa: *mut ifaddrmsg = NLMSG_DATA!(nlh) as *mut ifaddrmsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifaddrmsg>() as u32) as __u32;
-            (*nlh).nlmsg_type = RTM_DELADDR;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*ifa).ifa_family = addr.ip_family;
-            (*ifa).ifa_prefixlen = addr.ip_mask;
-            (*ifa).ifa_index = ifinfo.ifi_index as u32;
+        let mut ifa = ifaddrmsg::default();
+        ifa.ifa_family = addr.ip_family;
+        ifa.ifa_prefixlen = addr.ip_mask;
+        ifa.ifa_index = ifinfo.ifi_index as u32;
 
-            addattr_var(
-                nlh,
-                IFA_ADDRESS,
-                addr.addr.as_ptr() as *const u8,
-                addr.addr.len(),
-            );
-        }
+        let mut req = NlMsgBuilder::new(RTM_DELADDR, NLM_F_REQUEST);
+        req.push(&ifa);
+        req.attr(IFA_ADDRESS, addr.addr.as_slice());
 
         // ignore EADDRNOTAVAIL here..
-        self.rtnl_talk(v.as_mut_slice(), false)?;
+        self.rtnl_talk(req, false)?;
 
         Ok(())
     }
@@ -1788,49 +1597,26 @@ impl RtnlHandle {
 
     fn get_link_addresses(&mut self, ifinfo: &ifinfomsg) -> Result<Vec<RtIPAddr>> {
         let mut del_addrs: Vec<RtIPAddr> = Vec::new();
-        unsafe {
-            let (_sav, av) = self.dump_all_addresses(ifinfo.ifi_index as __u32)?;
-
-            for a in &av {
-                let nlh: *const nlmsghdr = *a;
-                let ifa: *const ifaddrmsg = NLMSG_DATA!(nlh) as *const ifaddrmsg;
 
-                if (*nlh).nlmsg_type != RTM_NEWADDR {
-                    continue;
-                }
-
-                let tlen = NLMSG_SPACE!(mem::size_of::<ifaddrmsg>());
-                if (*nlh).nlmsg_len < tlen {
-                    info!(
-                        sl!(),
-                        "invalid nlmsg! nlmsg_len: {}, nlmsg_space: {}",
-                        (*nlh).nlmsg_len,
-                        tlen
-                    );
-                    break;
-                }
-
-                if (*ifa).ifa_flags as u32 & IFA_F_SECONDARY != 0 {
-                    continue;
-                }
-
-                let rta: *mut rtattr = IFA_RTA!(ifa) as *mut rtattr;
-                let rtalen = IFA_PAYLOAD!(nlh) as u32;
+        for a in &self.dump_all_addresses(ifinfo.ifi_index as __u32)? {
+            if a.msg_type != RTM_NEWADDR {
+                continue;
+            }
 
-                if ifinfo.ifi_index as u32 == (*ifa).ifa_index {
-                    let attrs = parse_attrs(rta, rtalen, (IFA_MAX + 1) as usize)?;
-                    let mut t: *const rtattr = attrs[IFA_LOCAL as usize];
+            let ifa: ifaddrmsg = a.header()?;
 
-                    if attrs[IFA_ADDRESS as usize] as i64 != 0 {
-                        t = attrs[IFA_ADDRESS as usize];
-                    }
+            if ifa.ifa_flags as u32 & IFA_F_SECONDARY != 0 {
+                continue;
+            }
 
-                    let addr = getattr_var(t as *const rtattr);
+            if ifinfo.ifi_index as u32 == ifa.ifa_index {
+                let attrs = a.attrs::<ifaddrmsg>()?;
 
+                if let Some(t) = attrs.get(IFA_ADDRESS).or_else(|| attrs.get(IFA_LOCAL)) {
                     del_addrs.push(RtIPAddr {
-                        ip_family: (*ifa).ifa_family,
-                        ip_mask: (*ifa).ifa_prefixlen,
-                        addr,
+                        ip_family: ifa.ifa_family,
+                        ip_mask: ifa.ifa_prefixlen,
+                        addr: t.data.to_vec(),
                     });
                 }
             }
@@ -1840,33 +1626,19 @@ impl RtnlHandle {
     }
 
     fn add_one_address(&mut self, ifinfo: &ifinfomsg, ip: &RtIPAddr) -> Result<()> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-            let mut ifa: *mut ifaddrmsg = NLMSG_DATA!(nlh) as *mut ifaddrmsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifaddrmsg>() as u32) as __u32;
-            (*nlh).nlmsg_type = RTM_NEWADDR;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL;
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*ifa).ifa_family = ip.ip_family;
-            (*ifa).ifa_prefixlen = ip.ip_mask;
-            (*ifa).ifa_index = ifinfo.ifi_index as __u32;
-
-            addattr_var(
-                nlh,
-                IFA_ADDRESS,
-                ip.addr.as_ptr() as *const u8,
-                ip.addr.len(),
-            );
-            // don't know why need IFA_LOCAL, without it
-            // kernel returns -EINVAL...
-            addattr_var(nlh, IFA_LOCAL, ip.addr.as_ptr() as *const u8, ip.addr.len());
+        let mut ifa = ifaddrmsg::default();
+        ifa.ifa_family = ip.ip_family;
+        ifa.ifa_prefixlen = ip.ip_mask;
+        ifa.ifa_index = ifinfo.ifi_index as __u32;
 
-            self.rtnl_talk(v.as_mut_slice(), false)?;
-        }
+        let mut req = NlMsgBuilder::new(RTM_NEWADDR, NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL);
+        req.push(&ifa);
+        req.attr(IFA_ADDRESS, ip.addr.as_slice());
+        // don't know why need IFA_LOCAL, without it
+        // kernel returns -EINVAL...
+        req.attr(IFA_LOCAL, ip.addr.as_slice());
+
+        self.rtnl_talk(req, false)?;
 
         Ok(())
     }
@@ -1899,42 +1671,22 @@ impl RtnlHandle {
         }
 
         // set name, set mtu, IFF_NOARP. in one rtnl_talk.
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let p: *mut u8 = v.as_mut_ptr() as *mut u8;
-            let mut nlh: *mut nlmsghdr = p as *mut nlmsghdr;
-            let mut ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>() as u32) as __u32;
-            (*nlh).nlmsg_type = RTM_NEWLINK;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*ifi).ifi_family = ifinfo.ifi_family;
-            (*ifi).ifi_type = ifinfo.ifi_type;
-            (*ifi).ifi_index = ifinfo.ifi_index;
-
-            if iface.raw_flags & libc::IFF_NOARP as u32 != 0 {
-                (*ifi).ifi_change |= libc::IFF_NOARP as u32;
-                (*ifi).ifi_flags |= libc::IFF_NOARP as u32;
-            }
-
-            addattr32(nlh, IFLA_MTU, iface.mtu as u32);
-
-            // if str is null terminated, use addattr_var
-            // otherwise, use addattr_str
-            addattr_var(
-                nlh,
-                IFLA_IFNAME,
-                iface.name.as_ptr() as *const u8,
-                iface.name.len(),
-            );
-            // addattr_str(nlh, IFLA_IFNAME, iface.name.as_str());
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_family = ifinfo.ifi_family;
+        ifi.ifi_type = ifinfo.ifi_type;
+        ifi.ifi_index = ifinfo.ifi_index;
+
+        if iface.raw_flags & libc::IFF_NOARP as u32 != 0 {
+            ifi.ifi_change |= libc::IFF_NOARP as u32;
+            ifi.ifi_flags |= libc::IFF_NOARP as u32;
         }
 
-        self.rtnl_talk(v.as_mut_slice(), false)?;
+        let mut req = NlMsgBuilder::new(RTM_NEWLINK, NLM_F_REQUEST);
+        req.push(&ifi);
+        req.attr_u32(IFLA_MTU, iface.mtu as u32);
+        req.attr_str(IFLA_IFNAME, iface.name.as_str());
+
+        self.rtnl_talk(req, false)?;
 
         let _ = self.set_link_status(&ifinfo, true);
         // test remove this link
@@ -1950,78 +1702,37 @@ impl RtnlHandle {
         self.set_link_status(&ifinfo, false)?;
 
         // delete this link per request
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-            let mut ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-            // No attributes needed?
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>()) as __u32;
-            (*nlh).nlmsg_type = RTM_DELLINK;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-            self.seq += 1;
-            (*nlh).nlmsg_seq = self.seq;
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_family = ifinfo.ifi_family;
+        ifi.ifi_index = ifinfo.ifi_index;
+        ifi.ifi_type = ifinfo.ifi_type;
 
-            (*ifi).ifi_family = ifinfo.ifi_family;
-            (*ifi).ifi_index = ifinfo.ifi_index;
-            (*ifi).ifi_type = ifinfo.ifi_type;
+        // No attributes needed?
+        let mut req = NlMsgBuilder::new(RTM_DELLINK, NLM_F_REQUEST);
+        req.push(&ifi);
 
-            self.rtnl_talk(v.as_mut_slice(), false)?;
-        }
+        self.rtnl_talk(req, false)?;
 
         Ok(iface.clone())
     }
 
-    fn get_name_by_index(&mut self, index: i32) -> Result<String> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        let mut i = 0;
-        unsafe {
-            while i < 5 {
-                i += 1;
-                let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-                let mut ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-                (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<ifinfomsg>()) as __u32;
-                (*nlh).nlmsg_type = RTM_GETLINK;
-                (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-                self.seq += 1;
-                (*nlh).nlmsg_seq = self.seq;
-
-                (*ifi).ifi_index = index;
-
-                addattr32(
-                    nlh,
-                    IFLA_EXT_MASK,
-                    RTEXT_FILTER_VF | RTEXT_FILTER_SKIP_STATS,
-                );
-
-                let mut retv = self.rtnl_talk(v.as_mut_slice(), true)?;
-
-                let nlh: *mut nlmsghdr = retv.as_mut_ptr() as *mut nlmsghdr;
-                let ifi: *mut ifinfomsg = NLMSG_DATA!(nlh) as *mut ifinfomsg;
-
-                if (*nlh).nlmsg_type != RTM_NEWLINK && (*nlh).nlmsg_type != RTM_DELLINK {
-                    info!(sl!(), "wrong message!");
-                    continue;
-                }
-
-                let tlen = NLMSG_SPACE!(mem::size_of::<ifinfomsg>());
-                if (*nlh).nlmsg_len < tlen {
-                    info!(sl!(), "corrupt message?");
-                    continue;
-                }
+    pub fn get_index_by_name(&mut self, name: &str) -> Result<i32> {
+        Ok(self.find_link_by_name(name)?.ifi_index)
+    }
 
-                let rta: *mut rtattr = IFLA_RTA!(ifi) as *mut rtattr;
-                let rtalen = IFLA_PAYLOAD!(nlh) as u32;
+    pub fn get_name_by_index(&mut self, index: i32) -> Result<String> {
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_index = index;
 
-                let attrs = parse_attrs(rta, rtalen, (IFLA_MAX + 1) as usize)?;
+        let mut req = NlMsgBuilder::new(RTM_GETLINK, NLM_F_REQUEST);
+        req.push(&ifi);
+        req.attr_u32(IFLA_EXT_MASK, RTEXT_FILTER_VF | RTEXT_FILTER_SKIP_STATS);
 
-                let t = attrs[IFLA_IFNAME as usize];
-                if t as i64 != 0 {
+        if let Some(m) = self.rtnl_talk(req, true)? {
+            if m.msg_type == RTM_NEWLINK {
+                if let Some(t) = m.attrs::<ifinfomsg>()?.get(IFLA_IFNAME) {
                     // we have a name
-                    let tdata = getattr_var(t as *const rtattr);
-                    return Ok(String::from_utf8(tdata)?);
+                    return Ok(t.as_string()?);
                 }
             }
         }
@@ -2036,118 +1747,14 @@ impl RtnlHandle {
         // Fix Me: think about othe tables, ipv6..
         let mut rs: Vec<Route> = Vec::new();
 
-        unsafe {
-            let (_srv, rv) = self.dump_all_route_msgs()?;
-
-            // parse out routes and store in rs
-            for r in &rv {
-                let nlh: *const nlmsghdr = *r;
-                let rtm: *const rtmsg = NLMSG_DATA!(nlh) as *const rtmsg;
-
-                if (*nlh).nlmsg_type != RTM_NEWROUTE && (*nlh).nlmsg_type != RTM_DELROUTE {
-                    info!(sl!(), "not route message!");
-                    continue;
-                }
-
-                let tlen = NLMSG_SPACE!(mem::size_of::<rtmsg>());
-                if (*nlh).nlmsg_len < tlen {
-                    info!(
-                        sl!(),
-                        "invalid nlmsg! nlmsg_len: {}, nlmsg_spae: {}",
-                        (*nlh).nlmsg_len,
-                        tlen
-                    );
-                    break;
-                }
-
-                let rta: *mut rtattr = RTM_RTA!(rtm) as *mut rtattr;
-
-                if (*rtm).rtm_table != RT_TABLE_MAIN as u8 {
-                    continue;
-                }
-
-                let rtalen = RTM_PAYLOAD!(nlh) as u32;
-
-                let attrs = parse_attrs(rta, rtalen, (RTA_MAX + 1) as usize)?;
-
-                let t = attrs[RTA_TABLE as usize];
-                if t as i64 != 0 {
-                    let table = getattr32(t);
-                    if table != RT_TABLE_MAIN {
-                        continue;
-                    }
-                }
-                // find source, destination, gateway, scope, and
-                // and device name
-
-                let mut t = attrs[RTA_DST as usize];
-                let mut rte: Route = Route::default();
-
-                // destination
-                if t as i64 != 0 {
-                    let data: *const u8 = RTA_DATA!(t) as *const u8;
-                    let len = RTA_PAYLOAD!(t) as u32;
-                    rte.dest = format!("{}/{}", format_address(data, len)?, (*rtm).rtm_dst_len);
-                }
-
-                // gateway
-                t = attrs[RTA_GATEWAY as usize];
-                if t as i64 != 0 {
-                    let data: *const u8 = RTA_DATA!(t) as *const u8;
-                    let len = RTA_PAYLOAD!(t) as u32;
-                    rte.gateway = format_address(data, len)?;
-
-                    // for gateway, destination is 0.0.0.0
-                    rte.dest = "0.0.0.0".to_string();
-                }
-
-                // source
-                t = attrs[RTA_SRC as usize];
-
-                if t as i64 == 0 {
-                    t = attrs[RTA_PREFSRC as usize];
-                }
-
-                if t as i64 != 0 {
-                    let data: *const u8 = RTA_DATA!(t) as *const u8;
-                    let len = RTA_PAYLOAD!(t) as u32;
-
-                    rte.source = format_address(data, len)?;
-
-                    if (*rtm).rtm_src_len != 0 {
-                        rte.source = format!("{}/{}", rte.source.as_str(), (*rtm).rtm_src_len);
-                    }
-                }
-
-                // scope
-                rte.scope = (*rtm).rtm_scope as u32;
-
-                // oif
-                t = attrs[RTA_OIF as usize];
-                if t as i64 != 0 {
-                    let data: *const i32 = RTA_DATA!(t) as *const i32;
-                    assert_eq!(RTA_PAYLOAD!(t), 4);
-
-                    /*
-
-                    let mut n: Vec<u8> = vec![0; libc::IF_NAMESIZE];
-                    let np: *mut libc::c_char = n.as_mut_ptr() as *mut libc::c_char;
-                    let tn = libc::if_indextoname(*data as u32,
-                        np);
-
-                    if tn as i64 == 0 {
-                        info!(sl!(), "no name?");
-                    } else {
-                        info!(sl!(), "name(indextoname): {}", String::from_utf8(n)?);
-                    }
-                    // std::process::exit(-1);
-                    */
-
-                    rte.device = self
-                        .get_name_by_index(*data)
-                        .unwrap_or("unknown".to_string());
-                }
+        // parse out routes and store in rs
+        for m in &self.dump_all_route_msgs()? {
+            if m.msg_type != RTM_NEWROUTE && m.msg_type != RTM_DELROUTE {
+                info!(sl!(), "not route message!");
+                continue;
+            }
 
+            if let Some(rte) = self.parse_route_msg(m)? {
                 rs.push(rte);
             }
         }
@@ -2155,116 +1762,123 @@ impl RtnlHandle {
         Ok(rs)
     }
 
-    unsafe fn dump_all_route_msgs(&mut self) -> Result<(Vec<Vec<u8>>, Vec<*const nlmsghdr>)> {
-        let mut v: Vec<u8> = vec![0; 2048];
-        let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-        let mut rtm: *mut rtmsg = NLMSG_DATA!(nlh) as *mut rtmsg;
+    // parse_route_msg converts a RTM_NEWROUTE or RTM_DELROUTE message
+    // into a Route, routes out of the main table are skipped.
+    fn parse_route_msg(&mut self, m: &NlMsg) -> Result<Option<Route>> {
+        let rtm: rtmsg = m.header()?;
 
-        (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<rtmsg>()) as u32;
-        (*nlh).nlmsg_type = RTM_GETROUTE;
-        (*nlh).nlmsg_flags = NLM_F_REQUEST | NLM_F_DUMP;
+        if rtm.rtm_table != RT_TABLE_MAIN as u8 {
+            return Ok(None);
+        }
 
-        self.seq += 1;
-        self.dump = self.seq;
-        (*nlh).nlmsg_seq = self.seq;
+        let attrs = m.attrs::<rtmsg>()?;
 
-        (*rtm).rtm_family = libc::AF_INET as u8;
-        (*rtm).rtm_table = RT_TABLE_MAIN as u8;
+        if let Some(t) = attrs.get(RTA_TABLE) {
+            if t.as_u32()? != RT_TABLE_MAIN {
+                return Ok(None);
+            }
+        }
 
-        addattr32(nlh, RTA_TABLE, RT_TABLE_MAIN);
+        // find source, destination, gateway, scope, and
+        // and device name
+        let mut rte: Route = Route::default();
 
-        self.send_message(v.as_mut_slice())?;
+        // destination
+        if let Some(t) = attrs.get(RTA_DST) {
+            rte.dest = format!("{}/{}", format_address(t.data)?, rtm.rtm_dst_len);
+        }
 
-        self.recv_dump_message()
-    }
+        // gateway
+        if let Some(t) = attrs.get(RTA_GATEWAY) {
+            rte.gateway = format_address(t.data)?;
 
-    fn get_all_routes(&mut self) -> Result<Vec<RtRoute>> {
-        let mut rs: Vec<RtRoute> = Vec::new();
+            // for gateway, destination is 0.0.0.0
+            rte.dest = "0.0.0.0".to_string();
+        }
 
-        unsafe {
-            let (_srv, rv) = self.dump_all_route_msgs()?;
+        // source
+        if let Some(t) = attrs.get(RTA_SRC).or_else(|| attrs.get(RTA_PREFSRC)) {
+            rte.source = format_address(t.data)?;
 
-            for r in &rv {
-                let nlh: *const nlmsghdr = *r;
-                let rtm: *const rtmsg = NLMSG_DATA!(nlh) as *const rtmsg;
+            if rtm.rtm_src_len != 0 {
+                rte.source = format!("{}/{}", rte.source.as_str(), rtm.rtm_src_len);
+            }
+        }
 
-                if (*nlh).nlmsg_type != RTM_NEWROUTE && (*nlh).nlmsg_type != RTM_DELROUTE {
-                    info!(sl!(), "not route message!");
-                    continue;
-                }
+        // scope
+        rte.scope = rtm.rtm_scope as u32;
 
-                let tlen = NLMSG_SPACE!(mem::size_of::<rtmsg>());
-                if (*nlh).nlmsg_len < tlen {
-                    info!(
-                        sl!(),
-                        "invalid nlmsg! nlmsg_len: {}, nlmsg_spae: {}",
-                        (*nlh).nlmsg_len,
-                        tlen
-                    );
-                    break;
-                }
+        // protocol and onlink flag
+        rte.protocol = rtm.rtm_protocol as u32;
+        rte.onlink = rtm.rtm_flags & RTNH_F_ONLINK as u32 != 0;
 
-                if (*rtm).rtm_table != RT_TABLE_MAIN as u8 {
-                    continue;
-                }
+        // metric
+        if let Some(t) = attrs.get(RTA_PRIORITY) {
+            rte.metric = t.as_u32()?;
+        }
 
-                let rta: *mut rtattr = RTM_RTA!(rtm) as *mut rtattr;
-                let rtalen = RTM_PAYLOAD!(nlh) as u32;
+        // mtu, nested in metrics
+        if let Some(t) = attrs.get(RTA_METRICS) {
+            if let Some(mx) = t.nested()?.get(RTAX_MTU) {
+                rte.mtu = mx.as_u32()?;
+            }
+        }
 
-                let attrs = parse_attrs(rta, rtalen, (RTA_MAX + 1) as usize)?;
+        // multipath nexthops
+        if let Some(t) = attrs.get(RTA_MULTIPATH) {
+            for nh in parse_multipath(t.data)? {
+                let mut n: Nexthop = Nexthop::default();
 
-                let t = attrs[RTA_TABLE as usize];
-                if t as i64 != 0 {
-                    let table = getattr32(t);
-                    if table != RT_TABLE_MAIN {
-                        continue;
-                    }
+                if let Some(gw) = nh.gateway.as_ref() {
+                    n.gateway = format_address(gw.as_slice())?;
                 }
 
-                // find source, destination, gateway, scope, and
-                // and device name
+                n.device = self
+                    .get_name_by_index(nh.index)
+                    .unwrap_or("unknown".to_string());
+                n.weight = nh.hops as u32 + 1;
+                n.onlink = nh.flags & RTNH_F_ONLINK != 0;
 
-                let mut t = attrs[RTA_DST as usize];
-                let mut rte: RtRoute = RtRoute::default();
+                rte.multipath.push(n);
+            }
 
-                rte.dst_len = (*rtm).rtm_dst_len;
-                rte.src_len = (*rtm).rtm_src_len;
-                rte.dest = None;
-                rte.protocol = (*rtm).rtm_protocol;
-                // destination
-                if t as i64 != 0 {
-                    rte.dest = Some(getattr_var(t as *const rtattr));
-                }
+            if rte.dest.is_empty() {
+                rte.dest = "0.0.0.0".to_string();
+            }
+        }
 
-                // gateway
-                t = attrs[RTA_GATEWAY as usize];
-                if t as i64 != 0 {
-                    rte.gateway = Some(getattr_var(t as *const rtattr));
-                    if rte.dest.is_none() {
-                        rte.dest = Some(vec![0 as u8; 4]);
-                    }
-                }
+        // oif
+        if let Some(t) = attrs.get(RTA_OIF) {
+            rte.device = self
+                .get_name_by_index(t.as_i32()?)
+                .unwrap_or("unknown".to_string());
+        }
 
-                // source
-                t = attrs[RTA_SRC as usize];
+        Ok(Some(rte))
+    }
 
-                if t as i64 == 0 {
-                    t = attrs[RTA_PREFSRC as usize];
-                }
+    fn dump_all_route_msgs(&mut self) -> Result<Vec<NlMsg>> {
+        let mut rtm = rtmsg::default();
+        rtm.rtm_family = libc::AF_INET as u8;
+        rtm.rtm_table = RT_TABLE_MAIN as u8;
 
-                if t as i64 != 0 {
-                    rte.source = Some(getattr_var(t as *const rtattr));
-                }
+        let mut req = NlMsgBuilder::new(RTM_GETROUTE, NLM_F_REQUEST | NLM_F_DUMP);
+        req.push(&rtm);
+        req.attr_u32(RTA_TABLE, RT_TABLE_MAIN);
+
+        self.dump(req)
+    }
 
-                // scope
-                rte.scope = (*rtm).rtm_scope;
+    fn get_all_routes(&mut self) -> Result<Vec<RtRoute>> {
+        let mut rs: Vec<RtRoute> = Vec::new();
 
-                // oif
-                t = attrs[RTA_OIF as usize];
-                if t as i64 != 0 {
-                    rte.index = getattr32(t as *const rtattr) as i32;
-                }
+        for m in &self.dump_all_route_msgs()? {
+            if m.msg_type != RTM_NEWROUTE && m.msg_type != RTM_DELROUTE {
+                info!(sl!(), "not route message!");
+                continue;
+            }
 
+            if let Some(rte) = parse_rt_route(m)? {
                 rs.push(rte);
             }
         }
@@ -2274,7 +1888,13 @@ impl RtnlHandle {
 
     fn delete_all_routes(&mut self, rs: &Vec<RtRoute>) -> Result<()> {
         for r in rs {
-            let name = self.get_name_by_index(r.index)?;
+            // multipath routes have no oif, use the first nexthop device
+            let index = match r.multipath.first() {
+                Some(nh) if r.index == 0 => nh.index,
+                _ => r.index,
+            };
+
+            let name = self.get_name_by_index(index)?;
             if name.as_str().contains("lo") || name.as_str().contains("::1") {
                 continue;
             }
@@ -2290,411 +1910,555 @@ impl RtnlHandle {
     }
 
     fn add_one_route(&mut self, r: &RtRoute) -> Result<()> {
-        let mut v: Vec<u8> = vec![0; 2048];
+        let mut rtm = rtmsg::default();
+        rtm.rtm_family = libc::AF_INET as u8;
+        rtm.rtm_table = RT_TABLE_MAIN as u8;
+        rtm.rtm_protocol = if r.protocol != RTPROTO_UNSPEC {
+            r.protocol
+        } else {
+            RTPROTO_BOOT
+        };
+        rtm.rtm_type = RTN_UNICAST;
 
-        unsafe {
-            let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-            let mut rtm: *mut rtmsg = NLMSG_DATA!(nlh) as *mut rtmsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<rtmsg>()) as u32;
-            (*nlh).nlmsg_type = RTM_NEWROUTE;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL;
-
-            self.seq += 1;
-            self.dump = self.seq;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*rtm).rtm_family = libc::AF_INET as u8;
-            (*rtm).rtm_table = RT_TABLE_MAIN as u8;
-            (*rtm).rtm_scope = RT_SCOPE_NOWHERE;
-            (*rtm).rtm_protocol = RTPROTO_BOOT;
-            (*rtm).rtm_scope = RT_SCOPE_UNIVERSE;
-            (*rtm).rtm_type = RTN_UNICAST;
-
-            (*rtm).rtm_dst_len = r.dst_len;
-            (*rtm).rtm_src_len = r.src_len;
-            (*rtm).rtm_scope = r.scope;
-
-            if r.source.is_some() {
-                let len = r.source.as_ref().unwrap().len();
-                if r.src_len > 0 {
-                    addattr_var(
-                        nlh,
-                        RTA_SRC,
-                        r.source.as_ref().unwrap().as_ptr() as *const u8,
-                        len,
-                    );
-                } else {
-                    addattr_var(
-                        nlh,
-                        RTA_PREFSRC,
-                        r.source.as_ref().unwrap().as_ptr() as *const u8,
-                        len,
-                    );
-                }
-            }
+        rtm.rtm_dst_len = r.dst_len;
+        rtm.rtm_src_len = r.src_len;
+        rtm.rtm_scope = r.scope;
+        rtm.rtm_flags = r.flags;
 
-            if r.dest.is_some() {
-                let len = r.dest.as_ref().unwrap().len();
-                addattr_var(
-                    nlh,
-                    RTA_DST,
-                    r.dest.as_ref().unwrap().as_ptr() as *const u8,
-                    len,
-                );
-            }
+        let mut req = NlMsgBuilder::new(RTM_NEWROUTE, NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL);
+        req.push(&rtm);
 
-            if r.gateway.is_some() {
-                let len = r.gateway.as_ref().unwrap().len();
-                addattr_var(
-                    nlh,
-                    RTA_GATEWAY,
-                    r.gateway.as_ref().unwrap().as_ptr() as *const u8,
-                    len,
-                );
+        if let Some(src) = r.source.as_ref() {
+            if r.src_len > 0 {
+                req.attr(RTA_SRC, src.as_slice());
+            } else {
+                req.attr(RTA_PREFSRC, src.as_slice());
             }
+        }
+
+        if let Some(dst) = r.dest.as_ref() {
+            req.attr(RTA_DST, dst.as_slice());
+        }
+
+        if let Some(gw) = r.gateway.as_ref() {
+            req.attr(RTA_GATEWAY, gw.as_slice());
+        }
+
+        if r.priority > 0 {
+            req.attr_u32(RTA_PRIORITY, r.priority);
+        }
 
-            addattr32(nlh, RTA_OIF, r.index as u32);
+        if r.mtu > 0 {
+            let mx = req.nest_start(RTA_METRICS);
+            req.attr_u32(RTAX_MTU, r.mtu);
+            req.nest_end(mx);
+        }
 
-            self.rtnl_talk(v.as_mut_slice(), false)?;
+        if r.multipath.is_empty() {
+            req.attr_u32(RTA_OIF, r.index as u32);
+        } else {
+            addattr_multipath(&mut req, &r.multipath);
         }
+
+        self.rtnl_talk(req, false)?;
+
         Ok(())
     }
 
     fn delete_one_route(&mut self, r: &RtRoute) -> Result<()> {
         info!(sl!(), "delete route");
-        let mut v: Vec<u8> = vec![0; 2048];
-        unsafe {
-            let mut nlh: *mut nlmsghdr = v.as_mut_ptr() as *mut nlmsghdr;
-            let mut rtm: *mut rtmsg = NLMSG_DATA!(nlh) as *mut rtmsg;
-
-            (*nlh).nlmsg_len = NLMSG_LENGTH!(mem::size_of::<rtmsg>()) as u32;
-            (*nlh).nlmsg_type = RTM_DELROUTE;
-            (*nlh).nlmsg_flags = NLM_F_REQUEST;
-
-            self.seq += 1;
-            self.dump = self.seq;
-            (*nlh).nlmsg_seq = self.seq;
-
-            (*rtm).rtm_family = libc::AF_INET as u8;
-            (*rtm).rtm_table = RT_TABLE_MAIN as u8;
-            (*rtm).rtm_scope = RT_SCOPE_NOWHERE;
-
-            (*rtm).rtm_dst_len = r.dst_len;
-            (*rtm).rtm_src_len = r.src_len;
-            (*rtm).rtm_scope = r.scope;
-
-            if r.source.is_some() {
-                let len = r.source.as_ref().unwrap().len();
-                if r.src_len > 0 {
-                    addattr_var(
-                        nlh,
-                        RTA_SRC,
-                        r.source.as_ref().unwrap().as_ptr() as *const u8,
-                        len,
-                    );
-                } else {
-                    addattr_var(
-                        nlh,
-                        RTA_PREFSRC,
-                        r.source.as_ref().unwrap().as_ptr() as *const u8,
-                        len,
-                    );
-                }
-            }
 
-            if r.dest.is_some() {
-                let len = r.dest.as_ref().unwrap().len();
-                addattr_var(
-                    nlh,
-                    RTA_DST,
-                    r.dest.as_ref().unwrap().as_ptr() as *const u8,
-                    len,
-                );
-            }
+        let mut rtm = rtmsg::default();
+        rtm.rtm_family = libc::AF_INET as u8;
+        rtm.rtm_table = RT_TABLE_MAIN as u8;
+
+        rtm.rtm_dst_len = r.dst_len;
+        rtm.rtm_src_len = r.src_len;
+        rtm.rtm_scope = r.scope;
 
-            if r.gateway.is_some() {
-                let len = r.gateway.as_ref().unwrap().len();
-                addattr_var(
-                    nlh,
-                    RTA_GATEWAY,
-                    r.gateway.as_ref().unwrap().as_ptr() as *const u8,
-                    len,
-                );
+        let mut req = NlMsgBuilder::new(RTM_DELROUTE, NLM_F_REQUEST);
+        req.push(&rtm);
+
+        if let Some(src) = r.source.as_ref() {
+            if r.src_len > 0 {
+                req.attr(RTA_SRC, src.as_slice());
+            } else {
+                req.attr(RTA_PREFSRC, src.as_slice());
             }
-            addattr32(nlh, RTA_OIF, r.index as u32);
+        }
+
+        if let Some(dst) = r.dest.as_ref() {
+            req.attr(RTA_DST, dst.as_slice());
+        }
 
-            self.rtnl_talk(v.as_mut_slice(), false)?;
+        if let Some(gw) = r.gateway.as_ref() {
+            req.attr(RTA_GATEWAY, gw.as_slice());
         }
 
+        if r.priority > 0 {
+            req.attr_u32(RTA_PRIORITY, r.priority);
+        }
+
+        if r.multipath.is_empty() {
+            req.attr_u32(RTA_OIF, r.index as u32);
+        }
+
+        self.rtnl_talk(req, false)?;
+
         Ok(())
     }
 
     pub fn update_routes(&mut self, rt: &Vec<Route>) -> Result<Vec<Route>> {
+        // all the routes are converted before the current ones are deleted,
+        // so that one which does not convert leaves these in place
+        let mut link_routes = Vec::new();
+        let mut gateway_routes = Vec::new();
+        for grpcroute in rt {
+            let r = match self.rt_route(grpcroute)? {
+                Some(r) => r,
+                None => continue,
+            };
+
+            // routes with gateways, multipath included, have to go
+            // after the link routes they depend on
+            if grpcroute.gateway.as_str() == "" && grpcroute.multipath.is_empty() {
+                link_routes.push(r);
+            } else {
+                gateway_routes.push(r);
+            }
+        }
+
         let rs = self.get_all_routes()?;
         self.delete_all_routes(&rs)?;
 
-        for grpcroute in rt {
-            if grpcroute.gateway.as_str() == "" {
-                let r = RtRoute::from(grpcroute.clone());
-                if r.index == -1 {
-                    continue;
-                }
-                self.add_one_route(&r)?;
-            }
+        for r in link_routes.iter().chain(gateway_routes.iter()) {
+            self.add_one_route(r)?;
         }
 
-        for grpcroute in rt {
-            if grpcroute.gateway.as_str() != "" {
-                let r = RtRoute::from(grpcroute.clone());
-                if r.index == -1 {
-                    continue;
-                }
-                self.add_one_route(&r)?;
+        Ok(rt.clone())
+    }
+
+    // rt_route converts r into the RtRoute to add, None when a device of
+    // it, that of the route or that of a nexthop, is not there. An address
+    // which does not parse is an error.
+    fn rt_route(&mut self, r: &Route) -> Result<Option<RtRoute>> {
+        // for multipath routes, the device comes with each nexthop
+        let index = if r.multipath.is_empty() {
+            match self.find_link_by_name(r.device.as_str()) {
+                Ok(ifi) => ifi.ifi_index,
+                Err(_) => return Ok(None),
             }
+        } else {
+            0
+        };
+
+        let mut multipath: Vec<RtNexthop> = Vec::new();
+        for nh in r.multipath.iter() {
+            let ifi = match self.find_link_by_name(nh.device.as_str()) {
+                Ok(ifi) => ifi,
+                Err(_) => return Ok(None),
+            };
+
+            multipath.push(RtNexthop {
+                gateway: if nh.gateway.is_empty() {
+                    None
+                } else {
+                    Some(
+                        parse_ipaddr(nh.gateway.as_str())
+                            .chain_err(|| format!("invalid gateway {}", nh.gateway))?,
+                    )
+                },
+                index: ifi.ifi_index,
+                hops: (nh.weight.max(1).min(256) - 1) as u8,
+                flags: if nh.onlink { RTNH_F_ONLINK } else { 0 },
+            });
         }
 
-        Ok(rt.clone())
+        let (dest, dst_len) = if r.dest.is_empty() {
+            (Some(vec![0 as u8; 4]), 0)
+        } else {
+            let (dst, mask) = parse_cider(r.dest.as_str())
+                .chain_err(|| format!("invalid destination {}", r.dest))?;
+            (Some(dst), mask)
+        };
+
+        let (source, src_len) = if r.source.is_empty() {
+            (None, 0)
+        } else {
+            let (src, mask) = parse_cider(r.source.as_str())
+                .chain_err(|| format!("invalid source {}", r.source))?;
+            (Some(src), mask)
+        };
+
+        let gateway = if r.gateway.is_empty() {
+            None
+        } else {
+            Some(
+                parse_ipaddr(r.gateway.as_str())
+                    .chain_err(|| format!("invalid gateway {}", r.gateway))?,
+            )
+        };
+
+        Ok(Some(RtRoute {
+            dest,
+            source,
+            src_len,
+            dst_len,
+            index,
+            gateway,
+            scope: r.scope as u8,
+            protocol: r.protocol as u8,
+            priority: r.metric,
+            mtu: r.mtu,
+            flags: if r.onlink { RTNH_F_ONLINK as u32 } else { 0 },
+            multipath,
+        }))
     }
+
     pub fn handle_localhost(&mut self) -> Result<()> {
         let ifi = self.find_link_by_name("lo")?;
 
         self.set_link_status(&ifi, true)
     }
-}
 
-unsafe fn parse_attrs(
-    mut rta: *mut rtattr,
-    mut rtalen: u32,
-    max: usize,
-) -> Result<Vec<*const rtattr>> {
-    let mut attrs: Vec<*const rtattr> = vec![0 as *const rtattr; max as usize];
+    // parse_events converts the notifications received by a handle bound
+    // to RTMGRP_* groups into NetworkEvents. self is only used to look up
+    // device names, so it must not be the handle receiving the notifications.
+    pub fn parse_events(&mut self, buf: &[u8]) -> Result<Vec<NetworkEvent>> {
+        let mut events: Vec<NetworkEvent> = Vec::new();
 
-    while RTA_OK!(rta, rtalen) {
-        let rtype = (*rta).rta_type as usize;
+        for m in parse_messages(buf) {
+            let m = m?;
+            let mut ev: NetworkEvent = NetworkEvent::default();
 
-        if rtype < max && attrs[rtype] as i64 == 0 {
-            attrs[rtype] = rta as *const rtattr;
-        }
+            ev.deleted = m.msg_type == RTM_DELLINK
+                || m.msg_type == RTM_DELADDR
+                || m.msg_type == RTM_DELROUTE;
 
-        rta = RTA_NEXT!(rta, rtalen)
-    }
+            match m.msg_type {
+                RTM_NEWLINK | RTM_DELLINK => {
+                    ev.kind = NetworkEvent_Kind::LINK;
+                    ev.set_interface(parse_link_msg(&m)?);
+                    events.push(ev);
+                }
+                RTM_NEWADDR | RTM_DELADDR => {
+                    let ifa: ifaddrmsg = m.header()?;
+                    let mut iface: Interface = Interface::default();
 
-    Ok(attrs)
-}
+                    iface.name = self
+                        .get_name_by_index(ifa.ifa_index as i32)
+                        .unwrap_or("unknown".to_string());
+                    iface.IPAddresses.push(parse_addr_msg(&m)?);
 
-unsafe fn addattr_var(mut nlh: *mut nlmsghdr, cat: u16, data: *const u8, len: usize) {
-    let mut rta: *mut rtattr = NLMSG_TAIL!(nlh) as *mut rtattr;
-    let alen = RTA_LENGTH!(len) as u16;
+                    ev.kind = NetworkEvent_Kind::ADDRESS;
+                    ev.set_interface(iface);
+                    events.push(ev);
+                }
+                RTM_NEWROUTE | RTM_DELROUTE => {
+                    if let Some(route) = self.parse_route_msg(&m)? {
+                        ev.kind = NetworkEvent_Kind::ROUTE;
+                        ev.set_route(route);
+                        events.push(ev);
+                    }
+                }
+                _ => {}
+            }
+        }
 
-    (*rta).rta_type = cat;
-    (*rta).rta_len = alen;
+        Ok(events)
+    }
+}
 
-    if len > 0 {
-        libc::memcpy(
-            RTA_DATA!(rta) as *mut libc::c_void,
-            data as *const libc::c_void,
-            len,
-        );
+// check_ack turns a NLMSG_ERROR message into an error, the extended
+// ack message the kernel may attach is only logged.
+fn check_ack(m: &NlMsg) -> Result<()> {
+    if let Err(e) = m.ack() {
+        info!(sl!(), "netlink request failed"; "error" => format!("{}", e));
+        return Err(e.into());
     }
 
-    (*nlh).nlmsg_len = NLMSG_ALIGN!((*nlh).nlmsg_len) + RTA_ALIGN!(alen);
+    Ok(())
 }
 
-unsafe fn addattr_str(mut nlh: *mut nlmsghdr, cat: u16, data: &str) {
-    let mut rta: *mut rtattr = NLMSG_TAIL!(nlh) as *mut rtattr;
-    let len = data.len();
-    let alen = RTA_LENGTH!(len + 1) as u16;
-    let tp: *mut libc::c_void = RTA_DATA!(rta) as *mut libc::c_void;
+// parse_link_msg fills out an Interface from a RTM_NEWLINK or RTM_DELLINK
+// message, without its IP addresses.
+fn parse_link_msg(m: &NlMsg) -> Result<Interface> {
+    let ifi: ifinfomsg = m.header()?;
+    let attrs = m.attrs::<ifinfomsg>()?;
 
-    (*rta).rta_type = cat;
-    (*rta).rta_len = alen;
+    let mut iface: Interface = Interface::default();
 
-    libc::memcpy(
-        tp,
-        data.as_ptr() as *const libc::c_void,
-        len as libc::size_t,
-    );
+    if let Some(t) = attrs.get(IFLA_IFNAME) {
+        iface.name = t.as_string()?;
+    }
 
-    (*nlh).nlmsg_len = NLMSG_ALIGN!((*nlh).nlmsg_len) + RTA_ALIGN!(alen);
-}
+    if let Some(t) = attrs.get(IFLA_MTU) {
+        iface.mtu = t.as_u32()? as u64;
+    }
 
-unsafe fn addattr_size(mut nlh: *mut nlmsghdr, cat: u16, val: u64, size: u8) {
-    assert_eq!(size == 1 || size == 2 || size == 4 || size == 8, true);
+    if let Some(t) = attrs.get(IFLA_ADDRESS) {
+        iface.hwAddr = format_address(t.data)?;
+    }
 
-    let mut rta: *mut rtattr = NLMSG_TAIL!(nlh) as *mut rtattr;
-    (*rta).rta_type = cat;
+    // IFF_UP, IFF_RUNNING, etc.
+    iface.raw_flags = ifi.ifi_flags;
+    iface.index = ifi.ifi_index;
 
-    if size == 1 {
-        let data: *mut u8 = RTA_DATA!(rta) as *mut u8;
-        *data = val as u8;
-        let len = RTA_LENGTH!(1) as u16;
-        (*rta).rta_len = len;
+    if let Some(t) = attrs.get(IFLA_OPERSTATE) {
+        iface.oper_state = format_oper_state(t.as_u8()?).to_string();
     }
 
-    if size == 2 {
-        let data: *mut u16 = RTA_DATA!(rta) as *mut u16;
-        *data = val as u16;
-        let len = RTA_LENGTH!(2) as u16;
-        (*rta).rta_len = len;
+    if let Some(t) = attrs.get(IFLA_CARRIER) {
+        iface.carrier = t.as_u8()? != 0;
     }
 
-    if size == 4 {
-        let data: *mut u32 = RTA_DATA!(rta) as *mut u32;
-        *data = val as u32;
-        let len = RTA_LENGTH!(4) as u16;
-        (*rta).rta_len = len;
+    if let Some(t) = attrs.get(IFLA_TXQLEN) {
+        iface.txqlen = t.as_u32()?;
     }
 
-    if size == 8 {
-        let data: *mut u64 = RTA_DATA!(rta) as *mut u64;
-        *data = val as u64;
-        let len = RTA_LENGTH!(8) as u16;
-        (*rta).rta_len = len;
+    if let Some(t) = attrs.get(IFLA_MASTER) {
+        iface.master_index = t.as_i32()?;
     }
 
-    (*nlh).nlmsg_len = NLMSG_ALIGN!((*nlh).nlmsg_len) + RTA_ALIGN!((*rta).rta_len);
-}
+    if let Some(t) = attrs.get(IFLA_LINKINFO) {
+        if let Some(kind) = t.nested()?.get(IFLA_INFO_KIND) {
+            iface.field_type = kind.as_string()?;
+        }
+    }
 
-unsafe fn addattr8(nlh: *mut nlmsghdr, cat: u16, val: u8) {
-    addattr_size(nlh, cat, val as u64, 1);
-}
+    if let Some(t) = attrs.get(IFLA_STATS64) {
+        // older kernels send fewer counters, the missing ones stay zero
+        let mut buf = [0u8; mem::size_of::<rtnl_link_stats64>()];
+        let len = t.data.len().min(buf.len());
+        buf[..len].copy_from_slice(&t.data[..len]);
+
+        let st: rtnl_link_stats64 = read_struct(&buf)?;
+        let mut stats = InterfaceStatistics::new();
+        stats.rx_packets = st.rx_packets;
+        stats.tx_packets = st.tx_packets;
+        stats.rx_bytes = st.rx_bytes;
+        stats.tx_bytes = st.tx_bytes;
+        stats.rx_errors = st.rx_errors;
+        stats.tx_errors = st.tx_errors;
+        stats.rx_dropped = st.rx_dropped;
+        stats.tx_dropped = st.tx_dropped;
+        stats.multicast = st.multicast;
+        stats.collisions = st.collisions;
+        iface.set_statistics(stats);
+    }
+
+    Ok(iface)
+}
+
+fn format_oper_state(state: u8) -> &'static str {
+    match state {
+        IF_OPER_NOTPRESENT => "notpresent",
+        IF_OPER_DOWN => "down",
+        IF_OPER_LOWERLAYERDOWN => "lowerlayerdown",
+        IF_OPER_TESTING => "testing",
+        IF_OPER_DORMANT => "dormant",
+        IF_OPER_UP => "up",
+        _ => "unknown",
+    }
+}
+
+// get_link_device returns the driver and the PCI address, written as
+// "bridgeAddr/deviceAddr", of the device behind interface name. Both are
+// empty for virtual interfaces, which have no device.
+fn get_link_device(sysfs_net: &str, name: &str) -> (String, String) {
+    let device = Path::new(sysfs_net).join(name).join("device");
+
+    let driver = fs::read_link(device.join("driver"))
+        .ok()
+        .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_default();
+
+    // the PCI devices between the root bus and the interface, virtio
+    // adding one more level which is not PCI
+    let pci_addr = match fs::canonicalize(&device) {
+        Ok(path) => path
+            .iter()
+            .filter_map(|c| c.to_str().and_then(pci_slot))
+            .collect::<Vec<&str>>()
+            .join("/"),
+        Err(_) => String::new(),
+    };
 
-unsafe fn addattr16(nlh: *mut nlmsghdr, cat: u16, val: u16) {
-    addattr_size(nlh, cat, val as u64, 2);
+    (driver, pci_addr)
 }
 
-unsafe fn addattr32(nlh: *mut nlmsghdr, cat: u16, val: u32) {
-    addattr_size(nlh, cat, val as u64, 4);
-}
+// pci_slot returns the slot of a "domain:bus:slot.function" PCI address.
+fn pci_slot(addr: &str) -> Option<&str> {
+    let fields: Vec<&str> = addr.split(|c| c == ':' || c == '.').collect();
+    let hex = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_hexdigit());
+
+    if fields.len() == 4
+        && hex(fields[0], 4)
+        && hex(fields[1], 2)
+        && hex(fields[2], 2)
+        && hex(fields[3], 1)
+    {
+        return Some(fields[2]);
+    }
 
-unsafe fn addattr64(nlh: *mut nlmsghdr, cat: u16, val: u64) {
-    addattr_size(nlh, cat, val, 8);
+    None
 }
 
-unsafe fn getattr_var(rta: *const rtattr) -> Vec<u8> {
-    assert_ne!(rta as i64, 0);
-    let data: *const libc::c_void = RTA_DATA!(rta) as *const libc::c_void;
-    let alen: usize = RTA_PAYLOAD!(rta) as usize;
+// parse_addr_msg gets the IPAddress of a RTM_NEWADDR or RTM_DELADDR message.
+fn parse_addr_msg(m: &NlMsg) -> Result<IPAddress> {
+    let ifa: ifaddrmsg = m.header()?;
+    let attrs = m.attrs::<ifaddrmsg>()?;
+
+    let t = match attrs.get(IFA_ADDRESS).or_else(|| attrs.get(IFA_LOCAL)) {
+        Some(t) => t,
+        None => return Err(ErrorKind::Nix(nix::Error::Sys(Errno::EINVAL)).into()),
+    };
+
+    let mut one: IPAddress = IPAddress::default();
+
+    one.mask = format!("{}", ifa.ifa_prefixlen);
+    one.family = IPFamily::v4;
 
-    let mut v: Vec<u8> = vec![0; alen];
-    let tp: *mut libc::c_void = v.as_mut_ptr() as *mut libc::c_void;
+    if ifa.ifa_family == libc::AF_INET6 as u8 {
+        one.family = IPFamily::v6;
+    }
 
-    libc::memcpy(tp, data, alen as libc::size_t);
+    one.address = format_address(t.data)?;
 
-    v
+    Ok(one)
 }
 
-unsafe fn getattr_size(rta: *const rtattr) -> u64 {
-    let alen: usize = RTA_PAYLOAD!(rta) as usize;
-    assert!(alen == 1 || alen == 2 || alen == 4 || alen == 8);
-    let tp: *const u8 = RTA_DATA!(rta) as *const u8;
+// parse_rt_route converts a route message into the RtRoute needed to
+// delete it, routes out of the main table are skipped.
+fn parse_rt_route(m: &NlMsg) -> Result<Option<RtRoute>> {
+    let rtm: rtmsg = m.header()?;
 
-    if alen == 1 {
-        let data: *const u8 = tp as *const u8;
-        return *data as u64;
+    if rtm.rtm_table != RT_TABLE_MAIN as u8 {
+        return Ok(None);
     }
 
-    if alen == 2 {
-        let data: *const u16 = tp as *const u16;
-        return *data as u64;
+    let attrs = m.attrs::<rtmsg>()?;
+
+    if let Some(t) = attrs.get(RTA_TABLE) {
+        if t.as_u32()? != RT_TABLE_MAIN {
+            return Ok(None);
+        }
     }
 
-    if alen == 4 {
-        let data: *const u32 = tp as *const u32;
-        return *data as u64;
+    let mut rte: RtRoute = RtRoute::default();
+
+    rte.dst_len = rtm.rtm_dst_len;
+    rte.src_len = rtm.rtm_src_len;
+    rte.protocol = rtm.rtm_protocol;
+    rte.scope = rtm.rtm_scope;
+    rte.flags = rtm.rtm_flags;
+
+    rte.dest = attrs.get(RTA_DST).map(|t| t.data.to_vec());
+    rte.gateway = attrs.get(RTA_GATEWAY).map(|t| t.data.to_vec());
+    rte.source = attrs
+        .get(RTA_SRC)
+        .or_else(|| attrs.get(RTA_PREFSRC))
+        .map(|t| t.data.to_vec());
+
+    // metric
+    if let Some(t) = attrs.get(RTA_PRIORITY) {
+        rte.priority = t.as_u32()?;
     }
 
-    if alen == 8 {
-        let data: *const u64 = tp as *const u64;
-        return *data;
+    // multipath nexthops
+    if let Some(t) = attrs.get(RTA_MULTIPATH) {
+        rte.multipath = parse_multipath(t.data)?;
     }
 
-    panic!("impossible!");
-}
+    // default routes have no destination
+    if rte.dest.is_none() && (rte.gateway.is_some() || !rte.multipath.is_empty()) {
+        rte.dest = Some(vec![0 as u8; 4]);
+    }
 
-unsafe fn getattr8(rta: *const rtattr) -> u8 {
-    let alen = RTA_PAYLOAD!(rta);
-    assert!(alen == 1);
-    getattr_size(rta) as u8
-}
+    // oif
+    if let Some(t) = attrs.get(RTA_OIF) {
+        rte.index = t.as_i32()?;
+    }
 
-unsafe fn getattr16(rta: *const rtattr) -> u16 {
-    let alen = RTA_PAYLOAD!(rta);
-    assert!(alen == 2);
-    getattr_size(rta) as u16
+    Ok(Some(rte))
 }
 
-unsafe fn getattr32(rta: *const rtattr) -> u32 {
-    let alen = RTA_PAYLOAD!(rta);
-    assert!(alen == 4);
-    getattr_size(rta) as u32
-}
+fn addattr_multipath(req: &mut NlMsgBuilder, nhs: &[RtNexthop]) {
+    let mp = req.nest_start(RTA_MULTIPATH);
 
-unsafe fn getattr64(rta: *const rtattr) -> u64 {
-    let alen = RTA_PAYLOAD!(rta);
-    assert!(alen == 8);
-    getattr_size(rta)
-}
+    for nh in nhs {
+        let mut rtnh = rtnexthop::default();
+        rtnh.rtnh_flags = nh.flags;
+        rtnh.rtnh_hops = nh.hops;
+        rtnh.rtnh_ifindex = nh.index;
 
-unsafe fn format_address(addr: *const u8, len: u32) -> Result<String> {
-    let mut a: String;
-    if len == 4 {
-        // ipv4
-        let mut i = 1;
-        let mut p = addr as i64;
+        let start = req.push(&rtnh);
 
-        a = format!("{}", *(p as *const u8));
-        while i < len {
-            p += 1;
-            i += 1;
-            a.push_str(format!(".{}", *(p as *const u8)).as_str());
+        if let Some(gw) = nh.gateway.as_ref() {
+            req.attr(RTA_GATEWAY, gw.as_slice());
         }
 
-        return Ok(a);
+        req.nest_end(start);
     }
 
-    if len == 6 {
-        // hwaddr
-        let mut i = 1;
-        let mut p = addr as i64;
+    req.nest_end(mp);
+}
 
-        a = format!("{:0<2X}", *(p as *const u8));
-        while i < len {
-            p += 1;
-            i += 1;
-            a.push_str(format!(":{:0<2X}", *(p as *const u8)).as_str());
+// parse_multipath parses the rtnexthop records of a RTA_MULTIPATH attribute
+fn parse_multipath(mut data: &[u8]) -> Result<Vec<RtNexthop>> {
+    let mut nhs: Vec<RtNexthop> = Vec::new();
+    let hdrlen = mem::size_of::<rtnexthop>();
+
+    while !data.is_empty() {
+        let rtnh: rtnexthop = read_struct(data)?;
+        let len = rtnh.rtnh_len as usize;
+
+        if len < hdrlen {
+            return Err(NlError::BadLength(len).into());
         }
 
-        return Ok(a);
-    }
+        if len > data.len() {
+            return Err(NlError::Truncated {
+                need: len,
+                have: data.len(),
+            }
+            .into());
+        }
 
-    if len == 16 {
-        // ipv6
-        let p = addr as *const u8 as *const libc::c_void;
-        let mut ar: [u8; 16] = [0; 16];
-        let mut v: Vec<u8> = vec![0; 16];
-        let dp: *mut libc::c_void = v.as_mut_ptr() as *mut libc::c_void;
-        libc::memcpy(dp, p, 16);
+        let attrs = NlAttrs::parse(&data[hdrlen..len])?;
 
-        ar.copy_from_slice(v.as_slice());
+        nhs.push(RtNexthop {
+            gateway: attrs.get(RTA_GATEWAY).map(|t| t.data.to_vec()),
+            index: rtnh.rtnh_ifindex,
+            hops: rtnh.rtnh_hops,
+            flags: rtnh.rtnh_flags,
+        });
 
-        return Ok(Ipv6Addr::from(ar).to_string());
+        data = &data[nl_align(len).min(data.len())..];
+    }
 
-        /*
-            let l = len / 2;
+    Ok(nhs)
+}
 
-            a = format!("{:0<4X}", *(p as *const u16));
+fn format_address(addr: &[u8]) -> Result<String> {
+    match addr.len() {
+        // ipv4
+        4 => Ok(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]).to_string()),
+        // hwaddr
+        6 => Ok(addr
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<String>>()
+            .join(":")),
+        // ipv6
+        16 => {
+            let mut ar: [u8; 16] = [0; 16];
+            ar.copy_from_slice(addr);
 
-            while i < l {
-                p += 2;
-                i += 1;
-                a.push_str(format!(":{:0<4X}", *(p as *const u16)).as_str());
-            }
-        */
+            Ok(Ipv6Addr::from(ar).to_string())
+        }
+        _ => Err(ErrorKind::Nix(nix::Error::Sys(Errno::EINVAL)).into()),
     }
-
-    return Err(ErrorKind::Nix(nix::Error::Sys(Errno::EINVAL)).into());
 }
 
 impl Drop for RtnlHandle {
@@ -2705,6 +2469,18 @@ impl Drop for RtnlHandle {
     }
 }
 
+#[derive(Default)]
+pub struct RtNexthop {
+    pub gateway: Option<Vec<u8>>,
+    pub index: i32,
+    // weight - 1, as rtnh_hops
+    pub hops: u8,
+    pub flags: u8,
+}
+
+// cannot be zeroed as the other netlink structs,
+// multipath is a Vec
+#[derive(Default)]
 pub struct RtRoute {
     pub dest: Option<Vec<u8>>,
     pub source: Option<Vec<u8>>,
@@ -2714,12 +2490,10 @@ pub struct RtRoute {
     pub dst_len: u8,
     pub src_len: u8,
     pub protocol: u8,
-}
-
-impl Default for RtRoute {
-    fn default() -> Self {
-        unsafe { mem::zeroed::<Self>() }
-    }
+    pub priority: u32,
+    pub mtu: u32,
+    pub flags: u32,
+    pub multipath: Vec<RtNexthop>,
 }
 
 fn parse_cidripv4(s: &str) -> Result<(Vec<u8>, u8)> {
@@ -2754,58 +2528,6 @@ fn parse_cider(s: &str) -> Result<(Vec<u8>, u8)> {
     Ok((parse_ipaddr(addr.as_str())?, mask))
 }
 
-impl From<Route> for RtRoute {
-    fn from(r: Route) -> Self {
-        // only handle ipv4
-
-        let index = {
-            let mut rh = RtnlHandle::new(NETLINK_ROUTE, 0).unwrap();
-            match rh.find_link_by_name(r.device.as_str()) {
-                Ok(ifi) => ifi.ifi_index,
-                Err(_) => -1,
-            }
-        };
-
-        let (dest, dst_len) = if r.dest.is_empty() {
-            (Some(vec![0 as u8; 4]), 0)
-        } else {
-            let (dst, mask) = parse_cider(r.dest.as_str()).unwrap();
-            (Some(dst), mask)
-        };
-
-        let (source, src_len) = if r.source.is_empty() {
-            (None, 0)
-        } else {
-            let (src, mask) = parse_cider(r.source.as_str()).unwrap();
-            (Some(src), mask)
-        };
-
-        let gateway = if r.gateway.is_empty() {
-            None
-        } else {
-            Some(parse_ipaddr(r.gateway.as_str()).unwrap())
-        };
-
-        /*
-                let (dest, dst_len) = if gateway.is_some() {
-                    (vec![0 as u8; 4], 0)
-                } else {
-                    (tdest, tdst_len)
-                };
-        */
-        Self {
-            dest,
-            source,
-            src_len,
-            dst_len,
-            index,
-            gateway,
-            scope: r.scope as u8,
-            protocol: RTPROTO_UNSPEC,
-        }
-    }
-}
-
 pub struct RtIPAddr {
     pub ip_family: __u8,
     pub ip_mask: __u8,
@@ -2834,9 +2556,91 @@ impl From<IPAddress> for RtIPAddr {
 
 #[cfg(test)]
 mod tests {
-    use crate::netlink::{nlmsghdr, NLMSG_ALIGNTO, RTA_ALIGNTO, RTM_BASE};
+    use crate::netlink::{
+        addattr_multipath, format_address, get_link_device, ifinfomsg, nlmsghdr, parse_addr_msg,
+        parse_link_msg, parse_multipath, parse_rt_route, pci_slot, rtmsg, rtnl_link_stats64,
+        RtNexthop, RtnlHandle, IFLA_CARRIER, IFLA_IFNAME, IFLA_INFO_KIND, IFLA_LINKINFO,
+        IFLA_MASTER, IFLA_OPERSTATE, IFLA_STATS64, IF_OPER_LOWERLAYERDOWN, NETLINK_ROUTE,
+        NLMSG_ALIGNTO, NLM_F_REQUEST, RTA_ALIGNTO, RTA_MULTIPATH, RTM_BASE, RTM_NEWLINK,
+        RTM_NEWROUTE, RTNH_F_ONLINK, RTPROTO_BOOT, RTPROTO_KERNEL, RT_SCOPE_LINK,
+        RT_SCOPE_UNIVERSE,
+    };
+    use crate::nlmsg::{parse_messages, NlMsg, NlMsgBuilder};
     use libc;
+    use protocols::types::{IPFamily, Nexthop, Route};
+    use std::fs;
     use std::mem;
+    use std::os::unix::fs::symlink;
+    use tempfile::tempdir;
+
+    // Replies recorded from the kernel, eth0 having index 4 and
+    // 192.0.2.2/24 as address.
+
+    // RTM_NEWLINK for eth0, trimmed to the name, txqlen, operstate,
+    // mtu, address and broadcast attributes
+    const LINK_REPLY: [u8; 92] = [
+        0x5c, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x65, 0x00, 0x00, 0x00, 0xea, 0x1d, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x0d, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x05, 0x00, 0x10, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x78, 0x05, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x02, 0xfc, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x00,
+    ];
+
+    // RTM_GETADDR dump: 127.0.0.1/8 on lo, 192.0.2.2/24 on eth0
+    const ADDR_REPLY: [u8; 164] = [
+        0x4c, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00, 0x65, 0x00, 0x00, 0x00, 0x65, 0x1d, 0x00,
+        0x00, 0x02, 0x08, 0x80, 0xfe, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x7f, 0x00,
+        0x00, 0x01, 0x08, 0x00, 0x02, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x07, 0x00, 0x03, 0x00, 0x6c,
+        0x6f, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x80, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+        0x00, 0x58, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00, 0x65, 0x00, 0x00, 0x00, 0x65, 0x1d,
+        0x00, 0x00, 0x02, 0x18, 0x80, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0xc0,
+        0x00, 0x02, 0x02, 0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08, 0x00, 0x04, 0x00,
+        0xc0, 0x00, 0x02, 0xff, 0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x08, 0x00, 0x80, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    ];
+
+    // RTM_GETROUTE dump: the main table holds a default route via
+    // 192.0.2.1 and the link route of eth0, the other ones belong to
+    // the local table
+    const ROUTE_REPLY: [u8; 412] = [
+        0x34, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00, 0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x08,
+        0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x18, 0x00, 0x00, 0xfe, 0x02, 0xfd,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0xc0, 0x00, 0x02, 0x00, 0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08,
+        0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x08, 0x00, 0x00, 0xff, 0x02, 0xfe,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xff, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0x7f, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08,
+        0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0xff, 0x02, 0xfe,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xff, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08, 0x00, 0x07, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08,
+        0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0xff, 0x02, 0xfd,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xff, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0x7f, 0xff, 0xff, 0xff, 0x08, 0x00, 0x07, 0x00, 0x7f, 0x00, 0x00, 0x01, 0x08,
+        0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0xff, 0x02, 0xfe,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xff, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08,
+        0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x8e, 0x1d, 0x00, 0x00, 0x02, 0x20, 0x00, 0x00, 0xff, 0x02, 0xfd,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0xff, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x01, 0x00, 0xc0, 0x00, 0x02, 0xff, 0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x08,
+        0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00,
+    ];
+
+    fn messages(buf: &[u8]) -> Vec<NlMsg> {
+        parse_messages(buf).map(|m| m.unwrap()).collect()
+    }
+
     #[test]
     fn test_macro() {
         println!("{}", RTA_ALIGN!(10));
@@ -2847,4 +2651,313 @@ mod tests {
             NLMSG_ALIGN!(mem::size_of::<nlmsghdr>() as libc::c_uint)
         );
     }
+
+    #[test]
+    fn test_multipath() {
+        let nhs = vec![
+            RtNexthop {
+                gateway: Some(vec![192, 168, 0, 1]),
+                index: 2,
+                hops: 0,
+                flags: RTNH_F_ONLINK,
+            },
+            RtNexthop {
+                gateway: Some(vec![192, 168, 1, 1]),
+                index: 3,
+                hops: 4,
+                flags: 0,
+            },
+            RtNexthop {
+                gateway: None,
+                index: 4,
+                hops: 1,
+                flags: 0,
+            },
+        ];
+
+        let mut req = NlMsgBuilder::new(RTM_NEWROUTE, NLM_F_REQUEST);
+        req.push(&rtmsg::default());
+        addattr_multipath(&mut req, &nhs);
+        let buf = req.finish().unwrap();
+
+        // nlmsghdr + rtmsg + rtattr + 2 * (rtnexthop + gateway) + rtnexthop
+        assert_eq!(
+            buf.len(),
+            mem::size_of::<nlmsghdr>() + mem::size_of::<rtmsg>() + 4 + 2 * (8 + 8) + 8
+        );
+
+        let msgs = messages(buf.as_slice());
+        let attrs = msgs[0].attrs::<rtmsg>().unwrap();
+        let mp = attrs.get(RTA_MULTIPATH).unwrap();
+
+        let parsed = parse_multipath(mp.data).unwrap();
+        assert_eq!(parsed.len(), nhs.len());
+
+        for (p, nh) in parsed.iter().zip(nhs.iter()) {
+            assert_eq!(p.gateway, nh.gateway);
+            assert_eq!(p.index, nh.index);
+            assert_eq!(p.hops, nh.hops);
+            assert_eq!(p.flags, nh.flags);
+        }
+
+        // rtnh_len beyond the attribute
+        let mut data = mp.data.to_vec();
+        data[0] = 0x30;
+        assert!(parse_multipath(data.as_slice()).is_err());
+
+        // rtnh_len shorter than rtnexthop
+        data[0] = 4;
+        assert!(parse_multipath(data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_format_address() {
+        assert_eq!(format_address(&[192, 0, 2, 1]).unwrap(), "192.0.2.1");
+        assert_eq!(
+            format_address(&[0x02, 0xfc, 0, 0, 0, 0x01]).unwrap(),
+            "02:FC:00:00:00:01"
+        );
+
+        let mut v6 = [0 as u8; 16];
+        v6[15] = 1;
+        assert_eq!(format_address(&v6).unwrap(), "::1");
+
+        assert!(format_address(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_parse_link_msg() {
+        let msgs = messages(&LINK_REPLY);
+        assert_eq!(msgs.len(), 1);
+
+        let iface = parse_link_msg(&msgs[0]).unwrap();
+        assert_eq!(iface.name, "eth0");
+        assert_eq!(iface.mtu, 1400);
+        assert_eq!(iface.hwAddr, "02:FC:00:00:00:01");
+        assert_eq!(
+            iface.raw_flags & (libc::IFF_UP | libc::IFF_RUNNING) as u32,
+            (libc::IFF_UP | libc::IFF_RUNNING) as u32
+        );
+        assert_eq!(iface.index, 4);
+        assert_eq!(iface.txqlen, 1000);
+        assert_eq!(iface.oper_state, "up");
+        assert!(!iface.has_statistics());
+    }
+
+    #[test]
+    fn test_parse_link_extended() {
+        let mut st = rtnl_link_stats64::default();
+        st.rx_packets = 1;
+        st.tx_bytes = 1 << 40;
+        st.collisions = 3;
+        st.rx_nohandler = 4;
+
+        let mut ifi = ifinfomsg::default();
+        ifi.ifi_index = 9;
+
+        let mut req = NlMsgBuilder::new(RTM_NEWLINK, 0);
+        req.push(&ifi);
+        req.attr_str(IFLA_IFNAME, "veth0");
+        req.attr_u8(IFLA_OPERSTATE, IF_OPER_LOWERLAYERDOWN);
+        req.attr_u8(IFLA_CARRIER, 1);
+        req.attr_u32(IFLA_MASTER, 7);
+        let info = req.nest_start(IFLA_LINKINFO);
+        req.attr_str(IFLA_INFO_KIND, "veth");
+        req.nest_end(info);
+        req.attr_struct(IFLA_STATS64, &st);
+        let buf = req.finish().unwrap();
+
+        let iface = parse_link_msg(&messages(&buf)[0]).unwrap();
+        assert_eq!(iface.index, 9);
+        assert_eq!(iface.name, "veth0");
+        assert_eq!(iface.oper_state, "lowerlayerdown");
+        assert!(iface.carrier);
+        assert_eq!(iface.master_index, 7);
+        assert_eq!(iface.field_type, "veth");
+
+        let stats = iface.get_statistics();
+        assert_eq!(stats.rx_packets, 1);
+        assert_eq!(stats.tx_bytes, 1 << 40);
+        assert_eq!(stats.collisions, 3);
+
+        // kernels before rx_nohandler send 23 counters only
+        let msgs = messages(&buf);
+        let attrs = msgs[0].attrs::<ifinfomsg>().unwrap();
+        let stats64 = attrs.get(IFLA_STATS64).unwrap();
+
+        let mut req = NlMsgBuilder::new(RTM_NEWLINK, 0);
+        req.push(&ifi);
+        req.attr(IFLA_STATS64, &stats64.data[..stats64.data.len() - 8]);
+        let short = req.finish().unwrap();
+
+        let iface = parse_link_msg(&messages(&short)[0]).unwrap();
+        assert_eq!(iface.get_statistics().collisions, 3);
+        assert_eq!(iface.oper_state, "");
+    }
+
+    #[test]
+    fn test_get_link_device() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        // eth0 is a virtio device behind a PCI bridge in slot 2, lo has
+        // no device at all
+        let pci = root.join("devices/pci0000:00/0000:00:02.0/0000:01:01.0");
+        let drv = root.join("bus/virtio/drivers/virtio_net");
+        let net = root.join("class/net");
+        fs::create_dir_all(pci.join("virtio1")).unwrap();
+        fs::create_dir_all(&drv).unwrap();
+        fs::create_dir_all(net.join("eth0")).unwrap();
+        fs::create_dir_all(net.join("lo")).unwrap();
+        symlink(&drv, pci.join("virtio1/driver")).unwrap();
+        symlink(pci.join("virtio1"), net.join("eth0/device")).unwrap();
+
+        let net = net.to_str().unwrap();
+        assert_eq!(
+            get_link_device(net, "eth0"),
+            ("virtio_net".to_string(), "02/01".to_string())
+        );
+        assert_eq!(get_link_device(net, "lo"), (String::new(), String::new()));
+        assert_eq!(
+            get_link_device(net, "missing"),
+            (String::new(), String::new())
+        );
+    }
+
+    #[test]
+    fn test_pci_slot() {
+        assert_eq!(pci_slot("0000:00:02.0"), Some("02"));
+        assert_eq!(pci_slot("0000:01:1f.7"), Some("1f"));
+        assert_eq!(pci_slot("pci0000:00"), None);
+        assert_eq!(pci_slot("virtio1"), None);
+        assert_eq!(pci_slot("0000:00:02.10"), None);
+    }
+
+    #[test]
+    fn test_parse_addr_msg() {
+        let addrs: Vec<(String, String)> = messages(&ADDR_REPLY)
+            .iter()
+            .map(|m| parse_addr_msg(m).unwrap())
+            .map(|a| {
+                assert_eq!(a.family, IPFamily::v4);
+                (a.address, a.mask)
+            })
+            .collect();
+
+        assert_eq!(
+            addrs,
+            vec![
+                ("127.0.0.1".to_string(), "8".to_string()),
+                ("192.0.2.2".to_string(), "24".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rt_route() {
+        let msgs = messages(&ROUTE_REPLY);
+        assert_eq!(msgs.len(), 7);
+
+        let rs: Vec<_> = msgs
+            .iter()
+            .filter_map(|m| parse_rt_route(m).unwrap())
+            .collect();
+        assert_eq!(rs.len(), 2);
+
+        // default via 192.0.2.1 dev eth0
+        assert_eq!(rs[0].dest, Some(vec![0; 4]));
+        assert_eq!(rs[0].dst_len, 0);
+        assert_eq!(rs[0].gateway, Some(vec![192, 0, 2, 1]));
+        assert_eq!(rs[0].index, 4);
+        assert_eq!(rs[0].protocol, RTPROTO_BOOT);
+        assert_eq!(rs[0].scope, RT_SCOPE_UNIVERSE);
+
+        // 192.0.2.0/24 dev eth0 proto kernel scope link src 192.0.2.2
+        assert_eq!(rs[1].dest, Some(vec![192, 0, 2, 0]));
+        assert_eq!(rs[1].dst_len, 24);
+        assert_eq!(rs[1].gateway, None);
+        assert_eq!(rs[1].source, Some(vec![192, 0, 2, 2]));
+        assert_eq!(rs[1].index, 4);
+        assert_eq!(rs[1].protocol, RTPROTO_KERNEL);
+        assert_eq!(rs[1].scope, RT_SCOPE_LINK);
+    }
+
+    // parse runs the parsers over buf, they may fail but not panic.
+    fn parse(buf: &[u8]) {
+        for m in parse_messages(buf) {
+            if let Ok(m) = m {
+                let _ = parse_link_msg(&m);
+                let _ = parse_addr_msg(&m);
+                let _ = parse_rt_route(&m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzz_replies() {
+        for reply in [&LINK_REPLY[..], &ADDR_REPLY[..], &ROUTE_REPLY[..]].iter() {
+            // truncated
+            for len in 0..reply.len() {
+                parse(&reply[..len]);
+            }
+
+            // corrupted
+            for i in 0..reply.len() {
+                for v in [0x00, 0x01, 0x03, 0x7f, 0x80, 0xff].iter() {
+                    let mut buf = reply.to_vec();
+                    buf[i] = *v;
+                    parse(buf.as_slice());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rt_route() {
+        let mut rtnl = RtnlHandle::new(NETLINK_ROUTE, 0).unwrap();
+        let lo = rtnl.find_link_by_name("lo").unwrap().ifi_index;
+
+        let route = |dest: &str, device: &str, nexthops: &[(&str, &str)]| {
+            let mut r = Route::new();
+            r.set_dest(dest.to_string());
+            r.set_device(device.to_string());
+            for (gateway, device) in nexthops {
+                let mut nh = Nexthop::new();
+                nh.set_gateway(gateway.to_string());
+                nh.set_device(device.to_string());
+                r.mut_multipath().push(nh);
+            }
+            r
+        };
+
+        let r = rtnl
+            .rt_route(&route("127.0.0.0/8", "lo", &[]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(r.index, lo);
+        assert_eq!(r.dst_len, 8);
+
+        // skipped by update_routes, multipath or not
+        assert!(rtnl
+            .rt_route(&route("", "nosuchdev", &[]))
+            .unwrap()
+            .is_none());
+        assert!(rtnl
+            .rt_route(&route("", "", &[("127.0.0.2", "lo"), ("", "nosuchdev")]))
+            .unwrap()
+            .is_none());
+
+        let r = rtnl
+            .rt_route(&route("", "", &[("127.0.0.2", "lo"), ("", "lo")]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(r.multipath.len(), 2);
+        assert_eq!(r.multipath[0].index, lo);
+        assert_eq!(r.multipath[0].gateway, Some(vec![127, 0, 0, 2]));
+
+        assert!(rtnl
+            .rt_route(&route("", "", &[("127.0.0.x", "lo")]))
+            .is_err());
+        assert!(rtnl.rt_route(&route("127.0.0/8", "lo", &[])).is_err());
+    }
 }
@@ -0,0 +1,129 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use crate::netlink::{
+    RtnlHandle, NETLINK_ROUTE, RTMGRP_IPV4_IFADDR, RTMGRP_IPV4_ROUTE, RTMGRP_IPV6_IFADDR,
+    RTMGRP_LINK,
+};
+use crate::sandbox::Sandbox;
+use nix::errno::Errno;
+use protocols::agent::NetworkEvent_Kind;
+use rustjail::errors::*;
+use slog::Logger;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Only IPv4 routes are tracked, as list_routes() does.
+const NETMON_GROUPS: u32 =
+    RTMGRP_LINK | RTMGRP_IPV4_IFADDR | RTMGRP_IPV6_IFADDR | RTMGRP_IPV4_ROUTE;
+
+// how long to wait after failing to receive the events, doubled up to the
+// maximum while the failures last
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+// refresh_network dumps the interfaces and/or the routes and stores
+// them in the sandbox network. Dumping rather than applying each event
+// keeps track of the changes the kernel does not notify, such as
+// routes flushed when a link goes down.
+fn refresh_network(
+    logger: &Logger,
+    sandbox: &Arc<Mutex<Sandbox>>,
+    rtnl: &mut RtnlHandle,
+    links: bool,
+    routes: bool,
+) {
+    if links {
+        match rtnl.list_interfaces() {
+            Ok(ifaces) => sandbox.lock().unwrap().network.set_interfaces(ifaces),
+            Err(e) => error!(logger, "failed to list interfaces"; "error" => format!("{}", e)),
+        }
+    }
+
+    if routes {
+        match rtnl.list_routes() {
+            Ok(rs) => sandbox.lock().unwrap().network.set_routes(rs),
+            Err(e) => error!(logger, "failed to list routes"; "error" => format!("{}", e)),
+        }
+    }
+}
+
+// watch_network_events listens to the link, address and route changes
+// done in the guest, keeps the sandbox network in sync and publishes
+// them to the GetNetworkEvents subscribers.
+pub fn watch_network_events(sandbox: Arc<Mutex<Sandbox>>) {
+    let logger = sandbox
+        .lock()
+        .unwrap()
+        .logger
+        .new(o!("subsystem" => "netmon"));
+
+    thread::spawn(move || {
+        let events = match RtnlHandle::new(NETLINK_ROUTE, NETMON_GROUPS) {
+            Ok(h) => h,
+            Err(e) => {
+                error!(logger, "failed to subscribe to netlink events"; "error" => format!("{}", e));
+                return;
+            }
+        };
+
+        // Requests go through their own handle, their replies must not
+        // be mixed with the notifications.
+        let mut rtnl = match RtnlHandle::new(NETLINK_ROUTE, 0) {
+            Ok(h) => h,
+            Err(e) => {
+                error!(logger, "failed to open netlink handle"; "error" => format!("{}", e));
+                return;
+            }
+        };
+
+        refresh_network(&logger, &sandbox, &mut rtnl, true, true);
+
+        let mut delay = RETRY_DELAY;
+        loop {
+            let data = match events.recv_message() {
+                Ok(data) => {
+                    delay = RETRY_DELAY;
+                    data
+                }
+                Err(e) => {
+                    if let ErrorKind::Nix(nix::Error::Sys(Errno::ENOBUFS)) = e.kind() {
+                        // some notifications were lost
+                        warn!(logger, "netlink events overrun");
+                        refresh_network(&logger, &sandbox, &mut rtnl, true, true);
+                        continue;
+                    }
+
+                    error!(logger, "receive netlink event failed";
+                        "error" => format!("{}", e),
+                        "retry-in" => format!("{:?}", delay));
+                    thread::sleep(delay);
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                    continue;
+                }
+            };
+
+            let evs = match rtnl.parse_events(data.as_slice()) {
+                Ok(evs) => evs,
+                Err(e) => {
+                    error!(logger, "failed to parse netlink event"; "error" => format!("{}", e));
+                    continue;
+                }
+            };
+
+            let links = evs.iter().any(|ev| ev.kind != NetworkEvent_Kind::ROUTE);
+            let routes = evs.iter().any(|ev| ev.kind == NetworkEvent_Kind::ROUTE);
+
+            refresh_network(&logger, &sandbox, &mut rtnl, links, routes);
+
+            let mut sb = sandbox.lock().unwrap();
+            for ev in evs.iter() {
+                info!(logger, "got network event"; "event" => format!("{:?}", ev));
+                sb.network.publish(ev);
+            }
+        }
+    });
+}
@@ -3,9 +3,14 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use futures::sync::mpsc::{self, Receiver, Sender};
+use protocols::agent::NetworkEvent;
 use protocols::types::{Interface, Route};
 use std::collections::HashMap;
 
+// how many network events a subscriber may fall behind
+const EVENTS_BUFFER: usize = 64;
+
 // Network fully describes a sandbox network with its interfaces, routes and dns
 // related information.
 #[derive(Debug, Default)]
@@ -13,6 +18,7 @@ pub struct Network {
     ifaces: HashMap<String, Interface>,
     routes: Vec<Route>,
     dns: Vec<String>,
+    event_senders: Vec<Sender<NetworkEvent>>,
 }
 
 impl Network {
@@ -21,10 +27,107 @@ impl Network {
             ifaces: HashMap::new(),
             routes: Vec::new(),
             dns: Vec::new(),
+            event_senders: Vec::new(),
         }
     }
 
     pub fn set_dns(&mut self, dns: String) {
         self.dns.push(dns);
     }
+
+    pub fn set_interfaces(&mut self, ifaces: Vec<Interface>) {
+        self.ifaces = ifaces
+            .into_iter()
+            .map(|iface| (iface.name.clone(), iface))
+            .collect();
+    }
+
+    pub fn set_routes(&mut self, routes: Vec<Route>) {
+        self.routes = routes;
+    }
+
+    // subscribe returns a receiver getting all the network events
+    // published from now on, until it falls behind.
+    pub fn subscribe(&mut self) -> Receiver<NetworkEvent> {
+        let (tx, rx) = mpsc::channel(EVENTS_BUFFER);
+        self.event_senders.push(tx);
+        rx
+    }
+
+    // publish sends the event to every subscriber. The ones which dropped
+    // their receiver, or whose buffer is full, are forgotten, so that
+    // their stream ends after the events it holds rather than missing
+    // some.
+    pub fn publish(&mut self, event: &NetworkEvent) {
+        let senders = std::mem::replace(&mut self.event_senders, Vec::new());
+        self.event_senders = senders
+            .into_iter()
+            .filter_map(|mut tx| tx.try_send(event.clone()).ok().map(|_| tx))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::Stream;
+    use protocols::agent::NetworkEvent_Kind;
+
+    #[test]
+    fn test_set_interfaces() {
+        let mut network = Network::new();
+
+        let mut eth0 = Interface::new();
+        eth0.set_name("eth0".to_string());
+        let mut lo = Interface::new();
+        lo.set_name("lo".to_string());
+
+        network.set_interfaces(vec![eth0.clone(), lo]);
+        assert_eq!(network.ifaces.len(), 2);
+        assert_eq!(network.ifaces.get("eth0"), Some(&eth0));
+
+        network.set_interfaces(vec![eth0]);
+        assert_eq!(network.ifaces.len(), 1);
+        assert!(network.ifaces.get("lo").is_none());
+    }
+
+    #[test]
+    fn test_publish() {
+        let mut network = Network::new();
+
+        let mut ev = NetworkEvent::new();
+        ev.set_kind(NetworkEvent_Kind::ROUTE);
+        ev.set_deleted(true);
+
+        // no subscriber
+        network.publish(&ev);
+
+        let rx1 = network.subscribe();
+        let rx2 = network.subscribe();
+        assert_eq!(network.event_senders.len(), 2);
+
+        drop(rx2);
+        network.publish(&ev);
+        assert_eq!(network.event_senders.len(), 1);
+
+        let mut events = rx1.wait();
+        assert_eq!(events.next(), Some(Ok(ev)));
+    }
+
+    #[test]
+    fn test_publish_slow_subscriber() {
+        let mut network = Network::new();
+        let ev = NetworkEvent::new();
+
+        let rx = network.subscribe();
+        let mut sent = 0;
+        while !network.event_senders.is_empty() {
+            network.publish(&ev);
+            sent += 1;
+        }
+        assert!(sent > EVENTS_BUFFER);
+
+        // what was buffered is received, then the stream ends
+        assert_eq!(rx.wait().count(), sent - 1);
+    }
 }
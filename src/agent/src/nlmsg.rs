@@ -0,0 +1,737 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Safe netlink message builder and parser.
+//
+// Messages are assembled in a growable buffer and replies are parsed
+// with bounds-checked reads, so that a short or corrupted message ends
+// up as an NlError instead of an out of bounds access. The only unsafe
+// code left is the copy of the fixed-size headers (ifinfomsg, rtmsg...)
+// from and to bytes, see NlStruct.
+
+use crate::netlink::{NLMSGERR_ATTR_MASG, NLM_F_ACK_TLVS, NLM_F_CAPPED};
+use nix::errno::Errno;
+use rustjail::errors::*;
+use std::fmt;
+use std::mem;
+use std::ptr;
+use std::slice;
+
+// sizeof(struct nlmsghdr) and sizeof(struct nlattr)
+pub const NL_HDR_LEN: usize = 16;
+pub const NLA_HDR_LEN: usize = 4;
+
+const NLA_F_NESTED: u16 = 1 << 15;
+const NLA_F_NET_BYTEORDER: u16 = 1 << 14;
+const NLA_TYPE_MASK: u16 = !(NLA_F_NESTED | NLA_F_NET_BYTEORDER);
+
+pub type NlResult<T> = std::result::Result<T, NlError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NlError {
+    // the buffer ends before the length announced by a header
+    Truncated { need: usize, have: usize },
+    // a length field smaller than the header it is part of
+    BadLength(usize),
+    // an attribute payload which does not have the size of its type
+    BadAttrSize { kind: u16, size: usize },
+    // a string attribute which is not valid UTF-8
+    BadString(u16),
+    // an attribute or a nest too long for its u16 length
+    TooLong(usize),
+    // the kernel rejected the request, msg is its extended ack, if any
+    Kernel { errno: Errno, msg: Option<String> },
+}
+
+impl fmt::Display for NlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NlError::Truncated { need, have } => {
+                write!(f, "truncated message: need {} bytes, have {}", need, have)
+            }
+            NlError::BadLength(len) => write!(f, "invalid length {}", len),
+            NlError::BadAttrSize { kind, size } => {
+                write!(f, "invalid size {} for attribute {}", size, kind)
+            }
+            NlError::BadString(kind) => write!(f, "attribute {} is not a valid string", kind),
+            NlError::TooLong(len) => write!(f, "attribute too long: {} bytes", len),
+            NlError::Kernel {
+                errno,
+                msg: Some(msg),
+            } => write!(f, "{}: {}", errno.desc(), msg),
+            NlError::Kernel { errno, msg: None } => write!(f, "{}", errno.desc()),
+        }
+    }
+}
+
+impl std::error::Error for NlError {}
+
+// Kernel errors keep their errno, as the rest of the agent matches
+// on ErrorKind::Nix.
+impl From<NlError> for Error {
+    fn from(e: NlError) -> Self {
+        match e {
+            NlError::Kernel { errno, .. } => ErrorKind::Nix(nix::Error::Sys(errno)).into(),
+            _ => ErrorKind::ErrorCode(format!("netlink: {}", e)).into(),
+        }
+    }
+}
+
+pub fn nl_align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+// NlStruct is implemented by the fixed-size headers carried by netlink
+// messages, which are copied from and to the message buffers.
+//
+// Safety: implementors must be repr(C), have no padding, and be valid
+// for any bit pattern.
+pub unsafe trait NlStruct: Copy + Default {}
+
+fn struct_bytes<T: NlStruct>(v: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>()) }
+}
+
+pub fn read_struct<T: NlStruct>(buf: &[u8]) -> NlResult<T> {
+    let size = mem::size_of::<T>();
+    if buf.len() < size {
+        return Err(NlError::Truncated {
+            need: size,
+            have: buf.len(),
+        });
+    }
+
+    Ok(unsafe { ptr::read_unaligned(buf.as_ptr() as *const T) })
+}
+
+// callers check the bounds
+fn u16_at(buf: &[u8], off: usize) -> u16 {
+    u16::from_ne_bytes([buf[off], buf[off + 1]])
+}
+
+fn u32_at(buf: &[u8], off: usize) -> u32 {
+    u32::from_ne_bytes([buf[off], buf[off + 1], buf[off + 2], buf[off + 3]])
+}
+
+// NlMsgBuilder assembles one netlink message. The buffer grows as
+// needed, nlmsg_len is filled in by finish() and the sequence number
+// is left to RtnlHandle.
+pub struct NlMsgBuilder {
+    buf: Vec<u8>,
+    // first error hit while building, reported by finish()
+    err: Option<NlError>,
+}
+
+impl NlMsgBuilder {
+    pub fn new(msg_type: u16, flags: u16) -> Self {
+        let mut buf: Vec<u8> = vec![0; NL_HDR_LEN];
+        buf[4..6].copy_from_slice(&msg_type.to_ne_bytes());
+        buf[6..8].copy_from_slice(&flags.to_ne_bytes());
+
+        Self { buf, err: None }
+    }
+
+    pub fn add_flags(&mut self, flags: u16) {
+        let flags = u16_at(&self.buf, 6) | flags;
+        self.buf[6..8].copy_from_slice(&flags.to_ne_bytes());
+    }
+
+    pub fn set_seq(&mut self, seq: u32) {
+        self.buf[8..12].copy_from_slice(&seq.to_ne_bytes());
+    }
+
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn fail(&mut self, e: NlError) {
+        if self.err.is_none() {
+            self.err = Some(e);
+        }
+    }
+
+    fn pad(&mut self) {
+        let len = nl_align(self.buf.len());
+        self.buf.resize(len, 0);
+    }
+
+    // push appends a fixed-size header and returns its offset
+    pub fn push<T: NlStruct>(&mut self, v: &T) -> usize {
+        let off = self.buf.len();
+        self.buf.extend_from_slice(struct_bytes(v));
+        self.pad();
+        off
+    }
+
+    pub fn attr(&mut self, kind: u16, data: &[u8]) {
+        let len = NLA_HDR_LEN + data.len();
+        if len > u16::max_value() as usize {
+            self.fail(NlError::TooLong(len));
+            return;
+        }
+
+        self.buf.extend_from_slice(&(len as u16).to_ne_bytes());
+        self.buf.extend_from_slice(&kind.to_ne_bytes());
+        self.buf.extend_from_slice(data);
+        self.pad();
+    }
+
+    pub fn attr_u8(&mut self, kind: u16, val: u8) {
+        self.attr(kind, &[val]);
+    }
+
+    pub fn attr_u16(&mut self, kind: u16, val: u16) {
+        self.attr(kind, &val.to_ne_bytes());
+    }
+
+    pub fn attr_u32(&mut self, kind: u16, val: u32) {
+        self.attr(kind, &val.to_ne_bytes());
+    }
+
+    pub fn attr_u64(&mut self, kind: u16, val: u64) {
+        self.attr(kind, &val.to_ne_bytes());
+    }
+
+    pub fn attr_struct<T: NlStruct>(&mut self, kind: u16, v: &T) {
+        self.attr(kind, struct_bytes(v));
+    }
+
+    // attr_str adds a NUL terminated string
+    pub fn attr_str(&mut self, kind: u16, s: &str) {
+        let mut data = s.as_bytes().to_vec();
+        data.push(0);
+        self.attr(kind, data.as_slice());
+    }
+
+    // nest_start opens a nested attribute, the attributes added until
+    // nest_end() go into it.
+    pub fn nest_start(&mut self, kind: u16) -> usize {
+        let off = self.buf.len();
+        self.attr(kind, &[]);
+        off
+    }
+
+    // nest_end closes the nest opened at start. It also closes any record
+    // starting with a u16 length, such as a pushed rtnexthop.
+    pub fn nest_end(&mut self, start: usize) {
+        let len = self.buf.len() - start;
+        if len > u16::max_value() as usize {
+            self.fail(NlError::TooLong(len));
+            return;
+        }
+
+        self.buf[start..start + 2].copy_from_slice(&(len as u16).to_ne_bytes());
+    }
+
+    pub fn finish(mut self) -> NlResult<Vec<u8>> {
+        if let Some(e) = self.err.take() {
+            return Err(e);
+        }
+
+        let len = self.buf.len() as u32;
+        self.buf[0..4].copy_from_slice(&len.to_ne_bytes());
+
+        Ok(self.buf)
+    }
+}
+
+// NlMsg is one message of a netlink reply, the payload being what
+// follows its nlmsghdr.
+#[derive(Debug, Clone)]
+pub struct NlMsg {
+    pub msg_type: u16,
+    pub flags: u16,
+    pub seq: u32,
+    pub pid: u32,
+    pub payload: Vec<u8>,
+}
+
+impl NlMsg {
+    // header returns the fixed-size header at the start of the payload
+    pub fn header<T: NlStruct>(&self) -> NlResult<T> {
+        read_struct(self.payload.as_slice())
+    }
+
+    // attrs parses the attributes following the header T
+    pub fn attrs<T: NlStruct>(&self) -> NlResult<NlAttrs<'_>> {
+        let off = nl_align(mem::size_of::<T>());
+        if self.payload.len() < off {
+            return Err(NlError::Truncated {
+                need: off,
+                have: self.payload.len(),
+            });
+        }
+
+        NlAttrs::parse(&self.payload[off..])
+    }
+
+    // ack decodes a NLMSG_ERROR message, an error code of 0 being an ack.
+    pub fn ack(&self) -> NlResult<()> {
+        // struct nlmsgerr: the error code, then the request header
+        let need = 4 + NL_HDR_LEN;
+        if self.payload.len() < need {
+            return Err(NlError::Truncated {
+                need,
+                have: self.payload.len(),
+            });
+        }
+
+        let error = u32_at(&self.payload, 0) as i32;
+        if error == 0 {
+            return Ok(());
+        }
+
+        let mut msg = None;
+        if self.flags & NLM_F_ACK_TLVS != 0 {
+            // the whole request is echoed back, unless capped
+            let req_len = if self.flags & NLM_F_CAPPED != 0 {
+                NL_HDR_LEN
+            } else {
+                u32_at(&self.payload, 4) as usize
+            };
+
+            let off = 4 + nl_align(req_len);
+            if off <= self.payload.len() {
+                if let Ok(attrs) = NlAttrs::parse(&self.payload[off..]) {
+                    msg = attrs
+                        .get(NLMSGERR_ATTR_MASG as u16)
+                        .and_then(|a| a.as_string().ok());
+                }
+            }
+        }
+
+        Err(NlError::Kernel {
+            errno: Errno::from_i32(-error),
+            msg,
+        })
+    }
+}
+
+// parse_messages iterates over the messages of a buffer received from a
+// netlink socket. It stops after the first malformed message.
+pub fn parse_messages(buf: &[u8]) -> NlMsgIter<'_> {
+    NlMsgIter { buf }
+}
+
+pub struct NlMsgIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> NlMsgIter<'a> {
+    fn parse_one(&mut self) -> NlResult<NlMsg> {
+        let buf = self.buf;
+        if buf.len() < NL_HDR_LEN {
+            return Err(NlError::Truncated {
+                need: NL_HDR_LEN,
+                have: buf.len(),
+            });
+        }
+
+        let len = u32_at(buf, 0) as usize;
+        if len < NL_HDR_LEN {
+            return Err(NlError::BadLength(len));
+        }
+
+        if len > buf.len() {
+            return Err(NlError::Truncated {
+                need: len,
+                have: buf.len(),
+            });
+        }
+
+        let msg = NlMsg {
+            msg_type: u16_at(buf, 4),
+            flags: u16_at(buf, 6),
+            seq: u32_at(buf, 8),
+            pid: u32_at(buf, 12),
+            payload: buf[NL_HDR_LEN..len].to_vec(),
+        };
+
+        self.buf = &buf[nl_align(len).min(buf.len())..];
+
+        Ok(msg)
+    }
+}
+
+impl<'a> Iterator for NlMsgIter<'a> {
+    type Item = NlResult<NlMsg>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+
+        let r = self.parse_one();
+        if r.is_err() {
+            self.buf = &[];
+        }
+
+        Some(r)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NlAttr<'a> {
+    // type without the NLA_F_NESTED and NLA_F_NET_BYTEORDER flags
+    pub kind: u16,
+    pub data: &'a [u8],
+}
+
+impl<'a> NlAttr<'a> {
+    fn fixed(&self, size: usize) -> NlResult<&'a [u8]> {
+        if self.data.len() != size {
+            return Err(NlError::BadAttrSize {
+                kind: self.kind,
+                size: self.data.len(),
+            });
+        }
+
+        Ok(self.data)
+    }
+
+    pub fn as_u8(&self) -> NlResult<u8> {
+        Ok(self.fixed(1)?[0])
+    }
+
+    pub fn as_u16(&self) -> NlResult<u16> {
+        Ok(u16_at(self.fixed(2)?, 0))
+    }
+
+    pub fn as_u32(&self) -> NlResult<u32> {
+        Ok(u32_at(self.fixed(4)?, 0))
+    }
+
+    pub fn as_i32(&self) -> NlResult<i32> {
+        Ok(self.as_u32()? as i32)
+    }
+
+    pub fn as_u64(&self) -> NlResult<u64> {
+        let mut b = [0 as u8; 8];
+        b.copy_from_slice(self.fixed(8)?);
+        Ok(u64::from_ne_bytes(b))
+    }
+
+    // as_string returns a string attribute without its NUL terminator
+    pub fn as_string(&self) -> NlResult<String> {
+        let s = match self.data.iter().position(|c| *c == 0) {
+            Some(n) => &self.data[..n],
+            None => self.data,
+        };
+
+        String::from_utf8(s.to_vec()).map_err(|_| NlError::BadString(self.kind))
+    }
+
+    // as_struct reads a fixed-size structure, newer kernels may send a
+    // longer one
+    pub fn as_struct<T: NlStruct>(&self) -> NlResult<T> {
+        read_struct(self.data)
+    }
+
+    pub fn nested(&self) -> NlResult<NlAttrs<'a>> {
+        NlAttrs::parse(self.data)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NlAttrs<'a> {
+    attrs: Vec<NlAttr<'a>>,
+}
+
+impl<'a> NlAttrs<'a> {
+    pub fn parse(mut buf: &'a [u8]) -> NlResult<Self> {
+        let mut attrs: Vec<NlAttr<'a>> = Vec::new();
+
+        while !buf.is_empty() {
+            if buf.len() < NLA_HDR_LEN {
+                return Err(NlError::Truncated {
+                    need: NLA_HDR_LEN,
+                    have: buf.len(),
+                });
+            }
+
+            let len = u16_at(buf, 0) as usize;
+            if len < NLA_HDR_LEN {
+                return Err(NlError::BadLength(len));
+            }
+
+            if len > buf.len() {
+                return Err(NlError::Truncated {
+                    need: len,
+                    have: buf.len(),
+                });
+            }
+
+            attrs.push(NlAttr {
+                kind: u16_at(buf, 2) & NLA_TYPE_MASK,
+                data: &buf[NLA_HDR_LEN..len],
+            });
+
+            buf = &buf[nl_align(len).min(buf.len())..];
+        }
+
+        Ok(Self { attrs })
+    }
+
+    // get returns the first attribute of type kind
+    pub fn get(&self, kind: u16) -> Option<NlAttr<'a>> {
+        self.attrs.iter().find(|a| a.kind == kind).cloned()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, NlAttr<'a>> {
+        self.attrs.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlink::{NLMSG_ERROR, NLM_F_REQUEST};
+
+    #[repr(C)]
+    #[derive(Copy, Clone, Debug, Default)]
+    struct testhdr {
+        family: u8,
+        pad: [u8; 3],
+        index: i32,
+    }
+
+    unsafe impl NlStruct for testhdr {}
+
+    // NLMSG_ERROR replied by the kernel to a RTM_NEWROUTE with a gateway
+    // out of reach, with the request echoed back and an extended ack.
+    const ENETUNREACH_REPLY: [u8; 104] = [
+        0x68, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x65, 0x00, 0x00, 0x00, 0xb7, 0x1d, 0x00,
+        0x00, 0x9b, 0xff, 0xff, 0xff, 0x34, 0x00, 0x00, 0x00, 0x18, 0x00, 0x05, 0x06, 0x65, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x09, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x01, 0x02, 0x03, 0x04, 0x08, 0x00, 0x04, 0x00, 0xe7, 0x03, 0x00, 0x00, 0x20, 0x00, 0x01,
+        0x00, 0x4e, 0x65, 0x78, 0x74, 0x68, 0x6f, 0x70, 0x20, 0x68, 0x61, 0x73, 0x20, 0x69, 0x6e,
+        0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x67, 0x61, 0x74, 0x65, 0x77, 0x61, 0x79, 0x00,
+    ];
+
+    fn build_test_msg() -> Vec<u8> {
+        let mut b = NlMsgBuilder::new(16, NLM_F_REQUEST);
+        b.push(&testhdr {
+            family: 2,
+            index: -1,
+            ..Default::default()
+        });
+        b.attr_u32(1, 0xdeadbeef);
+        b.attr_str(2, "eth0");
+        b.attr(3, &[1, 2, 3, 4, 5]);
+        let nest = b.nest_start(4);
+        b.attr_u8(1, 7);
+        b.attr_u64(2, 1 << 40);
+        b.nest_end(nest);
+        b.attr_u16(5, 1500);
+        b.set_seq(42);
+        b.finish().unwrap()
+    }
+
+    #[test]
+    fn test_builder() {
+        let buf = build_test_msg();
+
+        // header, testhdr, u32, "eth0\0" and 5 bytes padded to 8,
+        // nest of u8 and u64, u16, all padded to 4 bytes
+        assert_eq!(buf.len(), 16 + 8 + 8 + 12 + 12 + 4 + 8 + 12 + 8);
+        assert_eq!(buf.len() % 4, 0);
+
+        let msgs: Vec<NlMsg> = parse_messages(buf.as_slice())
+            .collect::<NlResult<Vec<NlMsg>>>()
+            .unwrap();
+        assert_eq!(msgs.len(), 1);
+
+        let m = &msgs[0];
+        assert_eq!(m.msg_type, 16);
+        assert_eq!(m.flags, NLM_F_REQUEST);
+        assert_eq!(m.seq, 42);
+
+        let hdr: testhdr = m.header().unwrap();
+        assert_eq!(hdr.family, 2);
+        assert_eq!(hdr.index, -1);
+
+        let attrs = m.attrs::<testhdr>().unwrap();
+        assert_eq!(attrs.iter().count(), 5);
+        assert_eq!(attrs.get(1).unwrap().as_u32().unwrap(), 0xdeadbeef);
+        assert_eq!(attrs.get(2).unwrap().as_string().unwrap(), "eth0");
+        assert_eq!(attrs.get(3).unwrap().data, &[1, 2, 3, 4, 5]);
+        assert_eq!(attrs.get(5).unwrap().as_u16().unwrap(), 1500);
+        assert!(attrs.get(6).is_none());
+
+        let nested = attrs.get(4).unwrap().nested().unwrap();
+        assert_eq!(nested.get(1).unwrap().as_u8().unwrap(), 7);
+        assert_eq!(nested.get(2).unwrap().as_u64().unwrap(), 1 << 40);
+
+        // wrong sizes are reported, not read past
+        assert_eq!(
+            attrs.get(5).unwrap().as_u32(),
+            Err(NlError::BadAttrSize { kind: 5, size: 2 })
+        );
+    }
+
+    #[test]
+    fn test_builder_too_long() {
+        let mut b = NlMsgBuilder::new(16, NLM_F_REQUEST);
+        b.attr(1, vec![0; 70000].as_slice());
+        assert_eq!(b.finish(), Err(NlError::TooLong(70004)));
+
+        let mut b = NlMsgBuilder::new(16, NLM_F_REQUEST);
+        let nest = b.nest_start(1);
+        for _ in 0..2 {
+            b.attr(2, vec![0; 40000].as_slice());
+        }
+        b.nest_end(nest);
+        assert_eq!(b.finish(), Err(NlError::TooLong(80012)));
+    }
+
+    #[test]
+    fn test_parse_messages() {
+        // a reply holding several messages
+        let mut buf = build_test_msg();
+        let mut b = NlMsgBuilder::new(17, 0);
+        b.attr_u32(1, 1);
+        buf.extend(b.finish().unwrap());
+
+        let msgs: Vec<NlMsg> = parse_messages(buf.as_slice())
+            .collect::<NlResult<Vec<NlMsg>>>()
+            .unwrap();
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(msgs[1].msg_type, 17);
+
+        // truncated second message, the first one is still returned
+        let short = &buf[..buf.len() - 1];
+        let r: Vec<NlResult<NlMsg>> = parse_messages(short).collect();
+        assert_eq!(r.len(), 2);
+        assert!(r[0].is_ok());
+        assert_eq!(
+            r[1].as_ref().unwrap_err(),
+            &NlError::Truncated { need: 24, have: 23 }
+        );
+
+        // nlmsg_len shorter than nlmsghdr
+        let mut bad = buf.clone();
+        bad[0] = 8;
+        let r: Vec<NlResult<NlMsg>> = parse_messages(bad.as_slice()).collect();
+        assert_eq!(r.len(), 1);
+        assert_eq!(r[0].as_ref().unwrap_err(), &NlError::BadLength(8));
+
+        // header larger than the payload
+        let empty = NlMsgBuilder::new(17, 0).finish().unwrap();
+        let m = parse_messages(empty.as_slice()).next().unwrap().unwrap();
+        assert_eq!(
+            m.header::<testhdr>().unwrap_err(),
+            NlError::Truncated { need: 8, have: 0 }
+        );
+        assert_eq!(
+            m.attrs::<testhdr>().unwrap_err(),
+            NlError::Truncated { need: 8, have: 0 }
+        );
+    }
+
+    #[test]
+    fn test_parse_attrs() {
+        // attribute longer than the buffer
+        let buf = [8, 0, 1, 0, 0, 0];
+        assert_eq!(
+            NlAttrs::parse(&buf).unwrap_err(),
+            NlError::Truncated { need: 8, have: 6 }
+        );
+
+        // attribute shorter than its header
+        let buf = [2, 0, 1, 0, 0, 0, 0, 0];
+        assert_eq!(NlAttrs::parse(&buf).unwrap_err(), NlError::BadLength(2));
+
+        // trailing bytes
+        let buf = [4, 0, 1, 0, 0, 0];
+        assert_eq!(
+            NlAttrs::parse(&buf).unwrap_err(),
+            NlError::Truncated { need: 4, have: 2 }
+        );
+
+        // flags are masked out of the type, the last attribute
+        // may be unpadded
+        let buf = [4, 0, 1, 0x80, 5, 0, 2, 0, 9];
+        let attrs = NlAttrs::parse(&buf).unwrap();
+        assert!(attrs.get(1).unwrap().data.is_empty());
+        assert_eq!(attrs.get(2).unwrap().as_u8().unwrap(), 9);
+
+        // invalid UTF-8
+        let buf = [6, 0, 3, 0, 0xff, 0xfe];
+        let attrs = NlAttrs::parse(&buf).unwrap();
+        assert_eq!(
+            attrs.get(3).unwrap().as_string(),
+            Err(NlError::BadString(3))
+        );
+    }
+
+    #[test]
+    fn test_ack() {
+        let m = parse_messages(&ENETUNREACH_REPLY).next().unwrap().unwrap();
+        assert_eq!(m.msg_type, NLMSG_ERROR);
+        assert_eq!(
+            m.ack(),
+            Err(NlError::Kernel {
+                errno: Errno::ENETUNREACH,
+                msg: Some("Nexthop has invalid gateway".to_string()),
+            })
+        );
+
+        // the errno survives the conversion
+        let e: Error = m.ack().unwrap_err().into();
+        match e.kind() {
+            ErrorKind::Nix(nix::Error::Sys(errno)) => assert_eq!(*errno, Errno::ENETUNREACH),
+            _ => panic!("unexpected error kind"),
+        }
+
+        // without extended ack
+        let mut buf = ENETUNREACH_REPLY[..36].to_vec();
+        buf[0] = 36;
+        buf[6] = 0;
+        buf[7] = 0;
+        let m = parse_messages(buf.as_slice()).next().unwrap().unwrap();
+        assert_eq!(
+            m.ack(),
+            Err(NlError::Kernel {
+                errno: Errno::ENETUNREACH,
+                msg: None,
+            })
+        );
+
+        // ack
+        buf[16..20].copy_from_slice(&[0; 4]);
+        let m = parse_messages(buf.as_slice()).next().unwrap().unwrap();
+        assert_eq!(m.ack(), Ok(()));
+
+        // truncated nlmsgerr
+        buf[0] = 24;
+        let m = parse_messages(&buf[..24]).next().unwrap().unwrap();
+        assert_eq!(m.ack(), Err(NlError::Truncated { need: 20, have: 8 }));
+    }
+
+    #[test]
+    fn test_fuzz_error_reply() {
+        // neither truncation nor corruption may panic
+        for len in 0..ENETUNREACH_REPLY.len() {
+            for m in parse_messages(&ENETUNREACH_REPLY[..len]) {
+                if let Ok(m) = m {
+                    let _ = m.ack();
+                }
+            }
+        }
+
+        for i in 0..ENETUNREACH_REPLY.len() {
+            for v in [0x00, 0x01, 0x7f, 0xff].iter() {
+                let mut buf = ENETUNREACH_REPLY.to_vec();
+                buf[i] = *v;
+                for m in parse_messages(buf.as_slice()) {
+                    if let Ok(m) = m {
+                        let _ = m.ack();
+                    }
+                }
+            }
+        }
+    }
+}
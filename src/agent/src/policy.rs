@@ -0,0 +1,810 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Authorization policy of the RPCs, for the hosts the guest does not trust:
+// a JSON document which denies whole RPCs and restricts the containers
+// CreateContainer may create, e.g.
+//
+//   {
+//     "deny_rpcs": ["ExecProcess", "CopyFile", "ReadStdout"],
+//     "containers": {
+//       "images": ["registry.example.com/app@sha256:[0-9a-f]{64}"],
+//       "commands": [["/app/server"], ["/app/server", "--port", "[0-9]+"]],
+//       "env": ["PATH=.*", "HOSTNAME=.*"],
+//       "mounts": [{"destination": "/etc/(hosts|hostname|resolv.conf)"}],
+//       "hooks": [["/usr/bin/ldconfig"]],
+//       "storages": [{
+//         "driver": "image",
+//         "mount_point": "/run/kata-containers/shared/containers/.*",
+//         "driver_options": ["image_ref=registry.example.com/app@sha256:[0-9a-f]{64}"]
+//       }],
+//       "devices": [
+//         {"type": "blk", "container_path": "/dev/data"},
+//         {"type": "c", "container_path": "/dev/fuse"}
+//       ]
+//     }
+//   }
+//
+// The patterns are regular expressions matching the whole value, and those
+// of a command match its arguments one by one. A list left out of
+// "containers" does not restrict, while an empty one allows nothing, except
+// for hooks: they run in the guest, out of any container, and are denied
+// unless "hooks" allows their command. The devices of the OCI spec which no
+// device of the request replaces are matched by the rules without "vm_path",
+// of their OCI type if any. The images are those the host
+// names in the annotations of the container, which makes their check only
+// advisory: the image unpacked in the guest is the one the storages name.
+// The policy is loaded at boot, or else set by the first SetPolicy call, and
+// cannot change afterwards. Without one, everything is allowed.
+
+use protocols::agent::{CreateContainerRequest, Device, Storage};
+use protocols::oci::LinuxDevice;
+use regex::Regex;
+use rustjail::errors::*;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::sync::RwLock;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "policy"))
+    };
+}
+
+// where the guest image may bring its policy
+const DEFAULT_POLICY_FILE: &'static str = "/etc/kata-containers/policy.json";
+
+// the annotations of the image of a container, set by CRI-O and containerd
+const IMAGE_ANNOTATIONS: [&'static str; 2] = [
+    "io.kubernetes.cri-o.ImageName",
+    "io.kubernetes.cri.image-name",
+];
+
+lazy_static! {
+    static ref POLICY: RwLock<Option<Policy>> = RwLock::new(None);
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyDocument {
+    #[serde(default)]
+    deny_rpcs: Vec<String>,
+    containers: Option<ContainerDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContainerDocument {
+    images: Option<Vec<String>>,
+    commands: Option<Vec<Vec<String>>>,
+    env: Option<Vec<String>>,
+    mounts: Option<Vec<MountDocument>>,
+    #[serde(default)]
+    hooks: Vec<Vec<String>>,
+    storages: Option<Vec<StorageDocument>>,
+    devices: Option<Vec<DeviceDocument>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MountDocument {
+    destination: String,
+    source: Option<String>,
+    #[serde(rename = "type")]
+    fs_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StorageDocument {
+    driver: String,
+    source: Option<String>,
+    fstype: Option<String>,
+    mount_point: Option<String>,
+    driver_options: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DeviceDocument {
+    #[serde(rename = "type")]
+    dev_type: Option<String>,
+    vm_path: Option<String>,
+    container_path: String,
+}
+
+#[derive(Debug)]
+pub struct Policy {
+    deny_rpcs: HashSet<String>,
+    containers: Option<ContainerPolicy>,
+}
+
+#[derive(Debug)]
+struct ContainerPolicy {
+    images: Option<Vec<Regex>>,
+    commands: Option<Vec<Vec<Regex>>>,
+    env: Option<Vec<Regex>>,
+    mounts: Option<Vec<MountRule>>,
+    hooks: Vec<Vec<Regex>>,
+    storages: Option<Vec<StorageRule>>,
+    devices: Option<Vec<DeviceRule>>,
+}
+
+#[derive(Debug)]
+struct MountRule {
+    destination: Regex,
+    source: Option<Regex>,
+    fs_type: Option<String>,
+}
+
+impl MountRule {
+    fn matches(&self, mount: &protocols::oci::Mount) -> bool {
+        self.destination.is_match(&mount.destination)
+            && self
+                .source
+                .as_ref()
+                .map_or(true, |s| s.is_match(&mount.source))
+            && self
+                .fs_type
+                .as_ref()
+                .map_or(true, |t| *t == mount.field_type)
+    }
+}
+
+#[derive(Debug)]
+struct StorageRule {
+    driver: String,
+    source: Option<Regex>,
+    fstype: Option<String>,
+    mount_point: Option<Regex>,
+    driver_options: Option<Vec<Regex>>,
+}
+
+impl StorageRule {
+    fn matches(&self, storage: &Storage) -> bool {
+        self.driver == storage.driver
+            && self
+                .source
+                .as_ref()
+                .map_or(true, |s| s.is_match(&storage.source))
+            && self.fstype.as_ref().map_or(true, |t| *t == storage.fstype)
+            && self
+                .mount_point
+                .as_ref()
+                .map_or(true, |m| m.is_match(&storage.mount_point))
+            && self.driver_options.as_ref().map_or(true, |o| {
+                storage.driver_options.iter().all(|v| any_match(o, v))
+            })
+    }
+}
+
+#[derive(Debug)]
+struct DeviceRule {
+    dev_type: Option<String>,
+    vm_path: Option<Regex>,
+    container_path: Regex,
+}
+
+impl DeviceRule {
+    fn matches(&self, device: &Device) -> bool {
+        self.container_path.is_match(&device.container_path)
+            && self
+                .dev_type
+                .as_ref()
+                .map_or(true, |t| *t == device.field_type)
+            && self
+                .vm_path
+                .as_ref()
+                .map_or(true, |p| p.is_match(&device.vm_path))
+    }
+
+    fn matches_oci(&self, device: &LinuxDevice) -> bool {
+        self.container_path.is_match(&device.Path)
+            && self.dev_type.as_ref().map_or(true, |t| *t == device.Type)
+            && self.vm_path.is_none()
+    }
+}
+
+// pattern compiles a pattern of the policy, which matches whole values.
+fn pattern(p: &str) -> Result<Regex> {
+    Regex::new(&format!("^(?:{})$", p)).chain_err(|| format!("invalid pattern {}", p))
+}
+
+fn patterns(ps: Option<Vec<String>>) -> Result<Option<Vec<Regex>>> {
+    match ps {
+        Some(ps) => Ok(Some(
+            ps.iter().map(|p| pattern(p)).collect::<Result<Vec<_>>>()?,
+        )),
+        None => Ok(None),
+    }
+}
+
+fn command_patterns(cs: Vec<Vec<String>>) -> Result<Vec<Vec<Regex>>> {
+    cs.iter()
+        .map(|c| c.iter().map(|p| pattern(p)).collect())
+        .collect()
+}
+
+fn optional_pattern(p: Option<String>) -> Result<Option<Regex>> {
+    match p {
+        Some(p) => Ok(Some(pattern(&p)?)),
+        None => Ok(None),
+    }
+}
+
+fn any_match(patterns: &[Regex], value: &str) -> bool {
+    patterns.iter().any(|p| p.is_match(value))
+}
+
+// any_command_match tells whether args match any of the commands, argument
+// by argument.
+fn any_command_match(commands: &[Vec<Regex>], args: &[&str]) -> bool {
+    commands
+        .iter()
+        .any(|c| c.len() == args.len() && c.iter().zip(args.iter()).all(|(p, a)| p.is_match(a)))
+}
+
+fn denied(what: String) -> Error {
+    ErrorKind::ErrorCode(format!("{} denied by policy", what)).into()
+}
+
+impl Policy {
+    pub fn from_json(doc: &str) -> Result<Policy> {
+        let doc: PolicyDocument = serde_json::from_str(doc)?;
+
+        let containers = match doc.containers {
+            Some(c) => {
+                let mounts = match c.mounts {
+                    Some(mounts) => Some(
+                        mounts
+                            .into_iter()
+                            .map(|m| {
+                                Ok(MountRule {
+                                    destination: pattern(&m.destination)?,
+                                    source: optional_pattern(m.source)?,
+                                    fs_type: m.fs_type,
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?,
+                    ),
+                    None => None,
+                };
+                let storages = match c.storages {
+                    Some(storages) => Some(
+                        storages
+                            .into_iter()
+                            .map(|s| {
+                                Ok(StorageRule {
+                                    driver: s.driver,
+                                    source: optional_pattern(s.source)?,
+                                    fstype: s.fstype,
+                                    mount_point: optional_pattern(s.mount_point)?,
+                                    driver_options: patterns(s.driver_options)?,
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?,
+                    ),
+                    None => None,
+                };
+                let devices = match c.devices {
+                    Some(devices) => Some(
+                        devices
+                            .into_iter()
+                            .map(|d| {
+                                Ok(DeviceRule {
+                                    dev_type: d.dev_type,
+                                    vm_path: optional_pattern(d.vm_path)?,
+                                    container_path: pattern(&d.container_path)?,
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?,
+                    ),
+                    None => None,
+                };
+
+                Some(ContainerPolicy {
+                    images: patterns(c.images)?,
+                    commands: match c.commands {
+                        Some(commands) => Some(command_patterns(commands)?),
+                        None => None,
+                    },
+                    env: patterns(c.env)?,
+                    mounts,
+                    hooks: command_patterns(c.hooks)?,
+                    storages,
+                    devices,
+                })
+            }
+            None => None,
+        };
+
+        Ok(Policy {
+            deny_rpcs: doc.deny_rpcs.into_iter().collect(),
+            containers,
+        })
+    }
+
+    pub fn allows_rpc(&self, method: &str) -> bool {
+        !self.deny_rpcs.contains(method)
+    }
+
+    pub fn check_create_container(&self, req: &CreateContainerRequest) -> Result<()> {
+        let spec = match req.OCI.as_ref() {
+            Some(spec) => spec,
+            None => return Err(denied("container without OCI spec".to_string())),
+        };
+
+        // the hooks are denied even without rules for the containers
+        if let Some(hooks) = spec.Hooks.as_ref() {
+            let allowed = self.containers.as_ref().map_or(&[][..], |p| &p.hooks[..]);
+            for hook in hooks
+                .Prestart
+                .iter()
+                .chain(hooks.Poststart.iter())
+                .chain(hooks.Poststop.iter())
+            {
+                let command = std::iter::once(&hook.Path)
+                    .chain(hook.Args.iter().skip(1))
+                    .map(|a| a.as_str())
+                    .collect::<Vec<_>>();
+                if !any_command_match(allowed, &command) {
+                    return Err(denied(format!("hook {:?}", command)));
+                }
+            }
+        }
+
+        let policy = match &self.containers {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+
+        if let Some(images) = &policy.images {
+            let image = IMAGE_ANNOTATIONS
+                .iter()
+                .filter_map(|a| spec.Annotations.get(*a))
+                .next();
+            match image {
+                Some(image) if any_match(images, image) => (),
+                Some(image) => return Err(denied(format!("image {}", image))),
+                None => return Err(denied("container without image".to_string())),
+            }
+        }
+
+        let process = spec.Process.as_ref();
+
+        if let Some(commands) = &policy.commands {
+            let command = process
+                .map(|p| p.Args.iter().map(|a| a.as_str()).collect::<Vec<_>>())
+                .unwrap_or_default();
+            if !any_command_match(commands, &command) {
+                return Err(denied(format!("command {:?}", command)));
+            }
+        }
+
+        if let (Some(env), Some(process)) = (&policy.env, process) {
+            for var in process.Env.iter() {
+                if !any_match(env, var) {
+                    // the value may be a secret
+                    let name = var.split('=').next().unwrap_or_default();
+                    return Err(denied(format!("environment variable {}", name)));
+                }
+            }
+        }
+
+        if let Some(rules) = &policy.mounts {
+            for mount in spec.Mounts.iter() {
+                if !rules.iter().any(|r| r.matches(mount)) {
+                    return Err(denied(format!(
+                        "mount of {} on {}",
+                        mount.source, mount.destination
+                    )));
+                }
+            }
+        }
+
+        if let Some(rules) = &policy.storages {
+            for storage in req.storages.iter() {
+                if !rules.iter().any(|r| r.matches(storage)) {
+                    return Err(denied(format!(
+                        "{} storage of {} on {}",
+                        storage.driver, storage.source, storage.mount_point
+                    )));
+                }
+            }
+        }
+
+        if let Some(rules) = &policy.devices {
+            for device in req.devices.iter() {
+                if !rules.iter().any(|r| r.matches(device)) {
+                    return Err(denied(format!(
+                        "{} device {} on {}",
+                        device.field_type, device.id, device.container_path
+                    )));
+                }
+            }
+
+            // those of the request replace the ones of the spec at their path
+            let devices = spec
+                .Linux
+                .as_ref()
+                .map_or(&[][..], |l| l.Devices.as_slice());
+            for device in devices
+                .iter()
+                .filter(|d| !req.devices.iter().any(|r| r.container_path == d.Path))
+            {
+                if !rules.iter().any(|r| r.matches_oci(device)) {
+                    return Err(denied(format!(
+                        "{} device {} ({}:{})",
+                        device.Type, device.Path, device.Major, device.Minor
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// set makes doc the policy, unless there is one already.
+pub fn set(doc: &str) -> Result<()> {
+    let policy = Policy::from_json(doc)?;
+
+    let mut current = POLICY.write().unwrap();
+    if current.is_some() {
+        return Err(ErrorKind::ErrorCode("the policy is already set".to_string()).into());
+    }
+
+    info!(sl!(), "policy set"; "denied-rpcs" => format!("{:?}", policy.deny_rpcs));
+    *current = Some(policy);
+
+    Ok(())
+}
+
+// load sets the policy of the file at path.
+pub fn load(path: &str) -> Result<()> {
+    let doc = fs::read_to_string(path).chain_err(|| format!("failed to read policy {}", path))?;
+    set(&doc).chain_err(|| format!("invalid policy {}", path))
+}
+
+// init loads the policy of the configuration at boot, if any, or else the
+// one of the guest image.
+pub fn init(path: Option<&str>) -> Result<()> {
+    match path {
+        Some(path) => load(path),
+        None if Path::new(DEFAULT_POLICY_FILE).exists() => load(DEFAULT_POLICY_FILE),
+        None => Ok(()),
+    }
+}
+
+pub fn allows_rpc(method: &str) -> bool {
+    match &*POLICY.read().unwrap() {
+        Some(policy) => policy.allows_rpc(method),
+        None => true,
+    }
+}
+
+pub fn check_create_container(req: &CreateContainerRequest) -> Result<()> {
+    match &*POLICY.read().unwrap() {
+        Some(policy) => policy.check_create_container(req),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protocols::oci::{Hook, Hooks, Linux, Mount, Process, Spec};
+
+    const POLICY_DOC: &'static str = r#"{
+        "deny_rpcs": ["ExecProcess", "CopyFile"],
+        "containers": {
+            "images": ["registry.example.com/app@sha256:[0-9a-f]+"],
+            "commands": [["/app/server"], ["/app/server", "--port", "[0-9]+"]],
+            "env": ["PATH=.*", "MODE=(dev|prod)"],
+            "mounts": [
+                {"destination": "/etc/hosts", "source": "/run/kata-containers/shared/.*"},
+                {"destination": "/proc", "type": "proc"}
+            ]
+        }
+    }"#;
+
+    fn create_request(
+        image: &str,
+        args: &[&str],
+        env: &[&str],
+        mounts: &[(&str, &str, &str)],
+    ) -> CreateContainerRequest {
+        let mut process = Process::new();
+        process.set_Args(args.iter().map(|a| a.to_string()).collect());
+        process.set_Env(env.iter().map(|e| e.to_string()).collect());
+
+        let mut spec = Spec::new();
+        spec.set_Process(process);
+        if !image.is_empty() {
+            spec.mut_Annotations()
+                .insert(IMAGE_ANNOTATIONS[1].to_string(), image.to_string());
+        }
+        for (source, destination, fs_type) in mounts {
+            let mut mount = Mount::new();
+            mount.set_source(source.to_string());
+            mount.set_destination(destination.to_string());
+            mount.set_field_type(fs_type.to_string());
+            spec.mut_Mounts().push(mount);
+        }
+
+        let mut req = CreateContainerRequest::new();
+        req.set_OCI(spec);
+        req
+    }
+
+    #[test]
+    fn test_policy_rpcs() {
+        let policy = Policy::from_json(POLICY_DOC).unwrap();
+
+        assert!(!policy.allows_rpc("ExecProcess"));
+        assert!(!policy.allows_rpc("CopyFile"));
+        assert!(policy.allows_rpc("CreateContainer"));
+        assert!(policy.allows_rpc("ReadStdout"));
+
+        let open = Policy::from_json("{}").unwrap();
+        assert!(open.allows_rpc("ExecProcess"));
+        assert!(open
+            .check_create_container(&create_request("", &["/bin/sh"], &[], &[]))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_policy_create_container() {
+        let policy = Policy::from_json(POLICY_DOC).unwrap();
+        let image = "registry.example.com/app@sha256:0123abcd";
+        let mounts = [
+            (
+                "/run/kata-containers/shared/ctr/hosts",
+                "/etc/hosts",
+                "bind",
+            ),
+            ("proc", "/proc", "proc"),
+        ];
+
+        #[derive(Debug)]
+        struct TestData<'a> {
+            image: &'a str,
+            args: &'a [&'a str],
+            env: &'a [&'a str],
+            mounts: &'a [(&'a str, &'a str, &'a str)],
+            allowed: bool,
+        }
+
+        let tests = &[
+            TestData {
+                image,
+                args: &["/app/server", "--port", "80"],
+                env: &["PATH=/bin", "MODE=prod"],
+                mounts: &mounts,
+                allowed: true,
+            },
+            TestData {
+                image: "registry.example.com/app:latest",
+                args: &["/app/server"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image: "",
+                args: &["/app/server"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/bin/sh", "-c", "/app/server"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            // the patterns match whole values
+            TestData {
+                image,
+                args: &["/app/serverx"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            // the arguments are matched one by one
+            TestData {
+                image,
+                args: &["/app/server --port", "80"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/app/server", "--port", "80", "--debug"],
+                env: &[],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/app/server"],
+                env: &["MODE=debug"],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/app/server"],
+                env: &["LD_PRELOAD=/tmp/x.so"],
+                mounts: &[],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/app/server"],
+                env: &[],
+                mounts: &[("/", "/etc/hosts", "bind")],
+                allowed: false,
+            },
+            TestData {
+                image,
+                args: &["/app/server"],
+                env: &[],
+                mounts: &[("proc", "/proc", "sysfs")],
+                allowed: false,
+            },
+        ];
+
+        for (i, d) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, d);
+            let req = create_request(d.image, d.args, d.env, d.mounts);
+            let result = policy.check_create_container(&req);
+            assert_eq!(result.is_ok(), d.allowed, "{}: {:?}", msg, result);
+        }
+
+        let result = policy.check_create_container(&CreateContainerRequest::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_policy_hooks() {
+        let hook = |path: &str, args: &[&str]| {
+            let mut hook = Hook::new();
+            hook.set_Path(path.to_string());
+            hook.set_Args(args.iter().map(|a| a.to_string()).collect());
+            hook
+        };
+        let mut req = create_request("", &["/bin/sh"], &[], &[]);
+        let mut hooks = Hooks::new();
+        hooks
+            .mut_Poststop()
+            .push(hook("/bin/sh", &["sh", "-c", "reboot"]));
+        req.mut_OCI().set_Hooks(hooks);
+
+        // denied by any policy, even without rules for the containers
+        for doc in &["{}", r#"{"containers": {}}"#, POLICY_DOC] {
+            let policy = Policy::from_json(doc).unwrap();
+            assert!(policy.check_create_container(&req).is_err(), "{}", doc);
+        }
+
+        let policy =
+            Policy::from_json(r#"{"containers": {"hooks": [["/usr/bin/ldconfig"]]}}"#).unwrap();
+        assert!(policy.check_create_container(&req).is_err());
+
+        let mut hooks = Hooks::new();
+        hooks
+            .mut_Prestart()
+            .push(hook("/usr/bin/ldconfig", &["ldconfig"]));
+        req.mut_OCI().set_Hooks(hooks.clone());
+        assert!(policy.check_create_container(&req).is_ok());
+
+        hooks.mut_Prestart()[0].set_Args(vec!["ldconfig".to_string(), "-r".to_string()].into());
+        req.mut_OCI().set_Hooks(hooks);
+        assert!(policy.check_create_container(&req).is_err());
+    }
+
+    #[test]
+    fn test_policy_storages_and_devices() {
+        let policy = Policy::from_json(
+            r#"{"containers": {
+                "storages": [{
+                    "driver": "image",
+                    "mount_point": "/run/kata-containers/shared/containers/[^/]+/rootfs",
+                    "driver_options": ["image_ref=app@sha256:[0-9a-f]+"]
+                }],
+                "devices": [
+                    {"type": "blk", "container_path": "/dev/data"},
+                    {"type": "c", "container_path": "/dev/fuse"}
+                ]
+            }}"#,
+        )
+        .unwrap();
+
+        let storage = |driver: &str, mount_point: &str, image_ref: &str| {
+            let mut storage = Storage::new();
+            storage.set_driver(driver.to_string());
+            storage.set_mount_point(mount_point.to_string());
+            storage.set_driver_options(vec![format!("image_ref={}", image_ref)].into());
+            storage
+        };
+        let rootfs = "/run/kata-containers/shared/containers/ctr/rootfs";
+
+        let mut req = create_request("", &["/bin/sh"], &[], &[]);
+        req.mut_storages()
+            .push(storage("image", rootfs, "app@sha256:0123"));
+        assert!(policy.check_create_container(&req).is_ok());
+
+        for bad in vec![
+            storage("image", rootfs, "app:latest"),
+            storage("image", "/run/kata-containers/sandbox", "app@sha256:0123"),
+            storage("local", rootfs, "app@sha256:0123"),
+        ] {
+            let mut req = req.clone();
+            req.mut_storages().push(bad);
+            assert!(policy.check_create_container(&req).is_err());
+        }
+
+        let mut device = Device::new();
+        device.set_field_type("blk".to_string());
+        device.set_container_path("/dev/data".to_string());
+        req.mut_devices().push(device.clone());
+        assert!(policy.check_create_container(&req).is_ok());
+
+        // the spec device the request device replaces
+        let oci_device = |path: &str, dev_type: &str, major: i64, minor: i64| {
+            let mut device = LinuxDevice::new();
+            device.set_Path(path.to_string());
+            device.set_Type(dev_type.to_string());
+            device.set_Major(major);
+            device.set_Minor(minor);
+            device
+        };
+        let mut linux = Linux::new();
+        linux.mut_Devices().push(oci_device("/dev/data", "b", 8, 0));
+        req.mut_OCI().set_Linux(linux.clone());
+        assert!(policy.check_create_container(&req).is_ok());
+
+        // spec devices of their own
+        let mut fuse = req.clone();
+        linux
+            .mut_Devices()
+            .push(oci_device("/dev/fuse", "c", 10, 229));
+        fuse.mut_OCI().set_Linux(linux.clone());
+        assert!(policy.check_create_container(&fuse).is_ok());
+
+        let mut mem = req.clone();
+        linux.mut_Devices().push(oci_device("/dev/mem", "c", 1, 1));
+        mem.mut_OCI().set_Linux(linux);
+        assert!(policy.check_create_container(&mem).is_err());
+
+        device.set_container_path("/dev/mem".to_string());
+        req.mut_devices().push(device);
+        assert!(policy.check_create_container(&req).is_err());
+    }
+
+    #[test]
+    fn test_policy_invalid() {
+        assert!(Policy::from_json("").is_err());
+        assert!(Policy::from_json(r#"{"allow_rpcs": []}"#).is_err());
+        assert!(Policy::from_json(r#"{"containers": {"commands": [["("]]}}"#).is_err());
+        assert!(Policy::from_json(r#"{"containers": {"commands": ["/app/server"]}}"#).is_err());
+        assert!(Policy::from_json(r#"{"containers": {"mounts": [{"source": "/"}]}}"#).is_err());
+    }
+
+    #[test]
+    fn test_policy_set_once() {
+        // an invalid policy does not set it
+        assert!(set("{").is_err());
+
+        // denying nothing, lest it affects the other tests
+        assert!(set("{}").is_ok());
+        assert!(set("{}").is_err());
+        assert!(allows_rpc("ExecProcess"));
+    }
+}
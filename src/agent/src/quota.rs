@@ -0,0 +1,238 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Project quotas, which limit the space taken by a directory tree on
+// ext4 and xfs, provided the filesystem is mounted with prjquota.
+
+use libc;
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::{self, Mode};
+use nix::unistd;
+use rustjail::errors::*;
+use std::ffi::CString;
+use std::fs;
+use std::os::unix::io::RawFd;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// _IOR('X', 31, struct fsxattr) and _IOW('X', 32, struct fsxattr)
+const FS_IOC_FSGETXATTR: u64 = 0x801c_581f;
+const FS_IOC_FSSETXATTR: u64 = 0x401c_5820;
+const FS_XFLAG_PROJINHERIT: u32 = 0x0000_0200;
+
+const SYS_QUOTACTL_FD: libc::c_long = 443;
+// QCMD(Q_SETQUOTA, PRJQUOTA) and QCMD(Q_XGETQUOTA, PRJQUOTA)
+const Q_SETQUOTA_PRJQUOTA: libc::c_int = 0x8000_0802u32 as libc::c_int;
+const Q_XGETQUOTA_PRJQUOTA: libc::c_int = 0x0058_0302;
+const QIF_BLIMITS: u32 = 1;
+const QIF_DQBLKSIZE: u64 = 1024;
+// the basic blocks fs_disk_quota counts in
+const BBSIZE: u64 = 512;
+
+const SYSFS_DEV_BLOCK: &'static str = "/sys/dev/block";
+
+// the project IDs handed out, above those a system usually assigns
+static NEXT_PROJECT_ID: AtomicU32 = AtomicU32::new(1_048_577);
+
+// Handle the differing ioctl(2) request types for different targets
+#[cfg(target_env = "musl")]
+type IoctlRequestType = libc::c_int;
+#[cfg(target_env = "gnu")]
+type IoctlRequestType = libc::c_ulong;
+
+#[repr(C)]
+#[derive(Default)]
+struct fsxattr {
+    fsx_xflags: u32,
+    fsx_extsize: u32,
+    fsx_nextents: u32,
+    fsx_projid: u32,
+    fsx_cowextsize: u32,
+    fsx_pad: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct if_dqblk {
+    dqb_bhardlimit: u64,
+    dqb_bsoftlimit: u64,
+    dqb_curspace: u64,
+    dqb_ihardlimit: u64,
+    dqb_isoftlimit: u64,
+    dqb_curinodes: u64,
+    dqb_btime: u64,
+    dqb_itime: u64,
+    dqb_valid: u32,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct fs_disk_quota {
+    d_version: i8,
+    d_flags: i8,
+    d_fieldmask: u16,
+    d_id: u32,
+    d_blk_hardlimit: u64,
+    d_blk_softlimit: u64,
+    d_ino_hardlimit: u64,
+    d_ino_softlimit: u64,
+    d_bcount: u64,
+    d_icount: u64,
+    d_itimer: i32,
+    d_btimer: i32,
+    d_iwarns: u16,
+    d_bwarns: u16,
+    d_itimer_hi: i8,
+    d_btimer_hi: i8,
+    d_rtbtimer_hi: i8,
+    d_padding2: i8,
+    d_rtb_hardlimit: u64,
+    d_rtb_softlimit: u64,
+    d_rtbcount: u64,
+    d_rtbtimer: i32,
+    d_rtbwarns: u16,
+    d_padding3: i16,
+    d_padding4: [u8; 8],
+}
+
+// set_project_quota puts dir, and what will be created below it, in a
+// new project limited to size bytes, and returns the project ID.
+pub fn set_project_quota(dir: &str, size: u64) -> Result<u32> {
+    let fd = fcntl::open(
+        dir,
+        OFlag::O_RDONLY | OFlag::O_DIRECTORY | OFlag::O_CLOEXEC,
+        Mode::empty(),
+    )?;
+
+    let res = (|| {
+        let id = NEXT_PROJECT_ID.fetch_add(1, Ordering::SeqCst);
+
+        let mut attr = get_fsxattr(fd)?;
+        attr.fsx_projid = id;
+        attr.fsx_xflags |= FS_XFLAG_PROJINHERIT;
+        let ret = unsafe {
+            libc::ioctl(
+                fd,
+                FS_IOC_FSSETXATTR as IoctlRequestType,
+                &attr as *const fsxattr,
+            )
+        };
+        Errno::result(ret).chain_err(|| format!("set project of {}", dir))?;
+
+        let mut dq = if_dqblk {
+            dqb_bhardlimit: (size + QIF_DQBLKSIZE - 1) / QIF_DQBLKSIZE,
+            dqb_valid: QIF_BLIMITS,
+            ..Default::default()
+        };
+        quotactl(
+            fd,
+            Q_SETQUOTA_PRJQUOTA,
+            id,
+            &mut dq as *mut if_dqblk as *mut libc::c_char,
+        )
+        .chain_err(|| format!("set project quota of {}", dir))?;
+
+        Ok(id)
+    })();
+    let _ = unistd::close(fd);
+
+    res
+}
+
+// project_id returns the project ID of path, 0 when it has none.
+pub fn project_id(path: &str) -> Result<u32> {
+    let fd = fcntl::open(path, OFlag::O_RDONLY | OFlag::O_CLOEXEC, Mode::empty())?;
+    let res = get_fsxattr(fd);
+    let _ = unistd::close(fd);
+
+    Ok(res?.fsx_projid)
+}
+
+// project_usage returns the space and inodes used by the project of path,
+// as its quota accounts them, or None when path has no project.
+pub fn project_usage(path: &str) -> Result<Option<(u64, u64)>> {
+    let fd = fcntl::open(path, OFlag::O_RDONLY | OFlag::O_CLOEXEC, Mode::empty())?;
+
+    let res = (|| {
+        let id = get_fsxattr(fd)?.fsx_projid;
+        if id == 0 {
+            return Ok(None);
+        }
+
+        let mut dq = fs_disk_quota::default();
+        quotactl(
+            fd,
+            Q_XGETQUOTA_PRJQUOTA,
+            id,
+            &mut dq as *mut fs_disk_quota as *mut libc::c_char,
+        )
+        .chain_err(|| format!("get project quota of {}", path))?;
+
+        Ok(Some((dq.d_bcount * BBSIZE, dq.d_icount)))
+    })();
+    let _ = unistd::close(fd);
+
+    res
+}
+
+fn get_fsxattr(fd: RawFd) -> Result<fsxattr> {
+    let mut attr = fsxattr::default();
+    let ret = unsafe {
+        libc::ioctl(
+            fd,
+            FS_IOC_FSGETXATTR as IoctlRequestType,
+            &mut attr as *mut fsxattr,
+        )
+    };
+    Errno::result(ret)?;
+
+    Ok(attr)
+}
+
+// quotactl runs cmd on the project quota id of the filesystem of fd, by
+// fd on kernels from 5.14 and by block device before.
+fn quotactl(fd: RawFd, cmd: libc::c_int, id: u32, addr: *mut libc::c_char) -> Result<()> {
+    let ret = unsafe { libc::syscall(SYS_QUOTACTL_FD, fd, cmd, id, addr) };
+    match Errno::result(ret) {
+        Err(nix::Error::Sys(Errno::ENOSYS)) => {}
+        res => return Ok(res.map(drop)?),
+    }
+
+    let dev = fs_device(fd)?;
+    let special = CString::new(dev)?;
+    let ret = unsafe { libc::quotactl(cmd, special.as_ptr(), id as libc::c_int, addr) };
+    Errno::result(ret)?;
+
+    Ok(())
+}
+
+// fs_device returns the block device the filesystem of fd lives on.
+fn fs_device(fd: RawFd) -> Result<String> {
+    let st = stat::fstat(fd)?;
+    let uevent = fs::read_to_string(format!(
+        "{}/{}:{}/uevent",
+        SYSFS_DEV_BLOCK,
+        unsafe { libc::major(st.st_dev) },
+        unsafe { libc::minor(st.st_dev) }
+    ))?;
+
+    match uevent.lines().find(|l| l.starts_with("DEVNAME=")) {
+        Some(l) => Ok(format!("/dev/{}", &l["DEVNAME=".len()..])),
+        None => Err(ErrorKind::ErrorCode("no block device for quota".to_string()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem;
+
+    #[test]
+    fn test_quota_structs() {
+        assert_eq!(mem::size_of::<fsxattr>(), 28);
+        assert_eq!(mem::size_of::<if_dqblk>(), 72);
+        assert_eq!(mem::size_of::<fs_disk_quota>(), 112);
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Online growth of the ext4 and xfs filesystems of block storages, once
+// their device has been expanded on the host.
+
+use libc;
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::{self, Mode};
+use nix::sys::statfs;
+use nix::unistd;
+use rustjail::errors::*;
+use std::fs;
+use std::mem;
+use std::os::unix::io::RawFd;
+use std::path::Path;
+
+// _IOW('f', 16, __u64)
+const EXT4_IOC_RESIZE_FS: u64 = 0x4008_6610;
+// _IOR('X', 100, struct xfs_fsop_geom_v1) and _IOW('X', 110, struct xfs_growfs_data)
+const XFS_IOC_FSGEOMETRY_V1: u64 = 0x8070_5864;
+const XFS_IOC_FSGROWFSDATA: u64 = 0x4010_586e;
+
+const EXT4_SUPER_MAGIC: i64 = 0xef53;
+const XFS_SUPER_MAGIC: i64 = 0x5846_5342;
+
+const SYSFS_DEV_BLOCK: &'static str = "/sys/dev/block";
+const SECTOR_SIZE: u64 = 512;
+
+// Handle the differing ioctl(2) request types for different targets
+#[cfg(target_env = "musl")]
+type IoctlRequestType = libc::c_int;
+#[cfg(target_env = "gnu")]
+type IoctlRequestType = libc::c_ulong;
+
+#[repr(C)]
+#[derive(Default)]
+struct xfs_fsop_geom_v1 {
+    blocksize: u32,
+    rtextsize: u32,
+    agblocks: u32,
+    agcount: u32,
+    logblocks: u32,
+    sectsize: u32,
+    inodesize: u32,
+    imaxpct: u32,
+    datablocks: u64,
+    // the fields below are of no use here
+    rest: [u64; 9],
+}
+
+#[repr(C)]
+struct xfs_growfs_data {
+    newblocks: u64,
+    imaxpct: u32,
+}
+
+// resize_filesystem grows the filesystem mounted at mount_point to size
+// bytes, or to the size of its device when size is 0.
+pub fn resize_filesystem(mount_point: &str, size: u64) -> Result<()> {
+    let fd = fcntl::open(
+        mount_point,
+        OFlag::O_RDONLY | OFlag::O_DIRECTORY | OFlag::O_CLOEXEC,
+        Mode::empty(),
+    )?;
+
+    let res = (|| {
+        let st = stat::fstat(fd)?;
+        let dev_size = device_size(st.st_dev)?;
+        let size = match size {
+            0 => dev_size,
+            s if s > dev_size => {
+                return Err(ErrorKind::ErrorCode(format!(
+                    "device of {} holds {} bytes, less than {}",
+                    mount_point, dev_size, s
+                ))
+                .into())
+            }
+            s => s,
+        };
+
+        let mut sfs: libc::statfs = unsafe { mem::zeroed() };
+        statfs::statfs(mount_point, &mut sfs)?;
+
+        match sfs.f_type as i64 {
+            EXT4_SUPER_MAGIC => resize_ext4(fd, size / sfs.f_bsize as u64),
+            XFS_SUPER_MAGIC => resize_xfs(fd, size),
+            _ => Err(ErrorKind::ErrorCode(format!(
+                "no online resize for the filesystem of {}",
+                mount_point
+            ))
+            .into()),
+        }
+        .chain_err(|| format!("resize {}", mount_point))
+    })();
+    let _ = unistd::close(fd);
+
+    res
+}
+
+fn resize_ext4(fd: RawFd, blocks: u64) -> Result<()> {
+    let ret = unsafe {
+        libc::ioctl(
+            fd,
+            EXT4_IOC_RESIZE_FS as IoctlRequestType,
+            &blocks as *const u64,
+        )
+    };
+    Errno::result(ret)?;
+
+    Ok(())
+}
+
+fn resize_xfs(fd: RawFd, size: u64) -> Result<()> {
+    let mut geo = xfs_fsop_geom_v1::default();
+    let ret = unsafe {
+        libc::ioctl(
+            fd,
+            XFS_IOC_FSGEOMETRY_V1 as IoctlRequestType,
+            &mut geo as *mut xfs_fsop_geom_v1,
+        )
+    };
+    Errno::result(ret)?;
+
+    let blocks = size / geo.blocksize as u64;
+    if blocks <= geo.datablocks {
+        return Ok(());
+    }
+
+    // keep the share of the space inodes may take
+    let data = xfs_growfs_data {
+        newblocks: blocks,
+        imaxpct: geo.imaxpct,
+    };
+    let ret = unsafe {
+        libc::ioctl(
+            fd,
+            XFS_IOC_FSGROWFSDATA as IoctlRequestType,
+            &data as *const xfs_growfs_data,
+        )
+    };
+    Errno::result(ret)?;
+
+    Ok(())
+}
+
+// device_size returns the size in bytes of the block device numbered dev,
+// after a rescan for a SCSI disk to notice its new capacity. virtio-blk
+// devices are told about it by the host.
+fn device_size(dev: libc::dev_t) -> Result<u64> {
+    let sys = format!(
+        "{}/{}:{}",
+        SYSFS_DEV_BLOCK,
+        unsafe { libc::major(dev) },
+        unsafe { libc::minor(dev) }
+    );
+
+    let rescan = format!("{}/device/rescan", sys);
+    if Path::new(&rescan).exists() {
+        fs::write(&rescan, "1")?;
+    }
+
+    let sectors = fs::read_to_string(format!("{}/size", sys))?
+        .trim()
+        .parse::<u64>()?;
+
+    Ok(sectors * SECTOR_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xfs_structs() {
+        assert_eq!(mem::size_of::<xfs_fsop_geom_v1>(), 112);
+        assert_eq!(mem::size_of::<xfs_growfs_data>(), 16);
+    }
+}
@@ -4,10 +4,12 @@
 //
 
 //use crate::container::Container;
+use crate::luks;
 use crate::mount::{get_mount_fs_type, remove_mounts, TYPEROOTFS};
 use crate::namespace::Namespace;
 use crate::netlink::{RtnlHandle, NETLINK_ROUTE};
 use crate::network::Network;
+use crate::watchable::WatchableStorage;
 use libc::pid_t;
 use protocols::agent::OnlineCPUMemRequest;
 use regex::Regex;
@@ -15,31 +17,39 @@ use rustjail::cgroups;
 use rustjail::container::BaseContainer;
 use rustjail::container::LinuxContainer;
 use rustjail::errors::*;
-use rustjail::process::Process;
 use slog::Logger;
 use std::collections::HashMap;
 use std::fs;
 use std::sync::mpsc::Sender;
-
+use std::sync::{Arc, Mutex, Weak};
+
+// The sandbox lock is only held for short, and never while waiting for the
+// lock of a container, of the storages, of the netlink handle or of the
+// device map: each of these may be held for long, e.g. while a container is
+// destroyed or a device is waited for, without stalling the RPCs of the
+// other containers. The storages themselves are only locked for their
+// bookkeeping, see SandboxStorages::mount_lock.
 #[derive(Debug)]
 pub struct Sandbox {
     pub logger: Logger,
     pub id: String,
     pub hostname: String,
-    pub containers: HashMap<String, LinuxContainer>,
+    pub containers: HashMap<String, Arc<Mutex<LinuxContainer>>>,
+    // the containers being created, each locked by its creator until it is
+    // added or has failed, see reserve_container
+    pub creating: HashMap<String, Arc<Mutex<()>>>,
     pub network: Network,
-    pub mounts: Vec<String>,
-    pub container_mounts: HashMap<String, Vec<String>>,
-    pub pci_device_map: HashMap<String, String>,
+    pub storages: Arc<Mutex<SandboxStorages>>,
+    // the names of the block devices by their sysfs path
+    pub pci_device_map: Arc<Mutex<HashMap<String, String>>>,
     pub shared_utsns: Namespace,
     pub shared_ipcns: Namespace,
-    pub storages: HashMap<String, u32>,
     pub running: bool,
     pub no_pivot_root: bool,
     enable_grpc_trace: bool,
     pub sandbox_pid_ns: bool,
     pub sender: Option<Sender<i32>>,
-    pub rtnl: Option<RtnlHandle>,
+    pub rtnl: Arc<Mutex<Option<RtnlHandle>>>,
 }
 
 impl Sandbox {
@@ -53,21 +63,87 @@ impl Sandbox {
             hostname: "".to_string(),
             network: Network::new(),
             containers: HashMap::new(),
-            mounts: Vec::new(),
-            container_mounts: HashMap::new(),
-            pci_device_map: HashMap::new(),
+            creating: HashMap::new(),
+            storages: Arc::new(Mutex::new(SandboxStorages::default())),
+            pci_device_map: Arc::new(Mutex::new(HashMap::new())),
             shared_utsns: Namespace::new(&logger),
             shared_ipcns: Namespace::new(&logger),
-            storages: HashMap::new(),
             running: false,
             no_pivot_root: fs_type.eq(TYPEROOTFS),
             enable_grpc_trace: false,
             sandbox_pid_ns: false,
             sender: None,
-            rtnl: Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap()),
+            rtnl: Arc::new(Mutex::new(Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap()))),
         })
     }
 
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn set_hostname(&mut self, hostname: String) {
+        self.hostname = hostname;
+    }
+
+    pub fn add_container(&mut self, id: &str, c: Arc<Mutex<LinuxContainer>>) {
+        self.containers.insert(id.to_string(), c);
+    }
+
+    // reserve_container reserves id for a container to create, unless one
+    // exists or is being created, and returns the lock its creator holds
+    // until it is added or has failed.
+    pub fn reserve_container(&mut self, id: &str) -> Result<Arc<Mutex<()>>> {
+        if self.containers.contains_key(id) || self.creating.contains_key(id) {
+            return Err(ErrorKind::ErrorCode(format!("container {} already exists", id)).into());
+        }
+
+        let l = Arc::new(Mutex::new(()));
+        self.creating.insert(id.to_string(), l.clone());
+        Ok(l)
+    }
+
+    pub fn get_container(&self, id: &str) -> Option<Arc<Mutex<LinuxContainer>>> {
+        self.containers.get(id).cloned()
+    }
+
+    // container_list returns the containers, to lock once the sandbox is
+    // unlocked.
+    pub fn container_list(&self) -> Vec<Arc<Mutex<LinuxContainer>>> {
+        self.containers.values().cloned().collect()
+    }
+}
+
+// SandboxStorages are the storages of the sandbox and of its containers.
+#[derive(Debug, Default)]
+pub struct SandboxStorages {
+    pub mounts: Vec<String>,
+    pub container_mounts: HashMap<String, Vec<String>>,
+    // the reference counts of the storages shared by the containers
+    pub storages: HashMap<String, u32>,
+    pub overlay_layers: HashMap<String, Vec<String>>,
+    pub crypt_devices: HashMap<String, String>,
+    pub watchable_storages: HashMap<String, WatchableStorage>,
+    // the locks of the mount points, see mount_lock
+    mount_locks: HashMap<String, Weak<Mutex<()>>>,
+}
+
+impl SandboxStorages {
+    // mount_lock returns the lock of the mount point path. Its holder sets
+    // up the storage of path with the storages unlocked, while the storages
+    // of other mount points are set up meanwhile, and those of path wait.
+    pub fn mount_lock(&mut self, path: &str) -> Arc<Mutex<()>> {
+        self.mount_locks.retain(|_, l| l.upgrade().is_some());
+
+        if let Some(l) = self.mount_locks.get(path).and_then(Weak::upgrade) {
+            return l;
+        }
+
+        let l = Arc::new(Mutex::new(()));
+        self.mount_locks
+            .insert(path.to_string(), Arc::downgrade(&l));
+        l
+    }
+
     // unset_sandbox_storage will decrement the sandbox storage
     // reference counter. If there aren't any containers using
     // that sandbox storage, this method will remove the
@@ -76,7 +152,7 @@ impl Sandbox {
     // related directories by calling remove_sandbox_storage
     //
     // It's assumed that caller is calling this method after
-    // acquiring a lock on sandbox.
+    // acquiring a lock on the storages.
     pub fn unset_sandbox_storage(&mut self, path: &str) -> bool {
         match self.storages.get_mut(path) {
             None => return false,
@@ -84,8 +160,9 @@ impl Sandbox {
                 *count -= 1;
                 if *count < 1 {
                     self.storages.remove(path);
+                    return true;
                 }
-                return true;
+                return false;
             }
         }
     }
@@ -94,7 +171,7 @@ impl Sandbox {
     // containers are using that storage.
     //
     // It's assumed that caller is calling this method after
-    // acquiring a lock on sandbox.
+    // acquiring a lock on the storages.
     pub fn remove_sandbox_storage(&self, path: &str) -> Result<()> {
         let mounts = vec![path.to_string()];
         remove_mounts(&mounts)?;
@@ -107,65 +184,51 @@ impl Sandbox {
     // remove it from the sandbox.
     //
     // It's assumed that caller is calling this method after
-    // acquiring a lock on sandbox.
+    // acquiring a lock on the storages.
     pub fn unset_and_remove_sandbox_storage(&mut self, path: &str) -> Result<()> {
         if self.unset_sandbox_storage(path) {
-            return self.remove_sandbox_storage(path);
-        }
-        Ok(())
-    }
-
-    pub fn is_running(&self) -> bool {
-        self.running
-    }
-
-    pub fn set_hostname(&mut self, hostname: String) {
-        self.hostname = hostname;
-    }
-
-    pub fn setup_shared_namespaces(&mut self) -> Result<bool> {
-        // Set up shared IPC namespace
-        self.shared_ipcns = match Namespace::new(&self.logger).as_ipc().setup() {
-            Ok(ns) => ns,
-            Err(err) => {
-                return Err(ErrorKind::ErrorCode(format!(
-                    "Failed to setup persistent IPC namespace with error: {}",
-                    err
-                ))
-                .into())
+            // watchable storages are copies, not mounts
+            if let Some(w) = self.watchable_storages.remove(path) {
+                return w.remove();
             }
-        };
 
-        // // Set up shared UTS namespace
-        self.shared_utsns = match Namespace::new(&self.logger).as_uts().setup() {
-            Ok(ns) => ns,
-            Err(err) => {
-                return Err(ErrorKind::ErrorCode(format!(
-                    "Failed to setup persistent UTS namespace with error: {}",
-                    err
-                ))
-                .into())
-            }
-        };
-        Ok(true)
-    }
+            self.remove_sandbox_storage(path)?;
 
-    pub fn add_container(&mut self, c: LinuxContainer) {
-        self.containers.insert(c.id.clone(), c);
+            // close the dm-crypt mapping the storage was mounted from
+            if let Some(name) = self.crypt_devices.remove(path) {
+                luks::close(&name)?;
+            }
+        }
+        Ok(())
     }
 
-    pub fn get_container(&mut self, id: &str) -> Option<&mut LinuxContainer> {
-        self.containers.get_mut(id)
-    }
+    // remove_container_mounts unmounts the storages mounted for container
+    // cid, latest first, so that overlays go before the layers they are
+    // made of. Reference counted storages, e.g. layers still shared with
+    // other overlays, are only unmounted when their last user is gone.
+    //
+    // It's assumed that caller is calling this method after
+    // acquiring a lock on the storages.
+    pub fn remove_container_mounts(&mut self, cid: &str) -> Result<()> {
+        let mounts = match self.container_mounts.remove(cid) {
+            Some(mounts) => mounts,
+            None => return Ok(()),
+        };
 
-    pub fn find_process<'a>(&'a mut self, pid: pid_t) -> Option<&'a mut Process> {
-        for (_, c) in self.containers.iter_mut() {
-            if c.processes.get(&pid).is_some() {
-                return c.processes.get_mut(&pid);
+        for m in mounts.iter().rev() {
+            if let Some(layers) = self.overlay_layers.remove(m) {
+                remove_mounts(&vec![m.to_string()])?;
+                for l in layers.iter() {
+                    self.unset_and_remove_sandbox_storage(l)?;
+                }
+            } else if self.storages.contains_key(m) {
+                self.unset_and_remove_sandbox_storage(m)?;
+            } else {
+                remove_mounts(&vec![m.to_string()])?;
             }
         }
 
-        None
+        Ok(())
     }
 
     // set_sandbox_storage sets the sandbox level reference
@@ -175,7 +238,7 @@ impl Sandbox {
     // It will return true if storage is new.
     //
     // It's assumed that caller is calling this method after
-    // acquiring a lock on sandbox.
+    // acquiring a lock on the storages.
     pub fn set_sandbox_storage(&mut self, path: &str) -> bool {
         match self.storages.get_mut(path) {
             None => {
@@ -188,37 +251,99 @@ impl Sandbox {
             }
         }
     }
+}
 
-    pub fn destroy(&mut self) -> Result<()> {
-        for (_, ctr) in &mut self.containers {
-            ctr.destroy()?;
+// setup_shared_namespaces sets up the IPC and UTS namespaces shared by the
+// containers of sandbox.
+pub fn setup_shared_namespaces(sandbox: &Arc<Mutex<Sandbox>>) -> Result<bool> {
+    let logger = sandbox.lock().unwrap().logger.clone();
+
+    // Set up shared IPC namespace
+    let ipcns = match Namespace::new(&logger).as_ipc().setup() {
+        Ok(ns) => ns,
+        Err(err) => {
+            return Err(ErrorKind::ErrorCode(format!(
+                "Failed to setup persistent IPC namespace with error: {}",
+                err
+            ))
+            .into())
         }
-        Ok(())
+    };
+
+    // // Set up shared UTS namespace
+    let utsns = match Namespace::new(&logger).as_uts().setup() {
+        Ok(ns) => ns,
+        Err(err) => {
+            return Err(ErrorKind::ErrorCode(format!(
+                "Failed to setup persistent UTS namespace with error: {}",
+                err
+            ))
+            .into())
+        }
+    };
+
+    let mut s = sandbox.lock().unwrap();
+    s.shared_ipcns = ipcns;
+    s.shared_utsns = utsns;
+    Ok(true)
+}
+
+// destroy destroys the containers of sandbox, those being created once
+// they are.
+pub fn destroy(sandbox: &Arc<Mutex<Sandbox>>) -> Result<()> {
+    let creating: Vec<_> = sandbox.lock().unwrap().creating.values().cloned().collect();
+    for c in creating.iter() {
+        drop(c.lock().unwrap());
     }
 
-    pub fn online_cpu_memory(&self, req: &OnlineCPUMemRequest) -> Result<()> {
-        if req.nb_cpus > 0 {
-            // online cpus
-            online_cpus(&self.logger, req.nb_cpus as i32)?;
-        }
+    let containers = sandbox.lock().unwrap().container_list();
 
-        if !req.cpu_only {
-            // online memory
-            online_memory(&self.logger)?;
-        }
+    for ctr in containers.iter() {
+        ctr.lock().unwrap().destroy()?;
+    }
+    Ok(())
+}
 
-        let cpuset = cgroups::fs::get_guest_cpuset()?;
+pub fn online_cpu_memory(sandbox: &Arc<Mutex<Sandbox>>, req: &OnlineCPUMemRequest) -> Result<()> {
+    let (logger, containers) = {
+        let s = sandbox.lock().unwrap();
+        (s.logger.clone(), s.container_list())
+    };
 
-        for (_, ctr) in self.containers.iter() {
-            info!(self.logger, "updating {}", ctr.id.as_str());
-            ctr.cgroup_manager
-                .as_ref()
-                .unwrap()
-                .update_cpuset_path(cpuset.as_str())?;
-        }
+    if req.nb_cpus > 0 {
+        // online cpus
+        online_cpus(&logger, req.nb_cpus as i32)?;
+    }
 
-        Ok(())
+    if !req.cpu_only {
+        // online memory
+        online_memory(&logger)?;
+    }
+
+    let cpuset = cgroups::fs::get_guest_cpuset()?;
+
+    for ctr in containers.iter() {
+        let ctr = ctr.lock().unwrap();
+        info!(logger, "updating {}", ctr.id.as_str());
+        ctr.cgroup_manager
+            .as_ref()
+            .unwrap()
+            .update_cpuset_path(cpuset.as_str())?;
     }
+
+    Ok(())
+}
+
+// find_process_container returns the container of the process pid.
+pub fn find_process_container(
+    sandbox: &Arc<Mutex<Sandbox>>,
+    pid: pid_t,
+) -> Option<Arc<Mutex<LinuxContainer>>> {
+    let containers = sandbox.lock().unwrap().container_list();
+
+    containers
+        .into_iter()
+        .find(|c| c.lock().unwrap().processes.contains_key(&pid))
 }
 
 pub const CPU_ONLINE_PATH: &'static str = "/sys/devices/system/cpu";
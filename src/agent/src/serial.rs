@@ -0,0 +1,475 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Serving the runtime over a virtio-serial port, for hypervisors without
+// vsock. A port is a single byte stream with nothing like accept(2), so
+// the host multiplexes its connections over it with the yamux protocol of
+// github.com/hashicorp/yamux, as with the Go agent, and each stream is
+// relayed to the agent server listening on a unix socket.
+//
+// A yamux frame is a 12 bytes header, of the version, type, flags, stream
+// ID and length in big endian, followed by length bytes of data for data
+// frames. Each side of a stream may only send as much data as the other
+// opened its window for with window updates. The host opens the streams,
+// and keep alive is left to it. What the host sends on a stream is queued,
+// up to the window, and written to the server by a thread of the stream,
+// so that a server slow to read only stalls its own stream.
+
+use rustjail::errors::*;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "serial"))
+    };
+}
+
+const YAMUX_VERSION: u8 = 0;
+const HEADER_LENGTH: usize = 12;
+
+const TYPE_DATA: u8 = 0x0;
+const TYPE_WINDOW_UPDATE: u8 = 0x1;
+const TYPE_PING: u8 = 0x2;
+const TYPE_GO_AWAY: u8 = 0x3;
+
+const FLAG_SYN: u16 = 0x1;
+const FLAG_ACK: u16 = 0x2;
+const FLAG_FIN: u16 = 0x4;
+const FLAG_RST: u16 = 0x8;
+
+const GO_AWAY_PROTOCOL_ERROR: u32 = 0x1;
+
+const INITIAL_WINDOW: u32 = 256 * 1024;
+const RELAY_BUFFER_SIZE: usize = 64 * 1024;
+
+// how long to wait before reopening a port the host left
+const REOPEN_DELAY: Duration = Duration::from_millis(500);
+
+// serve relays the streams the host opens over port to the unix socket
+// backend, over and over as the host reconnects.
+pub fn serve(port: &str, backend: &str) {
+    let port = port.to_string();
+    let backend = PathBuf::from(backend);
+
+    thread::spawn(move || loop {
+        match OpenOptions::new().read(true).write(true).open(&port) {
+            Ok(f) => {
+                info!(sl!(), "serving"; "port" => port.as_str());
+                if let Err(e) = Session::new(f, &backend).run() {
+                    info!(sl!(), "session closed"; "error" => format!("{}", e));
+                }
+            }
+            Err(e) => {
+                error!(sl!(), "failed to open port";
+                    "port" => port.as_str(),
+                    "error" => format!("{}", e));
+            }
+        }
+
+        thread::sleep(REOPEN_DELAY);
+    });
+}
+
+#[derive(Debug, Default)]
+struct Window {
+    // what may be sent to the host
+    send: u32,
+    // what the host sent, not yet written to the server
+    recv: VecDeque<u8>,
+    // the host closed its side
+    fin: bool,
+    closed: bool,
+}
+
+struct Stream {
+    conn: UnixStream,
+    window: Mutex<Window>,
+    cond: Condvar,
+}
+
+impl Stream {
+    fn close(&self) {
+        let _ = self.conn.shutdown(Shutdown::Both);
+        self.window.lock().unwrap().closed = true;
+        self.cond.notify_all();
+    }
+}
+
+#[derive(Clone)]
+struct Session {
+    port: Arc<Mutex<File>>,
+    streams: Arc<Mutex<HashMap<u32, Arc<Stream>>>>,
+    backend: PathBuf,
+}
+
+impl Session {
+    fn new(port: File, backend: &Path) -> Self {
+        Session {
+            port: Arc::new(Mutex::new(port)),
+            streams: Arc::new(Mutex::new(HashMap::new())),
+            backend: backend.to_path_buf(),
+        }
+    }
+
+    // run reads the frames of the host until it goes away.
+    fn run(&self) -> Result<()> {
+        let mut port = self.port.lock().unwrap().try_clone()?;
+        let res = self.read_frames(&mut port);
+
+        for (_, s) in self.streams.lock().unwrap().drain() {
+            s.close();
+        }
+
+        res
+    }
+
+    fn read_frames(&self, port: &mut File) -> Result<()> {
+        loop {
+            let mut header = [0u8; HEADER_LENGTH];
+            port.read_exact(&mut header)?;
+
+            let version = header[0];
+            let kind = header[1];
+            let flags = u16::from_be_bytes([header[2], header[3]]);
+            let id = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+            let length = u32::from_be_bytes([header[8], header[9], header[10], header[11]]);
+
+            if version != YAMUX_VERSION {
+                self.send(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, &[])?;
+                return Err(ErrorKind::ErrorCode(format!("yamux version {}", version)).into());
+            }
+
+            match kind {
+                TYPE_DATA | TYPE_WINDOW_UPDATE => {
+                    if kind == TYPE_DATA && length > INITIAL_WINDOW {
+                        self.send(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, &[])?;
+                        return Err(ErrorKind::ErrorCode(format!(
+                            "yamux data of {} bytes over the window",
+                            length
+                        ))
+                        .into());
+                    }
+
+                    let mut data = Vec::new();
+                    if kind == TYPE_DATA {
+                        data.resize(length as usize, 0);
+                        port.read_exact(&mut data)?;
+                    }
+                    self.handle_stream_frame(kind, flags, id, length, &data)?;
+                }
+                TYPE_PING => {
+                    if flags & FLAG_SYN != 0 {
+                        self.send(TYPE_PING, FLAG_ACK, 0, length, &[])?;
+                    }
+                }
+                TYPE_GO_AWAY => {
+                    return Err(ErrorKind::ErrorCode(format!("host gone away: {}", length)).into())
+                }
+                _ => {
+                    self.send(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, &[])?;
+                    return Err(ErrorKind::ErrorCode(format!("yamux frame type {}", kind)).into());
+                }
+            }
+        }
+    }
+
+    fn handle_stream_frame(
+        &self,
+        kind: u8,
+        flags: u16,
+        id: u32,
+        length: u32,
+        data: &[u8],
+    ) -> Result<()> {
+        if flags & FLAG_SYN != 0 {
+            self.open_stream(id)?;
+        }
+
+        let stream = match self.streams.lock().unwrap().get(&id) {
+            Some(s) => s.clone(),
+            // the stream is gone, e.g. reset
+            None => return Ok(()),
+        };
+
+        {
+            let mut w = stream.window.lock().unwrap();
+            if kind == TYPE_WINDOW_UPDATE {
+                w.send = w.send.saturating_add(length);
+            } else if w.recv.len() + data.len() > INITIAL_WINDOW as usize {
+                drop(w);
+                self.send(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, &[])?;
+                return Err(ErrorKind::ErrorCode(format!("stream {} over its window", id)).into());
+            } else {
+                w.recv.extend(data);
+            }
+
+            if flags & FLAG_FIN != 0 {
+                w.fin = true;
+            }
+        }
+        stream.cond.notify_all();
+
+        if flags & FLAG_RST != 0 {
+            if let Some(s) = self.streams.lock().unwrap().remove(&id) {
+                s.close();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn open_stream(&self, id: u32) -> Result<()> {
+        if self.streams.lock().unwrap().contains_key(&id) {
+            self.send(TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, &[])?;
+            return Err(ErrorKind::ErrorCode(format!("stream {} opened twice", id)).into());
+        }
+
+        let conn = match UnixStream::connect(&self.backend) {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!(sl!(), "failed to connect to the server"; "error" => format!("{}", e));
+                return self.send(TYPE_WINDOW_UPDATE, FLAG_RST, id, 0, &[]);
+            }
+        };
+
+        let stream = Arc::new(Stream {
+            conn,
+            window: Mutex::new(Window {
+                send: INITIAL_WINDOW,
+                ..Default::default()
+            }),
+            cond: Condvar::new(),
+        });
+        self.streams.lock().unwrap().insert(id, stream.clone());
+        self.send(TYPE_WINDOW_UPDATE, FLAG_ACK, id, 0, &[])?;
+
+        let session = self.clone();
+        let s = stream.clone();
+        thread::spawn(move || {
+            if let Err(e) = session.forward(id, &s) {
+                info!(sl!(), "stream closed"; "id" => id, "error" => format!("{}", e));
+            }
+        });
+
+        let session = self.clone();
+        thread::spawn(move || {
+            if let Err(e) = session.relay(id, &stream) {
+                info!(sl!(), "stream closed"; "id" => id, "error" => format!("{}", e));
+            }
+            if let Some(s) = session.streams.lock().unwrap().remove(&id) {
+                s.close();
+            }
+        });
+
+        Ok(())
+    }
+
+    // forward writes to the server of stream what the host sent on it, and
+    // opens the window of the host as much again.
+    fn forward(&self, id: u32, stream: &Stream) -> Result<()> {
+        loop {
+            let data: Vec<u8> = {
+                let mut w = stream.window.lock().unwrap();
+                while w.recv.is_empty() && !w.fin && !w.closed {
+                    w = stream.cond.wait(w).unwrap();
+                }
+                if w.closed {
+                    return Ok(());
+                }
+                if w.recv.is_empty() {
+                    let _ = stream.conn.shutdown(Shutdown::Write);
+                    return Ok(());
+                }
+                w.recv.drain(..).collect()
+            };
+
+            if (&stream.conn).write_all(&data).is_err() {
+                return self.reset_stream(id);
+            }
+            self.send(TYPE_WINDOW_UPDATE, 0, id, data.len() as u32, &[])?;
+        }
+    }
+
+    fn reset_stream(&self, id: u32) -> Result<()> {
+        if let Some(s) = self.streams.lock().unwrap().remove(&id) {
+            s.close();
+        }
+        self.send(TYPE_WINDOW_UPDATE, FLAG_RST, id, 0, &[])
+    }
+
+    // relay sends to the host what the server writes to stream, as much as
+    // the window of the host takes.
+    fn relay(&self, id: u32, stream: &Stream) -> Result<()> {
+        let mut buf = vec![0u8; RELAY_BUFFER_SIZE];
+
+        loop {
+            let window = {
+                let mut w = stream.window.lock().unwrap();
+                while w.send == 0 && !w.closed {
+                    w = stream.cond.wait(w).unwrap();
+                }
+                if w.closed {
+                    return Ok(());
+                }
+                w.send as usize
+            };
+
+            let n = (&stream.conn).read(&mut buf[..window.min(RELAY_BUFFER_SIZE)])?;
+            if n == 0 {
+                return self.send(TYPE_DATA, FLAG_FIN, id, 0, &[]);
+            }
+
+            stream.window.lock().unwrap().send -= n as u32;
+            self.send(TYPE_DATA, 0, id, n as u32, &buf[..n])?;
+        }
+    }
+
+    fn send(&self, kind: u8, flags: u16, id: u32, length: u32, data: &[u8]) -> Result<()> {
+        let mut frame = Vec::with_capacity(HEADER_LENGTH + data.len());
+        frame.push(YAMUX_VERSION);
+        frame.push(kind);
+        frame.extend_from_slice(&flags.to_be_bytes());
+        frame.extend_from_slice(&id.to_be_bytes());
+        frame.extend_from_slice(&length.to_be_bytes());
+        frame.extend_from_slice(data);
+
+        self.port.lock().unwrap().write_all(&frame)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::io::{FromRawFd, IntoRawFd};
+    use std::os::unix::net::UnixListener;
+    use tempfile::tempdir;
+
+    fn write_frame(host: &mut UnixStream, kind: u8, flags: u16, id: u32, data: &[u8]) {
+        let mut frame = vec![YAMUX_VERSION, kind];
+        frame.extend_from_slice(&flags.to_be_bytes());
+        frame.extend_from_slice(&id.to_be_bytes());
+        frame.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        if kind == TYPE_DATA {
+            frame.extend_from_slice(data);
+        }
+        host.write_all(&frame).unwrap();
+    }
+
+    // read_frame returns the type, flags, stream ID, length and data of
+    // the next frame.
+    fn read_frame(host: &mut UnixStream) -> (u8, u16, u32, u32, Vec<u8>) {
+        let mut h = [0u8; HEADER_LENGTH];
+        host.read_exact(&mut h).unwrap();
+        let flags = u16::from_be_bytes([h[2], h[3]]);
+        let id = u32::from_be_bytes([h[4], h[5], h[6], h[7]]);
+        let length = u32::from_be_bytes([h[8], h[9], h[10], h[11]]);
+
+        let mut data = Vec::new();
+        if h[1] == TYPE_DATA {
+            data.resize(length as usize, 0);
+            host.read_exact(&mut data).unwrap();
+        }
+
+        (h[1], flags, id, length, data)
+    }
+
+    #[test]
+    fn test_session() {
+        let dir = tempdir().unwrap();
+        let backend = dir.path().join("agent.sock");
+
+        // an echo server, standing for the agent one
+        let listener = UnixListener::bind(&backend).unwrap();
+        thread::spawn(move || {
+            for conn in listener.incoming() {
+                let mut conn = conn.unwrap();
+                thread::spawn(move || {
+                    let mut r = conn.try_clone().unwrap();
+                    let _ = std::io::copy(&mut r, &mut conn);
+                });
+            }
+        });
+
+        let (mut host, port) = UnixStream::pair().unwrap();
+        let port = unsafe { File::from_raw_fd(port.into_raw_fd()) };
+        let session = Session::new(port, &backend);
+        let handle = thread::spawn(move || session.run());
+
+        write_frame(&mut host, TYPE_PING, FLAG_SYN, 0, &[0; 7]);
+        assert_eq!(read_frame(&mut host), (TYPE_PING, FLAG_ACK, 0, 7, vec![]));
+
+        write_frame(&mut host, TYPE_WINDOW_UPDATE, FLAG_SYN, 1, &[]);
+        assert_eq!(
+            read_frame(&mut host),
+            (TYPE_WINDOW_UPDATE, FLAG_ACK, 1, 0, vec![])
+        );
+
+        write_frame(&mut host, TYPE_DATA, 0, 1, b"hello");
+        let mut frames = vec![read_frame(&mut host), read_frame(&mut host)];
+        frames.sort();
+        assert_eq!(
+            frames,
+            vec![
+                (TYPE_DATA, 0, 1, 5, b"hello".to_vec()),
+                (TYPE_WINDOW_UPDATE, 0, 1, 5, vec![]),
+            ]
+        );
+
+        // the server closes its side once the host did
+        write_frame(&mut host, TYPE_DATA, FLAG_FIN, 1, &[]);
+        assert_eq!(read_frame(&mut host), (TYPE_DATA, FLAG_FIN, 1, 0, vec![]));
+
+        write_frame(&mut host, TYPE_GO_AWAY, 0, 0, &[]);
+        assert!(handle.join().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_session_limits() {
+        let dir = tempdir().unwrap();
+        let backend = dir.path().join("agent.sock");
+        let _listener = UnixListener::bind(&backend).unwrap();
+
+        let (mut host, port) = UnixStream::pair().unwrap();
+        let port = unsafe { File::from_raw_fd(port.into_raw_fd()) };
+        let session = Session::new(port, &backend);
+        let handle = thread::spawn(move || session.run());
+
+        write_frame(&mut host, TYPE_WINDOW_UPDATE, FLAG_SYN, 1, &[]);
+        assert_eq!(
+            read_frame(&mut host),
+            (TYPE_WINDOW_UPDATE, FLAG_ACK, 1, 0, vec![])
+        );
+
+        // windows opened over and over do not overflow
+        for _ in 0..2 {
+            let mut frame = vec![YAMUX_VERSION, TYPE_WINDOW_UPDATE, 0, 0, 0, 0, 0, 1];
+            frame.extend_from_slice(&u32::max_value().to_be_bytes());
+            host.write_all(&frame).unwrap();
+        }
+        write_frame(&mut host, TYPE_PING, FLAG_SYN, 0, &[0; 3]);
+        assert_eq!(read_frame(&mut host), (TYPE_PING, FLAG_ACK, 0, 3, vec![]));
+
+        // data over the window is not read
+        let mut frame = vec![YAMUX_VERSION, TYPE_DATA, 0, 0, 0, 0, 0, 1];
+        frame.extend_from_slice(&u32::max_value().to_be_bytes());
+        host.write_all(&frame).unwrap();
+        assert_eq!(
+            read_frame(&mut host),
+            (TYPE_GO_AWAY, 0, 0, GO_AWAY_PROTOCOL_ERROR, vec![])
+        );
+        assert!(handle.join().unwrap().is_err());
+    }
+}
@@ -0,0 +1,942 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Traffic control on top of rtnetlink: the ingress qdisc and mirred
+// redirection needed by the tc-redirect network model, plus tbf, htb
+// and fq_codel for shaping.
+
+#![allow(non_camel_case_types)]
+
+use crate::netlink::{
+    RtnlHandle, NLM_F_CREATE, NLM_F_DUMP, NLM_F_EXCL, NLM_F_REQUEST, RTM_DELQDISC, RTM_GETQDISC,
+    RTM_GETTCLASS, RTM_GETTFILTER, RTM_NEWQDISC, RTM_NEWTCLASS, RTM_NEWTFILTER,
+};
+use crate::nlmsg::{NlAttr, NlAttrs, NlMsg, NlMsgBuilder, NlStruct};
+use nix::errno::Errno;
+use protocols::agent::TrafficControl;
+use protocols::types::{MirredAction, Qdisc, TrafficClass, TrafficFilter};
+use rustjail::errors::*;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "tc"))
+    };
+}
+
+pub const TC_H_ROOT: u32 = 0xFFFF_FFFF;
+pub const TC_H_INGRESS: u32 = 0xFFFF_FFF1;
+const TC_H_MAJ_MASK: u32 = 0xFFFF_0000;
+const TC_H_MIN_MASK: u32 = 0x0000_FFFF;
+
+const TCA_KIND: u16 = 1;
+const TCA_OPTIONS: u16 = 2;
+
+const TC_LINKLAYER_ETHERNET: u8 = 1;
+
+// the kernel counts time in ticks of 64ns
+const PSCHED_SHIFT: u32 = 6;
+const NSEC_PER_SEC: u64 = 1_000_000_000;
+
+const TCA_TBF_PARMS: u16 = 1;
+const TCA_TBF_RATE64: u16 = 4;
+
+const TCA_HTB_PARMS: u16 = 1;
+const TCA_HTB_INIT: u16 = 2;
+const TCA_HTB_RATE64: u16 = 6;
+const TCA_HTB_CEIL64: u16 = 7;
+const TC_HTB_PROTOVER: u32 = 3;
+
+const TCA_FQ_CODEL_TARGET: u16 = 1;
+const TCA_FQ_CODEL_LIMIT: u16 = 2;
+const TCA_FQ_CODEL_INTERVAL: u16 = 3;
+const TCA_FQ_CODEL_ECN: u16 = 4;
+const TCA_FQ_CODEL_FLOWS: u16 = 5;
+
+const TCA_U32_CLASSID: u16 = 1;
+const TCA_U32_SEL: u16 = 5;
+const TCA_U32_ACT: u16 = 7;
+const TC_U32_TERMINAL: u8 = 1;
+
+const TCA_MATCHALL_CLASSID: u16 = 1;
+const TCA_MATCHALL_ACT: u16 = 2;
+
+const TCA_ACT_KIND: u16 = 1;
+const TCA_ACT_OPTIONS: u16 = 2;
+const TCA_MIRRED_PARMS: u16 = 2;
+
+const TC_ACT_PIPE: i32 = 3;
+const TC_ACT_STOLEN: i32 = 4;
+
+const TCA_EGRESS_REDIR: i32 = 1;
+const TCA_EGRESS_MIRROR: i32 = 2;
+const TCA_INGRESS_REDIR: i32 = 3;
+const TCA_INGRESS_MIRROR: i32 = 4;
+
+// ethertypes known by name, "all" being ETH_P_ALL
+const PROTOCOLS: [(&str, u16); 5] = [
+    ("all", 0x0003),
+    ("ip", 0x0800),
+    ("ipv6", 0x86DD),
+    ("arp", 0x0806),
+    ("802.1q", 0x8100),
+];
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct tcmsg {
+    pub tcm_family: u8,
+    pub tcm__pad1: u8,
+    pub tcm__pad2: u16,
+    pub tcm_ifindex: i32,
+    pub tcm_handle: u32,
+    pub tcm_parent: u32,
+    pub tcm_info: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_ratespec {
+    cell_log: u8,
+    linklayer: u8,
+    overhead: u16,
+    cell_align: i16,
+    mpu: u16,
+    rate: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_tbf_qopt {
+    rate: tc_ratespec,
+    peakrate: tc_ratespec,
+    limit: u32,
+    buffer: u32,
+    mtu: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_htb_glob {
+    version: u32,
+    rate2quantum: u32,
+    defcls: u32,
+    debug: u32,
+    direct_pkts: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_htb_opt {
+    rate: tc_ratespec,
+    ceil: tc_ratespec,
+    buffer: u32,
+    cbuffer: u32,
+    quantum: u32,
+    level: u32,
+    prio: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_u32_sel {
+    flags: u8,
+    offshift: u8,
+    nkeys: u8,
+    offmask: u16,
+    off: u16,
+    offoff: i16,
+    hoff: i16,
+    hmask: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_u32_key {
+    mask: u32,
+    val: u32,
+    off: i32,
+    offmask: i32,
+}
+
+// u32 selector with a single key matching every packet, which is what
+// "tc filter ... u32 match u32 0 0" sends.
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_u32_sel_all {
+    sel: tc_u32_sel,
+    key: tc_u32_key,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug)]
+struct tc_mirred {
+    index: u32,
+    capab: u32,
+    action: i32,
+    refcnt: i32,
+    bindcnt: i32,
+    eaction: i32,
+    ifindex: u32,
+}
+
+unsafe impl NlStruct for tcmsg {}
+unsafe impl NlStruct for tc_tbf_qopt {}
+unsafe impl NlStruct for tc_htb_glob {}
+unsafe impl NlStruct for tc_htb_opt {}
+unsafe impl NlStruct for tc_u32_sel_all {}
+unsafe impl NlStruct for tc_mirred {}
+
+impl RtnlHandle {
+    // update_traffic_control replaces the qdiscs, classes and filters of
+    // every interface tc refers to, and returns what is configured on
+    // those interfaces afterwards.
+    pub fn update_traffic_control(&mut self, tc: &TrafficControl) -> Result<TrafficControl> {
+        let mut devices: Vec<&str> = Vec::new();
+        let names = tc
+            .get_qdiscs()
+            .iter()
+            .map(|q| q.get_device())
+            .chain(tc.get_classes().iter().map(|c| c.get_device()))
+            .chain(tc.get_filters().iter().map(|f| f.get_device()));
+
+        for name in names {
+            if !devices.contains(&name) {
+                devices.push(name);
+            }
+        }
+
+        info!(sl!(), "update traffic control"; "devices" => format!("{:?}", devices));
+
+        let mut indexes = Vec::new();
+        for name in devices.iter() {
+            indexes.push(self.get_index_by_name(name)?);
+        }
+
+        // removing the root and ingress qdiscs takes their classes and
+        // filters away too
+        for index in indexes.iter() {
+            self.delete_qdisc(*index, TC_H_ROOT)?;
+            self.delete_qdisc(*index, TC_H_INGRESS)?;
+        }
+
+        // qdiscs attached to a class can only come once it exists
+        let (top, leaves): (Vec<&Qdisc>, Vec<&Qdisc>) = tc
+            .get_qdiscs()
+            .iter()
+            .partition(|q| q.get_parent() == "root" || q.get_kind() == "ingress");
+
+        for q in top {
+            self.add_qdisc(q)?;
+        }
+
+        for c in tc.get_classes() {
+            self.add_class(c)?;
+        }
+
+        for q in leaves {
+            self.add_qdisc(q)?;
+        }
+
+        for f in tc.get_filters() {
+            self.add_filter(f)?;
+        }
+
+        self.get_traffic_control(&indexes)
+    }
+
+    // list_traffic_control returns the qdiscs, classes and filters of
+    // device, or of every interface if device is empty.
+    pub fn list_traffic_control(&mut self, device: &str) -> Result<TrafficControl> {
+        if device.is_empty() {
+            return self.get_traffic_control(&[]);
+        }
+
+        let index = self.get_index_by_name(device)?;
+        self.get_traffic_control(&[index])
+    }
+
+    // get_traffic_control lists the interfaces in indexes, all of them
+    // if it is empty.
+    fn get_traffic_control(&mut self, indexes: &[i32]) -> Result<TrafficControl> {
+        let mut tc = TrafficControl::new();
+        let mut qdiscs: Vec<tcmsg> = Vec::new();
+
+        let req = tc_request(RTM_GETQDISC, NLM_F_REQUEST | NLM_F_DUMP, &tcmsg::default());
+        for m in self.dump(req)?.iter() {
+            let tcm: tcmsg = m.header()?;
+            if !indexes.is_empty() && !indexes.contains(&tcm.tcm_ifindex) {
+                continue;
+            }
+
+            tc.qdiscs.push(self.parse_qdisc(m)?);
+            qdiscs.push(tcm);
+        }
+
+        let mut ifindexes: Vec<i32> = qdiscs.iter().map(|q| q.tcm_ifindex).collect();
+        ifindexes.sort();
+        ifindexes.dedup();
+
+        for ifindex in ifindexes {
+            let mut tcm = tcmsg::default();
+            tcm.tcm_ifindex = ifindex;
+
+            let req = tc_request(RTM_GETTCLASS, NLM_F_REQUEST | NLM_F_DUMP, &tcm);
+            for m in self.dump(req)?.iter() {
+                if let Some(c) = self.parse_class(m)? {
+                    tc.classes.push(c);
+                }
+            }
+        }
+
+        // filters are dumped one qdisc at a time, the default qdiscs
+        // with a zero handle cannot have any
+        for q in qdiscs.iter().filter(|q| q.tcm_handle != 0) {
+            let mut tcm = tcmsg::default();
+            tcm.tcm_ifindex = q.tcm_ifindex;
+            tcm.tcm_parent = q.tcm_handle;
+
+            let req = tc_request(RTM_GETTFILTER, NLM_F_REQUEST | NLM_F_DUMP, &tcm);
+            for m in self.dump(req)?.iter() {
+                if let Some(f) = self.parse_filter(m)? {
+                    tc.filters.push(f);
+                }
+            }
+        }
+
+        Ok(tc)
+    }
+
+    fn delete_qdisc(&mut self, index: i32, parent: u32) -> Result<()> {
+        let mut tcm = tcmsg::default();
+        tcm.tcm_ifindex = index;
+        tcm.tcm_parent = parent;
+
+        let req = tc_request(RTM_DELQDISC, NLM_F_REQUEST, &tcm);
+        if let Err(e) = self.rtnl_talk(req, false) {
+            // there is no such qdisc, or only the default one
+            if let ErrorKind::Nix(nix::Error::Sys(errno)) = e.kind() {
+                if *errno == Errno::ENOENT || *errno == Errno::EINVAL {
+                    return Ok(());
+                }
+            }
+
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn add_qdisc(&mut self, q: &Qdisc) -> Result<()> {
+        let mut tcm = tcmsg::default();
+        tcm.tcm_ifindex = self.get_index_by_name(q.get_device())?;
+        tcm.tcm_parent = parse_handle(q.get_parent())?;
+        tcm.tcm_handle = parse_handle(q.get_handle())?;
+
+        if q.get_kind() == "ingress" {
+            tcm.tcm_parent = TC_H_INGRESS;
+            tcm.tcm_handle = TC_H_INGRESS & TC_H_MAJ_MASK;
+        }
+
+        let mut req = tc_request(
+            RTM_NEWQDISC,
+            NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL,
+            &tcm,
+        );
+        req.attr_str(TCA_KIND, q.get_kind());
+
+        match q.get_kind() {
+            "ingress" => {}
+            "tbf" => {
+                if q.get_rate() == 0 || q.get_burst() == 0 {
+                    return Err(
+                        ErrorKind::ErrorCode("tbf needs a rate and a burst".to_string()).into(),
+                    );
+                }
+
+                let mut qopt = tc_tbf_qopt::default();
+                qopt.rate = ratespec(q.get_rate());
+                qopt.buffer = xmit_ticks(q.get_rate(), q.get_burst());
+                qopt.limit = if q.get_limit() != 0 {
+                    q.get_limit()
+                } else {
+                    tbf_limit(q.get_rate(), q.get_burst())
+                };
+
+                let opts = req.nest_start(TCA_OPTIONS);
+                req.attr_struct(TCA_TBF_PARMS, &qopt);
+                if q.get_rate() > u64::from(u32::max_value()) {
+                    req.attr_u64(TCA_TBF_RATE64, q.get_rate());
+                }
+                req.nest_end(opts);
+            }
+            "htb" => {
+                let mut glob = tc_htb_glob::default();
+                glob.version = TC_HTB_PROTOVER;
+                glob.rate2quantum = 10;
+                glob.defcls = q.get_default_class();
+
+                let opts = req.nest_start(TCA_OPTIONS);
+                req.attr_struct(TCA_HTB_INIT, &glob);
+                req.nest_end(opts);
+            }
+            "fq_codel" => {
+                let opts = req.nest_start(TCA_OPTIONS);
+                if q.get_limit() != 0 {
+                    req.attr_u32(TCA_FQ_CODEL_LIMIT, q.get_limit());
+                }
+                if q.get_flows() != 0 {
+                    req.attr_u32(TCA_FQ_CODEL_FLOWS, q.get_flows());
+                }
+                if q.get_target() != 0 {
+                    req.attr_u32(TCA_FQ_CODEL_TARGET, q.get_target());
+                }
+                if q.get_interval() != 0 {
+                    req.attr_u32(TCA_FQ_CODEL_INTERVAL, q.get_interval());
+                }
+                // the kernel enables ecn by default, always say what we want
+                req.attr_u32(TCA_FQ_CODEL_ECN, q.get_ecn() as u32);
+                req.nest_end(opts);
+            }
+            kind => {
+                return Err(ErrorKind::ErrorCode(format!("unsupported qdisc {}", kind)).into());
+            }
+        }
+
+        self.rtnl_talk(req, false)?;
+
+        Ok(())
+    }
+
+    fn add_class(&mut self, c: &TrafficClass) -> Result<()> {
+        if c.get_kind() != "htb" {
+            return Err(ErrorKind::ErrorCode(format!("unsupported class {}", c.get_kind())).into());
+        }
+
+        if c.get_rate() == 0 {
+            return Err(ErrorKind::ErrorCode("htb class needs a rate".to_string()).into());
+        }
+
+        let rate = c.get_rate();
+        let ceil = if c.get_ceil() != 0 {
+            c.get_ceil()
+        } else {
+            rate
+        };
+
+        let mut tcm = tcmsg::default();
+        tcm.tcm_ifindex = self.get_index_by_name(c.get_device())?;
+        tcm.tcm_parent = parse_handle(c.get_parent())?;
+        tcm.tcm_handle = parse_handle(c.get_classid())?;
+
+        let mut opt = tc_htb_opt::default();
+        opt.rate = ratespec(rate);
+        opt.ceil = ratespec(ceil);
+        opt.buffer = xmit_ticks(rate, htb_burst(rate, c.get_burst()));
+        opt.cbuffer = xmit_ticks(ceil, htb_burst(ceil, c.get_cburst()));
+        opt.prio = c.get_prio();
+
+        let mut req = tc_request(
+            RTM_NEWTCLASS,
+            NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL,
+            &tcm,
+        );
+        req.attr_str(TCA_KIND, "htb");
+
+        let opts = req.nest_start(TCA_OPTIONS);
+        req.attr_struct(TCA_HTB_PARMS, &opt);
+        if rate > u64::from(u32::max_value()) {
+            req.attr_u64(TCA_HTB_RATE64, rate);
+        }
+        if ceil > u64::from(u32::max_value()) {
+            req.attr_u64(TCA_HTB_CEIL64, ceil);
+        }
+        req.nest_end(opts);
+
+        self.rtnl_talk(req, false)?;
+
+        Ok(())
+    }
+
+    fn add_filter(&mut self, f: &TrafficFilter) -> Result<()> {
+        let protocol = parse_protocol(f.get_protocol())?;
+        let classid = parse_handle(f.get_classid())?;
+
+        let mut tcm = tcmsg::default();
+        tcm.tcm_ifindex = self.get_index_by_name(f.get_device())?;
+        tcm.tcm_parent = parse_handle(f.get_parent())?;
+        tcm.tcm_info = (f.get_priority() << 16) | u32::from(protocol.to_be());
+
+        let mut req = tc_request(
+            RTM_NEWTFILTER,
+            NLM_F_REQUEST | NLM_F_CREATE | NLM_F_EXCL,
+            &tcm,
+        );
+        req.attr_str(TCA_KIND, f.get_kind());
+
+        let opts = req.nest_start(TCA_OPTIONS);
+        let act = match f.get_kind() {
+            "u32" => {
+                let mut sel = tc_u32_sel_all::default();
+                sel.sel.nkeys = 1;
+                if classid != 0 || f.has_mirred() {
+                    sel.sel.flags = TC_U32_TERMINAL;
+                }
+
+                req.attr_struct(TCA_U32_SEL, &sel);
+                if classid != 0 {
+                    req.attr_u32(TCA_U32_CLASSID, classid);
+                }
+
+                TCA_U32_ACT
+            }
+            "matchall" => {
+                if classid != 0 {
+                    req.attr_u32(TCA_MATCHALL_CLASSID, classid);
+                }
+
+                TCA_MATCHALL_ACT
+            }
+            kind => {
+                return Err(ErrorKind::ErrorCode(format!("unsupported filter {}", kind)).into());
+            }
+        };
+
+        if f.has_mirred() {
+            self.add_mirred(&mut req, act, f.get_mirred())?;
+        }
+        req.nest_end(opts);
+
+        self.rtnl_talk(req, false)?;
+
+        Ok(())
+    }
+
+    // add_mirred adds the action list of a filter, made of m only.
+    fn add_mirred(&mut self, req: &mut NlMsgBuilder, kind: u16, m: &MirredAction) -> Result<()> {
+        let mut p = tc_mirred::default();
+        p.ifindex = self.get_index_by_name(m.get_device())? as u32;
+        p.action = if m.get_mirror() {
+            TC_ACT_PIPE
+        } else {
+            TC_ACT_STOLEN
+        };
+        p.eaction = match (m.get_ingress(), m.get_mirror()) {
+            (false, false) => TCA_EGRESS_REDIR,
+            (false, true) => TCA_EGRESS_MIRROR,
+            (true, false) => TCA_INGRESS_REDIR,
+            (true, true) => TCA_INGRESS_MIRROR,
+        };
+
+        let acts = req.nest_start(kind);
+        // actions are nested by their order, starting at 1
+        let act = req.nest_start(1);
+        req.attr_str(TCA_ACT_KIND, "mirred");
+        let opts = req.nest_start(TCA_ACT_OPTIONS);
+        req.attr_struct(TCA_MIRRED_PARMS, &p);
+        req.nest_end(opts);
+        req.nest_end(act);
+        req.nest_end(acts);
+
+        Ok(())
+    }
+
+    fn device_name(&mut self, index: i32) -> String {
+        self.get_name_by_index(index)
+            .unwrap_or_else(|_| "unknown".to_string())
+    }
+
+    fn parse_qdisc(&mut self, m: &NlMsg) -> Result<Qdisc> {
+        let tcm: tcmsg = m.header()?;
+        let attrs = m.attrs::<tcmsg>()?;
+
+        let mut q = Qdisc::new();
+        q.set_device(self.device_name(tcm.tcm_ifindex));
+        q.set_parent(format_handle(tcm.tcm_parent));
+        q.set_handle(format_handle(tcm.tcm_handle));
+        if let Some(kind) = attrs.get(TCA_KIND) {
+            q.set_kind(kind.as_string()?);
+        }
+
+        // the options of other qdiscs are not always attributes, leave
+        // them alone
+        let opts = match (q.get_kind(), attrs.get(TCA_OPTIONS)) {
+            ("tbf", Some(o)) | ("htb", Some(o)) | ("fq_codel", Some(o)) => o.nested()?,
+            _ => return Ok(q),
+        };
+
+        match q.get_kind() {
+            "tbf" => {
+                if let Some(parms) = opts.get(TCA_TBF_PARMS) {
+                    let qopt: tc_tbf_qopt = parms.as_struct()?;
+                    let rate = rate64(&opts, TCA_TBF_RATE64, &qopt.rate)?;
+                    q.set_rate(rate);
+                    q.set_burst(xmit_size(rate, qopt.buffer));
+                    q.set_limit(qopt.limit);
+                }
+            }
+            "htb" => {
+                if let Some(init) = opts.get(TCA_HTB_INIT) {
+                    let glob: tc_htb_glob = init.as_struct()?;
+                    q.set_default_class(glob.defcls);
+                }
+            }
+            _ => {
+                if let Some(a) = opts.get(TCA_FQ_CODEL_LIMIT) {
+                    q.set_limit(a.as_u32()?);
+                }
+                if let Some(a) = opts.get(TCA_FQ_CODEL_FLOWS) {
+                    q.set_flows(a.as_u32()?);
+                }
+                if let Some(a) = opts.get(TCA_FQ_CODEL_TARGET) {
+                    q.set_target(a.as_u32()?);
+                }
+                if let Some(a) = opts.get(TCA_FQ_CODEL_INTERVAL) {
+                    q.set_interval(a.as_u32()?);
+                }
+                if let Some(a) = opts.get(TCA_FQ_CODEL_ECN) {
+                    q.set_ecn(a.as_u32()? != 0);
+                }
+            }
+        }
+
+        Ok(q)
+    }
+
+    fn parse_class(&mut self, m: &NlMsg) -> Result<Option<TrafficClass>> {
+        let tcm: tcmsg = m.header()?;
+        let attrs = m.attrs::<tcmsg>()?;
+
+        match attrs.get(TCA_KIND) {
+            Some(kind) if kind.as_string()? == "htb" => {}
+            _ => return Ok(None),
+        }
+
+        let mut c = TrafficClass::new();
+        c.set_device(self.device_name(tcm.tcm_ifindex));
+        c.set_parent(format_handle(tcm.tcm_parent));
+        c.set_classid(format_handle(tcm.tcm_handle));
+        c.set_kind("htb".to_string());
+
+        if let Some(o) = attrs.get(TCA_OPTIONS) {
+            let opts = o.nested()?;
+            if let Some(parms) = opts.get(TCA_HTB_PARMS) {
+                let opt: tc_htb_opt = parms.as_struct()?;
+                let rate = rate64(&opts, TCA_HTB_RATE64, &opt.rate)?;
+                let ceil = rate64(&opts, TCA_HTB_CEIL64, &opt.ceil)?;
+                c.set_rate(rate);
+                c.set_ceil(ceil);
+                c.set_burst(xmit_size(rate, opt.buffer));
+                c.set_cburst(xmit_size(ceil, opt.cbuffer));
+                c.set_prio(opt.prio);
+            }
+        }
+
+        Ok(Some(c))
+    }
+
+    fn parse_filter(&mut self, m: &NlMsg) -> Result<Option<TrafficFilter>> {
+        let tcm: tcmsg = m.header()?;
+        let attrs = m.attrs::<tcmsg>()?;
+
+        let kind = match attrs.get(TCA_KIND) {
+            Some(k) => k.as_string()?,
+            None => return Ok(None),
+        };
+
+        let (classid_kind, act_kind) = match kind.as_str() {
+            "u32" => (TCA_U32_CLASSID, TCA_U32_ACT),
+            "matchall" => (TCA_MATCHALL_CLASSID, TCA_MATCHALL_ACT),
+            _ => return Ok(None),
+        };
+
+        let opts = match attrs.get(TCA_OPTIONS) {
+            Some(o) => o.nested()?,
+            None => return Ok(None),
+        };
+
+        // u32 also reports its hash tables, which carry no selector
+        if kind == "u32" && opts.get(TCA_U32_SEL).is_none() {
+            return Ok(None);
+        }
+
+        let mut f = TrafficFilter::new();
+        f.set_device(self.device_name(tcm.tcm_ifindex));
+        f.set_parent(format_handle(tcm.tcm_parent));
+        f.set_priority(tcm.tcm_info >> 16);
+        f.set_protocol(format_protocol(u16::from_be(
+            (tcm.tcm_info & TC_H_MIN_MASK) as u16,
+        )));
+        f.set_kind(kind);
+
+        if let Some(classid) = opts.get(classid_kind) {
+            f.set_classid(format_handle(classid.as_u32()?));
+        }
+
+        if let Some(acts) = opts.get(act_kind) {
+            if let Some(mirred) = self.parse_mirred(&acts)? {
+                f.set_mirred(mirred);
+            }
+        }
+
+        Ok(Some(f))
+    }
+
+    fn parse_mirred(&mut self, acts: &NlAttr) -> Result<Option<MirredAction>> {
+        for act in acts.nested()?.iter() {
+            let attrs = act.nested()?;
+
+            match attrs.get(TCA_ACT_KIND) {
+                Some(kind) if kind.as_string()? == "mirred" => {}
+                _ => continue,
+            }
+
+            let parms = match attrs.get(TCA_ACT_OPTIONS) {
+                Some(o) => o.nested()?.get(TCA_MIRRED_PARMS),
+                None => None,
+            };
+
+            if let Some(parms) = parms {
+                let p: tc_mirred = parms.as_struct()?;
+
+                let mut m = MirredAction::new();
+                m.set_device(self.device_name(p.ifindex as i32));
+                m.set_mirror(p.eaction == TCA_EGRESS_MIRROR || p.eaction == TCA_INGRESS_MIRROR);
+                m.set_ingress(p.eaction == TCA_INGRESS_REDIR || p.eaction == TCA_INGRESS_MIRROR);
+
+                return Ok(Some(m));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+fn tc_request(msg_type: u16, flags: u16, tcm: &tcmsg) -> NlMsgBuilder {
+    let mut req = NlMsgBuilder::new(msg_type, flags);
+    req.push(tcm);
+    req
+}
+
+// parse_handle parses a handle written as tc(8) does, "major:minor" in
+// hexadecimal where a missing minor is zero.
+pub fn parse_handle(s: &str) -> Result<u32> {
+    match s {
+        "" | "none" => return Ok(0),
+        "root" => return Ok(TC_H_ROOT),
+        "ingress" => return Ok(TC_H_INGRESS),
+        _ => {}
+    }
+
+    let invalid = || Error::from(ErrorKind::ErrorCode(format!("invalid tc handle {}", s)));
+
+    let mut parts = s.splitn(2, ':');
+    let major = parts.next().unwrap_or("");
+    let minor = parts.next().ok_or_else(invalid)?;
+
+    let parse = |v: &str| {
+        if v.is_empty() {
+            Ok(0)
+        } else {
+            u16::from_str_radix(v, 16).map_err(|_| invalid())
+        }
+    };
+
+    Ok((u32::from(parse(major)?) << 16) | u32::from(parse(minor)?))
+}
+
+pub fn format_handle(h: u32) -> String {
+    match h {
+        0 => String::new(),
+        TC_H_ROOT => "root".to_string(),
+        TC_H_INGRESS => "ingress".to_string(),
+        _ if h & TC_H_MIN_MASK == 0 => format!("{:x}:", h >> 16),
+        _ => format!("{:x}:{:x}", h >> 16, h & TC_H_MIN_MASK),
+    }
+}
+
+fn parse_protocol(s: &str) -> Result<u16> {
+    if s.is_empty() {
+        return Ok(PROTOCOLS[0].1);
+    }
+
+    if let Some(&(_, p)) = PROTOCOLS.iter().find(|(name, _)| *name == s) {
+        return Ok(p);
+    }
+
+    if s.starts_with("0x") {
+        if let Ok(p) = u16::from_str_radix(&s[2..], 16) {
+            return Ok(p);
+        }
+    }
+
+    Err(ErrorKind::ErrorCode(format!("invalid protocol {}", s)).into())
+}
+
+fn format_protocol(p: u16) -> String {
+    match PROTOCOLS.iter().find(|(_, v)| *v == p) {
+        Some((name, _)) => name.to_string(),
+        None => format!("0x{:04x}", p),
+    }
+}
+
+fn ratespec(rate: u64) -> tc_ratespec {
+    tc_ratespec {
+        linklayer: TC_LINKLAYER_ETHERNET,
+        rate: rate.min(u64::from(u32::max_value())) as u32,
+        ..Default::default()
+    }
+}
+
+// rate64 returns the rate of spec, or the 64 bits attribute kind when
+// the rate does not fit in 32 bits.
+fn rate64(opts: &NlAttrs, kind: u16, spec: &tc_ratespec) -> Result<u64> {
+    match opts.get(kind) {
+        Some(a) => Ok(a.as_u64()?),
+        None => Ok(u64::from(spec.rate)),
+    }
+}
+
+// xmit_ticks is the time needed to send size bytes at rate bytes per
+// second, in kernel ticks.
+fn xmit_ticks(rate: u64, size: u32) -> u32 {
+    if rate == 0 {
+        return 0;
+    }
+
+    let ns = u64::from(size) * NSEC_PER_SEC / rate;
+    (ns >> PSCHED_SHIFT).min(u64::from(u32::max_value())) as u32
+}
+
+// xmit_size is the reverse of xmit_ticks.
+fn xmit_size(rate: u64, ticks: u32) -> u32 {
+    let ns = u128::from(ticks) << PSCHED_SHIFT;
+    let size = ns * u128::from(rate) / u128::from(NSEC_PER_SEC);
+    size.min(u128::from(u32::max_value())) as u32
+}
+
+// tbf_limit allows 25ms of queueing on top of the burst, as the kernel
+// documentation suggests.
+fn tbf_limit(rate: u64, burst: u32) -> u32 {
+    let limit = rate * 25 / 1000 + u64::from(burst);
+    limit.min(u64::from(u32::max_value())) as u32
+}
+
+// htb_burst defaults to 1ms worth of rate plus a full sized frame.
+fn htb_burst(rate: u64, burst: u32) -> u32 {
+    if burst != 0 {
+        return burst;
+    }
+
+    (rate / 1000 + 1600).min(u64::from(u32::max_value())) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nlmsg::parse_messages;
+    use std::mem;
+
+    #[test]
+    fn test_handle() {
+        let cases = [
+            ("", 0, ""),
+            ("root", TC_H_ROOT, "root"),
+            ("ingress", TC_H_INGRESS, "ingress"),
+            ("1:", 0x0001_0000, "1:"),
+            ("1:10", 0x0001_0010, "1:10"),
+            ("ffff:", 0xFFFF_0000, "ffff:"),
+            (":a", 0x0000_000a, "0:a"),
+        ];
+
+        for (s, h, f) in cases.iter() {
+            assert_eq!(parse_handle(s).unwrap(), *h, "{}", s);
+            assert_eq!(format_handle(*h), *f);
+        }
+
+        for s in ["1", "1:2:3", "10000:", "x:"].iter() {
+            assert!(parse_handle(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_protocol() {
+        assert_eq!(parse_protocol("").unwrap(), 0x0003);
+        assert_eq!(parse_protocol("ip").unwrap(), 0x0800);
+        assert_eq!(parse_protocol("0x88cc").unwrap(), 0x88cc);
+        assert!(parse_protocol("ipx").is_err());
+        assert!(parse_protocol("0x").is_err());
+
+        assert_eq!(format_protocol(0x86DD), "ipv6");
+        assert_eq!(format_protocol(0x88cc), "0x88cc");
+    }
+
+    #[test]
+    fn test_struct_size() {
+        assert_eq!(mem::size_of::<tcmsg>(), 20);
+        assert_eq!(mem::size_of::<tc_tbf_qopt>(), 36);
+        assert_eq!(mem::size_of::<tc_htb_glob>(), 20);
+        assert_eq!(mem::size_of::<tc_htb_opt>(), 44);
+        assert_eq!(mem::size_of::<tc_u32_sel_all>(), 32);
+        assert_eq!(mem::size_of::<tc_mirred>(), 28);
+    }
+
+    #[test]
+    fn test_xmit() {
+        // 1500 bytes at 1Mbit/s take 12ms
+        let ticks = xmit_ticks(125_000, 1500);
+        assert_eq!(ticks, 12_000_000 >> PSCHED_SHIFT);
+        assert_eq!(xmit_size(125_000, ticks), 1500);
+
+        assert_eq!(xmit_ticks(0, 1500), 0);
+        assert_eq!(xmit_ticks(1, u32::max_value()), u32::max_value());
+        assert_eq!(
+            xmit_size(u64::max_value(), u32::max_value()),
+            u32::max_value()
+        );
+
+        assert_eq!(tbf_limit(125_000, 1500), 3125 + 1500);
+        assert_eq!(htb_burst(125_000, 0), 1725);
+        assert_eq!(htb_burst(125_000, 3000), 3000);
+    }
+
+    #[test]
+    fn test_request() {
+        let mut tcm = tcmsg::default();
+        tcm.tcm_ifindex = 4;
+        tcm.tcm_parent = TC_H_INGRESS & TC_H_MAJ_MASK;
+        tcm.tcm_info = (1 << 16) | u32::from(0x0003u16.to_be());
+
+        let mut req = tc_request(RTM_NEWTFILTER, NLM_F_REQUEST, &tcm);
+        req.attr_str(TCA_KIND, "matchall");
+        let opts = req.nest_start(TCA_OPTIONS);
+        req.attr_u32(TCA_MATCHALL_CLASSID, 0x0001_0010);
+        req.nest_end(opts);
+
+        let buf = req.finish().unwrap();
+        let msgs: Vec<NlMsg> = parse_messages(&buf).map(|m| m.unwrap()).collect();
+        assert_eq!(msgs.len(), 1);
+
+        let m = &msgs[0];
+        assert_eq!(m.msg_type, RTM_NEWTFILTER);
+
+        let hdr: tcmsg = m.header().unwrap();
+        assert_eq!(hdr.tcm_ifindex, 4);
+        assert_eq!(format_handle(hdr.tcm_parent), "ffff:");
+        assert_eq!(hdr.tcm_info >> 16, 1);
+        assert_eq!(format_protocol(u16::from_be(hdr.tcm_info as u16)), "all");
+
+        let attrs = m.attrs::<tcmsg>().unwrap();
+        assert_eq!(
+            attrs.get(TCA_KIND).unwrap().as_string().unwrap(),
+            "matchall"
+        );
+        let opts = attrs.get(TCA_OPTIONS).unwrap().nested().unwrap();
+        assert_eq!(
+            opts.get(TCA_MATCHALL_CLASSID).unwrap().as_u32().unwrap(),
+            0x0001_0010
+        );
+    }
+}
@@ -0,0 +1,522 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Tracing of the RPCs and of the major steps they take, as spans exported
+// to a collector of the host in the JSON encoding of OTLP, the OpenTelemetry
+// protocol: an ExportTraceServiceRequest per batch of spans, framed by its
+// length as 8 bytes in big endian.
+//
+// The spans started on a thread while another is open are its children, and
+// the span of an RPC continues the trace of the runtime when its metadata
+// carry the context of a span, as a W3C traceparent or a Jaeger
+// uber-trace-id.
+
+use crate::config::ServerAddr;
+use crate::ttrpc;
+use rustjail::errors::*;
+use std::any::Any;
+use std::cell::Cell;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::unix::io::{FromRawFd, IntoRawFd};
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "tracer"))
+    };
+}
+
+const SERVICE_NAME: &'static str = "kata-agent";
+
+const TRACEPARENT_KEY: &'static str = "traceparent";
+const UBER_TRACE_ID_KEY: &'static str = "uber-trace-id";
+
+// OTLP span kinds
+const SPAN_KIND_INTERNAL: u32 = 1;
+const SPAN_KIND_SERVER: u32 = 2;
+
+// the spans finished before the exporter takes them, past which they are
+// dropped
+const SPAN_QUEUE_SIZE: usize = 4096;
+const BATCH_SIZE: usize = 512;
+const BATCH_TIMEOUT: Duration = Duration::from_secs(1);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SpanContext {
+    trace_id: u128,
+    span_id: u64,
+}
+
+#[derive(Debug)]
+struct SpanData {
+    context: SpanContext,
+    parent_id: u64,
+    name: String,
+    kind: u32,
+    start: SystemTime,
+    end: SystemTime,
+    attributes: Vec<(String, String)>,
+}
+
+enum Message {
+    Span(SpanData),
+    // exports the spans taken so far and tells when done
+    Flush(mpsc::Sender<()>),
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref EXPORTER: Mutex<Option<SyncSender<Message>>> = Mutex::new(None);
+}
+
+thread_local! {
+    // the span open on the thread, the parent of the next one
+    static CURRENT: Cell<Option<SpanContext>> = Cell::new(None);
+    static RANDOM: Cell<u64> = Cell::new(random_seed());
+}
+
+// start traces, exporting the spans to the collector at addr. The collector
+// is the one started with first.
+pub fn start(addr: &ServerAddr) -> Result<()> {
+    let mut exporter = EXPORTER.lock().unwrap();
+
+    if exporter.is_none() {
+        match addr {
+            ServerAddr::Vsock(_, _) | ServerAddr::Unix(_) => (),
+            _ => {
+                return Err(
+                    ErrorKind::ErrorCode(format!("invalid trace collector {:?}", addr)).into(),
+                )
+            }
+        }
+
+        let (tx, rx) = mpsc::sync_channel(SPAN_QUEUE_SIZE);
+        let addr = addr.clone();
+        thread::spawn(move || export(addr, rx));
+        *exporter = Some(tx);
+
+        // rustjail traces its steps as well
+        rustjail::trace::set_span_hook(rustjail_span);
+    }
+
+    ENABLED.store(true, Ordering::SeqCst);
+    info!(sl!(), "tracing started"; "collector" => format!("{:?}", addr));
+
+    Ok(())
+}
+
+// stop stops tracing, once the spans finished are exported.
+pub fn stop() {
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let exporter = EXPORTER.lock().unwrap().clone();
+    if let Some(exporter) = exporter {
+        let (tx, rx) = mpsc::channel();
+        if exporter.send(Message::Flush(tx)).is_ok() {
+            let _ = rx.recv_timeout(FLUSH_TIMEOUT);
+        }
+    }
+
+    info!(sl!(), "tracing stopped");
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+// A Span is open until dropped, when it is exported.
+pub struct Span {
+    data: Option<SpanData>,
+    // the span open before on the thread
+    previous: Option<SpanContext>,
+}
+
+impl Span {
+    fn new(name: &str, kind: u32, parent: Option<SpanContext>) -> Span {
+        if !is_enabled() {
+            return Span {
+                data: None,
+                previous: None,
+            };
+        }
+
+        let context = SpanContext {
+            trace_id: parent.map(|p| p.trace_id).unwrap_or_else(random_trace_id),
+            span_id: random_id(),
+        };
+        let previous = CURRENT.with(|c| c.replace(Some(context)));
+
+        Span {
+            data: Some(SpanData {
+                context: context,
+                parent_id: parent.map(|p| p.span_id).unwrap_or(0),
+                name: name.to_string(),
+                kind: kind,
+                start: SystemTime::now(),
+                end: UNIX_EPOCH,
+                attributes: Vec::new(),
+            }),
+            previous: previous,
+        }
+    }
+
+    pub fn set_attribute(&mut self, key: &str, value: &str) {
+        if let Some(data) = self.data.as_mut() {
+            data.attributes.push((key.to_string(), value.to_string()));
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let mut data = match self.data.take() {
+            Some(data) => data,
+            None => return,
+        };
+
+        data.end = SystemTime::now();
+        CURRENT.with(|c| c.set(self.previous));
+
+        if let Some(exporter) = EXPORTER.lock().unwrap().as_ref() {
+            if let Err(TrySendError::Full(_)) = exporter.try_send(Message::Span(data)) {
+                warn!(sl!(), "span dropped, the exporter is late");
+            }
+        }
+    }
+}
+
+// span starts a span, the child of the one open on the thread.
+pub fn span(name: &str) -> Span {
+    let parent = CURRENT.with(|c| c.get());
+    Span::new(name, SPAN_KIND_INTERNAL, parent)
+}
+
+// rpc_span starts the span of the RPC method, in the trace of the span of
+// the runtime, if its metadata, which are only read when tracing, tell it.
+pub fn rpc_span<F>(method: &str, metadata: F) -> Span
+where
+    F: FnOnce() -> Vec<(String, String)>,
+{
+    if !is_enabled() {
+        return Span::new(method, SPAN_KIND_SERVER, None);
+    }
+
+    let parent = metadata().iter().filter_map(parse_parent).next();
+    let mut span = Span::new(method, SPAN_KIND_SERVER, parent);
+    span.set_attribute("rpc.method", method);
+    span
+}
+
+fn rustjail_span(name: &str, attributes: &[(&str, &str)]) -> Box<dyn Any> {
+    let mut span = span(name);
+    for (key, value) in attributes.iter() {
+        span.set_attribute(key, value);
+    }
+    Box::new(span)
+}
+
+// parse_parent parses the context of a span of the runtime, from the
+// metadata entry key and value.
+fn parse_parent((key, value): &(String, String)) -> Option<SpanContext> {
+    let (trace_id, span_id) = match key.to_lowercase().as_str() {
+        // version-trace_id-parent_id-flags
+        TRACEPARENT_KEY => {
+            let fields: Vec<&str> = value.split('-').collect();
+            if fields.len() != 4 || fields[1].len() != 32 || fields[2].len() != 16 {
+                return None;
+            }
+            (fields[1], fields[2])
+        }
+        // trace_id:span_id:parent_span_id:flags, possibly URL encoded
+        UBER_TRACE_ID_KEY => {
+            let value = value.replace("%3A", ":").replace("%3a", ":");
+            let fields: Vec<&str> = value.split(':').collect();
+            if fields.len() != 4 || fields[0].len() > 32 || fields[1].len() > 16 {
+                return None;
+            }
+            let context = SpanContext {
+                trace_id: u128::from_str_radix(fields[0], 16).ok()?,
+                span_id: u64::from_str_radix(fields[1], 16).ok()?,
+            };
+            return Some(context).filter(|c| c.trace_id != 0 && c.span_id != 0);
+        }
+        _ => return None,
+    };
+
+    let context = SpanContext {
+        trace_id: u128::from_str_radix(trace_id, 16).ok()?,
+        span_id: u64::from_str_radix(span_id, 16).ok()?,
+    };
+
+    Some(context).filter(|c| c.trace_id != 0 && c.span_id != 0)
+}
+
+// export sends the spans of rx to the collector at addr, by batches.
+fn export(addr: ServerAddr, rx: Receiver<Message>) {
+    let mut conn = None;
+    let mut batch = Vec::new();
+
+    loop {
+        match rx.recv_timeout(BATCH_TIMEOUT) {
+            Ok(Message::Span(span)) => {
+                batch.push(span);
+                if batch.len() < BATCH_SIZE {
+                    continue;
+                }
+            }
+            Ok(Message::Flush(done)) => {
+                send_batch(&addr, &mut conn, &mut batch);
+                let _ = done.send(());
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+
+        send_batch(&addr, &mut conn, &mut batch);
+    }
+}
+
+// send_batch sends the spans of batch, over conn, connected again once
+// when lost. They are dropped when the collector is not there.
+fn send_batch(addr: &ServerAddr, conn: &mut Option<File>, batch: &mut Vec<SpanData>) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let doc = encode(batch);
+    let mut frame = (doc.len() as u64).to_be_bytes().to_vec();
+    frame.extend_from_slice(&doc);
+
+    for _ in 0..2 {
+        if conn.is_none() {
+            match connect(addr) {
+                Ok(c) => *conn = Some(c),
+                Err(e) => {
+                    warn!(sl!(), "failed to connect to the trace collector";
+                        "error" => format!("{}", e));
+                    break;
+                }
+            }
+        }
+
+        if conn.as_mut().unwrap().write_all(&frame).is_ok() {
+            break;
+        }
+        *conn = None;
+    }
+
+    batch.clear();
+}
+
+fn connect(addr: &ServerAddr) -> Result<File> {
+    match addr {
+        ServerAddr::Vsock(cid, port) => ttrpc::connect_vsock(*cid, *port as u32),
+        ServerAddr::Unix(path) => {
+            let fd = UnixStream::connect(path)?.into_raw_fd();
+            Ok(unsafe { File::from_raw_fd(fd) })
+        }
+        _ => Err(ErrorKind::ErrorCode(format!("invalid trace collector {:?}", addr)).into()),
+    }
+}
+
+// encode encodes spans as an OTLP ExportTraceServiceRequest.
+fn encode(spans: &[SpanData]) -> Vec<u8> {
+    let spans: Vec<serde_json::Value> = spans
+        .iter()
+        .map(|s| {
+            let mut span = serde_json::json!({
+                "traceId": format!("{:032x}", s.context.trace_id),
+                "spanId": format!("{:016x}", s.context.span_id),
+                "name": s.name,
+                "kind": s.kind,
+                "startTimeUnixNano": unix_nanos(s.start).to_string(),
+                "endTimeUnixNano": unix_nanos(s.end).to_string(),
+                "attributes": attributes(&s.attributes),
+            });
+            if s.parent_id != 0 {
+                span["parentSpanId"] = serde_json::json!(format!("{:016x}", s.parent_id));
+            }
+            span
+        })
+        .collect();
+
+    let service = vec![("service.name".to_string(), SERVICE_NAME.to_string())];
+    let req = serde_json::json!({
+        "resourceSpans": [{
+            "resource": { "attributes": attributes(&service) },
+            "scopeSpans": [{
+                "scope": { "name": SERVICE_NAME },
+                "spans": spans,
+            }],
+        }],
+    });
+
+    req.to_string().into_bytes()
+}
+
+fn attributes(attributes: &[(String, String)]) -> serde_json::Value {
+    attributes
+        .iter()
+        .map(|(k, v)| serde_json::json!({ "key": k, "value": { "stringValue": v } }))
+        .collect()
+}
+
+fn unix_nanos(t: SystemTime) -> u128 {
+    t.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+fn random_seed() -> u64 {
+    let mut seed = [0u8; 8];
+    if let Ok(mut f) = File::open("/dev/urandom") {
+        let _ = f.read_exact(&mut seed);
+    }
+
+    u64::from_ne_bytes(seed) ^ unix_nanos(SystemTime::now()) as u64
+}
+
+// random_id returns a random, non zero, ID, by splitmix64.
+fn random_id() -> u64 {
+    RANDOM.with(|r| loop {
+        let state = r.get().wrapping_add(0x9e3779b97f4a7c15);
+        r.set(state);
+
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        if z != 0 {
+            return z;
+        }
+    })
+}
+
+fn random_trace_id() -> u128 {
+    (random_id() as u128) << 64 | random_id() as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixListener;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_parent() {
+        let entry = |k: &str, v: &str| (k.to_string(), v.to_string());
+
+        assert_eq!(
+            parse_parent(&entry(
+                "traceparent",
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+            )),
+            Some(SpanContext {
+                trace_id: 0x0af7651916cd43dd8448eb211c80319c,
+                span_id: 0xb7ad6b7169203331,
+            })
+        );
+        assert_eq!(
+            parse_parent(&entry("uber-trace-id", "5f2a1b%3A3c4d%3A0%3A1")),
+            Some(SpanContext {
+                trace_id: 0x5f2a1b,
+                span_id: 0x3c4d,
+            })
+        );
+
+        assert!(parse_parent(&entry("traceparent", "00-0af7-b7ad-01")).is_none());
+        assert!(parse_parent(&entry(
+            "traceparent",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01"
+        ))
+        .is_none());
+        assert!(parse_parent(&entry("uber-trace-id", "xyz:1:0:1")).is_none());
+        assert!(parse_parent(&entry("user-agent", "grpc-go/1.23.0")).is_none());
+    }
+
+    #[test]
+    fn test_tracer() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("collector.sock");
+
+        // a collector stand-in, returning the batches it gets until those
+        // of the trace below. The tests running meanwhile may be traced
+        // too, in traces of their own.
+        const TRACE_ID: &'static str = "0af7651916cd43dd8448eb211c80319c";
+        let listener = UnixListener::bind(&path).unwrap();
+        let collector = thread::spawn(move || {
+            let (mut conn, _) = listener.accept().unwrap();
+            let mut reqs = Vec::new();
+            let mut spans = Vec::new();
+            while spans.len() < 2 {
+                let mut len = [0u8; 8];
+                conn.read_exact(&mut len).unwrap();
+                let mut doc = vec![0u8; u64::from_be_bytes(len) as usize];
+                conn.read_exact(&mut doc).unwrap();
+                let req = serde_json::from_slice::<serde_json::Value>(&doc).unwrap();
+                spans.extend(
+                    req["resourceSpans"][0]["scopeSpans"][0]["spans"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .filter(|s| s["traceId"] == TRACE_ID)
+                        .cloned(),
+                );
+                reqs.push(req);
+            }
+            (reqs, spans)
+        });
+
+        start(&ServerAddr::Unix(path.to_str().unwrap().to_string())).unwrap();
+
+        {
+            let _rpc = rpc_span("CreateContainer", || {
+                vec![(
+                    "traceparent".to_string(),
+                    format!("00-{}-b7ad6b7169203331-01", TRACE_ID),
+                )]
+            });
+            let mut step = span("wait_device");
+            step.set_attribute("device", "vda");
+        }
+
+        stop();
+
+        // untraced
+        drop(span("ignored"));
+
+        let (reqs, spans) = collector.join().unwrap();
+        assert_eq!(spans.len(), 2, "{:?}", reqs);
+
+        // the inner span ends first
+        let (step, rpc) = (&spans[0], &spans[1]);
+
+        assert_eq!(rpc["name"], "CreateContainer");
+        assert_eq!(rpc["kind"], SPAN_KIND_SERVER);
+        assert_eq!(rpc["traceId"], TRACE_ID);
+        assert_eq!(rpc["parentSpanId"], "b7ad6b7169203331");
+
+        assert_eq!(step["name"], "wait_device");
+        assert_eq!(step["traceId"], TRACE_ID);
+        assert_eq!(step["parentSpanId"], rpc["spanId"]);
+        assert_eq!(step["attributes"][0]["key"], "device");
+        assert_eq!(step["attributes"][0]["value"]["stringValue"], "vda");
+
+        assert_eq!(
+            reqs[0]["resourceSpans"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            SERVICE_NAME
+        );
+    }
+}
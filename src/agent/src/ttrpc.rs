@@ -0,0 +1,453 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// A server of the ttRPC protocol of github.com/containerd/ttrpc, which
+// carries the messages of gRPC services with far less machinery: each
+// message is framed by a 10 bytes header, the length of the message and
+// the ID of its stream in big endian, then its type and flags. Requests
+// are served each by a thread of its own, and answered on the stream
+// they came on, since a client has several in flight, e.g. WaitProcess.
+// Connections and the requests in flight on each are bounded, so that a
+// client cannot exhaust the threads of the guest: those over the bounds
+// are closed or answered RESOURCE_EXHAUSTED respectively.
+
+use nix::sys::socket::{self, AddressFamily, SockFlag, SockType};
+use nix::unistd;
+use protobuf::Message;
+use protocols::ttrpc::Response;
+pub use protocols::ttrpc::{Request, Status};
+use rustjail::errors::*;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::unix::io::{FromRawFd, IntoRawFd, RawFd};
+use std::os::unix::net::UnixListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "ttrpc"))
+    };
+}
+
+const MESSAGE_HEADER_LENGTH: usize = 10;
+const MESSAGE_LENGTH_MAX: usize = 4 << 20;
+const MESSAGE_TYPE_REQUEST: u8 = 0x1;
+const MESSAGE_TYPE_RESPONSE: u8 = 0x2;
+
+const CONNECTIONS_MAX: usize = 64;
+const REQUESTS_IN_FLIGHT_MAX: usize = 128;
+
+const VSOCK_SCHEME: &'static str = "vsock://";
+const UNIX_SCHEME: &'static str = "unix://";
+
+// The gRPC status codes the server answers with.
+pub const CODE_INVALID_ARGUMENT: i32 = 3;
+pub const CODE_NOT_FOUND: i32 = 5;
+pub const CODE_PERMISSION_DENIED: i32 = 7;
+pub const CODE_RESOURCE_EXHAUSTED: i32 = 8;
+pub const CODE_UNIMPLEMENTED: i32 = 12;
+pub const CODE_INTERNAL: i32 = 13;
+
+// MethodHandler serves the requests of the methods of a service, by name,
+// with their payload encoded and returning their response encoded.
+pub type MethodHandler =
+    Arc<dyn Fn(&Request) -> std::result::Result<Vec<u8>, Status> + Send + Sync>;
+
+pub fn status(code: i32, message: &str) -> Status {
+    let mut status = Status::new();
+    status.set_code(code);
+    status.set_message(message.to_string());
+    status
+}
+
+pub struct Server {
+    services: Arc<HashMap<String, MethodHandler>>,
+    listener: Option<RawFd>,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Server {
+            services: Arc::new(HashMap::new()),
+            listener: None,
+        }
+    }
+
+    // register_service serves the service, as named in its proto with the
+    // package, e.g. grpc.AgentService, with handler.
+    pub fn register_service(mut self, name: &str, handler: MethodHandler) -> Self {
+        Arc::get_mut(&mut self.services)
+            .unwrap()
+            .insert(name.to_string(), handler);
+        self
+    }
+
+    // bind listens on host, vsock://-1 for any CID or unix:///path, and
+    // port, which unix sockets have none of.
+    pub fn bind(mut self, host: &str, port: u16) -> Result<Self> {
+        let fd = if host.starts_with(VSOCK_SCHEME) {
+            let cid = host[VSOCK_SCHEME.len()..].parse::<i32>()?;
+            listen_vsock(cid as u32, port as u32)?
+        } else if host.starts_with(UNIX_SCHEME) {
+            listen_unix(&host[UNIX_SCHEME.len()..])?
+        } else {
+            return Err(ErrorKind::ErrorCode(format!("unsupported address {}", host)).into());
+        };
+
+        self.listener = Some(fd);
+        Ok(self)
+    }
+
+    // start accepts connections until shutdown.
+    pub fn start(&mut self) -> Result<()> {
+        let listener = match self.listener {
+            Some(fd) => fd,
+            None => return Err(ErrorKind::ErrorCode("ttRPC server not bound".to_string()).into()),
+        };
+
+        let services = self.services.clone();
+        let connections = Slots::new(CONNECTIONS_MAX);
+        thread::Builder::new()
+            .name("ttrpc-accept".to_string())
+            .spawn(move || loop {
+                let fd = match socket::accept4(listener, SockFlag::SOCK_CLOEXEC) {
+                    Ok(fd) => fd,
+                    Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => continue,
+                    Err(e) => {
+                        info!(sl!(), "stop accepting connections"; "error" => format!("{}", e));
+                        break;
+                    }
+                };
+                // closed when dropped, should it not be served
+                let conn = unsafe { File::from_raw_fd(fd) };
+
+                let slot = match connections.acquire() {
+                    Some(slot) => slot,
+                    None => {
+                        warn!(sl!(), "too many connections, closing"; "max" => CONNECTIONS_MAX);
+                        continue;
+                    }
+                };
+
+                let services = services.clone();
+                let res = thread::Builder::new()
+                    .name("ttrpc-conn".to_string())
+                    .spawn(move || {
+                        let _slot = slot;
+                        if let Err(e) = serve(conn, services) {
+                            info!(sl!(), "connection closed"; "error" => format!("{}", e));
+                        }
+                    });
+                if let Err(e) = res {
+                    warn!(sl!(), "failed to serve connection"; "error" => format!("{}", e));
+                }
+            })?;
+
+        Ok(())
+    }
+
+    pub fn shutdown(&mut self) {
+        if let Some(fd) = self.listener.take() {
+            // wakes up accept(2)
+            let _ = socket::shutdown(fd, socket::Shutdown::Both);
+            let _ = unistd::close(fd);
+        }
+    }
+}
+
+pub fn listen_vsock(cid: u32, port: u32) -> Result<RawFd> {
+    let fd = socket::socket(
+        AddressFamily::Vsock,
+        SockType::Stream,
+        SockFlag::SOCK_CLOEXEC,
+        None,
+    )?;
+
+    // nix knows not of sockaddr_vm
+    let mut addr: libc::sockaddr_vm = unsafe { mem::zeroed() };
+    addr.svm_family = libc::AF_VSOCK as libc::sa_family_t;
+    addr.svm_cid = cid;
+    addr.svm_port = port;
+    let ret = unsafe {
+        libc::bind(
+            fd,
+            &addr as *const libc::sockaddr_vm as *const libc::sockaddr,
+            mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+        )
+    };
+
+    if let Err(e) = nix::errno::Errno::result(ret).and_then(|_| socket::listen(fd, 128)) {
+        let _ = unistd::close(fd);
+        return Err(e.into());
+    }
+
+    Ok(fd)
+}
+
+pub fn connect_vsock(cid: u32, port: u32) -> Result<File> {
+    let fd = socket::socket(
+        AddressFamily::Vsock,
+        SockType::Stream,
+        SockFlag::SOCK_CLOEXEC,
+        None,
+    )?;
+    let conn = unsafe { File::from_raw_fd(fd) };
+
+    let mut addr: libc::sockaddr_vm = unsafe { mem::zeroed() };
+    addr.svm_family = libc::AF_VSOCK as libc::sa_family_t;
+    addr.svm_cid = cid;
+    addr.svm_port = port;
+    let ret = unsafe {
+        libc::connect(
+            fd,
+            &addr as *const libc::sockaddr_vm as *const libc::sockaddr,
+            mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+        )
+    };
+    nix::errno::Errno::result(ret)?;
+
+    Ok(conn)
+}
+
+fn listen_unix(path: &str) -> Result<RawFd> {
+    let _ = fs::remove_file(path);
+    Ok(UnixListener::bind(path)?.into_raw_fd())
+}
+
+// serve reads the requests of conn until it is closed.
+fn serve(mut conn: File, services: Arc<HashMap<String, MethodHandler>>) -> Result<()> {
+    let writer = Arc::new(Mutex::new(conn.try_clone()?));
+    let requests = Slots::new(REQUESTS_IN_FLIGHT_MAX);
+
+    loop {
+        let mut header = [0u8; MESSAGE_HEADER_LENGTH];
+        conn.read_exact(&mut header)?;
+
+        let length = be32(&header[0..4]) as usize;
+        let stream_id = be32(&header[4..8]);
+        let kind = header[8];
+
+        if length > MESSAGE_LENGTH_MAX {
+            io::copy(&mut (&mut conn).take(length as u64), &mut io::sink())?;
+            let status = status(
+                CODE_RESOURCE_EXHAUSTED,
+                &format!("message length {} over {}", length, MESSAGE_LENGTH_MAX),
+            );
+            respond(&writer, stream_id, Err(status))?;
+            continue;
+        }
+
+        let mut payload = vec![0u8; length];
+        conn.read_exact(&mut payload)?;
+
+        if kind != MESSAGE_TYPE_REQUEST {
+            warn!(sl!(), "unexpected message"; "type" => kind, "stream" => stream_id);
+            continue;
+        }
+
+        let req = match protobuf::parse_from_bytes::<Request>(&payload) {
+            Ok(req) => req,
+            Err(e) => {
+                let status = status(CODE_INVALID_ARGUMENT, &format!("bad request: {}", e));
+                respond(&writer, stream_id, Err(status))?;
+                continue;
+            }
+        };
+
+        let handler = match services.get(req.get_service()) {
+            Some(handler) => handler.clone(),
+            None => {
+                let status = status(
+                    CODE_NOT_FOUND,
+                    &format!("service {} not found", req.get_service()),
+                );
+                respond(&writer, stream_id, Err(status))?;
+                continue;
+            }
+        };
+
+        let slot = match requests.acquire() {
+            Some(slot) => slot,
+            None => {
+                let status = status(
+                    CODE_RESOURCE_EXHAUSTED,
+                    &format!("over {} requests in flight", REQUESTS_IN_FLIGHT_MAX),
+                );
+                respond(&writer, stream_id, Err(status))?;
+                continue;
+            }
+        };
+
+        let w = writer.clone();
+        let res = thread::Builder::new()
+            .name("ttrpc-request".to_string())
+            .spawn(move || {
+                let _slot = slot;
+                let res = handler(&req);
+                if let Err(e) = respond(&w, stream_id, res) {
+                    info!(sl!(), "failed to respond";
+                        "method" => req.get_method(),
+                        "error" => format!("{}", e));
+                }
+            });
+        if let Err(e) = res {
+            let status = status(CODE_RESOURCE_EXHAUSTED, &format!("serve request: {}", e));
+            respond(&writer, stream_id, Err(status))?;
+        }
+    }
+}
+
+// Slots bounds how many of something are in flight at once.
+struct Slots {
+    used: Arc<AtomicUsize>,
+    max: usize,
+}
+
+// Slot is one of Slots, given back when dropped.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slots {
+    fn new(max: usize) -> Self {
+        Slots {
+            used: Arc::new(AtomicUsize::new(0)),
+            max,
+        }
+    }
+
+    // acquire takes a slot, unless all max of them are taken.
+    fn acquire(&self) -> Option<Slot> {
+        let mut used = self.used.load(Ordering::SeqCst);
+        loop {
+            if used >= self.max {
+                return None;
+            }
+            match self
+                .used
+                .compare_exchange(used, used + 1, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => return Some(Slot(self.used.clone())),
+                Err(u) => used = u,
+            }
+        }
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// respond sends the response of stream_id.
+fn respond(
+    writer: &Mutex<File>,
+    stream_id: u32,
+    res: std::result::Result<Vec<u8>, Status>,
+) -> Result<()> {
+    let mut resp = Response::new();
+    match res {
+        Ok(payload) => resp.set_payload(payload),
+        Err(status) => resp.set_status(status),
+    }
+    let payload = resp
+        .write_to_bytes()
+        .chain_err(|| "encode ttRPC response")?;
+
+    let mut msg = Vec::with_capacity(MESSAGE_HEADER_LENGTH + payload.len());
+    msg.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    msg.extend_from_slice(&stream_id.to_be_bytes());
+    msg.push(MESSAGE_TYPE_RESPONSE);
+    msg.push(0);
+    msg.extend_from_slice(&payload);
+
+    writer.lock().unwrap().write_all(&msg)?;
+
+    Ok(())
+}
+
+fn be32(b: &[u8]) -> u32 {
+    (b[0] as u32) << 24 | (b[1] as u32) << 16 | (b[2] as u32) << 8 | b[3] as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixStream;
+    use tempfile::tempdir;
+
+    fn call(conn: &mut UnixStream, stream_id: u32, service: &str, payload: &[u8]) -> Response {
+        let mut req = Request::new();
+        req.set_service(service.to_string());
+        req.set_method("Echo".to_string());
+        req.set_payload(payload.to_vec());
+        let req = req.write_to_bytes().unwrap();
+
+        let mut msg = Vec::new();
+        msg.extend_from_slice(&(req.len() as u32).to_be_bytes());
+        msg.extend_from_slice(&stream_id.to_be_bytes());
+        msg.extend_from_slice(&[MESSAGE_TYPE_REQUEST, 0]);
+        msg.extend_from_slice(&req);
+        conn.write_all(&msg).unwrap();
+
+        let mut header = [0u8; MESSAGE_HEADER_LENGTH];
+        conn.read_exact(&mut header).unwrap();
+        assert_eq!(be32(&header[4..8]), stream_id);
+        assert_eq!(header[8], MESSAGE_TYPE_RESPONSE);
+
+        let mut payload = vec![0u8; be32(&header[0..4]) as usize];
+        conn.read_exact(&mut payload).unwrap();
+        protobuf::parse_from_bytes::<Response>(&payload).unwrap()
+    }
+
+    #[test]
+    fn test_server() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ttrpc.sock");
+        let host = format!("{}{}", UNIX_SCHEME, path.display());
+
+        let echo: MethodHandler = Arc::new(|req| match req.get_method() {
+            "Echo" => Ok(req.get_payload().to_vec()),
+            method => Err(status(CODE_UNIMPLEMENTED, method)),
+        });
+        let mut server = Server::new()
+            .register_service("test.Echo", echo)
+            .bind(&host, 0)
+            .unwrap();
+        server.start().unwrap();
+
+        let mut conn = UnixStream::connect(&path).unwrap();
+
+        let resp = call(&mut conn, 1, "test.Echo", b"hello");
+        assert!(!resp.has_status());
+        assert_eq!(resp.get_payload(), b"hello");
+
+        let resp = call(&mut conn, 3, "test.Nothing", b"");
+        assert_eq!(resp.get_status().get_code(), CODE_NOT_FOUND);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn test_slots() {
+        let slots = Slots::new(2);
+
+        let a = slots.acquire().unwrap();
+        let b = slots.acquire().unwrap();
+        assert!(slots.acquire().is_none());
+
+        drop(a);
+        let c = slots.acquire().unwrap();
+        assert!(slots.acquire().is_none());
+
+        drop(b);
+        drop(c);
+        assert_eq!(slots.used.load(Ordering::SeqCst), 0);
+    }
+}
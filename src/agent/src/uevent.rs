@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use crate::device::{online_device, ROOT_BUS_PATH, SCSI_BLOCK_SUFFIX, SYSFS_DIR};
+use crate::device::{device_matches, online_device, NVDIMM_BUS, ROOT_BUS_PATH, SYSFS_DIR};
 use crate::grpc::SYSFS_MEMORY_ONLINE_PATH;
 use crate::netlink::{RtnlHandle, NETLINK_UEVENT};
 use crate::sandbox::Sandbox;
@@ -75,17 +75,19 @@ pub fn watch_uevents(sandbox: Arc<Mutex<Sandbox>>) {
 
                             // Check if device hotplug event results in a device node being created.
                             if event.devname != ""
-                                && event.devpath.starts_with(ROOT_BUS_PATH)
+                                && (event.devpath.starts_with(ROOT_BUS_PATH)
+                                    || event.devpath.contains(NVDIMM_BUS))
                                 && event.subsystem == "block"
                             {
                                 let watcher = GLOBAL_DEVICE_WATCHER.clone();
                                 let mut w = watcher.lock().unwrap();
 
-                                let s = sandbox.clone();
-                                let mut sb = s.lock().unwrap();
+                                let pci_device_map = sandbox.lock().unwrap().pci_device_map.clone();
 
                                 // Add the device node name to the pci device map.
-                                sb.pci_device_map
+                                pci_device_map
+                                    .lock()
+                                    .unwrap()
                                     .insert(event.devpath.clone(), event.devname.clone());
 
                                 // Notify watchers that are interested in the udev event.
@@ -95,17 +97,7 @@ pub fn watch_uevents(sandbox: Arc<Mutex<Sandbox>>) {
 
                                 let empties: Vec<_> = w
                                     .iter()
-                                    .filter(|(dev_addr, _)| {
-                                        let pci_p = format!("{}/{}", ROOT_BUS_PATH, *dev_addr);
-
-                                        // blk block device
-                                        devpath.starts_with(pci_p.as_str()) ||
-                                            // scsi block device
-                                            {
-                                                (*dev_addr).ends_with(SCSI_BLOCK_SUFFIX) &&
-                                                devpath.contains(*dev_addr)
-                                            }
-                                    })
+                                    .filter(|(dev_addr, _)| device_matches(&devpath, dev_addr))
                                     .map(|(k, sender)| {
                                         let devname = event.devname.clone();
                                         let _ = sender.send(devname);
@@ -0,0 +1,320 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Watchable storages keep a guest-local copy of volumes, e.g. ConfigMaps
+// and Secrets, that the host cannot share with the guest. The host pushes
+// the files, and their updates, with CopyFile to a staging directory and
+// every completed file is published at the mount point the way kubelet
+// does: the files live in a timestamped snapshot directory, the ..data
+// symlink is atomically switched to the latest snapshot and each top
+// level entry is a symlink through ..data. Containers bind mount the
+// directory, so that they see the updates.
+
+use nix::unistd::{self, FchownatFlags, Gid, Uid};
+use rustjail::errors::*;
+use std::collections::HashSet;
+use std::fs;
+use std::os::unix::fs::{symlink, DirBuilderExt, MetadataExt};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DATA_DIR_NAME: &'static str = "..data";
+const DATA_DIR_TMP_NAME: &'static str = "..data_tmp";
+
+// As for a ConfigMap or a Secret.
+pub const MAX_ENTRIES_PER_STORAGE: usize = 16;
+pub const MAX_SIZE_PER_STORAGE: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct WatchableStorage {
+    // where the host copies the files to
+    pub source: PathBuf,
+    // where the containers see them
+    pub target: PathBuf,
+}
+
+impl WatchableStorage {
+    pub fn new(source: &Path, target: &Path) -> Self {
+        WatchableStorage {
+            source: source.to_path_buf(),
+            target: target.to_path_buf(),
+        }
+    }
+
+    // admit checks that path, of file_size bytes, may be copied to the
+    // staging directory without exceeding the limits of the storage. The
+    // caller holds the storages lock from admit to sync, so that copies
+    // cannot exceed the limits together.
+    pub fn admit(&self, path: &Path, file_size: i64) -> Result<()> {
+        let (count, size) = staged_usage(&self.source, &[path])?;
+        check_limits(count + 1, size + file_size.max(0) as u64)
+    }
+
+    // staging_path returns where the copy of path is written until it is
+    // complete, named as kubelet reserves, so that it is not published.
+    pub fn staging_path(path: &Path) -> PathBuf {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        path.with_file_name(format!("..{}.tmp", name))
+    }
+
+    // sync publishes the content of the staging directory as a new
+    // snapshot, and removes the previous one.
+    pub fn sync(&self) -> Result<()> {
+        let entries = staged_entries(&self.source)?;
+
+        let (count, size) = usage(&self.source, &entries)?;
+        check_limits(count, size)?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .chain_err(|| "bad system time")?;
+        let name = format!("..{}_{:09}", now.as_secs(), now.subsec_nanos());
+        let snapshot = self.target.join(&name);
+
+        fs::DirBuilder::new().mode(0o755).create(&snapshot)?;
+        if let Err(e) = copy_entries(&self.source, &snapshot, &entries) {
+            let _ = fs::remove_dir_all(&snapshot);
+            return Err(e);
+        }
+
+        let data = self.target.join(DATA_DIR_NAME);
+        let old = fs::read_link(&data).ok();
+
+        // rename(2) replaces the symlink atomically
+        let tmp = self.target.join(DATA_DIR_TMP_NAME);
+        if fs::symlink_metadata(&tmp).is_ok() {
+            fs::remove_file(&tmp)?;
+        }
+        symlink(&name, &tmp)?;
+        fs::rename(&tmp, &data)?;
+
+        let mut names = HashSet::new();
+        for e in entries.iter().filter(|e| e.components().count() == 1) {
+            let link = self.target.join(e);
+            if fs::symlink_metadata(&link).is_err() {
+                symlink(Path::new(DATA_DIR_NAME).join(e), &link)?;
+            }
+            names.insert(e.as_os_str().to_os_string());
+        }
+
+        for e in fs::read_dir(&self.target)? {
+            let e = e?;
+            let n = e.file_name();
+            if !n.to_string_lossy().starts_with("..") && !names.contains(&n) {
+                fs::remove_file(e.path())?;
+            }
+        }
+
+        if let Some(old) = old {
+            if old != Path::new(&name) {
+                fs::remove_dir_all(self.target.join(old))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // remove deletes both the staging directory and the published copy.
+    pub fn remove(&self) -> Result<()> {
+        fs::remove_dir_all(&self.target)?;
+        fs::remove_dir_all(&self.source)?;
+        Ok(())
+    }
+}
+
+fn check_limits(count: usize, size: u64) -> Result<()> {
+    if count > MAX_ENTRIES_PER_STORAGE {
+        return Err(ErrorKind::ErrorCode(format!(
+            "watchable storage holds more than {} files",
+            MAX_ENTRIES_PER_STORAGE
+        ))
+        .into());
+    }
+
+    if size > MAX_SIZE_PER_STORAGE {
+        return Err(ErrorKind::ErrorCode(format!(
+            "watchable storage holds more than {} bytes",
+            MAX_SIZE_PER_STORAGE
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+// staged_entries lists the regular files below dir and the directories
+// holding them, relative to dir, parents first. Entries kubelet reserves,
+// starting with "..", are left out.
+fn staged_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = Vec::new();
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+
+    while let Some(d) = dirs.pop() {
+        for e in fs::read_dir(dir.join(&d))? {
+            let e = e?;
+            if e.file_name().to_string_lossy().starts_with("..") {
+                continue;
+            }
+
+            let ft = e.file_type()?;
+            let rel = d.join(e.file_name());
+            if ft.is_dir() {
+                dirs.push(rel.clone());
+            } else if ft.is_file() {
+                files.push(rel.clone());
+            } else {
+                continue;
+            }
+            entries.push(rel);
+        }
+    }
+
+    // directories being created for a copy in progress stay out
+    entries.retain(|e| files.iter().any(|f| f.starts_with(e)));
+
+    Ok(entries)
+}
+
+// staged_usage returns the number of files below dir and their size,
+// leaving out those in excluded.
+fn staged_usage(dir: &Path, excluded: &[&Path]) -> Result<(usize, u64)> {
+    let entries: Vec<PathBuf> = staged_entries(dir)?
+        .into_iter()
+        .filter(|e| !excluded.contains(&dir.join(e).as_path()))
+        .collect();
+
+    usage(dir, &entries)
+}
+
+// usage returns the number of regular files among the entries below dir
+// and their size.
+fn usage(dir: &Path, entries: &[PathBuf]) -> Result<(usize, u64)> {
+    let mut count = 0;
+    let mut size = 0;
+    for e in entries.iter() {
+        let md = fs::symlink_metadata(dir.join(e))?;
+        if md.is_file() {
+            count += 1;
+            size += md.len();
+        }
+    }
+
+    Ok((count, size))
+}
+
+// copy_entries copies entries from src to dst, with their mode and owner.
+fn copy_entries(src: &Path, dst: &Path, entries: &[PathBuf]) -> Result<()> {
+    for e in entries.iter() {
+        let from = src.join(e);
+        let to = dst.join(e);
+        let md = fs::symlink_metadata(&from)?;
+
+        if md.is_dir() {
+            fs::DirBuilder::new().mode(md.mode()).create(&to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+
+        unistd::fchownat(
+            None,
+            &to,
+            Some(Uid::from_raw(md.uid())),
+            Some(Gid::from_raw(md.gid())),
+            FchownatFlags::NoFollowSymlink,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sync() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("staging");
+        let target = dir.path().join("volume");
+        fs::create_dir(&source).unwrap();
+        fs::create_dir(&target).unwrap();
+
+        let w = WatchableStorage::new(&source, &target);
+
+        fs::write(source.join("app.conf"), "a=1").unwrap();
+        fs::create_dir(source.join("empty")).unwrap();
+        fs::create_dir(source.join("certs")).unwrap();
+        fs::write(source.join("certs/ca.pem"), "ca").unwrap();
+        fs::set_permissions(source.join("app.conf"), fs::Permissions::from_mode(0o600)).unwrap();
+        w.sync().unwrap();
+
+        let first = fs::read_link(target.join(DATA_DIR_NAME)).unwrap();
+        assert_eq!(
+            fs::read_link(target.join("app.conf")).unwrap(),
+            Path::new("..data/app.conf")
+        );
+        assert_eq!(fs::read_to_string(target.join("app.conf")).unwrap(), "a=1");
+        assert_eq!(
+            fs::read_to_string(target.join("certs/ca.pem")).unwrap(),
+            "ca"
+        );
+        assert!(fs::symlink_metadata(target.join("empty")).is_err());
+        let md = fs::metadata(target.join("app.conf")).unwrap();
+        assert_eq!(md.mode() & 0o777, 0o600);
+
+        // an update switches to a new snapshot, dropping removed entries
+        fs::write(source.join("app.conf"), "a=2").unwrap();
+        fs::remove_dir_all(source.join("certs")).unwrap();
+        w.sync().unwrap();
+
+        let second = fs::read_link(target.join(DATA_DIR_NAME)).unwrap();
+        assert_ne!(first, second);
+        assert!(!target.join(&first).exists());
+        assert_eq!(fs::read_to_string(target.join("app.conf")).unwrap(), "a=2");
+        assert!(fs::symlink_metadata(target.join("certs")).is_err());
+        assert!(fs::symlink_metadata(target.join(DATA_DIR_TMP_NAME)).is_err());
+    }
+
+    #[test]
+    fn test_limits() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("staging");
+        let target = dir.path().join("volume");
+        fs::create_dir(&source).unwrap();
+        fs::create_dir(&target).unwrap();
+
+        let w = WatchableStorage::new(&source, &target);
+
+        for i in 0..MAX_ENTRIES_PER_STORAGE {
+            fs::write(source.join(format!("key{}", i)), "v").unwrap();
+        }
+        w.sync().unwrap();
+
+        // replacing a file is within the limits, and copies in progress
+        // are neither counted nor published
+        assert!(w.admit(&source.join("key0"), 2).is_ok());
+        let staging = WatchableStorage::staging_path(&source.join("key0"));
+        assert_eq!(staging, source.join("..key0.tmp"));
+        fs::write(&staging, "v").unwrap();
+        assert!(w.admit(&source.join("key0"), 2).is_ok());
+        w.sync().unwrap();
+        assert!(fs::symlink_metadata(target.join("..key0.tmp")).is_err());
+        fs::remove_file(&staging).unwrap();
+
+        assert!(w.admit(&source.join("extra"), 1).is_err());
+        assert!(w
+            .admit(&source.join("key0"), MAX_SIZE_PER_STORAGE as i64)
+            .is_err());
+
+        fs::write(source.join("extra"), "v").unwrap();
+        assert!(w.sync().is_err());
+        // the published copy is left as it was
+        assert_eq!(fs::read_to_string(target.join("key1")).unwrap(), "v");
+        assert!(fs::symlink_metadata(target.join("extra")).is_err());
+    }
+}